@@ -0,0 +1,231 @@
+// Builds the listing table used by `rust_book_examples::runner`.
+//
+// Every `pub mod` at the top level of a section file is a listing. The table
+// records where it lives, a title taken from the prose in front of it, the
+// comments that document its output and, when the listing has a `pub fn main`,
+// a function that runs it. The output is documented either by the comments
+// that close its body, or by a transcript in the prose that follows it, as
+// the book prints a `$ cargo run` session after the listing it runs.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+const BOOKS: [(&str, &str); 2] = [
+    ("trpl", "src/the_rust_programming_language"),
+    ("rbe", "src/rust_by_example"),
+];
+
+struct Listing {
+    path: String,
+    title: String,
+    file: String,
+    line: usize,
+    notes: Option<String>,
+    runnable: bool,
+}
+
+fn main() {
+    let mut listings = Vec::new();
+    for (book, dir) in BOOKS {
+        println!("cargo:rerun-if-changed={dir}");
+        let index = fs::read_to_string(format!("{dir}/mod.rs")).expect("book index");
+        for (file, module) in sections(book, &index) {
+            let file = format!("{dir}/{file}");
+            println!("cargo:rerun-if-changed={file}");
+            let source = fs::read_to_string(&file).expect("section file");
+            scan(&file, &module, &source, &mut listings);
+        }
+    }
+
+    let mut out = String::from("&[\n");
+    for l in &listings {
+        writeln!(out, "    Listing {{").unwrap();
+        writeln!(out, "        path: {:?},", l.path).unwrap();
+        writeln!(out, "        title: {:?},", l.title).unwrap();
+        writeln!(out, "        file: {:?},", l.file).unwrap();
+        writeln!(out, "        line: {},", l.line).unwrap();
+        match &l.notes {
+            Some(notes) => writeln!(out, "        notes: Some({notes:?}),").unwrap(),
+            None => writeln!(out, "        notes: None,").unwrap(),
+        }
+        if l.runnable {
+            writeln!(
+                out,
+                "        main: Some(|| ::std::process::Termination::report(crate::{}::main())),",
+                l.path
+            )
+            .unwrap();
+        } else {
+            writeln!(out, "        main: None,").unwrap();
+        }
+        writeln!(out, "    }},").unwrap();
+    }
+    out.push_str("]\n");
+
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("listings.rs");
+    fs::write(dest, out).unwrap();
+}
+
+/// Reads a book's `mod.rs` and returns `(file name, module path)` per section.
+fn sections(book: &str, index: &str) -> Vec<(String, String)> {
+    let mut out = Vec::new();
+    let mut chapter: Option<&str> = None;
+    let mut file: Option<&str> = None;
+    for line in index.lines().map(str::trim) {
+        if let Some(rest) = line.strip_prefix("#[path = \"") {
+            file = rest.strip_suffix("\"]").filter(|f| *f != ".");
+        } else if let Some(name) = line.strip_prefix("pub mod ") {
+            if let Some(name) = name.strip_suffix(" {") {
+                chapter = Some(name);
+            } else if let (Some(name), Some(f)) = (name.strip_suffix(';'), file.take()) {
+                let module = match chapter {
+                    Some(ch) => format!("{book}::{ch}::{name}"),
+                    None => format!("{book}::{name}"),
+                };
+                out.push((f.to_string(), module));
+            }
+        } else if line == "}" {
+            chapter = None;
+        }
+    }
+    out
+}
+
+fn scan(file: &str, module: &str, source: &str, out: &mut Vec<Listing>) {
+    let lines: Vec<&str> = source.lines().collect();
+    let heading = lines
+        .first()
+        .and_then(|l| l.strip_prefix("//"))
+        .map(str::trim)
+        .unwrap_or(module);
+    let mut prose_start = 0;
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        let Some(decl) = line.strip_prefix("pub mod ") else {
+            i += 1;
+            continue;
+        };
+        let name = decl.split(' ').next().unwrap();
+        let prose = &lines[prose_start..i];
+        let title = title(prose).unwrap_or_else(|| heading.to_string());
+        let start = i;
+        let mut body: &[&str] = &[];
+        if decl.ends_with('{') {
+            let end = (i..lines.len()).find(|&k| lines[k] == "}").unwrap_or(lines.len());
+            body = &lines[i + 1..end];
+            i = end;
+        }
+        let next = (i + 1..lines.len()).find(|&k| lines[k].starts_with("pub mod ")).unwrap_or(lines.len());
+        let notes = match trailing_comments(body) {
+            Some(notes) if documents_output(&notes) => Some(notes),
+            notes => following_transcript(&lines[(i + 1).min(next)..next]).or(notes),
+        };
+        out.push(Listing {
+            path: format!("{module}::{name}"),
+            title,
+            file: file.to_string(),
+            line: start + 1,
+            notes,
+            runnable: body.iter().any(|l| l.starts_with("    pub fn main(")),
+        });
+        i += 1;
+        prose_start = i;
+    }
+}
+
+/// Picks a title out of the prose in front of a listing: the caption of a
+/// numbered listing if there is one, otherwise the last one-line `//` heading.
+fn title(prose: &[&str]) -> Option<String> {
+    let mut heading = None;
+    let mut in_block = false;
+    for line in prose {
+        if in_block {
+            in_block = !line.contains("*/");
+            continue;
+        }
+        if line.starts_with("/*") {
+            in_block = !line.contains("*/");
+            continue;
+        }
+        if line.starts_with("///") {
+            continue;
+        }
+        if let Some(text) = line.strip_prefix("//").map(str::trim) {
+            if let Some(pos) = text.find("Listing ") {
+                if let Some((_, caption)) = text[pos..].split_once(": ") {
+                    return Some(caption.trim().to_string());
+                }
+            }
+            if !text.is_empty() && !text.starts_with("http") && !text.ends_with(':') && text.len() <= 80 {
+                heading = Some(text.to_string());
+            }
+        }
+    }
+    heading
+}
+
+/// Whether `line`, stripped of comment markers, starts a transcript: an
+/// `Output` block or a command line.
+fn starts_transcript(line: &str) -> bool {
+    let line = line.trim_start();
+    let line = line.strip_prefix("/*").or_else(|| line.strip_prefix("//")).unwrap_or(line).trim_start();
+    line.starts_with("Output") || line.starts_with("$ cargo run") || line.starts_with("$ ./")
+}
+
+fn documents_output(notes: &str) -> bool {
+    notes.lines().any(starts_transcript)
+}
+
+/// Returns the transcript in the first comment of the prose after a
+/// listing, from its first command line or `Output` on, if it has one.
+fn following_transcript(prose: &[&str]) -> Option<String> {
+    let first = prose.iter().position(|l| !l.trim().is_empty())?;
+    let line = prose[first].trim_start();
+    let end = if line.starts_with("/*") {
+        (first..prose.len()).find(|&k| prose[k].contains("*/"))?
+    } else if line.starts_with("//") {
+        (first..prose.len()).take_while(|&k| prose[k].trim_start().starts_with("//")).last()?
+    } else {
+        return None;
+    };
+    let start = (first..=end).find(|&k| starts_transcript(prose[k]))?;
+    Some(prose[start..=end].join("\n").trim_end().to_string())
+}
+
+/// Returns the comments that close a listing's body, without the module
+/// indentation.
+fn trailing_comments(body: &[&str]) -> Option<String> {
+    let mut start = body.len();
+    let mut k = body.len();
+    while k > 0 {
+        let line = body[k - 1].trim();
+        if line.is_empty() || line.starts_with("//") {
+            k -= 1;
+            if !line.is_empty() {
+                start = k;
+            }
+        } else if line.ends_with("*/") {
+            let open = (0..k).rev().find(|&j| body[j].trim_start().starts_with("/*"));
+            match open {
+                Some(j) => {
+                    k = j;
+                    start = j;
+                }
+                None => break,
+            }
+        } else {
+            break;
+        }
+    }
+    if start == body.len() {
+        return None;
+    }
+    let text: Vec<&str> = body[start..]
+        .iter()
+        .map(|l| l.strip_prefix("    ").unwrap_or(l))
+        .collect();
+    Some(text.join("\n").trim_end().to_string())
+}
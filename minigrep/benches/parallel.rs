@@ -0,0 +1,87 @@
+//! Compares searching a directory tree on one thread with the worker pool.
+//!
+//! Run with `cargo bench -p minigrep`. Set `MINIGREP_BENCH_FILES` to change
+//! the number of generated files (default 400).
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use minigrep::{Config, PatternKind};
+
+const LINES_PER_FILE: usize = 2_000;
+const ROUNDS: usize = 5;
+
+fn main() {
+    let files: usize = env::var("MINIGREP_BENCH_FILES")
+        .ok()
+        .and_then(|n| n.parse().ok())
+        .unwrap_or(400);
+    let root = env::temp_dir().join(format!("minigrep-bench-{}", std::process::id()));
+    generate(&root, files).expect("failed to write the benchmark files");
+
+    let cpus = thread::available_parallelism().map_or(1, |n| n.get());
+    let mut jobs = vec![1, 2, 4, cpus];
+    jobs.sort_unstable();
+    jobs.dedup();
+
+    println!("{files} files of {LINES_PER_FILE} lines, best of {ROUNDS} rounds");
+    for kind in [PatternKind::Literal, PatternKind::Regex] {
+        let query = match kind {
+            PatternKind::Literal => "needle",
+            PatternKind::Regex => r"need(le|ful) \d+",
+        };
+        let baseline = time(&root, query, kind, 1);
+        for &jobs in &jobs {
+            let elapsed = if jobs == 1 { baseline } else { time(&root, query, kind, jobs) };
+            println!(
+                "{:<8} -j {jobs:<3} {:>9.2?}  {:>5.2}x",
+                format!("{kind:?}"),
+                elapsed,
+                baseline.as_secs_f64() / elapsed.as_secs_f64(),
+            );
+        }
+    }
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+fn time(root: &Path, query: &str, pattern_kind: PatternKind, jobs: usize) -> Duration {
+    let config = Config {
+        patterns: vec![query.to_string()],
+        paths: vec![root.to_path_buf()],
+        pattern_kind,
+        jobs,
+        ..Config::default()
+    };
+    (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            minigrep::run_to(&config, &mut io::sink()).unwrap();
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn generate(root: &Path, files: usize) -> io::Result<()> {
+    let _ = fs::remove_dir_all(root);
+    for i in 0..files {
+        let dir = root.join(format!("dir{}", i % 16));
+        fs::create_dir_all(&dir)?;
+        let contents: String = (0..LINES_PER_FILE)
+            .map(|n| {
+                if (n * 31 + i) % 97 == 0 {
+                    format!("line {n}: a needle {i} in the haystack\n")
+                } else {
+                    format!("line {n}: nothing to see in file {i}, just hay and more hay\n")
+                }
+            })
+            .collect();
+        fs::write(dir.join(format!("file{i:04}.txt")), contents)?;
+    }
+    Ok(())
+}
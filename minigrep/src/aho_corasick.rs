@@ -0,0 +1,305 @@
+//! Searching for many literal patterns at once.
+//!
+//! [`AhoCorasick`] builds a trie of the patterns with failure links, the
+//! automaton of Aho and Corasick, and finds the matches of all of them in one
+//! pass over the text. Like grep, it reports leftmost-longest matches: of the
+//! matches starting earliest the longest wins, and matches do not overlap.
+//!
+//! A case-insensitive automaton compares the Unicode case folding of the
+//! patterns and the text, so that `STRASSE` matches `Straße` and `σ` matches
+//! `ς`. A match never starts or ends inside the folding of one character.
+
+use std::char::{ToLowercase, ToUppercase};
+use std::collections::VecDeque;
+use std::ops::Range;
+
+/// One match: where it is in the text, and which pattern it was.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternMatch {
+    pub bytes: Range<usize>,
+    /// The index of the pattern in the list given to [`AhoCorasick::new`].
+    pub pattern: usize,
+}
+
+/// An automaton matching a fixed set of patterns.
+#[derive(Debug)]
+pub struct AhoCorasick {
+    states: Vec<State>,
+    /// The length in chars of each pattern, after folding.
+    lens: Vec<usize>,
+    fold: bool,
+    /// The first empty pattern, which matches at the start of any text.
+    empty: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// Trie edges, sorted by char.
+    next: Vec<(char, usize)>,
+    /// The state for the longest proper suffix of this one's string that is
+    /// in the trie.
+    fail: usize,
+    /// The pattern spelled by this state's string, if any.
+    output: Option<usize>,
+    /// The nearest state along the failure links that has an output.
+    dict: Option<usize>,
+    /// The length of this state's string.
+    depth: usize,
+}
+
+const ROOT: usize = 0;
+
+impl AhoCorasick {
+    /// Builds the automaton for `patterns`. With `case_insensitive`, patterns
+    /// and text are compared by their case folding. A pattern given twice
+    /// reports the first index.
+    pub fn new<P: AsRef<str>>(patterns: &[P], case_insensitive: bool) -> AhoCorasick {
+        let mut automaton = AhoCorasick {
+            states: vec![State::default()],
+            lens: Vec::with_capacity(patterns.len()),
+            fold: case_insensitive,
+            empty: None,
+        };
+        for (index, pattern) in patterns.iter().enumerate() {
+            let chars: Vec<char> = pattern
+                .as_ref()
+                .chars()
+                .flat_map(|c| Folded::new(c, case_insensitive))
+                .collect();
+            automaton.lens.push(chars.len());
+            automaton.insert(&chars, index);
+        }
+        automaton.link();
+        automaton
+    }
+
+    fn insert(&mut self, chars: &[char], index: usize) {
+        if chars.is_empty() {
+            self.empty.get_or_insert(index);
+            return;
+        }
+        let mut state = ROOT;
+        for &c in chars {
+            let edges = &self.states[state].next;
+            state = match edges.binary_search_by_key(&c, |&(c, _)| c) {
+                Ok(i) => edges[i].1,
+                Err(i) => {
+                    let id = self.states.len();
+                    let depth = self.states[state].depth + 1;
+                    self.states[state].next.insert(i, (c, id));
+                    self.states.push(State {
+                        depth,
+                        ..State::default()
+                    });
+                    id
+                }
+            };
+        }
+        self.states[state].output.get_or_insert(index);
+    }
+
+    /// Sets the failure and dictionary links, breadth first.
+    fn link(&mut self) {
+        let mut queue = VecDeque::new();
+        queue.extend(self.states[ROOT].next.iter().map(|&(_, s)| s));
+        while let Some(state) = queue.pop_front() {
+            for i in 0..self.states[state].next.len() {
+                let (c, child) = self.states[state].next[i];
+                let fail = if state == ROOT {
+                    ROOT
+                } else {
+                    self.step(self.states[state].fail, c)
+                };
+                let target = &self.states[fail];
+                let dict = if target.output.is_some() {
+                    Some(fail)
+                } else {
+                    target.dict
+                };
+                self.states[child].fail = fail;
+                self.states[child].dict = dict;
+                queue.push_back(child);
+            }
+        }
+    }
+
+    /// The state after reading `c` in `state`.
+    fn step(&self, mut state: usize, c: char) -> usize {
+        loop {
+            let edges = &self.states[state].next;
+            if let Ok(i) = edges.binary_search_by_key(&c, |&(c, _)| c) {
+                return edges[i].1;
+            }
+            if state == ROOT {
+                return ROOT;
+            }
+            state = self.states[state].fail;
+        }
+    }
+
+    /// Whether any pattern matches in `text`.
+    pub fn is_match(&self, text: &str) -> bool {
+        !self.find_all(text).is_empty()
+    }
+
+    /// Returns the leftmost-longest, non-overlapping matches in `text`. An
+    /// empty pattern only matches if nothing else does, once, at the start.
+    pub fn find_all(&self, text: &str) -> Vec<PatternMatch> {
+        let mut found = Vec::new();
+        // For each char fed to the automaton since `pos`: the byte offset of
+        // the text char it came from, and whether it is the first char of
+        // that one's folding.
+        let mut fed: Vec<(usize, bool)> = Vec::new();
+        let mut pos = 0;
+        while pos < text.len() {
+            fed.clear();
+            let mut state = ROOT;
+            // The best match so far, with the index in `fed` of its start.
+            let mut best: Option<(usize, PatternMatch)> = None;
+            for (i, c) in text[pos..].char_indices() {
+                let (start, end) = (pos + i, pos + i + c.len_utf8());
+                let mut folded = Folded::new(c, self.fold).peekable();
+                while let Some(f) = folded.next() {
+                    fed.push((start, fed.last().is_none_or(|&(s, _)| s != start)));
+                    state = self.step(state, f);
+                    if folded.peek().is_some() {
+                        continue;
+                    }
+                    // The longest valid match ending here starts earliest.
+                    let mut candidate = self.states[state].output.map(|_| state).or(self.states[state].dict);
+                    while let Some(s) = candidate {
+                        let pattern = self.states[s].output.unwrap();
+                        let first = fed.len() - self.lens[pattern];
+                        if fed[first].1 {
+                            let better = best.as_ref().is_none_or(|(b, m)| {
+                                first < *b || (first == *b && end > m.bytes.end)
+                            });
+                            if better {
+                                let bytes = fed[first].0..end;
+                                best = Some((first, PatternMatch { bytes, pattern }));
+                            }
+                            break;
+                        }
+                        candidate = self.states[s].dict;
+                    }
+                }
+                // No match yet to come can start at or before the best one.
+                let earliest = fed.len() - self.states[state].depth;
+                if best.as_ref().is_some_and(|(b, _)| earliest > *b) {
+                    break;
+                }
+            }
+            match best {
+                Some((_, m)) => {
+                    pos = m.bytes.end;
+                    found.push(m);
+                }
+                None => break,
+            }
+        }
+        if found.is_empty() {
+            if let Some(pattern) = self.empty {
+                found.push(PatternMatch { bytes: 0..0, pattern });
+            }
+        }
+        found
+    }
+}
+
+/// Returns the case folding of `text`, as a case-insensitive automaton sees
+/// it.
+pub(crate) fn fold(text: &str) -> String {
+    text.chars().flat_map(folded).collect()
+}
+
+/// Returns the case folding of `c`.
+pub(crate) fn folded(c: char) -> impl Iterator<Item = char> {
+    Folded::new(c, true)
+}
+
+/// The chars `c` is compared as: its simple case folding, approximated as
+/// the lowercase of its uppercase so that `ß` folds to `ss` and `ς` to `σ`.
+enum Folded {
+    Same(Option<char>),
+    Fold {
+        upper: ToUppercase,
+        lower: Option<ToLowercase>,
+    },
+}
+
+impl Folded {
+    fn new(c: char, fold: bool) -> Folded {
+        if !fold {
+            Folded::Same(Some(c))
+        } else if c.is_ascii() {
+            Folded::Same(Some(c.to_ascii_lowercase()))
+        } else {
+            Folded::Fold {
+                upper: c.to_uppercase(),
+                lower: None,
+            }
+        }
+    }
+}
+
+impl Iterator for Folded {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        match self {
+            Folded::Same(c) => c.take(),
+            Folded::Fold { upper, lower } => loop {
+                if let Some(c) = lower.as_mut().and_then(Iterator::next) {
+                    return Some(c);
+                }
+                *lower = Some(upper.next()?.to_lowercase());
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find<'t>(patterns: &[&str], fold: bool, text: &'t str) -> Vec<(&'t str, usize)> {
+        let automaton = AhoCorasick::new(patterns, fold);
+        automaton
+            .find_all(text)
+            .into_iter()
+            .map(|m| (&text[m.bytes], m.pattern))
+            .collect()
+    }
+
+    #[test]
+    fn leftmost_longest() {
+        assert_eq!(find(&["he", "she", "his", "hers"], false, "ushers"), [("she", 1)]);
+        assert_eq!(find(&["bcd", "abcde"], false, "abcdef"), [("abcde", 1)]);
+        assert_eq!(find(&["ab", "abcd", "cd"], false, "abcx"), [("ab", 0)]);
+        assert_eq!(find(&["a", "ab"], false, "ab ab a"), [("ab", 1), ("ab", 1), ("a", 0)]);
+        assert_eq!(find(&["aa"], false, "aaaaa"), [("aa", 0), ("aa", 0)]);
+        assert_eq!(find(&["x", "x"], false, "x"), [("x", 0)]);
+        assert_eq!(find(&["é", "ét"], false, "l'été"), [("ét", 1), ("é", 0)]);
+    }
+
+    #[test]
+    fn empty_and_no_patterns() {
+        assert_eq!(find(&["", "b"], false, "abc"), [("b", 1)]);
+        assert_eq!(find(&["", "x"], false, "abc"), [("", 0)]);
+        assert_eq!(find(&[], false, "abc"), []);
+        assert!(!AhoCorasick::new(&["abc"], false).is_match(""));
+    }
+
+    #[test]
+    fn case_folding() {
+        assert_eq!(find(&["rust"], true, "Rust, TRUST"), [("Rust", 0), ("RUST", 0)]);
+        assert_eq!(find(&["STRASSE"], true, "Straße"), [("Straße", 0)]);
+        assert_eq!(find(&["ΟΔΟΣ"], true, "οδός οδος"), [("οδος", 0)]);
+        assert_eq!(find(&["σ"], true, "ς"), [("ς", 0)]);
+        assert_eq!(find(&["ÉTÉ"], true, "l'été"), [("été", 0)]);
+        // Half of the folding of `ß` is not a match.
+        assert_eq!(find(&["s"], true, "ß"), []);
+        assert_eq!(find(&["as"], true, "aß"), []);
+        assert_eq!(find(&["Rust"], false, "rust"), []);
+    }
+}
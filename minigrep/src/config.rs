@@ -0,0 +1,737 @@
+//! Building a [`Config`] from the command line, the environment and a
+//! configuration file.
+//!
+//! Each setting is taken from the first of these that has it:
+//!
+//! 1. the command line,
+//! 2. the environment: `IGNORE_CASE` turns on `--ignore-case`, unless it is
+//!    `0` or `false`, and `NO_COLOR`, unless empty, sets `--color never`,
+//! 3. the configuration file, `$MINIGREP_CONFIG` if that is set (set it empty
+//!    for none), else `$XDG_CONFIG_HOME/minigrep/config.toml`, else
+//!    `~/.config/minigrep/config.toml`,
+//! 4. the defaults.
+//!
+//! The configuration file holds `name = value` lines in TOML syntax, where
+//! the names are those of the long flags that may go there, such as
+//!
+//! ```toml
+//! # Always search like this.
+//! ignore-case = true
+//! hidden = true
+//! threads = 4
+//! context = 2
+//! ```
+//!
+//! A flag that takes no value is set with `true` and unset with `false`.
+
+use std::env;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+
+use crate::sink::Palette;
+use crate::{ColorChoice, Config, Decompress, OutputMode, PatternKind};
+
+/// Why no `Config` could be built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    /// `--help` was given; the error displays the help.
+    Help,
+    /// `--version` was given; the error displays the version.
+    Version,
+    MissingQuery,
+    MissingPath,
+    UnknownFlag(String),
+    /// A flag that takes a value came last.
+    MissingValue(String),
+    /// A flag that takes no value was given one, as in `--hidden=yes`.
+    UnexpectedValue(String),
+    InvalidValue {
+        flag: String,
+        value: String,
+        expected: &'static str,
+    },
+    /// Two flags that do not go together.
+    Conflict(&'static str, &'static str),
+    /// The first flag only goes with the second.
+    Requires(&'static str, &'static str),
+    /// The configuration file could not be read, or has an error on `line`.
+    File {
+        path: PathBuf,
+        line: Option<usize>,
+        message: String,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Help => write!(f, "{}", help()),
+            ConfigError::Version => write!(f, "minigrep {}", env!("CARGO_PKG_VERSION")),
+            ConfigError::MissingQuery => write!(f, "Didn't get a query string"),
+            ConfigError::MissingPath => write!(f, "Didn't get a file path"),
+            ConfigError::UnknownFlag(flag) => write!(f, "unknown flag {flag}, see --help"),
+            ConfigError::MissingValue(flag) => write!(f, "{flag} needs a value"),
+            ConfigError::UnexpectedValue(flag) => write!(f, "{flag} does not take a value"),
+            ConfigError::InvalidValue {
+                flag,
+                value,
+                expected,
+            } => write!(f, "{flag} needs {expected}, not `{value}`"),
+            ConfigError::Conflict(a, b) => write!(f, "{a} cannot be used with {b}"),
+            ConfigError::Requires(a, b) => write!(f, "{a} needs {b}"),
+            ConfigError::File {
+                path,
+                line: Some(line),
+                message,
+            } => write!(f, "{}:{line}: {message}", path.display()),
+            ConfigError::File { path, message, .. } => write!(f, "{}: {message}", path.display()),
+        }
+    }
+}
+
+impl error::Error for ConfigError {}
+
+/// A flag minigrep understands.
+struct Flag {
+    short: Option<char>,
+    long: &'static str,
+    /// What the value is called in the help, for a flag that takes one.
+    value: Option<&'static str>,
+    /// Whether the configuration file may set it.
+    in_file: bool,
+    help: &'static str,
+}
+
+const fn flag(short: char, long: &'static str, help: &'static str) -> Flag {
+    Flag {
+        short: if short == ' ' { None } else { Some(short) },
+        long,
+        value: None,
+        in_file: false,
+        help,
+    }
+}
+
+impl Flag {
+    const fn value(mut self, name: &'static str) -> Flag {
+        self.value = Some(name);
+        self
+    }
+
+    const fn in_file(mut self) -> Flag {
+        self.in_file = true;
+        self
+    }
+}
+
+const FLAGS: &[Flag] = &[
+    flag('e', "regexp", "search for PATTERN; may be given many times").value("PATTERN"),
+    flag('f', "file", "search for the patterns in FILE, one per line").value("FILE"),
+    flag('E', "extended-regexp", "the patterns are regular expressions").in_file(),
+    flag('F', "fixed-strings", "the patterns are plain strings (the default)").in_file(),
+    flag(' ', "fuzzy", "match within NUM edits of the patterns, best first").value("NUM"),
+    flag('i', "ignore-case", "ignore case, comparing case foldings").in_file(),
+    flag(' ', "case-sensitive", "respect case (the default)").in_file(),
+    flag('v', "invert-match", "select the lines that do not match"),
+    flag('n', "line-number", "print line numbers").in_file(),
+    flag('b', "byte-offset", "print the byte offset of each line").in_file(),
+    flag('A', "after-context", "print NUM lines after each match").value("NUM").in_file(),
+    flag('B', "before-context", "print NUM lines before each match").value("NUM").in_file(),
+    flag('C', "context", "print NUM lines around each match").value("NUM").in_file(),
+    flag('o', "only-matching", "print only the matched parts of lines"),
+    flag('c', "count", "print the number of selected lines per file"),
+    flag('l', "files-with-matches", "print only the paths of files with matches"),
+    flag('L', "files-without-match", "print only the paths of files without"),
+    flag(' ', "json", "print a JSON object per line"),
+    flag('a', "text", "print lines of binary files as if they were text").in_file(),
+    flag(' ', "hidden", "search hidden files and directories").in_file(),
+    flag('z', "decompress", "decompress every file, even without a header").in_file(),
+    flag(' ', "no-decompress", "search gzip and zlib files as they are").in_file(),
+    flag('j', "threads", "search NUM files at a time (default: CPUs)").value("NUM").in_file(),
+    flag(' ', "follow", "search the lines added to the files as they come"),
+    flag(' ', "tui", "browse the matches, searching again as you type"),
+    flag(' ', "color", "color the output: auto (the default), always or never").value("WHEN").in_file(),
+    flag(' ', "replace", "rewrite the files, replacing matches with TEXT").value("TEXT"),
+    flag(' ', "dry-run", "with --replace, print the diff instead"),
+    flag('h', "help", "print this help"),
+    flag('V', "version", "print the version"),
+];
+
+/// The text `--help` prints.
+fn help() -> String {
+    let mut help = String::from(
+        "Prints the lines of files that contain a query.\n\n\
+         Usage: minigrep [OPTIONS] QUERY PATH...\n       \
+         minigrep [OPTIONS] -e PATTERN... PATH...\n       \
+         minigrep index DIR...\n       \
+         minigrep --tui [-e QUERY] PATH...\n\n\
+         Directories are searched recursively, skipping hidden and ignored files.\n\
+         Files compressed with gzip or zlib are searched for what they hold.\n\
+         `minigrep index` writes a trigram index of each DIR, or brings it up to\n\
+         date, which later searches of DIR use to skip files that cannot match.\n\
+         To search for `index` itself, use `-e index` or `-- index`.\n\
+         With --tui, type the query; arrows move, Enter opens $EDITOR, Esc quits.\n\n\
+         Options:\n",
+    );
+    for flag in FLAGS {
+        let short = flag.short.map_or("    ".to_string(), |c| format!("-{c}, "));
+        let long = match flag.value {
+            Some(value) => format!("--{} {value}", flag.long),
+            None => format!("--{}", flag.long),
+        };
+        help.push_str(&format!("  {short}{long:<25} {}\n", flag.help));
+    }
+    help.push_str(
+        "\nColors are given like GREP_COLORS in MINIGREP_COLORS, as in mt=01;31:fn=35,\n\
+         for matches (mt), file names (fn), line numbers (ln), byte offsets (bn)\n\
+         and separators (se).\n\
+         \nSettings come from the command line, then the IGNORE_CASE and NO_COLOR\n\
+         environment variables, then ~/.config/minigrep/config.toml (or\n\
+         $MINIGREP_CONFIG), then the defaults. The file takes `name = value`\n\
+         lines, with the names of these flags:\n   ",
+    );
+    let mut width = 0;
+    for flag in FLAGS.iter().filter(|f| f.in_file) {
+        if width + flag.long.len() > 72 {
+            help.push_str("\n   ");
+            width = 0;
+        }
+        help.push(' ');
+        help.push_str(flag.long);
+        width += flag.long.len() + 1;
+    }
+    help
+}
+
+/// A value for a flag: from the command line, the environment or the file.
+enum Value {
+    /// A flag without a value given, or a boolean from the file.
+    Switch(bool),
+    Text(String),
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            patterns: Vec::new(),
+            pattern_files: Vec::new(),
+            paths: Vec::new(),
+            ignore_case: false,
+            pattern_kind: PatternKind::Literal,
+            hidden: false,
+            jobs: thread::available_parallelism().map_or(1, |n| n.get()),
+            line_numbers: false,
+            byte_offsets: false,
+            before_context: 0,
+            after_context: 0,
+            invert: false,
+            text: false,
+            decompress: Decompress::Auto,
+            output: OutputMode::Lines,
+            replace: None,
+            dry_run: false,
+            fuzzy: None,
+            index: false,
+            follow: false,
+            tui: false,
+            color: ColorChoice::Auto,
+            palette: Palette::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Builds a `Config` from the program's arguments, program name first,
+    /// the environment and the configuration file. A setting on the command
+    /// line wins over `IGNORE_CASE` (unless `0` or `false`), which wins over
+    /// the file: `$MINIGREP_CONFIG`, or else `config.toml` in the `minigrep`
+    /// directory of `$XDG_CONFIG_HOME` or `~/.config`. `--help` and
+    /// `--version` come back as [`ConfigError::Help`] and
+    /// [`ConfigError::Version`].
+    ///
+    /// The query is followed by one or more paths; with `-e PATTERN` or
+    /// `-f FILE` there is no query. Short flags may be grouped, as in `-in`,
+    /// and values may be attached, as in `-j4`, `-C2` or `--context=2`.
+    /// Arguments after `--` are never flags. When the first argument is
+    /// `index`, the others are the directories to index.
+    pub fn build(
+        args: impl Iterator<Item = String>,
+    ) -> Result<Config, ConfigError> {
+        let env = |name: &str| env::var(name).ok();
+        let file = match file_path(env) {
+            Some(path) => match fs::read_to_string(&path) {
+                Ok(text) => Some((path, text)),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+                Err(err) => {
+                    return Err(ConfigError::File {
+                        path,
+                        line: None,
+                        message: err.to_string(),
+                    })
+                }
+            },
+            None => None,
+        };
+        let file = file.as_ref().map(|(path, text)| (path.as_path(), text.as_str()));
+        Config::build_from(args, env, file)
+    }
+
+    /// Like [`build`](Config::build), with the environment given as a lookup
+    /// function and the configuration file, if any, as its path and contents.
+    pub fn build_from(
+        mut args: impl Iterator<Item = String>,
+        env: impl Fn(&str) -> Option<String>,
+        file: Option<(&Path, &str)>,
+    ) -> Result<Config, ConfigError> {
+        args.next();
+        let mut args = args.peekable();
+        let mut config = Config {
+            index: args.next_if(|arg| arg == "index").is_some(),
+            ..Config::default()
+        };
+
+        if let Some((path, text)) = file {
+            let error = |line, message| ConfigError::File {
+                path: path.to_path_buf(),
+                line: Some(line),
+                message,
+            };
+            for (line, name, value) in parse_file(text).map_err(|(line, message)| error(line, message))? {
+                match FLAGS.iter().find(|f| f.long == name.replace('_', "-")) {
+                    Some(flag) if flag.in_file => config
+                        .set(flag, value)
+                        .map_err(|err| error(line, err.to_string()))?,
+                    Some(_) => return Err(error(line, format!("`{name}` cannot be set in the file"))),
+                    None => return Err(error(line, format!("unknown setting `{name}`"))),
+                }
+            }
+        }
+
+        if let Some(value) = env("IGNORE_CASE") {
+            let on = !matches!(value.as_str(), "0" | "false");
+            config.set(long("ignore-case"), Value::Switch(on))?;
+        }
+        if env("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            config.color = ColorChoice::Never;
+        }
+        if let Some(spec) = env("MINIGREP_COLORS") {
+            config.palette = Palette::parse(&spec).map_err(|entry| ConfigError::InvalidValue {
+                flag: "MINIGREP_COLORS".to_string(),
+                value: entry,
+                expected: "entries like `mt=01;31` for mt, fn, ln, bn or se",
+            })?;
+        }
+
+        let mut positional = Vec::new();
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                positional.extend(args.by_ref());
+            } else if let Some(rest) = arg.strip_prefix("--") {
+                let (name, attached) = match rest.split_once('=') {
+                    Some((name, value)) => (name, Some(value.to_string())),
+                    None => (rest, None),
+                };
+                let flag = FLAGS
+                    .iter()
+                    .find(|f| f.long == name)
+                    .ok_or_else(|| ConfigError::UnknownFlag(format!("--{name}")))?;
+                let value = match (flag.value, attached) {
+                    (None, None) => Value::Switch(true),
+                    (None, Some(_)) => return Err(ConfigError::UnexpectedValue(format!("--{name}"))),
+                    (Some(_), Some(value)) => Value::Text(value),
+                    (Some(_), None) => Value::Text(
+                        args.next()
+                            .ok_or_else(|| ConfigError::MissingValue(format!("--{name}")))?,
+                    ),
+                };
+                config.set(flag, value)?;
+            } else if let Some(cluster) = arg.strip_prefix('-').filter(|c| !c.is_empty()) {
+                for (i, c) in cluster.char_indices() {
+                    let flag = FLAGS
+                        .iter()
+                        .find(|f| f.short == Some(c))
+                        .ok_or_else(|| ConfigError::UnknownFlag(format!("-{c}")))?;
+                    if flag.value.is_none() {
+                        config.set(flag, Value::Switch(true))?;
+                        continue;
+                    }
+                    // The rest of the argument is the value, or else the next one.
+                    let rest = &cluster[i + c.len_utf8()..];
+                    let value = if rest.is_empty() {
+                        args.next().ok_or_else(|| ConfigError::MissingValue(format!("-{c}")))?
+                    } else {
+                        rest.to_string()
+                    };
+                    config.set(flag, Value::Text(value))?;
+                    break;
+                }
+            } else {
+                positional.push(arg);
+            }
+        }
+
+        if config.replace.is_some() && config.invert {
+            return Err(ConfigError::Conflict("--replace", "-v"));
+        }
+        if config.fuzzy.is_some() && config.pattern_kind == PatternKind::Regex {
+            return Err(ConfigError::Conflict("--fuzzy", "-E"));
+        }
+        if config.dry_run && config.replace.is_none() {
+            return Err(ConfigError::Requires("--dry-run", "--replace"));
+        }
+        let output = output_flag(config.output);
+        let replace = config.replace.is_some().then_some("--replace");
+        let index = config.index.then_some("index");
+        if config.follow {
+            // Following never gets to the end of the files.
+            let ends = !matches!(config.output, OutputMode::OnlyMatching | OutputMode::Json);
+            let fuzzy = config.fuzzy.is_some().then_some("--fuzzy");
+            conflict("--follow", &[output.filter(|_| ends), replace, fuzzy, index])?;
+        }
+        if config.tui {
+            conflict("--tui", &[output, config.follow.then_some("--follow"), replace, index])?;
+        }
+
+        let mut positional = positional.into_iter();
+        if config.patterns.is_empty() && config.pattern_files.is_empty() && !config.index && !config.tui {
+            config.patterns.push(positional.next().ok_or(ConfigError::MissingQuery)?);
+        }
+        config.paths = positional.map(PathBuf::from).collect();
+        if config.paths.is_empty() {
+            return Err(ConfigError::MissingPath);
+        }
+        Ok(config)
+    }
+
+    /// Applies one flag.
+    fn set(&mut self, flag: &Flag, value: Value) -> Result<(), ConfigError> {
+        let name = match flag.short {
+            Some(c) => format!("-{c}"),
+            None => format!("--{}", flag.long),
+        };
+        let number = |expected| match &value {
+            Value::Text(text) => text.parse::<usize>().map_err(|_| ConfigError::InvalidValue {
+                flag: name.clone(),
+                value: text.clone(),
+                expected,
+            }),
+            Value::Switch(_) => Err(ConfigError::MissingValue(name.clone())),
+        };
+        let text = || match &value {
+            Value::Text(text) => Ok(text.clone()),
+            Value::Switch(_) => Err(ConfigError::MissingValue(name.clone())),
+        };
+        let on = match value {
+            Value::Switch(on) => on,
+            Value::Text(_) => false,
+        };
+        match flag.long {
+            "regexp" => self.patterns.push(text()?),
+            "file" => self.pattern_files.push(PathBuf::from(text()?)),
+            // Turning off -E goes back to the default, and turning off a
+            // default, -F or --case-sensitive, leaves things as they are.
+            "extended-regexp" => self.pattern_kind = if on { PatternKind::Regex } else { PatternKind::Literal },
+            "fixed-strings" if on => self.pattern_kind = PatternKind::Literal,
+            "fuzzy" => self.fuzzy = Some(number("a number of edits")?),
+            "ignore-case" => self.ignore_case = on,
+            "case-sensitive" if on => self.ignore_case = false,
+            "fixed-strings" | "case-sensitive" => {}
+            "invert-match" => self.invert = on,
+            "line-number" => self.line_numbers = on,
+            "byte-offset" => self.byte_offsets = on,
+            "after-context" => self.after_context = number("a number of lines")?,
+            "before-context" => self.before_context = number("a number of lines")?,
+            "context" => {
+                self.before_context = number("a number of lines")?;
+                self.after_context = self.before_context;
+            }
+            "only-matching" => self.output = OutputMode::OnlyMatching,
+            "count" => self.output = OutputMode::Count,
+            "files-with-matches" => self.output = OutputMode::FilesWithMatches,
+            "files-without-match" => self.output = OutputMode::FilesWithoutMatch,
+            "json" => self.output = OutputMode::Json,
+            "text" => self.text = on,
+            "hidden" => self.hidden = on,
+            "decompress" => self.decompress = if on { Decompress::Always } else { Decompress::Auto },
+            "no-decompress" => self.decompress = if on { Decompress::Never } else { Decompress::Auto },
+            "threads" => {
+                self.jobs = number("a positive number of threads")?;
+                if self.jobs == 0 {
+                    return Err(ConfigError::InvalidValue {
+                        flag: name,
+                        value: "0".to_string(),
+                        expected: "a positive number of threads",
+                    });
+                }
+            }
+            "replace" => self.replace = Some(text()?),
+            "dry-run" => self.dry_run = on,
+            "follow" => self.follow = on,
+            "tui" => self.tui = on,
+            "color" => {
+                self.color = match text()?.as_str() {
+                    "auto" => ColorChoice::Auto,
+                    "always" => ColorChoice::Always,
+                    "never" => ColorChoice::Never,
+                    other => {
+                        return Err(ConfigError::InvalidValue {
+                            flag: name,
+                            value: other.to_string(),
+                            expected: "auto, always or never",
+                        })
+                    }
+                }
+            }
+            "help" => return Err(ConfigError::Help),
+            "version" => return Err(ConfigError::Version),
+            other => unreachable!("flag --{other} is not handled"),
+        }
+        Ok(())
+    }
+}
+
+/// The first of the flags given in `others`, as a conflict with `flag`.
+fn conflict(flag: &'static str, others: &[Option<&'static str>]) -> Result<(), ConfigError> {
+    match others.iter().flatten().next() {
+        Some(other) => Err(ConfigError::Conflict(flag, other)),
+        None => Ok(()),
+    }
+}
+
+/// The flag that chooses `output`, if it is not the default.
+fn output_flag(output: OutputMode) -> Option<&'static str> {
+    match output {
+        OutputMode::Lines => None,
+        OutputMode::OnlyMatching => Some("-o"),
+        OutputMode::Count => Some("-c"),
+        OutputMode::FilesWithMatches => Some("-l"),
+        OutputMode::FilesWithoutMatch => Some("-L"),
+        OutputMode::Json => Some("--json"),
+    }
+}
+
+fn long(name: &str) -> &'static Flag {
+    FLAGS.iter().find(|f| f.long == name).unwrap()
+}
+
+/// Where the configuration file is, if anywhere.
+fn file_path(env: impl Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    if let Some(path) = env("MINIGREP_CONFIG") {
+        return (!path.is_empty()).then(|| PathBuf::from(path));
+    }
+    let dir = match env("XDG_CONFIG_HOME").filter(|dir| !dir.is_empty()) {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(env("HOME")?).join(".config"),
+    };
+    Some(dir.join("minigrep").join("config.toml"))
+}
+
+/// A line of the configuration file: its number, the name and the value.
+type Setting = (usize, String, Value);
+
+/// Parses the `name = value` lines of a configuration file: TOML without
+/// tables or arrays. Returns the settings, or the line number and message of
+/// the first error.
+fn parse_file(text: &str) -> Result<Vec<Setting>, (usize, String)> {
+    let mut settings = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let number = i + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with('[') {
+            return Err((number, "tables are not supported".to_string()));
+        }
+        let Some((name, value)) = line.split_once('=') else {
+            return Err((number, "expected `name = value`".to_string()));
+        };
+        let name = name.trim();
+        let name = match name.strip_prefix('"').and_then(|n| n.strip_suffix('"')) {
+            Some(quoted) => quoted,
+            None => name,
+        };
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err((number, format!("invalid name `{name}`")));
+        }
+        let value = parse_value(value.trim()).map_err(|message| (number, message))?;
+        settings.push((number, name.to_string(), value));
+    }
+    Ok(settings)
+}
+
+/// Parses a TOML boolean, integer or string, followed by an optional comment.
+fn parse_value(text: &str) -> Result<Value, String> {
+    let (value, rest) = if let Some(quoted) = text.strip_prefix('"') {
+        let mut value = String::new();
+        let mut chars = quoted.char_indices();
+        let end = loop {
+            match chars.next() {
+                Some((i, '"')) => break i + 1,
+                Some((_, '\\')) => value.push(match chars.next() {
+                    Some((_, 'n')) => '\n',
+                    Some((_, 't')) => '\t',
+                    Some((_, c @ ('"' | '\\'))) => c,
+                    _ => return Err("unsupported escape in string".to_string()),
+                }),
+                Some((_, c)) => value.push(c),
+                None => return Err("unterminated string".to_string()),
+            }
+        };
+        (Value::Text(value), &quoted[end..])
+    } else if let Some(quoted) = text.strip_prefix('\'') {
+        let end = quoted.find('\'').ok_or("unterminated string")?;
+        (Value::Text(quoted[..end].to_string()), &quoted[end + 1..])
+    } else {
+        let end = text.find('#').unwrap_or(text.len());
+        let value = match text[..end].trim() {
+            "true" => Value::Switch(true),
+            "false" => Value::Switch(false),
+            number if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit() || c == '_') => {
+                Value::Text(number.replace('_', ""))
+            }
+            other => return Err(format!("expected true, false, a number or a string, not `{other}`")),
+        };
+        (value, &text[end..])
+    };
+    let rest = rest.trim_start();
+    if !rest.is_empty() && !rest.starts_with('#') {
+        return Err(format!("unexpected `{rest}` after the value"));
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> impl Iterator<Item = String> {
+        list.iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn build(list: &[&str], ignore_case: Option<&str>, file: &str) -> Result<Config, ConfigError> {
+        let env = |name: &str| (name == "IGNORE_CASE").then(|| ignore_case.map(String::from)).flatten();
+        Config::build_from(args(list), env, Some((Path::new("config.toml"), file)))
+    }
+
+    #[test]
+    fn flags() {
+        let config = build(&["minigrep", "-inC2", "--threads=3", "to", "--", "-v", "src"], None, "").unwrap();
+        assert!(config.ignore_case && config.line_numbers);
+        assert_eq!((config.before_context, config.after_context), (2, 2));
+        assert_eq!(config.jobs, 3);
+        assert_eq!(config.patterns, ["to"]);
+        assert_eq!(config.paths, [PathBuf::from("-v"), PathBuf::from("src")]);
+
+        let config = build(&["minigrep", "--ignore-case", "-e", "a", "--regexp", "b", "--case-sensitive", "src"], None, "").unwrap();
+        assert!(!config.ignore_case);
+        assert_eq!(config.patterns, ["a", "b"]);
+
+        assert_eq!(build(&["minigrep", "-x"], None, "").err(), Some(ConfigError::UnknownFlag("-x".to_string())));
+        assert_eq!(
+            build(&["minigrep", "--hidden=1", "a", "b"], None, "").err(),
+            Some(ConfigError::UnexpectedValue("--hidden".to_string()))
+        );
+        assert_eq!(
+            build(&["minigrep", "a", "b", "--context"], None, "").unwrap_err().to_string(),
+            "--context needs a value"
+        );
+        assert_eq!(
+            build(&["minigrep", "-j0", "a", "b"], None, "").unwrap_err().to_string(),
+            "-j needs a positive number of threads, not `0`"
+        );
+        assert_eq!(build(&["minigrep", "a", "--help"], None, "").err(), Some(ConfigError::Help));
+        assert_eq!(build(&["minigrep", "-V"], None, "").err(), Some(ConfigError::Version));
+    }
+
+    #[test]
+    fn precedence() {
+        let file = "ignore-case = true\nhidden = true\nthreads = 2\n";
+        let config = build(&["minigrep", "a", "b"], None, file).unwrap();
+        assert!(config.ignore_case && config.hidden);
+        assert_eq!(config.jobs, 2);
+
+        // The environment wins over the file, and the command line over both.
+        let config = build(&["minigrep", "a", "b"], Some("0"), file).unwrap();
+        assert!(!config.ignore_case);
+        let config = build(&["minigrep", "-j", "5", "a", "b"], Some("1"), "ignore_case = false\nthreads = 2").unwrap();
+        assert!(config.ignore_case);
+        assert_eq!(config.jobs, 5);
+        let config = build(&["minigrep", "--case-sensitive", "a", "b"], Some("1"), file).unwrap();
+        assert!(!config.ignore_case);
+    }
+
+    #[test]
+    fn colors() {
+        let build = |list: &[&str], vars: &[(&str, &str)], file| {
+            let env = |name: &str| vars.iter().find(|(n, _)| *n == name).map(|(_, v)| v.to_string());
+            Config::build_from(args(list), env, Some((Path::new("config.toml"), file)))
+        };
+        let config = build(&["minigrep", "a", "b"], &[], "").unwrap();
+        assert_eq!((config.color, config.palette), (ColorChoice::Auto, Palette::default()));
+
+        // NO_COLOR wins over the file, and the command line over it.
+        let config = build(&["minigrep", "a", "b"], &[("NO_COLOR", "1")], "color = \"always\"").unwrap();
+        assert_eq!(config.color, ColorChoice::Never);
+        let config = build(&["minigrep", "a", "b"], &[("NO_COLOR", "")], "color = \"always\"").unwrap();
+        assert_eq!(config.color, ColorChoice::Always);
+        let config = build(&["minigrep", "--color=always", "a", "b"], &[("NO_COLOR", "1")], "").unwrap();
+        assert_eq!(config.color, ColorChoice::Always);
+
+        let config = build(&["minigrep", "a", "b"], &[("MINIGREP_COLORS", "mt=4:ln=")], "").unwrap();
+        assert_eq!((config.palette.matched.as_str(), config.palette.line_number.as_str()), ("4", ""));
+        assert_eq!(
+            build(&["minigrep", "--color", "yes", "a", "b"], &[], "").unwrap_err().to_string(),
+            "--color needs auto, always or never, not `yes`"
+        );
+        assert_eq!(
+            build(&["minigrep", "a", "b"], &[("MINIGREP_COLORS", "mt=1:mn=2")], "").unwrap_err().to_string(),
+            "MINIGREP_COLORS needs entries like `mt=01;31` for mt, fn, ln, bn or se, not `mn=2`"
+        );
+    }
+
+    #[test]
+    fn config_files() {
+        let file = "# comment\n\n\"context\" = 3 # lines\nextended-regexp = true\ntext = false\n";
+        let config = build(&["minigrep", "a", "b"], None, file).unwrap();
+        assert_eq!(config.after_context, 3);
+        assert_eq!(config.pattern_kind, PatternKind::Regex);
+        let config = build(&["minigrep", "a", "b"], None, "extended-regexp = true\nfixed-strings = false\n").unwrap();
+        assert_eq!(config.pattern_kind, PatternKind::Regex);
+        let config = build(&["minigrep", "a", "b"], None, "fixed-strings = false\ncase-sensitive = false\n").unwrap();
+        assert_eq!((config.pattern_kind, config.ignore_case), (PatternKind::Literal, false));
+
+        let error = |file| build(&["minigrep", "a", "b"], None, file).unwrap_err().to_string();
+        assert_eq!(error("\n[section]"), "config.toml:2: tables are not supported");
+        assert_eq!(error("hiden = true"), "config.toml:1: unknown setting `hiden`");
+        assert_eq!(error("replace = \"x\""), "config.toml:1: `replace` cannot be set in the file");
+        assert_eq!(error("threads = \"many\""), "config.toml:1: -j needs a positive number of threads, not `many`");
+        assert_eq!(error("hidden = yes"), "config.toml:1: expected true, false, a number or a string, not `yes`");
+        assert_eq!(error("context = \"2"), "config.toml:1: unterminated string");
+        assert_eq!(error("context = 2 3"), "config.toml:1: expected true, false, a number or a string, not `2 3`");
+    }
+
+    #[test]
+    fn config_file_location() {
+        let env = |vars: &'static [(&str, &str)]| {
+            move |name: &str| vars.iter().find(|(n, _)| *n == name).map(|(_, v)| v.to_string())
+        };
+        assert_eq!(
+            file_path(env(&[("HOME", "/home/ferris")])),
+            Some(PathBuf::from("/home/ferris/.config/minigrep/config.toml"))
+        );
+        assert_eq!(
+            file_path(env(&[("HOME", "/home/ferris"), ("XDG_CONFIG_HOME", "/xdg")])),
+            Some(PathBuf::from("/xdg/minigrep/config.toml"))
+        );
+        assert_eq!(file_path(env(&[("HOME", "/h"), ("MINIGREP_CONFIG", "")])), None);
+        assert_eq!(file_path(env(&[])), None);
+    }
+}
@@ -0,0 +1,356 @@
+//! Following files as lines are added to them, for `--follow`.
+//!
+//! Each file is read to its end when following starts, to count its lines,
+//! and every line added after that is searched just as [`run`](crate::run)
+//! would search it. A line still being written is held back until its line
+//! ending arrives.
+//!
+//! A file that gets shorter was truncated, and is followed again from its
+//! start. A path that comes to name another file, going by its device and
+//! inode, was rotated: the rest of the old file is searched, then the new
+//! one from its start. Both are reported to the sink as errors, which by
+//! default go to stderr like `tail -f` says them.
+//!
+//! On Linux, inotify says when something changes in the directories of the
+//! files, with a look every second anyway in case a rotated file was moved
+//! elsewhere and is still being written. Elsewhere, or when inotify cannot
+//! be had, the files are looked at four times a second.
+
+use std::error::Error;
+use std::fs::{self, File, Metadata};
+use std::io::{self, BufRead, BufReader, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use crate::sink::OutputSink;
+use crate::{metadata, Config, Scan, Searcher};
+
+/// How long to wait between looks at the files without inotify.
+const POLL: Duration = Duration::from_millis(250);
+
+/// Searches the lines added to the files in `config.paths` as they come, and
+/// only returns with an error.
+pub(crate) fn run(config: &Config, searcher: &Searcher, sink: &mut dyn OutputSink) -> Result<(), Box<dyn Error>> {
+    let mut files = Vec::new();
+    for path in &config.paths {
+        if metadata(path)?.is_dir() {
+            return Err(format!("{}: is a directory, --follow needs files", path.display()).into());
+        }
+        files.push(Followed::open(path, searcher).map_err(|err| format!("{}: {err}", path.display()))?);
+    }
+    for file in &files {
+        sink.begin(&file.path)?;
+    }
+    let mut watcher = Watcher::new(&config.paths);
+    loop {
+        watcher.wait()?;
+        for file in &mut files {
+            if let Err(err) = file.check(searcher, sink) {
+                sink.error(&format!("{}: {err}", file.path.display()))?;
+            }
+        }
+    }
+}
+
+/// A file being followed.
+struct Followed {
+    path: PathBuf,
+    reader: BufReader<File>,
+    /// The device and inode of the file being read.
+    id: (u64, u64),
+    scan: Scan,
+    /// What has been read of the line being written.
+    partial: Vec<u8>,
+}
+
+impl Followed {
+    /// Opens the file at `path` to follow it from its end.
+    fn open(path: &Path, searcher: &Searcher) -> io::Result<Followed> {
+        let file = File::open(path)?;
+        let mut followed = Followed {
+            path: path.to_path_buf(),
+            id: id(&file.metadata()?),
+            reader: BufReader::with_capacity(64 * 1024, file),
+            scan: searcher.scan(false),
+            partial: Vec::new(),
+        };
+        loop {
+            followed.partial.clear();
+            let read = followed.reader.read_until(b'\n', &mut followed.partial)?;
+            if read == 0 || !followed.partial.ends_with(b"\n") {
+                return Ok(followed);
+            }
+            followed.scan.number += 1;
+            followed.scan.offset += read;
+        }
+    }
+
+    /// Searches the lines added since the last check, then starts over if the
+    /// file was truncated, or moves on to the new file if it was replaced.
+    fn check(&mut self, searcher: &Searcher, sink: &mut dyn OutputSink) -> io::Result<()> {
+        self.read(searcher, sink)?;
+        let metadata = match fs::metadata(&self.path) {
+            Ok(metadata) => metadata,
+            // Moved away, and the new file is not there yet.
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        if id(&metadata) != self.id {
+            // The old file is done with, line ending or not.
+            if !self.partial.is_empty() {
+                self.search(searcher, sink)?;
+            }
+            let file = File::open(&self.path)?;
+            sink.error(&format!("{}: replaced, following the new file", self.path.display()))?;
+            self.id = id(&file.metadata()?);
+            self.reader = BufReader::with_capacity(64 * 1024, file);
+        } else if metadata.len() < (self.scan.offset + self.partial.len()) as u64 {
+            sink.error(&format!("{}: truncated, following from the start", self.path.display()))?;
+            self.reader.seek(SeekFrom::Start(0))?;
+        } else {
+            return Ok(());
+        }
+        self.scan = searcher.scan(false);
+        self.partial.clear();
+        sink.begin(&self.path)?;
+        self.read(searcher, sink)
+    }
+
+    /// Searches the lines added to the file being read.
+    fn read(&mut self, searcher: &Searcher, sink: &mut dyn OutputSink) -> io::Result<()> {
+        loop {
+            let read = self.reader.read_until(b'\n', &mut self.partial)?;
+            if read == 0 || !self.partial.ends_with(b"\n") {
+                return Ok(());
+            }
+            self.search(searcher, sink)?;
+        }
+    }
+
+    /// Searches the line in `partial`, and empties it.
+    fn search(&mut self, searcher: &Searcher, sink: &mut dyn OutputSink) -> io::Result<()> {
+        let binary = self.scan.summary.binary;
+        let path = &self.path;
+        searcher.search_line(&mut self.scan, &self.partial, &mut |line| sink.line(path, &line))?;
+        if self.scan.summary.binary && !binary {
+            sink.binary(path)?;
+        }
+        self.partial.clear();
+        Ok(())
+    }
+}
+
+/// What tells one file from another.
+#[cfg(unix)]
+fn id(metadata: &Metadata) -> (u64, u64) {
+    use std::os::unix::fs::MetadataExt;
+    (metadata.dev(), metadata.ino())
+}
+
+/// Without inodes to go by, rotation goes unnoticed, unless the new file is
+/// shorter.
+#[cfg(not(unix))]
+fn id(_metadata: &Metadata) -> (u64, u64) {
+    (0, 0)
+}
+
+/// What says when to look at the files again.
+enum Watcher {
+    #[cfg(target_os = "linux")]
+    Inotify(inotify::Inotify),
+    Poll,
+}
+
+impl Watcher {
+    fn new(paths: &[PathBuf]) -> Watcher {
+        #[cfg(target_os = "linux")]
+        {
+            let dirs: Vec<&Path> = paths
+                .iter()
+                .map(|path| path.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new(".")))
+                .collect();
+            if let Ok(inotify) = inotify::Inotify::watch(&dirs) {
+                return Watcher::Inotify(inotify);
+            }
+        }
+        let _ = paths;
+        Watcher::Poll
+    }
+
+    /// Waits until the files may have changed.
+    fn wait(&mut self) -> io::Result<()> {
+        match self {
+            #[cfg(target_os = "linux")]
+            Watcher::Inotify(inotify) => inotify.wait(Duration::from_secs(1)),
+            Watcher::Poll => {
+                thread::sleep(POLL);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Just enough of inotify(7) and poll(2), declared by hand as minigrep has no
+/// dependencies.
+#[cfg(target_os = "linux")]
+mod inotify {
+    use std::ffi::{c_char, c_int, c_short, c_ulong, CString};
+    use std::fs::File;
+    use std::io::{self, Read};
+    use std::os::fd::{AsRawFd, FromRawFd};
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+    use std::time::Duration;
+
+    const IN_NONBLOCK: c_int = 0o4000;
+    const IN_CLOEXEC: c_int = 0o2000000;
+    const IN_MODIFY: u32 = 0x2;
+    const IN_MOVED_FROM: u32 = 0x40;
+    const IN_MOVED_TO: u32 = 0x80;
+    const IN_CREATE: u32 = 0x100;
+    const IN_DELETE: u32 = 0x200;
+    const POLLIN: c_short = 1;
+
+    #[repr(C)]
+    struct PollFd {
+        fd: c_int,
+        events: c_short,
+        revents: c_short,
+    }
+
+    extern "C" {
+        fn inotify_init1(flags: c_int) -> c_int;
+        fn inotify_add_watch(fd: c_int, path: *const c_char, mask: u32) -> c_int;
+        fn poll(fds: *mut PollFd, nfds: c_ulong, timeout: c_int) -> c_int;
+    }
+
+    /// Watches directories for files being written, created, moved and
+    /// deleted.
+    pub(super) struct Inotify {
+        file: File,
+    }
+
+    impl Inotify {
+        pub(super) fn watch(dirs: &[&Path]) -> io::Result<Inotify> {
+            // SAFETY: a plain system call; the descriptor it returns is then
+            // owned by the `File` alone.
+            let fd = unsafe { inotify_init1(IN_NONBLOCK | IN_CLOEXEC) };
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let file = unsafe { File::from_raw_fd(fd) };
+            let mask = IN_MODIFY | IN_MOVED_FROM | IN_MOVED_TO | IN_CREATE | IN_DELETE;
+            for dir in dirs {
+                let dir = CString::new(dir.as_os_str().as_bytes())?;
+                // SAFETY: `dir` is a NUL-terminated string that outlives the
+                // call.
+                if unsafe { inotify_add_watch(fd, dir.as_ptr(), mask) } < 0 {
+                    return Err(io::Error::last_os_error());
+                }
+            }
+            Ok(Inotify { file })
+        }
+
+        /// Waits until something changes in the directories, or `timeout`
+        /// passes, and forgets what it was.
+        pub(super) fn wait(&mut self, timeout: Duration) -> io::Result<()> {
+            let mut fds = PollFd {
+                fd: self.file.as_raw_fd(),
+                events: POLLIN,
+                revents: 0,
+            };
+            // SAFETY: `fds` is one valid `pollfd` for the length of the call.
+            if unsafe { poll(&mut fds, 1, timeout.as_millis() as c_int) } < 0 {
+                let err = io::Error::last_os_error();
+                if err.kind() != io::ErrorKind::Interrupted {
+                    return Err(err);
+                }
+            }
+            // The events only say to look; the files themselves say what
+            // happened.
+            let mut events = [0; 4096];
+            loop {
+                match self.file.read(&mut events) {
+                    Ok(0) => return Ok(()),
+                    Ok(_) => {}
+                    Err(err) if err.kind() == io::ErrorKind::WouldBlock => return Ok(()),
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sink::Line;
+    use std::io::Write;
+
+    /// Records the lines and messages it is handed.
+    #[derive(Default)]
+    struct Recorder(Vec<String>);
+
+    impl OutputSink for Recorder {
+        fn line(&mut self, _path: &Path, line: &Line) -> io::Result<()> {
+            self.0.push(format!("{}:{}:{}", line.number, line.offset, line.text));
+            Ok(())
+        }
+
+        fn error(&mut self, message: &str) -> io::Result<()> {
+            self.0.push(message.to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn appends_truncation_and_rotation() {
+        let dir = std::env::temp_dir().join(format!("minigrep-{}-follow", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("log");
+        fs::write(&path, "old frog\nhalf a ").unwrap();
+        let config = Config {
+            patterns: vec!["frog".to_string()],
+            ..Config::default()
+        };
+        let searcher = Searcher::new(&config, &config.patterns).unwrap();
+        let mut followed = Followed::open(&path, &searcher).unwrap();
+        let mut sink = Recorder::default();
+        let append = |text: &str| File::options().append(true).open(&path).unwrap().write_all(text.as_bytes()).unwrap();
+
+        // Lines count on from those already there, and wait for their end.
+        append("frog\na toad\nanother fr");
+        followed.check(&searcher, &mut sink).unwrap();
+        assert_eq!(sink.0, ["2:9:half a frog"]);
+        append("og\n");
+        followed.check(&searcher, &mut sink).unwrap();
+        assert_eq!(sink.0[1..], ["4:28:another frog"]);
+
+        sink.0.clear();
+        fs::write(&path, "frog\n").unwrap();
+        followed.check(&searcher, &mut sink).unwrap();
+        assert_eq!(sink.0, [format!("{}: truncated, following from the start", path.display()), "1:0:frog".into()]);
+
+        // Lines added to the old file before the new one shows up are not
+        // lost, nor is one without its line ending.
+        sink.0.clear();
+        fs::rename(&path, dir.join("log.1")).unwrap();
+        followed.check(&searcher, &mut sink).unwrap();
+        let mut old = File::options().append(true).open(dir.join("log.1")).unwrap();
+        old.write_all(b"last frog\nunfinished frog").unwrap();
+        fs::write(&path, "new frog\n").unwrap();
+        followed.check(&searcher, &mut sink).unwrap();
+        assert_eq!(
+            sink.0,
+            [
+                "2:5:last frog".to_string(),
+                "3:15:unfinished frog".to_string(),
+                format!("{}: replaced, following the new file", path.display()),
+                "1:0:new frog".to_string(),
+            ]
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
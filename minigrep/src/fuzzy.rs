@@ -0,0 +1,332 @@
+//! Approximate matching, for `minigrep --fuzzy N`.
+//!
+//! A [`Fuzzy`] pattern matches wherever a part of the text is within N edits
+//! of it, an edit being a character inserted, deleted or replaced: their
+//! Levenshtein distance. The text is scanned with the bitap algorithm of Wu
+//! and Manber, which keeps, for each number of edits up to N, a bit per
+//! pattern character telling whether the pattern up to there matches the
+//! end of the text read so far with that many edits. Patterns longer than
+//! 64 characters, whose bits do not fit in a word, are scanned with the
+//! dynamic programming of Sellers instead.
+//!
+//! Case-insensitive patterns compare the case folding of characters, as
+//! [`aho_corasick`](crate::aho_corasick) does, but one character at a time:
+//! `ß` is an edit away from `s`, and two from `ss`.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::aho_corasick::{fold, folded};
+use crate::index::Required;
+
+/// Longest pattern, in characters, scanned with bitap.
+const MAX_BITAP: usize = 64;
+
+/// A pattern matched approximately.
+#[derive(Debug, Clone)]
+pub struct Fuzzy {
+    /// The key of each character of the pattern.
+    pattern: Vec<u32>,
+    max_edits: usize,
+    fold: bool,
+    /// For each key in the pattern, a bit for each position where it is.
+    masks: HashMap<u32, u64>,
+    required: Required,
+}
+
+/// Where a [`Fuzzy`] pattern matched, and how many edits away the text is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    pub bytes: Range<usize>,
+    pub distance: usize,
+}
+
+/// The state of a scan after each character.
+enum Scan {
+    /// For each number of edits, the bitap bits.
+    Bitap(Vec<u64>),
+    /// The edits between each prefix of the pattern and the best part of the
+    /// text ending here.
+    Sellers(Vec<usize>),
+}
+
+impl Fuzzy {
+    /// Builds the matcher for `pattern` with up to `max_edits` edits, or
+    /// `None` if that is enough to delete the whole pattern, so that
+    /// everything would match.
+    pub fn new(pattern: &str, max_edits: usize, case_insensitive: bool) -> Option<Fuzzy> {
+        let keys: Vec<u32> = pattern.chars().map(|c| key(c, case_insensitive)).collect();
+        if max_edits >= keys.len() {
+            return None;
+        }
+        let mut masks = HashMap::new();
+        if keys.len() <= MAX_BITAP {
+            for (i, &key) in keys.iter().enumerate() {
+                *masks.entry(key).or_insert(0) |= 1 << i;
+            }
+        }
+        Some(Fuzzy {
+            required: required(pattern, max_edits),
+            pattern: keys,
+            max_edits,
+            fold: case_insensitive,
+            masks,
+        })
+    }
+
+    /// What every match contains, for skipping files with the
+    /// [index](crate::index).
+    pub(crate) fn required(&self) -> &Required {
+        &self.required
+    }
+
+    /// The fewest edits that turn a part of `text` into the pattern, if it
+    /// is within the limit.
+    pub fn distance(&self, text: &str) -> Option<usize> {
+        let mut scan = self.start();
+        let mut best = None;
+        for c in text.chars() {
+            if let Some(distance) = self.step(&mut scan, key(c, self.fold)) {
+                if best.is_none_or(|best| distance < best) {
+                    best = Some(distance);
+                }
+                if distance == 0 {
+                    break;
+                }
+            }
+        }
+        best
+    }
+
+    /// Returns the matches in `text`, without overlaps. Where matches ending
+    /// one after the other overlap, the closest one, then the first, wins, and
+    /// it starts as early as it can for that distance.
+    pub fn find_all(&self, text: &str) -> Vec<FuzzyMatch> {
+        let chars: Vec<(usize, u32)> = text.char_indices().map(|(i, c)| (i, key(c, self.fold))).collect();
+        let byte = |i: usize| chars.get(i).map_or(text.len(), |&(offset, _)| offset);
+        let mut found = Vec::new();
+        let mut from = 0;
+        let mut scan = self.start();
+        // The end and distance of the best match in the current run of
+        // positions where a match ends.
+        let mut best: Option<(usize, usize)> = None;
+        let mut i = 0;
+        while i <= chars.len() {
+            let distance = match chars.get(i) {
+                Some(&(_, key)) => self.step(&mut scan, key),
+                None => None,
+            };
+            i += 1;
+            if let Some(distance) = distance {
+                if best.is_none_or(|(_, best)| distance < best) {
+                    best = Some((i, distance));
+                }
+                if distance > 0 {
+                    continue;
+                }
+            }
+            let Some((end, distance)) = best.take() else { continue };
+            let start = self.start_of(&chars[from..end], distance) + from;
+            found.push(FuzzyMatch {
+                bytes: byte(start)..byte(end),
+                distance,
+            });
+            from = end;
+            i = end;
+            scan = self.start();
+        }
+        found
+    }
+
+    fn start(&self) -> Scan {
+        if self.pattern.len() <= MAX_BITAP {
+            // With d edits, the first d characters can be deleted.
+            Scan::Bitap((0..=self.max_edits).map(|d| (1 << d) - 1).collect())
+        } else {
+            Scan::Sellers((0..=self.pattern.len()).collect())
+        }
+    }
+
+    /// Reads the character with `key`, returning the fewest edits of a match
+    /// that ends with it, if within the limit.
+    fn step(&self, scan: &mut Scan, key: u32) -> Option<usize> {
+        let len = self.pattern.len();
+        match scan {
+            Scan::Bitap(bits) => {
+                let mask = self.masks.get(&key).copied().unwrap_or(0);
+                let mut fewer = bits[0];
+                bits[0] = ((bits[0] << 1) | 1) & mask;
+                for d in 1..bits.len() {
+                    let old = bits[d];
+                    // A match, an inserted character, a replaced one or a
+                    // deleted one.
+                    bits[d] = (((old << 1) | 1) & mask) | fewer | ((fewer | bits[d - 1]) << 1) | 1;
+                    fewer = old;
+                }
+                bits.iter().position(|bits| bits & (1 << (len - 1)) != 0)
+            }
+            Scan::Sellers(column) => {
+                // `column[0]` stays 0: a match can start anywhere.
+                let mut diagonal = column[0];
+                for j in 1..=len {
+                    let cost = usize::from(self.pattern[j - 1] != key);
+                    let edits = (diagonal + cost).min(column[j] + 1).min(column[j - 1] + 1);
+                    diagonal = column[j];
+                    column[j] = edits;
+                }
+                Some(column[len]).filter(|&edits| edits <= self.max_edits)
+            }
+        }
+    }
+
+    /// The earliest start, in `chars`, of a match `distance` edits away that
+    /// ends at the end of `chars`, found by matching backwards.
+    fn start_of(&self, chars: &[(usize, u32)], distance: usize) -> usize {
+        let len = self.pattern.len();
+        let mut column: Vec<usize> = (0..=len).collect();
+        let mut start = chars.len();
+        for (s, &(_, key)) in chars.iter().enumerate().rev() {
+            let mut diagonal = column[0];
+            column[0] += 1;
+            for j in 1..=len {
+                let cost = usize::from(self.pattern[len - j] != key);
+                let edits = (diagonal + cost).min(column[j] + 1).min(column[j - 1] + 1);
+                diagonal = column[j];
+                column[j] = edits;
+            }
+            if column[len] == distance {
+                start = s;
+            }
+            if column.iter().all(|&edits| edits > distance) {
+                break;
+            }
+        }
+        start
+    }
+}
+
+/// What `c` is compared as: itself, or its case folding. A character that
+/// folds to several gets a key of its own, beyond the chars.
+fn key(c: char, fold: bool) -> u32 {
+    if !fold {
+        return c as u32;
+    }
+    if c.is_ascii() {
+        return c.to_ascii_lowercase() as u32;
+    }
+    let mut chars = folded(c);
+    match (chars.next(), chars.next()) {
+        (Some(f), None) => f as u32,
+        _ => c as u32 + 0x11_0000,
+    }
+}
+
+/// With `max_edits` edits spread over `max_edits + 1` pieces of the pattern,
+/// one of the pieces is left as it is.
+fn required(pattern: &str, max_edits: usize) -> Required {
+    let chars: Vec<char> = pattern.chars().collect();
+    let pieces = max_edits + 1;
+    Required::any_of((0..pieces).map(|i| {
+        let piece: String = chars[i * chars.len() / pieces..(i + 1) * chars.len() / pieces].iter().collect();
+        Required::contains(fold(&piece))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The fewest edits between `pattern` and any part of `text`, by brute
+    /// force.
+    fn naive(pattern: &[char], text: &[char]) -> usize {
+        let edit = |a: &[char], b: &[char]| {
+            let mut row: Vec<usize> = (0..=b.len()).collect();
+            for i in 1..=a.len() {
+                let mut diagonal = row[0];
+                row[0] = i;
+                for j in 1..=b.len() {
+                    let edits = (diagonal + usize::from(a[i - 1] != b[j - 1])).min(row[j] + 1).min(row[j - 1] + 1);
+                    diagonal = row[j];
+                    row[j] = edits;
+                }
+            }
+            row[b.len()]
+        };
+        (0..=text.len())
+            .flat_map(|s| (s..=text.len()).map(move |e| (s, e)))
+            .map(|(s, e)| edit(pattern, &text[s..e]))
+            .min()
+            .unwrap()
+    }
+
+    fn find(pattern: &str, max_edits: usize, text: &str) -> Vec<(String, usize)> {
+        let fuzzy = Fuzzy::new(pattern, max_edits, false).unwrap();
+        let found = fuzzy.find_all(text);
+        let distance = found.iter().map(|m| m.distance).min();
+        assert_eq!(fuzzy.distance(text), distance);
+        found.into_iter().map(|m| (text[m.bytes].to_string(), m.distance)).collect()
+    }
+
+    #[test]
+    fn matches_within_the_edits() {
+        assert_eq!(find("frog", 1, "a frog"), [("frog".to_string(), 0)]);
+        assert_eq!(find("frog", 1, "a frig and a frg"), [("frig".to_string(), 1), ("frg".to_string(), 1)]);
+        // Swapping two letters is two edits.
+        assert_eq!(find("frog", 1, "a forg"), []);
+        assert_eq!(find("frog", 1, "a fog"), [("fog".to_string(), 1)]);
+        assert_eq!(find("frog", 1, "a bog"), []);
+        assert_eq!(find("frog", 2, "a bog"), [(" bog".to_string(), 2)]);
+        assert_eq!(find("frog", 0, "frogfrog"), [("frog".to_string(), 0), ("frog".to_string(), 0)]);
+        assert_eq!(find("connection", 2, "conection refused"), [("conection".to_string(), 1)]);
+        assert!(Fuzzy::new("ab", 2, false).is_none());
+
+        let folded = Fuzzy::new("ÉTÉ", 1, true).unwrap();
+        assert_eq!(folded.distance("l'été"), Some(0));
+        assert_eq!(folded.distance("l'eté"), Some(1));
+    }
+
+    #[test]
+    fn bitap_and_sellers_agree_with_brute_force() {
+        // Every string over a small alphabet, against a few patterns.
+        let alphabet = ['a', 'b', 'c'];
+        let mut texts = vec![String::new()];
+        for _ in 0..6 {
+            let longer: Vec<String> = texts
+                .iter()
+                .filter(|t| t.len() == texts.last().unwrap().len())
+                .flat_map(|t| alphabet.iter().map(move |c| format!("{t}{c}")))
+                .collect();
+            texts.extend(longer);
+        }
+        for (pattern, max_edits) in [("abc", 1), ("abca", 2), ("bb", 1), ("cabac", 2)] {
+            let fuzzy = Fuzzy::new(pattern, max_edits, false).unwrap();
+            let pattern: Vec<char> = pattern.chars().collect();
+            for text in &texts {
+                let chars: Vec<char> = text.chars().collect();
+                let expected = Some(naive(&pattern, &chars)).filter(|&d| d <= max_edits);
+                assert_eq!(fuzzy.distance(text), expected, "{pattern:?} in {text:?}");
+                let mut scan = Scan::Sellers((0..=pattern.len()).collect());
+                let by_sellers = text.chars().filter_map(|c| fuzzy.step(&mut scan, c as u32)).min();
+                assert_eq!(by_sellers, expected, "{pattern:?} in {text:?}");
+                for m in fuzzy.find_all(text) {
+                    let found: Vec<char> = text[m.bytes].chars().collect();
+                    assert_eq!(naive(&pattern, &found).max(m.distance), m.distance);
+                }
+            }
+        }
+
+        let long = "a".repeat(70);
+        let fuzzy = Fuzzy::new(&long, 3, false).unwrap();
+        assert!(fuzzy.masks.is_empty());
+        assert_eq!(fuzzy.distance(&format!("x{}b{}x", "a".repeat(30), "a".repeat(39))), Some(1));
+        assert_eq!(fuzzy.distance(&"a".repeat(66)), None);
+    }
+
+    #[test]
+    fn required_pieces() {
+        let contains = |text: &str| Required::Contains(text.to_string());
+        assert_eq!(required("connection", 1), Required::AnyOf(vec![contains("conne"), contains("ction")]));
+        assert_eq!(required("FrogSong", 1), Required::AnyOf(vec![contains("frog"), contains("song")]));
+        assert_eq!(required("frogs", 2), Required::Anything);
+    }
+}
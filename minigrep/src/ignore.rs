@@ -0,0 +1,242 @@
+//! `.gitignore` and `.ignore` files.
+//!
+//! Patterns follow gitignore: blank lines and `#` comments are skipped, `!`
+//! re-includes what an earlier pattern excluded, a trailing `/` matches
+//! directories only, and a `/` at the start or in the middle anchors the
+//! pattern to the directory holding the file; otherwise it matches a name at
+//! any depth. `*` and `?` match within one path component, `[a-z]` and `[!a-z]`
+//! match one character of a class, and `**` matches any number of components.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The files read from each directory, later ones taking precedence.
+pub const FILE_NAMES: [&str; 2] = [".gitignore", ".ignore"];
+
+/// The rules of the ignore files in one directory.
+#[derive(Debug, Default)]
+pub struct Ignore {
+    root: PathBuf,
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug)]
+struct Rule {
+    segments: Vec<Vec<char>>,
+    negated: bool,
+    dir_only: bool,
+    anchored: bool,
+}
+
+impl Ignore {
+    /// Parses ignore-file `text` whose patterns are relative to `root`.
+    pub fn parse(root: &Path, text: &str) -> Ignore {
+        let mut ignore = Ignore {
+            root: root.to_path_buf(),
+            rules: Vec::new(),
+        };
+        ignore.add(text);
+        ignore
+    }
+
+    /// Reads the ignore files in `dir`. Returns `None` if it has none.
+    pub fn read(dir: &Path) -> io::Result<Option<Ignore>> {
+        let mut ignore = Ignore::parse(dir, "");
+        for name in FILE_NAMES {
+            match fs::read_to_string(dir.join(name)) {
+                Ok(text) => ignore.add(&text),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok((!ignore.rules.is_empty()).then_some(ignore))
+    }
+
+    fn add(&mut self, text: &str) {
+        self.rules.extend(text.lines().filter_map(Rule::parse));
+    }
+
+    /// Returns `Some(true)` if the last rule matching `path` ignores it,
+    /// `Some(false)` if it re-includes it, and `None` if no rule matches.
+    pub fn matched(&self, path: &Path, is_dir: bool) -> Option<bool> {
+        let relative = path.strip_prefix(&self.root).ok()?;
+        let components: Vec<Vec<char>> = relative
+            .iter()
+            .map(|c| c.to_string_lossy().chars().collect())
+            .collect();
+        if components.is_empty() {
+            return None;
+        }
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matches(&components, is_dir))
+            .map(|rule| !rule.negated)
+    }
+}
+
+impl Rule {
+    fn parse(line: &str) -> Option<Rule> {
+        let mut line = line.trim_end_matches('\r');
+        // Trailing spaces are dropped unless the last one is escaped.
+        let trimmed = line.trim_end_matches(' ');
+        if trimmed.len() < line.len() && trimmed.ends_with('\\') {
+            line = &line[..trimmed.len() + 1];
+        } else {
+            line = trimmed;
+        }
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let negated = line.starts_with('!');
+        // Either the `!`, or the `\` escaping a leading `!` or `#`.
+        if negated || line.starts_with("\\!") || line.starts_with("\\#") {
+            line = &line[1..];
+        }
+        let dir_only = line.ends_with('/');
+        let line = line.trim_end_matches('/');
+        let anchored = line.contains('/');
+        let line = line.strip_prefix('/').unwrap_or(line);
+        if line.is_empty() {
+            return None;
+        }
+        Some(Rule {
+            segments: line.split('/').map(|s| s.chars().collect()).collect(),
+            negated,
+            dir_only,
+            anchored,
+        })
+    }
+
+    fn matches(&self, components: &[Vec<char>], is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+        if self.anchored {
+            match_components(&self.segments, components)
+        } else {
+            glob(&self.segments[0], components.last().unwrap())
+        }
+    }
+}
+
+/// Matches pattern components against path components; `**` stands for any
+/// number of them, or at least one at the end of the pattern.
+fn match_components(pattern: &[Vec<char>], path: &[Vec<char>]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((segment, [])) if segment[..] == ['*', '*'] => !path.is_empty(),
+        Some((segment, rest)) if segment[..] == ['*', '*'] => {
+            (0..=path.len()).any(|skip| match_components(rest, &path[skip..]))
+        }
+        Some((segment, rest)) => match path.split_first() {
+            Some((name, path)) => glob(segment, name) && match_components(rest, path),
+            None => false,
+        },
+    }
+}
+
+/// Matches one path component against a pattern with `*`, `?`, classes and
+/// `\` escapes.
+fn glob(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|skip| glob(rest, &name[skip..])),
+        Some(('?', rest)) => !name.is_empty() && glob(rest, &name[1..]),
+        Some(('[', _)) if class(pattern).is_some() => {
+            let (len, matches) = class(pattern).unwrap();
+            match name.split_first() {
+                Some((&c, name)) => matches(c) && glob(&pattern[len..], name),
+                None => false,
+            }
+        }
+        Some(('\\', [escaped, rest @ ..])) => name.first() == Some(escaped) && glob(rest, &name[1..]),
+        Some((c, rest)) => name.first() == Some(c) && glob(rest, &name[1..]),
+    }
+}
+
+/// Parses the class at the start of `pattern`. Returns its length and a test
+/// for its characters, or `None` if the `[` is not closed.
+fn class(pattern: &[char]) -> Option<(usize, impl Fn(char) -> bool + '_)> {
+    let mut i = 1;
+    let negated = matches!(pattern.get(i), Some('!' | '^'));
+    if negated {
+        i += 1;
+    }
+    let start = i;
+    // A `]` right after the opening bracket is a member.
+    if pattern.get(i) == Some(&']') {
+        i += 1;
+    }
+    while *pattern.get(i)? != ']' {
+        i += 1;
+    }
+    let members = &pattern[start..i];
+    let matches = move |c: char| {
+        let mut k = 0;
+        let mut found = false;
+        while k < members.len() {
+            if k + 2 < members.len() && members[k + 1] == '-' {
+                found |= (members[k]..=members[k + 2]).contains(&c);
+                k += 3;
+            } else {
+                found |= members[k] == c;
+                k += 1;
+            }
+        }
+        found != negated
+    };
+    Some((i + 1, matches))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ignored(patterns: &str, path: &str, is_dir: bool) -> Option<bool> {
+        Ignore::parse(Path::new("/repo"), patterns).matched(&Path::new("/repo").join(path), is_dir)
+    }
+
+    #[test]
+    fn names_match_at_any_depth() {
+        assert_eq!(ignored("target", "target", true), Some(true));
+        assert_eq!(ignored("target", "a/b/target", false), Some(true));
+        assert_eq!(ignored("*.log", "logs/today.log", false), Some(true));
+        assert_eq!(ignored("*.log", "logs/today.txt", false), None);
+        assert_eq!(ignored("file?.txt", "file1.txt", false), Some(true));
+        assert_eq!(ignored("file[0-4].txt", "file5.txt", false), None);
+        assert_eq!(ignored("file[!0-4].txt", "file5.txt", false), Some(true));
+    }
+
+    #[test]
+    fn slashes_anchor_patterns() {
+        assert_eq!(ignored("/build", "build", true), Some(true));
+        assert_eq!(ignored("/build", "src/build", true), None);
+        assert_eq!(ignored("doc/*.html", "doc/index.html", false), Some(true));
+        assert_eq!(ignored("doc/*.html", "doc/api/index.html", false), None);
+        assert_eq!(ignored("**/fixtures", "a/b/fixtures", true), Some(true));
+        assert_eq!(ignored("doc/**/*.html", "doc/index.html", false), Some(true));
+        assert_eq!(ignored("doc/**/*.html", "doc/a/b/index.html", false), Some(true));
+        assert_eq!(ignored("doc/**", "doc/a/index.html", false), Some(true));
+        assert_eq!(ignored("doc/**", "doc", true), None);
+    }
+
+    #[test]
+    fn directories_only_and_negation() {
+        assert_eq!(ignored("cache/", "cache", true), Some(true));
+        assert_eq!(ignored("cache/", "cache", false), None);
+        assert_eq!(ignored("*.log\n!keep.log", "keep.log", false), Some(false));
+        assert_eq!(ignored("!keep.log\n*.log", "keep.log", false), Some(true));
+    }
+
+    #[test]
+    fn comments_blanks_and_escapes() {
+        assert_eq!(ignored("# notes\n\n", "notes", false), None);
+        assert_eq!(ignored("\\#notes", "#notes", false), Some(true));
+        assert_eq!(ignored("\\!important", "!important", false), Some(true));
+        assert_eq!(ignored("trailing   ", "trailing", false), Some(true));
+        assert_eq!(ignored("a\\*b", "a*b", false), Some(true));
+        assert_eq!(ignored("a\\*b", "axb", false), None);
+    }
+}
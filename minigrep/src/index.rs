@@ -0,0 +1,583 @@
+//! A trigram index of a directory tree, for searching it again and again.
+//!
+//! `minigrep index DIR` reads the files a search of DIR would and writes
+//! `DIR/.minigrep-index`. The index holds the path, modification time and
+//! size of each file, and for each trigram, three bytes in a row within a
+//! line after [case folding](crate::aho_corasick), the files that contain
+//! it. A search below DIR works out from its patterns which trigrams a
+//! matching line must contain, and skips the files the index says lack them.
+//! Compressed files are indexed by what they decompress to, so the index is
+//! only used when searching with the default `Decompress::Auto`.
+//! Files that are new or changed since, going by their modification time and
+//! size, are searched as usual. Running `minigrep index DIR` again only reads
+//! those.
+//!
+//! The format, with all integers little-endian:
+//!
+//! ```text
+//! magic      b"MGINDEX\0"
+//! version    u32
+//! files      u32, then per file:
+//!            path length u32, path (UTF-8, relative to DIR, `/` between names),
+//!            mtime seconds u64, mtime nanoseconds u32, size u64
+//! trigrams   u32, then per trigram, in ascending order:
+//!            the trigram (3 bytes), file count u32, file numbers (u32, ascending)
+//! checksum   u64, FNV-1a of everything before it
+//! ```
+//!
+//! An index that is truncated, has another magic or version, or fails the
+//! checksum is not used by searches, and `minigrep index` builds it afresh.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, Write};
+use std::path::{self, Component, Path, PathBuf};
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::aho_corasick::fold;
+use crate::walk::Walk;
+use crate::Decompress;
+
+/// The name of the index file in an indexed directory.
+pub const FILE_NAME: &str = ".minigrep-index";
+
+const MAGIC: &[u8; 8] = b"MGINDEX\0";
+const VERSION: u32 = 2;
+
+/// The index of one directory tree.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct Index {
+    files: Vec<Entry>,
+    /// For each trigram, the numbers of the files that contain it.
+    postings: BTreeMap<u32, Vec<u32>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    path: String,
+    modified: (u64, u32),
+    size: u64,
+}
+
+impl Entry {
+    /// Whether `metadata` is that of the file as it was indexed.
+    fn is_current(&self, metadata: &fs::Metadata) -> bool {
+        metadata.len() == self.size && metadata.modified().ok().and_then(timestamp) == Some(self.modified)
+    }
+}
+
+fn timestamp(time: SystemTime) -> Option<(u64, u32)> {
+    let since = time.duration_since(UNIX_EPOCH).ok()?;
+    Some((since.as_secs(), since.subsec_nanos()))
+}
+
+/// What [`update`] did.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Update {
+    /// Files in the index.
+    pub files: usize,
+    /// Files read, because they were new or had changed.
+    pub read: usize,
+    /// Files dropped, because they are gone.
+    pub removed: usize,
+    /// Whether the old index was unreadable and was built afresh.
+    pub rebuilt: bool,
+    /// Files and directories that could not be read, and so are not indexed.
+    pub errors: Vec<String>,
+}
+
+/// Brings the index of the directory `dir` up to date, creating it if need be.
+///
+/// Files modified while the index is being built are left out of it, so
+/// that a change made in the same tick of the file system's clock as the
+/// index read the file cannot go unnoticed.
+pub fn update(dir: &Path) -> io::Result<Update> {
+    let started = SystemTime::now();
+    let index_path = dir.join(FILE_NAME);
+    let mut result = Update::default();
+    let old = match Index::read(&index_path) {
+        Ok(index) => index,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Index::default(),
+        Err(err) if err.kind() == io::ErrorKind::InvalidData => {
+            result.rebuilt = true;
+            Index::default()
+        }
+        Err(err) => return Err(err),
+    };
+    let mut old_trigrams = old.trigrams_by_file();
+    let old_ids: HashMap<&str, usize> = old.files.iter().enumerate().map(|(id, f)| (f.path.as_str(), id)).collect();
+
+    let mut files = Vec::new();
+    let mut trigrams = Vec::new();
+    for file in Walk::new(&[dir.to_path_buf()], false) {
+        let path = match file {
+            Ok(path) => path,
+            Err(err) => {
+                result.errors.push(err.to_string());
+                continue;
+            }
+        };
+        let Some(name) = relative_name(dir, &path) else { continue };
+        let indexed = fs::metadata(&path).and_then(|metadata| {
+            let unchanged = old_ids.get(name.as_str()).filter(|&&id| old.files[id].is_current(&metadata));
+            if let Some(&id) = unchanged {
+                return Ok(Some((old.files[id].clone(), std::mem::take(&mut old_trigrams[id]))));
+            }
+            let modified = metadata.modified()?;
+            if modified >= started {
+                return Ok(None);
+            }
+            result.read += 1;
+            let entry = Entry {
+                path: name.clone(),
+                modified: timestamp(modified).unwrap_or_default(),
+                size: metadata.len(),
+            };
+            Ok(Some((entry, file_trigrams(&path)?)))
+        });
+        match indexed {
+            Ok(Some((entry, set))) => {
+                files.push(entry);
+                trigrams.push(set);
+            }
+            Ok(None) => {}
+            Err(err) => result.errors.push(format!("{}: {err}", path.display())),
+        }
+    }
+    let kept: BTreeSet<&str> = files.iter().map(|f| f.path.as_str()).collect();
+    result.removed = old.files.iter().filter(|f| !kept.contains(f.path.as_str())).count();
+    result.files = files.len();
+
+    let mut postings: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+    for (id, set) in trigrams.into_iter().enumerate() {
+        for trigram in set {
+            postings.entry(trigram).or_default().push(id as u32);
+        }
+    }
+    Index { files, postings }.write(&index_path)?;
+    Ok(result)
+}
+
+/// The path of `path` relative to `dir`, with `/` between names, if it is
+/// valid UTF-8.
+fn relative_name(dir: &Path, path: &Path) -> Option<String> {
+    let names: Option<Vec<&str>> = path
+        .strip_prefix(dir)
+        .ok()?
+        .components()
+        .filter(|c| *c != Component::CurDir)
+        .map(|c| c.as_os_str().to_str())
+        .collect();
+    Some(names?.join("/"))
+}
+
+/// The trigrams in the lines of the file at `path`, read the way a search
+/// reads it.
+fn file_trigrams(path: &Path) -> io::Result<Vec<u32>> {
+    let mut reader = crate::open(path, Decompress::Auto)?;
+    let mut set = BTreeSet::new();
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        if reader.read_until(b'\n', &mut buf)? == 0 {
+            break;
+        }
+        let mut line = &buf[..];
+        if let Some(rest) = line.strip_suffix(b"\n") {
+            line = rest.strip_suffix(b"\r").unwrap_or(rest);
+        }
+        set.extend(trigrams(&fold(&String::from_utf8_lossy(line))));
+    }
+    Ok(set.into_iter().collect())
+}
+
+/// The trigrams of `text`, which is already case-folded, in order.
+fn trigrams(text: &str) -> impl Iterator<Item = u32> + '_ {
+    text.as_bytes()
+        .windows(3)
+        .map(|w| (w[0] as u32) << 16 | (w[1] as u32) << 8 | w[2] as u32)
+}
+
+impl Index {
+    /// Reads the index at `path`. An index that is not in the current format,
+    /// or is damaged, is an error of kind [`io::ErrorKind::InvalidData`].
+    pub(crate) fn read(path: &Path) -> io::Result<Index> {
+        let bytes = fs::read(path)?;
+        Index::decode(&bytes).map_err(|message| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("corrupt index: {message}"))
+        })
+    }
+
+    /// The trigrams of each file, by file number.
+    fn trigrams_by_file(&self) -> Vec<Vec<u32>> {
+        let mut by_file = vec![Vec::new(); self.files.len()];
+        for (&trigram, ids) in &self.postings {
+            for &id in ids {
+                by_file[id as usize].push(trigram);
+            }
+        }
+        by_file
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&VERSION.to_le_bytes());
+        out.extend_from_slice(&(self.files.len() as u32).to_le_bytes());
+        for file in &self.files {
+            out.extend_from_slice(&(file.path.len() as u32).to_le_bytes());
+            out.extend_from_slice(file.path.as_bytes());
+            out.extend_from_slice(&file.modified.0.to_le_bytes());
+            out.extend_from_slice(&file.modified.1.to_le_bytes());
+            out.extend_from_slice(&file.size.to_le_bytes());
+        }
+        out.extend_from_slice(&(self.postings.len() as u32).to_le_bytes());
+        for (trigram, ids) in &self.postings {
+            out.extend_from_slice(&trigram.to_be_bytes()[1..]);
+            out.extend_from_slice(&(ids.len() as u32).to_le_bytes());
+            for id in ids {
+                out.extend_from_slice(&id.to_le_bytes());
+            }
+        }
+        let checksum = fnv1a(&out);
+        out.extend_from_slice(&checksum.to_le_bytes());
+        out
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Index, &'static str> {
+        if bytes.len() < MAGIC.len() + 4 + 8 || &bytes[..MAGIC.len()] != MAGIC {
+            return Err("not an index");
+        }
+        let (body, checksum) = bytes.split_at(bytes.len() - 8);
+        if u32::from_le_bytes(body[MAGIC.len()..MAGIC.len() + 4].try_into().unwrap()) != VERSION {
+            return Err("unsupported version");
+        }
+        if fnv1a(body) != u64::from_le_bytes(checksum.try_into().unwrap()) {
+            return Err("checksum mismatch");
+        }
+
+        let mut reader = Reader(&body[MAGIC.len() + 4..]);
+        let file_count = reader.u32()? as usize;
+        let mut files = Vec::with_capacity(file_count.min(reader.0.len()));
+        for _ in 0..file_count {
+            let len = reader.u32()? as usize;
+            let path = std::str::from_utf8(reader.take(len)?).map_err(|_| "path not UTF-8")?;
+            files.push(Entry {
+                path: path.to_string(),
+                modified: (reader.u64()?, reader.u32()?),
+                size: reader.u64()?,
+            });
+        }
+        let mut postings = BTreeMap::new();
+        let mut previous = None;
+        for _ in 0..reader.u32()? {
+            let trigram = reader.take(3)?;
+            let trigram = u32::from_be_bytes([0, trigram[0], trigram[1], trigram[2]]);
+            if previous.is_some_and(|p| p >= trigram) {
+                return Err("trigrams out of order");
+            }
+            previous = Some(trigram);
+            let count = reader.u32()? as usize;
+            let mut ids = Vec::with_capacity(count.min(reader.0.len() / 4));
+            for _ in 0..count {
+                let id = reader.u32()?;
+                if id as usize >= files.len() || ids.last().is_some_and(|&last| last >= id) {
+                    return Err("bad file number");
+                }
+                ids.push(id);
+            }
+            postings.insert(trigram, ids);
+        }
+        if !reader.0.is_empty() {
+            return Err("trailing bytes");
+        }
+        Ok(Index { files, postings })
+    }
+
+    /// Writes the index to `path`, replacing the file there in one step.
+    fn write(&self, path: &Path) -> io::Result<()> {
+        let temp = path.with_file_name(format!("{FILE_NAME}.{}", process::id()));
+        let result = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&temp)
+            .and_then(|mut file| {
+                file.write_all(&self.encode())?;
+                file.sync_all()
+            })
+            .and_then(|()| fs::rename(&temp, path));
+        if result.is_err() {
+            let _ = fs::remove_file(&temp);
+        }
+        result
+    }
+
+    /// The numbers of the files that may contain what `required` asks for,
+    /// or `None` if any may.
+    fn candidates(&self, required: &Required) -> Option<BTreeSet<u32>> {
+        match required {
+            Required::Anything => None,
+            Required::Contains(text) => {
+                let mut found: Option<BTreeSet<u32>> = None;
+                for trigram in trigrams(text) {
+                    let ids = self.postings.get(&trigram).map_or(&[][..], Vec::as_slice);
+                    let ids = match found {
+                        Some(found) => ids.iter().copied().filter(|id| found.contains(id)).collect(),
+                        None => ids.iter().copied().collect(),
+                    };
+                    found = Some(ids);
+                }
+                found
+            }
+            Required::All(parts) => {
+                let mut found: Option<BTreeSet<u32>> = None;
+                for part in parts {
+                    if let Some(ids) = self.candidates(part) {
+                        found = Some(match found {
+                            Some(found) => found.intersection(&ids).copied().collect(),
+                            None => ids,
+                        });
+                    }
+                }
+                found
+            }
+            Required::AnyOf(parts) => {
+                let mut found = BTreeSet::new();
+                for part in parts {
+                    found.extend(self.candidates(part)?);
+                }
+                Some(found)
+            }
+        }
+    }
+}
+
+/// Reads the fields of an index, failing at its end.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], &'static str> {
+        if self.0.len() < len {
+            return Err("truncated");
+        }
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn u32(&mut self) -> Result<u32, &'static str> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, &'static str> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// What every line that a search selects must contain, as far as its
+/// patterns tell. Strings are case-folded, like the index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Required {
+    /// Nothing useful is known.
+    Anything,
+    Contains(String),
+    All(Vec<Required>),
+    AnyOf(Vec<Required>),
+}
+
+impl Required {
+    /// The line contains `text`, which is already case-folded. Strings
+    /// shorter than a trigram tell the index nothing.
+    pub(crate) fn contains(text: String) -> Required {
+        if text.len() < 3 {
+            Required::Anything
+        } else {
+            Required::Contains(text)
+        }
+    }
+
+    pub(crate) fn all(parts: impl IntoIterator<Item = Required>) -> Required {
+        let mut all = Vec::new();
+        for part in parts {
+            match part {
+                Required::Anything => {}
+                Required::All(parts) => all.extend(parts),
+                part => all.push(part),
+            }
+        }
+        match all.len() {
+            0 => Required::Anything,
+            1 => all.pop().unwrap(),
+            _ => Required::All(all),
+        }
+    }
+
+    /// One of `parts`; with none at all, nothing can match.
+    pub(crate) fn any_of(parts: impl IntoIterator<Item = Required>) -> Required {
+        let mut any = Vec::new();
+        for part in parts {
+            match part {
+                Required::Anything => return Required::Anything,
+                Required::AnyOf(parts) => any.extend(parts),
+                part => any.push(part),
+            }
+        }
+        if any.len() == 1 {
+            any.pop().unwrap()
+        } else {
+            Required::AnyOf(any)
+        }
+    }
+}
+
+/// The indexes that cover the paths of a search, and which of their files
+/// may match.
+#[derive(Default)]
+pub(crate) struct Prefilter {
+    roots: Vec<Root>,
+}
+
+struct Root {
+    /// The indexed directory, as an absolute path.
+    dir: PathBuf,
+    index: Index,
+    ids: HashMap<String, usize>,
+    /// Whether each file may match, by file number.
+    candidates: Vec<bool>,
+}
+
+impl Prefilter {
+    /// Finds the indexes in or above `paths` and works out which of their
+    /// files may hold what is `required`. Indexes that cannot be read are
+    /// passed to `unusable`, with the reason, and left out.
+    pub(crate) fn new(paths: &[PathBuf], required: &Required, mut unusable: impl FnMut(String)) -> Prefilter {
+        let mut prefilter = Prefilter::default();
+        if *required == Required::Anything {
+            return prefilter;
+        }
+        for path in paths {
+            let Ok(path) = path::absolute(path) else { continue };
+            let Some(dir) = path.ancestors().find(|dir| dir.join(FILE_NAME).is_file()) else { continue };
+            if prefilter.roots.iter().any(|root| root.dir == dir) {
+                continue;
+            }
+            let index_path = dir.join(FILE_NAME);
+            let index = match Index::read(&index_path) {
+                Ok(index) => index,
+                Err(err) => {
+                    unusable(format!("{}: {err}, searching without it", index_path.display()));
+                    continue;
+                }
+            };
+            let mut candidates = vec![true; index.files.len()];
+            if let Some(ids) = index.candidates(required) {
+                candidates.fill(false);
+                for id in ids {
+                    candidates[id as usize] = true;
+                }
+            }
+            let ids = index.files.iter().enumerate().map(|(id, f)| (f.path.clone(), id)).collect();
+            prefilter.roots.push(Root {
+                dir: dir.to_path_buf(),
+                index,
+                ids,
+                candidates,
+            });
+        }
+        prefilter
+    }
+
+    /// Whether the file at `path` certainly holds no match: it is in an
+    /// index, has not changed since, and lacks some trigram it would need.
+    pub(crate) fn skips(&self, path: &Path) -> bool {
+        if self.roots.is_empty() {
+            return false;
+        }
+        let Ok(path) = path::absolute(path) else { return false };
+        let Some(root) = self.roots.iter().filter(|root| path.starts_with(&root.dir)).max_by_key(|r| r.dir.as_os_str().len())
+        else {
+            return false;
+        };
+        let Some(&id) = relative_name(&root.dir, &path).and_then(|name| root.ids.get(&name)) else {
+            return false;
+        };
+        !root.candidates[id] && fs::metadata(&path).is_ok_and(|metadata| root.index.files[id].is_current(&metadata))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Index {
+        let mut postings = BTreeMap::new();
+        for (id, text) in ["a frog", "a bog", "frogs and bogs"].iter().enumerate() {
+            for trigram in trigrams(text) {
+                let ids: &mut Vec<u32> = postings.entry(trigram).or_default();
+                if ids.last() != Some(&(id as u32)) {
+                    ids.push(id as u32);
+                }
+            }
+        }
+        let entry = |path: &str, size| Entry {
+            path: path.to_string(),
+            modified: (1_700_000_000, 123),
+            size,
+        };
+        Index {
+            files: vec![entry("frog.txt", 6), entry("sub/bog.txt", 5), entry("both.txt", 14)],
+            postings,
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let index = sample();
+        assert_eq!(Index::decode(&index.encode()), Ok(index));
+        assert_eq!(Index::decode(&Index::default().encode()), Ok(Index::default()));
+    }
+
+    #[test]
+    fn corruption_is_detected() {
+        let bytes = sample().encode();
+        // Cut short anywhere.
+        for len in 0..bytes.len() {
+            assert!(Index::decode(&bytes[..len]).is_err(), "truncated to {len} bytes");
+        }
+        // Any flipped bit.
+        for i in 0..bytes.len() {
+            let mut damaged = bytes.clone();
+            damaged[i] ^= 0x10;
+            assert!(Index::decode(&damaged).is_err(), "byte {i} flipped");
+        }
+        let mut other_version = bytes.clone();
+        other_version[8] = VERSION as u8 + 1;
+        assert_eq!(Index::decode(&other_version), Err("unsupported version"));
+        assert_eq!(Index::decode(b"not an index at all"), Err("not an index"));
+
+        // Damage that keeps the checksum right is still caught.
+        let mut index = sample();
+        index.postings.insert(0x616263, vec![7]);
+        assert_eq!(Index::decode(&index.encode()), Err("bad file number"));
+    }
+
+    #[test]
+    fn candidates() {
+        let index = sample();
+        let ids = |required: Required| index.candidates(&required).map(|ids| ids.into_iter().collect::<Vec<_>>());
+        assert_eq!(ids(Required::contains("frog".to_string())), Some(vec![0, 2]));
+        assert_eq!(ids(Required::contains("bogs".to_string())), Some(vec![2]));
+        assert_eq!(ids(Required::contains("toad".to_string())), Some(vec![]));
+        assert_eq!(ids(Required::contains("og".to_string())), None);
+        let frog_or_bog = || Required::any_of([Required::contains("frog".into()), Required::contains("bog".into())]);
+        assert_eq!(ids(frog_or_bog()), Some(vec![0, 1, 2]));
+        assert_eq!(ids(Required::all([frog_or_bog(), Required::contains("and".into())])), Some(vec![2]));
+        assert_eq!(ids(Required::any_of([frog_or_bog(), Required::contains("x".into())])), None);
+        assert_eq!(ids(Required::any_of([])), Some(vec![]));
+    }
+}
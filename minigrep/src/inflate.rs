@@ -0,0 +1,771 @@
+//! Decompressing gzip, zlib and raw deflate data (RFC 1952, 1950 and 1951),
+//! so that compressed files, such as rotated logs, can be searched.
+//!
+//! A [`Decoder`] reads compressed data from a [`BufRead`] and is itself a
+//! `BufRead` of what it decompresses. It decompresses a block of output at a
+//! time as it is read, so a search sees the first lines of a file before the
+//! rest is decompressed, and never holds more than a little over twice the
+//! 32 KiB deflate window.
+//!
+//! The CRC-32 of a gzip member and the Adler-32 of a zlib stream are checked
+//! at the end, so output can be read before it turns out to be damaged. Data
+//! that is damaged is an error of kind [`io::ErrorKind::InvalidData`], and
+//! data that is cut short one of kind [`io::ErrorKind::UnexpectedEof`], each
+//! returned once the output decompressed before it has been read.
+
+use std::fmt;
+use std::io::{self, BufRead, Read};
+
+/// How far back a deflate back-reference can reach.
+const WINDOW: usize = 32 * 1024;
+
+/// The ways deflate data comes wrapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// A gzip file: a header, deflate data, then a CRC-32 and the length.
+    /// Members one after the other decompress to their contents one after
+    /// the other, as with `gzip -d`.
+    Gzip,
+    /// A zlib stream: a two-byte header, deflate data, then an Adler-32.
+    Zlib,
+    /// Deflate data on its own, with nothing to recognise it by.
+    Deflate,
+}
+
+impl Format {
+    /// The format that `head`, the start of a file, announces, if any: gzip
+    /// by its magic number, and zlib by the headers zlib writes for its
+    /// fastest, default and best compression. The headers for the levels in
+    /// between spell `x^`, which text starts with too often.
+    pub fn detect(head: &[u8]) -> Option<Format> {
+        match head {
+            [0x1f, 0x8b, 8, ..] => Some(Format::Gzip),
+            [0x78, 0x01 | 0x9c | 0xda, ..] => Some(Format::Zlib),
+            _ => None,
+        }
+    }
+
+    /// The format of data that is known to be compressed: the one `head`
+    /// announces, allowing any valid zlib header, or else raw deflate.
+    pub fn guess(head: &[u8]) -> Format {
+        match head {
+            [cmf, flg, ..] if cmf & 0x0f == 8 && cmf >> 4 <= 7 && (u16::from(*cmf) << 8 | u16::from(*flg)) % 31 == 0 => {
+                Format::Zlib
+            }
+            _ => Format::detect(head).unwrap_or(Format::Deflate),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Format::Gzip => "gzip",
+            Format::Zlib => "zlib",
+            Format::Deflate => "deflate",
+        })
+    }
+}
+
+/// Decompresses the data read from `R`.
+pub struct Decoder<R> {
+    input: Bits<R>,
+    format: Format,
+    state: State,
+    /// Whether the block being decoded is the last of its stream.
+    last: bool,
+    /// Up to a window of output already read, for back-references to copy
+    /// from, then the output not read yet.
+    out: Vec<u8>,
+    /// How much of `out` has been read.
+    read: usize,
+    checksum: Checksum,
+    /// The length of the output of the current stream, modulo 2^32.
+    size: u32,
+    /// Whether a gzip member has ended, so that more data may be another.
+    members: bool,
+    /// The error that stopped decompressing, held until the output before it
+    /// is read.
+    error: Option<io::Error>,
+}
+
+/// Where a [`Decoder`] is in its input.
+enum State {
+    /// The header of a stream is next.
+    Header,
+    /// The header of a block is next, or the trailer after the last block.
+    Block,
+    /// Within a stored block, with this many bytes to go.
+    Stored(usize),
+    /// Within a compressed block, decoded with these codes.
+    Codes(Box<Codes>),
+    /// The checksum after the last block is next.
+    Trailer,
+    Done,
+}
+
+/// Why decompressing stopped.
+enum Fail {
+    Io(io::Error),
+    Eof,
+    Corrupt(&'static str),
+}
+
+impl From<io::Error> for Fail {
+    fn from(err: io::Error) -> Fail {
+        Fail::Io(err)
+    }
+}
+
+impl<R: BufRead> Decoder<R> {
+    pub fn new(input: R, format: Format) -> Decoder<R> {
+        Decoder {
+            input: Bits::new(input),
+            format,
+            state: State::Header,
+            last: false,
+            out: Vec::with_capacity(3 * WINDOW),
+            read: 0,
+            checksum: Checksum::new(format),
+            size: 0,
+            members: false,
+            error: None,
+        }
+    }
+
+    /// Decompresses about a window's worth of output, less at the end.
+    fn fill(&mut self) -> Result<(), Fail> {
+        if self.read > WINDOW {
+            self.out.drain(..self.read - WINDOW);
+            self.read = WINDOW;
+        }
+        let limit = self.read + WINDOW;
+        let mut checked = self.out.len();
+        while self.out.len() < limit {
+            match &self.state {
+                State::Header => {
+                    self.state = if self.header()? { State::Block } else { State::Done };
+                }
+                State::Block if self.last => self.state = State::Trailer,
+                State::Block => {
+                    self.last = self.input.bits(1)? == 1;
+                    self.state = match self.input.bits(2)? {
+                        0 => {
+                            self.input.align();
+                            let len = self.input.bits(16)?;
+                            if len != !self.input.bits(16)? & 0xffff {
+                                return Err(Fail::Corrupt("stored block length does not match its complement"));
+                            }
+                            State::Stored(len as usize)
+                        }
+                        1 => State::Codes(Box::new(Codes::fixed())),
+                        2 => State::Codes(Box::new(Codes::read(&mut self.input)?)),
+                        _ => return Err(Fail::Corrupt("invalid block type")),
+                    };
+                }
+                &State::Stored(left) => {
+                    let len = left.min(limit - self.out.len());
+                    self.input.copy(&mut self.out, len)?;
+                    self.state = if len == left { State::Block } else { State::Stored(left - len) };
+                }
+                State::Codes(codes) => {
+                    if codes.decode(&mut self.input, &mut self.out, limit)? {
+                        self.state = State::Block;
+                    }
+                }
+                State::Trailer => {
+                    self.check(&mut checked);
+                    self.trailer()?;
+                    self.state = if self.format == Format::Gzip { State::Header } else { State::Done };
+                }
+                State::Done => break,
+            }
+        }
+        self.check(&mut checked);
+        Ok(())
+    }
+
+    /// Adds the output from `checked` on to the checksum.
+    fn check(&mut self, checked: &mut usize) {
+        let new = &self.out[*checked..];
+        self.checksum.update(new);
+        self.size = self.size.wrapping_add(new.len() as u32);
+        *checked = self.out.len();
+    }
+
+    /// Reads the header of a stream. Returns false at the end of the input
+    /// after a gzip member, or at data that is not another member.
+    fn header(&mut self) -> Result<bool, Fail> {
+        self.last = false;
+        self.checksum = Checksum::new(self.format);
+        self.size = 0;
+        match self.format {
+            Format::Gzip => {
+                if self.members && !self.input.starts_with(&[0x1f, 0x8b])? {
+                    // Like gzip, ignore what follows the last member.
+                    return Ok(false);
+                }
+                if self.input.bits(16)? != 0x8b1f {
+                    return Err(Fail::Corrupt("not in gzip format"));
+                }
+                if self.input.bits(8)? != 8 {
+                    return Err(Fail::Corrupt("unknown compression method"));
+                }
+                let flags = self.input.bits(8)?;
+                if flags & 0xe0 != 0 {
+                    return Err(Fail::Corrupt("reserved flags are set"));
+                }
+                // The modification time, extra flags and operating system.
+                self.input.skip(6)?;
+                if flags & 4 != 0 {
+                    let len = self.input.bits(16)?;
+                    self.input.skip(len as usize)?;
+                }
+                // The file name, then the comment, each ending with a NUL.
+                for flag in [8, 16] {
+                    if flags & flag != 0 {
+                        while self.input.bits(8)? != 0 {}
+                    }
+                }
+                if flags & 2 != 0 {
+                    self.input.skip(2)?;
+                }
+            }
+            Format::Zlib => {
+                let cmf = self.input.bits(8)?;
+                let flg = self.input.bits(8)?;
+                if cmf & 0x0f != 8 || cmf >> 4 > 7 || (cmf << 8 | flg) % 31 != 0 {
+                    return Err(Fail::Corrupt("not in zlib format"));
+                }
+                if flg & 0x20 != 0 {
+                    return Err(Fail::Corrupt("preset dictionaries are not supported"));
+                }
+            }
+            Format::Deflate => {}
+        }
+        Ok(true)
+    }
+
+    /// Reads and checks what follows the last block of a stream.
+    fn trailer(&mut self) -> Result<(), Fail> {
+        self.input.align();
+        match self.format {
+            Format::Gzip => {
+                let crc = self.input.bits(16)? | self.input.bits(16)? << 16;
+                let size = self.input.bits(16)? | self.input.bits(16)? << 16;
+                if crc != self.checksum.value() {
+                    return Err(Fail::Corrupt("CRC-32 does not match"));
+                }
+                if size != self.size {
+                    return Err(Fail::Corrupt("length does not match"));
+                }
+                self.members = true;
+            }
+            Format::Zlib => {
+                let mut adler = 0;
+                for _ in 0..4 {
+                    adler = adler << 8 | self.input.bits(8)?;
+                }
+                if adler != self.checksum.value() {
+                    return Err(Fail::Corrupt("Adler-32 does not match"));
+                }
+            }
+            Format::Deflate => {}
+        }
+        Ok(())
+    }
+}
+
+impl<R: BufRead> Read for Decoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.consume(len);
+        Ok(len)
+    }
+}
+
+impl<R: BufRead> BufRead for Decoder<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.read == self.out.len() {
+            if let Some(err) = self.error.take() {
+                return Err(err);
+            }
+            if let Err(fail) = self.fill() {
+                // Whatever follows would be garbage.
+                self.state = State::Done;
+                let err = match fail {
+                    Fail::Io(err) => err,
+                    Fail::Eof => io::Error::new(io::ErrorKind::UnexpectedEof, format!("{} data ends early", self.format)),
+                    Fail::Corrupt(what) => {
+                        io::Error::new(io::ErrorKind::InvalidData, format!("corrupt {} data: {what}", self.format))
+                    }
+                };
+                if self.read == self.out.len() {
+                    return Err(err);
+                }
+                self.error = Some(err);
+            }
+        }
+        Ok(&self.out[self.read..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.read = (self.read + amt).min(self.out.len());
+    }
+}
+
+/// The compressed input, read least significant bit first, as deflate packs
+/// it.
+struct Bits<R> {
+    inner: R,
+    /// Bits read from `inner` but not used yet, the next one lowest.
+    bits: u64,
+    count: u32,
+}
+
+impl<R: BufRead> Bits<R> {
+    fn new(inner: R) -> Bits<R> {
+        Bits { inner, bits: 0, count: 0 }
+    }
+
+    /// Reads bytes until at least `n` bits are buffered, or the input ends.
+    fn want(&mut self, n: u32) -> io::Result<()> {
+        while self.count < n {
+            let Some(&byte) = self.inner.fill_buf()?.first() else {
+                break;
+            };
+            self.inner.consume(1);
+            self.bits |= u64::from(byte) << self.count;
+            self.count += 8;
+        }
+        Ok(())
+    }
+
+    /// The next `n` bits, without using them up. Past the end of the input
+    /// they are zeros.
+    fn peek(&mut self, n: u32) -> io::Result<u32> {
+        self.want(n)?;
+        Ok((self.bits & ((1 << n) - 1)) as u32)
+    }
+
+    fn drop(&mut self, n: u32) -> Result<(), Fail> {
+        if n > self.count {
+            return Err(Fail::Eof);
+        }
+        self.bits >>= n;
+        self.count -= n;
+        Ok(())
+    }
+
+    /// Reads an `n`-bit number, for `n` up to 16.
+    fn bits(&mut self, n: u32) -> Result<u32, Fail> {
+        let value = self.peek(n)?;
+        self.drop(n)?;
+        Ok(value)
+    }
+
+    /// Skips to the next byte boundary.
+    fn align(&mut self) {
+        let partial = self.count % 8;
+        self.bits >>= partial;
+        self.count -= partial;
+    }
+
+    fn skip(&mut self, bytes: usize) -> Result<(), Fail> {
+        for _ in 0..bytes {
+            self.bits(8)?;
+        }
+        Ok(())
+    }
+
+    /// Whether the bytes from here on start with `prefix`, reading nothing.
+    /// The input must be at a byte boundary.
+    fn starts_with(&mut self, prefix: &[u8]) -> io::Result<bool> {
+        let buffered = (self.count / 8) as usize;
+        let ahead: Vec<u8> = (0..buffered).map(|i| (self.bits >> (8 * i)) as u8).collect();
+        if ahead.len() >= prefix.len() {
+            return Ok(ahead.starts_with(prefix));
+        }
+        let rest = self.inner.fill_buf()?;
+        Ok(prefix.starts_with(&ahead) && rest.starts_with(&prefix[ahead.len()..]))
+    }
+
+    /// Copies `len` bytes, from a byte boundary, to `out`.
+    fn copy(&mut self, out: &mut Vec<u8>, len: usize) -> Result<(), Fail> {
+        let mut copied = 0;
+        while copied < len && self.count >= 8 {
+            out.push(self.bits(8)? as u8);
+            copied += 1;
+        }
+        while copied < len {
+            let available = self.inner.fill_buf()?;
+            if available.is_empty() {
+                return Err(Fail::Eof);
+            }
+            let take = available.len().min(len - copied);
+            out.extend_from_slice(&available[..take]);
+            self.inner.consume(take);
+            copied += take;
+        }
+        Ok(())
+    }
+}
+
+/// A canonical Huffman code, decoded by looking up as many bits as its
+/// longest code has.
+struct Huffman {
+    /// For every value of the next `bits` bits, the symbol whose code they
+    /// start with, shifted left by 4, plus the length of the code; 0 if none
+    /// does.
+    table: Vec<u16>,
+    bits: u32,
+}
+
+impl Huffman {
+    /// The code with the given code length for each symbol, 0 for symbols
+    /// that do not occur. Codes may be incomplete, but not oversubscribed.
+    fn new(lengths: &[u8]) -> Result<Huffman, Fail> {
+        let mut counts = [0u16; 16];
+        for &len in lengths {
+            counts[len as usize] += 1;
+        }
+        counts[0] = 0;
+        let mut next = [0u16; 16];
+        let mut code = 0u32;
+        for len in 1..16 {
+            code = (code + u32::from(counts[len - 1])) << 1;
+            next[len] = code as u16;
+            if code + u32::from(counts[len]) > 1 << len {
+                return Err(Fail::Corrupt("oversubscribed Huffman code"));
+            }
+        }
+        let bits = lengths.iter().copied().max().unwrap_or(0).max(1) as u32;
+        let mut table = vec![0u16; 1 << bits];
+        for (symbol, &len) in lengths.iter().enumerate().filter(|(_, &len)| len > 0) {
+            let len = u32::from(len);
+            let code = next[len as usize];
+            next[len as usize] += 1;
+            // The stream holds codes most significant bit first.
+            let reversed = (code.reverse_bits() >> (16 - len)) as usize;
+            for fill in (reversed..table.len()).step_by(1 << len) {
+                table[fill] = (symbol as u16) << 4 | len as u16;
+            }
+        }
+        Ok(Huffman { table, bits })
+    }
+
+    fn decode<R: BufRead>(&self, input: &mut Bits<R>) -> Result<u16, Fail> {
+        let entry = self.table[input.peek(self.bits)? as usize];
+        if entry == 0 {
+            // Either a code the table lacks, or zeros past the end.
+            input.want(self.bits)?;
+            return Err(if input.count < self.bits { Fail::Eof } else { Fail::Corrupt("invalid Huffman code") });
+        }
+        input.drop(u32::from(entry & 15))?;
+        Ok(entry >> 4)
+    }
+}
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097,
+    6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] =
+    [0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13];
+/// The order in which a dynamic block gives the lengths of the code lengths.
+const CODE_LENGTH_ORDER: [usize; 19] = [16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15];
+
+/// The codes of a compressed block: one for literals, lengths and the end of
+/// the block, and one for distances.
+struct Codes {
+    literals: Huffman,
+    distances: Huffman,
+}
+
+impl Codes {
+    /// The codes of a block compressed with fixed codes.
+    fn fixed() -> Codes {
+        let mut literals = [8; 288];
+        literals[144..256].fill(9);
+        literals[256..280].fill(7);
+        Codes {
+            literals: Huffman::new(&literals).unwrap_or_else(|_| unreachable!()),
+            distances: Huffman::new(&[5; 30]).unwrap_or_else(|_| unreachable!()),
+        }
+    }
+
+    /// Reads the codes at the start of a block compressed with dynamic codes.
+    fn read<R: BufRead>(input: &mut Bits<R>) -> Result<Codes, Fail> {
+        let literals = input.bits(5)? as usize + 257;
+        let distances = input.bits(5)? as usize + 1;
+        let code_lengths = input.bits(4)? as usize + 4;
+        let mut lengths = [0; 19];
+        for &symbol in &CODE_LENGTH_ORDER[..code_lengths] {
+            lengths[symbol] = input.bits(3)? as u8;
+        }
+        let code = Huffman::new(&lengths)?;
+
+        let mut lengths = Vec::with_capacity(literals + distances);
+        while lengths.len() < literals + distances {
+            let (len, repeat) = match code.decode(input)? {
+                len @ 0..=15 => (len as u8, 1),
+                16 => {
+                    let previous = *lengths.last().ok_or(Fail::Corrupt("repeated code length with none before"))?;
+                    (previous, 3 + input.bits(2)?)
+                }
+                17 => (0, 3 + input.bits(3)?),
+                _ => (0, 11 + input.bits(7)?),
+            };
+            if lengths.len() + repeat as usize > literals + distances {
+                return Err(Fail::Corrupt("too many code lengths"));
+            }
+            lengths.extend((0..repeat).map(|_| len));
+        }
+        if lengths[256] == 0 {
+            return Err(Fail::Corrupt("no code for the end of the block"));
+        }
+        Ok(Codes {
+            literals: Huffman::new(&lengths[..literals])?,
+            distances: Huffman::new(&lengths[literals..])?,
+        })
+    }
+
+    /// Decodes symbols onto `out` until the end of the block, when it returns
+    /// true, or until `out` reaches `limit`. A copy may take `out` past it.
+    fn decode<R: BufRead>(&self, input: &mut Bits<R>, out: &mut Vec<u8>, limit: usize) -> Result<bool, Fail> {
+        while out.len() < limit {
+            let symbol = self.literals.decode(input)? as usize;
+            if symbol < 256 {
+                out.push(symbol as u8);
+                continue;
+            }
+            if symbol == 256 {
+                return Ok(true);
+            }
+            let i = symbol - 257;
+            if i >= LENGTH_BASE.len() {
+                return Err(Fail::Corrupt("invalid length code"));
+            }
+            let len = LENGTH_BASE[i] as usize + input.bits(u32::from(LENGTH_EXTRA[i]))? as usize;
+            let i = self.distances.decode(input)? as usize;
+            if i >= DISTANCE_BASE.len() {
+                return Err(Fail::Corrupt("invalid distance code"));
+            }
+            let distance = DISTANCE_BASE[i] as usize + input.bits(u32::from(DISTANCE_EXTRA[i]))? as usize;
+            if distance > out.len() {
+                return Err(Fail::Corrupt("distance too far back"));
+            }
+            let start = out.len() - distance;
+            if distance >= len {
+                out.extend_from_within(start..start + len);
+            } else {
+                // The copy overlaps what it writes, repeating the last bytes.
+                for i in start..start + len {
+                    out.push(out[i]);
+                }
+            }
+        }
+        Ok(false)
+    }
+}
+
+/// The checksum that ends a stream, over its output so far.
+enum Checksum {
+    None,
+    Crc32(u32),
+    Adler32(u32),
+}
+
+/// The CRC-32 of each byte, for the polynomial gzip uses.
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { 0xedb8_8320 ^ (crc >> 1) } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+impl Checksum {
+    fn new(format: Format) -> Checksum {
+        match format {
+            Format::Gzip => Checksum::Crc32(0),
+            Format::Zlib => Checksum::Adler32(1),
+            Format::Deflate => Checksum::None,
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        match self {
+            Checksum::None => {}
+            Checksum::Crc32(crc) => {
+                let mut c = !*crc;
+                for &byte in bytes {
+                    c = CRC_TABLE[((c ^ u32::from(byte)) & 0xff) as usize] ^ (c >> 8);
+                }
+                *crc = !c;
+            }
+            Checksum::Adler32(adler) => {
+                let (mut a, mut b) = (*adler & 0xffff, *adler >> 16);
+                // 5552 bytes is as many as can be summed before `b` could
+                // overflow.
+                for chunk in bytes.chunks(5552) {
+                    for &byte in chunk {
+                        a += u32::from(byte);
+                        b += a;
+                    }
+                    a %= 65521;
+                    b %= 65521;
+                }
+                *adler = b << 16 | a;
+            }
+        }
+    }
+
+    fn value(&self) -> u32 {
+        match *self {
+            Checksum::None => 0,
+            Checksum::Crc32(value) | Checksum::Adler32(value) => value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inflate(format: Format, data: &[u8]) -> io::Result<Vec<u8>> {
+        let mut out = Vec::new();
+        Decoder::new(data, format).read_to_end(&mut out)?;
+        Ok(out)
+    }
+
+    /// `printf 'a frog\na bog\n' | gzip -9n`, with fixed codes.
+    const FROGS_GZ: &[u8] = &[
+        0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x4b, 0x54, 0x48, 0x2b, 0xca, 0x4f, 0xe7, 0x4a,
+        0x54, 0x48, 0x02, 0x92, 0x00, 0xf6, 0x7b, 0xdd, 0x69, 0x0d, 0x00, 0x00, 0x00,
+    ];
+
+    /// The numbers from 0 to 59 in binary, between spaces, deflated by zlib
+    /// at level 9 with dynamic codes.
+    const BINARY_DEFLATE: &[u8] = &[
+        0x3d, 0x90, 0xc1, 0x11, 0xc0, 0x20, 0x0c, 0xc3, 0x56, 0x61, 0x84, 0x78, 0xff, 0xe5, 0x4a, 0x2c, 0xd1, 0x0f,
+        0x1c, 0xe0, 0x93, 0x15, 0xe6, 0xe4, 0x64, 0x4e, 0x76, 0xbd, 0xdb, 0xdc, 0xbd, 0xc7, 0x9e, 0xf7, 0x62, 0x6f,
+        0x26, 0x7d, 0xea, 0xdb, 0x3e, 0x36, 0x45, 0x8c, 0x5c, 0x83, 0x4d, 0x12, 0x6d, 0x76, 0x24, 0x42, 0x10, 0x01,
+        0xa3, 0x10, 0xba, 0x02, 0x10, 0x62, 0x91, 0xd0, 0xc5, 0xcb, 0xa7, 0x80, 0x06, 0x2b, 0xe8, 0xb0, 0xc4, 0x16,
+        0x6b, 0xe8, 0x19, 0x27, 0xf8, 0x67, 0x88, 0x3a, 0xfa, 0x20, 0xa4, 0xdd, 0xd3, 0xd3, 0x0f, 0x41, 0x0c, 0x55,
+        0x74, 0xfe, 0xf7, 0x03, 0xda, 0x3f, 0x7d, 0xfd, 0x19, 0xc0, 0xff, 0x89, 0xe3, 0xe4, 0x03,
+    ];
+
+    #[test]
+    fn formats() {
+        assert_eq!(Format::detect(FROGS_GZ), Some(Format::Gzip));
+        assert_eq!(Format::detect(b"x\x9c..."), Some(Format::Zlib));
+        assert_eq!(Format::detect(b"x^2 + y^2"), None);
+        assert_eq!(Format::guess(b"x^..."), Format::Zlib);
+        assert_eq!(Format::guess(b"\x4b\x54"), Format::Deflate);
+
+        assert_eq!(inflate(Format::Gzip, FROGS_GZ).unwrap(), b"a frog\na bog\n");
+        // The same deflate data, as zlib wraps it and on its own.
+        let deflate = &FROGS_GZ[10..FROGS_GZ.len() - 8];
+        let zlib = [&[0x78, 0xda], deflate, &[0x1d, 0x48, 0x03, 0xfd]].concat();
+        assert_eq!(inflate(Format::Zlib, &zlib).unwrap(), b"a frog\na bog\n");
+        assert_eq!(inflate(Format::Deflate, deflate).unwrap(), b"a frog\na bog\n");
+
+        // Members one after the other, then what gzip calls trailing garbage.
+        let mut twice = [FROGS_GZ, FROGS_GZ, b"\0\0"].concat();
+        assert_eq!(inflate(Format::Gzip, &twice).unwrap(), b"a frog\na bog\na frog\na bog\n");
+        twice.truncate(FROGS_GZ.len() + 15);
+        let err = inflate(Format::Gzip, &twice).unwrap_err();
+        assert_eq!((err.kind(), err.to_string()), (io::ErrorKind::UnexpectedEof, "gzip data ends early".to_string()));
+    }
+
+    #[test]
+    fn blocks() {
+        let binary: Vec<String> = (0..60).map(|i| format!("{i:b}")).collect();
+        assert_eq!(inflate(Format::Deflate, BINARY_DEFLATE).unwrap(), binary.join(" ").as_bytes());
+
+        // A stored block, then one with fixed codes whose back-reference
+        // overlaps what it copies.
+        let mut deflate = vec![0x00, 0x05, 0x00, 0xfa, 0xff];
+        deflate.extend_from_slice(b"hello");
+        deflate.extend(fixed_block(&[Symbol::Literal(b'a'), Symbol::Literal(b'b'), Symbol::Copy(10, 2)]));
+        assert_eq!(inflate(Format::Deflate, &deflate).unwrap(), b"helloabababababab");
+
+        let err = inflate(Format::Deflate, &[0x00, 0x05, 0x00, 0xfb, 0xff]).unwrap_err();
+        assert_eq!(err.to_string(), "corrupt deflate data: stored block length does not match its complement");
+        let err = inflate(Format::Deflate, &fixed_block(&[Symbol::Copy(3, 1)])).unwrap_err();
+        assert_eq!(err.to_string(), "corrupt deflate data: distance too far back");
+        let err = inflate(Format::Deflate, &[0x07]).unwrap_err();
+        assert_eq!(err.to_string(), "corrupt deflate data: invalid block type");
+        let err = inflate(Format::Deflate, &BINARY_DEFLATE[..50]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn checksums() {
+        let mut damaged = FROGS_GZ.to_vec();
+        let crc = damaged.len() - 8;
+        damaged[crc] ^= 1;
+        let err = inflate(Format::Gzip, &damaged).unwrap_err();
+        assert_eq!(
+            (err.kind(), err.to_string()),
+            (io::ErrorKind::InvalidData, "corrupt gzip data: CRC-32 does not match".to_string())
+        );
+
+        let mut crc = Checksum::new(Format::Gzip);
+        crc.update(b"123456789");
+        assert_eq!(crc.value(), 0xcbf4_3926);
+        let mut adler = Checksum::new(Format::Zlib);
+        adler.update(b"Wikipedia");
+        assert_eq!(adler.value(), 0x11e6_0398);
+    }
+
+    enum Symbol {
+        Literal(u8),
+        Copy(u16, u16),
+    }
+
+    /// A last block with fixed codes holding `symbols`, then its end.
+    fn fixed_block(symbols: &[Symbol]) -> Vec<u8> {
+        // BFINAL, then the block type, 1, least significant bit first.
+        let mut bits = vec![true, true, false];
+        // Huffman codes go most significant bit first, other numbers least.
+        let code = |bits: &mut Vec<bool>, value: u32, len: u32| bits.extend((0..len).rev().map(|i| value >> i & 1 == 1));
+        let number = |bits: &mut Vec<bool>, value: u16, len: u8| bits.extend((0..len).map(|i| value >> i & 1 == 1));
+        let literal = |bits: &mut Vec<bool>, symbol: u32| match symbol {
+            0..=143 => code(bits, 0x30 + symbol, 8),
+            144..=255 => code(bits, 0x190 + symbol - 144, 9),
+            256..=279 => code(bits, symbol - 256, 7),
+            _ => code(bits, 0xc0 + symbol - 280, 8),
+        };
+        for symbol in symbols {
+            match *symbol {
+                Symbol::Literal(byte) => literal(&mut bits, u32::from(byte)),
+                Symbol::Copy(len, distance) => {
+                    let i = LENGTH_BASE.iter().rposition(|&base| base <= len).unwrap();
+                    literal(&mut bits, 257 + i as u32);
+                    number(&mut bits, len - LENGTH_BASE[i], LENGTH_EXTRA[i]);
+                    let i = DISTANCE_BASE.iter().rposition(|&base| base <= distance).unwrap();
+                    code(&mut bits, i as u32, 5);
+                    number(&mut bits, distance - DISTANCE_BASE[i], DISTANCE_EXTRA[i]);
+                }
+            }
+        }
+        literal(&mut bits, 256);
+        bits.chunks(8).map(|byte| byte.iter().rev().fold(0, |acc, &bit| acc << 1 | u8::from(bit))).collect()
+    }
+}
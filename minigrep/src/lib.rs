@@ -0,0 +1,1019 @@
+//! The I/O project of chapters 12 and 13 of "The Rust Programming Language":
+//! a small `grep` that prints the lines of a file containing a query.
+//!
+//! It started as the final, iterator-based version of the listings in
+//! `trpl::ch12_minigrep` and `trpl::ch13_functional_features::improving_our_io_project`,
+//! and has since learned to search whole directory trees.
+
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, IsTerminal, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+pub mod aho_corasick;
+mod config;
+mod follow;
+pub mod fuzzy;
+pub mod ignore;
+pub mod index;
+pub mod inflate;
+mod pool;
+pub mod regex;
+pub mod replace;
+pub mod sink;
+mod tui;
+pub mod walk;
+
+use aho_corasick::{fold, AhoCorasick, PatternMatch};
+use fuzzy::Fuzzy;
+use regex::Regex;
+use replace::Template;
+use sink::{Line, OutputSink, Palette};
+use index::{Prefilter, Required};
+use inflate::{Decoder, Format};
+use walk::Walk;
+
+pub use config::ConfigError;
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// What to search for; a line is selected if any of them matches.
+    pub patterns: Vec<String>,
+    /// Files to read more patterns from, one per line.
+    pub pattern_files: Vec<PathBuf>,
+    /// Files and directories to search; directories are searched recursively.
+    pub paths: Vec<PathBuf>,
+    pub ignore_case: bool,
+    pub pattern_kind: PatternKind,
+    /// Also search hidden files and directories inside the given directories.
+    pub hidden: bool,
+    /// Number of threads searching files; 1 searches on the calling thread.
+    pub jobs: usize,
+    /// Prefix each line with its line number.
+    pub line_numbers: bool,
+    /// Prefix each line with the byte offset of its start in the file.
+    pub byte_offsets: bool,
+    /// Lines of context to print before each match.
+    pub before_context: usize,
+    /// Lines of context to print after each match.
+    pub after_context: usize,
+    /// Select the lines that do not match instead.
+    pub invert: bool,
+    /// Print the lines of files with NUL bytes too, rather than just saying
+    /// that they match.
+    pub text: bool,
+    /// Which files are decompressed before they are searched.
+    pub decompress: Decompress,
+    pub output: OutputMode,
+    /// Replace the matches in the files with this, see [`Template`], instead
+    /// of printing them.
+    pub replace: Option<String>,
+    /// Print what `replace` would change instead of changing it.
+    pub dry_run: bool,
+    /// Match the patterns approximately, within this many edits, and rank
+    /// the lines by how close they come, see [`fuzzy`].
+    pub fuzzy: Option<usize>,
+    /// Build or update the [index] of each directory in `paths` instead of
+    /// searching (`minigrep index DIR...`).
+    pub index: bool,
+    /// Keep searching the files in `paths` as lines are added to them, like
+    /// `tail -f | grep`, instead of searching what they hold now.
+    pub follow: bool,
+    /// Browse the matches in the terminal, searching again as the query is
+    /// edited, starting from `patterns` (`minigrep --tui [-e QUERY] PATH...`).
+    pub tui: bool,
+    /// When to color the output.
+    pub color: ColorChoice,
+    /// The colors, from `MINIGREP_COLORS`.
+    pub palette: Palette,
+}
+
+/// How the patterns are matched against each line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PatternKind {
+    /// The patterns are plain substrings (`-F`, the default).
+    #[default]
+    Literal,
+    /// The patterns are regular expressions (`-E`), see [`regex`].
+    Regex,
+}
+
+/// Which files are searched for what they decompress to, see [`inflate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Decompress {
+    /// Files that start like gzip or zlib data (the default).
+    #[default]
+    Auto,
+    /// Every file, as raw deflate data if it has no header (`-z`).
+    Always,
+    /// None (`--no-decompress`).
+    Never,
+}
+
+/// When the output is colored with a [`Palette`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    /// When it goes to a terminal (the default).
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// What is printed about the selected lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputMode {
+    /// The lines themselves.
+    #[default]
+    Lines,
+    /// Only the parts of the lines that matched, one per line (`-o`).
+    OnlyMatching,
+    /// The number of lines selected in each file (`-c`).
+    Count,
+    /// The paths of the files where some line was selected (`-l`).
+    FilesWithMatches,
+    /// The paths of the files where no line was selected (`-L`).
+    FilesWithoutMatch,
+    /// A JSON object per line (`--json`), see [`sink::Json`].
+    Json,
+}
+
+/// Searches every file below `config.paths` and prints the matching lines.
+/// When more than one file can be involved, each line is prefixed with the
+/// path of its file, like `grep -r` does.
+///
+/// A path given in `config` that does not exist is an error. Files inside
+/// directories that cannot be read are reported on stderr and skipped.
+///
+/// Files are read a line at a time, so memory use is bounded by the longest
+/// line rather than the size of the file. Bytes that are not valid UTF-8 are
+/// replaced with U+FFFD. A file with a NUL byte is taken to be binary: rather
+/// than printing its lines, minigrep says that it matches, unless
+/// `config.text` is set.
+///
+/// Files compressed with gzip or zlib, recognised by how they start rather
+/// than by their names, are searched for what they decompress to, as it is
+/// decompressed; line numbers and byte offsets count in that.
+///
+/// Files in a directory indexed with `minigrep index` that cannot match, by
+/// the [index], are not read at all.
+///
+/// The matches, paths and line numbers are colored when `config.color` says
+/// so, or by default when the output goes to a terminal.
+pub fn run(mut config: Config) -> Result<(), Box<dyn Error>> {
+    let stdout = io::stdout();
+    if config.color == ColorChoice::Auto && stdout.is_terminal() {
+        config.color = ColorChoice::Always;
+    }
+    run_to(&config, &mut stdout.lock())
+}
+
+/// Like [`run`], but writes the output to `out`, which is colored only with
+/// `--color=always`.
+pub fn run_to(config: &Config, out: &mut impl Write) -> Result<(), Box<dyn Error>> {
+    if config.index {
+        return run_index(config, out);
+    }
+    if config.tui {
+        return tui::run(config);
+    }
+    if let Some(replacement) = &config.replace {
+        return run_replace(config, replacement, out);
+    }
+    let with_path = many_files(config);
+    let colors = (config.color == ColorChoice::Always).then(|| config.palette.clone());
+    match config.output {
+        OutputMode::Lines | OutputMode::OnlyMatching => {
+            let mut sink = sink::Standard::new(out);
+            sink.with_path = with_path;
+            sink.line_numbers = config.line_numbers;
+            sink.byte_offsets = config.byte_offsets;
+            sink.separators = !ranked(config) && (config.before_context > 0 || config.after_context > 0);
+            sink.only_matching = config.output == OutputMode::OnlyMatching;
+            sink.colors = colors;
+            run_with(config, &mut sink)
+        }
+        OutputMode::Count => {
+            let mut sink = sink::Count::new(out);
+            sink.with_path = with_path;
+            sink.colors = colors;
+            run_with(config, &mut sink)
+        }
+        OutputMode::FilesWithMatches | OutputMode::FilesWithoutMatch => {
+            let mut sink = match config.output {
+                OutputMode::FilesWithMatches => sink::Files::with_matches(out),
+                _ => sink::Files::without_match(out),
+            };
+            sink.colors = colors;
+            run_with(config, &mut sink)
+        }
+        OutputMode::Json => run_with(config, &mut sink::Json::new(out)),
+    }
+}
+
+/// Searches like [`run`], handing the results to `sink` instead of printing
+/// them. Only the options of `config` that choose and search the files, and
+/// the lines of context, apply; the sink decides what to make of the lines.
+///
+/// With `config.jobs` above one and more than one file to search, files are
+/// searched on that many worker threads; the sink still gets them in the
+/// order the files were found. The first few hundred lines of a file searched
+/// ahead of its turn are then held until it comes, so memory use stays
+/// bounded.
+///
+/// With `config.fuzzy`, the selected lines are handed over once all files
+/// are searched, ranked by how few edits they are from the patterns, and
+/// there is no context.
+///
+/// With `config.follow`, the lines added to the files are handed over as
+/// they come, and it only returns with an error; see `tail -f`.
+pub fn run_with(config: &Config, sink: &mut dyn OutputSink) -> Result<(), Box<dyn Error>> {
+    let patterns = read_patterns(config)?;
+    let searcher = Searcher::new(config, &patterns)?;
+    for path in &config.paths {
+        metadata(path)?;
+    }
+    if config.follow {
+        return follow::run(config, &searcher, sink);
+    }
+    let mut unusable = Vec::new();
+    let prefilter = prefilter(config, &patterns, &searcher.matcher, |message| unusable.push(message));
+    for message in unusable {
+        sink.error(&message)?;
+    }
+
+    if ranked(config) {
+        let matcher = &searcher.matcher;
+        let mut ranked = sink::Ranked::new(sink, |line: &Line| matcher.distance(&line.text));
+        search_paths(config, &searcher, &prefilter, &mut ranked)?;
+        ranked.finish()?;
+    } else {
+        search_paths(config, &searcher, &prefilter, sink)?;
+    }
+    Ok(())
+}
+
+/// Whether the lines are ranked rather than given in file order.
+fn ranked(config: &Config) -> bool {
+    config.fuzzy.is_some()
+        && !config.invert
+        && matches!(config.output, OutputMode::Lines | OutputMode::OnlyMatching | OutputMode::Json)
+}
+
+/// Whether `config.paths` can hold more than one file: there are several, or
+/// a directory.
+fn many_files(config: &Config) -> bool {
+    config.paths.len() > 1 || config.paths.iter().any(|p| p.is_dir())
+}
+
+/// Searches the files below `config.paths` that `prefilter` does not skip.
+fn search_paths(
+    config: &Config,
+    searcher: &Searcher,
+    prefilter: &Prefilter,
+    sink: &mut dyn OutputSink,
+) -> Result<(), Box<dyn Error>> {
+    if config.jobs > 1 && many_files(config) {
+        let mut path = PathBuf::new();
+        pool::search(&config.paths, config.hidden, config.jobs, prefilter, searcher, |found| match found {
+            Found::Begin(file) => {
+                path = file;
+                sink.begin(&path)
+            }
+            Found::Line(line) => sink.line(&path, &line),
+            Found::End(result) => finish(sink, &path, result),
+            Found::Error(err) => sink.error(&err),
+        })?;
+    } else {
+        for file in Walk::new(&config.paths, config.hidden) {
+            match file {
+                Ok(path) if prefilter.skips(&path) => {}
+                Ok(path) => {
+                    sink.begin(&path)?;
+                    let result = searcher.search_file(&path, |line| sink.line(&path, &line))?;
+                    finish(sink, &path, result)?;
+                }
+                Err(err) => sink.error(&err.to_string())?,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Rewrites the files below `config.paths`, replacing the matches with
+/// `replacement`, and prints the paths of the files changed. With
+/// `config.dry_run`, prints the diff of the changes instead. Files are
+/// rewritten one at a time, whatever `config.jobs` says.
+///
+/// A file that cannot be rewritten, such as a binary one, is reported on
+/// stderr and the others are still rewritten, but it makes this an error in
+/// the end.
+fn run_replace(config: &Config, replacement: &str, out: &mut impl Write) -> Result<(), Box<dyn Error>> {
+    let patterns = read_patterns(config)?;
+    let matcher = Matcher::new(config, &patterns)?;
+    let template = Template::parse(replacement);
+    for path in &config.paths {
+        metadata(path)?;
+    }
+    let prefilter = prefilter(config, &patterns, &matcher, |message| eprintln!("minigrep: {message}"));
+    let mut failed = 0;
+    for file in Walk::new(&config.paths, config.hidden) {
+        match file {
+            Ok(path) if prefilter.skips(&path) => {}
+            Ok(path) => match replace::rewrite(&matcher, &template, &path, config.decompress, config.dry_run, out) {
+                Ok(changed) if changed > 0 && !config.dry_run => writeln!(out, "{}", path.display())?,
+                Ok(_) => {}
+                Err(err) => {
+                    eprintln!("minigrep: {}: {err}", path.display());
+                    failed += 1;
+                }
+            },
+            Err(err) => eprintln!("minigrep: {err}"),
+        }
+    }
+    match failed {
+        0 => Ok(()),
+        1 => Err("1 file could not be rewritten".into()),
+        _ => Err(format!("{failed} files could not be rewritten").into()),
+    }
+}
+
+/// Builds or updates the index of each directory in `config.paths`, and says
+/// what was done.
+fn run_index(config: &Config, out: &mut impl Write) -> Result<(), Box<dyn Error>> {
+    for dir in &config.paths {
+        if !metadata(dir)?.is_dir() {
+            return Err(format!("{}: not a directory", dir.display()).into());
+        }
+    }
+    for dir in &config.paths {
+        let update = index::update(dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+        for error in &update.errors {
+            eprintln!("minigrep: {error}");
+        }
+        write!(
+            out,
+            "{}: {} files indexed, {} read, {} removed",
+            dir.display(),
+            update.files,
+            update.read,
+            update.removed
+        )?;
+        if update.rebuilt {
+            write!(out, " (the old index was corrupt and has been rebuilt)")?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+/// What skips the files that the indexes say cannot match, reporting the
+/// indexes it cannot use to `unusable`. Nothing is skipped when the output
+/// needs every file, as with `-v`, `-c` and `-L`, or when files are not
+/// decompressed the way the index read them.
+fn prefilter(config: &Config, patterns: &[String], matcher: &Matcher, unusable: impl FnMut(String)) -> Prefilter {
+    if config.invert
+        || matches!(config.output, OutputMode::Count | OutputMode::FilesWithoutMatch)
+        || config.decompress != Decompress::Auto
+    {
+        return Prefilter::default();
+    }
+    Prefilter::new(&config.paths, &matcher.required(patterns), unusable)
+}
+
+/// The metadata of `path`, given on the command line, or an error naming
+/// it.
+pub(crate) fn metadata(path: &Path) -> Result<fs::Metadata, String> {
+    fs::metadata(path).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Tells `sink` how the search of the file at `path` ended.
+fn finish(sink: &mut dyn OutputSink, path: &Path, result: io::Result<Summary>) -> io::Result<()> {
+    match result {
+        Ok(summary) => {
+            if summary.binary {
+                sink.binary(path)?;
+            }
+            sink.end(path, summary.selected)
+        }
+        Err(err) => sink.error(&format!("{}: {err}", path.display())),
+    }
+}
+
+/// What searching a file on a worker thread finds, as it finds it: the
+/// file, the lines selected and their context, and how the search ended.
+enum Found {
+    Begin(PathBuf),
+    Line(Line),
+    End(io::Result<Summary>),
+    /// The walk could not go on; the message names the path.
+    Error(String),
+}
+
+/// How many lines of a file were selected, and whether it was binary with
+/// some selected.
+#[derive(Debug, Default)]
+struct Summary {
+    selected: usize,
+    binary: bool,
+}
+
+/// Where a search is in a file: the lines held back as context, and what
+/// was found so far.
+struct Scan {
+    summary: Summary,
+    /// Whether the file has a NUL, as far as it was read.
+    binary: bool,
+    before: VecDeque<Line>,
+    /// How many more lines after the last selected one are context.
+    after: usize,
+    /// The number of the last line searched.
+    number: usize,
+    /// The byte offset of the next line.
+    offset: usize,
+}
+
+/// Everything the worker threads need to search a file.
+struct Searcher {
+    matcher: Matcher,
+    invert: bool,
+    text: bool,
+    decompress: Decompress,
+    /// Whether every selected line must be counted, even in binary files.
+    count: bool,
+    before_context: usize,
+    after_context: usize,
+}
+
+impl Searcher {
+    fn new(config: &Config, patterns: &[String]) -> Result<Searcher, Box<dyn Error>> {
+        // Context only makes sense where whole lines are printed in file order.
+        let context = !ranked(config) && matches!(config.output, OutputMode::Lines | OutputMode::Json);
+        Ok(Searcher {
+            matcher: Matcher::new(config, patterns)?,
+            invert: config.invert,
+            text: config.text,
+            decompress: config.decompress,
+            count: config.output == OutputMode::Count,
+            before_context: if context { config.before_context } else { 0 },
+            after_context: if context { config.after_context } else { 0 },
+        })
+    }
+
+    /// Searches the file at `path` a line at a time, handing `emit` each
+    /// selected line and its context as soon as they are known. An error from
+    /// `emit` stops the search and is returned as is; reading the file can
+    /// fail after some lines were emitted, which is the inner error.
+    fn search_file<E>(
+        &self,
+        path: &Path,
+        mut emit: impl FnMut(Line) -> Result<(), E>,
+    ) -> Result<io::Result<Summary>, E> {
+        let mut reader = match open(path, self.decompress) {
+            Ok(reader) => reader,
+            Err(err) => return Ok(Err(err)),
+        };
+        // Like grep, look for a NUL in the first block, and then in each line.
+        let mut scan = match reader.fill_buf() {
+            Ok(block) => self.scan(block.contains(&0)),
+            Err(err) => return Ok(Err(err)),
+        };
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+            match reader.read_until(b'\n', &mut buf) {
+                Ok(0) => break,
+                Ok(_) => {}
+                Err(err) => return Ok(Err(err)),
+            }
+            if !self.search_line(&mut scan, &buf, &mut emit)? {
+                break;
+            }
+        }
+        Ok(Ok(scan.summary))
+    }
+
+    /// The state of a search at the start of a file; `binary` says whether
+    /// its first block has a NUL.
+    fn scan(&self, binary: bool) -> Scan {
+        Scan {
+            summary: Summary::default(),
+            binary: binary && !self.text,
+            before: VecDeque::with_capacity(self.before_context),
+            after: 0,
+            number: 0,
+            offset: 0,
+        }
+    }
+
+    /// Searches `buf`, the next line of the file with its line ending if it
+    /// has one, handing `emit` the lines it selects along with their context.
+    /// Returns false once the rest of the file need not be searched, because
+    /// it is binary and matches.
+    fn search_line<E>(
+        &self,
+        scan: &mut Scan,
+        buf: &[u8],
+        emit: &mut impl FnMut(Line) -> Result<(), E>,
+    ) -> Result<bool, E> {
+        scan.number += 1;
+        let start = scan.offset;
+        scan.offset += buf.len();
+        let mut bytes = buf;
+        if let Some(rest) = bytes.strip_suffix(b"\n") {
+            bytes = rest.strip_suffix(b"\r").unwrap_or(rest);
+        }
+        scan.binary |= !self.text && bytes.contains(&0);
+
+        let text = String::from_utf8_lossy(bytes);
+        let spans = self.matcher.spans(&text);
+        let selected = spans.is_empty() == self.invert;
+        scan.summary.selected += usize::from(selected);
+        if scan.binary {
+            scan.summary.binary |= selected;
+            return Ok(!scan.summary.binary || self.count);
+        }
+        if !selected && scan.after == 0 && self.before_context == 0 {
+            return Ok(true);
+        }
+
+        let line = Line {
+            number: scan.number,
+            offset: start,
+            text: text.into_owned(),
+            spans: if self.invert { Vec::new() } else { spans },
+            selected,
+        };
+        if selected {
+            for context in scan.before.drain(..) {
+                emit(context)?;
+            }
+            emit(line)?;
+            scan.after = self.after_context;
+        } else if scan.after > 0 {
+            emit(line)?;
+            scan.after -= 1;
+        } else {
+            if scan.before.len() == self.before_context {
+                scan.before.pop_front();
+            }
+            scan.before.push_back(line);
+        }
+        Ok(true)
+    }
+}
+
+/// Opens the file at `path` to be read, through a [`Decoder`] if
+/// `decompress` says it is compressed.
+fn open(path: &Path, decompress: Decompress) -> io::Result<Box<dyn BufRead>> {
+    let mut reader = BufReader::with_capacity(64 * 1024, File::open(path)?);
+    let format = match decompress {
+        Decompress::Auto => Format::detect(reader.fill_buf()?),
+        Decompress::Always => Some(Format::guess(reader.fill_buf()?)),
+        Decompress::Never => None,
+    };
+    Ok(match format {
+        Some(format) => Box::new(Decoder::new(reader, format)),
+        None => Box::new(reader),
+    })
+}
+
+/// The patterns compiled once for all files, according to the `Config`.
+enum Matcher {
+    /// One pattern, matched case-sensitively.
+    Literal(String),
+    /// Any number of patterns, matched in one pass.
+    Literals(AhoCorasick),
+    Regex(Vec<Regex>),
+    Fuzzy(Vec<Fuzzy>),
+}
+
+/// The patterns given in `config`, then those in its pattern files.
+fn read_patterns(config: &Config) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut patterns = config.patterns.clone();
+    for path in &config.pattern_files {
+        let text = fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+        patterns.extend(text.lines().map(String::from));
+    }
+    Ok(patterns)
+}
+
+impl Matcher {
+    fn new(config: &Config, patterns: &[String]) -> Result<Matcher, Box<dyn Error>> {
+        if let Some(max_edits) = config.fuzzy {
+            let fuzzy = patterns
+                .iter()
+                .map(|pattern| {
+                    Fuzzy::new(pattern, max_edits, config.ignore_case).ok_or_else(|| {
+                        format!("--fuzzy {max_edits} allows deleting all of `{pattern}`, so every line would match")
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+            return Ok(Matcher::Fuzzy(fuzzy));
+        }
+        Ok(match config.pattern_kind {
+            PatternKind::Literal => match patterns {
+                [pattern] if !config.ignore_case => Matcher::Literal(pattern.clone()),
+                _ => Matcher::Literals(AhoCorasick::new(patterns, config.ignore_case)),
+            },
+            PatternKind::Regex => {
+                let compile = if config.ignore_case {
+                    Regex::case_insensitive
+                } else {
+                    Regex::new
+                };
+                let regexes = patterns.iter().map(|p| compile(p)).collect::<Result<_, _>>()?;
+                Matcher::Regex(regexes)
+            }
+        })
+    }
+
+    /// What every matching line contains, given the `patterns` the matcher
+    /// was built from.
+    fn required(&self, patterns: &[String]) -> Required {
+        match self {
+            Matcher::Regex(regexes) => Required::any_of(regexes.iter().map(|regex| regex.required().clone())),
+            Matcher::Fuzzy(fuzzy) => Required::any_of(fuzzy.iter().map(|fuzzy| fuzzy.required().clone())),
+            _ => Required::any_of(patterns.iter().map(|pattern| Required::contains(fold(pattern)))),
+        }
+    }
+
+    /// Where the patterns match in `line`, and which did.
+    fn spans(&self, line: &str) -> Vec<PatternMatch> {
+        match self {
+            Matcher::Literal(query) => literal_spans(query, line)
+                .into_iter()
+                .map(|bytes| PatternMatch { bytes, pattern: 0 })
+                .collect(),
+            Matcher::Literals(automaton) => automaton.find_all(line),
+            Matcher::Regex(regexes) => leftmost_longest(regexes.iter().enumerate().flat_map(|(pattern, regex)| {
+                regex_spans(regex, line)
+                    .into_iter()
+                    .map(move |bytes| PatternMatch { bytes, pattern })
+            })),
+            Matcher::Fuzzy(fuzzy) => leftmost_longest(fuzzy.iter().enumerate().flat_map(|(pattern, fuzzy)| {
+                fuzzy.find_all(line).into_iter().map(move |m| PatternMatch { bytes: m.bytes, pattern })
+            })),
+        }
+    }
+
+    /// How many edits `line` is from matching, for ranking: 0 unless the
+    /// patterns are fuzzy.
+    fn distance(&self, line: &str) -> usize {
+        match self {
+            Matcher::Fuzzy(fuzzy) => fuzzy.iter().filter_map(|fuzzy| fuzzy.distance(line)).min().unwrap_or(usize::MAX),
+            _ => 0,
+        }
+    }
+}
+
+/// Keeps the leftmost-longest of the matches of several patterns, without
+/// overlaps.
+fn leftmost_longest(spans: impl Iterator<Item = PatternMatch>) -> Vec<PatternMatch> {
+    let mut spans: Vec<PatternMatch> = spans.collect();
+    spans.sort_by_key(|m| (m.bytes.start, Reverse(m.bytes.end)));
+    let mut next = 0;
+    spans.retain(|m| {
+        let keep = m.bytes.start >= next;
+        if keep {
+            next = m.bytes.end.max(m.bytes.start + 1);
+        }
+        keep
+    });
+    spans
+}
+
+/// A line that contains the query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match<'a> {
+    /// The line, without its line ending.
+    pub line: &'a str,
+    /// Line number, counting from 1.
+    pub line_number: usize,
+    /// Where `line` sits in the searched text, in bytes.
+    pub bytes: Range<usize>,
+    /// Where the query matched within `line`, in bytes.
+    pub spans: Vec<Range<usize>>,
+}
+
+/// Splits `contents` like [`str::lines`], also returning each line's number
+/// and the byte offset of its start.
+fn lines_of(contents: &str) -> impl Iterator<Item = (usize, usize, &str)> {
+    let mut offset = 0;
+    contents
+        .split_inclusive('\n')
+        .enumerate()
+        .map(move |(i, raw)| {
+            let start = offset;
+            offset += raw.len();
+            let line = match raw.strip_suffix('\n') {
+                Some(line) => line.strip_suffix('\r').unwrap_or(line),
+                None => raw,
+            };
+            (i + 1, start, line)
+        })
+}
+
+/// Returns the lines for which `spans` finds at least one span.
+fn search_with<'a>(contents: &'a str, spans: impl Fn(&str) -> Vec<Range<usize>>) -> Vec<Match<'a>> {
+    lines_of(contents)
+        .filter_map(|(line_number, offset, line)| {
+            let spans = spans(line);
+            (!spans.is_empty()).then(|| Match {
+                line,
+                line_number,
+                bytes: offset..offset + line.len(),
+                spans,
+            })
+        })
+        .collect()
+}
+
+pub fn search<'a>(query: &str, contents: &'a str) -> Vec<Match<'a>> {
+    search_with(contents, |line| literal_spans(query, line))
+}
+
+/// Like [`search`], comparing the Unicode case folding of the query and of
+/// the lines, see [`aho_corasick`].
+pub fn search_case_insensitive<'a>(query: &str, contents: &'a str) -> Vec<Match<'a>> {
+    let automaton = AhoCorasick::new(&[query], true);
+    search_with(contents, |line| {
+        automaton.find_all(line).into_iter().map(|m| m.bytes).collect()
+    })
+}
+
+pub fn search_regex<'a>(regex: &Regex, contents: &'a str) -> Vec<Match<'a>> {
+    search_with(contents, |line| regex_spans(regex, line))
+}
+
+/// Like [`search`], finding the lines where some part is within `max_edits`
+/// edits of `query`, see [`fuzzy`]. The closest lines come first.
+///
+/// # Panics
+///
+/// If `max_edits` is not less than the number of characters in `query`, as
+/// every line would match.
+pub fn search_fuzzy<'a>(query: &str, contents: &'a str, max_edits: usize) -> Vec<Match<'a>> {
+    let fuzzy = Fuzzy::new(query, max_edits, false).expect("more edits than the query has characters");
+    let mut matches = search_with(contents, |line| fuzzy.find_all(line).into_iter().map(|m| m.bytes).collect());
+    matches.sort_by_cached_key(|m| fuzzy.distance(m.line));
+    matches
+}
+
+fn literal_spans(query: &str, line: &str) -> Vec<Range<usize>> {
+    line.match_indices(query)
+        .map(|(start, found)| start..start + found.len())
+        .collect()
+}
+
+fn regex_spans(regex: &Regex, line: &str) -> Vec<Range<usize>> {
+    // The DFA answers whether there is a match much faster than the NFA
+    // finds where.
+    if !regex.is_match(line) {
+        return Vec::new();
+    }
+    regex.find_iter(line).map(|m| m.start()..m.end()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn lines<'a>(matches: Vec<Match<'a>>) -> Vec<&'a str> {
+        matches.into_iter().map(|m| m.line).collect()
+    }
+
+    #[test]
+    fn case_sensitive() {
+        let query = "duct";
+        let contents = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Duct tape.";
+
+        assert_eq!(
+            vec!["safe, fast, productive."],
+            lines(search(query, contents))
+        );
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let query = "rUsT";
+        let contents = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Trust me.";
+
+        assert_eq!(
+            vec!["Rust:", "Trust me."],
+            lines(search_case_insensitive(query, contents))
+        );
+    }
+
+    #[test]
+    fn regex() {
+        let contents = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Trust me.";
+
+        let regex = Regex::new(r"^\w+ \w+\.$").unwrap();
+        assert_eq!(
+            vec!["Pick three.", "Trust me."],
+            lines(search_regex(&regex, contents))
+        );
+
+        let regex = Regex::case_insensitive("^(pick|rust)").unwrap();
+        assert_eq!(
+            vec!["Rust:", "Pick three."],
+            lines(search_regex(&regex, contents))
+        );
+    }
+
+    #[test]
+    fn fuzzy() {
+        let contents = "\
+a frog
+fog
+a bog
+froggy
+toad";
+
+        assert_eq!(vec!["a frog", "froggy", "fog"], lines(search_fuzzy("frog", contents, 1)));
+        assert_eq!(vec!["a frog", "froggy", "fog", "a bog"], lines(search_fuzzy("frog", contents, 2)));
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn match_locations() {
+        let contents = "Rust:\r\nsafe, fast, productive.\nfast, faster, fastest\n";
+
+        assert_eq!(
+            search("fast", contents),
+            vec![
+                Match {
+                    line: "safe, fast, productive.",
+                    line_number: 2,
+                    bytes: 7..30,
+                    spans: vec![6..10],
+                },
+                Match {
+                    line: "fast, faster, fastest",
+                    line_number: 3,
+                    bytes: 31..52,
+                    spans: vec![0..4, 6..10, 14..18],
+                },
+            ]
+        );
+
+        let matches = search_case_insensitive("RUST", contents);
+        assert_eq!(matches[0].bytes, 0..5);
+        assert_eq!(matches[0].spans, vec![0..4]);
+        assert_eq!(search_case_insensitive("STRASSE", "Straße")[0].spans, vec![0..7]);
+        assert_eq!(search_case_insensitive("ÉTÉ", "l'été")[0].spans, vec![2..7]);
+
+        let regex = Regex::new("fast(er|est)?").unwrap();
+        assert_eq!(
+            search_regex(&regex, contents)[1].spans,
+            vec![0..4, 6..12, 14..21]
+        );
+    }
+
+    #[test]
+    fn build_needs_query_and_file_path() {
+        // Without the environment or a configuration file.
+        let build = |list: &[&str]| {
+            let args = list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+            Config::build_from(args.into_iter(), |_| None, None)
+        };
+
+        assert_eq!(
+            build(&["minigrep"]).err(),
+            Some(ConfigError::MissingQuery)
+        );
+        assert_eq!(
+            build(&["minigrep", "to"]).err(),
+            Some(ConfigError::MissingPath)
+        );
+
+        let config = build(&["minigrep", "to", "poem.txt"]).unwrap();
+        assert_eq!(config.patterns, ["to"]);
+        assert_eq!(config.paths, [PathBuf::from("poem.txt")]);
+        assert_eq!(config.pattern_kind, PatternKind::Literal);
+
+        let config = build(&["minigrep", "-E", "--", "-E", "poem.txt"]).unwrap();
+        assert_eq!(config.patterns, ["-E"]);
+        assert_eq!(config.pattern_kind, PatternKind::Regex);
+
+        let config = build(&["minigrep", "to", "src", "--hidden", "poem.txt"]).unwrap();
+        assert_eq!(config.paths, [PathBuf::from("src"), PathBuf::from("poem.txt")]);
+        assert!(config.hidden);
+
+        let config = build(&["minigrep", "-j", "3", "to", "-j2", "src"]).unwrap();
+        assert_eq!(config.jobs, 2);
+        assert_eq!(config.paths, [PathBuf::from("src")]);
+        assert_eq!(
+            build(&["minigrep", "-j0", "to", "src"]).unwrap_err().to_string(),
+            "-j needs a positive number of threads, not `0`"
+        );
+
+        let config = build(&["minigrep", "-n", "-C", "2", "-A1", "to", "src"]).unwrap();
+        assert!(config.line_numbers && !config.byte_offsets);
+        assert_eq!((config.before_context, config.after_context), (2, 1));
+        assert_eq!(
+            build(&["minigrep", "-B", "x", "to", "src"]).unwrap_err().to_string(),
+            "-B needs a number of lines, not `x`"
+        );
+
+        let config = build(&["minigrep", "-v", "-c", "to", "-l", "src"]).unwrap();
+        assert!(config.invert);
+        assert_eq!(config.output, OutputMode::FilesWithMatches);
+
+        let config = build(&["minigrep", "-e", "to", "-eTo", "-f", "list", "src"]).unwrap();
+        assert_eq!(config.patterns, ["to", "To"]);
+        assert_eq!(config.pattern_files, [PathBuf::from("list")]);
+        assert_eq!(config.paths, [PathBuf::from("src")]);
+        assert_eq!(
+            build(&["minigrep", "to", "src", "-e"]).err(),
+            Some(ConfigError::MissingValue("-e".to_string()))
+        );
+
+        let config = build(&["minigrep", "--replace=$0s", "--dry-run", "to", "src"]).unwrap();
+        assert_eq!(config.replace.as_deref(), Some("$0s"));
+        assert!(config.dry_run);
+        assert_eq!(
+            build(&["minigrep", "--dry-run", "to", "src"]).err(),
+            Some(ConfigError::Requires("--dry-run", "--replace"))
+        );
+
+        assert!(build(&["minigrep", "--follow", "-n", "to", "log"]).unwrap().follow);
+        assert_eq!(
+            build(&["minigrep", "--follow", "-l", "to", "log"]).err(),
+            Some(ConfigError::Conflict("--follow", "-l"))
+        );
+        assert_eq!(
+            build(&["minigrep", "--fuzzy=1", "--follow", "to", "log"]).err(),
+            Some(ConfigError::Conflict("--follow", "--fuzzy"))
+        );
+
+        let config = build(&["minigrep", "--tui", "-e", "to", "src", "tests"]).unwrap();
+        assert!(config.tui);
+        assert_eq!(config.patterns, ["to"]);
+        assert_eq!(config.paths, [PathBuf::from("src"), PathBuf::from("tests")]);
+        assert_eq!(build(&["minigrep", "--tui", "src"]).unwrap().patterns, [] as [String; 0]);
+        assert_eq!(
+            build(&["minigrep", "--tui", "--json", "src"]).err(),
+            Some(ConfigError::Conflict("--tui", "--json"))
+        );
+    }
+
+    /// Records what `run_with` hands it.
+    #[derive(Default)]
+    struct Recorder(Vec<String>);
+
+    impl OutputSink for Recorder {
+        fn begin(&mut self, path: &Path) -> io::Result<()> {
+            self.0.push(format!("begin {}", path.display()));
+            Ok(())
+        }
+
+        fn line(&mut self, _path: &Path, line: &Line) -> io::Result<()> {
+            let spans: Vec<_> = line.spans.iter().map(|m| (m.bytes.clone(), m.pattern)).collect();
+            self.0.push(format!("{} {} {:?} {}", line.number, line.text, spans, line.selected));
+            Ok(())
+        }
+
+        fn end(&mut self, _path: &Path, selected: usize) -> io::Result<()> {
+            self.0.push(format!("end {selected}"));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn custom_sinks() {
+        let poem = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("poem.txt");
+        let args = ["minigrep", "-A1", "frog", poem.to_str().unwrap()];
+        // Without the environment or a configuration file.
+        let config = Config::build_from(args.iter().map(|s| s.to_string()), |_| None, None).unwrap();
+        let mut recorder = Recorder::default();
+        run_with(&config, &mut recorder).unwrap();
+        assert_eq!(
+            recorder.0,
+            [
+                format!("begin {}", poem.display()),
+                "7 How public, like a frog [(19..23, 0)] true".to_string(),
+                "8 To tell your name the livelong day [] false".to_string(),
+                "end 1".to_string(),
+            ]
+        );
+    }
+}
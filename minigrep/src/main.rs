@@ -0,0 +1,20 @@
+use std::env;
+use std::process;
+
+use minigrep::{Config, ConfigError};
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        if let ConfigError::Help | ConfigError::Version = err {
+            println!("{err}");
+            process::exit(0);
+        }
+        eprintln!("Problem parsing arguments: {err}");
+        process::exit(1);
+    });
+
+    if let Err(e) = minigrep::run(config) {
+        eprintln!("Application error: {e}");
+        process::exit(1);
+    }
+}
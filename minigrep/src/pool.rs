@@ -0,0 +1,82 @@
+//! Searching files on a pool of worker threads.
+//!
+//! The walk runs on a thread of its own. It hands the files it finds to the
+//! workers over one channel, each with a channel of its own for the worker
+//! to send the lines it finds back over, and hands those channels to the
+//! caller, in the order it found the files, over another. Lines are thus
+//! passed on in file order, so the output is the same as when searching on
+//! one thread, and those of the file whose turn it is as they are found.
+//!
+//! The channels are bounded, so that the walk and the workers get no more
+//! than a few files per worker, and [`LINES_AHEAD`] lines per file, ahead of
+//! the file being passed on, however long that one takes.
+
+use std::io;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::Mutex;
+use std::thread;
+
+use crate::index::Prefilter;
+use crate::walk::Walk;
+use crate::{Found, Searcher};
+
+/// How many lines of a file a worker finds before it waits for the file's
+/// turn.
+const LINES_AHEAD: usize = 256;
+
+/// Searches the files below `paths` that `prefilter` does not skip with
+/// `jobs` worker threads, calling `found` with what is found in each file in
+/// the order the walk found the files.
+pub(crate) fn search(
+    paths: &[PathBuf],
+    hidden: bool,
+    jobs: usize,
+    prefilter: &Prefilter,
+    searcher: &Searcher,
+    mut found: impl FnMut(Found) -> io::Result<()>,
+) -> io::Result<()> {
+    let (job_tx, job_rx) = mpsc::sync_channel::<(PathBuf, SyncSender<Found>)>(jobs);
+    let job_rx = Mutex::new(job_rx);
+    let (file_tx, file_rx) = mpsc::sync_channel::<Receiver<Found>>(jobs);
+
+    thread::scope(|scope| {
+        scope.spawn(move || {
+            let files = Walk::new(paths, hidden).filter(|file| !file.as_ref().is_ok_and(|path| prefilter.skips(path)));
+            for file in files {
+                let (result_tx, result_rx) = mpsc::sync_channel(LINES_AHEAD);
+                let sent = match file {
+                    Ok(path) => job_tx.send((path, result_tx)).is_ok(),
+                    Err(err) => result_tx.send(Found::Error(err.to_string())).is_ok(),
+                };
+                if !sent || file_tx.send(result_rx).is_err() {
+                    break;
+                }
+            }
+        });
+
+        for _ in 0..jobs {
+            let job_rx = &job_rx;
+            scope.spawn(move || loop {
+                let job = job_rx.lock().unwrap().recv();
+                let Ok((path, result_tx)) = job else { break };
+                // Sending fails once the search has stopped.
+                if result_tx.send(Found::Begin(path.clone())).is_err() {
+                    continue;
+                }
+                if let Ok(result) = searcher.search_file(&path, |line| result_tx.send(Found::Line(line))) {
+                    let _ = result_tx.send(Found::End(result));
+                }
+            });
+        }
+
+        // A file whose lines stop short is one whose worker panicked, which
+        // the scope passes on once the others are done.
+        for result_rx in file_rx {
+            for result in result_rx {
+                found(result)?;
+            }
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,1325 @@
+//! A small regular-expression engine for `minigrep -E`.
+//!
+//! A pattern is parsed into a syntax tree and compiled to a Thompson NFA.
+//! Whether a line matches at all is answered by a DFA built from that NFA by
+//! subset construction. Where it matches, and what the groups captured, comes
+//! from running the NFA as a Pike VM. Patterns whose DFA would grow too large
+//! use the Pike VM for both.
+//!
+//! Supported syntax:
+//!
+//! - literals, `.` (anything but a newline) and `\` escapes of punctuation
+//! - classes such as `[a-z_]` and `[^0-9]`, plus `\d`, `\w`, `\s` and their
+//!   negations `\D`, `\W`, `\S` (ASCII only)
+//! - alternation `a|b`
+//! - groups: capturing `(...)`, named `(?P<name>...)` or `(?<name>...)`, and
+//!   non-capturing `(?:...)`
+//! - anchors `^` and `$` for the start and end of the text
+//! - repetition `*`, `+`, `?`, `{n}`, `{n,}` and `{n,m}`, made lazy by a
+//!   trailing `?`
+
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap};
+use std::error;
+use std::fmt;
+
+use crate::aho_corasick::fold as fold_case;
+use crate::index::Required;
+
+/// Largest count allowed in `{n,m}`.
+const MAX_REPEAT: u32 = 1000;
+
+/// Largest compiled program, in instructions.
+const MAX_PROGRAM: usize = 100_000;
+
+/// Largest DFA built before falling back to the Pike VM.
+const MAX_DFA_STATES: usize = 2048;
+
+/// Largest set of strings tracked when working out what a match contains.
+const MAX_EXACT: usize = 64;
+
+/// Why a pattern could not be compiled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    /// Offset of the problem in the pattern, in characters.
+    pub position: usize,
+    pub message: &'static str,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid regex: {} at position {}", self.message, self.position)
+    }
+}
+
+impl error::Error for Error {}
+
+/// A compiled regular expression.
+#[derive(Debug, Clone)]
+pub struct Regex {
+    pattern: String,
+    program: Vec<Inst>,
+    /// One entry per group, the whole match first; `Some` for named groups.
+    names: Vec<Option<String>>,
+    dfa: Option<Dfa>,
+    required: Required,
+}
+
+impl Regex {
+    pub fn new(pattern: &str) -> Result<Regex, Error> {
+        Regex::compile(pattern, false)
+    }
+
+    /// Compiles `pattern` so that letters match either case.
+    pub fn case_insensitive(pattern: &str) -> Result<Regex, Error> {
+        Regex::compile(pattern, true)
+    }
+
+    fn compile(pattern: &str, ignore_case: bool) -> Result<Regex, Error> {
+        let mut parser = Parser {
+            chars: pattern.chars().collect(),
+            pos: 0,
+            ignore_case,
+            names: vec![None],
+        };
+        let tree = parser.alternation()?;
+        if parser.pos < parser.chars.len() {
+            return Err(parser.error("unmatched `)`"));
+        }
+
+        let mut compiler = Compiler { program: Vec::new() };
+        compiler.emit(Inst::Save(0));
+        compiler.node(&tree).map_err(|message| Error { position: 0, message })?;
+        compiler.emit(Inst::Save(1));
+        compiler.emit(Inst::Match);
+
+        let dfa = Dfa::build(&compiler.program);
+        Ok(Regex {
+            pattern: pattern.to_string(),
+            program: compiler.program,
+            names: parser.names,
+            dfa,
+            required: Literals::of(&tree).into_required(),
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// What every match contains, for skipping files with the
+    /// [index](crate::index).
+    pub(crate) fn required(&self) -> &Required {
+        &self.required
+    }
+
+    /// Number of groups, counting the whole match as group 0.
+    pub fn captures_len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        match &self.dfa {
+            Some(dfa) => dfa.is_match(text),
+            None => self.pike(text, 0).is_some(),
+        }
+    }
+
+    /// Returns the leftmost match, preferring earlier alternatives and, for
+    /// repetitions, longer matches unless they are lazy.
+    pub fn find<'t>(&self, text: &'t str) -> Option<Match<'t>> {
+        self.captures(text).and_then(|caps| caps.get(0))
+    }
+
+    /// Returns the successive non-overlapping matches in `text`. An empty
+    /// match right where the previous match ended is skipped.
+    pub fn find_iter<'r, 't>(&'r self, text: &'t str) -> Matches<'r, 't> {
+        Matches {
+            regex: self,
+            text,
+            pos: 0,
+            last_end: None,
+        }
+    }
+
+    pub fn captures<'t>(&self, text: &'t str) -> Option<Captures<'t, '_>> {
+        self.captures_at(text, 0)
+    }
+
+    /// Like [`captures`](Regex::captures), for the leftmost match starting at
+    /// or after byte `start`. The text before `start` still counts for `^`.
+    pub fn captures_at<'t>(&self, text: &'t str, start: usize) -> Option<Captures<'t, '_>> {
+        let slots = self.pike(text, start)?;
+        Some(Captures {
+            text,
+            slots,
+            names: &self.names,
+        })
+    }
+
+    /// Runs the NFA over `text` from byte `start`, trying every later start
+    /// position until one matches. Returns the capture slots of the match.
+    fn pike(&self, text: &str, start: usize) -> Option<Vec<Option<usize>>> {
+        let mut current = Threads::new(self.program.len());
+        let mut next = Threads::new(self.program.len());
+        let mut stack = Vec::new();
+        let mut slots = vec![None; self.names.len() * 2];
+        let mut matched = None;
+        let mut pos = start;
+        loop {
+            if matched.is_none() {
+                // A match starting here ranks below every thread started earlier.
+                self.add(&mut current, 0, &mut slots, pos, text.len(), &mut stack);
+            } else if current.pcs.is_empty() {
+                break;
+            }
+            let c = text[pos..].chars().next();
+            for i in 0..current.pcs.len() {
+                let pc = current.pcs[i];
+                match &self.program[pc] {
+                    Inst::Match => {
+                        // Threads after this one have lower priority.
+                        matched = Some(current.slots[i].clone());
+                        break;
+                    }
+                    Inst::Set(ranges) => {
+                        if let Some(c) = c.filter(|&c| contains(ranges, c)) {
+                            let mut thread = std::mem::take(&mut current.slots[i]);
+                            let at = pos + c.len_utf8();
+                            self.add(&mut next, pc + 1, &mut thread, at, text.len(), &mut stack);
+                        }
+                    }
+                    _ => unreachable!("only sets and matches are queued"),
+                }
+            }
+            let Some(c) = c else { break };
+            pos += c.len_utf8();
+            std::mem::swap(&mut current, &mut next);
+            next.clear();
+        }
+        matched
+    }
+
+    /// Queues the threads reachable from `pc` without consuming input, in
+    /// priority order.
+    fn add(
+        &self,
+        list: &mut Threads,
+        pc: usize,
+        slots: &mut [Option<usize>],
+        pos: usize,
+        len: usize,
+        stack: &mut Vec<Job>,
+    ) {
+        stack.push(Job::Visit(pc));
+        while let Some(job) = stack.pop() {
+            let pc = match job {
+                Job::Visit(pc) => pc,
+                Job::Restore(slot, value) => {
+                    slots[slot] = value;
+                    continue;
+                }
+            };
+            if list.seen[pc] {
+                continue;
+            }
+            list.seen[pc] = true;
+            list.touched.push(pc);
+            match &self.program[pc] {
+                Inst::Jmp(to) => stack.push(Job::Visit(*to)),
+                Inst::Split(first, second) => {
+                    stack.push(Job::Visit(*second));
+                    stack.push(Job::Visit(*first));
+                }
+                Inst::Save(slot) => {
+                    stack.push(Job::Restore(*slot, slots[*slot]));
+                    slots[*slot] = Some(pos);
+                    stack.push(Job::Visit(pc + 1));
+                }
+                Inst::Start if pos == 0 => stack.push(Job::Visit(pc + 1)),
+                Inst::End if pos == len => stack.push(Job::Visit(pc + 1)),
+                Inst::Start | Inst::End => {}
+                Inst::Set(_) | Inst::Match => {
+                    list.pcs.push(pc);
+                    list.slots.push(slots.to_vec());
+                }
+            }
+        }
+    }
+}
+
+/// Where a regex or one of its groups matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'t> {
+    text: &'t str,
+    start: usize,
+    end: usize,
+}
+
+impl<'t> Match<'t> {
+    /// Byte offset of the start of the match.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Byte offset just past the end of the match.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    pub fn as_str(&self) -> &'t str {
+        &self.text[self.start..self.end]
+    }
+}
+
+/// The iterator returned by [`Regex::find_iter`].
+#[derive(Debug)]
+pub struct Matches<'r, 't> {
+    regex: &'r Regex,
+    text: &'t str,
+    pos: usize,
+    last_end: Option<usize>,
+}
+
+impl<'t> Iterator for Matches<'_, 't> {
+    type Item = Match<'t>;
+
+    fn next(&mut self) -> Option<Match<'t>> {
+        loop {
+            if self.pos > self.text.len() {
+                return None;
+            }
+            let slots = self.regex.pike(self.text, self.pos)?;
+            let (start, end) = (slots[0]?, slots[1]?);
+            let step = self.text[end..].chars().next().map_or(1, char::len_utf8);
+            if start == end && self.last_end == Some(end) {
+                self.pos = end + step;
+                continue;
+            }
+            self.pos = if start == end { end + step } else { end };
+            self.last_end = Some(end);
+            return Some(Match {
+                text: self.text,
+                start,
+                end,
+            });
+        }
+    }
+}
+
+/// The groups of one match. Group 0 is the whole match.
+#[derive(Debug, Clone)]
+pub struct Captures<'t, 'r> {
+    text: &'t str,
+    slots: Vec<Option<usize>>,
+    names: &'r [Option<String>],
+}
+
+impl<'t> Captures<'t, '_> {
+    /// Returns group `i`, or `None` if it took no part in the match.
+    pub fn get(&self, i: usize) -> Option<Match<'t>> {
+        match (self.slots.get(2 * i)?, self.slots.get(2 * i + 1)?) {
+            (Some(start), Some(end)) => Some(Match {
+                text: self.text,
+                start: *start,
+                end: *end,
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self, name: &str) -> Option<Match<'t>> {
+        let i = self.names.iter().position(|n| n.as_deref() == Some(name))?;
+        self.get(i)
+    }
+
+    /// Number of groups, counting the whole match.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+// Syntax
+
+/// Sorted, non-overlapping, non-adjacent inclusive character ranges.
+type Ranges = Vec<(char, char)>;
+
+enum Node {
+    Empty,
+    Set(Ranges),
+    Start,
+    End,
+    Group(Box<Node>, Option<usize>),
+    Concat(Vec<Node>),
+    Alternate(Vec<Node>),
+    Repeat {
+        node: Box<Node>,
+        min: u32,
+        max: Option<u32>,
+        greedy: bool,
+    },
+}
+
+/// What a `\` escape stands for.
+enum Escape {
+    Char(char),
+    Set(Ranges),
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    ignore_case: bool,
+    names: Vec<Option<String>>,
+}
+
+impl Parser {
+    fn error(&self, message: &'static str) -> Error {
+        Error {
+            position: self.pos,
+            message,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.peek() == Some(c);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn alternation(&mut self) -> Result<Node, Error> {
+        let mut branches = vec![self.concat()?];
+        while self.eat('|') {
+            branches.push(self.concat()?);
+        }
+        Ok(if branches.len() == 1 {
+            branches.pop().unwrap()
+        } else {
+            Node::Alternate(branches)
+        })
+    }
+
+    fn concat(&mut self) -> Result<Node, Error> {
+        let mut items = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let atom = self.atom()?;
+            items.push(self.repetition(atom)?);
+        }
+        Ok(match items.len() {
+            0 => Node::Empty,
+            1 => items.pop().unwrap(),
+            _ => Node::Concat(items),
+        })
+    }
+
+    fn atom(&mut self) -> Result<Node, Error> {
+        let c = self.chars[self.pos];
+        if matches!(c, '*' | '+' | '?') {
+            return Err(self.error("repetition with nothing to repeat"));
+        }
+        self.pos += 1;
+        Ok(match c {
+            '.' => Node::Set(complement(&[('\n', '\n')])),
+            '^' => Node::Start,
+            '$' => Node::End,
+            '(' => self.group()?,
+            '[' => Node::Set(self.class()?),
+            '\\' => match self.escape()? {
+                Escape::Char(c) => Node::Set(self.literal(c)),
+                Escape::Set(ranges) => Node::Set(ranges),
+            },
+            c => Node::Set(self.literal(c)),
+        })
+    }
+
+    fn literal(&self, c: char) -> Ranges {
+        let mut ranges = vec![(c, c)];
+        if self.ignore_case {
+            fold(&mut ranges);
+        }
+        ranges
+    }
+
+    fn group(&mut self) -> Result<Node, Error> {
+        let open = self.pos - 1;
+        let index = if self.eat('?') {
+            if self.eat(':') {
+                None
+            } else if (self.eat('P') && self.eat('<')) || self.eat('<') {
+                let name = self.group_name()?;
+                self.names.push(Some(name));
+                Some(self.names.len() - 1)
+            } else {
+                return Err(self.error("unsupported group syntax"));
+            }
+        } else {
+            self.names.push(None);
+            Some(self.names.len() - 1)
+        };
+        let body = self.alternation()?;
+        if !self.eat(')') {
+            return Err(Error {
+                position: open,
+                message: "unclosed group",
+            });
+        }
+        Ok(Node::Group(Box::new(body), index))
+    }
+
+    fn group_name(&mut self) -> Result<String, Error> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_alphanumeric() || c == '_') {
+            self.pos += 1;
+        }
+        let name: String = self.chars[start..self.pos].iter().collect();
+        if name.is_empty() || !self.eat('>') {
+            return Err(self.error("invalid group name"));
+        }
+        if self.names.iter().any(|n| n.as_deref() == Some(&name)) {
+            return Err(Error {
+                position: start,
+                message: "duplicate group name",
+            });
+        }
+        Ok(name)
+    }
+
+    fn class(&mut self) -> Result<Ranges, Error> {
+        let open = self.pos - 1;
+        let negated = self.eat('^');
+        let mut ranges = Vec::new();
+        let mut first = true;
+        loop {
+            let Some(c) = self.peek() else {
+                return Err(Error {
+                    position: open,
+                    message: "unclosed character class",
+                });
+            };
+            self.pos += 1;
+            if c == ']' && !first {
+                break;
+            }
+            first = false;
+            let lo = match c {
+                '\\' => match self.escape()? {
+                    Escape::Char(c) => c,
+                    Escape::Set(set) => {
+                        ranges.extend(set);
+                        continue;
+                    }
+                },
+                c => c,
+            };
+            let is_range = self.peek() == Some('-')
+                && self.chars.get(self.pos + 1).is_some_and(|&c| c != ']');
+            if !is_range {
+                ranges.push((lo, lo));
+                continue;
+            }
+            self.pos += 1;
+            let hi = match self.chars[self.pos] {
+                '\\' => {
+                    self.pos += 1;
+                    match self.escape()? {
+                        Escape::Char(c) => c,
+                        Escape::Set(_) => return Err(self.error("invalid class range")),
+                    }
+                }
+                c => {
+                    self.pos += 1;
+                    c
+                }
+            };
+            if hi < lo {
+                return Err(self.error("invalid class range"));
+            }
+            ranges.push((lo, hi));
+        }
+        if self.ignore_case {
+            fold(&mut ranges);
+        }
+        let ranges = normalize(ranges);
+        Ok(if negated { complement(&ranges) } else { ranges })
+    }
+
+    /// Parses what follows a `\`.
+    fn escape(&mut self) -> Result<Escape, Error> {
+        let Some(c) = self.peek() else {
+            return Err(self.error("trailing backslash"));
+        };
+        const DIGIT: &[(char, char)] = &[('0', '9')];
+        const WORD: &[(char, char)] = &[('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')];
+        const SPACE: &[(char, char)] = &[('\t', '\r'), (' ', ' ')];
+        let escape = match c {
+            'd' => Escape::Set(DIGIT.to_vec()),
+            'w' => Escape::Set(WORD.to_vec()),
+            's' => Escape::Set(SPACE.to_vec()),
+            'D' => Escape::Set(complement(DIGIT)),
+            'W' => Escape::Set(complement(WORD)),
+            'S' => Escape::Set(complement(SPACE)),
+            'n' => Escape::Char('\n'),
+            'r' => Escape::Char('\r'),
+            't' => Escape::Char('\t'),
+            c if c.is_alphanumeric() => return Err(self.error("unsupported escape")),
+            c => Escape::Char(c),
+        };
+        self.pos += 1;
+        Ok(escape)
+    }
+
+    fn repetition(&mut self, mut node: Node) -> Result<Node, Error> {
+        loop {
+            let (min, max) = match self.peek() {
+                Some('*') => (0, None),
+                Some('+') => (1, None),
+                Some('?') => (0, Some(1)),
+                Some('{') => match self.counted()? {
+                    Some(bounds) => bounds,
+                    // Not a count after all: the `{` is a literal.
+                    None => return Ok(node),
+                },
+                _ => return Ok(node),
+            };
+            // Step over the operator, or the `}` that closes a count.
+            self.pos += 1;
+            let greedy = !self.eat('?');
+            node = Node::Repeat {
+                node: Box::new(node),
+                min,
+                max,
+                greedy,
+            };
+        }
+    }
+
+    /// Parses `{n}`, `{n,}` or `{n,m}` up to, but not past, the closing `}`.
+    /// Returns `None`, and moves nothing, if the braces do not hold a count.
+    fn counted(&mut self) -> Result<Option<(u32, Option<u32>)>, Error> {
+        let start = self.pos;
+        self.pos += 1;
+        let min = self.number();
+        let max = if self.eat(',') { self.number() } else { min };
+        let (Some(min), Some('}')) = (min, self.peek()) else {
+            self.pos = start;
+            return Ok(None);
+        };
+        if min > MAX_REPEAT || max.is_some_and(|max| max > MAX_REPEAT) {
+            return Err(self.error("repetition count too large"));
+        }
+        if max.is_some_and(|max| max < min) {
+            return Err(self.error("invalid repetition range"));
+        }
+        Ok(Some((min, max)))
+    }
+
+    fn number(&mut self) -> Option<u32> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let digits: String = self.chars[start..self.pos].iter().collect();
+        (!digits.is_empty()).then(|| digits.parse().unwrap_or(u32::MAX))
+    }
+}
+
+/// Adds the other case of every letter in `ranges`.
+fn fold(ranges: &mut Ranges) {
+    fn single(mut chars: impl Iterator<Item = char>) -> Option<char> {
+        let c = chars.next()?;
+        chars.next().is_none().then_some(c)
+    }
+
+    let mut extra = Vec::new();
+    for &(lo, hi) in ranges.iter() {
+        // Wide ranges come from negations and already hold both cases.
+        if hi as u32 - lo as u32 > 0x3000 {
+            continue;
+        }
+        for c in lo..=hi {
+            for other in [single(c.to_lowercase()), single(c.to_uppercase())].into_iter().flatten() {
+                if other != c {
+                    extra.push((other, other));
+                }
+            }
+        }
+    }
+    ranges.extend(extra);
+    *ranges = normalize(std::mem::take(ranges));
+}
+
+fn normalize(mut ranges: Ranges) -> Ranges {
+    ranges.sort_unstable();
+    let mut out: Ranges = Vec::with_capacity(ranges.len());
+    for (lo, hi) in ranges {
+        match out.last_mut() {
+            Some(last) if after(last.1).is_none_or(|next| lo <= next) => {
+                last.1 = last.1.max(hi);
+            }
+            _ => out.push((lo, hi)),
+        }
+    }
+    out
+}
+
+fn complement(ranges: &[(char, char)]) -> Ranges {
+    let mut out = Vec::new();
+    let mut next = Some('\0');
+    for &(lo, hi) in &normalize(ranges.to_vec()) {
+        let Some(from) = next else { break };
+        if lo > from {
+            out.push((from, before(lo).unwrap()));
+        }
+        next = after(hi);
+    }
+    if let Some(from) = next {
+        out.push((from, char::MAX));
+    }
+    out
+}
+
+/// The next `char`, stepping over the surrogate gap.
+fn after(c: char) -> Option<char> {
+    match c {
+        '\u{D7FF}' => Some('\u{E000}'),
+        c => char::from_u32(c as u32 + 1),
+    }
+}
+
+/// The previous `char`, stepping over the surrogate gap.
+fn before(c: char) -> Option<char> {
+    match c {
+        '\u{E000}' => Some('\u{D7FF}'),
+        c => char::from_u32((c as u32).checked_sub(1)?),
+    }
+}
+
+fn contains(ranges: &[(char, char)], c: char) -> bool {
+    ranges
+        .binary_search_by(|&(lo, hi)| {
+            if hi < c {
+                Ordering::Less
+            } else if lo > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+// Literals
+
+/// What is known about the strings a node matches, case-folded.
+struct Literals {
+    /// All of them, if there are few enough.
+    exact: Option<BTreeSet<String>>,
+    /// What they contain, when `exact` is `None`.
+    required: Required,
+}
+
+impl Literals {
+    fn exact(strings: BTreeSet<String>) -> Literals {
+        Literals {
+            exact: Some(strings),
+            required: Required::Anything,
+        }
+    }
+
+    fn empty() -> Literals {
+        Literals::exact(BTreeSet::from([String::new()]))
+    }
+
+    fn unknown(required: Required) -> Literals {
+        Literals {
+            exact: None,
+            required,
+        }
+    }
+
+    fn of(node: &Node) -> Literals {
+        match node {
+            Node::Empty | Node::Start | Node::End => Literals::empty(),
+            Node::Set(ranges) => {
+                let size: u32 = ranges.iter().map(|&(lo, hi)| hi as u32 - lo as u32 + 1).sum();
+                if size as usize > MAX_EXACT / 4 {
+                    return Literals::unknown(Required::Anything);
+                }
+                let chars = ranges.iter().flat_map(|&(lo, hi)| lo..=hi);
+                Literals::exact(chars.map(|c| fold_case(c.encode_utf8(&mut [0; 4]))).collect())
+            }
+            Node::Group(node, _) => Literals::of(node),
+            Node::Concat(items) => {
+                // The exact strings of the run of items since the last one
+                // that had too many, and what is required before it.
+                let mut done = Vec::new();
+                let mut run = BTreeSet::from([String::new()]);
+                for item in items {
+                    let item = Literals::of(item);
+                    match item.exact {
+                        Some(exact) if run.len() * exact.len() <= MAX_EXACT => {
+                            run = run.iter().flat_map(|a| exact.iter().map(move |b| format!("{a}{b}"))).collect();
+                        }
+                        Some(exact) => {
+                            done.push(Literals::exact(std::mem::replace(&mut run, exact)).into_required());
+                        }
+                        None => {
+                            done.push(Literals::exact(std::mem::take(&mut run)).into_required());
+                            done.push(item.required);
+                            run.insert(String::new());
+                        }
+                    }
+                }
+                if done.is_empty() {
+                    return Literals::exact(run);
+                }
+                done.push(Literals::exact(run).into_required());
+                Literals::unknown(Required::all(done))
+            }
+            Node::Alternate(branches) => {
+                let branches: Vec<Literals> = branches.iter().map(Literals::of).collect();
+                let mut union = BTreeSet::new();
+                for branch in &branches {
+                    match &branch.exact {
+                        Some(exact) if union.len() + exact.len() <= MAX_EXACT => union.extend(exact.iter().cloned()),
+                        _ => return Literals::unknown(Required::any_of(branches.into_iter().map(Literals::into_required))),
+                    }
+                }
+                Literals::exact(union)
+            }
+            Node::Repeat { node, min, max, .. } => {
+                let inner = Literals::of(node);
+                match (min, max, inner.exact) {
+                    (0, Some(1), Some(mut exact)) if exact.len() < MAX_EXACT => {
+                        exact.insert(String::new());
+                        Literals::exact(exact)
+                    }
+                    (0, _, _) => Literals::unknown(Required::Anything),
+                    // At least one copy, so whatever one contains.
+                    (_, _, exact) => Literals {
+                        exact,
+                        required: inner.required,
+                    }
+                    .into_unknown(),
+                }
+            }
+        }
+    }
+
+    fn into_required(self) -> Required {
+        match self.exact {
+            Some(exact) => Required::any_of(exact.into_iter().map(Required::contains)),
+            None => self.required,
+        }
+    }
+
+    fn into_unknown(self) -> Literals {
+        Literals::unknown(self.into_required())
+    }
+}
+
+// NFA
+
+#[derive(Debug, Clone)]
+enum Inst {
+    /// Consumes one character in the set.
+    Set(Ranges),
+    /// Continues at both targets, the first with higher priority.
+    Split(usize, usize),
+    Jmp(usize),
+    /// Records the current position in a capture slot.
+    Save(usize),
+    Start,
+    End,
+    Match,
+}
+
+struct Compiler {
+    program: Vec<Inst>,
+}
+
+impl Compiler {
+    fn emit(&mut self, inst: Inst) -> usize {
+        self.program.push(inst);
+        self.program.len() - 1
+    }
+
+    fn node(&mut self, node: &Node) -> Result<(), &'static str> {
+        if self.program.len() > MAX_PROGRAM {
+            return Err("pattern too large");
+        }
+        match node {
+            Node::Empty => {}
+            Node::Set(ranges) => {
+                self.emit(Inst::Set(ranges.clone()));
+            }
+            Node::Start => {
+                self.emit(Inst::Start);
+            }
+            Node::End => {
+                self.emit(Inst::End);
+            }
+            Node::Group(body, Some(i)) => {
+                self.emit(Inst::Save(2 * i));
+                self.node(body)?;
+                self.emit(Inst::Save(2 * i + 1));
+            }
+            Node::Group(body, None) => self.node(body)?,
+            Node::Concat(items) => {
+                for item in items {
+                    self.node(item)?;
+                }
+            }
+            Node::Alternate(branches) => {
+                let mut jumps = Vec::new();
+                for (k, branch) in branches.iter().enumerate() {
+                    if k + 1 == branches.len() {
+                        self.node(branch)?;
+                        break;
+                    }
+                    let split = self.emit(Inst::Split(0, 0));
+                    self.node(branch)?;
+                    jumps.push(self.emit(Inst::Jmp(0)));
+                    self.program[split] = Inst::Split(split + 1, self.program.len());
+                }
+                let end = self.program.len();
+                for jump in jumps {
+                    self.program[jump] = Inst::Jmp(end);
+                }
+            }
+            Node::Repeat {
+                node,
+                min,
+                max,
+                greedy,
+            } => {
+                let split = |from: usize, to: usize| {
+                    if *greedy {
+                        Inst::Split(from, to)
+                    } else {
+                        Inst::Split(to, from)
+                    }
+                };
+                for _ in 0..*min {
+                    self.node(node)?;
+                }
+                match max {
+                    None => {
+                        let start = self.emit(Inst::Split(0, 0));
+                        self.node(node)?;
+                        self.emit(Inst::Jmp(start));
+                        self.program[start] = split(start + 1, self.program.len());
+                    }
+                    Some(max) => {
+                        let mut splits = Vec::new();
+                        for _ in *min..*max {
+                            splits.push(self.emit(Inst::Split(0, 0)));
+                            self.node(node)?;
+                        }
+                        let end = self.program.len();
+                        for at in splits {
+                            self.program[at] = split(at + 1, end);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+enum Job {
+    Visit(usize),
+    Restore(usize, Option<usize>),
+}
+
+/// The Pike VM's threads for one position, in priority order.
+struct Threads {
+    pcs: Vec<usize>,
+    slots: Vec<Vec<Option<usize>>>,
+    seen: Vec<bool>,
+    /// Every instruction marked in `seen`, to unmark them cheaply.
+    touched: Vec<usize>,
+}
+
+impl Threads {
+    fn new(len: usize) -> Threads {
+        Threads {
+            pcs: Vec::new(),
+            slots: Vec::new(),
+            seen: vec![false; len],
+            touched: Vec::new(),
+        }
+    }
+
+    fn clear(&mut self) {
+        for pc in self.touched.drain(..) {
+            self.seen[pc] = false;
+        }
+        self.pcs.clear();
+        self.slots.clear();
+    }
+}
+
+// DFA
+
+/// A DFA that answers whether a text contains a match anywhere. Characters
+/// are grouped into classes that no instruction tells apart.
+#[derive(Debug, Clone)]
+struct Dfa {
+    /// The first character of every class, ascending.
+    bounds: Vec<u32>,
+    ascii: Vec<u16>,
+    classes: usize,
+    /// `states * classes` transitions.
+    table: Vec<u32>,
+    /// States holding a match; once reached, the text matches.
+    accept: Vec<bool>,
+    /// States that match if the text ends here.
+    accept_at_end: Vec<bool>,
+}
+
+impl Dfa {
+    fn build(program: &[Inst]) -> Option<Dfa> {
+        let mut bounds = vec![0];
+        for inst in program {
+            if let Inst::Set(ranges) = inst {
+                for &(lo, hi) in ranges {
+                    bounds.push(lo as u32);
+                    bounds.push(hi as u32 + 1);
+                }
+            }
+        }
+        bounds.sort_unstable();
+        bounds.dedup();
+        bounds.retain(|&b| b <= char::MAX as u32);
+        let classes = bounds.len();
+        if classes > u16::MAX as usize {
+            return None;
+        }
+        // A character standing for each class; `None` if the class holds only
+        // surrogates.
+        let samples: Vec<Option<char>> = (0..classes)
+            .map(|k| {
+                let end = bounds.get(k + 1).copied().unwrap_or(char::MAX as u32 + 1);
+                (bounds[k]..end).find_map(char::from_u32)
+            })
+            .collect();
+
+        let mut closure = Closure::new(program.len());
+        let first = closure.run(program, &[0], true, false);
+        let restart = closure.run(program, &[0], false, false);
+
+        // The start state is kept out of `index`: it alone is at the start of
+        // the text.
+        let mut sets = vec![first];
+        let mut index: HashMap<Vec<usize>, u32> = HashMap::new();
+        let mut table = Vec::new();
+        let mut done = 0;
+        while done < sets.len() {
+            let set = sets[done].clone();
+            let matched = set.iter().any(|&pc| matches!(program[pc], Inst::Match));
+            for sample in &samples {
+                if matched {
+                    table.push(done as u32);
+                    continue;
+                }
+                let mut seeds = restart.clone();
+                if let Some(c) = *sample {
+                    for &pc in &set {
+                        if let Inst::Set(ranges) = &program[pc] {
+                            if contains(ranges, c) {
+                                seeds.push(pc + 1);
+                            }
+                        }
+                    }
+                }
+                let target = closure.run(program, &seeds, false, false);
+                let id = match index.get(&target) {
+                    Some(&id) => id,
+                    None => {
+                        if sets.len() == MAX_DFA_STATES {
+                            return None;
+                        }
+                        let id = sets.len() as u32;
+                        index.insert(target.clone(), id);
+                        sets.push(target);
+                        id
+                    }
+                };
+                table.push(id);
+            }
+            done += 1;
+        }
+
+        let mut accept = Vec::with_capacity(sets.len());
+        let mut accept_at_end = Vec::with_capacity(sets.len());
+        for (id, set) in sets.iter().enumerate() {
+            let matched = set.iter().any(|&pc| matches!(program[pc], Inst::Match));
+            let ends: Vec<usize> = set
+                .iter()
+                .filter(|&&pc| matches!(program[pc], Inst::End))
+                .map(|&pc| pc + 1)
+                .collect();
+            let at_end = closure.run(program, &ends, id == 0, true);
+            accept.push(matched);
+            accept_at_end.push(matched || at_end.iter().any(|&pc| matches!(program[pc], Inst::Match)));
+        }
+
+        let ascii = (0..128)
+            .map(|c| (bounds.partition_point(|&b| b <= c) - 1) as u16)
+            .collect();
+        Some(Dfa {
+            bounds,
+            ascii,
+            classes,
+            table,
+            accept,
+            accept_at_end,
+        })
+    }
+
+    fn class(&self, c: char) -> usize {
+        let c = c as u32;
+        if c < 128 {
+            self.ascii[c as usize] as usize
+        } else {
+            self.bounds.partition_point(|&b| b <= c) - 1
+        }
+    }
+
+    fn is_match(&self, text: &str) -> bool {
+        let mut state = 0;
+        for c in text.chars() {
+            if self.accept[state] {
+                return true;
+            }
+            state = self.table[state * self.classes + self.class(c)] as usize;
+        }
+        self.accept_at_end[state]
+    }
+}
+
+/// Computes the NFA states reachable without consuming input, reusing its
+/// buffers between calls.
+struct Closure {
+    seen: Vec<bool>,
+    stack: Vec<usize>,
+}
+
+impl Closure {
+    fn new(len: usize) -> Closure {
+        Closure {
+            seen: vec![false; len],
+            stack: Vec::new(),
+        }
+    }
+
+    /// Returns the sorted set of `Set`, `Match` and, unless `at_end`, `End`
+    /// instructions reachable from `seeds`.
+    fn run(&mut self, program: &[Inst], seeds: &[usize], at_start: bool, at_end: bool) -> Vec<usize> {
+        let mut out = Vec::new();
+        let mut touched = Vec::new();
+        self.stack.extend_from_slice(seeds);
+        while let Some(pc) = self.stack.pop() {
+            if self.seen[pc] {
+                continue;
+            }
+            self.seen[pc] = true;
+            touched.push(pc);
+            match &program[pc] {
+                Inst::Jmp(to) => self.stack.push(*to),
+                Inst::Split(first, second) => {
+                    self.stack.push(*first);
+                    self.stack.push(*second);
+                }
+                Inst::Save(_) => self.stack.push(pc + 1),
+                Inst::Start if at_start => self.stack.push(pc + 1),
+                Inst::End if at_end => self.stack.push(pc + 1),
+                Inst::Start => {}
+                Inst::End | Inst::Set(_) | Inst::Match => out.push(pc),
+            }
+        }
+        for pc in touched {
+            self.seen[pc] = false;
+        }
+        out.sort_unstable();
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks that the DFA and the Pike VM agree before answering.
+    fn matches(pattern: &str, text: &str) -> bool {
+        let re = Regex::new(pattern).unwrap();
+        let nfa = re.pike(text, 0).is_some();
+        assert_eq!(re.is_match(text), nfa, "DFA and NFA disagree on {pattern:?} against {text:?}");
+        nfa
+    }
+
+    fn find<'t>(pattern: &str, text: &'t str) -> Option<&'t str> {
+        Regex::new(pattern).unwrap().find(text).map(|m| m.as_str())
+    }
+
+    #[test]
+    fn literals_and_classes() {
+        assert!(matches("duct", "productive"));
+        assert!(!matches("duct", "Duct tape"));
+        assert!(matches("r.st", "Trust me."));
+        assert!(!matches("a.b", "a\nb"));
+        assert!(matches("[Dd]uct", "Duct tape"));
+        assert!(matches("[^a-z ]", "who are you?"));
+        assert!(!matches("[^a-z ]", "who are you"));
+        assert!(matches(r"\d\d", "page 42"));
+        assert!(!matches(r"\d\d", "page 4"));
+        assert!(matches(r"\w+\s\w+", "two words"));
+        assert!(matches(r"[\d-]", "-"));
+        assert!(matches(r"[]x]", "]"));
+        assert!(matches(r"\.", "a.b"));
+        assert!(!matches(r"\.", "ab"));
+        assert!(matches("ü", "Grüße"));
+    }
+
+    #[test]
+    fn alternation_and_anchors() {
+        assert!(matches("frog|bog", "To an admiring bog!"));
+        assert!(!matches("frog|bog", "How dreary to be somebody!"));
+        assert!(matches("^To", "To tell your name"));
+        assert!(!matches("^To", "Then To"));
+        assert!(matches("bog!$", "To an admiring bog!"));
+        assert!(!matches("^bog$", "bogs"));
+        assert!(matches("^$", ""));
+        assert!(!matches("^$", "x"));
+        assert!(matches("^(a|b)*$", "abba"));
+        assert!(!matches("^(a|b)*$", "abc"));
+    }
+
+    #[test]
+    fn repetition() {
+        assert!(matches("^a{3}$", "aaa"));
+        assert!(!matches("^a{3}$", "aa"));
+        assert!(matches("^a{2,}$", "aaaa"));
+        assert!(matches("^a{1,2}b$", "aab"));
+        assert!(!matches("^a{1,2}b$", "aaab"));
+        assert!(matches("^colou?r$", "color"));
+        assert!(matches("x{", "x{"));
+        assert!(matches("^(ab)+$", "ababab"));
+        assert!(!matches("^(ab)+$", ""));
+        assert!(matches("(a*)*b", "aaab"));
+
+        assert_eq!(find("a+", "baaa"), Some("aaa"));
+        assert_eq!(find("a+?", "baaa"), Some("a"));
+        assert_eq!(find("<.*>", "<a><b>"), Some("<a><b>"));
+        assert_eq!(find("<.*?>", "<a><b>"), Some("<a>"));
+        assert_eq!(find("a|ab", "ab"), Some("a"));
+    }
+
+    #[test]
+    fn find_iter() {
+        let all = |pattern: &str, text: &str| -> Vec<(usize, usize)> {
+            let re = Regex::new(pattern).unwrap();
+            re.find_iter(text).map(|m| (m.start(), m.end())).collect()
+        };
+        assert_eq!(all("o", "nobody, too"), [(1, 2), (3, 4), (9, 10), (10, 11)]);
+        assert_eq!(all("o+", "nobody, too"), [(1, 2), (3, 4), (9, 11)]);
+        assert_eq!(all("^o", "oo"), [(0, 1)]);
+        assert_eq!(all("x*", "axb"), [(0, 0), (1, 2), (3, 3)]);
+        assert_eq!(all("é|", "aé"), [(0, 0), (1, 3)]);
+    }
+
+    #[test]
+    fn captures() {
+        let re = Regex::new(r"(\w+)@(?P<host>\w+)\.(?:com|org)").unwrap();
+        assert_eq!(re.captures_len(), 3);
+        let caps = re.captures("mail ferris@rust.org now").unwrap();
+        assert_eq!(caps.get(0).unwrap().as_str(), "ferris@rust.org");
+        assert_eq!(caps.get(0).unwrap().start(), 5);
+        assert_eq!(caps.get(1).unwrap().as_str(), "ferris");
+        assert_eq!(caps.name("host").unwrap().as_str(), "rust");
+        assert_eq!(caps.get(3), None);
+
+        let re = Regex::new("(a)|(b)").unwrap();
+        let caps = re.captures("b").unwrap();
+        assert_eq!(caps.get(1), None);
+        assert_eq!(caps.get(2).unwrap().as_str(), "b");
+
+        let re = Regex::new(r"(\w)\w*").unwrap();
+        let caps = re.captures_at("one two", 3).unwrap();
+        assert_eq!(caps.get(0).unwrap().as_str(), "two");
+        assert_eq!(caps.get(1).unwrap().as_str(), "t");
+        assert!(Regex::new("^o").unwrap().captures_at("oo", 1).is_none());
+    }
+
+    #[test]
+    fn ignoring_case() {
+        let re = Regex::case_insensitive("rUsT").unwrap();
+        assert!(re.is_match("Rust:"));
+        assert!(re.is_match("Trust me."));
+        assert!(!re.is_match("safe, fast"));
+        let re = Regex::case_insensitive("[^a-z]").unwrap();
+        assert!(!re.is_match("ABC"));
+        assert!(Regex::case_insensitive("straße").unwrap().is_match("STRAßE"));
+    }
+
+    #[test]
+    fn errors() {
+        let error = |pattern: &str| Regex::new(pattern).unwrap_err();
+        assert_eq!(error("(ab").message, "unclosed group");
+        assert_eq!(error("ab)").message, "unmatched `)`");
+        assert_eq!(error("ab)").position, 2);
+        assert_eq!(error("[ab").message, "unclosed character class");
+        assert_eq!(error("*a").message, "repetition with nothing to repeat");
+        assert_eq!(error("[z-a]").message, "invalid class range");
+        assert_eq!(error("a{3,1}").message, "invalid repetition range");
+        assert_eq!(error("a{5000}").message, "repetition count too large");
+        assert_eq!(error(r"\q").message, "unsupported escape");
+        assert_eq!(error(r"a\").message, "trailing backslash");
+        assert_eq!(error("(?P<x>a)(?P<x>b)").message, "duplicate group name");
+    }
+
+    #[test]
+    fn required_literals() {
+        let required = |pattern: &str| Regex::new(pattern).unwrap().required().clone();
+        let contains = |text: &str| Required::Contains(text.to_string());
+        assert_eq!(required("frog"), contains("frog"));
+        assert_eq!(required("^How"), contains("how"));
+        assert_eq!(required("fro[gs]"), Required::AnyOf(vec![contains("frog"), contains("fros")]));
+        assert_eq!(required("colou?r"), Required::AnyOf(vec![contains("color"), contains("colour")]));
+        assert_eq!(required(r"name \w+ day"), Required::All(vec![contains("name "), contains(" day")]));
+        assert_eq!(
+            required("(frog|toad)s.*bog"),
+            Required::All(vec![Required::AnyOf(vec![contains("frogs"), contains("toads")]), contains("bog")])
+        );
+        assert_eq!(required("(frog)+s"), contains("frog"));
+        assert_eq!(required("a.b|frog"), Required::Anything);
+        assert_eq!(required("x*"), Required::Anything);
+        assert_eq!(required("[a-z]+"), Required::Anything);
+        // `ẞ` folds to `ß` but `ß` to `ss`, and the index folds the same way.
+        let folded = Regex::case_insensitive("STRAẞE").unwrap();
+        assert_eq!(*folded.required(), Required::AnyOf(vec![contains("strasse"), contains("straße")]));
+    }
+
+    #[test]
+    fn large_patterns_fall_back_to_the_nfa() {
+        let re = Regex::new("(a|b)*a(a|b){12}").unwrap();
+        assert!(re.dfa.is_none());
+        assert!(re.is_match("baaaaaaaaaaaaab"));
+        assert!(!re.is_match("aaaaaaaaaaaa"));
+    }
+}
@@ -0,0 +1,361 @@
+//! Rewriting the matches in files, for `--replace`.
+//!
+//! Files are matched line by line exactly as when searching. Each match is
+//! replaced by a [`Template`], where `$1` or `${name}` stand for the groups
+//! of a regex match and `$0` for the whole match. A file is written to a
+//! temporary file next to it, which then takes its place with a rename, so
+//! that nothing ever sees it half written. With a dry run, a unified diff of
+//! the changes is printed instead.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+
+use crate::aho_corasick::PatternMatch;
+use crate::inflate::Format;
+use crate::{Decompress, Matcher};
+
+/// A replacement text, parsed once.
+///
+/// `$N` and `${N}` are the Nth group, `$name` and `${name}` a named one and
+/// `$$` a literal `$`. A name is the longest run of letters, digits and `_`
+/// after the `$`, so `$1a` is the group named `1a`: write `${1}a` for group
+/// 1 followed by an `a`. Groups that did not take part in the match, or do
+/// not exist, expand to nothing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    Index(usize),
+    Name(String),
+}
+
+impl Template {
+    pub fn parse(text: &str) -> Template {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut rest = text;
+        while let Some(dollar) = rest.find('$') {
+            literal.push_str(&rest[..dollar]);
+            rest = &rest[dollar + 1..];
+            let (name, after) = if let Some(braced) = rest.strip_prefix('{') {
+                match braced.find('}') {
+                    Some(close) => (&braced[..close], &braced[close + 1..]),
+                    None => ("", rest),
+                }
+            } else {
+                let len = rest
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+                (&rest[..len], &rest[len..])
+            };
+            if name.is_empty() {
+                // `$$`, or a `$` that does not start a group.
+                literal.push('$');
+                rest = rest.strip_prefix('$').unwrap_or(rest);
+                continue;
+            }
+            if !literal.is_empty() {
+                parts.push(Part::Text(std::mem::take(&mut literal)));
+            }
+            parts.push(match name.parse() {
+                Ok(index) => Part::Index(index),
+                Err(_) => Part::Name(name.to_string()),
+            });
+            rest = after;
+        }
+        literal.push_str(rest);
+        if !literal.is_empty() {
+            parts.push(Part::Text(literal));
+        }
+        Template { parts }
+    }
+
+    /// Appends the template to `dst`, taking the groups from `group`, which
+    /// is given either an index or a name.
+    fn expand<'t>(&self, dst: &mut String, group: impl Fn(Result<usize, &str>) -> Option<&'t str>) {
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => dst.push_str(text),
+                Part::Index(i) => dst.push_str(group(Ok(*i)).unwrap_or("")),
+                Part::Name(name) => dst.push_str(group(Err(name)).unwrap_or("")),
+            }
+        }
+    }
+}
+
+/// Returns `line` with each of the `spans` that `matcher` found replaced.
+fn substitute(matcher: &Matcher, template: &Template, line: &str, spans: &[PatternMatch]) -> String {
+    let mut replaced = String::with_capacity(line.len());
+    let mut last = 0;
+    for span in spans {
+        replaced.push_str(&line[last..span.bytes.start]);
+        let matched = &line[span.bytes.clone()];
+        match matcher {
+            Matcher::Regex(regexes) => {
+                let caps = regexes[span.pattern].captures_at(line, span.bytes.start);
+                template.expand(&mut replaced, |group| {
+                    let caps = caps.as_ref()?;
+                    match group {
+                        Ok(i) => caps.get(i),
+                        Err(name) => caps.name(name),
+                    }
+                    .map(|m| m.as_str())
+                });
+            }
+            _ => template.expand(&mut replaced, |group| (group == Ok(0)).then_some(matched)),
+        }
+        last = span.bytes.end;
+    }
+    replaced.push_str(&line[last..]);
+    replaced
+}
+
+/// Replaces the matches in the file at `path`. With `dry_run`, the file is
+/// left alone and the diff is written to `out`. Returns the number of lines
+/// changed.
+///
+/// Lines that are not valid UTF-8 are left as they are; files with NUL bytes
+/// are not rewritten at all, and neither are compressed files, unless
+/// `decompress` is [`Decompress::Never`] and they are taken as binary. A
+/// symbolic link is followed, and the file it points to rewritten. The new
+/// file gets the permissions of the old one but belongs to whoever runs
+/// minigrep.
+pub(crate) fn rewrite(
+    matcher: &Matcher,
+    template: &Template,
+    path: &Path,
+    decompress: Decompress,
+    dry_run: bool,
+    out: &mut dyn Write,
+) -> io::Result<usize> {
+    let mut reader = BufReader::with_capacity(64 * 1024, File::open(path)?);
+    let head = reader.fill_buf()?;
+    if decompress != Decompress::Never && Format::detect(head).is_some() {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "compressed file, not rewritten"));
+    }
+    if head.contains(&0) {
+        return Err(binary());
+    }
+    // Created at the first change, most files having none.
+    let mut temp: Option<Temp> = None;
+    let mut diff = Diff::new(path);
+
+    let mut buf = Vec::new();
+    let mut changed = 0;
+    let mut offset = 0;
+    loop {
+        buf.clear();
+        let read = reader.read_until(b'\n', &mut buf)?;
+        if read == 0 {
+            break;
+        }
+        let start = offset;
+        offset += read as u64;
+        let mut line = &buf[..];
+        if let Some(rest) = line.strip_suffix(b"\n") {
+            line = rest.strip_suffix(b"\r").unwrap_or(rest);
+        }
+        let ending = &buf[line.len()..];
+        if line.contains(&0) {
+            return Err(binary());
+        }
+
+        let replaced = match std::str::from_utf8(line) {
+            Ok(text) => {
+                let spans = matcher.spans(text);
+                (!spans.is_empty())
+                    .then(|| substitute(matcher, template, text, &spans))
+                    .filter(|replaced| replaced != text)
+                    .map(|replaced| (text, replaced))
+            }
+            Err(_) => None,
+        };
+        match replaced {
+            Some((text, replaced)) => {
+                changed += 1;
+                if dry_run {
+                    diff.change(out, text, &replaced)?;
+                    continue;
+                }
+                if temp.is_none() {
+                    let mut new = Temp::create(&fs::canonicalize(path)?)?;
+                    io::copy(&mut File::open(path)?.take(start), &mut new.writer)?;
+                    temp = Some(new);
+                }
+                let writer = &mut temp.as_mut().unwrap().writer;
+                writer.write_all(replaced.as_bytes())?;
+                writer.write_all(ending)?;
+            }
+            None => {
+                diff.same(out)?;
+                if let Some(temp) = &mut temp {
+                    temp.writer.write_all(&buf)?;
+                }
+            }
+        }
+    }
+    diff.same(out)?;
+
+    if let Some(temp) = temp {
+        temp.replace(&reader.get_ref().metadata()?.permissions())?;
+    }
+    Ok(changed)
+}
+
+fn binary() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "binary file, not rewritten")
+}
+
+/// The new contents of a file, being written next to it.
+struct Temp {
+    path: PathBuf,
+    target: PathBuf,
+    writer: BufWriter<File>,
+}
+
+impl Temp {
+    fn create(target: &Path) -> io::Result<Temp> {
+        let name = target.file_name().unwrap_or_default().to_string_lossy();
+        let path = target.with_file_name(format!(".{name}.minigrep-{}", process::id()));
+        let file = OpenOptions::new().write(true).create_new(true).open(&path)?;
+        Ok(Temp {
+            path,
+            target: target.to_path_buf(),
+            writer: BufWriter::new(file),
+        })
+    }
+
+    /// Puts the new contents in place of the old, with `permissions`.
+    fn replace(mut self, permissions: &fs::Permissions) -> io::Result<()> {
+        self.writer.flush()?;
+        let file = self.writer.get_ref();
+        file.set_permissions(permissions.clone())?;
+        file.sync_all()?;
+        fs::rename(&self.path, &self.target)?;
+        // Renamed: nothing left to clean up.
+        self.path = PathBuf::new();
+        Ok(())
+    }
+}
+
+impl Drop for Temp {
+    fn drop(&mut self) {
+        if !self.path.as_os_str().is_empty() {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// A unified diff without context, built a line at a time. Runs of changed
+/// lines make one hunk.
+struct Diff<'p> {
+    path: &'p Path,
+    started: bool,
+    /// The number of the next line in the old file and in the new one.
+    old_line: usize,
+    new_line: usize,
+    removed: Vec<String>,
+    added: Vec<String>,
+}
+
+impl<'p> Diff<'p> {
+    fn new(path: &'p Path) -> Diff<'p> {
+        Diff {
+            path,
+            started: false,
+            old_line: 1,
+            new_line: 1,
+            removed: Vec::new(),
+            added: Vec::new(),
+        }
+    }
+
+    fn change(&mut self, out: &mut dyn Write, old: &str, new: &str) -> io::Result<()> {
+        if !self.started {
+            let path = self.path.display();
+            writeln!(out, "--- a/{path}\n+++ b/{path}")?;
+            self.started = true;
+        }
+        self.removed.push(old.to_string());
+        // The replacement may have added lines.
+        self.added.extend(new.split('\n').map(String::from));
+        Ok(())
+    }
+
+    /// Moves past an unchanged line, printing the hunk that ends there.
+    fn same(&mut self, out: &mut dyn Write) -> io::Result<()> {
+        if !self.removed.is_empty() {
+            writeln!(
+                out,
+                "@@ -{},{} +{},{} @@",
+                self.old_line,
+                self.removed.len(),
+                self.new_line,
+                self.added.len()
+            )?;
+            for line in &self.removed {
+                writeln!(out, "-{line}")?;
+            }
+            for line in &self.added {
+                writeln!(out, "+{line}")?;
+            }
+            self.old_line += self.removed.len();
+            self.new_line += self.added.len();
+            self.removed.clear();
+            self.added.clear();
+        }
+        self.old_line += 1;
+        self.new_line += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::regex::Regex;
+
+    fn replace(pattern: &str, template: &str, line: &str) -> String {
+        let regex = Regex::new(pattern).unwrap();
+        let matcher = Matcher::Regex(vec![regex]);
+        let spans = matcher.spans(line);
+        substitute(&matcher, &Template::parse(template), line, &spans)
+    }
+
+    #[test]
+    fn templates() {
+        assert_eq!(
+            Template::parse("a$1${name}$$ $x_1."),
+            Template {
+                parts: vec![
+                    Part::Text("a".to_string()),
+                    Part::Index(1),
+                    Part::Name("name".to_string()),
+                    Part::Text("$ ".to_string()),
+                    Part::Name("x_1".to_string()),
+                    Part::Text(".".to_string()),
+                ]
+            }
+        );
+        assert_eq!(Template::parse("$ ${"), Template { parts: vec![Part::Text("$ ${".to_string())] });
+    }
+
+    #[test]
+    fn substitution() {
+        assert_eq!(replace(r"(\w+)@(\w+)", "$2 at ${1}s", "ferris@rust, bob@home"), "rust at ferriss, home at bobs");
+        assert_eq!(replace(r"(?P<y>\d+)-(?P<m>\d+)", "$m/$y", "on 2024-05"), "on 05/2024");
+        assert_eq!(replace("a(x)?", "[$1]", "ab"), "[]b");
+        assert_eq!(replace("^o", "0", "oo"), "0o");
+
+        let matcher = Matcher::Literals(crate::aho_corasick::AhoCorasick::new(&["frog", "bog"], true));
+        let line = "a Frog in a BOG";
+        let spans = matcher.spans(line);
+        assert_eq!(substitute(&matcher, &Template::parse("<$0$1>"), line, &spans), "a <Frog> in a <BOG>");
+    }
+}
@@ -0,0 +1,468 @@
+//! Where the results of a search go.
+//!
+//! [`run_with`](crate::run_with) hands each searched file to an
+//! [`OutputSink`]: first [`begin`](OutputSink::begin), then every selected
+//! line, then [`binary`](OutputSink::binary) if the file turned out to be
+//! binary, then [`end`](OutputSink::end), or [`error`](OutputSink::error) if
+//! the file could not be read to the end. The sinks here print what `grep` would
+//! for its different output modes; tools embedding minigrep can write their
+//! own.
+
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::aho_corasick::PatternMatch;
+
+/// A line handed to a sink: one the search selected, or context around one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    /// Line number, counting from 1.
+    pub number: usize,
+    /// Byte offset of the start of the line in its file.
+    pub offset: usize,
+    /// The line, without its line ending.
+    pub text: String,
+    /// Where the patterns matched within `text`, in bytes, and which did.
+    /// Empty for context lines and for lines selected by an inverted search.
+    pub spans: Vec<PatternMatch>,
+    /// Whether the search selected the line, rather than it being context.
+    pub selected: bool,
+}
+
+/// Receives the results of a search, one file at a time, in the order the
+/// files were found.
+pub trait OutputSink {
+    /// Called before the lines of each file searched.
+    fn begin(&mut self, path: &Path) -> io::Result<()> {
+        let _ = path;
+        Ok(())
+    }
+
+    /// Called for each selected line and each context line, in file order.
+    fn line(&mut self, path: &Path, line: &Line) -> io::Result<()>;
+
+    /// Called when a line was selected in a binary file, that is one with NUL
+    /// bytes. The lines from there on are not passed to the sink.
+    fn binary(&mut self, path: &Path) -> io::Result<()> {
+        let _ = path;
+        Ok(())
+    }
+
+    /// Called after the lines of each file, with the number of lines selected.
+    /// For binary files that are not counted, it may stop at 1.
+    fn end(&mut self, path: &Path, selected: usize) -> io::Result<()> {
+        let _ = (path, selected);
+        Ok(())
+    }
+
+    /// Called for a file or directory that could not be searched, or not to
+    /// the end.
+    fn error(&mut self, message: &str) -> io::Result<()> {
+        eprintln!("minigrep: {message}");
+        Ok(())
+    }
+}
+
+/// The colors of the parts of the output, as SGR parameters such as `01;31`
+/// for bold red. An empty one leaves its part plain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    pub matched: String,
+    pub path: String,
+    pub line_number: String,
+    pub byte_offset: String,
+    /// The `:` and `-` after a prefix, and `--` between groups of lines.
+    pub separator: String,
+}
+
+impl Default for Palette {
+    /// The colors `grep` uses.
+    fn default() -> Palette {
+        Palette {
+            matched: "01;31".to_string(),
+            path: "35".to_string(),
+            line_number: "32".to_string(),
+            byte_offset: "32".to_string(),
+            separator: "36".to_string(),
+        }
+    }
+}
+
+impl Palette {
+    /// The default palette with the changes in `spec`, given the way
+    /// `GREP_COLORS` gives them, as in `mt=01;32:fn=34`: `mt` for matches,
+    /// `fn` for paths, `ln` for line numbers, `bn` for byte offsets and `se`
+    /// for separators. Returns the first entry it does not understand.
+    pub fn parse(spec: &str) -> Result<Palette, String> {
+        let mut palette = Palette::default();
+        for entry in spec.split(':').filter(|entry| !entry.is_empty()) {
+            let (name, sgr) = entry.split_once('=').ok_or_else(|| entry.to_string())?;
+            let part = match name {
+                "mt" => &mut palette.matched,
+                "fn" => &mut palette.path,
+                "ln" => &mut palette.line_number,
+                "bn" => &mut palette.byte_offset,
+                "se" => &mut palette.separator,
+                _ => return Err(entry.to_string()),
+            };
+            if !sgr.bytes().all(|b| b.is_ascii_digit() || b == b';') {
+                return Err(entry.to_string());
+            }
+            *part = sgr.to_string();
+        }
+        Ok(palette)
+    }
+}
+
+/// Writes `text`, in the color `sgr` if there is one.
+fn paint(out: &mut impl Write, sgr: Option<&str>, text: impl Display) -> io::Result<()> {
+    match sgr.filter(|sgr| !sgr.is_empty()) {
+        Some(sgr) => write!(out, "\x1b[{sgr}m{text}\x1b[m"),
+        None => write!(out, "{text}"),
+    }
+}
+
+/// Prints lines the way `grep` does, optionally prefixed with the path, line
+/// number and byte offset, with `--` between groups of lines that are not
+/// adjacent when there is context.
+pub struct Standard<W> {
+    out: W,
+    pub with_path: bool,
+    pub line_numbers: bool,
+    pub byte_offsets: bool,
+    /// Print `--` between groups of lines.
+    pub separators: bool,
+    /// Print each matched part of a line on a line of its own (`-o`).
+    pub only_matching: bool,
+    /// Color the matches and prefixes with these.
+    pub colors: Option<Palette>,
+    printed_any: bool,
+    previous: Option<usize>,
+}
+
+impl<W: Write> Standard<W> {
+    /// Prints just the lines to `out`.
+    pub fn new(out: W) -> Standard<W> {
+        Standard {
+            out,
+            with_path: false,
+            line_numbers: false,
+            byte_offsets: false,
+            separators: false,
+            only_matching: false,
+            colors: None,
+            printed_any: false,
+            previous: None,
+        }
+    }
+
+    fn prefix(&mut self, path: &Path, number: usize, offset: usize, separator: char) -> io::Result<()> {
+        let colors = self.colors.as_ref();
+        let separator_color = colors.map(|c| c.separator.as_str());
+        if self.with_path {
+            paint(&mut self.out, colors.map(|c| c.path.as_str()), path.display())?;
+            paint(&mut self.out, separator_color, separator)?;
+        }
+        if self.line_numbers {
+            paint(&mut self.out, colors.map(|c| c.line_number.as_str()), number)?;
+            paint(&mut self.out, separator_color, separator)?;
+        }
+        if self.byte_offsets {
+            paint(&mut self.out, colors.map(|c| c.byte_offset.as_str()), offset)?;
+            paint(&mut self.out, separator_color, separator)?;
+        }
+        Ok(())
+    }
+
+    /// Prints the text of `line`, with its matches colored if there are
+    /// colors.
+    fn text(&mut self, line: &Line) -> io::Result<()> {
+        let Some(colors) = &self.colors else {
+            return writeln!(self.out, "{}", line.text);
+        };
+        let mut last = 0;
+        for span in &line.spans {
+            if span.bytes.start < last || span.bytes.is_empty() {
+                continue;
+            }
+            write!(self.out, "{}", &line.text[last..span.bytes.start])?;
+            paint(&mut self.out, Some(&colors.matched), &line.text[span.bytes.clone()])?;
+            last = span.bytes.end;
+        }
+        writeln!(self.out, "{}", &line.text[last..])
+    }
+}
+
+impl<W: Write> OutputSink for Standard<W> {
+    fn begin(&mut self, _path: &Path) -> io::Result<()> {
+        self.previous = None;
+        Ok(())
+    }
+
+    fn line(&mut self, path: &Path, line: &Line) -> io::Result<()> {
+        if self.only_matching {
+            // Empty matches print nothing, not empty lines, as with grep.
+            for span in line.spans.iter().filter(|span| !span.bytes.is_empty()) {
+                self.prefix(path, line.number, line.offset + span.bytes.start, ':')?;
+                let color = self.colors.as_ref().map(|c| c.matched.as_str());
+                paint(&mut self.out, color, &line.text[span.bytes.clone()])?;
+                writeln!(self.out)?;
+            }
+            return Ok(());
+        }
+        let adjacent = self.previous.is_some_and(|p| line.number == p + 1);
+        if self.separators && self.printed_any && !adjacent {
+            paint(&mut self.out, self.colors.as_ref().map(|c| c.separator.as_str()), "--")?;
+            writeln!(self.out)?;
+        }
+        let separator = if line.selected { ':' } else { '-' };
+        self.prefix(path, line.number, line.offset, separator)?;
+        self.text(line)?;
+        self.previous = Some(line.number);
+        self.printed_any = true;
+        Ok(())
+    }
+
+    fn binary(&mut self, path: &Path) -> io::Result<()> {
+        writeln!(self.out, "Binary file {} matches", path.display())
+    }
+}
+
+/// Prints how many lines each file selected (`-c`).
+pub struct Count<W> {
+    out: W,
+    pub with_path: bool,
+    /// Color the paths with these.
+    pub colors: Option<Palette>,
+}
+
+impl<W: Write> Count<W> {
+    pub fn new(out: W) -> Count<W> {
+        Count {
+            out,
+            with_path: false,
+            colors: None,
+        }
+    }
+}
+
+impl<W: Write> OutputSink for Count<W> {
+    fn line(&mut self, _path: &Path, _line: &Line) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn end(&mut self, path: &Path, selected: usize) -> io::Result<()> {
+        if self.with_path {
+            let colors = self.colors.as_ref();
+            paint(&mut self.out, colors.map(|c| c.path.as_str()), path.display())?;
+            paint(&mut self.out, colors.map(|c| c.separator.as_str()), ':')?;
+        }
+        writeln!(self.out, "{selected}")
+    }
+}
+
+/// Prints the paths of the files that selected some line (`-l`), or of those
+/// that selected none (`-L`).
+pub struct Files<W> {
+    out: W,
+    with_matches: bool,
+    /// Color the paths with these.
+    pub colors: Option<Palette>,
+}
+
+impl<W: Write> Files<W> {
+    pub fn with_matches(out: W) -> Files<W> {
+        Files {
+            out,
+            with_matches: true,
+            colors: None,
+        }
+    }
+
+    pub fn without_match(out: W) -> Files<W> {
+        Files {
+            out,
+            with_matches: false,
+            colors: None,
+        }
+    }
+}
+
+impl<W: Write> OutputSink for Files<W> {
+    fn line(&mut self, _path: &Path, _line: &Line) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn end(&mut self, path: &Path, selected: usize) -> io::Result<()> {
+        if (selected > 0) == self.with_matches {
+            paint(&mut self.out, self.colors.as_ref().map(|c| c.path.as_str()), path.display())?;
+            writeln!(self.out)?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints one JSON object per line (`--json`), such as
+///
+/// ```text
+/// {"type":"match","path":"poem.txt","line_number":7,"byte_offset":142,"text":"How public, like a frog","spans":[{"start":19,"end":23,"text":"frog","pattern":0}]}
+/// ```
+///
+/// Context lines have the type `"context"` and no spans. Offsets are in bytes;
+/// span offsets count from the start of the line, and `pattern` is the index
+/// of the pattern that matched. A binary file that matches
+/// gets `{"type":"binary","path":...}` instead of its lines from there on.
+pub struct Json<W> {
+    out: W,
+}
+
+impl<W: Write> Json<W> {
+    pub fn new(out: W) -> Json<W> {
+        Json { out }
+    }
+}
+
+impl<W: Write> OutputSink for Json<W> {
+    fn line(&mut self, path: &Path, line: &Line) -> io::Result<()> {
+        let kind = if line.selected { "match" } else { "context" };
+        let spans: Vec<String> = line
+            .spans
+            .iter()
+            .map(|span| {
+                format!(
+                    r#"{{"start":{},"end":{},"text":{},"pattern":{}}}"#,
+                    span.bytes.start,
+                    span.bytes.end,
+                    json_string(&line.text[span.bytes.clone()]),
+                    span.pattern
+                )
+            })
+            .collect();
+        writeln!(
+            self.out,
+            r#"{{"type":"{kind}","path":{},"line_number":{},"byte_offset":{},"text":{},"spans":[{}]}}"#,
+            json_string(&path.to_string_lossy()),
+            line.number,
+            line.offset,
+            json_string(&line.text),
+            spans.join(",")
+        )
+    }
+
+    fn binary(&mut self, path: &Path) -> io::Result<()> {
+        writeln!(self.out, r#"{{"type":"binary","path":{}}}"#, json_string(&path.to_string_lossy()))
+    }
+}
+
+/// Holds the lines back, and passes them on to another sink sorted by
+/// `score`, lowest first, when [`finish`](Ranked::finish) is called. Lines
+/// that score the same keep their order. Everything else goes straight
+/// through, so binary files and errors are reported before the lines.
+pub struct Ranked<'s, F> {
+    inner: &'s mut dyn OutputSink,
+    score: F,
+    lines: Vec<(usize, PathBuf, Line)>,
+}
+
+impl<'s, F: Fn(&Line) -> usize> Ranked<'s, F> {
+    pub fn new(inner: &'s mut dyn OutputSink, score: F) -> Ranked<'s, F> {
+        Ranked {
+            inner,
+            score,
+            lines: Vec::new(),
+        }
+    }
+
+    /// Passes on the lines, best first.
+    pub fn finish(mut self) -> io::Result<()> {
+        self.lines.sort_by_key(|(score, _, _)| *score);
+        for (_, path, line) in &self.lines {
+            self.inner.line(path, line)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: Fn(&Line) -> usize> OutputSink for Ranked<'_, F> {
+    fn begin(&mut self, path: &Path) -> io::Result<()> {
+        self.inner.begin(path)
+    }
+
+    fn line(&mut self, path: &Path, line: &Line) -> io::Result<()> {
+        self.lines.push(((self.score)(line), path.to_path_buf(), line.clone()));
+        Ok(())
+    }
+
+    fn binary(&mut self, path: &Path) -> io::Result<()> {
+        self.inner.binary(path)
+    }
+
+    fn end(&mut self, path: &Path, selected: usize) -> io::Result<()> {
+        self.inner.end(path, selected)
+    }
+
+    fn error(&mut self, message: &str) -> io::Result<()> {
+        self.inner.error(message)
+    }
+}
+
+/// Quotes `s` as a JSON string.
+fn json_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c < ' ' => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_strings_are_escaped() {
+        assert_eq!(json_string("plain"), r#""plain""#);
+        assert_eq!(json_string("a \"b\" \\ c"), r#""a \"b\" \\ c""#);
+        assert_eq!(json_string("tab\tbell\u{7}"), r#""tab\tbell\u0007""#);
+        assert_eq!(json_string("été"), r#""été""#);
+    }
+
+    #[test]
+    fn colors() {
+        let palette = Palette::parse("mt=01;32::se=").unwrap();
+        assert_eq!((palette.matched.as_str(), palette.separator.as_str()), ("01;32", ""));
+        assert_eq!(palette.path, Palette::default().path);
+        assert_eq!(Palette::parse("mt=1:xx=2"), Err("xx=2".to_string()));
+        assert_eq!(Palette::parse("fn=red"), Err("fn=red".to_string()));
+
+        let mut out = Vec::new();
+        let mut sink = Standard::new(&mut out);
+        sink.with_path = true;
+        sink.line_numbers = true;
+        sink.colors = Some(palette);
+        let span = |bytes| PatternMatch { bytes, pattern: 0 };
+        let line = Line {
+            number: 7,
+            offset: 0,
+            text: "a frog, a frog".to_string(),
+            spans: vec![span(2..6), span(10..14)],
+            selected: true,
+        };
+        sink.line(Path::new("poem.txt"), &line).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[35mpoem.txt\x1b[m:\x1b[32m7\x1b[m:a \x1b[01;32mfrog\x1b[m, a \x1b[01;32mfrog\x1b[m\n"
+        );
+    }
+}
@@ -0,0 +1,585 @@
+//! Browsing the matches in the terminal, for `--tui`.
+//!
+//! The screen has the query at the top, the matching lines below it, and
+//! the lines around the selected match at the bottom. Every key that
+//! changes the query searches again, with the rest of the `Config` as
+//! given. Up, Down, Page Up and Page Down move through the matches, Enter
+//! opens the selected one in `$VISUAL` or `$EDITOR` (else `vi`) at its line,
+//! Ctrl-U clears the query, and Esc or Ctrl-C leaves.
+//!
+//! The terminal is put in raw mode with termios(3), and drawn on with ANSI
+//! escapes on the alternate screen, so the shell's screen comes back as it
+//! was.
+
+use std::env;
+use std::error::Error;
+use std::io::{self, BufRead, IsTerminal, Read, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::sink::{Line, OutputSink};
+use crate::{metadata, Config, OutputMode};
+
+const MATCH: &str = "\x1b[1;31m";
+const END_MATCH: &str = "\x1b[22;39m";
+const SELECTED: &str = "\x1b[7m";
+const END_SELECTED: &str = "\x1b[27m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// Runs the browser until it is left.
+pub(crate) fn run(config: &Config) -> Result<(), Box<dyn Error>> {
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        return Err("--tui needs a terminal".into());
+    }
+    for path in &config.paths {
+        metadata(path)?;
+    }
+    let mut app = App::new(config);
+    app.search();
+    let terminal = Terminal::enter()?;
+    let mut stdin = io::stdin().lock();
+    let mut stdout = io::stdout().lock();
+    let mut buf = [0; 256];
+    loop {
+        let (rows, columns) = terminal.size();
+        stdout.write_all(app.render(rows, columns).as_bytes())?;
+        stdout.flush()?;
+        let read = stdin.read(&mut buf)?;
+        if read == 0 {
+            return Ok(());
+        }
+        let query = app.query.clone();
+        for key in keys(&buf[..read]) {
+            match app.press(key) {
+                Action::None => {}
+                Action::Quit => return Ok(()),
+                Action::Open(path, line) => {
+                    terminal.leave();
+                    let opened = open_editor(&path, line);
+                    terminal.resume()?;
+                    if let Err(err) = opened {
+                        app.error = Some(err);
+                    }
+                }
+            }
+        }
+        // Typing or pasting several keys at once searches once.
+        if app.query != query {
+            app.search();
+        }
+    }
+}
+
+/// A key read from the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Key {
+    Char(char),
+    /// A letter typed with Ctrl held.
+    Ctrl(char),
+    Backspace,
+    Enter,
+    Escape,
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+}
+
+/// The keys in `bytes`, as a terminal in raw mode sends them. An escape
+/// sequence is taken to arrive in one read, so a lone ESC is the Esc key.
+fn keys(bytes: &[u8]) -> Vec<Key> {
+    let mut keys = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        i += 1;
+        let key = match byte {
+            0x1b if matches!(bytes.get(i), Some(b'[' | b'O')) => {
+                // CSI or SS3: parameters, then a final byte from `@` to `~`.
+                let start = i + 1;
+                let end = bytes[start..].iter().position(|b| (0x40..=0x7e).contains(b)).map_or(bytes.len(), |n| start + n);
+                i = (end + 1).min(bytes.len());
+                match &bytes[start..i] {
+                    b"A" => Key::Up,
+                    b"B" => Key::Down,
+                    b"5~" => Key::PageUp,
+                    b"6~" => Key::PageDown,
+                    _ => continue,
+                }
+            }
+            0x1b => Key::Escape,
+            b'\r' | b'\n' => Key::Enter,
+            0x7f | 0x08 => Key::Backspace,
+            0x01..=0x1a => Key::Ctrl(char::from(b'a' + byte - 1)),
+            0x00..=0x1f => continue,
+            _ => {
+                let len = match byte {
+                    0xf0.. => 4,
+                    0xe0.. => 3,
+                    0xc0.. => 2,
+                    _ => 1,
+                };
+                let end = (i - 1 + len).min(bytes.len());
+                let text = String::from_utf8_lossy(&bytes[i - 1..end]);
+                i = end;
+                match text.chars().next() {
+                    Some(c) if c != char::REPLACEMENT_CHARACTER => Key::Char(c),
+                    _ => continue,
+                }
+            }
+        };
+        keys.push(key);
+    }
+    keys
+}
+
+/// What a key asks of the loop around the [`App`].
+#[derive(Debug, PartialEq, Eq)]
+enum Action {
+    None,
+    Quit,
+    /// Open the file in the editor at this line.
+    Open(PathBuf, usize),
+}
+
+/// A selected line and the file it is in.
+struct Hit {
+    path: PathBuf,
+    line: Line,
+}
+
+/// What the browser shows, and how it changes with each key.
+struct App<'c> {
+    config: &'c Config,
+    query: String,
+    hits: Vec<Hit>,
+    /// What went wrong with the last search or the editor.
+    error: Option<String>,
+    /// The hit selected, and the first one on the screen.
+    selected: usize,
+    top: usize,
+    preview: Option<Preview>,
+}
+
+/// The lines around a hit, with their numbers.
+struct Preview {
+    /// The hit, and how many lines were read for it.
+    hit: usize,
+    rows: usize,
+    lines: Vec<(usize, String)>,
+}
+
+impl<'c> App<'c> {
+    fn new(config: &'c Config) -> App<'c> {
+        App {
+            config,
+            query: config.patterns.first().cloned().unwrap_or_default(),
+            hits: Vec::new(),
+            error: None,
+            selected: 0,
+            top: 0,
+            preview: None,
+        }
+    }
+
+    /// Searches for the query afresh. Files that cannot be read are skipped
+    /// without a word, as there is nowhere to say it.
+    fn search(&mut self) {
+        self.hits.clear();
+        self.error = None;
+        self.selected = 0;
+        self.top = 0;
+        self.preview = None;
+        if self.query.is_empty() {
+            return;
+        }
+        let config = Config {
+            patterns: vec![self.query.clone()],
+            pattern_files: Vec::new(),
+            output: OutputMode::Lines,
+            before_context: 0,
+            after_context: 0,
+            tui: false,
+            ..self.config.clone()
+        };
+        if let Err(err) = crate::run_with(&config, &mut Collect(&mut self.hits)) {
+            self.error = Some(err.to_string());
+        }
+    }
+
+    fn press(&mut self, key: Key) -> Action {
+        let last = self.hits.len().saturating_sub(1);
+        match key {
+            Key::Escape | Key::Ctrl('c') => return Action::Quit,
+            Key::Enter => {
+                if let Some(hit) = self.hits.get(self.selected) {
+                    return Action::Open(hit.path.clone(), hit.line.number);
+                }
+            }
+            Key::Char(c) => self.query.push(c),
+            Key::Backspace => {
+                self.query.pop();
+            }
+            Key::Ctrl('u') => self.query.clear(),
+            Key::Up | Key::Ctrl('p') => self.selected = self.selected.saturating_sub(1),
+            Key::Down | Key::Ctrl('n') => self.selected = (self.selected + 1).min(last),
+            Key::PageUp => self.selected = self.selected.saturating_sub(10),
+            Key::PageDown => self.selected = (self.selected + 10).min(last),
+            Key::Ctrl(_) => {}
+        }
+        Action::None
+    }
+
+    /// The whole screen, `rows` by `columns`, as escapes and text.
+    fn render(&mut self, rows: usize, columns: usize) -> String {
+        let list_rows = rows.saturating_sub(3).div_ceil(2).max(1);
+        let preview_rows = rows.saturating_sub(3 + list_rows);
+        if self.selected < self.top {
+            self.top = self.selected;
+        } else if self.selected >= self.top + list_rows {
+            self.top = self.selected + 1 - list_rows;
+        }
+
+        let mut lines = vec![format!("> {}", clip(&self.query, &[], columns.saturating_sub(2)))];
+        let status = match &self.error {
+            Some(error) => format!("{MATCH}{}{END_MATCH}", clip(error, &[], columns)),
+            None if self.query.is_empty() => format!("{DIM}type to search{RESET}"),
+            None => format!("{DIM}{} of {} matching lines{RESET}", (self.selected + 1).min(self.hits.len()), self.hits.len()),
+        };
+        lines.push(status);
+        for (i, hit) in self.hits.iter().enumerate().skip(self.top).take(list_rows) {
+            let prefix = format!("{}:{}:", hit.path.display(), hit.line.number);
+            let spans: Vec<_> = hit.line.spans.iter().map(|m| m.bytes.clone()).collect();
+            let text = clip(&hit.line.text, &spans, columns.saturating_sub(prefix.chars().count()));
+            let line = format!("{DIM}{}{RESET}{text}", clip(&prefix, &[], columns));
+            lines.push(if i == self.selected { format!("{SELECTED}{line}{END_SELECTED}") } else { line });
+        }
+        lines.resize(2 + list_rows, String::new());
+
+        if let Some(hit) = self.hits.get(self.selected) {
+            let title = format!("── {} ", hit.path.display());
+            let fill = columns.saturating_sub(title.chars().count());
+            lines.push(format!("{DIM}{}{}{RESET}", clip(&title, &[], columns), "─".repeat(fill)));
+            if self.preview.as_ref().is_none_or(|p| (p.hit, p.rows) != (self.selected, preview_rows)) {
+                self.preview = Some(Preview {
+                    hit: self.selected,
+                    rows: preview_rows,
+                    lines: preview(hit, self.config, preview_rows),
+                });
+            }
+            let hit = &self.hits[self.selected];
+            let spans: Vec<_> = hit.line.spans.iter().map(|m| m.bytes.clone()).collect();
+            for (number, text) in &self.preview.as_ref().unwrap().lines {
+                let (marker, spans) = if *number == hit.line.number { ('>', &spans[..]) } else { (' ', &[][..]) };
+                let text = clip(text, spans, columns.saturating_sub(8));
+                lines.push(format!("{marker}{DIM}{number:>6}{RESET} {text}"));
+            }
+        }
+
+        let mut screen = String::from("\x1b[H");
+        for (i, line) in lines.iter().take(rows).enumerate() {
+            // A line break after the last row would scroll the screen.
+            if i > 0 {
+                screen.push_str("\r\n");
+            }
+            screen.push_str(line);
+            screen.push_str("\x1b[K");
+        }
+        screen.push_str("\x1b[J");
+        // Leave the cursor at the end of the query.
+        let column = 3 + self.query.chars().count().min(columns.saturating_sub(3));
+        screen.push_str(&format!("\x1b[1;{column}H"));
+        screen
+    }
+}
+
+/// Up to `rows` lines around the line of `hit` in its file.
+fn preview(hit: &Hit, config: &Config, rows: usize) -> Vec<(usize, String)> {
+    let Ok(reader) = crate::open(&hit.path, config.decompress) else {
+        return Vec::new();
+    };
+    let first = hit.line.number.saturating_sub(rows / 2).max(1);
+    reader
+        .split(b'\n')
+        .map_while(Result::ok)
+        .zip(1..)
+        .skip(first - 1)
+        .take(rows)
+        .map(|(line, number)| {
+            let line = line.strip_suffix(b"\r").unwrap_or(&line);
+            (number, String::from_utf8_lossy(line).into_owned())
+        })
+        .collect()
+}
+
+/// `text` cut to `width` characters, with control characters shown as
+/// spaces and the bytes in `spans` highlighted.
+fn clip(text: &str, spans: &[Range<usize>], width: usize) -> String {
+    let mut clipped = String::new();
+    let mut highlighted = false;
+    for (i, c) in text.char_indices().take(width) {
+        let inside = spans.iter().any(|span| span.contains(&i));
+        if inside != highlighted {
+            clipped.push_str(if inside { MATCH } else { END_MATCH });
+            highlighted = inside;
+        }
+        clipped.push(if c.is_control() { ' ' } else { c });
+    }
+    if highlighted {
+        clipped.push_str(END_MATCH);
+    }
+    clipped
+}
+
+/// Collects the selected lines.
+struct Collect<'h>(&'h mut Vec<Hit>);
+
+impl OutputSink for Collect<'_> {
+    fn line(&mut self, path: &Path, line: &Line) -> io::Result<()> {
+        if line.selected {
+            self.0.push(Hit {
+                path: path.to_path_buf(),
+                line: line.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    fn error(&mut self, _message: &str) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Runs the editor on `path` at `line`, and waits for it. Returns what went
+/// wrong, if anything, to be shown.
+fn open_editor(path: &Path, line: usize) -> Result<(), String> {
+    let editor = env::var("VISUAL")
+        .or_else(|_| env::var("EDITOR"))
+        .ok()
+        .filter(|editor| !editor.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+    // Like git, allow arguments in the variable, as in `code --wait`.
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or("vi");
+    match Command::new(program).args(words).arg(format!("+{line}")).arg(path).status() {
+        Ok(status) if status.success() => Ok(()),
+        Ok(status) => Err(format!("{editor}: {status}")),
+        Err(err) => Err(format!("{editor}: {err}")),
+    }
+}
+
+/// The terminal in raw mode, showing the alternate screen, until dropped.
+struct Terminal {
+    /// The settings to go back to.
+    saved: termios::Termios,
+}
+
+impl Terminal {
+    fn enter() -> io::Result<Terminal> {
+        let terminal = Terminal { saved: termios::get()? };
+        terminal.resume()?;
+        Ok(terminal)
+    }
+
+    /// Puts the terminal back in raw mode on the alternate screen.
+    fn resume(&self) -> io::Result<()> {
+        termios::set(&termios::raw(&self.saved))?;
+        let mut stdout = io::stdout();
+        stdout.write_all(b"\x1b[?1049h")?;
+        stdout.flush()
+    }
+
+    /// Puts the terminal back as it was, for now.
+    fn leave(&self) {
+        let mut stdout = io::stdout();
+        let _ = stdout.write_all(b"\x1b[0m\x1b[?1049l");
+        let _ = stdout.flush();
+        let _ = termios::set(&self.saved);
+    }
+
+    /// The rows and columns of the terminal, 24 by 80 if it will not say.
+    fn size(&self) -> (usize, usize) {
+        match termios::size() {
+            Some((rows, columns)) if rows > 0 && columns > 0 => (rows, columns),
+            _ => (24, 80),
+        }
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        self.leave();
+    }
+}
+
+/// Just enough of termios(3) and the `TIOCGWINSZ` ioctl(2) for the terminal
+/// on standard input, declared by hand as minigrep has no dependencies.
+#[cfg(unix)]
+mod termios {
+    use std::ffi::{c_int, c_ulong};
+    use std::io;
+
+    const STDIN: c_int = 0;
+    const TCSANOW: c_int = 0;
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    const TIOCGWINSZ: c_ulong = 0x5413;
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    const TIOCGWINSZ: c_ulong = 0x4008_7468;
+
+    /// A `struct termios`, whose layout differs from one system to the
+    /// next; it is only ever filled in and read by the C library, and is
+    /// larger than any of them.
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub(super) struct Termios([u64; 32]);
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct Winsize {
+        rows: u16,
+        columns: u16,
+        x_pixels: u16,
+        y_pixels: u16,
+    }
+
+    extern "C" {
+        fn tcgetattr(fd: c_int, termios: *mut Termios) -> c_int;
+        fn tcsetattr(fd: c_int, actions: c_int, termios: *const Termios) -> c_int;
+        fn cfmakeraw(termios: *mut Termios);
+        fn ioctl(fd: c_int, request: c_ulong, ...) -> c_int;
+    }
+
+    /// The settings of the terminal.
+    pub(super) fn get() -> io::Result<Termios> {
+        let mut termios = Termios([0; 32]);
+        // SAFETY: `termios` is larger than the C library's `struct termios`
+        // and outlives the call.
+        if unsafe { tcgetattr(STDIN, &mut termios) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(termios)
+    }
+
+    pub(super) fn set(termios: &Termios) -> io::Result<()> {
+        // SAFETY: `termios` was filled in by `tcgetattr`.
+        if unsafe { tcsetattr(STDIN, TCSANOW, termios) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    /// `termios` in raw mode, without echo, as `stty raw -echo` sets it.
+    pub(super) fn raw(termios: &Termios) -> Termios {
+        let mut raw = *termios;
+        // SAFETY: `raw` was filled in by `tcgetattr`.
+        unsafe { cfmakeraw(&mut raw) };
+        raw
+    }
+
+    /// The rows and columns of the terminal, if it says.
+    pub(super) fn size() -> Option<(usize, usize)> {
+        let mut size = Winsize::default();
+        // SAFETY: `TIOCGWINSZ` fills in a `struct winsize`, which `size`
+        // has the layout of, and outlives the call.
+        if unsafe { ioctl(STDIN, TIOCGWINSZ, &mut size as *mut Winsize) } != 0 {
+            return None;
+        }
+        Some((usize::from(size.rows), usize::from(size.columns)))
+    }
+}
+
+/// Without termios, there is no raw mode to put the terminal in.
+#[cfg(not(unix))]
+mod termios {
+    use std::io;
+
+    pub(super) type Termios = ();
+
+    pub(super) fn get() -> io::Result<Termios> {
+        Err(io::Error::new(io::ErrorKind::Unsupported, "--tui needs a Unix terminal"))
+    }
+
+    pub(super) fn set(_termios: &Termios) -> io::Result<()> {
+        Ok(())
+    }
+
+    pub(super) fn raw(_termios: &Termios) -> Termios {}
+
+    pub(super) fn size() -> Option<(usize, usize)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reading_keys() {
+        assert_eq!(
+            keys(b"fr\xc3\xb6g\x7f\r"),
+            [Key::Char('f'), Key::Char('r'), Key::Char('ö'), Key::Char('g'), Key::Backspace, Key::Enter]
+        );
+        assert_eq!(
+            keys(b"\x1b[A\x1bOB\x1b[5~\x1b[6~\x1b[1;5C\x03\x1b"),
+            [Key::Up, Key::Down, Key::PageUp, Key::PageDown, Key::Ctrl('c'), Key::Escape]
+        );
+    }
+
+    fn poem() -> Config {
+        Config {
+            paths: vec![PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("poem.txt")],
+            jobs: 1,
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn searching_as_you_type() {
+        let config = poem();
+        let mut app = App::new(&config);
+        app.search();
+        assert!(app.hits.is_empty());
+        for c in "body".chars() {
+            app.press(Key::Char(c));
+            app.search();
+        }
+        let numbers: Vec<_> = app.hits.iter().map(|hit| hit.line.number).collect();
+        assert_eq!(numbers, [1, 2, 6]);
+
+        assert_eq!(app.press(Key::Up), Action::None);
+        assert_eq!(app.selected, 0);
+        app.press(Key::PageDown);
+        assert_eq!(app.selected, 2);
+        assert_eq!(app.press(Key::Enter), Action::Open(config.paths[0].clone(), 6));
+        app.press(Key::Ctrl('u'));
+        app.search();
+        assert_eq!(app.press(Key::Enter), Action::None);
+        assert_eq!(app.press(Key::Escape), Action::Quit);
+    }
+
+    #[test]
+    fn drawing() {
+        let config = Config {
+            patterns: vec!["frog".to_string()],
+            ..poem()
+        };
+        let mut app = App::new(&config);
+        app.search();
+        let screen = app.render(9, 40);
+        let rows: Vec<&str> = screen.split("\x1b[K\r\n").collect();
+        assert_eq!(rows.len(), 9);
+        assert_eq!(rows[0], "\x1b[H> frog");
+        assert_eq!(rows[1], format!("{DIM}1 of 1 matching lines{RESET}"));
+        let prefix = format!("{}:7:", config.paths[0].display());
+        assert!(rows[2].starts_with(&format!("{SELECTED}{DIM}{}", clip(&prefix, &[], 40))));
+        assert_eq!(rows[3..5], ["", ""]);
+        assert!(rows[5].starts_with(&format!("{DIM}── {}", clip(&config.paths[0].display().to_string(), &[], 37))));
+        // The preview has the lines around the match, the match marked.
+        assert_eq!(rows[6], format!(" {DIM}     6{RESET} How dreary to be somebody!"));
+        assert_eq!(rows[7], format!(">{DIM}     7{RESET} How public, like a {MATCH}frog{END_MATCH}"));
+        assert!(rows[8].ends_with("\x1b[K\x1b[J\x1b[1;7H"));
+    }
+}
@@ -0,0 +1,158 @@
+//! Expanding the paths given to minigrep into the files to search.
+//!
+//! Directories are walked recursively in name order. Inside them, hidden
+//! entries (names starting with `.`) are skipped unless asked for, `.git`
+//! directories and minigrep's [index](crate::index) files are always
+//! skipped, and so is anything excluded by the
+//! [`ignore`](crate::ignore) files met on the way down. Paths given
+//! explicitly are searched even if they are hidden or ignored. Symbolic links
+//! to files are followed; links to directories are not.
+
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use crate::ignore::Ignore;
+use crate::index;
+
+/// A path that could not be walked.
+#[derive(Debug)]
+pub struct Error {
+    pub path: PathBuf,
+    pub source: io::Error,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.source)
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// The ignore rules in force in a directory: its own, then its parents'.
+struct Level {
+    ignore: Ignore,
+    parent: Option<Rc<Level>>,
+}
+
+impl Level {
+    fn ignores(level: &Option<Rc<Level>>, path: &Path, is_dir: bool) -> bool {
+        let mut level = level.as_deref();
+        while let Some(current) = level {
+            if let Some(ignored) = current.ignore.matched(path, is_dir) {
+                return ignored;
+            }
+            level = current.parent.as_deref();
+        }
+        false
+    }
+}
+
+struct Pending {
+    path: PathBuf,
+    is_dir: bool,
+    rules: Option<Rc<Level>>,
+}
+
+/// An iterator over the files below a list of paths, in a stable order.
+pub struct Walk {
+    stack: Vec<Result<Pending, Error>>,
+    hidden: bool,
+}
+
+impl Walk {
+    /// Walks `paths` in the order given. With `hidden`, entries whose names
+    /// start with `.` are searched too.
+    pub fn new(paths: &[PathBuf], hidden: bool) -> Walk {
+        let stack = paths
+            .iter()
+            .rev()
+            .map(|path| match fs::metadata(path) {
+                Ok(metadata) => Ok(Pending {
+                    path: path.clone(),
+                    is_dir: metadata.is_dir(),
+                    rules: None,
+                }),
+                Err(source) => Err(Error {
+                    path: path.clone(),
+                    source,
+                }),
+            })
+            .collect();
+        Walk { stack, hidden }
+    }
+
+    /// Queues the entries of `dir` that are to be searched.
+    fn expand(&mut self, dir: Pending) -> Result<(), Error> {
+        let error = |source| Error {
+            path: dir.path.clone(),
+            source,
+        };
+        let rules = match Ignore::read(&dir.path).map_err(error)? {
+            Some(ignore) => Some(Rc::new(Level {
+                ignore,
+                parent: dir.rules.clone(),
+            })),
+            None => dir.rules.clone(),
+        };
+
+        let mut children = Vec::new();
+        for entry in fs::read_dir(&dir.path).map_err(error)? {
+            let entry = entry.map_err(error)?;
+            let name = entry.file_name();
+            let hidden = name.to_string_lossy().starts_with('.');
+            if name == ".git" || name == index::FILE_NAME || (hidden && !self.hidden) {
+                continue;
+            }
+            let path = entry.path();
+            let file_type = entry.file_type().map_err(error)?;
+            let is_dir = if file_type.is_symlink() {
+                match fs::metadata(&path) {
+                    Ok(target) if target.is_dir() => continue,
+                    Ok(_) => false,
+                    // A dangling link: let reading it report the error.
+                    Err(_) => false,
+                }
+            } else {
+                file_type.is_dir()
+            };
+            if Level::ignores(&rules, &path, is_dir) {
+                continue;
+            }
+            children.push(Pending {
+                path,
+                is_dir,
+                rules: rules.clone(),
+            });
+        }
+        children.sort_by(|a, b| a.path.cmp(&b.path));
+        self.stack.extend(children.into_iter().rev().map(Ok));
+        Ok(())
+    }
+}
+
+impl Iterator for Walk {
+    type Item = Result<PathBuf, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.stack.pop()? {
+                Ok(pending) if pending.is_dir => {
+                    if let Err(err) = self.expand(pending) {
+                        return Some(Err(err));
+                    }
+                }
+                Ok(pending) => return Some(Ok(pending.path)),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
@@ -0,0 +1,699 @@
+//! Runs the `minigrep` binary against `poem.txt`, the way chapter 12 does.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::thread;
+use std::time::Duration;
+
+fn minigrep(args: &[&str], ignore_case: bool) -> Output {
+    minigrep_in(Path::new(env!("CARGO_MANIFEST_DIR")), args, ignore_case)
+}
+
+fn minigrep_in(dir: &Path, args: &[&str], ignore_case: bool) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_minigrep"));
+    command
+        .args(args)
+        .current_dir(dir)
+        .env_remove("IGNORE_CASE")
+        .env_remove("NO_COLOR")
+        .env_remove("MINIGREP_COLORS")
+        .env("MINIGREP_CONFIG", "");
+    if ignore_case {
+        command.env("IGNORE_CASE", "1");
+    }
+    command.output().expect("failed to run minigrep")
+}
+
+/// Creates a scratch directory holding `files`, given as `(path, contents)`.
+fn tree(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("minigrep-{}-{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    for (path, contents) in files {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+    root
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+fn stderr(output: &Output) -> &str {
+    std::str::from_utf8(&output.stderr).unwrap()
+}
+
+#[test]
+fn one_match() {
+    let output = minigrep(&["frog", "poem.txt"], false);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "How public, like a frog\n");
+}
+
+#[test]
+fn several_matches() {
+    let output = minigrep(&["body", "poem.txt"], false);
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "I'm nobody! Who are you?\nAre you nobody, too?\nHow dreary to be somebody!\n"
+    );
+}
+
+#[test]
+fn no_match() {
+    let output = minigrep(&["monomorphization", "poem.txt"], false);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "");
+}
+
+#[test]
+fn ignore_case_from_environment() {
+    let output = minigrep(&["to", "poem.txt"], false);
+    assert_eq!(
+        stdout(&output),
+        "Are you nobody, too?\nHow dreary to be somebody!\n"
+    );
+
+    let output = minigrep(&["to", "poem.txt"], true);
+    assert_eq!(
+        stdout(&output),
+        "Are you nobody, too?\n\
+         How dreary to be somebody!\n\
+         To tell your name the livelong day\n\
+         To an admiring bog!\n"
+    );
+}
+
+#[test]
+fn errors_go_to_stderr() {
+    let output = minigrep(&[], false);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "");
+    assert_eq!(
+        stderr(&output),
+        "Problem parsing arguments: Didn't get a query string\n"
+    );
+
+    let output = minigrep(&["to", "poem.txt", "no-such-file.txt"], false);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "");
+    assert!(stderr(&output).starts_with("Application error: no-such-file.txt: "), "{}", stderr(&output));
+}
+
+#[test]
+fn flags_environment_and_config_file() {
+    let output = minigrep(&["--help"], false);
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with("Prints the lines of files that contain a query.\n"));
+    assert!(stdout(&output).contains("  -i, --ignore-case "));
+    let output = minigrep(&["-V"], false);
+    assert_eq!(stdout(&output), concat!("minigrep ", env!("CARGO_PKG_VERSION"), "\n"));
+
+    let output = minigrep(&["--frog", "poem.txt"], false);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stderr(&output), "Problem parsing arguments: unknown flag --frog, see --help\n");
+
+    // The command line wins over IGNORE_CASE, which wins over the file.
+    let root = tree("config", &[("config.toml", "# defaults\nline-number = true\nignore-case = true\n")]);
+    let poem = Path::new(env!("CARGO_MANIFEST_DIR")).join("poem.txt");
+    let run = |args: &[&str], ignore_case: Option<&str>| {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_minigrep"));
+        command.args(args).arg(&poem).env("MINIGREP_CONFIG", root.join("config.toml")).env_remove("IGNORE_CASE");
+        if let Some(value) = ignore_case {
+            command.env("IGNORE_CASE", value);
+        }
+        command.output().expect("failed to run minigrep")
+    };
+    assert_eq!(stdout(&run(&["TELL"], None)), "3:Then there's a pair of us - don't tell!\n8:To tell your name the livelong day\n");
+    assert_eq!(stdout(&run(&["TELL"], Some("0"))), "");
+    assert_eq!(stdout(&run(&["-i", "TELL"], Some("false"))), "3:Then there's a pair of us - don't tell!\n8:To tell your name the livelong day\n");
+    assert_eq!(stdout(&run(&["--case-sensitive", "TELL"], Some("1"))), "");
+
+    fs::write(root.join("config.toml"), "threads = lots\n").unwrap();
+    let output = run(&["TELL"], None);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).ends_with(
+        "config.toml:1: expected true, false, a number or a string, not `lots`\n"
+    ));
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn regex_queries() {
+    let output = minigrep(&["-E", "^(How|To) [a-z]+ ", "poem.txt"], false);
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "How dreary to be somebody!\n\
+         To tell your name the livelong day\n\
+         To an admiring bog!\n"
+    );
+
+    let output = minigrep(&["-E", "^to", "poem.txt"], true);
+    assert_eq!(stdout(&output), "To tell your name the livelong day\nTo an admiring bog!\n");
+
+    let output = minigrep(&["-E", "(frog", "poem.txt"], false);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stderr(&output), "Application error: invalid regex: unclosed group at position 0\n");
+}
+
+#[test]
+fn directories_are_searched_recursively() {
+    let root = tree(
+        "recursive",
+        &[
+            ("poem.txt", "How public, like a frog\n"),
+            ("b/notes.txt", "a frog again\nno match\n"),
+            ("a/deep/er/frog.txt", "frog\n"),
+        ],
+    );
+    let output = minigrep_in(&root, &["frog", "."], false);
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "./a/deep/er/frog.txt:frog\n\
+         ./b/notes.txt:a frog again\n\
+         ./poem.txt:How public, like a frog\n"
+    );
+
+    let output = minigrep_in(&root, &["frog", "poem.txt", "b"], false);
+    assert_eq!(stdout(&output), "poem.txt:How public, like a frog\nb/notes.txt:a frog again\n");
+
+    let output = minigrep_in(&root, &["frog", "poem.txt"], false);
+    assert_eq!(stdout(&output), "How public, like a frog\n");
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn ignore_files_and_hidden_entries() {
+    let root = tree(
+        "ignored",
+        &[
+            (".gitignore", "target/\n*.log\n!keep.log\n"),
+            ("src/.ignore", "/generated.rs\n"),
+            ("src/lib.rs", "needle\n"),
+            ("src/generated.rs", "needle\n"),
+            ("src/sub/generated.rs", "needle\n"),
+            ("target/out.rs", "needle\n"),
+            ("debug.log", "needle\n"),
+            ("keep.log", "needle\n"),
+            (".hidden/file.txt", "needle\n"),
+            (".env", "needle\n"),
+            (".git/config", "needle\n"),
+        ],
+    );
+
+    let output = minigrep_in(&root, &["needle", "."], false);
+    assert_eq!(
+        stdout(&output),
+        "./keep.log:needle\n./src/lib.rs:needle\n./src/sub/generated.rs:needle\n"
+    );
+
+    let output = minigrep_in(&root, &["--hidden", "needle", "."], false);
+    assert_eq!(
+        stdout(&output),
+        "./.env:needle\n\
+         ./.hidden/file.txt:needle\n\
+         ./keep.log:needle\n\
+         ./src/lib.rs:needle\n\
+         ./src/sub/generated.rs:needle\n"
+    );
+
+    // Paths named on the command line are searched regardless.
+    let output = minigrep_in(&root, &["needle", "debug.log", ".env"], false);
+    assert_eq!(stdout(&output), "debug.log:needle\n.env:needle\n");
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn worker_threads_keep_the_order() {
+    let files: Vec<(String, String)> = (0..60)
+        .map(|i| {
+            let contents = (0..i * 20).map(|n| format!("line {n} of {i}\n")).collect();
+            (format!("dir{}/file{i:02}.txt", i % 7), contents)
+        })
+        .collect();
+    let files: Vec<(&str, &str)> = files.iter().map(|(p, c)| (p.as_str(), c.as_str())).collect();
+    let root = tree("jobs", &files);
+
+    let one = minigrep_in(&root, &["-j", "1", "-E", "^line 1[0-9]* ", "."], false);
+    assert!(one.status.success());
+    let expected: usize = (0..60)
+        .map(|i| (0..i * 20).filter(|n| n.to_string().starts_with('1')).count())
+        .sum();
+    assert_eq!(stdout(&one).lines().count(), expected);
+    for jobs in ["2", "8"] {
+        let many = minigrep_in(&root, &["-j", jobs, "-E", "^line 1[0-9]* ", "."], false);
+        assert_eq!(stdout(&many), stdout(&one));
+    }
+    fs::remove_dir_all(root).unwrap();
+}
+
+/// The most memory minigrep had resident at once, in kB, running with
+/// `args` in `dir`, and the length of its output, which is read as it comes.
+#[cfg(target_os = "linux")]
+fn peak_memory(dir: &Path, args: &[&str]) -> (u64, u64) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_minigrep"))
+        .args(args)
+        .current_dir(dir)
+        .env("MINIGREP_CONFIG", "")
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut out = child.stdout.take().unwrap();
+    let reader = thread::spawn(move || io::copy(&mut out, &mut io::sink()).unwrap());
+    // The high-water mark only grows, so reading it often enough until the
+    // process exits gives the peak.
+    let status = format!("/proc/{}/status", child.id());
+    let mut peak = 0;
+    while child.try_wait().unwrap().is_none() {
+        let text = fs::read_to_string(&status).unwrap_or_default();
+        if let Some(kb) = text.lines().find_map(|line| line.strip_prefix("VmHWM:")) {
+            peak = peak.max(kb.trim().trim_end_matches(" kB").parse().unwrap());
+        }
+        thread::sleep(Duration::from_millis(5));
+    }
+    (peak, reader.join().unwrap())
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn memory_is_bounded() {
+    // Every line matches, and the second file waits for the first.
+    let contents: String = (0..400_000).map(|n| format!("a matching line {n:09}\n")).collect();
+    let root = tree("memory", &[("a.log", &contents), ("b.log", &contents)]);
+    for args in [&["line", "."][..], &["-j4", "line", "."]] {
+        let (peak, output) = peak_memory(&root, args);
+        assert_eq!(output, 2 * 400_000 * "./a.log:a matching line 000000000\n".len() as u64);
+        assert!(peak < 20_000, "{args:?} peaked at {peak} kB");
+    }
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn line_numbers_offsets_and_context() {
+    let output = minigrep(&["-n", "-C1", "-E", "nobody|frog|bog", "poem.txt"], false);
+    assert_eq!(
+        stdout(&output),
+        "1:I'm nobody! Who are you?\n\
+         2:Are you nobody, too?\n\
+         3-Then there's a pair of us - don't tell!\n\
+         --\n\
+         6-How dreary to be somebody!\n\
+         7:How public, like a frog\n\
+         8-To tell your name the livelong day\n\
+         9:To an admiring bog!\n"
+    );
+
+    let output = minigrep(&["-b", "-A", "1", "tell", "poem.txt"], false);
+    assert_eq!(
+        stdout(&output),
+        "46:Then there's a pair of us - don't tell!\n\
+         86-They'd banish us, you know.\n\
+         --\n\
+         166:To tell your name the livelong day\n\
+         201-To an admiring bog!\n"
+    );
+
+    // Groups in different files are separated too.
+    let root = tree("context", &[("a.txt", "one\ntwo\n"), ("b.txt", "two\nthree\n")]);
+    let output = minigrep_in(&root, &["-n", "-B1", "two", "a.txt", "b.txt"], false);
+    assert_eq!(stdout(&output), "a.txt-1-one\na.txt:2:two\n--\nb.txt:1:two\n");
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn output_modes() {
+    let output = minigrep(&["-c", "to", "poem.txt"], false);
+    assert_eq!(stdout(&output), "2\n");
+
+    let output = minigrep(&["-v", "-n", "o", "poem.txt"], false);
+    assert_eq!(stdout(&output), "5:\n");
+
+    let output = minigrep(&["-o", "-b", "-E", "[a-z]*ody", "poem.txt"], false);
+    assert_eq!(stdout(&output), "4:nobody\n33:nobody\n132:somebody\n");
+    let output = minigrep(&["-o", "-E", "x*", "poem.txt"], false);
+    assert_eq!(stdout(&output), "");
+
+    let root = tree("modes", &[("a.txt", "frog\n"), ("b.txt", "toad\n"), ("c.txt", "frog\nfrog\n")]);
+    let output = minigrep_in(&root, &["-c", "frog", "."], false);
+    assert_eq!(stdout(&output), "./a.txt:1\n./b.txt:0\n./c.txt:2\n");
+    let output = minigrep_in(&root, &["-l", "frog", "."], false);
+    assert_eq!(stdout(&output), "./a.txt\n./c.txt\n");
+    let output = minigrep_in(&root, &["-L", "frog", "."], false);
+    assert_eq!(stdout(&output), "./b.txt\n");
+    fs::remove_dir_all(root).unwrap();
+
+    let output = minigrep(&["--json", "-E", "\"?frog", "poem.txt"], false);
+    assert_eq!(
+        stdout(&output),
+        r#"{"type":"match","path":"poem.txt","line_number":7,"byte_offset":142,"text":"How public, like a frog","spans":[{"start":19,"end":23,"text":"frog","pattern":0}]}"#
+            .to_string()
+            + "\n"
+    );
+}
+
+#[test]
+fn invalid_utf8_and_binary_files() {
+    let root = tree("binary", &[]);
+    fs::create_dir_all(&root).unwrap();
+    fs::write(root.join("latin1.txt"), b"caf\xe9 frog\nplain\n").unwrap();
+    fs::write(root.join("image.bin"), b"\x89PNG\0\0frog\nfrog again\n").unwrap();
+    // The NUL is past the first block read, so lines before it are printed.
+    let late = format!("frog\n{}\0frog\n", "toad\n".repeat(20_000));
+    fs::write(root.join("late.txt"), late).unwrap();
+
+    let output = minigrep_in(&root, &["frog", "latin1.txt"], false);
+    assert_eq!(stdout(&output), "caf\u{fffd} frog\n");
+
+    let output = minigrep_in(&root, &["-n", "frog", "."], false);
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "Binary file ./image.bin matches\n\
+         ./late.txt:1:frog\n\
+         Binary file ./late.txt matches\n\
+         ./latin1.txt:1:caf\u{fffd} frog\n"
+    );
+
+    let output = minigrep_in(&root, &["-c", "frog", "image.bin", "late.txt"], false);
+    assert_eq!(stdout(&output), "image.bin:2\nlate.txt:2\n");
+    let output = minigrep_in(&root, &["-a", "again", "image.bin"], false);
+    assert_eq!(stdout(&output), "frog again\n");
+    let output = minigrep_in(&root, &["toad", "image.bin"], false);
+    assert_eq!(stdout(&output), "");
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn several_patterns() {
+    let output = minigrep(&["-e", "frog", "-e", "bog", "poem.txt"], false);
+    assert_eq!(stdout(&output), "How public, like a frog\nTo an admiring bog!\n");
+
+    let root = tree(
+        "patterns",
+        &[
+            ("patterns.txt", "nobody\nbody\nYOU\n"),
+            ("text.txt", "Straße\nStrasse\nstrand\n"),
+        ],
+    );
+    let poem = Path::new(env!("CARGO_MANIFEST_DIR")).join("poem.txt");
+    let poem = poem.to_str().unwrap();
+    let output = minigrep_in(&root, &["-o", "-n", "-f", "patterns.txt", poem], true);
+    assert_eq!(stdout(&output), "1:nobody\n1:you\n2:you\n2:nobody\n4:you\n6:body\n8:you\n");
+
+    // Each span says which pattern it matched, counting -e before -f.
+    let output = minigrep_in(&root, &["--json", "-e", "frog", "-f", "patterns.txt", poem], false);
+    let json = stdout(&output);
+    assert!(json.contains(r#""text":"nobody","pattern":1}"#), "{json}");
+    assert!(json.contains(r#""text":"body","pattern":2}"#), "{json}");
+    assert!(json.contains(r#""text":"frog","pattern":0}"#), "{json}");
+
+    let output = minigrep_in(&root, &["-e", "STRASSE", "-e", "xyz", "text.txt"], true);
+    assert_eq!(stdout(&output), "Straße\nStrasse\n");
+
+    let output = minigrep_in(&root, &["-E", "-e", "^s.r", "-e", "e$", "text.txt"], false);
+    assert_eq!(stdout(&output), "Straße\nStrasse\nstrand\n");
+
+    let output = minigrep_in(&root, &["-f", "missing.txt", "text.txt"], false);
+    assert!(stderr(&output).starts_with("Application error: missing.txt: "));
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn replace_in_place() {
+    let root = tree(
+        "replace",
+        &[
+            ("src/a.rs", "fn old_name() {}\nfn main() {\n    old_name();\n}\n"),
+            ("src/b.rs", "// nothing to see\n"),
+            ("crlf.txt", "old_name\r\nkeep\r\n"),
+        ],
+    );
+    fs::write(root.join("latin1.txt"), b"caf\xe9 old_name\nold_name\n").unwrap();
+
+    let args = ["-E", "--replace", "new_${1}", "--dry-run", r"old_(\w+)", "src"];
+    let output = minigrep_in(&root, &args, false);
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "--- a/src/a.rs\n\
+         +++ b/src/a.rs\n\
+         @@ -1,1 +1,1 @@\n\
+         -fn old_name() {}\n\
+         +fn new_name() {}\n\
+         @@ -3,1 +3,1 @@\n\
+         -    old_name();\n\
+         +    new_name();\n"
+    );
+    assert_eq!(fs::read_to_string(root.join("src/a.rs")).unwrap(), "fn old_name() {}\nfn main() {\n    old_name();\n}\n");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(root.join("src/a.rs"), fs::Permissions::from_mode(0o751)).unwrap();
+    }
+    let args = ["-E", "--replace", "new_${1}", r"old_(\w+)", "."];
+    let output = minigrep_in(&root, &args, false);
+    assert_eq!(stdout(&output), "./crlf.txt\n./latin1.txt\n./src/a.rs\n");
+    assert_eq!(fs::read_to_string(root.join("src/a.rs")).unwrap(), "fn new_name() {}\nfn main() {\n    new_name();\n}\n");
+    assert_eq!(fs::read_to_string(root.join("crlf.txt")).unwrap(), "new_name\r\nkeep\r\n");
+    // The line that is not UTF-8 is left alone.
+    assert_eq!(fs::read(root.join("latin1.txt")).unwrap(), b"caf\xe9 old_name\nnew_name\n");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(root.join("src/a.rs")).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o751);
+    }
+    // No temporary files are left behind.
+    let mut names: Vec<_> = fs::read_dir(root.join("src")).unwrap().map(|e| e.unwrap().file_name()).collect();
+    names.sort();
+    assert_eq!(names, ["a.rs", "b.rs"]);
+
+    // The files that cannot be rewritten make it fail, once the others are.
+    fs::write(root.join("bin.dat"), b"new_name\0").unwrap();
+    fs::write(root.join("c.txt"), "new_name\n").unwrap();
+    let output = minigrep_in(&root, &["--replace", "name", "new_name", "bin.dat", "c.txt", "bin.dat"], false);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "c.txt\n");
+    assert_eq!(
+        stderr(&output),
+        "minigrep: bin.dat: binary file, not rewritten\n\
+         minigrep: bin.dat: binary file, not rewritten\n\
+         Application error: 2 files could not be rewritten\n"
+    );
+    assert_eq!(fs::read_to_string(root.join("c.txt")).unwrap(), "name\n");
+    fs::remove_file(root.join("bin.dat")).unwrap();
+    fs::remove_file(root.join("c.txt")).unwrap();
+
+    let output = minigrep_in(&root, &["-v", "--replace", "x", "y", "."], false);
+    assert_eq!(stderr(&output), "Problem parsing arguments: --replace cannot be used with -v\n");
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn trigram_index() {
+    let root = tree(
+        "index",
+        &[("a.txt", "a frog\n"), ("sub/b.txt", "a bog\n"), ("c.txt", "frogs and bogs\n")],
+    );
+    let output = minigrep_in(&root, &["index", "."], false);
+    assert_eq!(stdout(&output), ".: 3 files indexed, 3 read, 0 removed\n");
+    assert!(root.join(".minigrep-index").is_file());
+    let output = minigrep_in(&root, &["--hidden", "frog", "."], false);
+    assert_eq!(stdout(&output), "./a.txt:a frog\n./c.txt:frogs and bogs\n");
+
+    // A file the index vouches for is not read: here it is changed behind the
+    // index's back, keeping its size and modification time.
+    let b = root.join("sub/b.txt");
+    let modified = fs::metadata(&b).unwrap().modified().unwrap();
+    fs::write(&b, "frog!\n").unwrap();
+    fs::File::options().write(true).open(&b).unwrap().set_modified(modified).unwrap();
+    let output = minigrep_in(&root, &["frog", "."], false);
+    assert_eq!(stdout(&output), "./a.txt:a frog\n./c.txt:frogs and bogs\n");
+    // Without the index to go by, every file is read.
+    let output = minigrep_in(&root, &["-c", "frog", "."], false);
+    assert_eq!(stdout(&output), "./a.txt:1\n./c.txt:1\n./sub/b.txt:1\n");
+
+    // Changed files are searched as they are, and reindexing only reads them.
+    fs::write(root.join("a.txt"), "a frog\nand a toad\n").unwrap();
+    fs::remove_file(root.join("c.txt")).unwrap();
+    let output = minigrep_in(&root, &["-i", "TOAD", "sub", "a.txt"], false);
+    assert_eq!(stdout(&output), "a.txt:and a toad\n");
+    let output = minigrep_in(&root, &["index", "."], false);
+    assert_eq!(stdout(&output), ".: 2 files indexed, 1 read, 1 removed\n");
+
+    // A damaged index is not used, and is rebuilt by the next `index`.
+    fs::write(root.join(".minigrep-index"), "not an index").unwrap();
+    let output = minigrep_in(&root, &["frog", "."], false);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "./a.txt:a frog\n./sub/b.txt:frog!\n");
+    assert!(stderr(&output).ends_with(".minigrep-index: corrupt index: not an index, searching without it\n"));
+    let output = minigrep_in(&root, &["index", "."], false);
+    assert_eq!(
+        stdout(&output),
+        ".: 2 files indexed, 2 read, 0 removed (the old index was corrupt and has been rebuilt)\n"
+    );
+    let output = minigrep_in(&root, &["frog", "."], false);
+    assert_eq!(stderr(&output), "");
+
+    let output = minigrep_in(&root, &["index", "a.txt"], false);
+    assert_eq!(stderr(&output), "Application error: a.txt: not a directory\n");
+    let output = minigrep_in(&root, &["--", "index", "a.txt"], false);
+    assert_eq!(stdout(&output), "");
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn fuzzy_matches_are_ranked() {
+    let root = tree("fuzzy", &[("a.txt", "a bog\nfog\n"), ("b.txt", "Frog\ntoad\n"), ("c.txt", "frogs\n")]);
+    let output = minigrep_in(&root, &["--fuzzy", "1", "frog", "."], false);
+    assert_eq!(stdout(&output), "./c.txt:frogs\n./a.txt:fog\n./b.txt:Frog\n");
+    let output = minigrep_in(&root, &["-i", "--fuzzy=2", "-o", "frog", "."], false);
+    assert_eq!(stdout(&output), "./b.txt:Frog\n./c.txt:frog\n./a.txt:fog\n./a.txt: bog\n");
+    let output = minigrep_in(&root, &["--fuzzy", "1", "-c", "frog", "."], false);
+    assert_eq!(stdout(&output), "./a.txt:1\n./b.txt:1\n./c.txt:1\n");
+
+    let output = minigrep_in(&root, &["--fuzzy", "1", "-E", "fr.g", "."], false);
+    assert_eq!(stderr(&output), "Problem parsing arguments: --fuzzy cannot be used with -E\n");
+    let output = minigrep_in(&root, &["--fuzzy", "3", "fog", "."], false);
+    assert_eq!(
+        stderr(&output),
+        "Application error: --fuzzy 3 allows deleting all of `fog`, so every line would match\n"
+    );
+    fs::remove_dir_all(root).unwrap();
+}
+
+/// `printf 'a frog\na bog\n' | gzip -9n`.
+const FROGS_GZ: &[u8] = &[
+    0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x4b, 0x54, 0x48, 0x2b, 0xca, 0x4f, 0xe7, 0x4a, 0x54,
+    0x48, 0x02, 0x92, 0x00, 0xf6, 0x7b, 0xdd, 0x69, 0x0d, 0x00, 0x00, 0x00,
+];
+
+#[test]
+fn compressed_files() {
+    let root = tree("compressed", &[("plain.txt", "a frog\n")]);
+    // Found by how they start, whatever they are called.
+    fs::write(root.join("rotated.1"), FROGS_GZ).unwrap();
+    fs::write(root.join("raw"), &FROGS_GZ[10..FROGS_GZ.len() - 8]).unwrap();
+    let output = minigrep_in(&root, &["-nb", "bog", "."], false);
+    assert_eq!(stdout(&output), "./rotated.1:2:7:a bog\n");
+    let output = minigrep_in(&root, &["-z", "bog", "."], false);
+    assert_eq!(stdout(&output), "./raw:a bog\n./rotated.1:a bog\n");
+    assert!(stderr(&output).starts_with("minigrep: ./plain.txt: corrupt deflate data: "));
+    let output = minigrep_in(&root, &["--no-decompress", "-c", "frog", "."], false);
+    assert_eq!(stdout(&output), "./plain.txt:1\n./raw:0\n./rotated.1:0\n");
+
+    // Damaged data is reported after the lines before the damage.
+    fs::write(root.join("rotated.2"), [FROGS_GZ, FROGS_GZ].concat()).unwrap();
+    let mut damaged = fs::read(root.join("rotated.2")).unwrap();
+    damaged[FROGS_GZ.len() + 20] ^= 0xff;
+    fs::write(root.join("rotated.2"), damaged).unwrap();
+    let output = minigrep_in(&root, &["frog", "rotated.2"], false);
+    assert_eq!(stdout(&output), "a frog\na frog\n");
+    assert!(stderr(&output).starts_with("minigrep: rotated.2: corrupt gzip data: "));
+    fs::remove_file(root.join("rotated.2")).unwrap();
+
+    // Indexes hold what compressed files decompress to.
+    minigrep_in(&root, &["index", "."], false);
+    let output = minigrep_in(&root, &["bog", "."], false);
+    assert_eq!(stdout(&output), "./rotated.1:a bog\n");
+
+    let output = minigrep_in(&root, &["--replace", "toad", "frog", "rotated.1"], false);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stderr(&output),
+        "minigrep: rotated.1: compressed file, not rewritten\nApplication error: 1 file could not be rewritten\n"
+    );
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn follow_a_log() {
+    use std::io::{BufRead, BufReader, Write};
+    use std::process::Stdio;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    let root = tree("follow", &[("app.log", "an old frog\n")]);
+    let mut child = Command::new(env!("CARGO_BIN_EXE_minigrep"))
+        .args(["--follow", "-n", "frog", "app.log"])
+        .current_dir(&root)
+        .env("MINIGREP_CONFIG", "")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // Lines from stdout and stderr, in the order they come.
+    let (tx, lines) = mpsc::channel();
+    let err_tx = tx.clone();
+    let stdout = child.stdout.take().unwrap();
+    let stderr = child.stderr.take().unwrap();
+    thread::spawn(move || BufReader::new(stdout).lines().map_while(Result::ok).try_for_each(|l| tx.send(l)));
+    thread::spawn(move || BufReader::new(stderr).lines().map_while(Result::ok).try_for_each(|l| err_tx.send(l)));
+    let next = || lines.recv_timeout(Duration::from_secs(10)).expect("no output from minigrep --follow");
+    let append = |text: &str| {
+        let mut log = fs::File::options().append(true).open(root.join("app.log")).unwrap();
+        log.write_all(text.as_bytes()).unwrap();
+    };
+    // Give it time to read to the end.
+    thread::sleep(Duration::from_millis(500));
+
+    append("a toad\na new frog\n");
+    assert_eq!(next(), "3:a new frog");
+    fs::write(root.join("app.log"), "").unwrap();
+    assert_eq!(next(), "minigrep: app.log: truncated, following from the start");
+    append("frog one\n");
+    assert_eq!(next(), "1:frog one");
+    fs::rename(root.join("app.log"), root.join("app.log.1")).unwrap();
+    fs::write(root.join("app.log"), "toad\nfrog two\n").unwrap();
+    assert_eq!(next(), "minigrep: app.log: replaced, following the new file");
+    assert_eq!(next(), "2:frog two");
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn colored_output() {
+    // Piped, the output stays plain unless asked.
+    let output = minigrep(&["-n", "frog", "poem.txt"], false);
+    assert_eq!(stdout(&output), "7:How public, like a frog\n");
+
+    let output = minigrep(&["--color=always", "-n", "frog", "poem.txt"], false);
+    assert_eq!(stdout(&output), "\x1b[32m7\x1b[m\x1b[36m:\x1b[mHow public, like a \x1b[01;31mfrog\x1b[m\n");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_minigrep"))
+        .args(["-c", "frog", "poem.txt", "poem.txt"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .env("MINIGREP_CONFIG", "")
+        .env("MINIGREP_COLORS", "fn=34:se=")
+        .env_remove("NO_COLOR")
+        .arg("--color=always")
+        .output()
+        .unwrap();
+    assert_eq!(stdout(&output), "\x1b[34mpoem.txt\x1b[m:1\n".repeat(2));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_minigrep"))
+        .args(["-l", "frog", "poem.txt"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .env("MINIGREP_CONFIG", "")
+        .env("NO_COLOR", "1")
+        .output()
+        .unwrap();
+    assert_eq!(stdout(&output), "poem.txt\n");
+}
+
+#[test]
+fn tui_needs_a_terminal() {
+    let output = minigrep(&["--tui", "-e", "frog", "poem.txt"], false);
+    assert!(!output.status.success());
+    assert_eq!(stderr(&output), "Application error: --tui needs a terminal\n");
+}
@@ -0,0 +1,217 @@
+//! Compares the thread pool with the event loops, serving the same routes to
+//! many connections open at once over loopback.
+//!
+//! Run with `cargo bench -p serv`. Each backend serves from a child process
+//! of the benchmark, to which the benchmark opens `SERV_BENCH_CONNECTIONS`
+//! connections (default 10000) before it sends anything, then
+//! `SERV_BENCH_REQUESTS` requests on each (default 10), one after another.
+//! Both backends have `SERV_BENCH_WORKERS` workers (default 8).
+//!
+//! It prints how long opening the connections took, how long the requests
+//! took, and percentiles of their latencies. Each connection is a file in
+//! both processes, whose limit on open files must allow for them.
+
+#[cfg(target_os = "linux")]
+fn main() {
+    linux::main();
+}
+
+#[cfg(not(target_os = "linux"))]
+fn main() {
+    println!("The event loop backend, and so this benchmark, is for Linux only.");
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::cell::RefCell;
+    use std::env;
+    use std::ffi::c_int;
+    use std::future::poll_fn;
+    use std::io::{self, BufRead, BufReader};
+    use std::net::{SocketAddr, TcpStream};
+    use std::process::{Child, Command, Stdio};
+    use std::rc::Rc;
+    use std::task::{Poll, Waker};
+    use std::time::{Duration, Instant};
+
+    use serv::runtime::{self, Runtime};
+    use serv::{route, routes, Backend, Options, Server};
+
+    #[route(GET, "/")]
+    fn index() -> &'static str {
+        "Hello!\n"
+    }
+
+    #[route(GET, "/users/:id")]
+    fn user(id: u32) -> String {
+        format!("user {id}\n")
+    }
+
+    const RLIMIT_NOFILE: c_int = 7;
+
+    #[repr(C)]
+    struct Rlimit {
+        current: u64,
+        max: u64,
+    }
+
+    extern "C" {
+        fn getrlimit(resource: c_int, limit: *mut Rlimit) -> c_int;
+        fn setrlimit(resource: c_int, limit: *const Rlimit) -> c_int;
+    }
+
+    fn setting(name: &str, default: usize) -> usize {
+        env::var(name).ok().and_then(|n| n.parse().ok()).unwrap_or(default)
+    }
+
+    pub fn main() {
+        raise_file_limit();
+        let workers = setting("SERV_BENCH_WORKERS", serv::DEFAULT_WORKERS);
+        if let Ok(backend) = env::var("SERV_BENCH_BACKEND") {
+            let backend = if backend == "events" { Backend::EventLoop } else { Backend::Threads };
+            // Connections wait for their first request while the others
+            // open, which may take longer than the default read timeout.
+            let options = Options {
+                read_timeout: Duration::from_secs(600),
+                ..Options::default()
+            };
+            let server = Server::bind("127.0.0.1:0").unwrap().workers(workers).backend(backend).options(options);
+            println!("{}", server.local_addr().unwrap());
+            server.serve(routes![index, user]).unwrap();
+            return;
+        }
+
+        let connections = setting("SERV_BENCH_CONNECTIONS", 10_000);
+        let requests = setting("SERV_BENCH_REQUESTS", 10);
+        println!("{connections} connections, {requests} requests each, {workers} workers");
+        println!(
+            "{:<12} {:>9} {:>9} {:>10} {:>10} {:>10} {:>10}",
+            "backend", "connect", "total", "requests/s", "p50", "p99", "max"
+        );
+        for (name, backend) in [("threads", "threads"), ("event loops", "events")] {
+            let mut server = ServerProcess(
+                Command::new(env::current_exe().unwrap())
+                    .env("SERV_BENCH_BACKEND", backend)
+                    .stdout(Stdio::piped())
+                    .spawn()
+                    .expect("failed to start the server"),
+            );
+            let mut addr = String::new();
+            BufReader::new(server.0.stdout.take().unwrap()).read_line(&mut addr).unwrap();
+            let addr: SocketAddr = addr.trim().parse().unwrap();
+
+            let (connected, elapsed, mut latencies) = run(addr, connections, requests).expect("the benchmark failed");
+            latencies.sort_unstable();
+            let percentile = |p: usize| latencies[(latencies.len() * p / 100).min(latencies.len() - 1)];
+            println!(
+                "{name:<12} {:>9.2?} {:>9.2?} {:>10.0} {:>10.2?} {:>10.2?} {:>10.2?}",
+                connected,
+                elapsed,
+                latencies.len() as f64 / elapsed.as_secs_f64(),
+                percentile(50),
+                percentile(99),
+                latencies[latencies.len() - 1],
+            );
+        }
+    }
+
+    /// The server's process, killed as the benchmark is done with it, or
+    /// fails.
+    struct ServerProcess(Child);
+
+    impl Drop for ServerProcess {
+        fn drop(&mut self) {
+            let _ = self.0.kill();
+            let _ = self.0.wait();
+        }
+    }
+
+    /// Lets the process open as many files as it may, as each connection is
+    /// one.
+    fn raise_file_limit() {
+        let mut limit = Rlimit { current: 0, max: 0 };
+        // SAFETY: `limit` has the layout of `struct rlimit` on 64-bit Linux,
+        // and outlives both calls.
+        unsafe {
+            if getrlimit(RLIMIT_NOFILE, &mut limit) == 0 {
+                limit.current = limit.max;
+                setrlimit(RLIMIT_NOFILE, &limit);
+            }
+        }
+    }
+
+    /// Opens the connections, then sends the requests on all of them at
+    /// once. Returns how long opening them took, how long the requests took,
+    /// and each one's latency.
+    fn run(addr: SocketAddr, connections: usize, requests: usize) -> io::Result<(Duration, Duration, Vec<Duration>)> {
+        let connecting = Instant::now();
+        let streams = (0..connections)
+            .map(|i| {
+                TcpStream::connect(addr)
+                    .map_err(|err| io::Error::new(err.kind(), format!("connection {i} of {connections} failed: {err}")))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        let connected = connecting.elapsed();
+        let latencies = Rc::new(RefCell::new(Vec::with_capacity(connections * requests)));
+        let start = Instant::now();
+        Runtime::new()?.block_on(async {
+            let tasks = Rc::new(RefCell::new(connections));
+            let done: Rc<RefCell<Option<Waker>>> = Rc::default();
+            for (i, stream) in streams.into_iter().enumerate() {
+                let stream = runtime::TcpStream::from_std(stream)?;
+                let (latencies, tasks, done) = (Rc::clone(&latencies), Rc::clone(&tasks), Rc::clone(&done));
+                runtime::spawn(async move {
+                    for _ in 0..requests {
+                        let sent = Instant::now();
+                        let request = format!("GET /users/{i} HTTP/1.1\r\nHost: localhost\r\n\r\n");
+                        stream.write_all(request.as_bytes()).await.expect("failed to send a request");
+                        read_response(&stream).await.expect("failed to read a response");
+                        latencies.borrow_mut().push(sent.elapsed());
+                    }
+                    *tasks.borrow_mut() -= 1;
+                    if let Some(waker) = done.borrow_mut().take_if(|_| *tasks.borrow() == 0) {
+                        waker.wake();
+                    }
+                });
+            }
+            poll_fn(|cx| match *tasks.borrow() {
+                0 => Poll::Ready(()),
+                _ => {
+                    *done.borrow_mut() = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            })
+            .await;
+            Ok::<_, io::Error>(())
+        })?;
+        let elapsed = start.elapsed();
+        let latencies = latencies.take();
+        Ok((connected, elapsed, latencies))
+    }
+
+    /// Reads one response with a `Content-Length`, and nothing more, as
+    /// responses are read before the next request is sent.
+    async fn read_response(stream: &runtime::TcpStream) -> io::Result<()> {
+        let mut response = Vec::new();
+        let mut buf = [0; 1024];
+        loop {
+            let n = stream.read(&mut buf).await?;
+            if n == 0 {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+            response.extend_from_slice(&buf[..n]);
+            let Some(end) = response.windows(4).position(|window| window == b"\r\n\r\n") else {
+                continue;
+            };
+            let head = String::from_utf8_lossy(&response[..end]);
+            let length: usize = head
+                .lines()
+                .find_map(|line| line.strip_prefix("Content-Length: "))
+                .and_then(|length| length.parse().ok())
+                .ok_or(io::ErrorKind::InvalidData)?;
+            if response.len() >= end + 4 + length {
+                return Ok(());
+            }
+        }
+    }
+}
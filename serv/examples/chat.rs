@@ -0,0 +1,106 @@
+//! A chat room over WebSockets: `cargo run -p serv --example chat`, then
+//! open `http://127.0.0.1:7878` in a few browser tabs.
+//!
+//! Every connection runs on a worker thread of its own, which waits for what
+//! its user says. The threads do not share the list of who is in the room;
+//! as in listing 16-11, each holds a clone of the transmitting end of one
+//! channel, and sends what happens down it. The thread at the receiving end
+//! owns the room, and fans each message out to everyone's
+//! `websocket::Sender`.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+use serv::websocket::{self, Message};
+use serv::{Request, Response, Server};
+
+/// What the connection threads tell the room about.
+enum Event {
+    Join(u64, websocket::Sender),
+    Say(u64, String),
+    Leave(u64),
+}
+
+const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8">
+    <title>Chat</title>
+  </head>
+  <body>
+    <pre id="log"></pre>
+    <form id="form"><input id="line" autocomplete="off" autofocus> <button>Say</button></form>
+    <script>
+      const log = document.getElementById("log");
+      const line = document.getElementById("line");
+      const socket = new WebSocket(`ws://${location.host}/chat`);
+      socket.onmessage = (event) => { log.textContent += event.data + "\n"; };
+      socket.onclose = () => { log.textContent += "(disconnected)\n"; };
+      document.getElementById("form").onsubmit = (event) => {
+        event.preventDefault();
+        socket.send(line.value);
+        line.value = "";
+      };
+    </script>
+  </body>
+</html>
+"#;
+
+/// Owns the room: who is in it, and how to reach them.
+fn room(events: mpsc::Receiver<Event>) {
+    let mut members: HashMap<u64, websocket::Sender> = HashMap::new();
+    for event in events {
+        let line = match event {
+            Event::Join(id, sender) => {
+                members.insert(id, sender);
+                format!("* user {id} joined")
+            }
+            Event::Say(id, text) => format!("user {id}: {text}"),
+            Event::Leave(id) => {
+                members.remove(&id);
+                format!("* user {id} left")
+            }
+        };
+        println!("{line}");
+        // A member whose connection is gone leaves with the next message.
+        members.retain(|_, sender| sender.send(Message::Text(line.clone())).is_ok());
+    }
+}
+
+fn main() {
+    let (events, received) = mpsc::channel();
+    thread::spawn(move || room(received));
+    let next_id = AtomicU64::new(1);
+
+    let handler = move |request: &Request| match request.path.as_str() {
+        "/" => Response::html(200, PAGE),
+        "/chat" => {
+            let id = next_id.fetch_add(1, Ordering::Relaxed);
+            let events = events.clone();
+            websocket::upgrade(request, move |mut socket| {
+                if events.send(Event::Join(id, socket.sender())).is_err() {
+                    return;
+                }
+                while let Ok(message) = socket.recv() {
+                    match message {
+                        Message::Text(text) => {
+                            let _ = events.send(Event::Say(id, text));
+                        }
+                        Message::Close(..) => break,
+                        Message::Binary(_) | Message::Ping(_) | Message::Pong(_) => {}
+                    }
+                }
+                let _ = events.send(Event::Leave(id));
+            })
+        }
+        _ => Response::not_found(),
+    };
+
+    // A connection keeps its worker for as long as it is open, so there are
+    // as many members at most as workers.
+    let server = Server::bind("127.0.0.1:7878").unwrap().workers(64);
+    println!("Chatting on http://{}", server.local_addr().unwrap());
+    server.serve(handler).unwrap();
+}
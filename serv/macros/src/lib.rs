@@ -0,0 +1,375 @@
+//! The `#[route]` attribute and the `routes!` macro of `serv`, the
+//! attribute-like macro `19_5_macros.rs` sketches. See `serv::router` for
+//! how they are used.
+//!
+//! They are written against `proc_macro` alone: the items they read are
+//! simple enough to take apart token by token.
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// The methods a route can be for, and their `serv::Method` variants.
+const METHODS: [(&str, &str); 7] = [
+    ("GET", "Get"),
+    ("HEAD", "Head"),
+    ("POST", "Post"),
+    ("PUT", "Put"),
+    ("DELETE", "Delete"),
+    ("OPTIONS", "Options"),
+    ("PATCH", "Patch"),
+];
+
+/// Makes the function a handler for `METHOD` requests to the path, as in
+/// `#[route(GET, "/users/:id")]`.
+///
+/// The function stays as it is, and can be an `async fn`. Next to it goes a
+/// module of the same name holding the `ROUTE` that `routes!` puts in a
+/// router.
+#[proc_macro_attribute]
+pub fn route(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let companion = parse_route(attr).and_then(|route| {
+        let handler = parse_handler(item.clone())?;
+        companion(&route, &handler)
+    });
+    let mut out = item;
+    out.extend(companion.unwrap_or_else(Error::into_tokens));
+    out
+}
+
+/// A `serv::router::Router` with the routes of the handlers given, as in
+/// `routes![index, users::show]`. Two handlers with the same route are a
+/// compile error.
+#[proc_macro]
+pub fn routes(input: TokenStream) -> TokenStream {
+    let handlers = match parse_paths(input) {
+        Ok(handlers) => handlers,
+        Err(err) => return err.into_tokens(),
+    };
+    let mut code = String::from("{\n");
+    for (i, first) in handlers.iter().enumerate() {
+        for second in &handlers[i + 1..] {
+            let message = format!("`{second}` has the same route as `{first}`");
+            code.push_str(&format!(
+                "const _: () = if ::serv::router::same_key({first}::KEY, {second}::KEY) {{ panic!({message:?}) }};\n"
+            ));
+        }
+    }
+    let routes: Vec<String> = handlers.iter().map(|handler| format!("{handler}::ROUTE")).collect();
+    code.push_str(&format!("::serv::router::Router::new(::std::vec![{}])\n}}", routes.join(", ")));
+    code.parse().unwrap()
+}
+
+/// A compile error at `span`.
+struct Error {
+    span: Span,
+    message: String,
+}
+
+impl Error {
+    fn new(span: Span, message: impl Into<String>) -> Error {
+        Error {
+            span,
+            message: message.into(),
+        }
+    }
+
+    fn into_tokens(self) -> TokenStream {
+        let mut message = Literal::string(&self.message);
+        message.set_span(self.span);
+        let mut group = Group::new(Delimiter::Parenthesis, TokenTree::from(message).into());
+        group.set_span(self.span);
+        let mut bang = Punct::new('!', Spacing::Alone);
+        bang.set_span(self.span);
+        let mut semicolon = Punct::new(';', Spacing::Alone);
+        semicolon.set_span(self.span);
+        [
+            TokenTree::from(Ident::new("compile_error", self.span)),
+            bang.into(),
+            group.into(),
+            semicolon.into(),
+        ]
+        .into_iter()
+        .collect()
+    }
+}
+
+/// What `#[route(...)]` says.
+struct Route {
+    /// The `serv::Method` variant.
+    method: &'static str,
+    pattern: String,
+    /// The names of the parameters in `pattern`, in order.
+    params: Vec<String>,
+    /// The method and the pattern with the names of its parameters left
+    /// out, the same for two routes that would match the same requests.
+    key: String,
+    span: Span,
+}
+
+fn parse_route(attr: TokenStream) -> Result<Route, Error> {
+    let usage = "expected `#[route(METHOD, \"/path\")]`";
+    let mut tokens = attr.into_iter();
+    let method = match tokens.next() {
+        Some(TokenTree::Ident(method)) => method,
+        other => return Err(Error::new(span_of(other.as_ref()), usage)),
+    };
+    let name = method.to_string();
+    let Some(&(_, variant)) = METHODS.iter().find(|(m, _)| *m == name) else {
+        let names: Vec<&str> = METHODS.iter().map(|(m, _)| *m).collect();
+        return Err(Error::new(method.span(), format!("unknown method `{name}`, expected {}", names.join(", "))));
+    };
+    match tokens.next() {
+        Some(TokenTree::Punct(comma)) if comma.as_char() == ',' => {}
+        other => return Err(Error::new(span_of(other.as_ref()), usage)),
+    }
+    let literal = match tokens.next() {
+        Some(TokenTree::Literal(literal)) => literal,
+        other => return Err(Error::new(span_of(other.as_ref()), usage)),
+    };
+    match tokens.next() {
+        None => {}
+        Some(TokenTree::Punct(comma)) if comma.as_char() == ',' && tokens.next().is_none() => {}
+        Some(other) => return Err(Error::new(other.span(), usage)),
+    }
+    let span = literal.span();
+    let text = literal.to_string();
+    let pattern = match text.strip_prefix('"').and_then(|t| t.strip_suffix('"')) {
+        Some(pattern) if !pattern.contains('\\') => pattern.to_string(),
+        Some(_) => return Err(Error::new(span, "escapes are not supported in route paths")),
+        None => return Err(Error::new(span, usage)),
+    };
+    let Some(path) = pattern.strip_prefix('/') else {
+        return Err(Error::new(span, "route paths start with `/`"));
+    };
+    let mut params = Vec::new();
+    let mut key = format!("{name} ");
+    for segment in path.split('/') {
+        key.push('/');
+        match segment.strip_prefix(':') {
+            Some(param) => {
+                if !is_identifier(param) {
+                    return Err(Error::new(span, format!("`:{param}` is not a valid parameter name")));
+                }
+                if params.iter().any(|p| p == param) {
+                    return Err(Error::new(span, format!("`:{param}` appears twice in the path")));
+                }
+                params.push(param.to_string());
+                key.push(':');
+            }
+            None => key.push_str(segment),
+        }
+    }
+    Ok(Route {
+        method: variant,
+        pattern,
+        params,
+        key,
+        span,
+    })
+}
+
+/// What the function under `#[route]` looks like from the outside.
+struct Handler {
+    visibility: String,
+    is_async: bool,
+    name: Ident,
+    params: Vec<Param>,
+}
+
+struct Param {
+    name: String,
+    span: Span,
+    /// Whether the parameter is a reference, which is given the request.
+    request: bool,
+}
+
+fn parse_handler(item: TokenStream) -> Result<Handler, Error> {
+    let mut tokens = item.into_iter().peekable();
+    let mut visibility = String::new();
+    let mut is_async = false;
+    let name = loop {
+        match tokens.next() {
+            // Attributes.
+            Some(TokenTree::Punct(p)) if p.as_char() == '#' => {
+                tokens.next();
+            }
+            Some(TokenTree::Ident(ident)) => match ident.to_string().as_str() {
+                "pub" => {
+                    visibility.push_str("pub");
+                    if let Some(TokenTree::Group(group)) = tokens.peek() {
+                        if group.delimiter() == Delimiter::Parenthesis {
+                            visibility.push_str(&group.to_string());
+                            tokens.next();
+                        }
+                    }
+                }
+                "const" => {}
+                "fn" => match tokens.next() {
+                    Some(TokenTree::Ident(name)) => break name,
+                    other => return Err(Error::new(span_of(other.as_ref()), "expected the function name")),
+                },
+                "async" => is_async = true,
+                _ => return Err(Error::new(ident.span(), "#[route] goes on a function")),
+            },
+            other => return Err(Error::new(span_of(other.as_ref()), "#[route] goes on a function")),
+        }
+    };
+    let params = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => parse_params(group)?,
+        Some(TokenTree::Punct(p)) if p.as_char() == '<' => {
+            return Err(Error::new(p.span(), "route handlers cannot be generic"))
+        }
+        other => return Err(Error::new(span_of(other.as_ref()), "expected the function's parameters")),
+    };
+    Ok(Handler {
+        visibility,
+        is_async,
+        name,
+        params,
+    })
+}
+
+/// The parameters in `group`, which must be plain names.
+fn parse_params(group: Group) -> Result<Vec<Param>, Error> {
+    let mut params = Vec::new();
+    let mut tokens = group.stream().into_iter().peekable();
+    while tokens.peek().is_some() {
+        let mut name = tokens.next();
+        if matches!(&name, Some(TokenTree::Ident(ident)) if ident.to_string() == "mut") {
+            name = tokens.next();
+        }
+        let name = match name {
+            Some(TokenTree::Ident(ident)) if ident.to_string() != "self" => ident,
+            other => return Err(Error::new(span_of(other.as_ref()), "route handler parameters must be plain names")),
+        };
+        match tokens.next() {
+            Some(TokenTree::Punct(colon)) if colon.as_char() == ':' => {}
+            other => return Err(Error::new(span_of(other.as_ref()), "expected `:` and a type")),
+        }
+        let request = matches!(tokens.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '&');
+        // The type runs to the next comma outside angle brackets; commas
+        // inside other brackets are in groups of their own.
+        let mut depth = 0;
+        let mut previous = ' ';
+        for token in tokens.by_ref() {
+            if let TokenTree::Punct(p) = &token {
+                match p.as_char() {
+                    '<' => depth += 1,
+                    '>' if previous != '-' => depth -= 1,
+                    ',' if depth == 0 => break,
+                    _ => {}
+                }
+                previous = p.as_char();
+            } else {
+                previous = ' ';
+            }
+        }
+        params.push(Param {
+            name: name.to_string(),
+            span: name.span(),
+            request,
+        });
+    }
+    Ok(params)
+}
+
+/// The module next to the handler holding its route.
+fn companion(route: &Route, handler: &Handler) -> Result<TokenStream, Error> {
+    // The arguments, and the path parameters they are parsed from first. A
+    // segment that does not parse makes the request not found.
+    let (mut args, mut parsed) = (Vec::new(), String::new());
+    let not_found = match handler.is_async {
+        true => "::std::boxed::Box::pin(::std::future::ready(::serv::Response::not_found()))",
+        false => "::serv::Response::not_found()",
+    };
+    for param in &handler.params {
+        if param.request {
+            // The future of an async handler owns the request it borrows.
+            args.push(if handler.is_async { "&request" } else { "request" }.to_string());
+            continue;
+        }
+        let Some(index) = route.params.iter().position(|p| *p == param.name) else {
+            let message = format!("`{}` is not a parameter of the route path `{}`", param.name, route.pattern);
+            return Err(Error::new(param.span, message));
+        };
+        parsed.push_str(&format!(
+            "let param{index} = match params[{index}].parse() {{ Ok(value) => value, Err(_) => return {not_found} }};\n"
+        ));
+        args.push(format!("param{index}"));
+    }
+    if let Some(missing) = route.params.iter().find(|p| !handler.params.iter().any(|h| h.name == **p)) {
+        return Err(Error::new(route.span, format!("`:{missing}` is not taken by `{}`", handler.name)));
+    }
+    let name = &handler.name;
+    let args = args.join(", ");
+    // An async handler's future is waited for in a runtime of its own where
+    // the response is wanted at once.
+    let (handler_fn, future, future_fn) = match handler.is_async {
+        false => (
+            format!("|request, params| {{ {parsed} ::serv::Response::from(super::{name}({args})) }}"),
+            "None",
+            String::new(),
+        ),
+        true => (
+            "|request, params| ::serv::runtime::wait(future(request.clone(), params))".to_string(),
+            "Some(future)",
+            format!(
+                "fn future(request: ::serv::Request, params: &[&str]) -> ::serv::ResponseFuture {{
+                    {parsed}
+                    ::std::boxed::Box::pin(async move {{ ::serv::Response::from(super::{name}({args}).await) }})
+                }}"
+            ),
+        ),
+    };
+    let code = format!(
+        "#[doc(hidden)]
+        #[allow(unused_variables)]
+        {visibility} mod {name} {{
+            pub const KEY: &str = {key:?};
+            pub const ROUTE: ::serv::router::Route = ::serv::router::Route {{
+                method: ::serv::Method::{method},
+                pattern: {pattern:?},
+                handler: {handler_fn},
+                future: {future},
+            }};
+            {future_fn}
+        }}",
+        visibility = handler.visibility,
+        key = route.key,
+        method = route.method,
+        pattern = route.pattern,
+    );
+    Ok(code.parse().unwrap())
+}
+
+/// The comma-separated paths given to `routes!`.
+fn parse_paths(input: TokenStream) -> Result<Vec<String>, Error> {
+    let mut paths = Vec::new();
+    let mut path = String::new();
+    for token in input {
+        match &token {
+            TokenTree::Punct(p) if p.as_char() == ',' => {
+                if path.is_empty() {
+                    return Err(Error::new(p.span(), "expected the name of a handler"));
+                }
+                paths.push(std::mem::take(&mut path));
+            }
+            TokenTree::Punct(p) if p.as_char() == ':' => path.push(':'),
+            TokenTree::Ident(ident) => path.push_str(&ident.to_string()),
+            other => return Err(Error::new(other.span(), "expected the name of a handler")),
+        }
+    }
+    if !path.is_empty() {
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn span_of(token: Option<&TokenTree>) -> Span {
+    token.map_or_else(Span::call_site, TokenTree::span)
+}
@@ -0,0 +1,372 @@
+//! Serving connections on event loops, as [`Backend::EventLoop`] does.
+//!
+//! Each worker runs a [`Runtime`] whose main task accepts connections from
+//! the listener, which the loops share, and spawns a task for each. A
+//! connection task reads what arrives into a buffer and parses requests out
+//! of it as the workers of the thread pool do out of a `BufReader`, except
+//! that a request cut short is parsed again once more of it has arrived,
+//! rather than waited for in the middle of parsing.
+//!
+//! [`Backend::EventLoop`]: crate::Backend::EventLoop
+
+use std::cell::Cell;
+use std::future::poll_fn;
+use std::io::{self, BufRead, Read};
+use std::mem;
+use std::net::{Shutdown, TcpListener};
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Poll, Waker};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::http::{self, Framing};
+use crate::runtime::{self, Runtime, TcpStream};
+use crate::{Handler, Open, PoolCreationError, Request, RequestError, Response, Shared, Site, Upgraded};
+
+/// Serves connections from `listener` on `loops` event loops, until the
+/// server is told to stop, then stops as [`crate::Server::serve`] tells.
+pub(crate) fn serve(listener: TcpListener, loops: usize, shared: &Arc<Shared>) -> io::Result<()> {
+    if loops == 0 {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, PoolCreationError));
+    }
+    let mut threads = Vec::with_capacity(loops);
+    let mut result = Ok(());
+    for id in 0..loops {
+        let spawned = listener.try_clone().and_then(|listener| {
+            let shared = Arc::clone(shared);
+            thread::Builder::new().name(format!("serv-loop-{id}")).spawn(move || run(listener, &shared))
+        });
+        match spawned {
+            Ok(thread) => threads.push(thread),
+            Err(err) => {
+                result = Err(err);
+                shared.stop();
+                break;
+            }
+        }
+    }
+    // The loops hold listeners of their own, and stop listening as they stop.
+    drop(listener);
+    shared.wait_for_stop();
+    shared.drain();
+    for thread in threads {
+        let stopped = join(thread);
+        result = result.and(stopped);
+    }
+    result
+}
+
+fn join(thread: JoinHandle<io::Result<()>>) -> io::Result<()> {
+    thread.join().unwrap_or_else(|panic| panic::resume_unwind(panic))
+}
+
+/// Runs one event loop, which stops the server if it fails.
+fn run(listener: TcpListener, shared: &Arc<Shared>) -> io::Result<()> {
+    let result = Runtime::new().and_then(|runtime| runtime.block_on(accept(listener, shared)));
+    if result.is_err() {
+        shared.stop();
+    }
+    result
+}
+
+/// Accepts connections until the server is told to stop, then waits for
+/// those it accepted to close, or for the shutdown timeout to pass.
+async fn accept(listener: TcpListener, shared: &Arc<Shared>) -> io::Result<()> {
+    let listener = runtime::TcpListener::from_std(listener)?;
+    poll_fn(|cx| {
+        shared.wake_on_stop(cx.waker().clone());
+        Poll::Ready(())
+    })
+    .await;
+    let tasks = Rc::new(Tasks::default());
+    let result = loop {
+        let accepted = poll_fn(|cx| match shared.stopping.load(Ordering::SeqCst) {
+            true => Poll::Ready(None),
+            false => listener.poll_accept(cx).map(Some),
+        })
+        .await;
+        let stream = match accepted {
+            None => break Ok(()),
+            Some(Ok((stream, _))) => stream,
+            Some(Err(err)) if err.kind() == io::ErrorKind::ConnectionAborted => continue,
+            Some(Err(err)) => break Err(err),
+        };
+        let (running, open) = (tasks.start(), shared.open(stream.shared()));
+        runtime::spawn(async move {
+            // The client may go away at any time; there is no one to tell.
+            let _ = serve_connection(stream, open).await;
+            drop(running);
+        });
+    };
+    drop(listener);
+    let timeout = shared.site().options.shutdown_timeout;
+    let _ = runtime::timeout(timeout + Duration::from_secs(1), tasks.finished()).await;
+    result
+}
+
+/// The connection tasks of a loop, to wait for as it stops.
+#[derive(Default)]
+struct Tasks {
+    running: Cell<usize>,
+    /// Woken as the last of them finishes.
+    finished: Cell<Option<Waker>>,
+}
+
+impl Tasks {
+    fn start(self: &Rc<Tasks>) -> Running {
+        self.running.set(self.running.get() + 1);
+        Running(Rc::clone(self))
+    }
+
+    async fn finished(&self) {
+        poll_fn(|cx| match self.running.get() {
+            0 => Poll::Ready(()),
+            _ => {
+                self.finished.set(Some(cx.waker().clone()));
+                Poll::Pending
+            }
+        })
+        .await;
+    }
+}
+
+/// A connection task, counted until it is dropped.
+struct Running(Rc<Tasks>);
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        self.0.running.set(self.0.running.get() - 1);
+        if self.0.running.get() == 0 {
+            if let Some(waker) = self.0.finished.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// [`Shared::serve_connection`], waiting with the runtime rather than in
+/// the system calls.
+async fn serve_connection(stream: TcpStream, open: Open) -> io::Result<()> {
+    let shared = Arc::clone(&open.shared);
+    let mut input = Input::default();
+    let mut first = true;
+    let on_upgrade = loop {
+        let mut site = shared.site();
+        if !first {
+            shared.set_idle(open.id, true);
+            if shared.stopping.load(Ordering::SeqCst) {
+                return Ok(());
+            }
+            let more = input.wait(&stream, site.options.keep_alive).await?;
+            shared.set_idle(open.id, false);
+            if !more {
+                return Ok(());
+            }
+            site = shared.site();
+        }
+        first = false;
+        match serve_request(&stream, &mut input, &site, &shared.stopping).await? {
+            Served::KeepAlive => {}
+            Served::Close => return Ok(()),
+            Served::Upgrade(on_upgrade) => break on_upgrade,
+        }
+    };
+    drop(open);
+    // What the new protocol does with the connection is up to it, and may
+    // block: it gets a thread, and the connection as it was.
+    let stream = stream.into_std()?;
+    let open = shared.open(&Arc::new(stream.try_clone()?));
+    thread::Builder::new().name("serv-upgraded".to_string()).spawn(move || {
+        on_upgrade(Upgraded::new(stream, input.buf));
+        drop(open);
+    })?;
+    Ok(())
+}
+
+/// What comes of a request.
+enum Served {
+    KeepAlive,
+    Close,
+    Upgrade(Box<dyn FnOnce(Upgraded) + Send>),
+}
+
+/// What has arrived on a connection, and not been parsed yet.
+#[derive(Default)]
+struct Input {
+    buf: Vec<u8>,
+    /// Whether the client has closed its end.
+    eof: bool,
+}
+
+impl Input {
+    /// Reads what arrives next, waiting `timeout` at most.
+    async fn fill(&mut self, stream: &TcpStream, timeout: Duration) -> io::Result<()> {
+        let len = self.buf.len();
+        self.buf.resize(len + 4096, 0);
+        let read = runtime::timeout(timeout, stream.read(&mut self.buf[len..])).await.and_then(|read| read);
+        self.buf.truncate(len + *read.as_ref().unwrap_or(&0));
+        self.eof = read? == 0;
+        Ok(())
+    }
+
+    /// Waits up to `idle` for the next request to start, unless it has.
+    /// Returns whether it did, rather than the connection closing or staying
+    /// idle.
+    async fn wait(&mut self, stream: &TcpStream, idle: Duration) -> io::Result<bool> {
+        if self.buf.is_empty() && !self.eof {
+            match self.fill(stream, idle).await {
+                Err(err) if err.kind() == io::ErrorKind::TimedOut => return Ok(false),
+                result => result?,
+            }
+        }
+        Ok(!self.buf.is_empty())
+    }
+}
+
+/// The bytes that have arrived, as a reader that fails with
+/// [`io::ErrorKind::WouldBlock`] at their end unless the client has closed
+/// its end, so that a request cut short is told from a malformed one.
+struct Received<'a> {
+    buf: &'a [u8],
+    eof: bool,
+}
+
+impl Read for Received<'_> {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        let n = self.fill_buf()?.read(out)?;
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl BufRead for Received<'_> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.buf.is_empty() && !self.eof {
+            return Err(io::ErrorKind::WouldBlock.into());
+        }
+        Ok(self.buf)
+    }
+
+    fn consume(&mut self, n: usize) {
+        self.buf = &self.buf[n..];
+    }
+}
+
+/// [`crate::serve_request`], for a connection of an event loop.
+async fn serve_request(stream: &TcpStream, input: &mut Input, site: &Site, closing: &AtomicBool) -> io::Result<Served> {
+    let options = &site.options;
+    let limits = &options.limits;
+    // The head is parsed again as more of it arrives, which its limits keep
+    // cheap. Where it ends is kept.
+    let (mut request, mut at) = loop {
+        let mut received = Received {
+            buf: &input.buf,
+            eof: input.eof,
+        };
+        match Request::read_head(&mut received, limits) {
+            Ok(None) => return Ok(Served::Close),
+            Ok(Some(request)) => break (request, input.buf.len() - received.buf.len()),
+            Err(RequestError::Io(err)) if err.kind() == io::ErrorKind::WouldBlock => {
+                input.fill(stream, options.read_timeout).await?;
+            }
+            Err(err) => return refuse(stream, err).await.map(|()| Served::Close),
+        }
+    };
+    if request.expects_continue() {
+        stream.write_all(b"HTTP/1.1 100 Continue\r\n\r\n").await?;
+    }
+    // The body is decoded once all of it, or the whole of its next chunk,
+    // has arrived, rather than again from its start after every read.
+    let framing = request.framing();
+    loop {
+        let rest = &input.buf[at..];
+        let room = limits.body - request.body.len();
+        let wanted = match framing {
+            Ok(Framing::Length(length)) if length <= room as u64 => length as usize,
+            Ok(Framing::Chunked) => http::chunk_len(rest, room).unwrap_or(0),
+            _ => 0,
+        };
+        if rest.len() < wanted && !input.eof {
+            input.fill(stream, options.read_timeout).await?;
+            continue;
+        }
+        let mut received = Received {
+            buf: rest,
+            eof: input.eof,
+        };
+        let start = request.body.len();
+        let read = match framing {
+            Ok(Framing::Chunked) => http::read_chunk(&mut received, limits, &mut request.body),
+            _ => request.read_body(&mut received, limits).map(|()| false),
+        };
+        match read {
+            Ok(more) => {
+                at = input.buf.len() - received.buf.len();
+                if !more {
+                    break;
+                }
+            }
+            Err(RequestError::Io(err)) if err.kind() == io::ErrorKind::WouldBlock => {
+                request.body.truncate(start);
+                input.fill(stream, options.read_timeout).await?;
+            }
+            Err(err) => return refuse(stream, err).await.map(|()| Served::Close),
+        }
+    }
+    input.buf.drain(..at);
+    request.remote = stream.peer_addr().ok();
+    let wants_keep_alive = request.keep_alive();
+    // What writing the response needs of the request, which the handler is
+    // given.
+    let body = mem::take(&mut request.body);
+    let head = request.clone();
+    request.body = body;
+    let mut response = respond(Arc::clone(&site.handler), request).await;
+    let keep_alive = !closing.load(Ordering::SeqCst) && !options.keep_alive.is_zero() && wants_keep_alive;
+    let mut out = Vec::new();
+    let keep_alive = response.write_to(&mut out, Some(&head), keep_alive)?;
+    stream.write_all(&out).await?;
+    Ok(match (response.status, response.upgrade.take()) {
+        (101, Some(on_upgrade)) => Served::Upgrade(on_upgrade),
+        _ if keep_alive => Served::KeepAlive,
+        _ => Served::Close,
+    })
+}
+
+/// [`crate::refuse`], for a connection of an event loop.
+async fn refuse(stream: &TcpStream, err: RequestError) -> io::Result<()> {
+    let Some(mut response) = err.response() else {
+        return Err(io::Error::other(err));
+    };
+    let mut out = Vec::new();
+    response.write_to(&mut out, None, false)?;
+    stream.write_all(&out).await?;
+    stream.shutdown(Shutdown::Write)?;
+    let discard = async {
+        let (mut left, mut buf) = (1024 * 1024, [0; 4096]);
+        while left > 0 {
+            match stream.read(&mut buf).await {
+                Ok(0) | Err(_) => break,
+                Ok(n) => left -= n.min(left),
+            }
+        }
+    };
+    let _ = runtime::timeout(Duration::from_secs(1), discard).await;
+    Ok(())
+}
+
+/// The response the future of `handler` gives, or `500 Internal Server
+/// Error` if it panics.
+async fn respond(handler: Arc<dyn Handler>, request: Request) -> Response {
+    let internal_error = || Response::text(500, "500 Internal Server Error\n");
+    let Ok(mut future) = panic::catch_unwind(AssertUnwindSafe(|| handler.call(request))) else {
+        return internal_error();
+    };
+    poll_fn(|cx| {
+        panic::catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(cx))).unwrap_or_else(|_| Poll::Ready(internal_error()))
+    })
+    .await
+}
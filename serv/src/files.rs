@@ -0,0 +1,131 @@
+//! Serving the files below a directory.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::http::{self, Method, Request, Response};
+use crate::Handler;
+
+/// Answers `GET` and `HEAD` requests with the files below `root`. A request
+/// for a directory gets its `index.html`, and a request for a file that is
+/// not there gets `404.html` from `root`, or a plain `404 Not Found`.
+///
+/// Paths that would lead out of `root`, through `..`, are not found.
+#[derive(Debug, Clone)]
+pub struct StaticFiles {
+    root: PathBuf,
+}
+
+impl StaticFiles {
+    pub fn new(root: impl Into<PathBuf>) -> StaticFiles {
+        StaticFiles { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Where the file for the request path `path` would be, if it is below
+    /// `root`.
+    fn resolve(&self, path: &str) -> Option<PathBuf> {
+        let mut file = self.root.clone();
+        for segment in path.strip_prefix('/')?.split('/') {
+            match segment {
+                "" | "." => {}
+                ".." => return None,
+                _ if segment.contains(['\\', '\0']) => return None,
+                _ => file.push(segment),
+            }
+        }
+        Some(file)
+    }
+
+    fn not_found(&self) -> Response {
+        match fs::read(self.root.join("404.html")) {
+            Ok(page) => Response::new(404)
+                .with_header("Content-Type", "text/html; charset=utf-8")
+                .with_body(page),
+            Err(_) => Response::not_found(),
+        }
+    }
+}
+
+impl Handler for StaticFiles {
+    fn handle(&self, request: &Request) -> Response {
+        if !matches!(request.method, Method::Get | Method::Head) {
+            return Response::text(405, "405 Method Not Allowed\n").with_header("Allow", "GET, HEAD");
+        }
+        let Some(mut file) = self.resolve(&request.path) else {
+            return self.not_found();
+        };
+        if file.is_dir() {
+            // Relative links in the index resolve against the directory only
+            // when its path ends with a slash.
+            if !request.path.ends_with('/') {
+                let mut location = format!("{}/", request.target.split('?').next().unwrap_or("/"));
+                if let Some(query) = &request.query {
+                    location = format!("{location}?{query}");
+                }
+                return Response::new(301).with_header("Location", location);
+            }
+            file.push("index.html");
+        }
+        let contents = fs::read(&file).and_then(|contents| Ok((contents, fs::metadata(&file)?.modified()?)));
+        match contents {
+            Ok((contents, modified)) => Response::new(200)
+                .with_header("Content-Type", content_type(&file))
+                .with_header("Last-Modified", http::date(modified))
+                .with_body(contents),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => self.not_found(),
+            Err(err) if err.kind() == io::ErrorKind::PermissionDenied => Response::text(403, "403 Forbidden\n"),
+            Err(_) => Response::text(500, "500 Internal Server Error\n"),
+        }
+    }
+}
+
+/// The media type of `file`, by its extension.
+pub fn content_type(file: &Path) -> &'static str {
+    let extension = file.extension().and_then(|e| e.to_str()).unwrap_or("").to_ascii_lowercase();
+    match extension.as_str() {
+        "html" | "htm" => "text/html; charset=utf-8",
+        "css" => "text/css; charset=utf-8",
+        "js" | "mjs" => "text/javascript; charset=utf-8",
+        "json" => "application/json",
+        "txt" | "rs" | "toml" => "text/plain; charset=utf-8",
+        "md" => "text/markdown; charset=utf-8",
+        "xml" => "application/xml",
+        "svg" => "image/svg+xml",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "ico" => "image/vnd.microsoft.icon",
+        "wasm" => "application/wasm",
+        "pdf" => "application/pdf",
+        "woff2" => "font/woff2",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolving() {
+        let files = StaticFiles::new("/srv/www");
+        assert_eq!(files.resolve("/"), Some(PathBuf::from("/srv/www")));
+        assert_eq!(files.resolve("/a//./b.html"), Some(PathBuf::from("/srv/www/a/b.html")));
+        assert_eq!(files.resolve("/a/../../etc/passwd"), None);
+        assert_eq!(files.resolve("/a\\..\\b"), None);
+        assert_eq!(files.resolve("*"), None);
+    }
+
+    #[test]
+    fn content_types() {
+        assert_eq!(content_type(Path::new("index.HTML")), "text/html; charset=utf-8");
+        assert_eq!(content_type(Path::new("logo.png")), "image/png");
+        assert_eq!(content_type(Path::new("Makefile")), "application/octet-stream");
+    }
+}
@@ -0,0 +1,235 @@
+//! Compressing response bodies in the gzip format (RFC 1952).
+//!
+//! The deflate data (RFC 1951) inside is one block with the fixed Huffman
+//! codes, so there are no code tables to build and send: the repeats that
+//! LZ77 finds do most of the work on the text a server sends.
+
+/// How far back a repeat can be found.
+const WINDOW: usize = 32 * 1024;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+const HASH_BITS: u32 = 15;
+/// How many earlier positions with the same hash are tried for a repeat.
+const MAX_CHAIN: usize = 64;
+
+/// The smallest length of each length code from 257, and its extra bits.
+const LENGTHS: [(usize, u32); 29] = [
+    (3, 0), (4, 0), (5, 0), (6, 0), (7, 0), (8, 0), (9, 0), (10, 0), (11, 1), (13, 1), (15, 1), (17, 1), (19, 2),
+    (23, 2), (27, 2), (31, 2), (35, 3), (43, 3), (51, 3), (59, 3), (67, 4), (83, 4), (99, 4), (115, 4), (131, 5),
+    (163, 5), (195, 5), (227, 5), (258, 0),
+];
+
+/// The smallest distance of each distance code, and its extra bits.
+const DISTANCES: [(usize, u32); 30] = [
+    (1, 0), (2, 0), (3, 0), (4, 0), (5, 1), (7, 1), (9, 2), (13, 2), (17, 3), (25, 3), (33, 4), (49, 4), (65, 5),
+    (97, 5), (129, 6), (193, 6), (257, 7), (385, 7), (513, 8), (769, 8), (1025, 9), (1537, 9), (2049, 10),
+    (3073, 10), (4097, 11), (6145, 11), (8193, 12), (12289, 12), (16385, 13), (24577, 13),
+];
+
+const CRC_TABLE: [u32; 256] = crc_table();
+
+/// `data` compressed into a gzip member.
+pub fn compress(data: &[u8]) -> Vec<u8> {
+    // No file name or modification time; the operating system is unknown.
+    let header = vec![0x1f, 0x8b, 8, 0, 0, 0, 0, 0, 0, 255];
+    let mut bits = Bits { out: header, buffer: 0, count: 0 };
+    deflate(data, &mut bits);
+    let mut out = bits.finish();
+    out.extend(crc32(data).to_le_bytes());
+    out.extend((data.len() as u32).to_le_bytes());
+    out
+}
+
+/// Bits written least significant first, as deflate packs them.
+struct Bits {
+    out: Vec<u8>,
+    buffer: u64,
+    count: u32,
+}
+
+impl Bits {
+    fn put(&mut self, value: u32, count: u32) {
+        self.buffer |= u64::from(value) << self.count;
+        self.count += count;
+        while self.count >= 8 {
+            self.out.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    /// Huffman codes go most significant bit first.
+    fn put_code(&mut self, code: u32, length: u32) {
+        self.put(code.reverse_bits() >> (32 - length), length);
+    }
+
+    /// A literal byte, the end of the block (256) or a length code, in the
+    /// fixed code.
+    fn put_symbol(&mut self, symbol: u32) {
+        match symbol {
+            0..=143 => self.put_code(0x30 + symbol, 8),
+            144..=255 => self.put_code(0x190 + symbol - 144, 9),
+            256..=279 => self.put_code(symbol - 256, 7),
+            _ => self.put_code(0xc0 + symbol - 280, 8),
+        }
+    }
+
+    fn put_repeat(&mut self, length: usize, distance: usize) {
+        let code = LENGTHS.iter().rposition(|&(base, _)| base <= length).unwrap();
+        let (base, extra) = LENGTHS[code];
+        self.put_symbol(257 + code as u32);
+        self.put((length - base) as u32, extra);
+        let code = DISTANCES.iter().rposition(|&(base, _)| base <= distance).unwrap();
+        let (base, extra) = DISTANCES[code];
+        self.put_code(code as u32, 5);
+        self.put((distance - base) as u32, extra);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.out.push(self.buffer as u8);
+        }
+        self.out
+    }
+}
+
+/// Writes `data` as a final block with the fixed codes, replacing each run
+/// of bytes that repeats an earlier one by the longest repeat found.
+fn deflate(data: &[u8], bits: &mut Bits) {
+    bits.put(1, 1);
+    bits.put(1, 2);
+    let mut chains = Chains {
+        head: vec![u32::MAX; 1 << HASH_BITS],
+        previous: vec![u32::MAX; WINDOW],
+    };
+    let mut i = 0;
+    while i < data.len() {
+        let (length, distance) = chains.longest_match(data, i);
+        if length >= MIN_MATCH {
+            bits.put_repeat(length, distance);
+            for j in i..i + length {
+                chains.insert(data, j);
+            }
+            i += length;
+        } else {
+            bits.put_symbol(u32::from(data[i]));
+            chains.insert(data, i);
+            i += 1;
+        }
+    }
+    bits.put_symbol(256);
+}
+
+/// The earlier positions at which each three bytes start, most recent first.
+struct Chains {
+    /// The last position with each hash.
+    head: Vec<u32>,
+    /// The position before each one in the window with the same hash.
+    previous: Vec<u32>,
+}
+
+impl Chains {
+    fn hash(data: &[u8], i: usize) -> usize {
+        let bytes = u32::from(data[i]) << 16 | u32::from(data[i + 1]) << 8 | u32::from(data[i + 2]);
+        (bytes.wrapping_mul(0x9e37_79b1) >> (32 - HASH_BITS)) as usize
+    }
+
+    fn insert(&mut self, data: &[u8], i: usize) {
+        if i + MIN_MATCH <= data.len() {
+            let hash = Chains::hash(data, i);
+            self.previous[i % WINDOW] = self.head[hash];
+            self.head[hash] = i as u32;
+        }
+    }
+
+    /// The length and distance of the longest repeat of what starts at `i`.
+    fn longest_match(&self, data: &[u8], i: usize) -> (usize, usize) {
+        if i + MIN_MATCH > data.len() {
+            return (0, 0);
+        }
+        let max = MAX_MATCH.min(data.len() - i);
+        let (mut best, mut distance) = (0, 0);
+        let mut candidate = self.head[Chains::hash(data, i)];
+        for _ in 0..MAX_CHAIN {
+            if candidate == u32::MAX || i - candidate as usize > WINDOW {
+                break;
+            }
+            let start = candidate as usize;
+            let length = data[start..].iter().zip(&data[i..i + max]).take_while(|(a, b)| a == b).count();
+            if length > best {
+                (best, distance) = (length, i - start);
+                if length == max {
+                    break;
+                }
+            }
+            // A slot of the window overwritten by a later position no longer
+            // leads further back.
+            let next = self.previous[start % WINDOW];
+            if next != u32::MAX && next >= candidate {
+                break;
+            }
+            candidate = next;
+        }
+        (best, distance)
+    }
+}
+
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { 0xedb8_8320 ^ (crc >> 1) } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0, |crc, &byte| CRC_TABLE[((crc ^ u32::from(byte)) & 0xff) as usize] ^ (crc >> 8))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use minigrep::inflate::{Decoder, Format};
+    use std::io::Read;
+
+    fn decompress(gzip: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        Decoder::new(gzip, Format::Gzip).read_to_end(&mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn round_trips() {
+        let text = include_bytes!("http.rs");
+        let compressed = compress(text);
+        assert!(compressed.len() < text.len() / 2, "{} of {}", compressed.len(), text.len());
+        assert_eq!(decompress(&compressed), text);
+
+        let mut noise = Vec::new();
+        let mut state = 1u32;
+        for _ in 0..100_000 {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            noise.push((state >> 16) as u8);
+        }
+        // Long runs, and repeats further back than the window.
+        noise.extend(vec![b'a'; 1000]);
+        noise.extend_from_within(..50_000);
+        for data in [&b""[..], b"a", b"abcabcabcabc", &noise] {
+            assert_eq!(decompress(&compress(data)), data);
+        }
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+}
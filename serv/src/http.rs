@@ -0,0 +1,1002 @@
+//! Reading HTTP/1.x requests and writing responses, after RFC 9112.
+//!
+//! Requests are read strictly: a malformed request line or header is an
+//! error, answered with `400 Bad Request`, rather than guessed at.
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// How much of a request is read before it is refused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// The longest request line, in bytes; a longer one is `414 URI Too Long`.
+    pub request_line: usize,
+    /// The most bytes of header lines, and of the trailer lines of a chunked
+    /// body; more is `431 Request Header Fields Too Large`.
+    pub header_bytes: usize,
+    /// The most header lines, also `431`.
+    pub headers: usize,
+    /// The longest body, in bytes; a longer one is `413 Content Too Large`.
+    pub body: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Limits {
+        Limits {
+            request_line: 8 * 1024,
+            header_bytes: 16 * 1024,
+            headers: 100,
+            body: 1024 * 1024,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Head,
+    Post,
+    Put,
+    Delete,
+    Options,
+    Patch,
+    /// Any other method, as it was given.
+    Other(String),
+}
+
+impl Method {
+    fn parse(name: &str) -> Method {
+        match name {
+            "GET" => Method::Get,
+            "HEAD" => Method::Head,
+            "POST" => Method::Post,
+            "PUT" => Method::Put,
+            "DELETE" => Method::Delete,
+            "OPTIONS" => Method::Options,
+            "PATCH" => Method::Patch,
+            other => Method::Other(other.to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Method::Get => "GET",
+            Method::Head => "HEAD",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Delete => "DELETE",
+            Method::Options => "OPTIONS",
+            Method::Patch => "PATCH",
+            Method::Other(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Version {
+    Http10,
+    Http11,
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Version::Http10 => f.write_str("HTTP/1.0"),
+            Version::Http11 => f.write_str("HTTP/1.1"),
+        }
+    }
+}
+
+/// Header fields in the order they were given. Names are compared without
+/// regard to case.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Headers {
+    fields: Vec<(String, String)>,
+}
+
+impl Headers {
+    pub fn new() -> Headers {
+        Headers::default()
+    }
+
+    /// The value of the first field called `name`.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The values of every field called `name`.
+    pub fn get_all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.fields
+            .iter()
+            .filter(move |(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Adds a field, keeping any others of the same name.
+    pub fn add(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.fields.push((name.into(), value.into()));
+    }
+
+    /// Sets the field `name` to `value`, replacing any others of that name.
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        let name = name.into();
+        self.remove(&name);
+        self.fields.push((name, value.into()));
+    }
+
+    pub fn remove(&mut self, name: &str) {
+        self.fields.retain(|(n, _)| !n.eq_ignore_ascii_case(name));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields.iter().map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: Method,
+    /// The request target as it was given, such as `/search?q=rust`.
+    pub target: String,
+    /// The path of the target, percent-decoded.
+    pub path: String,
+    /// What follows the `?` in the target, as it was given.
+    pub query: Option<String>,
+    pub version: Version,
+    pub headers: Headers,
+    /// The body, without the chunked framing if it had it.
+    pub body: Vec<u8>,
+    /// The address of the client, if the request came over a connection.
+    pub remote: Option<SocketAddr>,
+}
+
+/// How the length of a request body is given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Framing {
+    None,
+    Length(u64),
+    Chunked,
+}
+
+/// Why a request could not be read.
+#[derive(Debug)]
+pub enum RequestError {
+    Io(io::Error),
+    /// The request is malformed, for the reason given.
+    BadRequest(&'static str),
+    /// The request is for a version of HTTP other than 1.0 and 1.1.
+    UnsupportedVersion,
+    /// The request line is longer than [`Limits::request_line`].
+    UriTooLong,
+    /// The header lines are more or longer than the limits allow.
+    HeadersTooLarge,
+    /// The body is longer than [`Limits::body`].
+    BodyTooLarge,
+    /// The body is sent with a transfer coding other than `chunked`.
+    UnsupportedEncoding,
+}
+
+impl RequestError {
+    /// The response telling the client what was wrong, if it can be told.
+    pub fn response(&self) -> Option<Response> {
+        match self {
+            RequestError::Io(_) => None,
+            RequestError::BadRequest(why) => Some(Response::text(400, format!("400 Bad Request: {why}\n"))),
+            RequestError::UnsupportedVersion => Some(Response::text(505, "505 HTTP Version Not Supported\n")),
+            RequestError::UriTooLong => Some(Response::text(414, "414 URI Too Long\n")),
+            RequestError::HeadersTooLarge => Some(Response::text(431, "431 Request Header Fields Too Large\n")),
+            RequestError::BodyTooLarge => Some(Response::text(413, "413 Content Too Large\n")),
+            RequestError::UnsupportedEncoding => Some(Response::text(501, "501 Not Implemented: transfer coding\n")),
+        }
+    }
+}
+
+impl fmt::Display for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RequestError::Io(err) => write!(f, "{err}"),
+            RequestError::BadRequest(why) => write!(f, "bad request: {why}"),
+            RequestError::UnsupportedVersion => write!(f, "unsupported HTTP version"),
+            RequestError::UriTooLong => write!(f, "request line too long"),
+            RequestError::HeadersTooLarge => write!(f, "headers too large"),
+            RequestError::BodyTooLarge => write!(f, "body too large"),
+            RequestError::UnsupportedEncoding => write!(f, "unsupported transfer coding"),
+        }
+    }
+}
+
+impl Error for RequestError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RequestError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for RequestError {
+    fn from(err: io::Error) -> RequestError {
+        RequestError::Io(err)
+    }
+}
+
+impl Request {
+    /// Reads the next request, with its body, within the default limits.
+    /// Returns `None` if the input ends before a request starts.
+    pub fn read(reader: &mut impl BufRead) -> Result<Option<Request>, RequestError> {
+        let limits = Limits::default();
+        let Some(mut request) = Request::read_head(reader, &limits)? else {
+            return Ok(None);
+        };
+        request.read_body(reader, &limits)?;
+        Ok(Some(request))
+    }
+
+    /// Reads the request line and headers of the next request, and checks
+    /// that its body, if any, is given in a way it can be read. Returns
+    /// `None` if the input ends before a request starts.
+    pub fn read_head(reader: &mut impl BufRead, limits: &Limits) -> Result<Option<Request>, RequestError> {
+        // A server should ignore empty lines in front of a request.
+        let line = loop {
+            match read_line(reader, limits.request_line)? {
+                Line::End => return Ok(None),
+                Line::TooLong => return Err(RequestError::UriTooLong),
+                Line::Text(line) if line.is_empty() => continue,
+                Line::Text(line) => break line,
+            }
+        };
+        let mut request = parse_request_line(&line)?;
+        read_fields(reader, limits, &mut request.headers, "the headers end early")?;
+        if request.version == Version::Http11 && request.headers.get_all("host").count() != 1 {
+            return Err(RequestError::BadRequest("HTTP/1.1 requests need one Host header"));
+        }
+        request.framing()?;
+        Ok(Some(request))
+    }
+
+    /// Reads the body the headers announce into `body`.
+    pub fn read_body(&mut self, reader: &mut impl BufRead, limits: &Limits) -> Result<(), RequestError> {
+        match self.framing()? {
+            Framing::None => {}
+            Framing::Length(length) => {
+                if length > limits.body as u64 {
+                    return Err(RequestError::BodyTooLarge);
+                }
+                self.body = Vec::with_capacity(length as usize);
+                reader.by_ref().take(length).read_to_end(&mut self.body)?;
+                if self.body.len() as u64 != length {
+                    return Err(RequestError::BadRequest("the body ends early"));
+                }
+            }
+            Framing::Chunked => read_chunked(reader, limits, &mut self.body)?,
+        }
+        Ok(())
+    }
+
+    /// Whether the client waits to be told to send the body, with `100
+    /// Continue`, before it does.
+    pub fn expects_continue(&self) -> bool {
+        self.version == Version::Http11
+            && self.headers.get("expect").is_some_and(|expect| expect.eq_ignore_ascii_case("100-continue"))
+            && self.framing().is_ok_and(|framing| framing != Framing::None)
+    }
+
+    /// Whether the client would keep the connection open for another
+    /// request: by default in HTTP/1.1, and with `Connection: keep-alive` in
+    /// HTTP/1.0.
+    pub fn keep_alive(&self) -> bool {
+        let options = || self.headers.get_all("connection").flat_map(|value| value.split(',')).map(str::trim);
+        if options().any(|option| option.eq_ignore_ascii_case("close")) {
+            return false;
+        }
+        self.version == Version::Http11 || options().any(|option| option.eq_ignore_ascii_case("keep-alive"))
+    }
+
+    /// How the body is given. A request with both `Transfer-Encoding` and
+    /// `Content-Length`, or with `Content-Length`s that disagree, is refused
+    /// rather than read one way when whatever passed it on may have read it
+    /// the other.
+    pub(crate) fn framing(&self) -> Result<Framing, RequestError> {
+        let list = |name| {
+            self.headers
+                .get_all(name)
+                .flat_map(|value| value.split(','))
+                .map(|item| item.trim_matches([' ', '\t']))
+                .filter(|item| !item.is_empty())
+                .collect::<Vec<_>>()
+        };
+        let (codings, lengths) = (list("transfer-encoding"), list("content-length"));
+        if !codings.is_empty() {
+            if !lengths.is_empty() {
+                return Err(RequestError::BadRequest("both Transfer-Encoding and Content-Length"));
+            }
+            if self.version == Version::Http10 {
+                return Err(RequestError::BadRequest("Transfer-Encoding in an HTTP/1.0 request"));
+            }
+            return match codings[..] {
+                [coding] if coding.eq_ignore_ascii_case("chunked") => Ok(Framing::Chunked),
+                _ => Err(RequestError::UnsupportedEncoding),
+            };
+        }
+        let Some(&length) = lengths.first() else {
+            return Ok(Framing::None);
+        };
+        if lengths.iter().any(|other| *other != length) {
+            return Err(RequestError::BadRequest("Content-Length headers that disagree"));
+        }
+        match length.bytes().all(|b| b.is_ascii_digit()).then(|| length.parse::<u64>()) {
+            Some(Ok(0)) => Ok(Framing::None),
+            Some(Ok(length)) => Ok(Framing::Length(length)),
+            _ => Err(RequestError::BadRequest("invalid Content-Length")),
+        }
+    }
+}
+
+/// A line of the head of a request.
+enum Line {
+    Text(String),
+    /// The line is longer than allowed.
+    TooLong,
+    /// The input ended before the line started.
+    End,
+}
+
+/// Reads a line ended by CRLF, or a bare LF, without its ending, if it is
+/// at most `limit` bytes long.
+fn read_line(reader: &mut impl BufRead, limit: usize) -> Result<Line, RequestError> {
+    let mut line = Vec::new();
+    let read = reader.by_ref().take(limit as u64 + 2).read_until(b'\n', &mut line)?;
+    if read == 0 {
+        return Ok(Line::End);
+    }
+    if line.pop() != Some(b'\n') {
+        return match read == limit + 2 {
+            true => Ok(Line::TooLong),
+            false => Err(RequestError::BadRequest("the request ends early")),
+        };
+    }
+    if line.last() == Some(&b'\r') {
+        line.pop();
+    }
+    if line.len() > limit {
+        return Ok(Line::TooLong);
+    }
+    // Field values may hold bytes outside ASCII, which are kept as best they
+    // can be; the rest of the request is checked to be ASCII where it is parsed.
+    Ok(Line::Text(String::from_utf8_lossy(&line).into_owned()))
+}
+
+/// Reads header lines into `headers`, up to the empty line that ends them.
+fn read_fields(
+    reader: &mut impl BufRead,
+    limits: &Limits,
+    headers: &mut Headers,
+    ends_early: &'static str,
+) -> Result<(), RequestError> {
+    let mut left = limits.header_bytes;
+    loop {
+        let line = match read_line(reader, left)? {
+            Line::Text(line) if line.is_empty() => return Ok(()),
+            Line::Text(line) => line,
+            Line::TooLong => return Err(RequestError::HeadersTooLarge),
+            Line::End => return Err(RequestError::BadRequest(ends_early)),
+        };
+        left = left.saturating_sub(line.len());
+        if line.starts_with([' ', '\t']) {
+            return Err(RequestError::BadRequest("folded header line"));
+        }
+        let (name, value) = line.split_once(':').ok_or(RequestError::BadRequest("header line without a colon"))?;
+        if !is_token(name) {
+            return Err(RequestError::BadRequest("invalid header name"));
+        }
+        if headers.fields.len() == limits.headers {
+            return Err(RequestError::HeadersTooLarge);
+        }
+        headers.add(name, value.trim_matches([' ', '\t']));
+    }
+}
+
+/// Reads a chunked body into `body`. The trailer fields after it are read
+/// and dropped.
+fn read_chunked(reader: &mut impl BufRead, limits: &Limits, body: &mut Vec<u8>) -> Result<(), RequestError> {
+    while read_chunk(reader, limits, body)? {}
+    Ok(())
+}
+
+/// Reads the next chunk of a chunked body onto the end of `body`, or the
+/// trailer fields after the last one. Returns whether more chunks follow.
+pub(crate) fn read_chunk(reader: &mut impl BufRead, limits: &Limits, body: &mut Vec<u8>) -> Result<bool, RequestError> {
+    let line = match read_line(reader, limits.request_line)? {
+        Line::Text(line) => line,
+        Line::TooLong => return Err(RequestError::BadRequest("chunk size line too long")),
+        Line::End => return Err(RequestError::BadRequest("the body ends early")),
+    };
+    let size = chunk_size(&line)?;
+    if size == 0 {
+        read_fields(reader, limits, &mut Headers::new(), "the body ends early")?;
+        return Ok(false);
+    }
+    if size > (limits.body - body.len()) as u64 {
+        return Err(RequestError::BodyTooLarge);
+    }
+    let start = body.len();
+    reader.by_ref().take(size).read_to_end(body)?;
+    if (body.len() - start) as u64 != size {
+        return Err(RequestError::BadRequest("the body ends early"));
+    }
+    match read_line(reader, 0)? {
+        Line::Text(_) => Ok(true),
+        Line::TooLong => Err(RequestError::BadRequest("chunk longer than its size")),
+        Line::End => Err(RequestError::BadRequest("the body ends early")),
+    }
+}
+
+/// How many bytes the chunk at the start of `buf` takes, from its size line
+/// through the CRLF after its data, once its size line is there. `None` for
+/// the last chunk, whose trailer fields can't be measured ahead, and for a
+/// chunk that can't be read, being malformed or larger than `room`.
+pub(crate) fn chunk_len(buf: &[u8], room: usize) -> Option<usize> {
+    let end = buf.iter().position(|&b| b == b'\n')?;
+    let line = std::str::from_utf8(&buf[..end]).ok()?;
+    let size = chunk_size(line.strip_suffix('\r').unwrap_or(line)).ok()?;
+    (size != 0 && size <= room as u64).then(|| end + 1 + size as usize + 2)
+}
+
+/// The size a chunk's size line gives.
+fn chunk_size(line: &str) -> Result<u64, RequestError> {
+    // Chunk extensions, after a `;`, are ignored.
+    let size = line.split(';').next().unwrap_or("").trim_matches([' ', '\t']);
+    if size.is_empty() || size.len() > 16 || !size.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(RequestError::BadRequest("invalid chunk size"));
+    }
+    Ok(u64::from_str_radix(size, 16).unwrap())
+}
+
+fn parse_request_line(line: &str) -> Result<Request, RequestError> {
+    let mut parts = line.split(' ');
+    let (Some(method), Some(target), Some(version), None) = (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(RequestError::BadRequest("the request line needs a method, a target and a version"));
+    };
+    if !is_token(method) {
+        return Err(RequestError::BadRequest("invalid method"));
+    }
+    let version = match version {
+        "HTTP/1.1" => Version::Http11,
+        "HTTP/1.0" => Version::Http10,
+        _ if version.starts_with("HTTP/") && version[5..].bytes().all(|b| b.is_ascii_digit() || b == b'.') => {
+            return Err(RequestError::UnsupportedVersion)
+        }
+        _ => return Err(RequestError::BadRequest("invalid HTTP version")),
+    };
+    if target.is_empty() || !target.bytes().all(|b| b.is_ascii_graphic()) {
+        return Err(RequestError::BadRequest("invalid request target"));
+    }
+    // The absolute form, `http://host/path`, is taken for its path.
+    let origin = match target.split_once("://") {
+        Some((scheme, rest)) if scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https") => {
+            match rest.find(['/', '?']) {
+                Some(start) if rest[start..].starts_with('?') => format!("/{}", &rest[start..]),
+                Some(start) => rest[start..].to_string(),
+                None => "/".to_string(),
+            }
+        }
+        _ if target == "*" && method == "OPTIONS" => target.to_string(),
+        _ if target.starts_with('/') => target.to_string(),
+        _ => return Err(RequestError::BadRequest("invalid request target")),
+    };
+    let (path, query) = match origin.split_once('?') {
+        Some((path, query)) => (path, Some(query.to_string())),
+        None => (origin.as_str(), None),
+    };
+    let path = percent_decode(path).ok_or(RequestError::BadRequest("invalid percent-encoding in the path"))?;
+    Ok(Request {
+        method: Method::parse(method),
+        target: target.to_string(),
+        path,
+        query,
+        version,
+        headers: Headers::new(),
+        body: Vec::new(),
+        remote: None,
+    })
+}
+
+/// Whether `text` is an HTTP token, as method and header names are.
+fn is_token(text: &str) -> bool {
+    !text.is_empty() && text.bytes().all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b))
+}
+
+/// Decodes the `%XX` escapes in `text`, if they are valid and decode to
+/// UTF-8.
+pub fn percent_decode(text: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+    while let Some((&b, tail)) = rest.split_first() {
+        if b == b'%' {
+            // Checked first, as `from_str_radix` takes a sign.
+            let hex = tail.get(..2).filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))?;
+            bytes.push(u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(b);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+pub struct Response {
+    pub status: u16,
+    pub headers: Headers,
+    pub body: Vec<u8>,
+    /// A body of a length not known in advance, sent as it is read, in place
+    /// of `body`.
+    pub(crate) stream: Option<Box<dyn Read + Send>>,
+    /// What takes the connection over once a `101 Switching Protocols` is
+    /// sent.
+    pub(crate) upgrade: Option<Box<dyn FnOnce(Upgraded) + Send>>,
+}
+
+impl fmt::Debug for Response {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Response")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .field("body", &String::from_utf8_lossy(&self.body))
+            .field("stream", &self.stream.is_some())
+            .field("upgrade", &self.upgrade.is_some())
+            .finish()
+    }
+}
+
+impl Response {
+    /// An empty response with the status `status`.
+    pub fn new(status: u16) -> Response {
+        Response {
+            status,
+            headers: Headers::new(),
+            body: Vec::new(),
+            stream: None,
+            upgrade: None,
+        }
+    }
+
+    /// A response whose body is read from `stream` as it is sent.
+    pub fn stream(status: u16, stream: impl Read + Send + 'static) -> Response {
+        Response {
+            stream: Some(Box::new(stream)),
+            ..Response::new(status)
+        }
+    }
+
+    /// A `101 Switching Protocols` to `protocol`, after which the connection
+    /// is handed to `on_upgrade` to speak it, on the worker that served the
+    /// request. See [`websocket::upgrade`](crate::websocket::upgrade).
+    pub fn upgrade(protocol: &str, on_upgrade: impl FnOnce(Upgraded) + Send + 'static) -> Response {
+        Response {
+            upgrade: Some(Box::new(on_upgrade)),
+            ..Response::new(101)
+        }
+        .with_header("Upgrade", protocol)
+        .with_header("Connection", "Upgrade")
+    }
+
+    /// A plain text response.
+    pub fn text(status: u16, body: impl Into<String>) -> Response {
+        Response::new(status)
+            .with_header("Content-Type", "text/plain; charset=utf-8")
+            .with_body(body.into())
+    }
+
+    /// An HTML response.
+    pub fn html(status: u16, body: impl Into<String>) -> Response {
+        Response::new(status)
+            .with_header("Content-Type", "text/html; charset=utf-8")
+            .with_body(body.into())
+    }
+
+    pub fn not_found() -> Response {
+        Response::text(404, "404 Not Found\n")
+    }
+
+    /// The response with the header `name` set to `value`.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Response {
+        self.headers.set(name, value);
+        self
+    }
+
+    pub fn with_body(mut self, body: impl Into<Vec<u8>>) -> Response {
+        self.body = body.into();
+        self
+    }
+
+    /// Writes the response to `request`, or to a request that could not be
+    /// read if there is none, adding the `Date` header and what says where
+    /// the body ends. The body is left out for a `HEAD` request. A streamed
+    /// body is sent chunked, or to an HTTP/1.0 client, ended by closing the
+    /// connection.
+    ///
+    /// Returns whether the connection stays open for another request: if
+    /// `keep_alive` says it may, the response does not say `Connection:
+    /// close` and its end is not the end of the connection. A `101
+    /// Switching Protocols` keeps its own `Connection` header, and ends the
+    /// connection as HTTP.
+    pub fn write_to(&mut self, out: &mut impl Write, request: Option<&Request>, keep_alive: bool) -> io::Result<bool> {
+        let head = request.is_some_and(|request| request.method == Method::Head);
+        let http10 = request.is_some_and(|request| request.version == Version::Http10);
+        let close = self.headers.get_all("connection").any(|value| value.eq_ignore_ascii_case("close"));
+        let switching = self.status == 101;
+        let ends_by_closing = http10 && self.stream.is_some();
+        let keep_alive = keep_alive && !close && !switching && !ends_by_closing;
+        let bodiless = self.status < 200 || self.status == 204 || self.status == 304;
+
+        let mut buf = Vec::with_capacity(256 + self.body.len());
+        write!(buf, "HTTP/1.1 {} {}\r\n", self.status, reason(self.status))?;
+        for (name, value) in self.headers.iter().filter(|(name, _)| switching || !name.eq_ignore_ascii_case("connection")) {
+            write!(buf, "{name}: {value}\r\n")?;
+        }
+        if self.headers.get("date").is_none() {
+            write!(buf, "Date: {}\r\n", date(SystemTime::now()))?;
+        }
+        if self.stream.is_some() {
+            if !http10 {
+                buf.extend_from_slice(b"Transfer-Encoding: chunked\r\n");
+            }
+        } else if !bodiless && self.headers.get("content-length").is_none() {
+            write!(buf, "Content-Length: {}\r\n", self.body.len())?;
+        }
+        if !keep_alive && !switching {
+            buf.extend_from_slice(b"Connection: close\r\n");
+        } else if keep_alive && http10 {
+            buf.extend_from_slice(b"Connection: keep-alive\r\n");
+        }
+        buf.extend_from_slice(b"\r\n");
+        if !head && !bodiless {
+            buf.extend_from_slice(&self.body);
+        }
+        out.write_all(&buf)?;
+        match &mut self.stream {
+            Some(stream) if !head && !bodiless && http10 => {
+                io::copy(stream, out)?;
+            }
+            Some(stream) if !head && !bodiless => write_chunked(stream, out)?,
+            _ => {}
+        }
+        out.flush()?;
+        Ok(keep_alive)
+    }
+}
+
+/// A connection that has switched from HTTP to another protocol, with what
+/// the client sent after the request that was read already.
+pub struct Upgraded {
+    stream: TcpStream,
+    buffered: Vec<u8>,
+    read: usize,
+}
+
+impl Upgraded {
+    pub fn new(stream: TcpStream, buffered: Vec<u8>) -> Upgraded {
+        Upgraded {
+            stream,
+            buffered,
+            read: 0,
+        }
+    }
+
+    pub fn stream(&self) -> &TcpStream {
+        &self.stream
+    }
+}
+
+impl Read for Upgraded {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.read < self.buffered.len() {
+            let read = (&self.buffered[self.read..]).read(buf)?;
+            self.read += read;
+            return Ok(read);
+        }
+        self.stream.read(buf)
+    }
+}
+
+impl Write for Upgraded {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stream.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+/// Copies `stream` to `out` in chunks, ending with the empty chunk.
+fn write_chunked(stream: &mut dyn Read, out: &mut impl Write) -> io::Result<()> {
+    let mut buf = vec![0; 16 * 1024];
+    loop {
+        let read = match stream.read(&mut buf) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        write!(out, "{read:x}\r\n")?;
+        out.write_all(&buf[..read])?;
+        out.write_all(b"\r\n")?;
+    }
+    out.write_all(b"0\r\n\r\n")
+}
+
+/// A `200 OK` plain text response.
+impl From<String> for Response {
+    fn from(body: String) -> Response {
+        Response::text(200, body)
+    }
+}
+
+impl From<&str> for Response {
+    fn from(body: &str) -> Response {
+        Response::text(200, body)
+    }
+}
+
+/// The reason phrase that goes with `status`.
+pub fn reason(status: u16) -> &'static str {
+    match status {
+        100 => "Continue",
+        101 => "Switching Protocols",
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted",
+        204 => "No Content",
+        301 => "Moved Permanently",
+        302 => "Found",
+        304 => "Not Modified",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        411 => "Length Required",
+        413 => "Content Too Large",
+        414 => "URI Too Long",
+        431 => "Request Header Fields Too Large",
+        500 => "Internal Server Error",
+        501 => "Not Implemented",
+        503 => "Service Unavailable",
+        505 => "HTTP Version Not Supported",
+        _ => "",
+    }
+}
+
+/// `time` in the format of the `Date` header, such as
+/// `Sun, 06 Nov 1994 08:49:37 GMT`.
+pub fn date(time: SystemTime) -> String {
+    const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    let seconds = time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let (days, seconds) = (seconds / 86400, seconds % 86400);
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{}, {day:02} {} {year} {:02}:{:02}:{:02} GMT",
+        // 1 January 1970 was a Thursday.
+        WEEKDAYS[((days + 4) % 7) as usize],
+        MONTHS[month as usize - 1],
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// `time` as access logs in the Common Log Format write it, such as
+/// `06/Nov/1994:08:49:37 +0000`.
+pub fn log_date(time: SystemTime) -> String {
+    let seconds = time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let (year, month, day) = civil_from_days(seconds / 86400);
+    format!(
+        "{day:02}/{}/{year}:{:02}:{:02}:{:02} +0000",
+        MONTHS[month as usize - 1],
+        seconds % 86400 / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+/// The year, month and day `days` days after 1 January 1970, by Howard
+/// Hinnant's `civil_from_days`.
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn read(text: &str) -> Result<Option<Request>, RequestError> {
+        Request::read(&mut text.as_bytes())
+    }
+
+    fn bad(text: &str) -> &'static str {
+        match read(text) {
+            Err(RequestError::BadRequest(why)) => why,
+            other => panic!("{text:?} gave {other:?}"),
+        }
+    }
+
+    #[test]
+    fn requests() {
+        let request = read("\r\nGET /a%20b/c?x=1&y HTTP/1.1\r\nHost: localhost\r\nX-Many:  one \r\nx-many:two\r\n\r\n")
+            .unwrap()
+            .unwrap();
+        assert_eq!(request.method, Method::Get);
+        assert_eq!((request.target.as_str(), request.path.as_str()), ("/a%20b/c?x=1&y", "/a b/c"));
+        assert_eq!(request.query.as_deref(), Some("x=1&y"));
+        assert_eq!(request.version, Version::Http11);
+        assert_eq!(request.headers.get("HOST"), Some("localhost"));
+        assert_eq!(request.headers.get_all("X-Many").collect::<Vec<_>>(), ["one", "two"]);
+
+        let request = read("BREW http://pot.example/coffee HTTP/1.0\n\n").unwrap().unwrap();
+        assert_eq!(request.method, Method::Other("BREW".to_string()));
+        assert_eq!((request.path.as_str(), request.version), ("/coffee", Version::Http10));
+        assert_eq!(read("OPTIONS * HTTP/1.0\r\n\r\n").unwrap().unwrap().path, "*");
+        assert_eq!(read("").unwrap(), None);
+        assert_eq!(read("\r\n").unwrap(), None);
+    }
+
+    #[test]
+    fn bad_requests() {
+        assert_eq!(bad("GET /\r\n\r\n"), "the request line needs a method, a target and a version");
+        assert_eq!(bad("GET  / HTTP/1.0\r\n\r\n"), "the request line needs a method, a target and a version");
+        assert_eq!(bad("G(T / HTTP/1.0\r\n\r\n"), "invalid method");
+        assert_eq!(bad("GET / HTTX/1.0\r\n\r\n"), "invalid HTTP version");
+        assert_eq!(bad("GET index.html HTTP/1.0\r\n\r\n"), "invalid request target");
+        assert_eq!(bad("GET /%zz HTTP/1.0\r\n\r\n"), "invalid percent-encoding in the path");
+        assert_eq!(bad("GET /%ff HTTP/1.0\r\n\r\n"), "invalid percent-encoding in the path");
+        assert_eq!(bad("GET /%+f HTTP/1.0\r\n\r\n"), "invalid percent-encoding in the path");
+        assert_eq!(bad("GET / HTTP/1.1\r\n\r\n"), "HTTP/1.1 requests need one Host header");
+        assert_eq!(bad("GET / HTTP/1.0\r\nHost : x\r\n\r\n"), "invalid header name");
+        assert_eq!(bad("GET / HTTP/1.0\r\nA: 1\r\n  2\r\n\r\n"), "folded header line");
+        assert_eq!(bad("GET / HTTP/1.0\r\nA 1\r\n\r\n"), "header line without a colon");
+        assert_eq!(bad("GET / HTTP/1.0\r\nA: 1\r\n"), "the headers end early");
+        assert_eq!(bad("GET / HTTP/1.0"), "the request ends early");
+        assert!(matches!(read("GET / HTTP/2.0\r\n\r\n"), Err(RequestError::UnsupportedVersion)));
+    }
+
+    #[test]
+    fn bodies() {
+        let request = read("POST /form HTTP/1.0\r\nContent-Length: 5\r\n\r\nhello, and more").unwrap().unwrap();
+        assert_eq!(request.body, b"hello");
+        let chunked = "POST / HTTP/1.1\r\nHost: x\r\nTransfer-Encoding: chunked\r\n\r\n\
+                       5;name=value\r\nhello\r\n7\r\n, world\r\n0\r\nChecksum: 1\r\n\r\nGET";
+        let mut input = chunked.as_bytes();
+        let request = Request::read(&mut input).unwrap().unwrap();
+        assert_eq!(request.body, b"hello, world");
+        assert_eq!(input, b"GET");
+
+        assert_eq!(bad("POST / HTTP/1.0\r\nContent-Length: 5\r\n\r\nhi"), "the body ends early");
+        assert_eq!(bad("POST / HTTP/1.0\r\nContent-Length: +5\r\n\r\n"), "invalid Content-Length");
+        assert_eq!(bad("POST / HTTP/1.0\r\nContent-Length: 1, 2\r\n\r\n"), "Content-Length headers that disagree");
+        assert_eq!(
+            bad("POST / HTTP/1.1\r\nHost: x\r\nContent-Length: 2\r\nTransfer-Encoding: chunked\r\n\r\n"),
+            "both Transfer-Encoding and Content-Length"
+        );
+        assert_eq!(bad("POST / HTTP/1.0\r\nTransfer-Encoding: chunked\r\n\r\n"), "Transfer-Encoding in an HTTP/1.0 request");
+        let chunked = |body: &str| format!("POST / HTTP/1.1\r\nHost: x\r\nTransfer-Encoding: chunked\r\n\r\n{body}");
+        assert_eq!(bad(&chunked("x\r\n")), "invalid chunk size");
+        assert_eq!(bad(&chunked("2\r\nabc\r\n")), "chunk longer than its size");
+        assert_eq!(bad(&chunked("5\r\nab")), "the body ends early");
+        assert_eq!(bad(&chunked("0\r\n")), "the body ends early");
+        let gzip = "POST / HTTP/1.1\r\nHost: x\r\nTransfer-Encoding: gzip, chunked\r\n\r\n";
+        assert!(matches!(read(gzip), Err(RequestError::UnsupportedEncoding)));
+    }
+
+    #[test]
+    fn limits() {
+        let limits = Limits {
+            request_line: 30,
+            header_bytes: 40,
+            headers: 3,
+            body: 10,
+        };
+        let read = |text: &str| {
+            let mut input = text.as_bytes();
+            let mut request = Request::read_head(&mut input, &limits)?.unwrap();
+            request.read_body(&mut input, &limits).map(|()| request)
+        };
+        assert!(read("GET /0123456789012345 HTTP/1.0\r\n\r\n").is_ok());
+        assert!(matches!(read("GET /01234567890123456 HTTP/1.0\r\n\r\n"), Err(RequestError::UriTooLong)));
+        assert!(read("GET / HTTP/1.0\r\nA: 0123456789\r\nB: 0123456789\r\n\r\n").is_ok());
+        let long = "GET / HTTP/1.0\r\nA: 0123456789\r\nB: 012345678901234567890123456789\r\n\r\n";
+        assert!(matches!(read(long), Err(RequestError::HeadersTooLarge)));
+        let many = "GET / HTTP/1.0\r\nA: 1\r\nB: 2\r\nC: 3\r\nD: 4\r\n\r\n";
+        assert!(matches!(read(many), Err(RequestError::HeadersTooLarge)));
+        assert!(read("PUT / HTTP/1.0\r\nContent-Length: 10\r\n\r\n0123456789").is_ok());
+        let large = "PUT / HTTP/1.0\r\nContent-Length: 11\r\n\r\n";
+        assert!(matches!(read(large), Err(RequestError::BodyTooLarge)));
+        let chunks = "PUT / HTTP/1.1\r\nHost: x\r\nTransfer-Encoding: chunked\r\n\r\n6\r\nabcdef\r\n5\r\nghijk\r\n";
+        assert!(matches!(read(chunks), Err(RequestError::BodyTooLarge)));
+    }
+
+    #[test]
+    fn connections() {
+        let request = |text: &str| read(text).unwrap().unwrap();
+        assert!(request("GET / HTTP/1.1\r\nHost: x\r\n\r\n").keep_alive());
+        assert!(!request("GET / HTTP/1.1\r\nHost: x\r\nConnection: TE, close\r\n\r\n").keep_alive());
+        assert!(!request("GET / HTTP/1.0\r\n\r\n").keep_alive());
+        assert!(request("GET / HTTP/1.0\r\nConnection: Keep-Alive\r\n\r\n").keep_alive());
+
+        let mut input: &[u8] = b"PUT / HTTP/1.1\r\nHost: x\r\nExpect: 100-continue\r\nContent-Length: 2\r\n\r\n";
+        let head = Request::read_head(&mut input, &Limits::default()).unwrap().unwrap();
+        assert!(head.expects_continue());
+        assert!(!request("GET / HTTP/1.1\r\nHost: x\r\nExpect: 100-continue\r\n\r\n").expects_continue());
+    }
+
+    #[test]
+    fn responses() {
+        let write = |mut response: Response, request: Option<&str>, keep_alive| {
+            let request = request.map(|text| read(text).unwrap().unwrap());
+            let mut out = Vec::new();
+            let kept = response.write_to(&mut out, request.as_ref(), keep_alive).unwrap();
+            (String::from_utf8(out).unwrap(), kept)
+        };
+        let gone = || Response::text(404, "gone\n").with_header("Date", "today");
+        assert_eq!(
+            write(gone(), None, false),
+            (
+                "HTTP/1.1 404 Not Found\r\nContent-Type: text/plain; charset=utf-8\r\nDate: today\r\n\
+                 Content-Length: 5\r\nConnection: close\r\n\r\ngone\n"
+                    .to_string(),
+                false
+            )
+        );
+        let (out, kept) = write(gone(), Some("HEAD / HTTP/1.1\r\nHost: x\r\n\r\n"), true);
+        assert!(out.ends_with("Date: today\r\nContent-Length: 5\r\n\r\n") && kept);
+        let (out, kept) = write(gone(), Some("GET / HTTP/1.0\r\nConnection: keep-alive\r\n\r\n"), true);
+        assert!(out.ends_with("Content-Length: 5\r\nConnection: keep-alive\r\n\r\ngone\n") && kept);
+        let (out, kept) = write(gone().with_header("Connection", "close"), None, true);
+        assert!(out.ends_with("Content-Length: 5\r\nConnection: close\r\n\r\ngone\n") && !kept);
+
+        let stream = || Response::stream(200, &b"streamed"[..]).with_header("Date", "today");
+        let (out, kept) = write(stream(), Some("GET / HTTP/1.1\r\nHost: x\r\n\r\n"), true);
+        assert_eq!(out, "HTTP/1.1 200 OK\r\nDate: today\r\nTransfer-Encoding: chunked\r\n\r\n8\r\nstreamed\r\n0\r\n\r\n");
+        assert!(kept);
+        let (out, kept) = write(stream(), Some("GET / HTTP/1.0\r\nConnection: keep-alive\r\n\r\n"), true);
+        assert_eq!(out, "HTTP/1.1 200 OK\r\nDate: today\r\nConnection: close\r\n\r\nstreamed");
+        assert!(!kept);
+        let (out, _) = write(Response::new(204).with_header("Date", "today"), None, false);
+        assert_eq!(out, "HTTP/1.1 204 No Content\r\nDate: today\r\nConnection: close\r\n\r\n");
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(date(UNIX_EPOCH), "Thu, 01 Jan 1970 00:00:00 GMT");
+        assert_eq!(date(UNIX_EPOCH + Duration::from_secs(784_111_777)), "Sun, 06 Nov 1994 08:49:37 GMT");
+        assert_eq!(date(UNIX_EPOCH + Duration::from_secs(951_782_400)), "Tue, 29 Feb 2000 00:00:00 GMT");
+        assert_eq!(log_date(UNIX_EPOCH + Duration::from_secs(784_111_777)), "06/Nov/1994:08:49:37 +0000");
+    }
+}
@@ -0,0 +1,521 @@
+//! The multithreaded web server of chapter 20 of "The Rust Programming
+//! Language", grown into a crate of its own.
+//!
+//! A [`Server`] accepts connections on a [`TcpListener`] and hands each to a
+//! [`ThreadPool`] worker, which reads the requests on it one after another
+//! and asks a [`Handler`] for the responses. A connection is kept open between
+//! requests unless the client asks otherwise, until it has been idle for the
+//! keep-alive timeout of the [`Options`]; while open, it has its worker to
+//! itself. [`StaticFiles`] is the handler that serves a directory:
+//!
+//! ```no_run
+//! use serv::{Server, StaticFiles};
+//!
+//! let server = Server::bind("127.0.0.1:7878").unwrap();
+//! server.serve(StaticFiles::new("www")).unwrap();
+//! ```
+//!
+//! A [`Control`] stops a server gracefully, letting the requests in flight
+//! finish, or changes what it serves without closing connections; the
+//! `serv` binary does both on signals, see [`signals`].
+//!
+//! Handlers for paths of their own are functions marked with `#[route]` and
+//! put in a [`Router`] with `routes!`, see [`router`]. Logging, compression
+//! and the like go around handlers as [`Middleware`], see [`middleware`]. A
+//! handler can also turn a connection into a WebSocket, see [`websocket`].
+//!
+//! On Linux, a server can instead wait on its connections with event loops,
+//! which serve any number of them with a few threads; see [`Backend`] and
+//! [`runtime`]. Handlers, routers among them, work the same on both.
+
+mod base64;
+#[cfg(target_os = "linux")]
+mod event_loop;
+pub mod files;
+mod gzip;
+pub mod http;
+pub mod middleware;
+pub mod pool;
+pub mod router;
+#[cfg(target_os = "linux")]
+pub mod runtime;
+mod sha1;
+#[cfg(unix)]
+pub mod signals;
+pub mod websocket;
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, RwLock};
+use std::task::Waker;
+use std::time::Duration;
+
+pub use files::StaticFiles;
+pub use http::{Limits, Method, Request, RequestError, Response, Upgraded};
+pub use middleware::{Middleware, Next};
+pub use pool::{PoolCreationError, ThreadPool};
+pub use router::Router;
+pub use serv_macros::{route, routes};
+
+/// How many workers a [`Server`] has unless told otherwise.
+pub const DEFAULT_WORKERS: usize = 8;
+
+/// How connections are served.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub limits: Limits,
+    /// How long a connection is kept open, idle, for another request. Zero
+    /// closes each connection after its first response.
+    pub keep_alive: Duration,
+    /// How long a client may take to send the rest of a request once it has
+    /// started, so that a silent client does not hold a worker forever.
+    pub read_timeout: Duration,
+    /// How long a shutdown waits for the requests in flight to be answered
+    /// before closing their connections anyway.
+    pub shutdown_timeout: Duration,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            limits: Limits::default(),
+            keep_alive: Duration::from_secs(5),
+            read_timeout: Duration::from_secs(10),
+            shutdown_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// A response still to come, as [`Handler::call`] gives it.
+pub type ResponseFuture = Pin<Box<dyn Future<Output = Response>>>;
+
+/// Answers requests. Closures taking a `&Request` are handlers too.
+pub trait Handler: Send + Sync + 'static {
+    fn handle(&self, request: &Request) -> Response;
+
+    /// The response to `request` as a future, which the event loop backend
+    /// waits on. By default the future calls `handle` when first polled,
+    /// which holds up the loop for as long as `handle` takes; handlers that
+    /// wait on other things are better off as `runtime::Async`.
+    fn call(self: Arc<Self>, request: Request) -> ResponseFuture {
+        Box::pin(async move { self.handle(&request) })
+    }
+
+    /// This handler inside `middleware`.
+    fn with(self, middleware: impl Middleware) -> middleware::Wrapped<Self>
+    where
+        Self: Sized,
+    {
+        middleware::Wrapped::new(self, middleware)
+    }
+}
+
+impl<F> Handler for F
+where
+    F: Fn(&Request) -> Response + Send + Sync + 'static,
+{
+    fn handle(&self, request: &Request) -> Response {
+        self(request)
+    }
+}
+
+/// How a [`Server`] waits on its connections.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Backend {
+    /// Each connection has a [`ThreadPool`] worker to itself while it is
+    /// open, so that no more connections are served at a time than there
+    /// are workers, and the others wait to be.
+    #[default]
+    Threads,
+    /// Each worker runs an event loop, see [`runtime`], which serves any
+    /// number of connections at a time, and answers each request with the
+    /// future of [`Handler::call`]. A handler that blocks holds up every
+    /// connection of its loop. A connection switching protocols is handed
+    /// to a thread of its own.
+    #[cfg(target_os = "linux")]
+    EventLoop,
+}
+
+pub struct Server {
+    listener: TcpListener,
+    workers: usize,
+    backend: Backend,
+    options: Options,
+    shared: Arc<Shared>,
+}
+
+impl Server {
+    /// Listens on `addr`. Port 0 picks a free port, see
+    /// [`Server::local_addr`].
+    pub fn bind(addr: impl ToSocketAddrs) -> io::Result<Server> {
+        Ok(Server {
+            listener: TcpListener::bind(addr)?,
+            workers: DEFAULT_WORKERS,
+            backend: Backend::default(),
+            options: Options::default(),
+            shared: Arc::default(),
+        })
+    }
+
+    /// Serves connections on `workers` threads, one connection at a time
+    /// each, or one event loop each, see [`Backend`].
+    pub fn workers(mut self, workers: usize) -> Server {
+        self.workers = workers;
+        self
+    }
+
+    pub fn backend(mut self, backend: Backend) -> Server {
+        self.backend = backend;
+        self
+    }
+
+    pub fn options(mut self, options: Options) -> Server {
+        self.options = options;
+        self
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// A handle to stop the server or change what it serves from another
+    /// thread, once it is serving.
+    pub fn control(&self) -> Control {
+        Control {
+            shared: Arc::clone(&self.shared),
+            addr: self.listener.local_addr().ok(),
+        }
+    }
+
+    /// Serves connections with `handler` until told to shut down by a
+    /// [`Control`], or until accepting a connection fails for a reason other
+    /// than the client going away first.
+    ///
+    /// A shutdown stops accepting connections and closes those that are idle.
+    /// The others are closed once the request in flight on them is answered,
+    /// or when the shutdown timeout of the options passes, whichever is
+    /// first. The workers are then joined, and `serve` returns.
+    pub fn serve(self, handler: impl Handler) -> io::Result<()> {
+        self.shared.set_site(Arc::new(handler), self.options);
+        match self.backend {
+            Backend::Threads => serve_threads(self.listener, self.workers, &self.shared),
+            #[cfg(target_os = "linux")]
+            Backend::EventLoop => event_loop::serve(self.listener, self.workers, &self.shared),
+        }
+    }
+}
+
+/// Serves each connection on a worker of a [`ThreadPool`].
+fn serve_threads(listener: TcpListener, workers: usize, shared: &Arc<Shared>) -> io::Result<()> {
+    let pool = ThreadPool::build(workers).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) if err.kind() == io::ErrorKind::ConnectionAborted => continue,
+            Err(err) => return Err(err),
+        };
+        // Known to `drain` from now on, rather than once a worker is free.
+        let stream = Arc::new(stream);
+        let open = shared.open(&stream);
+        pool.execute(move || {
+            // The client may go away at any time; there is no one to tell.
+            let _ = open.shared.serve_connection(&stream, open.id);
+        });
+        // Checked once what was accepted is served, as it may be a client
+        // that connected before the server was told to stop rather than the
+        // connection that wakes it.
+        if shared.stopping.load(Ordering::SeqCst) {
+            break;
+        }
+    }
+    drop(listener);
+    shared.drain();
+    drop(pool);
+    Ok(())
+}
+
+/// Stops a [`Server`] or changes what it serves, from another thread, as
+/// [`Server::control`] gives it. Clones control the same server.
+#[derive(Clone)]
+pub struct Control {
+    shared: Arc<Shared>,
+    /// Where the server listens, to wake it from waiting for a connection.
+    addr: Option<SocketAddr>,
+}
+
+impl Control {
+    /// Shuts the server down, see [`Server::serve`]. Returns at once.
+    pub fn shutdown(&self) {
+        if !self.shared.stop() {
+            return;
+        }
+        // The server waits in accept(2) for a connection; this is one.
+        if let Some(mut addr) = self.addr {
+            if addr.ip().is_unspecified() {
+                addr.set_ip(match addr {
+                    SocketAddr::V4(_) => IpAddr::V4(Ipv4Addr::LOCALHOST),
+                    SocketAddr::V6(_) => IpAddr::V6(Ipv6Addr::LOCALHOST),
+                });
+            }
+            let _ = TcpStream::connect_timeout(&addr, Duration::from_secs(1));
+        }
+    }
+
+    /// Answers the requests from now on with `handler`, under `options`,
+    /// those on connections already open included. Requests in flight are
+    /// answered as they started.
+    pub fn reload(&self, handler: impl Handler, options: Options) {
+        self.shared.set_site(Arc::new(handler), options);
+    }
+
+    /// Whether the server has been told to shut down.
+    pub fn is_shutting_down(&self) -> bool {
+        self.shared.stopping.load(Ordering::SeqCst)
+    }
+}
+
+/// The handler and options requests are answered with.
+#[derive(Clone)]
+struct Site {
+    handler: Arc<dyn Handler>,
+    options: Arc<Options>,
+}
+
+/// What a server shares with its workers and [`Control`]s.
+#[derive(Default)]
+struct Shared {
+    stopping: AtomicBool,
+    /// Set as the server starts serving.
+    site: RwLock<Option<Site>>,
+    connections: Mutex<Connections>,
+    /// Notified as each connection closes, and as the server is stopped.
+    closed: Condvar,
+    /// What wakes the event loops, to stop.
+    loops: Mutex<Vec<Waker>>,
+}
+
+/// The open connections, each with whether it waits for a request.
+#[derive(Default)]
+struct Connections {
+    next: u64,
+    open: HashMap<u64, (Arc<TcpStream>, bool)>,
+}
+
+impl Shared {
+    fn set_site(&self, handler: Arc<dyn Handler>, options: Options) {
+        let site = Site {
+            handler,
+            options: Arc::new(options),
+        };
+        *self.site.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(site);
+    }
+
+    fn site(&self) -> Site {
+        let site = self.site.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+        site.clone().expect("the site is set before connections are accepted")
+    }
+
+    fn connections(&self) -> MutexGuard<'_, Connections> {
+        self.connections.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Starts stopping the server, unless it is already. Returns whether it
+    /// was not.
+    fn stop(&self) -> bool {
+        if self.stopping.swap(true, Ordering::SeqCst) {
+            return false;
+        }
+        for waker in self.loops.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).drain(..) {
+            waker.wake();
+        }
+        // Taken, so that no one is between seeing `stopping` unset and
+        // waiting to be notified.
+        let _connections = self.connections();
+        self.closed.notify_all();
+        true
+    }
+
+    /// Waits until the server is told to stop.
+    #[cfg(target_os = "linux")]
+    fn wait_for_stop(&self) {
+        let connections = self.connections();
+        drop(self.closed.wait_while(connections, |_| !self.stopping.load(Ordering::SeqCst)));
+    }
+
+    /// Has `waker` woken as the server is told to stop.
+    #[cfg(target_os = "linux")]
+    fn wake_on_stop(&self, waker: Waker) {
+        self.loops.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).push(waker);
+    }
+
+    /// Makes `stream` known to [`Shared::drain`] until the [`Open`] returned
+    /// is dropped.
+    fn open(self: &Arc<Self>, stream: &Arc<TcpStream>) -> Open {
+        let mut connections = self.connections();
+        let id = connections.next;
+        connections.next += 1;
+        connections.open.insert(id, (Arc::clone(stream), false));
+        Open {
+            shared: Arc::clone(self),
+            id,
+        }
+    }
+
+    /// Like [`handle_connection`], but with the site as it is at each
+    /// request, for a connection [`Shared::open`] has made known as `id`.
+    fn serve_connection(&self, stream: &TcpStream, id: u64) -> io::Result<()> {
+        let mut reader = BufReader::new(stream);
+        let mut first = true;
+        loop {
+            let mut site = self.site();
+            if !first {
+                // Once marked idle, the connection is closed by a shutdown;
+                // one that started already has been missed by it.
+                self.set_idle(id, true);
+                if self.stopping.load(Ordering::SeqCst) {
+                    return Ok(());
+                }
+                let more = wait_for_request(stream, &mut reader, site.options.keep_alive)?;
+                self.set_idle(id, false);
+                if !more {
+                    return Ok(());
+                }
+                site = self.site();
+            }
+            first = false;
+            if !serve_request(stream, &mut reader, &*site.handler, &site.options, &self.stopping)? {
+                return Ok(());
+            }
+        }
+    }
+
+    fn set_idle(&self, id: u64, idle: bool) {
+        if let Some((_, was_idle)) = self.connections().open.get_mut(&id) {
+            *was_idle = idle;
+        }
+    }
+
+    /// Closes the idle connections, then waits for the others to close,
+    /// until the shutdown timeout passes and they are closed too.
+    fn drain(&self) {
+        let timeout = self.site().options.shutdown_timeout;
+        let connections = self.connections();
+        for (stream, idle) in connections.open.values() {
+            if *idle {
+                let _ = stream.shutdown(Shutdown::Both);
+            }
+        }
+        let (connections, _) = self
+            .closed
+            .wait_timeout_while(connections, timeout, |connections| !connections.open.is_empty())
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        for (stream, _) in connections.open.values() {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+    }
+}
+
+/// An open connection, forgotten by the server when dropped, however the
+/// connection ends.
+struct Open {
+    shared: Arc<Shared>,
+    id: u64,
+}
+
+impl Drop for Open {
+    fn drop(&mut self) {
+        self.shared.connections().open.remove(&self.id);
+        self.shared.closed.notify_all();
+    }
+}
+
+/// Answers the requests on `stream` with the responses `handler` gives,
+/// until the client closes the connection, asks for it to be closed or stays
+/// idle for longer than `options.keep_alive`. A request that cannot be read
+/// is answered with the error it has, and closes the connection; a handler
+/// that panics is answered with `500 Internal Server Error`.
+pub fn handle_connection(stream: TcpStream, handler: &dyn Handler, options: &Options) -> io::Result<()> {
+    let mut reader = BufReader::new(&stream);
+    let mut first = true;
+    loop {
+        if !first && !wait_for_request(&stream, &mut reader, options.keep_alive)? {
+            return Ok(());
+        }
+        first = false;
+        if !serve_request(&stream, &mut reader, handler, options, &AtomicBool::new(false))? {
+            return Ok(());
+        }
+    }
+}
+
+/// Reads the next request on `stream` and answers it. Returns whether the
+/// connection stays open for another, which it does not if `closing` is set
+/// by the time the response is ready. A response that switches protocols
+/// is given the connection, and returns when done with it.
+fn serve_request(
+    stream: &TcpStream,
+    reader: &mut BufReader<&TcpStream>,
+    handler: &dyn Handler,
+    options: &Options,
+    closing: &AtomicBool,
+) -> io::Result<bool> {
+    stream.set_read_timeout(Some(options.read_timeout))?;
+    let mut request = match Request::read_head(reader, &options.limits) {
+        Ok(Some(request)) => request,
+        Ok(None) => return Ok(false),
+        Err(err) => return refuse(stream, err).map(|()| false),
+    };
+    if request.expects_continue() {
+        (&*stream).write_all(b"HTTP/1.1 100 Continue\r\n\r\n")?;
+    }
+    if let Err(err) = request.read_body(reader, &options.limits) {
+        return refuse(stream, err).map(|()| false);
+    }
+    request.remote = stream.peer_addr().ok();
+    let mut response = respond(handler, &request);
+    let keep_alive = !closing.load(Ordering::SeqCst) && !options.keep_alive.is_zero() && request.keep_alive();
+    let keep_alive = response.write_to(&mut BufWriter::new(stream), Some(&request), keep_alive)?;
+    if let (101, Some(on_upgrade)) = (response.status, response.upgrade.take()) {
+        stream.set_read_timeout(None)?;
+        on_upgrade(Upgraded::new(stream.try_clone()?, reader.buffer().to_vec()));
+    }
+    Ok(keep_alive)
+}
+
+/// Waits up to `idle` for the next request to start. Returns whether one
+/// did, rather than the connection closing or staying idle.
+fn wait_for_request(stream: &TcpStream, reader: &mut impl BufRead, idle: Duration) -> io::Result<bool> {
+    stream.set_read_timeout(Some(idle))?;
+    match reader.fill_buf() {
+        Ok(buf) => Ok(!buf.is_empty()),
+        Err(err) if matches!(err.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+/// Answers a request that could not be read with its error, then closes the
+/// connection. What the client is still sending is read for a moment first:
+/// closing with it unread would reset the connection, and the client might
+/// lose the response.
+fn refuse(stream: &TcpStream, err: RequestError) -> io::Result<()> {
+    let Some(mut response) = err.response() else {
+        return Err(io::Error::other(err));
+    };
+    response.write_to(&mut BufWriter::new(stream), None, false)?;
+    stream.shutdown(Shutdown::Write)?;
+    stream.set_read_timeout(Some(Duration::from_secs(1)))?;
+    let _ = io::copy(&mut stream.take(1024 * 1024), &mut io::sink());
+    Ok(())
+}
+
+/// The response `handler` gives to `request`, or `500 Internal Server Error`
+/// if it panics.
+fn respond(handler: &dyn Handler, request: &Request) -> Response {
+    panic::catch_unwind(AssertUnwindSafe(|| handler.handle(request)))
+        .unwrap_or_else(|_| Response::text(500, "500 Internal Server Error\n"))
+}
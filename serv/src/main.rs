@@ -0,0 +1,224 @@
+use std::env;
+use std::fs;
+use std::process;
+use std::time::Duration;
+
+use serv::middleware::{AccessLog, Gzip, Wrapped};
+use serv::{Backend, Handler, Middleware, Next, Options, Request, Server, StaticFiles, DEFAULT_WORKERS};
+
+const USAGE: &str = "usage: serv [OPTIONS] [ROOT]
+
+Serves the files below ROOT (default: the current directory) over HTTP,
+compressed with gzip for the clients that take it.
+
+  -a, --addr ADDR             listen on ADDR (default: 127.0.0.1:7878)
+  -w, --workers WORKERS       handle WORKERS connections at a time (default: 8)
+  -e, --event-loop            run an event loop on each worker instead, which
+                              handles any number of connections (Linux only)
+  -k, --keep-alive SECONDS    keep idle connections open this long (default: 5,
+                              0 closes them after each response)
+      --max-body BYTES        refuse longer request bodies (default: 1048576)
+      --max-header-bytes BYTES
+                              refuse longer request headers (default: 16384)
+      --shutdown-timeout SECONDS
+                              on shutdown, wait this long for the requests in
+                              flight (default: 30)
+  -l, --log                   log each request to stderr, in the Common Log
+                              Format
+  -c, --config FILE           read settings from FILE, one `name = value` a
+                              line, named as the long flags or `root`; flags
+                              given here take precedence
+  -h, --help                  print this help
+
+SIGINT and SIGTERM stop accepting connections and wait for the requests in
+flight; a second one stops at once. SIGHUP reads the configuration again and
+serves from the new ROOT, with the new options, keeping connections open; a
+new address, number of workers or event loop setting needs a restart.";
+
+/// The settings a configuration file can have, as the long flags name them.
+const SETTINGS: [&str; 9] = [
+    "addr",
+    "workers",
+    "event-loop",
+    "keep-alive",
+    "max-body",
+    "max-header-bytes",
+    "shutdown-timeout",
+    "log",
+    "root",
+];
+
+struct Config {
+    addr: String,
+    workers: usize,
+    backend: Backend,
+    options: Options,
+    log: bool,
+    root: String,
+}
+
+impl Config {
+    /// The configuration the command line gives, with that of the file it
+    /// names, if any, under it.
+    fn build(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+        args.next();
+        let args: Vec<String> = args.collect();
+        let mut config = Config {
+            addr: "127.0.0.1:7878".to_string(),
+            workers: DEFAULT_WORKERS,
+            backend: Backend::Threads,
+            options: Options::default(),
+            log: false,
+            root: ".".to_string(),
+        };
+        let file = args.iter().position(|arg| arg == "-c" || arg == "--config");
+        if let Some(i) = file {
+            let path = args.get(i + 1).ok_or_else(|| format!("{} needs a value", args[i]))?;
+            let text = fs::read_to_string(path).map_err(|err| format!("cannot read {path}: {err}"))?;
+            config.apply(file_args(path, &text)?.into_iter())?;
+        }
+        config.apply(args.into_iter())?;
+        Ok(config)
+    }
+
+    fn apply(&mut self, mut args: impl Iterator<Item = String>) -> Result<(), String> {
+        let mut root = None;
+        while let Some(arg) = args.next() {
+            let mut value = |flag: &str| args.next().ok_or_else(|| format!("{flag} needs a value"));
+            let mut number = |flag: &str| {
+                let value = value(flag)?;
+                value.parse::<usize>().map_err(|_| format!("{flag} needs a number, not `{value}`"))
+            };
+            match arg.as_str() {
+                "-a" | "--addr" => self.addr = value(&arg)?,
+                "-w" | "--workers" => {
+                    self.workers = number(&arg)?;
+                    if self.workers == 0 {
+                        return Err(format!("{arg} needs a positive number, not `0`"));
+                    }
+                }
+                #[cfg(target_os = "linux")]
+                "-e" | "--event-loop" => self.backend = Backend::EventLoop,
+                #[cfg(not(target_os = "linux"))]
+                "-e" | "--event-loop" => return Err(format!("{arg} is for Linux only")),
+                "-k" | "--keep-alive" => self.options.keep_alive = Duration::from_secs(number(&arg)? as u64),
+                "--max-body" => self.options.limits.body = number(&arg)?,
+                "--max-header-bytes" => self.options.limits.header_bytes = number(&arg)?,
+                "--shutdown-timeout" => self.options.shutdown_timeout = Duration::from_secs(number(&arg)? as u64),
+                "-l" | "--log" => self.log = true,
+                "-c" | "--config" => {
+                    value(&arg)?;
+                }
+                "-h" | "--help" => return Err(USAGE.to_string()),
+                _ if arg.starts_with('-') => return Err(format!("unknown flag {arg}, see --help")),
+                _ if root.is_none() => root = Some(arg),
+                _ => return Err(format!("unexpected argument `{arg}`, see --help")),
+            }
+        }
+        if let Some(root) = root {
+            self.root = root;
+        }
+        Ok(())
+    }
+
+    /// The static files of the root, with the middleware the configuration
+    /// asks for.
+    fn handler(&self) -> Wrapped<StaticFiles> {
+        let log = self.log.then(AccessLog::stderr);
+        StaticFiles::new(&self.root)
+            .with(move |request: &Request, next: Next<'_>| match &log {
+                Some(log) => log.handle(request, next),
+                None => next.run(request),
+            })
+            .with(Gzip::new())
+    }
+}
+
+/// The settings in the configuration file at `path`, as the flags that give
+/// them.
+fn file_args(path: &str, text: &str) -> Result<Vec<String>, String> {
+    let mut args = Vec::new();
+    for (n, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (name, value) = line.split_once('=').map_or((line, ""), |(name, value)| (name.trim(), value.trim()));
+        if !SETTINGS.contains(&name) {
+            return Err(format!("{path}:{}: unknown setting `{name}`", n + 1));
+        }
+        match name {
+            "root" => args.push(value.to_string()),
+            "log" | "event-loop" => match value {
+                "" | "true" => args.push(format!("--{name}")),
+                "false" => {}
+                _ => return Err(format!("{path}:{}: {name} needs true or false, not `{value}`", n + 1)),
+            },
+            _ => args.extend([format!("--{name}"), value.to_string()]),
+        }
+    }
+    Ok(args)
+}
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        if err == USAGE {
+            println!("{err}");
+            process::exit(0);
+        }
+        eprintln!("Problem parsing arguments: {err}");
+        process::exit(1);
+    });
+
+    let server = Server::bind(&config.addr).unwrap_or_else(|err| {
+        eprintln!("Cannot listen on {}: {err}", config.addr);
+        process::exit(1);
+    });
+    #[cfg(unix)]
+    if let Err(err) = catch_signals(server.control(), &config) {
+        eprintln!("Cannot catch signals: {err}");
+        process::exit(1);
+    }
+    if let Ok(addr) = server.local_addr() {
+        eprintln!("Serving {} on http://{addr}", config.root);
+    }
+    let handler = config.handler();
+    if let Err(e) = server.workers(config.workers).backend(config.backend).options(config.options).serve(handler) {
+        eprintln!("Application error: {e}");
+        process::exit(1);
+    }
+}
+
+/// Shuts the server down on SIGINT and SIGTERM, or exits if it is shutting
+/// down already, and reloads the configuration on SIGHUP.
+#[cfg(unix)]
+fn catch_signals(control: serv::Control, config: &Config) -> std::io::Result<()> {
+    use serv::signals::{self, Signal};
+
+    let caught = signals::catch(&[Signal::Hangup, Signal::Interrupt, Signal::Terminate])?;
+    let (addr, workers, backend) = (config.addr.clone(), config.workers, config.backend);
+    std::thread::spawn(move || {
+        for signal in caught {
+            if signal != Signal::Hangup {
+                if control.is_shutting_down() {
+                    eprintln!("Stopping now");
+                    process::exit(1);
+                }
+                eprintln!("Shutting down");
+                control.shutdown();
+                continue;
+            }
+            match Config::build(env::args()) {
+                Ok(config) => {
+                    if config.addr != addr || config.workers != workers || config.backend != backend {
+                        eprintln!("The address, the number of workers and the event loops change on restart only");
+                    }
+                    control.reload(config.handler(), config.options);
+                    eprintln!("Reloaded, serving {}", config.root);
+                }
+                Err(err) => eprintln!("Cannot reload: {err}"),
+            }
+        }
+    });
+    Ok(())
+}
@@ -0,0 +1,795 @@
+//! Code that runs around handlers.
+//!
+//! A [`Middleware`] is given each request ahead of the handler it wraps,
+//! along with a [`Next`] that runs the rest of the way there: the middleware
+//! after it, then the handler. What it does before calling [`Next::run`]
+//! happens on the way in, and what it does with the response it gets back
+//! happens on the way out, the way a `Drop` guard's `drop` runs as its scope
+//! ends. It can also answer without calling `next` at all, as [`BasicAuth`]
+//! does for a request without the right credentials.
+//!
+//! [`Handler::with`] wraps any handler, a whole [`Router`] included, and
+//! [`Router::wrap`] wraps one of its routes. The middleware added first runs
+//! first:
+//!
+//! ```no_run
+//! use serv::middleware::{AccessLog, BasicAuth, Gzip};
+//! use serv::{route, routes, Handler, Server, StaticFiles};
+//!
+//! #[route(GET, "/")]
+//! fn index() -> &'static str {
+//!     "Hello!\n"
+//! }
+//!
+//! #[route(POST, "/admin/reload")]
+//! fn reload() -> &'static str {
+//!     "reloaded\n"
+//! }
+//!
+//! fn main() {
+//!     let router = routes![index, reload]
+//!         .wrap(&reload::ROUTE, BasicAuth::user("admin", "admin", "hunter2"))
+//!         .fallback(StaticFiles::new("www"));
+//!     let handler = router.with(AccessLog::stderr()).with(Gzip::new());
+//!     Server::bind("127.0.0.1:7878").unwrap().serve(handler).unwrap();
+//! }
+//! ```
+//!
+//! Closures taking the request and a `Next` are middleware too.
+//!
+//! On the event loop backend, the response of a handler that answers with a
+//! future, such as a [`runtime::handler`], is waited for with [`Next::call`],
+//! as [`Next::run`] would block the loop until it came. The middleware here
+//! do so, and [`runtime::middleware`] makes middleware of an async closure.
+//!
+//! [`Router`]: crate::Router
+//! [`Router::wrap`]: crate::Router::wrap
+//! [`runtime::handler`]: crate::runtime::handler
+//! [`runtime::middleware`]: crate::runtime::middleware
+
+use std::future::{self, Future};
+use std::io::{self, Write};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::SystemTime;
+use std::{fmt, process};
+
+use crate::http::{self, Headers, Method, Request, Response};
+use crate::{base64, gzip, Handler, ResponseFuture};
+
+/// A response still to come from a middleware, as [`Middleware::call`] gives
+/// it.
+pub type NextFuture<'a> = Pin<Box<dyn Future<Output = Response> + 'a>>;
+
+/// Runs around a handler, see the [module documentation](self).
+pub trait Middleware: Send + Sync + 'static {
+    /// Answers `request`, usually with what `next` answers it, or a request
+    /// made from it, with.
+    fn handle(&self, request: &Request, next: Next<'_>) -> Response;
+
+    /// `handle` as a future, which the event loop backend waits on. By
+    /// default the future calls `handle`, so that a handler answering with a
+    /// future is waited for by `next.run`, in a runtime of its own, holding
+    /// up the loop; middleware that waits with [`Next::call`] here does not.
+    fn call<'a>(&'a self, request: Request, next: Next<'a>) -> NextFuture<'a> {
+        Box::pin(async move { self.handle(&request, next) })
+    }
+}
+
+impl<F> Middleware for F
+where
+    F: Fn(&Request, Next<'_>) -> Response + Send + Sync + 'static,
+{
+    fn handle(&self, request: &Request, next: Next<'_>) -> Response {
+        self(request, next)
+    }
+}
+
+/// What comes after a middleware: the middleware after it, then the
+/// handler.
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    middleware: &'a [Box<dyn Middleware>],
+    handler: &'a (dyn Fn(&Request) -> Response + 'a),
+    /// The handler's response as a future, for handlers that answer with
+    /// one.
+    future: Option<&'a (dyn Fn(Request) -> ResponseFuture + 'a)>,
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(middleware: &'a [Box<dyn Middleware>], handler: &'a (dyn Fn(&Request) -> Response + 'a)) -> Next<'a> {
+        Next {
+            middleware,
+            handler,
+            future: None,
+        }
+    }
+
+    /// Has [`Next::call`] wait for the handler's response as `future` gives
+    /// it, rather than call the handler.
+    pub(crate) fn with_future(mut self, future: &'a (dyn Fn(Request) -> ResponseFuture + 'a)) -> Next<'a> {
+        self.future = Some(future);
+        self
+    }
+
+    /// The response the rest of the way gives to `request`.
+    pub fn run(self, request: &Request) -> Response {
+        match self.middleware.split_first() {
+            Some((first, rest)) => first.handle(request, Next { middleware: rest, ..self }),
+            None => (self.handler)(request),
+        }
+    }
+
+    /// The response the rest of the way gives to `request`, as a future that
+    /// waits on the middleware and handler that answer with futures rather
+    /// than blocking.
+    pub fn call(self, request: Request) -> NextFuture<'a> {
+        match (self.middleware.split_first(), self.future) {
+            (Some((first, rest)), _) => first.call(request, Next { middleware: rest, ..self }),
+            (None, Some(future)) => future(request),
+            (None, None) => Box::pin(async move { (self.handler)(&request) }),
+        }
+    }
+}
+
+/// A handler wrapped in middleware, as [`Handler::with`] makes it.
+pub struct Wrapped<H> {
+    handler: Arc<H>,
+    middleware: Vec<Box<dyn Middleware>>,
+}
+
+impl<H: Handler> Wrapped<H> {
+    pub(crate) fn new(handler: H, middleware: impl Middleware) -> Wrapped<H> {
+        Wrapped {
+            handler: Arc::new(handler),
+            middleware: vec![Box::new(middleware)],
+        }
+    }
+
+    /// Wraps the handler in `middleware` too, inside the middleware already
+    /// around it.
+    pub fn with(mut self, middleware: impl Middleware) -> Wrapped<H> {
+        self.middleware.push(Box::new(middleware));
+        self
+    }
+}
+
+impl<H: Handler> Handler for Wrapped<H> {
+    fn handle(&self, request: &Request) -> Response {
+        Next::new(&self.middleware, &|request| self.handler.handle(request)).run(request)
+    }
+
+    fn call(self: Arc<Self>, request: Request) -> ResponseFuture {
+        Box::pin(async move {
+            let handler = |request: &Request| self.handler.handle(request);
+            let future = |request| Arc::clone(&self.handler).call(request);
+            Next::new(&self.middleware, &handler).with_future(&future).call(request).await
+        })
+    }
+}
+
+/// Writes a line for each request in the Common Log Format, once it has
+/// been answered:
+///
+/// ```text
+/// 127.0.0.1 - admin [06/Nov/1994:08:49:37 +0000] "GET /index.html HTTP/1.1" 200 2326
+/// ```
+///
+/// The user is the one given with Basic authentication, whether or not it
+/// was let in. Clones write to the same place.
+#[derive(Clone)]
+pub struct AccessLog {
+    out: Arc<Mutex<Box<dyn Write + Send>>>,
+}
+
+impl AccessLog {
+    pub fn new(out: impl Write + Send + 'static) -> AccessLog {
+        AccessLog {
+            out: Arc::new(Mutex::new(Box::new(out))),
+        }
+    }
+
+    pub fn stderr() -> AccessLog {
+        AccessLog::new(io::stderr())
+    }
+
+    /// Writes the line for the request `entry` is of, now that it has been
+    /// answered with `response`.
+    fn write(&self, entry: Entry, response: &Response) {
+        let Entry { time, host, user, line } = entry;
+        let bytes = match response.body.len() {
+            0 => "-".to_string(),
+            _ if response.stream.is_some() => "-".to_string(),
+            length => length.to_string(),
+        };
+        let entry = format!("{host} - {user} [{}] \"{line}\" {} {bytes}\n", http::log_date(time), response.status);
+        // The log is no reason to fail the request.
+        let mut out = self.out.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let _ = out.write_all(entry.as_bytes()).and_then(|()| out.flush());
+    }
+}
+
+/// What the log says of a request, taken as it comes in.
+struct Entry {
+    time: SystemTime,
+    host: String,
+    user: String,
+    line: String,
+}
+
+impl Entry {
+    fn new(request: &Request) -> Entry {
+        Entry {
+            time: SystemTime::now(),
+            host: request.remote.map_or_else(|| "-".to_string(), |addr| addr.ip().to_string()),
+            user: credentials(request).map_or_else(|| "-".to_string(), |(user, _)| escape(&user)),
+            line: escape(&format!("{} {} {}", request.method, request.target, request.version)),
+        }
+    }
+}
+
+impl Middleware for AccessLog {
+    fn handle(&self, request: &Request, next: Next<'_>) -> Response {
+        let entry = Entry::new(request);
+        let response = next.run(request);
+        self.write(entry, &response);
+        response
+    }
+
+    fn call<'a>(&'a self, request: Request, next: Next<'a>) -> NextFuture<'a> {
+        let entry = Entry::new(&request);
+        Box::pin(async move {
+            let response = next.call(request).await;
+            self.write(entry, &response);
+            response
+        })
+    }
+}
+
+/// `text` with quotes, backslashes and control characters escaped, so that
+/// a client cannot forge log lines.
+fn escape(text: &str) -> String {
+    text.escape_default().to_string()
+}
+
+/// Compresses the responses of clients that accept gzip, if their bodies are
+/// text of at least the minimum size, 256 bytes unless set.
+#[derive(Debug, Clone)]
+pub struct Gzip {
+    min_size: usize,
+}
+
+impl Gzip {
+    pub fn new() -> Gzip {
+        Gzip { min_size: 256 }
+    }
+
+    /// Leaves bodies shorter than `bytes` as they are: compressing them
+    /// gains little or nothing.
+    pub fn min_size(mut self, bytes: usize) -> Gzip {
+        self.min_size = bytes;
+        self
+    }
+
+    /// `response`, compressed if the client `accepts` gzip and it is worth
+    /// compressing.
+    fn compress(&self, accepts: bool, mut response: Response) -> Response {
+        let compressible = response.headers.get("content-type").is_some_and(is_compressible);
+        if !compressible
+            || response.body.len() < self.min_size
+            || response.stream.is_some()
+            || response.headers.get("content-encoding").is_some()
+            || matches!(response.status, 100..=199 | 204 | 206 | 304)
+        {
+            return response;
+        }
+        vary(&mut response.headers, "Accept-Encoding");
+        if !accepts {
+            return response;
+        }
+        let compressed = gzip::compress(&response.body);
+        if compressed.len() < response.body.len() {
+            response.body = compressed;
+            response.headers.set("Content-Encoding", "gzip");
+            response.headers.remove("content-length");
+        }
+        response
+    }
+}
+
+impl Default for Gzip {
+    fn default() -> Gzip {
+        Gzip::new()
+    }
+}
+
+impl Middleware for Gzip {
+    fn handle(&self, request: &Request, next: Next<'_>) -> Response {
+        self.compress(accepts_gzip(&request.headers), next.run(request))
+    }
+
+    fn call<'a>(&'a self, request: Request, next: Next<'a>) -> NextFuture<'a> {
+        let accepts = accepts_gzip(&request.headers);
+        Box::pin(async move { self.compress(accepts, next.call(request).await) })
+    }
+}
+
+/// Whether bodies of `content_type` are text, which compresses well, rather
+/// than images, archives and the like, which are compressed already.
+fn is_compressible(content_type: &str) -> bool {
+    let essence = content_type.split(';').next().unwrap_or("").trim().to_ascii_lowercase();
+    essence.starts_with("text/") || ["json", "javascript", "xml"].iter().any(|kind| essence.contains(kind))
+}
+
+/// Whether the `Accept-Encoding` in `headers` takes gzip, by name or as
+/// `*`, with a weight above zero.
+fn accepts_gzip(headers: &Headers) -> bool {
+    let mut any = false;
+    for coding in headers.get_all("accept-encoding").flat_map(|value| value.split(',')) {
+        let mut parts = coding.split(';').map(str::trim);
+        let name = parts.next().unwrap_or("");
+        let weight = parts
+            .filter_map(|param| param.strip_prefix("q="))
+            .find_map(|q| q.parse::<f32>().ok())
+            .unwrap_or(1.0);
+        if name.eq_ignore_ascii_case("gzip") || name.eq_ignore_ascii_case("x-gzip") {
+            return weight > 0.0;
+        }
+        any |= name == "*" && weight > 0.0;
+    }
+    any
+}
+
+/// Adds `name` to the headers the response depends on, for caches.
+fn vary(headers: &mut Headers, name: &str) {
+    match headers.get("vary") {
+        Some(value) if value.split(',').any(|v| v.trim().eq_ignore_ascii_case(name) || v.trim() == "*") => {}
+        Some(value) => {
+            let value = format!("{value}, {name}");
+            headers.set("Vary", value);
+        }
+        None => headers.set("Vary", name),
+    }
+}
+
+/// Lets pages from other origins call the handler: answers the preflight
+/// `OPTIONS` requests browsers send before the requests they do not send
+/// freely, and marks responses as readable by the origins allowed.
+///
+/// Any origin is allowed unless some are given with [`Cors::origin`], or
+/// credentials are allowed.
+#[derive(Debug, Clone)]
+pub struct Cors {
+    origins: Vec<String>,
+    methods: Vec<String>,
+    headers: Vec<String>,
+    credentials: bool,
+    max_age: Option<u64>,
+}
+
+impl Cors {
+    /// Lets any origin make `GET`, `HEAD` and `POST` requests with any
+    /// headers, without credentials.
+    pub fn new() -> Cors {
+        Cors {
+            origins: Vec::new(),
+            methods: ["GET", "HEAD", "POST"].map(String::from).to_vec(),
+            headers: Vec::new(),
+            credentials: false,
+            max_age: None,
+        }
+    }
+
+    /// Allows `origin`, such as `https://example.com`, rather than any.
+    pub fn origin(mut self, origin: impl Into<String>) -> Cors {
+        self.origins.push(origin.into());
+        self
+    }
+
+    pub fn methods(mut self, methods: &[&str]) -> Cors {
+        self.methods = methods.iter().map(|m| m.to_string()).collect();
+        self
+    }
+
+    /// Allows the request headers given rather than any.
+    pub fn headers(mut self, headers: &[&str]) -> Cors {
+        self.headers = headers.iter().map(|h| h.to_string()).collect();
+        self
+    }
+
+    /// Lets requests carry cookies and credentials. Only the origins given
+    /// with [`Cors::origin`] are then allowed, none if there are none: any
+    /// site could otherwise read what the user's cookies give it.
+    pub fn credentials(mut self) -> Cors {
+        self.credentials = true;
+        self
+    }
+
+    /// Lets browsers remember a preflight answer for `seconds`.
+    pub fn max_age(mut self, seconds: u64) -> Cors {
+        self.max_age = Some(seconds);
+        self
+    }
+
+    /// Marks `response` as readable by `origin`, if it is allowed.
+    fn allow(&self, origin: &str, response: &mut Response) {
+        if self.origins.is_empty() && !self.credentials {
+            response.headers.set("Access-Control-Allow-Origin", "*");
+            return;
+        }
+        vary(&mut response.headers, "Origin");
+        if !self.origins.iter().any(|o| o == origin) {
+            return;
+        }
+        response.headers.set("Access-Control-Allow-Origin", origin);
+        if self.credentials {
+            response.headers.set("Access-Control-Allow-Credentials", "true");
+        }
+    }
+
+    /// The answer to a preflight request from `origin` asking to make a
+    /// `method` request.
+    fn preflight(&self, origin: &str, method: &str, request: &Request) -> Response {
+        let mut response = Response::new(204);
+        self.allow(origin, &mut response);
+        if response.headers.get("access-control-allow-origin").is_none() {
+            return response;
+        }
+        if self.methods.iter().any(|m| m == method) {
+            response.headers.set("Access-Control-Allow-Methods", self.methods.join(", "));
+        }
+        let headers = match (self.headers.is_empty(), request.headers.get("access-control-request-headers")) {
+            (false, _) => Some(self.headers.join(", ")),
+            (true, requested) => requested.map(str::to_string),
+        };
+        if let Some(headers) = headers {
+            response.headers.set("Access-Control-Allow-Headers", headers);
+        }
+        if let Some(seconds) = self.max_age {
+            response.headers.set("Access-Control-Max-Age", seconds.to_string());
+        }
+        response
+    }
+}
+
+impl Default for Cors {
+    fn default() -> Cors {
+        Cors::new()
+    }
+}
+
+impl Middleware for Cors {
+    fn handle(&self, request: &Request, next: Next<'_>) -> Response {
+        let Some(origin) = request.headers.get("origin") else {
+            return next.run(request);
+        };
+        match request.headers.get("access-control-request-method") {
+            Some(method) if request.method == Method::Options => self.preflight(origin, method, request),
+            _ => {
+                let mut response = next.run(request);
+                self.allow(origin, &mut response);
+                response
+            }
+        }
+    }
+
+    fn call<'a>(&'a self, request: Request, next: Next<'a>) -> NextFuture<'a> {
+        let Some(origin) = request.headers.get("origin").map(str::to_string) else {
+            return next.call(request);
+        };
+        match request.headers.get("access-control-request-method") {
+            Some(method) if request.method == Method::Options => {
+                Box::pin(future::ready(self.preflight(&origin, method, &request)))
+            }
+            _ => Box::pin(async move {
+                let mut response = next.call(request).await;
+                self.allow(&origin, &mut response);
+                response
+            }),
+        }
+    }
+}
+
+/// Gives each request an ID, in its `X-Request-Id` header and the
+/// response's, so that its traces can be told apart from those of others. An
+/// ID the client sends is kept if it is a reasonable one, as when a proxy in
+/// front has given it already.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RequestId;
+
+impl Middleware for RequestId {
+    fn handle(&self, request: &Request, next: Next<'_>) -> Response {
+        let (id, mut response) = match given_request_id(request) {
+            Some(id) => (id.to_string(), next.run(request)),
+            None => {
+                let id = new_request_id();
+                let mut request = request.clone();
+                request.headers.set("X-Request-Id", id.as_str());
+                let response = next.run(&request);
+                (id, response)
+            }
+        };
+        response.headers.set("X-Request-Id", id);
+        response
+    }
+
+    fn call<'a>(&'a self, mut request: Request, next: Next<'a>) -> NextFuture<'a> {
+        let id = match given_request_id(&request) {
+            Some(id) => id.to_string(),
+            None => {
+                let id = new_request_id();
+                request.headers.set("X-Request-Id", id.as_str());
+                id
+            }
+        };
+        Box::pin(async move {
+            let mut response = next.call(request).await;
+            response.headers.set("X-Request-Id", id);
+            response
+        })
+    }
+}
+
+/// The ID the client gave `request`, if it is a reasonable one.
+fn given_request_id(request: &Request) -> Option<&str> {
+    request.headers.get("x-request-id").filter(|id| {
+        (1..=200).contains(&id.len()) && id.bytes().all(|b| b.is_ascii_graphic())
+    })
+}
+
+/// An ID no other request to this process has, and unlikely to be one that
+/// a request to another has had: the time the process started its count,
+/// its ID and the count.
+fn new_request_id() -> String {
+    static START: OnceLock<String> = OnceLock::new();
+    static COUNT: AtomicU64 = AtomicU64::new(0);
+    let start = START.get_or_init(|| {
+        let since = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default();
+        format!("{:x}{:04x}", since.as_millis(), process::id() & 0xffff)
+    });
+    format!("{start}-{:x}", COUNT.fetch_add(1, Ordering::Relaxed))
+}
+
+/// Whether a user name and password let a request in.
+type Check = dyn Fn(&str, &str) -> bool + Send + Sync;
+
+/// Lets through only the requests with the user name and password of a user
+/// its check takes, in an `Authorization` header of the Basic scheme (RFC
+/// 7617). The others are answered with `401 Unauthorized`, which asks the
+/// browser for them.
+#[derive(Clone)]
+pub struct BasicAuth {
+    realm: String,
+    check: Arc<Check>,
+}
+
+impl BasicAuth {
+    /// Asks for the user name and password for `realm`, a name for what they
+    /// give access to, and lets in those that `check` takes.
+    pub fn new(realm: impl Into<String>, check: impl Fn(&str, &str) -> bool + Send + Sync + 'static) -> BasicAuth {
+        BasicAuth {
+            realm: realm.into(),
+            check: Arc::new(check),
+        }
+    }
+
+    /// Lets in `user` with `password` alone. Passwords are compared in time
+    /// that depends on their lengths only, so that timing the answers does
+    /// not give away how much of one is right.
+    pub fn user(realm: impl Into<String>, user: &str, password: &str) -> BasicAuth {
+        let (user, password) = (user.to_string(), password.to_string());
+        BasicAuth::new(realm, move |u, p| same(u.as_bytes(), user.as_bytes()) & same(p.as_bytes(), password.as_bytes()))
+    }
+
+    /// The `401 Unauthorized` to answer `request` with, unless its
+    /// credentials let it in.
+    fn refuse(&self, request: &Request) -> Option<Response> {
+        match credentials(request) {
+            Some((user, password)) if (self.check)(&user, &password) => None,
+            _ => {
+                let realm = self.realm.replace('\\', "\\\\").replace('"', "\\\"");
+                let response = Response::text(401, "401 Unauthorized\n")
+                    .with_header("WWW-Authenticate", format!("Basic realm=\"{realm}\", charset=\"UTF-8\""));
+                Some(response)
+            }
+        }
+    }
+}
+
+impl fmt::Debug for BasicAuth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BasicAuth").field("realm", &self.realm).finish_non_exhaustive()
+    }
+}
+
+impl Middleware for BasicAuth {
+    fn handle(&self, request: &Request, next: Next<'_>) -> Response {
+        self.refuse(request).unwrap_or_else(|| next.run(request))
+    }
+
+    fn call<'a>(&'a self, request: Request, next: Next<'a>) -> NextFuture<'a> {
+        match self.refuse(&request) {
+            Some(response) => Box::pin(future::ready(response)),
+            None => next.call(request),
+        }
+    }
+}
+
+/// The user name and password in the `Authorization` header of `request`,
+/// if it has one of the Basic scheme.
+fn credentials(request: &Request) -> Option<(String, String)> {
+    let (scheme, token) = request.headers.get("authorization")?.trim().split_once(' ')?;
+    if !scheme.eq_ignore_ascii_case("basic") {
+        return None;
+    }
+    let decoded = String::from_utf8(base64::decode(token.trim())?).ok()?;
+    let (user, password) = decoded.split_once(':')?;
+    Some((user.to_string(), password.to_string()))
+}
+
+/// Whether `a` and `b` are the same, taking as long for any `a` of a length.
+fn same(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn request(text: &str) -> Request {
+        Request::read(&mut text.as_bytes()).unwrap().unwrap()
+    }
+
+    fn hello(_: &Request) -> Response {
+        Response::html(200, "<p>Hello!</p>\n".repeat(40))
+    }
+
+    fn run(middleware: impl Middleware, request: &Request) -> Response {
+        middleware.handle(request, Next::new(&[], &hello))
+    }
+
+    #[test]
+    fn chains() {
+        fn tag(name: &'static str) -> impl Middleware {
+            move |request: &Request, next: Next<'_>| {
+                let mut request = request.clone();
+                request.headers.add("x-trace", format!("{name} in"));
+                let mut response = next.run(&request);
+                response.headers.add("x-trace", format!("{name} out"));
+                response
+            }
+        }
+        let handler = (|request: &Request| {
+            let trace: Vec<&str> = request.headers.get_all("x-trace").collect();
+            Response::text(200, trace.join(", "))
+        })
+        .with(tag("a"))
+        .with(tag("b"));
+        let response = handler.handle(&request("GET / HTTP/1.0\r\n\r\n"));
+        assert_eq!(response.body, b"a in, b in");
+        assert_eq!(response.headers.get_all("x-trace").collect::<Vec<_>>(), ["b out", "a out"]);
+    }
+
+    #[test]
+    fn access_log() {
+        #[derive(Clone)]
+        struct Shared(Arc<Mutex<Vec<u8>>>);
+        impl Write for Shared {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+        let out = Shared(Arc::default());
+        let log = AccessLog::new(out.clone());
+        let mut get = request("GET /a%20b?q=\"x\" HTTP/1.1\r\nHost: h\r\nAuthorization: Basic YWRtaW46cHc=\r\n\r\n");
+        get.remote = Some("10.0.0.1:5000".parse().unwrap());
+        run(log.clone(), &get);
+        log.handle(&request("DELETE / HTTP/1.0\r\n\r\n"), Next::new(&[], &|_| Response::new(204)));
+        let text = String::from_utf8(out.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        let (head, tail) = lines[0].split_once(" [").unwrap();
+        assert_eq!(head, "10.0.0.1 - admin");
+        assert!(tail.ends_with("+0000] \"GET /a%20b?q=\\\"x\\\" HTTP/1.1\" 200 560"), "{tail}");
+        assert!(lines[1].starts_with("- - - [") && lines[1].ends_with("] \"DELETE / HTTP/1.0\" 204 -"));
+    }
+
+    #[test]
+    fn compression() {
+        let get = request("GET / HTTP/1.1\r\nHost: h\r\nAccept-Encoding: deflate, gzip;q=0.5\r\n\r\n");
+        let response = run(Gzip::new(), &get);
+        assert_eq!(response.headers.get("content-encoding"), Some("gzip"));
+        assert_eq!(response.headers.get("vary"), Some("Accept-Encoding"));
+        let mut body = String::new();
+        minigrep::inflate::Decoder::new(&response.body[..], minigrep::inflate::Format::Gzip)
+            .read_to_string(&mut body)
+            .unwrap();
+        assert_eq!(body, "<p>Hello!</p>\n".repeat(40));
+
+        for accept in ["", "Accept-Encoding: br\r\n", "Accept-Encoding: gzip;q=0, *\r\n"] {
+            let response = run(Gzip::new(), &request(&format!("GET / HTTP/1.1\r\nHost: h\r\n{accept}\r\n")));
+            assert_eq!(response.headers.get("content-encoding"), None, "{accept}");
+            assert_eq!(response.headers.get("vary"), Some("Accept-Encoding"));
+        }
+        assert!(accepts_gzip(&request("GET / HTTP/1.0\r\nAccept-Encoding: br;q=1, *;q=0.1\r\n\r\n").headers));
+        assert_eq!(run(Gzip::new().min_size(1000), &get).headers.get("content-encoding"), None);
+        let png = Gzip::new().handle(&get, Next::new(&[], &|_| hello(&get).with_header("Content-Type", "image/png")));
+        assert_eq!(png.headers.get("content-encoding"), None);
+        assert!(is_compressible("application/json; charset=utf-8") && is_compressible("image/svg+xml"));
+    }
+
+    #[test]
+    fn cors() {
+        let simple = request("GET / HTTP/1.1\r\nHost: h\r\nOrigin: https://a.example\r\n\r\n");
+        let response = run(Cors::new(), &simple);
+        assert_eq!(response.headers.get("access-control-allow-origin"), Some("*"));
+        assert_eq!(response.headers.get("vary"), None);
+        assert_eq!(run(Cors::new(), &request("GET / HTTP/1.0\r\n\r\n")).headers.get("access-control-allow-origin"), None);
+
+        let only_b = Cors::new().origin("https://b.example").credentials();
+        let response = run(only_b.clone(), &simple);
+        assert_eq!(response.headers.get("access-control-allow-origin"), None);
+        assert_eq!(response.headers.get("vary"), Some("Origin"));
+        let from_b = request("GET / HTTP/1.1\r\nHost: h\r\nOrigin: https://b.example\r\n\r\n");
+        let response = run(only_b, &from_b);
+        assert_eq!(response.headers.get("access-control-allow-origin"), Some("https://b.example"));
+        assert_eq!(response.headers.get("access-control-allow-credentials"), Some("true"));
+        // Credentials without origins given allow none, rather than any.
+        let response = run(Cors::new().credentials(), &simple);
+        assert_eq!(response.headers.get("access-control-allow-origin"), None);
+        assert_eq!(response.headers.get("access-control-allow-credentials"), None);
+
+        let preflight = request(
+            "OPTIONS /api HTTP/1.1\r\nHost: h\r\nOrigin: https://a.example\r\n\
+             Access-Control-Request-Method: PUT\r\nAccess-Control-Request-Headers: content-type\r\n\r\n",
+        );
+        let cors = Cors::new().methods(&["GET", "PUT"]).max_age(600);
+        let response = run(cors.clone(), &preflight);
+        assert_eq!(response.status, 204);
+        assert_eq!(response.headers.get("access-control-allow-methods"), Some("GET, PUT"));
+        assert_eq!(response.headers.get("access-control-allow-headers"), Some("content-type"));
+        assert_eq!(response.headers.get("access-control-max-age"), Some("600"));
+        let response = run(cors.headers(&["x-token"]).methods(&["GET"]), &preflight);
+        assert_eq!(response.headers.get("access-control-allow-methods"), None);
+        assert_eq!(response.headers.get("access-control-allow-headers"), Some("x-token"));
+    }
+
+    #[test]
+    fn request_ids() {
+        let echo = |request: &Request| Response::text(200, request.headers.get("x-request-id").unwrap_or(""));
+        let first = RequestId.handle(&request("GET / HTTP/1.0\r\n\r\n"), Next::new(&[], &echo));
+        let second = RequestId.handle(&request("GET / HTTP/1.0\r\n\r\n"), Next::new(&[], &echo));
+        let id = first.headers.get("x-request-id").unwrap();
+        assert_eq!(first.body, id.as_bytes());
+        assert_ne!(second.headers.get("x-request-id"), Some(id));
+
+        let given = request("GET / HTTP/1.0\r\nX-Request-Id: abc-123\r\n\r\n");
+        let response = RequestId.handle(&given, Next::new(&[], &echo));
+        assert_eq!((response.headers.get("x-request-id"), &response.body[..]), (Some("abc-123"), &b"abc-123"[..]));
+        let bad = request(&format!("GET / HTTP/1.0\r\nX-Request-Id: {}\r\n\r\n", "x".repeat(201)));
+        assert_ne!(RequestId.handle(&bad, Next::new(&[], &echo)).body.len(), 201);
+    }
+
+    #[test]
+    fn basic_auth() {
+        let auth = BasicAuth::user("the \"admin\" area", "Aladdin", "open sesame");
+        let response = run(auth.clone(), &request("GET / HTTP/1.0\r\n\r\n"));
+        assert_eq!(response.status, 401);
+        assert_eq!(
+            response.headers.get("www-authenticate"),
+            Some("Basic realm=\"the \\\"admin\\\" area\", charset=\"UTF-8\"")
+        );
+        let with = |credentials: &str| request(&format!("GET / HTTP/1.0\r\nAuthorization: {credentials}\r\n\r\n"));
+        assert_eq!(run(auth.clone(), &with("Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ==")).status, 200);
+        assert_eq!(run(auth.clone(), &with("basic  QWxhZGRpbjpvcGVuIHNlc2FtZQ==")).status, 200);
+        for wrong in ["Basic QWxhZGRpbjpvcGVuIHNlc2FtZg==", "Bearer QWxhZGRpbjpvcGVuIHNlc2FtZQ==", "Basic !!"] {
+            assert_eq!(run(auth.clone(), &with(wrong)).status, 401, "{wrong}");
+        }
+        let anyone = BasicAuth::new("users", |user, _| !user.is_empty());
+        assert_eq!(run(anyone, &with("Basic ZmVycmlzOg==")).status, 200);
+    }
+}
@@ -0,0 +1,144 @@
+//! The thread pool of chapter 20: a fixed number of workers taking jobs from
+//! one channel.
+
+use std::error::Error;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// Runs jobs on a fixed number of threads. Dropping the pool lets the workers
+/// finish the jobs already given to them, then joins them.
+pub struct ThreadPool {
+    workers: Vec<Worker>,
+    sender: Option<mpsc::Sender<Job>>,
+}
+
+/// A pool of no threads was asked for.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PoolCreationError;
+
+impl fmt::Display for PoolCreationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a thread pool needs at least one thread")
+    }
+}
+
+impl Error for PoolCreationError {}
+
+impl ThreadPool {
+    /// Create a new ThreadPool.
+    ///
+    /// The size is the number of threads in the pool.
+    ///
+    /// # Panics
+    ///
+    /// The `new` function will panic if the size is zero.
+    pub fn new(size: usize) -> ThreadPool {
+        ThreadPool::build(size).unwrap()
+    }
+
+    /// Like [`ThreadPool::new`], but returns an error instead of panicking.
+    pub fn build(size: usize) -> Result<ThreadPool, PoolCreationError> {
+        if size == 0 {
+            return Err(PoolCreationError);
+        }
+        let (sender, receiver) = mpsc::channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = (0..size).map(|id| Worker::new(id, Arc::clone(&receiver))).collect();
+        Ok(ThreadPool {
+            workers,
+            sender: Some(sender),
+        })
+    }
+
+    /// Runs `f` on the first worker that is free. A job that panics does not
+    /// take its worker down with it.
+    pub fn execute<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.sender.as_ref().unwrap().send(Box::new(f)).unwrap();
+    }
+
+    /// How many workers the pool has.
+    pub fn size(&self) -> usize {
+        self.workers.len()
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        // Closing the channel is what tells the workers to stop, once they
+        // have taken the jobs still in it.
+        drop(self.sender.take());
+        for worker in &mut self.workers {
+            if let Some(thread) = worker.thread.take() {
+                thread.join().unwrap();
+            }
+        }
+    }
+}
+
+struct Worker {
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl Worker {
+    fn new(id: usize, receiver: Arc<Mutex<mpsc::Receiver<Job>>>) -> Worker {
+        let thread = thread::Builder::new()
+            .name(format!("serv-worker-{id}"))
+            .spawn(move || loop {
+                // The lock is let go of before the job runs, so the other
+                // workers can take jobs meanwhile.
+                let message = receiver.lock().unwrap().recv();
+                match message {
+                    Ok(job) => {
+                        let _ = panic::catch_unwind(AssertUnwindSafe(job));
+                    }
+                    Err(_) => break,
+                }
+            })
+            .expect("failed to spawn a worker thread");
+        Worker { thread: Some(thread) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[test]
+    fn size_zero() {
+        assert_eq!(ThreadPool::build(0).err(), Some(PoolCreationError));
+        assert_eq!(ThreadPool::new(3).size(), 3);
+    }
+
+    #[test]
+    fn drop_finishes_the_jobs() {
+        let done = Arc::new(AtomicUsize::new(0));
+        let pool = ThreadPool::new(2);
+        for _ in 0..8 {
+            let done = Arc::clone(&done);
+            pool.execute(move || {
+                thread::sleep(Duration::from_millis(10));
+                done.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        drop(pool);
+        assert_eq!(done.load(Ordering::SeqCst), 8);
+    }
+
+    #[test]
+    fn panicking_jobs_keep_the_workers() {
+        let pool = ThreadPool::new(1);
+        let (tx, rx) = mpsc::channel();
+        pool.execute(|| panic!("job failed"));
+        pool.execute(move || tx.send("still here").unwrap());
+        assert_eq!(rx.recv_timeout(Duration::from_secs(5)), Ok("still here"));
+    }
+}
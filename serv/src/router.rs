@@ -0,0 +1,291 @@
+//! Dispatching requests to handlers by method and path.
+//!
+//! Handlers are functions marked with `#[route(METHOD, "/path")]`. A path
+//! segment `:name` matches any one segment and is handed to the parameter
+//! called `name`, parsed to its type with [`FromStr`]: a segment that does not
+//! parse makes the request not found. A parameter that is a reference is given
+//! the [`Request`]. Handlers return anything that converts into a
+//! [`Response`], such as a `String`.
+//!
+//! On Linux, handlers can be `async fn`s, which the event loop backend waits
+//! on along with other connections, as it does a [`runtime::handler`], and
+//! the thread pool in a runtime of their own. Middleware around them, a
+//! router's or its routes', is best written as futures too, see
+//! [`Middleware::call`].
+//!
+//! [`routes!`] collects handlers into a [`Router`]:
+//!
+//! ```no_run
+//! use serv::{route, routes, Request, Response, Server};
+//!
+//! #[route(GET, "/")]
+//! fn index() -> &'static str {
+//!     "Hello!\n"
+//! }
+//!
+//! #[route(GET, "/users/:id/posts/:post")]
+//! fn post(id: u32, post: String, request: &Request) -> Response {
+//!     Response::text(200, format!("post {post} by user {id}, for {:?}\n", request.query))
+//! }
+//!
+//! fn main() {
+//!     let router = routes![index, post];
+//!     Server::bind("127.0.0.1:7878").unwrap().serve(router).unwrap();
+//! }
+//! ```
+//!
+//! Handlers are items of a module rather than of a function body: `#[route]`
+//! puts the route in a module next to the function, which refers to it
+//! through `super`.
+//!
+//! Two handlers for the same method and path do not compile, even when their
+//! parameters are named differently:
+//!
+//! ```compile_fail
+//! use serv::{route, routes};
+//!
+//! #[route(GET, "/users/:id")]
+//! fn user(id: u32) -> String {
+//!     format!("user {id}")
+//! }
+//!
+//! #[route(GET, "/users/:name")]
+//! fn user_by_name(name: String) -> String {
+//!     format!("user {name}")
+//! }
+//!
+//! fn main() {
+//!     let router = routes![user, user_by_name];
+//! }
+//! ```
+//!
+//! [`FromStr`]: std::str::FromStr
+//! [`runtime::handler`]: crate::runtime::handler
+//! [`routes!`]: crate::routes
+
+use std::sync::Arc;
+
+use crate::http::{Method, Request, Response};
+use crate::middleware::{Middleware, Next};
+use crate::{Handler, ResponseFuture};
+
+/// A handler with the method and path it answers, as `#[route]` makes it.
+#[derive(Debug, Clone)]
+pub struct Route {
+    pub method: Method,
+    /// The path, with `:name` for the segments that are parameters.
+    pub pattern: &'static str,
+    /// Answers a request, given the segments of the path that matched the
+    /// parameters in `pattern`, in order.
+    pub handler: fn(&Request, &[&str]) -> Response,
+    /// For a handler that is an `async fn`, its response as a future, which
+    /// `handler` waits for in a runtime of its own.
+    pub future: Option<fn(Request, &[&str]) -> ResponseFuture>,
+}
+
+impl Route {
+    /// The segments of `path` that match the parameters of the pattern, if it
+    /// matches.
+    fn matches<'a>(&self, path: &'a str) -> Option<Vec<&'a str>> {
+        let mut params = Vec::new();
+        let mut segments = path.split('/');
+        for expected in self.pattern.split('/') {
+            let segment = segments.next()?;
+            if expected.starts_with(':') && !segment.is_empty() {
+                params.push(segment);
+            } else if expected != segment {
+                return None;
+            }
+        }
+        segments.next().is_none().then_some(params)
+    }
+}
+
+/// Whether two `#[route]` keys are the same route. The `routes!` macro calls
+/// this at compile time.
+#[doc(hidden)]
+pub const fn same_key(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Answers each request with the first route that matches it. A route for
+/// `GET` answers `HEAD` too. A path that a route matches for other methods
+/// only is answered with `405 Method Not Allowed`; one that no route
+/// matches goes to the fallback, which is `404 Not Found` unless set.
+pub struct Router {
+    routes: Vec<Route>,
+    /// The middleware around each route, in the order of `routes`.
+    middleware: Vec<Vec<Box<dyn Middleware>>>,
+    fallback: Option<Arc<dyn Handler>>,
+}
+
+/// Where a [`Router`] sends a request.
+enum Dispatch<'a> {
+    /// To a route, with its middleware and the segments of the path that
+    /// match its parameters.
+    Route(&'a Route, &'a [Box<dyn Middleware>], Vec<&'a str>),
+    /// Nowhere: it is answered with this.
+    Answer(Response),
+    Fallback(&'a Arc<dyn Handler>),
+}
+
+impl Router {
+    pub fn new(routes: Vec<Route>) -> Router {
+        let middleware = routes.iter().map(|_| Vec::new()).collect();
+        Router {
+            routes,
+            middleware,
+            fallback: None,
+        }
+    }
+
+    /// Wraps the route for the same method and pattern as `route`, such as
+    /// `&reload::ROUTE` for the handler `reload`, in `middleware`, inside
+    /// the middleware already around it.
+    ///
+    /// # Panics
+    ///
+    /// If the router has no such route.
+    pub fn wrap(mut self, route: &Route, middleware: impl Middleware) -> Router {
+        let Some(i) = self.routes.iter().position(|r| r.method == route.method && r.pattern == route.pattern) else {
+            panic!("no route for {} {} to wrap", route.method, route.pattern);
+        };
+        self.middleware[i].push(Box::new(middleware));
+        self
+    }
+
+    /// Sends the requests no route matches to `handler`, such as
+    /// [`StaticFiles`](crate::StaticFiles).
+    pub fn fallback(mut self, handler: impl Handler) -> Router {
+        self.fallback = Some(Arc::new(handler));
+        self
+    }
+
+    pub fn routes(&self) -> &[Route] {
+        &self.routes
+    }
+
+    /// Where a request for `method` and `path` goes.
+    fn dispatch<'a>(&'a self, method: &Method, path: &'a str) -> Dispatch<'a> {
+        let mut allowed: Vec<&str> = Vec::new();
+        for (route, middleware) in self.routes.iter().zip(&self.middleware) {
+            let Some(params) = route.matches(path) else {
+                continue;
+            };
+            if route.method == *method || (route.method == Method::Get && *method == Method::Head) {
+                return Dispatch::Route(route, middleware, params);
+            }
+            allowed.push(route.method.as_str());
+        }
+        if !allowed.is_empty() {
+            if allowed.contains(&"GET") && !allowed.contains(&"HEAD") {
+                allowed.push("HEAD");
+            }
+            let response = Response::text(405, "405 Method Not Allowed\n").with_header("Allow", allowed.join(", "));
+            return Dispatch::Answer(response);
+        }
+        match &self.fallback {
+            Some(fallback) => Dispatch::Fallback(fallback),
+            None => Dispatch::Answer(Response::not_found()),
+        }
+    }
+}
+
+impl Handler for Router {
+    fn handle(&self, request: &Request) -> Response {
+        match self.dispatch(&request.method, &request.path) {
+            Dispatch::Route(route, middleware, params) => {
+                Next::new(middleware, &|request| (route.handler)(request, &params)).run(request)
+            }
+            Dispatch::Answer(response) => response,
+            Dispatch::Fallback(fallback) => fallback.handle(request),
+        }
+    }
+
+    fn call(self: Arc<Self>, request: Request) -> ResponseFuture {
+        Box::pin(async move {
+            // The request is handed on, so the parameters are segments of a
+            // copy of its path.
+            let path = request.path.clone();
+            match self.dispatch(&request.method, &path) {
+                Dispatch::Route(route, middleware, params) => {
+                    let handler = |request: &Request| (route.handler)(request, &params);
+                    let next = Next::new(middleware, &handler);
+                    match route.future {
+                        Some(future) => next.with_future(&|request| future(request, &params)).call(request).await,
+                        None => next.call(request).await,
+                    }
+                }
+                Dispatch::Answer(response) => response,
+                Dispatch::Fallback(fallback) => Arc::clone(fallback).call(request).await,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(method: &str, target: &str) -> Request {
+        let text = format!("{method} {target} HTTP/1.0\r\n\r\n");
+        Request::read(&mut text.as_bytes()).unwrap().unwrap()
+    }
+
+    fn route(method: Method, pattern: &'static str) -> Route {
+        Route {
+            method,
+            pattern,
+            handler: |request, params| Response::text(200, format!("{} {params:?}", request.method)),
+            future: None,
+        }
+    }
+
+    fn body(response: &Response) -> &str {
+        std::str::from_utf8(&response.body).unwrap()
+    }
+
+    #[test]
+    fn matching() {
+        let users = route(Method::Get, "/users/:id/posts/:post");
+        assert_eq!(users.matches("/users/7/posts/first"), Some(vec!["7", "first"]));
+        assert_eq!(users.matches("/users/7/posts"), None);
+        assert_eq!(users.matches("/users//posts/first"), None);
+        assert_eq!(users.matches("/users/7/posts/first/"), None);
+        assert_eq!(route(Method::Get, "/").matches("/"), Some(vec![]));
+        assert_eq!(route(Method::Get, "/").matches("/a"), None);
+        assert!(same_key("GET /users/:", "GET /users/:") && !same_key("GET /users/:", "PUT /users/:"));
+    }
+
+    #[test]
+    fn dispatching() {
+        let router = Router::new(vec![route(Method::Get, "/a/:x"), route(Method::Delete, "/a/:x")]);
+        assert_eq!(body(&router.handle(&request("GET", "/a/1"))), "GET [\"1\"]");
+        assert_eq!(body(&router.handle(&request("HEAD", "/a/1"))), "HEAD [\"1\"]");
+        assert_eq!(body(&router.handle(&request("DELETE", "/a/2"))), "DELETE [\"2\"]");
+        let response = router.handle(&request("PUT", "/a/1"));
+        assert_eq!(response.status, 405);
+        assert_eq!(response.headers.get("allow"), Some("GET, DELETE, HEAD"));
+        assert_eq!(router.handle(&request("GET", "/b")).status, 404);
+
+        let router = router.fallback(|_: &Request| Response::text(200, "fallback"));
+        assert_eq!(body(&router.handle(&request("GET", "/b"))), "fallback");
+
+        // Middleware goes around its route only.
+        let shout = |request: &Request, next: Next<'_>| next.run(request).with_body("shouting");
+        let router = router.wrap(&route(Method::Delete, "/a/:x"), shout);
+        assert_eq!(body(&router.handle(&request("DELETE", "/a/2"))), "shouting");
+        assert_eq!(body(&router.handle(&request("GET", "/a/2"))), "GET [\"2\"]");
+    }
+}
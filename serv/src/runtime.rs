@@ -0,0 +1,759 @@
+//! A small runtime for futures, which the event loop backend of [`Server`]
+//! runs on: an executor that polls them on one thread, a reactor that wakes
+//! them as their sockets get ready, with epoll(7), and timers.
+//!
+//! [`Runtime::block_on`] runs a future, and the tasks it [`spawn`]s, until
+//! that future is done. Futures wait on sockets with [`TcpListener`] and
+//! [`TcpStream`], and on time with [`sleep`] and [`timeout`]:
+//!
+//! ```no_run
+//! use serv::runtime::{self, Runtime, TcpListener};
+//!
+//! Runtime::new().unwrap().block_on(async {
+//!     let listener = TcpListener::bind("127.0.0.1:7878").unwrap();
+//!     loop {
+//!         let (stream, _) = listener.accept().await.unwrap();
+//!         runtime::spawn(async move {
+//!             let mut buf = [0; 1024];
+//!             while let Ok(n @ 1..) = stream.read(&mut buf).await {
+//!                 if stream.write_all(&buf[..n]).await.is_err() {
+//!                     break;
+//!                 }
+//!             }
+//!         });
+//!     }
+//! });
+//! ```
+//!
+//! A handler whose response is a future of this kind is an [`Async`]. The
+//! runtime is for Linux only, and its system calls are declared by hand, as
+//! serv has no dependencies to get them from.
+//!
+//! [`Server`]: crate::Server
+
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::ffi::c_int;
+use std::fmt;
+use std::fs::File;
+use std::future::{poll_fn, Future};
+use std::io::{self, Read, Write};
+use std::net::{self, Shutdown, SocketAddr, ToSocketAddrs};
+use std::os::fd::{AsRawFd, FromRawFd, RawFd};
+use std::pin::{pin, Pin};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Wake, Waker};
+use std::time::{Duration, Instant};
+
+use crate::middleware::{Middleware, Next, NextFuture};
+use crate::{Handler, Request, Response, ResponseFuture};
+
+const EPOLL_CLOEXEC: c_int = 0o2000000;
+const EPOLL_CTL_ADD: c_int = 1;
+const EPOLL_CTL_DEL: c_int = 2;
+const EPOLLIN: u32 = 0x001;
+const EPOLLOUT: u32 = 0x004;
+const EPOLLERR: u32 = 0x008;
+const EPOLLHUP: u32 = 0x010;
+const EPOLLRDHUP: u32 = 0x2000;
+const EPOLLET: u32 = 1 << 31;
+const EFD_CLOEXEC: c_int = 0o2000000;
+const EFD_NONBLOCK: c_int = 0o4000;
+
+/// `struct epoll_event`, which the kernel packs on x86-64 alone.
+#[derive(Clone, Copy)]
+#[cfg_attr(target_arch = "x86_64", repr(C, packed))]
+#[cfg_attr(not(target_arch = "x86_64"), repr(C))]
+struct EpollEvent {
+    events: u32,
+    data: u64,
+}
+
+extern "C" {
+    fn epoll_create1(flags: c_int) -> c_int;
+    fn epoll_ctl(epfd: c_int, op: c_int, fd: c_int, event: *mut EpollEvent) -> c_int;
+    fn epoll_wait(epfd: c_int, events: *mut EpollEvent, maxevents: c_int, timeout: c_int) -> c_int;
+    fn eventfd(initval: u32, flags: c_int) -> c_int;
+}
+
+/// The task id of the future `block_on` runs.
+const MAIN: usize = usize::MAX;
+
+/// The token of the eventfd that wakes the reactor.
+const NOTIFY: u64 = 0;
+
+thread_local! {
+    /// The runtime whose `block_on` runs on this thread.
+    static CURRENT: RefCell<Option<Rc<Core>>> = const { RefCell::new(None) };
+}
+
+/// The runtime running on this thread.
+fn current() -> Rc<Core> {
+    CURRENT
+        .with(|current| current.borrow().clone())
+        .expect("the serv runtime is used outside of Runtime::block_on")
+}
+
+/// Runs futures on the thread that calls [`Runtime::block_on`].
+pub struct Runtime {
+    core: Rc<Core>,
+}
+
+type Task = Pin<Box<dyn Future<Output = ()>>>;
+
+struct Core {
+    epoll: File,
+    queue: Arc<Queue>,
+    tasks: RefCell<HashMap<usize, (Task, Arc<TaskWaker>)>>,
+    next_task: Cell<usize>,
+    sources: RefCell<HashMap<u64, Waiting>>,
+    next_source: Cell<u64>,
+    /// The timers that have not fired, soonest first, which a [`Sleep`]
+    /// takes out as it is dropped.
+    timers: RefCell<BTreeSet<(Instant, u64)>>,
+    /// The tasks waiting on each timer that has not fired.
+    sleepers: RefCell<HashMap<u64, Waker>>,
+    next_timer: Cell<u64>,
+}
+
+/// The tasks waiting on a socket.
+#[derive(Default)]
+struct Waiting {
+    reader: Option<Waker>,
+    writer: Option<Waker>,
+}
+
+/// The tasks woken and not polled yet, which wakers add to from any thread.
+struct Queue {
+    ready: Mutex<VecDeque<usize>>,
+    /// An eventfd, written to wake the reactor.
+    notify: File,
+    /// Whether the reactor waits for events, and must be woken.
+    parked: AtomicBool,
+}
+
+impl Queue {
+    fn ready(&self) -> MutexGuard<'_, VecDeque<usize>> {
+        self.ready.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn push(&self, id: usize) {
+        self.ready().push_back(id);
+        if self.parked.load(Ordering::SeqCst) {
+            let _ = (&self.notify).write(&1u64.to_ne_bytes());
+        }
+    }
+}
+
+/// Wakes a task, once until it is polled again.
+struct TaskWaker {
+    id: usize,
+    queue: Arc<Queue>,
+    queued: AtomicBool,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        if !self.queued.swap(true, Ordering::SeqCst) {
+            self.queue.push(self.id);
+        }
+    }
+}
+
+impl Runtime {
+    pub fn new() -> io::Result<Runtime> {
+        // SAFETY: plain system calls; each descriptor returned is owned by
+        // the `File` made from it alone.
+        let epoll = unsafe { epoll_create1(EPOLL_CLOEXEC) };
+        if epoll < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let epoll = unsafe { File::from_raw_fd(epoll) };
+        let notify = unsafe { eventfd(0, EFD_CLOEXEC | EFD_NONBLOCK) };
+        if notify < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let notify = unsafe { File::from_raw_fd(notify) };
+        let mut event = EpollEvent {
+            events: EPOLLIN | EPOLLET,
+            data: NOTIFY,
+        };
+        // SAFETY: `event` outlives the call, which copies it.
+        if unsafe { epoll_ctl(epoll.as_raw_fd(), EPOLL_CTL_ADD, notify.as_raw_fd(), &mut event) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let queue = Arc::new(Queue {
+            ready: Mutex::default(),
+            notify,
+            parked: AtomicBool::new(false),
+        });
+        Ok(Runtime {
+            core: Rc::new(Core {
+                epoll,
+                queue,
+                tasks: RefCell::default(),
+                next_task: Cell::new(0),
+                sources: RefCell::default(),
+                next_source: Cell::new(NOTIFY + 1),
+                timers: RefCell::default(),
+                sleepers: RefCell::default(),
+                next_timer: Cell::new(0),
+            }),
+        })
+    }
+
+    /// Runs `future` until it is done, and the tasks it spawns meanwhile.
+    /// Those still running when it is done are dropped with the runtime, or
+    /// carry on at the next `block_on`. A runtime may run inside another's
+    /// `block_on`, which waits until it returns.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        let previous = CURRENT.with(|current| current.replace(Some(Rc::clone(&self.core))));
+        let _restore = Restore(previous);
+        let mut future = pin!(future);
+        let main = self.core.waker(MAIN);
+        main.wake_by_ref();
+        loop {
+            let ready: Vec<usize> = self.core.queue.ready().drain(..).collect();
+            for id in ready {
+                if id != MAIN {
+                    self.core.poll_task(id);
+                    continue;
+                }
+                main.queued.store(false, Ordering::SeqCst);
+                let waker = Waker::from(Arc::clone(&main));
+                if let Poll::Ready(output) = future.as_mut().poll(&mut Context::from_waker(&waker)) {
+                    return output;
+                }
+            }
+            self.core.turn();
+        }
+    }
+}
+
+/// Drops the tasks left, and with them what they hold of the runtime.
+impl Drop for Runtime {
+    fn drop(&mut self) {
+        let tasks = std::mem::take(&mut *self.core.tasks.borrow_mut());
+        drop(tasks);
+    }
+}
+
+impl fmt::Debug for Runtime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Runtime").field("tasks", &self.core.tasks.borrow().len()).finish_non_exhaustive()
+    }
+}
+
+/// Puts back the runtime a `block_on` replaced, however it returns.
+struct Restore(Option<Rc<Core>>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+    }
+}
+
+impl Core {
+    fn waker(&self, id: usize) -> Arc<TaskWaker> {
+        Arc::new(TaskWaker {
+            id,
+            queue: Arc::clone(&self.queue),
+            queued: AtomicBool::new(false),
+        })
+    }
+
+    fn poll_task(&self, id: usize) {
+        // Taken out while it runs, so that it can spawn others.
+        let Some((mut task, task_waker)) = self.tasks.borrow_mut().remove(&id) else {
+            return;
+        };
+        task_waker.queued.store(false, Ordering::SeqCst);
+        let waker = Waker::from(Arc::clone(&task_waker));
+        if task.as_mut().poll(&mut Context::from_waker(&waker)).is_pending() {
+            self.tasks.borrow_mut().insert(id, (task, task_waker));
+        }
+    }
+
+    /// Waits for sockets to get ready or timers to fire, unless a task is
+    /// ready already, and wakes the tasks waiting on them.
+    fn turn(&self) {
+        let mut events = [EpollEvent { events: 0, data: 0 }; 256];
+        self.queue.parked.store(true, Ordering::SeqCst);
+        let timeout = if !self.queue.ready().is_empty() {
+            0
+        } else {
+            let next = self.timers.borrow().first().map(|&(deadline, _)| deadline);
+            // Rounded up, so as not to wake before the deadline.
+            next.map_or(-1, |deadline| {
+                let left = deadline.saturating_duration_since(Instant::now());
+                left.as_nanos().div_ceil(1_000_000).min(c_int::MAX as u128) as c_int
+            })
+        };
+        // SAFETY: `events` has room for as many events as it is told.
+        let n = unsafe { epoll_wait(self.epoll.as_raw_fd(), events.as_mut_ptr(), events.len() as c_int, timeout) };
+        self.queue.parked.store(false, Ordering::SeqCst);
+        if n < 0 {
+            let err = io::Error::last_os_error();
+            assert_eq!(err.kind(), io::ErrorKind::Interrupted, "epoll_wait failed: {err}");
+        }
+        for event in &events[..n.max(0) as usize] {
+            let (flags, token) = (event.events, event.data);
+            if token == NOTIFY {
+                let _ = (&self.queue.notify).read(&mut [0; 8]);
+                continue;
+            }
+            let mut sources = self.sources.borrow_mut();
+            let Some(Waiting { reader, writer }) = sources.get_mut(&token) else {
+                continue;
+            };
+            let wakers = [
+                reader.take_if(|_| flags & (EPOLLIN | EPOLLRDHUP | EPOLLHUP | EPOLLERR) != 0),
+                writer.take_if(|_| flags & (EPOLLOUT | EPOLLHUP | EPOLLERR) != 0),
+            ];
+            wakers.into_iter().flatten().for_each(Waker::wake);
+        }
+        let now = Instant::now();
+        let mut timers = self.timers.borrow_mut();
+        while let Some(&(deadline, id)) = timers.first() {
+            if deadline > now {
+                break;
+            }
+            timers.pop_first();
+            if let Some(waker) = self.sleepers.borrow_mut().remove(&id) {
+                waker.wake();
+            }
+        }
+    }
+
+    fn register(&self, fd: RawFd) -> io::Result<u64> {
+        let token = self.next_source.get();
+        let mut event = EpollEvent {
+            events: EPOLLIN | EPOLLOUT | EPOLLRDHUP | EPOLLET,
+            data: token,
+        };
+        // SAFETY: `event` outlives the call, which copies it.
+        if unsafe { epoll_ctl(self.epoll.as_raw_fd(), EPOLL_CTL_ADD, fd, &mut event) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        self.next_source.set(token + 1);
+        self.sources.borrow_mut().insert(token, Waiting::default());
+        Ok(token)
+    }
+}
+
+/// Runs `future` as a task of its own, on the runtime of the current
+/// `block_on`, from the next time that runtime looks for work.
+///
+/// # Panics
+///
+/// Outside of [`Runtime::block_on`].
+pub fn spawn(future: impl Future<Output = ()> + 'static) {
+    let core = current();
+    let id = core.next_task.get();
+    core.next_task.set(id + 1);
+    let waker = core.waker(id);
+    core.tasks.borrow_mut().insert(id, (Box::pin(future), Arc::clone(&waker)));
+    waker.wake();
+}
+
+/// A future done once `duration` has passed.
+pub fn sleep(duration: Duration) -> Sleep {
+    Sleep {
+        deadline: Instant::now() + duration,
+        timer: None,
+    }
+}
+
+/// Waits for [`sleep`].
+pub struct Sleep {
+    deadline: Instant,
+    /// The timer, once the sleep has been polled.
+    timer: Option<(Rc<Core>, u64)>,
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let deadline = self.deadline;
+        if Instant::now() >= deadline {
+            return Poll::Ready(());
+        }
+        let (core, id) = self.timer.get_or_insert_with(|| {
+            let core = current();
+            let id = core.next_timer.get();
+            core.next_timer.set(id + 1);
+            core.timers.borrow_mut().insert((deadline, id));
+            (core, id)
+        });
+        core.sleepers.borrow_mut().insert(*id, cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        if let Some((core, id)) = &self.timer {
+            core.timers.borrow_mut().remove(&(self.deadline, *id));
+            core.sleepers.borrow_mut().remove(id);
+        }
+    }
+}
+
+/// Waits for `future` for `duration` at most, and fails with
+/// [`io::ErrorKind::TimedOut`] if it is not done by then.
+pub async fn timeout<F: Future>(duration: Duration, future: F) -> io::Result<F::Output> {
+    let mut future = pin!(future);
+    let mut sleep = sleep(duration);
+    poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+        Pin::new(&mut sleep).poll(cx).map(|()| Err(io::Error::new(io::ErrorKind::TimedOut, "timed out")))
+    })
+    .await
+}
+
+/// A socket the reactor of the current runtime watches, until it is
+/// dropped.
+struct Source {
+    core: Rc<Core>,
+    token: u64,
+    fd: RawFd,
+}
+
+impl Source {
+    fn new(fd: RawFd) -> io::Result<Source> {
+        let core = current();
+        let token = core.register(fd)?;
+        Ok(Source { core, token, fd })
+    }
+
+    /// Tries `op` until it would block, then has the task wait until the
+    /// socket is ready for it again, to read or to write.
+    fn poll<T>(&self, cx: &mut Context<'_>, write: bool, mut op: impl FnMut() -> io::Result<T>) -> Poll<io::Result<T>> {
+        loop {
+            match op() {
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
+                    if let Some(waiting) = self.core.sources.borrow_mut().get_mut(&self.token) {
+                        let waker = if write { &mut waiting.writer } else { &mut waiting.reader };
+                        *waker = Some(cx.waker().clone());
+                    }
+                    return Poll::Pending;
+                }
+                result => return Poll::Ready(result),
+            }
+        }
+    }
+}
+
+impl Drop for Source {
+    fn drop(&mut self) {
+        let mut event = EpollEvent { events: 0, data: 0 };
+        // SAFETY: as in `Core::register`. The descriptor is still open, as
+        // what owns it drops its `Source` first.
+        unsafe { epoll_ctl(self.core.epoll.as_raw_fd(), EPOLL_CTL_DEL, self.fd, &mut event) };
+        self.core.sources.borrow_mut().remove(&self.token);
+    }
+}
+
+/// A [`net::TcpListener`] whose connections are waited for by the runtime.
+pub struct TcpListener {
+    // Dropped first, while the listener is open.
+    source: Source,
+    inner: net::TcpListener,
+}
+
+impl TcpListener {
+    pub fn bind(addr: impl ToSocketAddrs) -> io::Result<TcpListener> {
+        TcpListener::from_std(net::TcpListener::bind(addr)?)
+    }
+
+    /// Makes `listener` nonblocking, and registers it with the runtime of
+    /// the current `block_on`.
+    pub fn from_std(listener: net::TcpListener) -> io::Result<TcpListener> {
+        listener.set_nonblocking(true)?;
+        Ok(TcpListener {
+            source: Source::new(listener.as_raw_fd())?,
+            inner: listener,
+        })
+    }
+
+    pub async fn accept(&self) -> io::Result<(TcpStream, SocketAddr)> {
+        poll_fn(|cx| self.poll_accept(cx)).await
+    }
+
+    /// Accepts a connection, or has the task of `cx` woken when there is one
+    /// to accept.
+    pub fn poll_accept(&self, cx: &mut Context<'_>) -> Poll<io::Result<(TcpStream, SocketAddr)>> {
+        self.source.poll(cx, false, || {
+            let (stream, addr) = self.inner.accept()?;
+            Ok((TcpStream::from_std(stream)?, addr))
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.inner.local_addr()
+    }
+}
+
+/// A [`net::TcpStream`] whose reads and writes wait with the runtime.
+pub struct TcpStream {
+    source: Source,
+    /// Shared with the server's registry of open connections, which shuts
+    /// them down as it stops.
+    inner: Arc<net::TcpStream>,
+}
+
+impl TcpStream {
+    /// Makes `stream` nonblocking, and registers it with the runtime of the
+    /// current `block_on`.
+    pub fn from_std(stream: net::TcpStream) -> io::Result<TcpStream> {
+        stream.set_nonblocking(true)?;
+        Ok(TcpStream {
+            source: Source::new(stream.as_raw_fd())?,
+            inner: Arc::new(stream),
+        })
+    }
+
+    /// The stream as it was, blocking, and no longer watched by the runtime.
+    /// Fails if it is still shared.
+    pub fn into_std(self) -> io::Result<net::TcpStream> {
+        let TcpStream { source, inner } = self;
+        let inner = Arc::try_unwrap(inner).map_err(|_| io::Error::other("the stream is still shared"))?;
+        drop(source);
+        inner.set_nonblocking(false)?;
+        Ok(inner)
+    }
+
+    /// Reads what has arrived, up to `buf.len()` bytes, once something has.
+    /// Returns 0 once the other end has closed the connection.
+    pub async fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
+        poll_fn(|cx| self.source.poll(cx, false, || (&*self.inner).read(buf))).await
+    }
+
+    pub async fn write_all(&self, mut buf: &[u8]) -> io::Result<()> {
+        while !buf.is_empty() {
+            match poll_fn(|cx| self.source.poll(cx, true, || (&*self.inner).write(buf))).await? {
+                0 => return Err(io::ErrorKind::WriteZero.into()),
+                n => buf = &buf[n..],
+            }
+        }
+        Ok(())
+    }
+
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        self.inner.peer_addr()
+    }
+
+    pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
+        self.inner.shutdown(how)
+    }
+
+    pub fn get_ref(&self) -> &net::TcpStream {
+        &self.inner
+    }
+
+    pub(crate) fn shared(&self) -> &Arc<net::TcpStream> {
+        &self.inner
+    }
+}
+
+/// A handler that answers with a future, made with [`handler`]. On the event
+/// loop backend, waiting in it holds up no other connection; on the thread
+/// pool, the worker waits in a runtime of its own.
+pub struct Async<F>(F);
+
+/// A handler that answers with the future `f` gives:
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use serv::runtime;
+/// use serv::{Backend, Request, Response, Server};
+///
+/// let slow = runtime::handler(|request: Request| async move {
+///     runtime::sleep(Duration::from_secs(1)).await;
+///     Response::text(200, format!("{} took a second\n", request.path))
+/// });
+/// Server::bind("127.0.0.1:7878").unwrap().backend(Backend::EventLoop).serve(slow).unwrap();
+/// ```
+pub fn handler<F, R>(f: F) -> Async<F>
+where
+    F: Fn(Request) -> R + Send + Sync + 'static,
+    R: Future<Output = Response> + 'static,
+{
+    Async(f)
+}
+
+impl<F, R> Handler for Async<F>
+where
+    F: Fn(Request) -> R + Send + Sync + 'static,
+    R: Future<Output = Response> + 'static,
+{
+    fn handle(&self, request: &Request) -> Response {
+        wait((self.0)(request.clone()))
+    }
+
+    fn call(self: Arc<Self>, request: Request) -> ResponseFuture {
+        Box::pin((self.0)(request))
+    }
+}
+
+/// Middleware that answers with a future, made with [`middleware`].
+pub struct AsyncMiddleware<F>(F);
+
+/// Middleware that answers with the future `f` gives. Where [`Next::run`]
+/// would block, [`Next::call`] waits for the handler, so that on the event
+/// loop backend a handler made with [`handler`] holds up no other
+/// connection:
+///
+/// ```no_run
+/// use std::time::{Duration, Instant};
+///
+/// use serv::runtime;
+/// use serv::{Backend, Handler, Next, Request, Response, Server};
+///
+/// let slow = runtime::handler(|_: Request| async {
+///     runtime::sleep(Duration::from_secs(1)).await;
+///     Response::text(200, "done\n")
+/// });
+/// let timed = slow.with(runtime::middleware(async |request: Request, next: Next<'_>| {
+///     let started = Instant::now();
+///     let response = next.call(request).await;
+///     response.with_header("Server-Timing", format!("total;dur={}", started.elapsed().as_millis()))
+/// }));
+/// Server::bind("127.0.0.1:7878").unwrap().backend(Backend::EventLoop).serve(timed).unwrap();
+/// ```
+pub fn middleware<F>(f: F) -> AsyncMiddleware<F>
+where
+    F: for<'a> AsyncFn(Request, Next<'a>) -> Response + Send + Sync + 'static,
+{
+    AsyncMiddleware(f)
+}
+
+impl<F> Middleware for AsyncMiddleware<F>
+where
+    F: for<'a> AsyncFn(Request, Next<'a>) -> Response + Send + Sync + 'static,
+{
+    fn handle(&self, request: &Request, next: Next<'_>) -> Response {
+        wait((self.0)(request.clone(), next))
+    }
+
+    fn call<'a>(&'a self, request: Request, next: Next<'a>) -> NextFuture<'a> {
+        Box::pin((self.0)(request, next))
+    }
+}
+
+/// Waits for `response` in a runtime of its own, as handlers that answer
+/// with futures do on the thread pool; `#[route]` functions that are `async`
+/// are answered with it there.
+#[doc(hidden)]
+pub fn wait(response: impl Future<Output = Response>) -> Response {
+    match Runtime::new() {
+        Ok(runtime) => runtime.block_on(response),
+        Err(_) => Response::text(503, "503 Service Unavailable\n"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::thread;
+
+    #[test]
+    fn tasks_and_timers() {
+        let runtime = Runtime::new().unwrap();
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let start = Instant::now();
+        let done = runtime.block_on({
+            let log = Rc::clone(&log);
+            async move {
+                for (name, millis) in [("slow", 60), ("fast", 20), ("now", 0)] {
+                    let log = Rc::clone(&log);
+                    spawn(async move {
+                        sleep(Duration::from_millis(millis)).await;
+                        log.borrow_mut().push(name);
+                    });
+                }
+                sleep(Duration::from_millis(40)).await;
+                log.borrow_mut().push("main");
+                let timed_out = timeout(Duration::from_millis(40), sleep(Duration::from_secs(60))).await.is_err();
+
+                // Timers given up on are gone at once, rather than at their deadlines.
+                for _ in 0..20 {
+                    timeout(Duration::from_secs(10), sleep(Duration::from_millis(1))).await.unwrap();
+                }
+                timed_out && current().timers.borrow().is_empty()
+            }
+        });
+        assert!(done);
+        assert_eq!(*log.borrow(), ["now", "fast", "main", "slow"]);
+        assert!(start.elapsed() >= Duration::from_millis(80));
+
+        // Woken from another thread.
+        let (sender, receiver) = std::sync::mpsc::channel::<Waker>();
+        let waker = thread::spawn(move || {
+            let waker = receiver.recv().unwrap();
+            thread::sleep(Duration::from_millis(20));
+            waker.wake();
+        });
+        let mut waited = false;
+        runtime.block_on(poll_fn(|cx| match waited {
+            true => Poll::Ready(()),
+            false => {
+                waited = true;
+                sender.send(cx.waker().clone()).unwrap();
+                Poll::Pending
+            }
+        }));
+        waker.join().unwrap();
+    }
+
+    #[test]
+    fn sockets() {
+        let runtime = Runtime::new().unwrap();
+        let echoed = runtime.block_on(async {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let addr = listener.local_addr().unwrap();
+            spawn(async move {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut buf = [0; 4096];
+                while let Ok(n @ 1..) = stream.read(&mut buf).await {
+                    stream.write_all(&buf[..n]).await.unwrap();
+                }
+            });
+            let client = TcpStream::from_std(net::TcpStream::connect(addr).unwrap()).unwrap();
+            // More than the socket buffers hold, so that writes wait.
+            let sent: Vec<u8> = (0..4_000_000u32).map(|i| (i % 251) as u8).collect();
+            let writer = {
+                let sent = sent.clone();
+                let client = net::TcpStream::try_clone(client.get_ref()).unwrap();
+                let client = TcpStream::from_std(client).unwrap();
+                async move {
+                    client.write_all(&sent).await.unwrap();
+                    client.shutdown(Shutdown::Write).unwrap();
+                }
+            };
+            spawn(writer);
+            let mut echoed = Vec::new();
+            let mut buf = [0; 65536];
+            loop {
+                match timeout(Duration::from_secs(10), client.read(&mut buf)).await.unwrap().unwrap() {
+                    0 => break,
+                    n => echoed.extend_from_slice(&buf[..n]),
+                }
+            }
+            echoed == sent
+        });
+        assert!(echoed);
+    }
+}
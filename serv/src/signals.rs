@@ -0,0 +1,136 @@
+//! Catching the signals that ask a server to stop or to reload.
+//!
+//! A signal handler may do next to nothing safely, so the one installed here
+//! only writes the number of the signal to a pipe. A thread reads the pipe
+//! and sends each signal on a channel, where ordinary code can take its
+//! time over it:
+//!
+//! ```no_run
+//! use serv::signals::{self, Signal};
+//! use serv::{Server, StaticFiles};
+//!
+//! let server = Server::bind("127.0.0.1:7878").unwrap();
+//! let control = server.control();
+//! let caught = signals::catch(&[Signal::Interrupt, Signal::Terminate]).unwrap();
+//! std::thread::spawn(move || {
+//!     if caught.recv().is_ok() {
+//!         control.shutdown();
+//!     }
+//! });
+//! server.serve(StaticFiles::new("www")).unwrap();
+//! ```
+//!
+//! Unlike the [`runtime`](crate::runtime), this works beyond Linux: the
+//! few constants that differ are given for the BSDs and macOS too.
+
+use std::ffi::{c_int, c_void};
+use std::fs::File;
+use std::io::{self, Read};
+use std::os::fd::FromRawFd;
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+const F_SETFD: c_int = 2;
+const F_SETFL: c_int = 4;
+const FD_CLOEXEC: c_int = 1;
+#[cfg(target_os = "linux")]
+const O_NONBLOCK: c_int = 0o4000;
+#[cfg(not(target_os = "linux"))]
+const O_NONBLOCK: c_int = 4;
+const SIG_ERR: usize = usize::MAX;
+
+extern "C" {
+    fn pipe(fds: *mut c_int) -> c_int;
+    fn fcntl(fd: c_int, command: c_int, arg: c_int) -> c_int;
+    fn write(fd: c_int, buf: *const c_void, count: usize) -> isize;
+    fn signal(signum: c_int, handler: extern "C" fn(c_int)) -> usize;
+}
+
+/// The signals a server is told things with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+    /// `SIGHUP`, by convention a request to read the configuration again.
+    Hangup,
+    /// `SIGINT`, sent by Ctrl-C.
+    Interrupt,
+    /// `SIGTERM`, sent by `kill` and by service managers to stop a process.
+    Terminate,
+}
+
+impl Signal {
+    fn number(self) -> c_int {
+        match self {
+            Signal::Hangup => 1,
+            Signal::Interrupt => 2,
+            Signal::Terminate => 15,
+        }
+    }
+
+    fn from_number(number: u8) -> Option<Signal> {
+        [Signal::Hangup, Signal::Interrupt, Signal::Terminate]
+            .into_iter()
+            .find(|signal| signal.number() == c_int::from(number))
+    }
+}
+
+/// Where the handler writes the signals it catches, once `catch` has made
+/// the pipe.
+static PIPE: AtomicI32 = AtomicI32::new(-1);
+
+extern "C" fn on_signal(number: c_int) {
+    let byte = number as u8;
+    // SAFETY: write(2) is async-signal-safe, and `byte` outlives the call.
+    // The pipe does not block: if it is full, signals enough are waiting.
+    unsafe { write(PIPE.load(Ordering::Relaxed), (&byte as *const u8).cast(), 1) };
+}
+
+/// Catches `signals` from now on, instead of letting them stop the process,
+/// and sends each one caught to the receiver returned. Signals can be caught
+/// this way once per process.
+pub fn catch(signals: &[Signal]) -> io::Result<mpsc::Receiver<Signal>> {
+    if PIPE.load(Ordering::SeqCst) != -1 {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, "signals are caught already"));
+    }
+    let mut fds = [0; 2];
+    // SAFETY: `fds` has room for the two descriptors pipe(2) writes.
+    if unsafe { pipe(fds.as_mut_ptr()) } < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // SAFETY: the read end is owned by this `File` alone from here on; the
+    // write end stays open for the handler for as long as the process runs.
+    let mut reader = unsafe { File::from_raw_fd(fds[0]) };
+    for (fd, command, arg) in [(fds[0], F_SETFD, FD_CLOEXEC), (fds[1], F_SETFD, FD_CLOEXEC), (fds[1], F_SETFL, O_NONBLOCK)] {
+        // SAFETY: plain system calls on descriptors that are open.
+        if unsafe { fcntl(fd, command, arg) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    if PIPE.compare_exchange(-1, fds[1], Ordering::SeqCst, Ordering::SeqCst).is_err() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, "signals are caught already"));
+    }
+    for caught in signals {
+        // SAFETY: `on_signal` does only what a signal handler may.
+        if unsafe { signal(caught.number(), on_signal) } == SIG_ERR {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    let (sender, receiver) = mpsc::channel();
+    thread::Builder::new().name("serv-signals".to_string()).spawn(move || {
+        let mut byte = [0];
+        loop {
+            match reader.read(&mut byte) {
+                Ok(1) => {}
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                _ => return,
+            }
+            let Some(signal) = Signal::from_number(byte[0]) else {
+                continue;
+            };
+            if sender.send(signal).is_err() {
+                return;
+            }
+        }
+    })?;
+    Ok(receiver)
+}
@@ -0,0 +1,582 @@
+//! WebSockets (RFC 6455): messages both ways over a connection that started
+//! as an HTTP request.
+//!
+//! A handler answers the request with [`upgrade`], which checks the
+//! handshake and, once the `101 Switching Protocols` is sent, hands the
+//! connection to a closure as a [`WebSocket`]. The closure runs on the
+//! worker that served the request, which it keeps until it returns:
+//!
+//! ```no_run
+//! use serv::websocket::{self, Message};
+//! use serv::{Request, Response, Server};
+//!
+//! let echo = |request: &Request| match request.path.as_str() {
+//!     "/echo" => websocket::upgrade(request, |mut socket| {
+//!         while let Ok(message) = socket.recv() {
+//!             match message {
+//!                 Message::Text(_) | Message::Binary(_) => socket.send(message).unwrap(),
+//!                 Message::Close(..) => break,
+//!                 Message::Ping(_) | Message::Pong(_) => {}
+//!             }
+//!         }
+//!     }),
+//!     _ => Response::not_found(),
+//! };
+//! Server::bind("127.0.0.1:7878").unwrap().serve(echo).unwrap();
+//! ```
+//!
+//! Pings are answered as they arrive, and a close from the other end is
+//! answered with the same code. [`WebSocket::sender`] gives a [`Sender`]
+//! that other threads can send on while the socket waits for messages, as
+//! the `chat` example does.
+
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::SystemTime;
+
+use crate::http::{Method, Request, Response, Upgraded, Version};
+use crate::{base64, sha1};
+
+/// What the handshake appends to the client's key before hashing it.
+const GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// The longest message a [`WebSocket`] takes unless told otherwise.
+pub const DEFAULT_MAX_MESSAGE: usize = 16 * 1024 * 1024;
+
+const CONTINUATION: u8 = 0x0;
+const TEXT: u8 = 0x1;
+const BINARY: u8 = 0x2;
+const CLOSE: u8 = 0x8;
+const PING: u8 = 0x9;
+const PONG: u8 = 0xa;
+
+/// The close codes of RFC 6455 that come up here.
+pub mod close {
+    pub const NORMAL: u16 = 1000;
+    pub const GOING_AWAY: u16 = 1001;
+    pub const PROTOCOL_ERROR: u16 = 1002;
+    pub const UNSUPPORTED_DATA: u16 = 1003;
+    /// Stands for no code, in a close that had none. It is never sent.
+    pub const NO_STATUS: u16 = 1005;
+    pub const INVALID_DATA: u16 = 1007;
+    pub const POLICY_VIOLATION: u16 = 1008;
+    pub const TOO_BIG: u16 = 1009;
+    pub const INTERNAL_ERROR: u16 = 1011;
+}
+
+/// A message, or a control frame, sent or received.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    Text(String),
+    Binary(Vec<u8>),
+    Ping(Vec<u8>),
+    Pong(Vec<u8>),
+    /// The end of the conversation, with a code from [`close`] and a
+    /// reason.
+    Close(u16, String),
+}
+
+/// Answers `request` with the handshake of a WebSocket, and once it is
+/// sent, hands the connection to `on_open`. A request that is not a
+/// WebSocket handshake is answered with `400 Bad Request`, or `426 Upgrade
+/// Required` for a version other than 13.
+pub fn upgrade(request: &Request, on_open: impl FnOnce(WebSocket) + Send + 'static) -> Response {
+    let has_token = |name: &str, token: &str| {
+        request
+            .headers
+            .get_all(name)
+            .flat_map(|value| value.split(','))
+            .any(|value| value.trim().eq_ignore_ascii_case(token))
+    };
+    if request.method != Method::Get || request.version != Version::Http11 {
+        return Response::text(400, "400 Bad Request: a WebSocket handshake is an HTTP/1.1 GET\n");
+    }
+    if !has_token("upgrade", "websocket") || !has_token("connection", "upgrade") {
+        return Response::text(400, "400 Bad Request: not a WebSocket handshake\n");
+    }
+    if request.headers.get("sec-websocket-version") != Some("13") {
+        return Response::text(426, "426 Upgrade Required: WebSocket version 13\n")
+            .with_header("Sec-WebSocket-Version", "13");
+    }
+    let key = request.headers.get("sec-websocket-key").unwrap_or("").trim();
+    if base64::decode(key).is_none_or(|nonce| nonce.len() != 16) {
+        return Response::text(400, "400 Bad Request: invalid Sec-WebSocket-Key\n");
+    }
+    Response::upgrade("websocket", move |upgraded| {
+        if let Ok(socket) = WebSocket::new(upgraded, false) {
+            on_open(socket);
+        }
+    })
+    .with_header("Sec-WebSocket-Accept", accept(key))
+}
+
+/// What the server answers a `Sec-WebSocket-Key` with.
+fn accept(key: &str) -> String {
+    base64::encode(&sha1::sha1(format!("{key}{GUID}").as_bytes()))
+}
+
+/// Opens a WebSocket to the server at `addr`, at `path`. Clients mask what
+/// they send; the keys here are not random enough to keep a browser's
+/// caches safe, which is no concern outside one.
+pub fn connect(addr: impl ToSocketAddrs, path: &str) -> io::Result<WebSocket> {
+    let stream = TcpStream::connect(addr)?;
+    let host = stream.peer_addr()?;
+    let nonce: Vec<u8> = (0..2).flat_map(|_| next_random().to_be_bytes()).collect();
+    let key = base64::encode(&nonce);
+    write!(
+        &stream,
+        "GET {path} HTTP/1.1\r\nHost: {host}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+         Sec-WebSocket-Key: {key}\r\nSec-WebSocket-Version: 13\r\n\r\n"
+    )?;
+    let mut socket = WebSocket::new(Upgraded::new(stream, Vec::new()), true)?;
+    let mut status = String::new();
+    socket.reader.read_line(&mut status)?;
+    let mut accepted = false;
+    loop {
+        let mut line = String::new();
+        if socket.reader.read_line(&mut line)? == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            accepted |= name.eq_ignore_ascii_case("sec-websocket-accept") && value.trim() == accept(&key);
+        }
+    }
+    if !status.starts_with("HTTP/1.1 101 ") || !accepted {
+        let message = format!("the server refused the WebSocket: {}", status.trim_end());
+        return Err(io::Error::new(io::ErrorKind::ConnectionRefused, message));
+    }
+    Ok(socket)
+}
+
+/// A number for masking keys and nonces: a xorshift of the time and a
+/// count.
+fn next_random() -> u64 {
+    static COUNT: AtomicU64 = AtomicU64::new(0);
+    let nanos = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).map_or(0, |d| d.as_nanos() as u64);
+    let mut x = nanos ^ COUNT.fetch_add(1, Ordering::Relaxed).wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1;
+    for _ in 0..4 {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+    }
+    x
+}
+
+/// One end of a WebSocket.
+pub struct WebSocket {
+    reader: BufReader<Upgraded>,
+    sender: Sender,
+    max_message: usize,
+    /// The opcode and payload of a message whose frames are still arriving.
+    partial: Option<(u8, Vec<u8>)>,
+    /// Whether the other end has sent its close.
+    closed: bool,
+}
+
+impl WebSocket {
+    /// The client end masks what it sends, and expects what it receives not
+    /// to be masked; the server end, the other way around.
+    fn new(upgraded: Upgraded, client: bool) -> io::Result<WebSocket> {
+        let sender = Sender {
+            shared: Arc::new(Shared {
+                stream: Mutex::new(upgraded.stream().try_clone()?),
+                closing: AtomicBool::new(false),
+            }),
+            client,
+        };
+        Ok(WebSocket {
+            reader: BufReader::new(upgraded),
+            sender,
+            max_message: DEFAULT_MAX_MESSAGE,
+            partial: None,
+            closed: false,
+        })
+    }
+
+    /// Refuses messages longer than `bytes`, closing with
+    /// [`close::TOO_BIG`].
+    pub fn max_message(mut self, bytes: usize) -> WebSocket {
+        self.max_message = bytes;
+        self
+    }
+
+    /// Waits for the next message. A ping is answered before it is
+    /// returned; a close, before it is returned as [`Message::Close`], after
+    /// which there are no more messages and `recv` fails. What breaks the
+    /// protocol closes the WebSocket with the code it calls for and fails
+    /// with [`io::ErrorKind::InvalidData`].
+    pub fn recv(&mut self) -> io::Result<Message> {
+        match self.next_message() {
+            Err(Fail::Protocol(code, why)) => {
+                self.closed = true;
+                let _ = self.sender.close(code, why);
+                self.sender.shutdown();
+                Err(io::Error::new(io::ErrorKind::InvalidData, why))
+            }
+            Err(Fail::Io(err)) => Err(err),
+            Ok(message) => Ok(message),
+        }
+    }
+
+    fn next_message(&mut self) -> Result<Message, Fail> {
+        loop {
+            if self.closed {
+                return Err(Fail::Io(io::Error::new(io::ErrorKind::NotConnected, "the WebSocket is closed")));
+            }
+            let frame = read_frame(&mut self.reader, !self.sender.client, self.max_message)?;
+            match frame.opcode {
+                PING => {
+                    // Once closing, the other end expects nothing but the close.
+                    if !self.sender.shared.closing.load(Ordering::SeqCst) {
+                        self.sender.send_frame(PONG, &frame.payload)?;
+                    }
+                    return Ok(Message::Ping(frame.payload));
+                }
+                PONG => return Ok(Message::Pong(frame.payload)),
+                CLOSE => {
+                    let (code, reason) = parse_close(&frame.payload)?;
+                    self.closed = true;
+                    if !self.sender.shared.closing.load(Ordering::SeqCst) {
+                        let code = if code == close::NO_STATUS { None } else { Some(code) };
+                        let _ = self.sender.close_frame(code, "");
+                    }
+                    self.sender.shutdown();
+                    return Ok(Message::Close(code, reason));
+                }
+                TEXT | BINARY if self.partial.is_some() => {
+                    return Err(Fail::Protocol(close::PROTOCOL_ERROR, "a message started before the last one ended"))
+                }
+                TEXT | BINARY if frame.fin => return message(frame.opcode, frame.payload),
+                TEXT | BINARY => self.partial = Some((frame.opcode, frame.payload)),
+                CONTINUATION => {
+                    let Some((opcode, mut payload)) = self.partial.take() else {
+                        return Err(Fail::Protocol(close::PROTOCOL_ERROR, "a continuation of no message"));
+                    };
+                    if payload.len() + frame.payload.len() > self.max_message {
+                        return Err(Fail::Protocol(close::TOO_BIG, "the message is too long"));
+                    }
+                    payload.extend(frame.payload);
+                    if frame.fin {
+                        return message(opcode, payload);
+                    }
+                    self.partial = Some((opcode, payload));
+                }
+                _ => return Err(Fail::Protocol(close::PROTOCOL_ERROR, "an unknown opcode")),
+            }
+        }
+    }
+
+    /// Sends `message`, see [`Sender::send`].
+    pub fn send(&self, message: Message) -> io::Result<()> {
+        self.sender.send(message)
+    }
+
+    /// Starts closing, see [`Sender::close`].
+    pub fn close(&self, code: u16, reason: &str) -> io::Result<()> {
+        self.sender.close(code, reason)
+    }
+
+    /// A handle to send on this WebSocket from other threads.
+    pub fn sender(&self) -> Sender {
+        self.sender.clone()
+    }
+}
+
+/// Closes the connection: the conversation is over once no one waits for
+/// what the other end says.
+impl Drop for WebSocket {
+    fn drop(&mut self) {
+        self.sender.shutdown();
+    }
+}
+
+impl fmt::Debug for WebSocket {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WebSocket").field("closed", &self.closed).finish_non_exhaustive()
+    }
+}
+
+/// Sends on a [`WebSocket`] from any thread. Clones send on the same one,
+/// a whole frame at a time.
+#[derive(Clone)]
+pub struct Sender {
+    shared: Arc<Shared>,
+    client: bool,
+}
+
+struct Shared {
+    stream: Mutex<TcpStream>,
+    /// Whether a close has been sent, after which nothing else may be.
+    closing: AtomicBool,
+}
+
+impl Sender {
+    /// Sends `message`, in one frame. Sending [`Message::Close`] is
+    /// [`Sender::close`]. Once a close is sent, sending fails.
+    pub fn send(&self, message: Message) -> io::Result<()> {
+        match message {
+            Message::Text(text) => self.send_frame(TEXT, text.as_bytes()),
+            Message::Binary(data) => self.send_frame(BINARY, &data),
+            Message::Ping(data) => self.send_control(PING, &data),
+            Message::Pong(data) => self.send_control(PONG, &data),
+            Message::Close(code, reason) => self.close(code, &reason),
+        }
+    }
+
+    /// Sends a close with `code`, from [`close`], and `reason`, which must
+    /// fit in 123 bytes. The other end answers with a close of its own,
+    /// which [`WebSocket::recv`] returns.
+    pub fn close(&self, code: u16, reason: &str) -> io::Result<()> {
+        if !is_sendable(code) {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("{code} is not a close code to send")));
+        }
+        self.close_frame(Some(code), reason)
+    }
+
+    fn close_frame(&self, code: Option<u16>, reason: &str) -> io::Result<()> {
+        let mut payload = Vec::new();
+        if let Some(code) = code {
+            payload.extend(code.to_be_bytes());
+            payload.extend(reason.as_bytes());
+        }
+        self.send_control(CLOSE, &payload)?;
+        self.shared.closing.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn send_control(&self, opcode: u8, payload: &[u8]) -> io::Result<()> {
+        if payload.len() > 125 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "control frames carry 125 bytes at most"));
+        }
+        self.send_frame(opcode, payload)
+    }
+
+    fn send_frame(&self, opcode: u8, payload: &[u8]) -> io::Result<()> {
+        let mut stream = self.stream();
+        if self.shared.closing.load(Ordering::SeqCst) {
+            return Err(io::Error::new(io::ErrorKind::NotConnected, "the WebSocket is closing"));
+        }
+        let mask = self.client.then(|| (next_random() as u32).to_be_bytes());
+        write_frame(&mut *stream, opcode, payload, mask)
+    }
+
+    fn stream(&self) -> MutexGuard<'_, TcpStream> {
+        self.shared.stream.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn shutdown(&self) {
+        let _ = self.stream().shutdown(Shutdown::Both);
+    }
+}
+
+impl fmt::Debug for Sender {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Sender").field("closing", &self.shared.closing).finish_non_exhaustive()
+    }
+}
+
+/// Why a message could not be received.
+enum Fail {
+    Io(io::Error),
+    /// The other end broke the protocol, and is closed with the code.
+    Protocol(u16, &'static str),
+}
+
+impl From<io::Error> for Fail {
+    fn from(err: io::Error) -> Fail {
+        Fail::Io(err)
+    }
+}
+
+struct Frame {
+    fin: bool,
+    opcode: u8,
+    payload: Vec<u8>,
+}
+
+/// Reads a frame, which is masked if it comes from a client and not
+/// otherwise.
+fn read_frame(reader: &mut impl Read, masked: bool, max: usize) -> Result<Frame, Fail> {
+    let mut head = [0; 2];
+    reader.read_exact(&mut head)?;
+    let (fin, opcode) = (head[0] & 0x80 != 0, head[0] & 0x0f);
+    if head[0] & 0x70 != 0 {
+        return Err(Fail::Protocol(close::PROTOCOL_ERROR, "reserved bits are set"));
+    }
+    if (head[1] & 0x80 != 0) != masked {
+        let why = if masked { "an unmasked frame from the client" } else { "a masked frame from the server" };
+        return Err(Fail::Protocol(close::PROTOCOL_ERROR, why));
+    }
+    let length = match head[1] & 0x7f {
+        126 => {
+            let mut length = [0; 2];
+            reader.read_exact(&mut length)?;
+            u64::from(u16::from_be_bytes(length))
+        }
+        127 => {
+            let mut length = [0; 8];
+            reader.read_exact(&mut length)?;
+            u64::from_be_bytes(length)
+        }
+        length => u64::from(length),
+    };
+    if opcode >= CLOSE && (!fin || length > 125) {
+        return Err(Fail::Protocol(close::PROTOCOL_ERROR, "a fragmented or long control frame"));
+    }
+    if length > max as u64 {
+        return Err(Fail::Protocol(close::TOO_BIG, "the message is too long"));
+    }
+    let mut mask = [0; 4];
+    if masked {
+        reader.read_exact(&mut mask)?;
+    }
+    let mut payload = vec![0; length as usize];
+    reader.read_exact(&mut payload)?;
+    if masked {
+        apply_mask(&mut payload, mask);
+    }
+    Ok(Frame { fin, opcode, payload })
+}
+
+/// Writes a frame with the whole of `payload`, masked with `mask` if given,
+/// in one write.
+fn write_frame(out: &mut impl Write, opcode: u8, payload: &[u8], mask: Option<[u8; 4]>) -> io::Result<()> {
+    let mut frame = Vec::with_capacity(payload.len() + 14);
+    frame.push(0x80 | opcode);
+    let masked = if mask.is_some() { 0x80 } else { 0 };
+    match payload.len() {
+        length @ 0..=125 => frame.push(masked | length as u8),
+        length @ 126..=0xffff => {
+            frame.push(masked | 126);
+            frame.extend((length as u16).to_be_bytes());
+        }
+        length => {
+            frame.push(masked | 127);
+            frame.extend((length as u64).to_be_bytes());
+        }
+    }
+    if let Some(mask) = mask {
+        frame.extend(mask);
+    }
+    let start = frame.len();
+    frame.extend(payload);
+    if let Some(mask) = mask {
+        apply_mask(&mut frame[start..], mask);
+    }
+    out.write_all(&frame)?;
+    out.flush()
+}
+
+/// Masks or unmasks `payload`, which are the same.
+fn apply_mask(payload: &mut [u8], mask: [u8; 4]) {
+    for (i, byte) in payload.iter_mut().enumerate() {
+        *byte ^= mask[i % 4];
+    }
+}
+
+fn message(opcode: u8, payload: Vec<u8>) -> Result<Message, Fail> {
+    if opcode == BINARY {
+        return Ok(Message::Binary(payload));
+    }
+    String::from_utf8(payload)
+        .map(Message::Text)
+        .map_err(|_| Fail::Protocol(close::INVALID_DATA, "a text message that is not UTF-8"))
+}
+
+/// The code and reason of a close frame's payload.
+fn parse_close(payload: &[u8]) -> Result<(u16, String), Fail> {
+    match payload {
+        [] => Ok((close::NO_STATUS, String::new())),
+        [_] => Err(Fail::Protocol(close::PROTOCOL_ERROR, "a close frame with half a code")),
+        [high, low, reason @ ..] => {
+            let code = u16::from_be_bytes([*high, *low]);
+            if !is_sendable(code) {
+                return Err(Fail::Protocol(close::PROTOCOL_ERROR, "an invalid close code"));
+            }
+            let reason = String::from_utf8(reason.to_vec())
+                .map_err(|_| Fail::Protocol(close::INVALID_DATA, "a close reason that is not UTF-8"))?;
+            Ok((code, reason))
+        }
+    }
+}
+
+/// Whether `code` may be sent in a close frame: the codes of the RFC, other
+/// than those that stand for what was not sent, and those for libraries and
+/// applications.
+fn is_sendable(code: u16) -> bool {
+    matches!(code, 1000..=1003 | 1007..=1011 | 3000..=4999)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handshake() {
+        // The example of RFC 6455, section 1.3.
+        assert_eq!(accept("dGhlIHNhbXBsZSBub25jZQ=="), "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+        let request = |headers: &str| {
+            let text = format!("GET /chat HTTP/1.1\r\nHost: h\r\n{headers}\r\n");
+            Request::read(&mut text.as_bytes()).unwrap().unwrap()
+        };
+        let handshake = "Upgrade: websocket\r\nConnection: keep-alive, Upgrade\r\n\
+                         Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n";
+        let response = upgrade(&request(handshake), |_| {});
+        assert_eq!(response.status, 101);
+        assert_eq!(response.headers.get("sec-websocket-accept"), Some("s3pPLMBiTxaQ9kYGzzhZRbK+xOo="));
+        assert_eq!(response.headers.get("upgrade"), Some("websocket"));
+
+        assert_eq!(upgrade(&request(""), |_| {}).status, 400);
+        let old = upgrade(&request(&handshake.replace(": 13", ": 8")), |_| {});
+        assert_eq!((old.status, old.headers.get("sec-websocket-version")), (426, Some("13")));
+        assert_eq!(upgrade(&request(&handshake.replace("dGhlIHNhbXBsZSBub25jZQ==", "c2hvcnQ=")), |_| {}).status, 400);
+    }
+
+    #[test]
+    fn frames() {
+        // A masked "Hello", from section 5.7.
+        let hello = [0x81, 0x85, 0x37, 0xfa, 0x21, 0x3d, 0x7f, 0x9f, 0x4d, 0x51, 0x58];
+        let frame = read_frame(&mut &hello[..], true, 100).ok().unwrap();
+        assert_eq!((frame.fin, frame.opcode, &frame.payload[..]), (true, TEXT, &b"Hello"[..]));
+        let mut written = Vec::new();
+        write_frame(&mut written, TEXT, b"Hello", Some([0x37, 0xfa, 0x21, 0x3d])).unwrap();
+        assert_eq!(written, hello);
+        written.clear();
+        write_frame(&mut written, TEXT, b"Hello", None).unwrap();
+        assert_eq!(written, [0x81, 0x05, b'H', b'e', b'l', b'l', b'o']);
+
+        for length in [125, 126, 65535, 65536] {
+            let mut written = Vec::new();
+            write_frame(&mut written, BINARY, &vec![7; length], None).unwrap();
+            let frame = read_frame(&mut &written[..], false, 1 << 20).ok().unwrap();
+            assert_eq!(frame.payload.len(), length);
+        }
+
+        let refused = |bytes: &[u8], masked: bool| match read_frame(&mut &bytes[..], masked, 100) {
+            Err(Fail::Protocol(code, _)) => code,
+            _ => panic!("{bytes:?} was read"),
+        };
+        assert_eq!(refused(&[0x81, 0x05, b'H', b'e', b'l', b'l', b'o'], true), close::PROTOCOL_ERROR);
+        assert_eq!(refused(&hello, false), close::PROTOCOL_ERROR);
+        assert_eq!(refused(&[0xc1, 0x00], false), close::PROTOCOL_ERROR);
+        assert_eq!(refused(&[0x09, 0x00], false), close::PROTOCOL_ERROR);
+        assert_eq!(refused(&[0x82, 0x7e, 0x01, 0x00], false), close::TOO_BIG);
+    }
+
+    #[test]
+    fn closes() {
+        assert_eq!(parse_close(&[]).ok(), Some((close::NO_STATUS, String::new())));
+        assert_eq!(parse_close(&[0x03, 0xe8, b'b', b'y', b'e']).ok(), Some((close::NORMAL, "bye".to_string())));
+        assert!(parse_close(&[0x03]).is_err());
+        assert!(parse_close(&[0x03, 0xed]).is_err());
+        assert!(parse_close(&[0x03, 0xe8, 0xff]).is_err());
+        assert!(is_sendable(close::NORMAL) && is_sendable(4000) && !is_sendable(close::NO_STATUS));
+    }
+}
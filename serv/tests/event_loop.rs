@@ -0,0 +1,307 @@
+//! Serves handlers on event loops, and talks to them over `TcpStream`s as
+//! `server.rs` does to the thread pool.
+
+#![cfg(target_os = "linux")]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serv::middleware::{AccessLog, RequestId};
+use serv::websocket::{self, Message};
+use serv::{route, routes, runtime, Backend, Handler, Limits, Next, Options, Request, Response, Server};
+
+#[route(GET, "/")]
+fn index() -> &'static str {
+    "Hello!\n"
+}
+
+#[route(GET, "/users/:id")]
+fn user(id: u32) -> String {
+    format!("user {id}\n")
+}
+
+#[route(POST, "/echo")]
+fn echo(request: &Request) -> Response {
+    Response::text(200, String::from_utf8_lossy(&request.body).into_owned())
+}
+
+#[route(GET, "/panic")]
+fn panics() -> &'static str {
+    panic!("on purpose")
+}
+
+#[route(GET, "/sleep/:ms")]
+async fn sleep(ms: u64, request: &Request) -> String {
+    runtime::sleep(Duration::from_millis(ms)).await;
+    format!("slept {ms}ms for {}\n", request.headers.get("x-request-id").is_some())
+}
+
+fn start(backend: Backend, workers: usize, handler: impl Handler) -> SocketAddr {
+    let server = Server::bind("127.0.0.1:0").unwrap().workers(workers).backend(backend);
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.serve(handler).unwrap());
+    addr
+}
+
+fn connect(addr: SocketAddr) -> TcpStream {
+    let stream = TcpStream::connect(addr).unwrap();
+    stream.set_read_timeout(Some(Duration::from_secs(10))).unwrap();
+    stream
+}
+
+/// Sends `request` as it is and returns the whole response, without its
+/// `Date` header.
+fn send(addr: SocketAddr, request: &str) -> String {
+    let mut stream = connect(addr);
+    stream.write_all(request.as_bytes()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response.lines().filter(|line| !line.starts_with("Date: ")).collect::<Vec<_>>().join("\n")
+}
+
+/// Reads one response with a `Content-Length`, and returns its head and
+/// body.
+fn read_response(reader: &mut impl BufRead) -> (String, String) {
+    let mut head = String::new();
+    while !head.ends_with("\r\n\r\n") {
+        assert_ne!(reader.read_line(&mut head).unwrap(), 0, "the connection closed after {head:?}");
+    }
+    let length = head.lines().find_map(|line| line.strip_prefix("Content-Length: ")).unwrap();
+    let mut body = vec![0; length.parse().unwrap()];
+    reader.read_exact(&mut body).unwrap();
+    (head, String::from_utf8(body).unwrap())
+}
+
+#[test]
+fn same_routes() {
+    let threads = start(Backend::Threads, 2, routes![index, user, echo, panics]);
+    let events = start(Backend::EventLoop, 2, routes![index, user, echo, panics]);
+    let requests = [
+        "GET / HTTP/1.1\r\nHost: x\r\nConnection: close\r\n\r\n".to_string(),
+        "GET /users/7 HTTP/1.0\r\n\r\n".to_string(),
+        "GET /users/x HTTP/1.1\r\nHost: x\r\nConnection: close\r\n\r\n".to_string(),
+        "HEAD / HTTP/1.1\r\nHost: x\r\nConnection: close\r\n\r\n".to_string(),
+        "POST /echo HTTP/1.1\r\nHost: x\r\nConnection: close\r\nContent-Length: 5\r\n\r\nhello".to_string(),
+        "POST /echo HTTP/1.1\r\nHost: x\r\nConnection: close\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n2\r\nde\r\n0\r\n\r\n"
+            .to_string(),
+        "GET /panic HTTP/1.1\r\nHost: x\r\nConnection: close\r\n\r\n".to_string(),
+        "GET / HTTP/1.1\r\n\r\n".to_string(),
+        "GET / HTTP/3\r\nHost: x\r\n\r\n".to_string(),
+        format!("GET /{} HTTP/1.1\r\nHost: x\r\n\r\n", "a".repeat(10_000)),
+    ];
+    for request in requests {
+        let expected = send(threads, &request);
+        assert!(expected.starts_with("HTTP/1.1 "), "{expected}");
+        assert_eq!(send(events, &request), expected, "for {request:?}");
+    }
+}
+
+#[test]
+fn keep_alive() {
+    let addr = start(Backend::EventLoop, 1, routes![index, user, echo]);
+    let mut stream = connect(addr);
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    // Two requests in one write, then one in pieces.
+    stream.write_all(b"GET /users/1 HTTP/1.1\r\nHost: x\r\n\r\nGET /users/2 HTTP/1.1\r\nHost: x\r\n\r\n").unwrap();
+    assert_eq!(read_response(&mut reader).1, "user 1\n");
+    assert_eq!(read_response(&mut reader).1, "user 2\n");
+    for piece in ["POST /echo HTTP/1.1\r\nHo", "st: x\r\nContent-Length: 6\r\n", "Expect: 100-continue\r\n\r\n"] {
+        stream.write_all(piece.as_bytes()).unwrap();
+        thread::sleep(Duration::from_millis(20));
+    }
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    assert_eq!(line, "HTTP/1.1 100 Continue\r\n");
+    reader.read_line(&mut line).unwrap();
+    stream.write_all(b"pie").unwrap();
+    thread::sleep(Duration::from_millis(20));
+    stream.write_all(b"ces").unwrap();
+    let (head, body) = read_response(&mut reader);
+    assert!(head.starts_with("HTTP/1.1 200 OK\r\n") && !head.contains("Connection: close"), "{head}");
+    assert_eq!(body, "pieces");
+}
+
+#[test]
+fn uploads() {
+    let options = Options {
+        limits: Limits {
+            body: 16 * 1024 * 1024,
+            ..Limits::default()
+        },
+        ..Options::default()
+    };
+    let server = Server::bind("127.0.0.1:0").unwrap().workers(1).backend(Backend::EventLoop).options(options);
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || {
+        server.serve(|request: &Request| {
+            let sum = request.body.iter().map(|&byte| u64::from(byte)).sum::<u64>();
+            Response::text(200, format!("{} {sum}\n", request.body.len()))
+        })
+    });
+    let body: Vec<u8> = (0..8 * 1024 * 1024).map(|i| (i % 251) as u8).collect();
+    let sum = body.iter().map(|&byte| u64::from(byte)).sum::<u64>();
+    let mut stream = connect(addr);
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+    // Bodies arriving a piece at a time are not read again from their start
+    // after every piece.
+    let started = Instant::now();
+    write!(stream, "POST / HTTP/1.1\r\nHost: x\r\nContent-Length: {}\r\n\r\n", body.len()).unwrap();
+    stream.write_all(&body).unwrap();
+    assert_eq!(read_response(&mut reader).1, format!("{} {sum}\n", body.len()));
+    stream.write_all(b"POST / HTTP/1.1\r\nHost: x\r\nTransfer-Encoding: chunked\r\n\r\n").unwrap();
+    for chunk in body.chunks(100_000) {
+        write!(stream, "{:x}\r\n", chunk.len()).unwrap();
+        stream.write_all(chunk).unwrap();
+        stream.write_all(b"\r\n").unwrap();
+    }
+    stream.write_all(b"0\r\n\r\n").unwrap();
+    assert_eq!(read_response(&mut reader).1, format!("{} {sum}\n", body.len()));
+    assert!(started.elapsed() < Duration::from_secs(2), "took {:?}", started.elapsed());
+}
+
+#[test]
+fn many_connections() {
+    // One loop, and more connections open at once than a pool of its size
+    // would serve.
+    let addr = start(Backend::EventLoop, 1, routes![index, user]);
+    let streams: Vec<TcpStream> = (0..200).map(|_| connect(addr)).collect();
+    for (i, stream) in streams.iter().enumerate().rev() {
+        write!(&*stream, "GET /users/{i} HTTP/1.1\r\nHost: x\r\n\r\n").unwrap();
+    }
+    for (i, stream) in streams.iter().enumerate() {
+        assert_eq!(read_response(&mut BufReader::new(stream)).1, format!("user {i}\n"));
+    }
+}
+
+#[test]
+fn async_handlers() {
+    let slow = runtime::handler(|request: Request| async move {
+        runtime::sleep(Duration::from_millis(300)).await;
+        Response::text(200, format!("slept on {}\n", request.path))
+    });
+    // The requests wait together, on one loop.
+    let addr = start(Backend::EventLoop, 1, slow);
+    let started = Instant::now();
+    let streams: Vec<TcpStream> = (0..20).map(|_| connect(addr)).collect();
+    for (i, stream) in streams.iter().enumerate() {
+        write!(&*stream, "GET /{i} HTTP/1.1\r\nHost: x\r\n\r\n").unwrap();
+    }
+    for (i, stream) in streams.iter().enumerate() {
+        assert_eq!(read_response(&mut BufReader::new(stream)).1, format!("slept on /{i}\n"));
+    }
+    assert!(started.elapsed() < Duration::from_secs(3), "took {:?}", started.elapsed());
+
+    // And the same handler works on the thread pool.
+    let slow = runtime::handler(|_: Request| async {
+        runtime::sleep(Duration::from_millis(10)).await;
+        Response::text(200, "slept\n")
+    });
+    let addr = start(Backend::Threads, 1, slow);
+    assert!(send(addr, "GET / HTTP/1.1\r\nHost: x\r\nConnection: close\r\n\r\n").ends_with("slept"));
+}
+
+#[test]
+fn async_middleware() {
+    let slow = runtime::handler(|request: Request| async move {
+        runtime::sleep(Duration::from_millis(300)).await;
+        Response::text(200, format!("slept on {}\n", request.path))
+    });
+    let passing = runtime::middleware(async |request: Request, next: Next<'_>| next.call(request).await);
+    let handler = routes![index, sleep]
+        .wrap(&sleep::ROUTE, passing)
+        .fallback(slow.with(AccessLog::new(std::io::sink())))
+        .with(RequestId);
+    let addr = start(Backend::EventLoop, 1, handler);
+    // Async routes and handlers inside middleware still wait together.
+    let started = Instant::now();
+    let paths = ["/sleep/300", "/a", "/sleep/300", "/b", "/sleep/300", "/c"];
+    let streams: Vec<TcpStream> = paths.iter().map(|_| connect(addr)).collect();
+    for (path, stream) in paths.iter().zip(&streams) {
+        write!(&*stream, "GET {path} HTTP/1.1\r\nHost: x\r\n\r\n").unwrap();
+    }
+    for (path, stream) in paths.iter().zip(&streams) {
+        let (head, body) = read_response(&mut BufReader::new(stream));
+        assert!(head.contains("\r\nX-Request-Id: "), "{head}");
+        match path.strip_prefix("/sleep/") {
+            Some(ms) => assert_eq!(body, format!("slept {ms}ms for true\n")),
+            None => assert_eq!(body, format!("slept on {path}\n")),
+        }
+    }
+    assert!(started.elapsed() < Duration::from_secs(1), "took {:?}", started.elapsed());
+
+    // And an async route works on the thread pool.
+    let addr = start(Backend::Threads, 1, routes![sleep]);
+    let response = send(addr, "GET /sleep/10 HTTP/1.1\r\nHost: x\r\nConnection: close\r\n\r\n");
+    assert!(response.ends_with("slept 10ms for false"), "{response}");
+    let response = send(addr, "GET /sleep/x HTTP/1.1\r\nHost: x\r\nConnection: close\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 404 Not Found"), "{response}");
+}
+
+#[test]
+fn websockets() {
+    let addr = start(Backend::EventLoop, 1, |request: &Request| {
+        websocket::upgrade(request, |mut socket| {
+            while let Ok(Message::Text(text)) = socket.recv() {
+                socket.send(Message::Text(text.to_uppercase())).unwrap();
+            }
+        })
+    });
+    let mut socket = websocket::connect(addr, "/").unwrap();
+    // The loop serves others while the WebSocket is open.
+    let mut other = websocket::connect(addr, "/").unwrap();
+    socket.send(Message::Text("hello".to_string())).unwrap();
+    other.send(Message::Text("there".to_string())).unwrap();
+    assert_eq!(socket.recv().unwrap(), Message::Text("HELLO".to_string()));
+    assert_eq!(other.recv().unwrap(), Message::Text("THERE".to_string()));
+}
+
+#[test]
+fn shutdown() {
+    let slow = runtime::handler(|_: Request| async {
+        runtime::sleep(Duration::from_millis(300)).await;
+        Response::text(200, "done\n")
+    });
+    let server = Server::bind("127.0.0.1:0").unwrap().workers(2).backend(Backend::EventLoop);
+    let (addr, control) = (server.local_addr().unwrap(), server.control());
+    let serving = thread::spawn(move || server.serve(slow));
+    let idle = connect(addr);
+    (&idle).write_all(b"GET / HTTP/1.1\r\nHost: x\r\n\r\n").unwrap();
+    read_response(&mut BufReader::new(&idle));
+    let busy = connect(addr);
+    (&busy).write_all(b"GET / HTTP/1.1\r\nHost: x\r\n\r\n").unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    let started = Instant::now();
+    control.shutdown();
+    // The request in flight is answered, and the idle connection closed.
+    let (head, body) = read_response(&mut BufReader::new(&busy));
+    assert!(head.contains("\r\nConnection: close\r\n") && body == "done\n", "{head}");
+    assert_eq!((&idle).read(&mut [0]).unwrap(), 0);
+    serving.join().unwrap().unwrap();
+    assert!(started.elapsed() < Duration::from_secs(2), "took {:?}", started.elapsed());
+    assert!(TcpStream::connect(addr).is_err());
+
+    // A deadline for what does not finish.
+    let stuck = runtime::handler(|_: Request| async {
+        runtime::sleep(Duration::from_secs(60)).await;
+        Response::text(200, "too late\n")
+    });
+    let options = Options {
+        shutdown_timeout: Duration::from_millis(200),
+        ..Options::default()
+    };
+    let server = Server::bind("127.0.0.1:0").unwrap().workers(1).backend(Backend::EventLoop).options(options);
+    let (addr, control) = (server.local_addr().unwrap(), server.control());
+    let serving = thread::spawn(move || server.serve(stuck));
+    let mut busy = connect(addr);
+    busy.write_all(b"GET / HTTP/1.1\r\nHost: x\r\n\r\n").unwrap();
+    thread::sleep(Duration::from_millis(100));
+    let started = Instant::now();
+    control.shutdown();
+    serving.join().unwrap().unwrap();
+    assert!(started.elapsed() < Duration::from_secs(3), "took {:?}", started.elapsed());
+    assert!(matches!(busy.read(&mut [0]), Ok(0) | Err(_)));
+}
@@ -0,0 +1,332 @@
+//! Runs a server on a loopback port and talks to it over `TcpStream`s.
+
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpStream};
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serv::{Handler, Limits, Options, Request, Response, Server, StaticFiles};
+
+/// Starts a server with `handler` on a free port, for the rest of the test
+/// run.
+fn start(handler: impl Handler) -> SocketAddr {
+    start_with(Options::default(), handler)
+}
+
+fn start_with(options: Options, handler: impl Handler) -> SocketAddr {
+    let server = Server::bind("127.0.0.1:0").unwrap().workers(4).options(options);
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.serve(handler).unwrap());
+    addr
+}
+
+/// Sends `request` as it is and returns the whole response.
+fn send(addr: SocketAddr, request: &str) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.set_read_timeout(Some(Duration::from_secs(10))).unwrap();
+    stream.write_all(request.as_bytes()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+fn get(addr: SocketAddr, path: &str) -> String {
+    send(addr, &format!("GET {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n"))
+}
+
+/// Reads one response from a connection that stays open: the head, and the
+/// body by its `Content-Length` or chunks.
+fn read_response(reader: &mut impl BufRead) -> (String, String) {
+    let mut head = String::new();
+    while !head.ends_with("\r\n\r\n") {
+        assert_ne!(reader.read_line(&mut head).unwrap(), 0, "the connection closed after {head:?}");
+    }
+    let header = |name: &str| {
+        head.lines()
+            .find_map(|line| line.strip_prefix(name))
+            .map(|value| value.trim().to_string())
+    };
+    let mut body = Vec::new();
+    if let Some(length) = header("Content-Length:") {
+        body.resize(length.parse().unwrap(), 0);
+        reader.read_exact(&mut body).unwrap();
+    } else if header("Transfer-Encoding:").as_deref() == Some("chunked") {
+        loop {
+            let mut size = String::new();
+            reader.read_line(&mut size).unwrap();
+            let size = usize::from_str_radix(size.trim_end(), 16).unwrap();
+            let mut chunk = vec![0; size + 2];
+            reader.read_exact(&mut chunk).unwrap();
+            if size == 0 {
+                break;
+            }
+            body.extend_from_slice(&chunk[..size]);
+        }
+    }
+    (head, String::from_utf8(body).unwrap())
+}
+
+fn status(response: &str) -> &str {
+    response.lines().next().unwrap()
+}
+
+fn body(response: &str) -> &str {
+    response.split_once("\r\n\r\n").unwrap().1
+}
+
+/// Creates a scratch directory holding `files`, given as `(path, contents)`.
+fn tree(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("serv-{}-{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    for (path, contents) in files {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+    root
+}
+
+#[test]
+fn static_files() {
+    let root = tree(
+        "static",
+        &[
+            ("index.html", "<h1>Hello!</h1>\n"),
+            ("docs/index.html", "<h1>Docs</h1>\n"),
+            ("docs/notes.txt", "take notes\n"),
+        ],
+    );
+    let addr = start(StaticFiles::new(&root));
+
+    let response = get(addr, "/");
+    assert_eq!(status(&response), "HTTP/1.1 200 OK");
+    assert!(response.contains("\r\nContent-Type: text/html; charset=utf-8\r\n"));
+    assert!(response.contains("\r\nContent-Length: 16\r\n"));
+    assert_eq!(body(&response), "<h1>Hello!</h1>\n");
+
+    assert_eq!(body(&get(addr, "/docs/notes%2etxt")), "take notes\n");
+    assert_eq!(body(&get(addr, "/docs/")), "<h1>Docs</h1>\n");
+    let response = get(addr, "/docs?page=2");
+    assert_eq!(status(&response), "HTTP/1.1 301 Moved Permanently");
+    assert!(response.contains("\r\nLocation: /docs/?page=2\r\n"));
+
+    let response = send(addr, "HEAD /docs/notes.txt HTTP/1.0\r\n\r\n");
+    assert_eq!(status(&response), "HTTP/1.1 200 OK");
+    assert!(response.contains("\r\nContent-Length: 11\r\n"));
+    assert_eq!(body(&response), "");
+
+    let response = send(addr, "POST / HTTP/1.0\r\n\r\n");
+    assert_eq!(status(&response), "HTTP/1.1 405 Method Not Allowed");
+    assert!(response.contains("\r\nAllow: GET, HEAD\r\n"));
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn not_found() {
+    let root = tree("missing", &[("public/index.html", "home\n"), ("secret.txt", "hush\n")]);
+    let addr = start(StaticFiles::new(root.join("public")));
+    let response = get(addr, "/nothing.html");
+    assert_eq!(status(&response), "HTTP/1.1 404 Not Found");
+    assert_eq!(body(&response), "404 Not Found\n");
+    assert_eq!(status(&get(addr, "/../secret.txt")), "HTTP/1.1 404 Not Found");
+    assert_eq!(status(&get(addr, "/%2e%2e/secret.txt")), "HTTP/1.1 404 Not Found");
+
+    // The book's own pages, with its 404.html.
+    let addr = start(StaticFiles::new(concat!(env!("CARGO_MANIFEST_DIR"), "/www")));
+    assert!(body(&get(addr, "/")).contains("<p>Hi from Rust</p>"));
+    let response = get(addr, "/something-else");
+    assert_eq!(status(&response), "HTTP/1.1 404 Not Found");
+    assert!(body(&response).contains("<h1>Oops!</h1>"));
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn bad_requests() {
+    let addr = start(|_: &Request| Response::text(200, "fine\n"));
+    let response = send(addr, "GET / HTTP/1.1\r\n\r\n");
+    assert_eq!(status(&response), "HTTP/1.1 400 Bad Request");
+    assert_eq!(body(&response), "400 Bad Request: HTTP/1.1 requests need one Host header\n");
+    assert_eq!(status(&send(addr, "hello\r\n\r\n")), "HTTP/1.1 400 Bad Request");
+    assert_eq!(status(&send(addr, "GET / HTTP/3\r\n\r\n")), "HTTP/1.1 505 HTTP Version Not Supported");
+
+    // A request that stops halfway is answered once the client has finished
+    // sending.
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.write_all(b"GET / HTTP/1.1\r\nHost: x\r\n").unwrap();
+    stream.shutdown(Shutdown::Write).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    assert_eq!(body(&response), "400 Bad Request: the headers end early\n");
+}
+
+#[test]
+fn handlers() {
+    let addr = start(|request: &Request| match request.path.as_str() {
+        "/panic" => panic!("handler failed"),
+        "/sleep" => {
+            thread::sleep(Duration::from_millis(500));
+            Response::text(200, "slept\n")
+        }
+        path => Response::text(200, format!("{} {path} {:?}\n", request.method, request.query)),
+    });
+    assert_eq!(body(&get(addr, "/a%20b?c")), "GET /a b Some(\"c\")\n");
+    assert_eq!(status(&get(addr, "/panic")), "HTTP/1.1 500 Internal Server Error");
+    assert_eq!(body(&get(addr, "/after")), "GET /after None\n");
+
+    // A slow request does not hold up the others.
+    let slow = thread::spawn(move || get(addr, "/sleep"));
+    thread::sleep(Duration::from_millis(50));
+    let fast = thread::spawn(move || get(addr, "/fast"));
+    assert_eq!(body(&fast.join().unwrap()), "GET /fast None\n");
+    assert!(!slow.is_finished());
+    assert_eq!(body(&slow.join().unwrap()), "slept\n");
+}
+
+/// Answers with the method, path and body of the request.
+fn echo(request: &Request) -> Response {
+    let body = String::from_utf8_lossy(&request.body);
+    Response::text(200, format!("{} {} {body}", request.method, request.path))
+}
+
+#[test]
+fn keep_alive() {
+    let addr = start(echo);
+    let stream = TcpStream::connect(addr).unwrap();
+    let mut reader = BufReader::new(&stream);
+    (&stream).write_all(b"GET /one HTTP/1.1\r\nHost: x\r\n\r\n").unwrap();
+    let (head, body) = read_response(&mut reader);
+    assert!(!head.contains("Connection:"));
+    assert_eq!(body, "GET /one ");
+
+    // Pipelined requests are answered in order, on the same connection.
+    (&stream)
+        .write_all(b"POST /two HTTP/1.1\r\nHost: x\r\nContent-Length: 3\r\n\r\nabcGET /three HTTP/1.1\r\nHost: x\r\n\r\n")
+        .unwrap();
+    assert_eq!(read_response(&mut reader).1, "POST /two abc");
+    assert_eq!(read_response(&mut reader).1, "GET /three ");
+
+    (&stream).write_all(b"GET /last HTTP/1.1\r\nHost: x\r\nConnection: close\r\n\r\n").unwrap();
+    let (head, body) = read_response(&mut reader);
+    assert!(head.contains("\r\nConnection: close\r\n"));
+    assert_eq!(body, "GET /last ");
+    assert_eq!(reader.read(&mut [0]).unwrap(), 0);
+
+    // HTTP/1.0 clients ask for it.
+    let stream = TcpStream::connect(addr).unwrap();
+    let mut reader = BufReader::new(&stream);
+    (&stream).write_all(b"GET /old HTTP/1.0\r\nConnection: keep-alive\r\n\r\n").unwrap();
+    assert!(read_response(&mut reader).0.contains("\r\nConnection: keep-alive\r\n"));
+    (&stream).write_all(b"GET /old HTTP/1.0\r\n\r\n").unwrap();
+    assert!(read_response(&mut reader).0.contains("\r\nConnection: close\r\n"));
+    assert_eq!(reader.read(&mut [0]).unwrap(), 0);
+}
+
+#[test]
+fn idle_connections_are_closed() {
+    let options = Options {
+        keep_alive: Duration::from_millis(200),
+        ..Options::default()
+    };
+    let addr = start_with(options, echo);
+    let stream = TcpStream::connect(addr).unwrap();
+    let mut reader = BufReader::new(&stream);
+    (&stream).write_all(b"GET / HTTP/1.1\r\nHost: x\r\n\r\n").unwrap();
+    read_response(&mut reader);
+    let idle = Instant::now();
+    assert_eq!(reader.read(&mut [0]).unwrap(), 0);
+    assert!(idle.elapsed() < Duration::from_secs(3));
+}
+
+#[test]
+fn chunked_bodies() {
+    let addr = start(|request: &Request| match request.path.as_str() {
+        "/stream" => Response::stream(200, &b"a streamed body"[..]),
+        _ => echo(request),
+    });
+    let stream = TcpStream::connect(addr).unwrap();
+    let mut reader = BufReader::new(&stream);
+    (&stream)
+        .write_all(
+            b"POST /upload HTTP/1.1\r\nHost: x\r\nTransfer-Encoding: chunked\r\n\r\n\
+              4\r\nWiki\r\n5;ext=1\r\npedia\r\n0\r\nTrailer: ignored\r\n\r\n",
+        )
+        .unwrap();
+    assert_eq!(read_response(&mut reader).1, "POST /upload Wikipedia");
+    (&stream).write_all(b"GET /stream HTTP/1.1\r\nHost: x\r\n\r\n").unwrap();
+    let (head, body) = read_response(&mut reader);
+    assert!(head.contains("\r\nTransfer-Encoding: chunked\r\n"));
+    assert_eq!(body, "a streamed body");
+
+    // With 100-continue the client waits to be asked for the body.
+    (&stream)
+        .write_all(b"PUT /file HTTP/1.1\r\nHost: x\r\nExpect: 100-continue\r\nContent-Length: 4\r\n\r\n")
+        .unwrap();
+    let mut interim = String::new();
+    reader.read_line(&mut interim).unwrap();
+    assert_eq!(interim, "HTTP/1.1 100 Continue\r\n");
+    reader.read_line(&mut interim).unwrap();
+    (&stream).write_all(b"data").unwrap();
+    assert_eq!(read_response(&mut reader).1, "PUT /file data");
+}
+
+#[test]
+fn limits() {
+    let options = Options {
+        limits: Limits {
+            request_line: 100,
+            header_bytes: 200,
+            headers: 10,
+            body: 1000,
+        },
+        ..Options::default()
+    };
+    let addr = start_with(options, echo);
+    let status_of = |request: String| status(&send(addr, &request)).to_string();
+    assert_eq!(status_of(format!("GET /{} HTTP/1.1\r\nHost: x\r\n\r\n", "a".repeat(100))), "HTTP/1.1 414 URI Too Long");
+    assert_eq!(
+        status_of(format!("GET / HTTP/1.1\r\nHost: x\r\nCookie: {}\r\n\r\n", "a".repeat(200))),
+        "HTTP/1.1 431 Request Header Fields Too Large"
+    );
+    assert_eq!(
+        status_of(format!("GET / HTTP/1.1\r\nHost: x\r\n{}\r\n", "A: 1\r\n".repeat(10))),
+        "HTTP/1.1 431 Request Header Fields Too Large"
+    );
+    // The body is refused by its length, before it is sent.
+    let response = send(addr, "POST / HTTP/1.1\r\nHost: x\r\nContent-Length: 1001\r\n\r\n");
+    assert_eq!(status(&response), "HTTP/1.1 413 Content Too Large");
+    assert!(response.contains("\r\nConnection: close\r\n"));
+    let chunks = format!("3e8\r\n{}\r\n1\r\nx\r\n0\r\n\r\n", "a".repeat(1000));
+    let request = format!("POST / HTTP/1.1\r\nHost: x\r\nTransfer-Encoding: chunked\r\n\r\n{chunks}");
+    assert_eq!(status_of(request), "HTTP/1.1 413 Content Too Large");
+    let request = "POST / HTTP/1.1\r\nHost: x\r\nContent-Length: 5\r\nContent-Length: 6\r\n\r\nhello!".to_string();
+    assert_eq!(status_of(request), "HTTP/1.1 400 Bad Request");
+    let request = "POST / HTTP/1.1\r\nHost: x\r\nTransfer-Encoding: br\r\n\r\n".to_string();
+    assert_eq!(status_of(request), "HTTP/1.1 501 Not Implemented");
+    assert_eq!(status_of(format!("POST / HTTP/1.0\r\nContent-Length: 1000\r\n\r\n{}", "a".repeat(1000))), "HTTP/1.1 200 OK");
+}
+
+#[test]
+fn control() {
+    let server = Server::bind("127.0.0.1:0").unwrap().workers(2);
+    let addr = server.local_addr().unwrap();
+    let control = server.control();
+    let serving = thread::spawn(move || server.serve(|_: &Request| Response::text(200, "old\n")));
+    let stream = TcpStream::connect(addr).unwrap();
+    let mut reader = BufReader::new(&stream);
+    (&stream).write_all(b"GET / HTTP/1.1\r\nHost: x\r\n\r\n").unwrap();
+    assert_eq!(read_response(&mut reader).1, "old\n");
+
+    // A reload reaches connections already open.
+    control.reload(|_: &Request| Response::text(200, "new\n"), Options::default());
+    (&stream).write_all(b"GET / HTTP/1.1\r\nHost: x\r\n\r\n").unwrap();
+    assert_eq!(read_response(&mut reader).1, "new\n");
+
+    let start = Instant::now();
+    control.shutdown();
+    serving.join().unwrap().unwrap();
+    assert!(start.elapsed() < Duration::from_secs(2), "the idle connection held the shutdown");
+    assert!(control.is_shutting_down());
+    assert_eq!(reader.read(&mut [0]).unwrap(), 0);
+}
@@ -0,0 +1,189 @@
+//! Runs the `serv` binary as a child process, the way
+//! `20_5_child_processes_pipes_wait` runs its children, sends it signals
+//! with `kill` and talks to it over `TcpStream`s.
+
+#![cfg(unix)]
+
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::path::PathBuf;
+use std::process::{Child, ChildStderr, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A running `serv`, killed if the test fails before it exits.
+struct Serv {
+    child: Child,
+    stderr: BufReader<ChildStderr>,
+    addr: SocketAddr,
+}
+
+impl Serv {
+    fn spawn(args: &[&str]) -> Serv {
+        let mut child = match Command::new(env!("CARGO_BIN_EXE_serv"))
+            .args(["--addr", "127.0.0.1:0"])
+            .args(args)
+            .stderr(Stdio::piped())
+            .spawn()
+        {
+            Err(why) => panic!("couldn't spawn serv: {why}"),
+            Ok(child) => child,
+        };
+        let mut stderr = BufReader::new(child.stderr.take().unwrap());
+        let mut line = String::new();
+        stderr.read_line(&mut line).unwrap();
+        let addr = match line.trim_end().rsplit_once("http://") {
+            Some((_, addr)) => addr.parse().unwrap(),
+            None => panic!("serv said {line:?}"),
+        };
+        Serv { child, stderr, addr }
+    }
+
+    fn signal(&self, name: &str) {
+        let status = Command::new("kill").arg(format!("-{name}")).arg(self.child.id().to_string()).status();
+        assert!(status.unwrap().success());
+    }
+
+    /// The next line `serv` writes to stderr.
+    fn said(&mut self) -> String {
+        let mut line = String::new();
+        self.stderr.read_line(&mut line).unwrap();
+        line.trim_end().to_string()
+    }
+
+    fn connect(&self) -> TcpStream {
+        let stream = TcpStream::connect(self.addr).unwrap();
+        stream.set_read_timeout(Some(Duration::from_secs(10))).unwrap();
+        stream
+    }
+
+    /// How the process exits, and how long it takes to.
+    fn wait(&mut self) -> (ExitStatus, Duration) {
+        let start = Instant::now();
+        let status = self.child.wait().unwrap();
+        (status, start.elapsed())
+    }
+}
+
+impl Drop for Serv {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Creates a scratch directory holding `files`, given as `(path, contents)`.
+fn tree(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("serv-{}-{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    for (path, contents) in files {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+    root
+}
+
+/// Reads one response with a `Content-Length`, and returns its head and
+/// body.
+fn read_response(reader: &mut impl BufRead) -> (String, String) {
+    let mut head = String::new();
+    while !head.ends_with("\r\n\r\n") {
+        assert_ne!(reader.read_line(&mut head).unwrap(), 0, "the connection closed after {head:?}");
+    }
+    let length = head.lines().find_map(|line| line.strip_prefix("Content-Length: ")).unwrap();
+    let mut body = vec![0; length.parse().unwrap()];
+    reader.read_exact(&mut body).unwrap();
+    (head, String::from_utf8(body).unwrap())
+}
+
+/// Whether the server closes `stream` rather than sending anything more.
+fn closed(stream: &mut TcpStream) -> bool {
+    matches!(stream.read(&mut [0]), Ok(0) | Err(_))
+}
+
+const GET: &str = "GET / HTTP/1.1\r\nHost: localhost\r\n\r\n";
+
+#[test]
+fn shutdown_drains_requests() {
+    let mut serv = Serv::spawn(&[concat!(env!("CARGO_MANIFEST_DIR"), "/www")]);
+    let mut idle = serv.connect();
+    idle.write_all(GET.as_bytes()).unwrap();
+    let (head, _) = read_response(&mut BufReader::new(&idle));
+    assert!(head.starts_with("HTTP/1.1 200 OK\r\n"));
+    let mut busy = serv.connect();
+    busy.write_all(b"GET / HTTP/1.1\r\nHost: localhost\r\n").unwrap();
+
+    serv.signal("TERM");
+    assert_eq!(serv.said(), "Shutting down");
+    // The idle connection is closed at once, and no new ones are taken.
+    assert!(closed(&mut idle));
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while TcpStream::connect(serv.addr).is_ok() {
+        assert!(Instant::now() < deadline, "still accepting connections");
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    // The request in flight is answered, and its connection closed.
+    busy.write_all(b"\r\n").unwrap();
+    let mut reader = BufReader::new(&busy);
+    let (head, body) = read_response(&mut reader);
+    assert!(head.starts_with("HTTP/1.1 200 OK\r\n") && head.contains("\r\nConnection: close\r\n"));
+    assert!(body.contains("<h1>Hello!</h1>"));
+    assert!(closed(&mut busy));
+    assert!(serv.wait().0.success());
+}
+
+#[test]
+fn shutdown_deadline() {
+    let mut serv = Serv::spawn(&["--shutdown-timeout", "1", concat!(env!("CARGO_MANIFEST_DIR"), "/www")]);
+    let mut stuck = serv.connect();
+    stuck.write_all(b"GET / HTTP/1.1\r\n").unwrap();
+    serv.signal("INT");
+    assert_eq!(serv.said(), "Shutting down");
+    // Without the deadline, the read timeout of 10 seconds would hold it.
+    let (status, took) = serv.wait();
+    assert!(status.success() && took < Duration::from_secs(5), "{status} after {took:?}");
+    assert!(closed(&mut stuck));
+
+    // A second signal does not wait for the deadline.
+    let mut serv = Serv::spawn(&[concat!(env!("CARGO_MANIFEST_DIR"), "/www")]);
+    let mut stuck = serv.connect();
+    stuck.write_all(b"GET / HTTP/1.1\r\n").unwrap();
+    serv.signal("TERM");
+    assert_eq!(serv.said(), "Shutting down");
+    serv.signal("INT");
+    let (status, took) = serv.wait();
+    assert!(!status.success() && took < Duration::from_secs(5), "{status} after {took:?}");
+}
+
+#[test]
+fn hangup_reloads() {
+    let root = tree("reload", &[("a/index.html", "from a\n"), ("b/index.html", "from b\n")]);
+    let config = root.join("serv.conf");
+    fs::write(&config, format!("# serv\nroot = {}\nkeep-alive = 30\n", root.join("a").display())).unwrap();
+    let mut serv = Serv::spawn(&["--config", config.to_str().unwrap()]);
+    let stream = serv.connect();
+    let mut reader = BufReader::new(&stream);
+    (&stream).write_all(GET.as_bytes()).unwrap();
+    assert_eq!(read_response(&mut reader).1, "from a\n");
+
+    fs::write(&config, format!("root = {}\nlog\n", root.join("b").display())).unwrap();
+    serv.signal("HUP");
+    assert_eq!(serv.said(), format!("Reloaded, serving {}", root.join("b").display()));
+    // The same connection, served from the new root, and logged.
+    (&stream).write_all(GET.as_bytes()).unwrap();
+    assert_eq!(read_response(&mut reader).1, "from b\n");
+    assert!(serv.said().ends_with("\"GET / HTTP/1.1\" 200 7"));
+
+    // A configuration that does not parse leaves the server as it was.
+    fs::write(&config, "colour = blue\n").unwrap();
+    serv.signal("HUP");
+    assert_eq!(serv.said(), format!("Cannot reload: {}:1: unknown setting `colour`", config.display()));
+    (&stream).write_all(GET.as_bytes()).unwrap();
+    assert_eq!(read_response(&mut reader).1, "from b\n");
+
+    serv.signal("TERM");
+    assert!(serv.wait().0.success());
+}
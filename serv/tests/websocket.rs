@@ -0,0 +1,172 @@
+//! Opens WebSockets to an echo server, and sends it frames by hand to see
+//! what it makes of those that break the protocol.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+use serv::websocket::{self, close, Message};
+use serv::{Request, Response, Server};
+
+fn start() -> SocketAddr {
+    let server = Server::bind("127.0.0.1:0").unwrap().workers(4);
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.serve(echo).unwrap());
+    addr
+}
+
+/// Sends back every message, and closes when asked to with "close".
+fn echo(request: &Request) -> Response {
+    if request.path != "/echo" {
+        return Response::not_found();
+    }
+    websocket::upgrade(request, |socket| {
+        let mut socket = socket.max_message(1 << 16);
+        while let Ok(message) = socket.recv() {
+            match message {
+                Message::Text(text) if text == "close" => socket.close(4000, "asked to").unwrap(),
+                Message::Text(_) | Message::Binary(_) => socket.send(message).unwrap(),
+                Message::Ping(_) | Message::Pong(_) | Message::Close(..) => {}
+            }
+        }
+    })
+}
+
+#[test]
+fn messages() {
+    let addr = start();
+    let mut socket = websocket::connect(addr, "/echo").unwrap();
+    socket.send(Message::Text("héllo".to_string())).unwrap();
+    assert_eq!(socket.recv().unwrap(), Message::Text("héllo".to_string()));
+    let data: Vec<u8> = (0..60_000u32).map(|i| i as u8).collect();
+    socket.send(Message::Binary(data.clone())).unwrap();
+    assert_eq!(socket.recv().unwrap(), Message::Binary(data));
+    socket.send(Message::Ping(b"are you there".to_vec())).unwrap();
+    assert_eq!(socket.recv().unwrap(), Message::Pong(b"are you there".to_vec()));
+
+    // Messages are sent from other threads while this one waits.
+    let sender = socket.sender();
+    thread::spawn(move || sender.send(Message::Text("from a thread".to_string())).unwrap());
+    assert_eq!(socket.recv().unwrap(), Message::Text("from a thread".to_string()));
+
+    socket.close(close::NORMAL, "done").unwrap();
+    assert!(socket.send(Message::Text("late".to_string())).is_err());
+    assert_eq!(socket.recv().unwrap(), Message::Close(close::NORMAL, String::new()));
+    assert!(socket.recv().is_err());
+
+    // The server closes, and the client answers.
+    let mut socket = websocket::connect(addr, "/echo").unwrap();
+    socket.send(Message::Text("close".to_string())).unwrap();
+    assert_eq!(socket.recv().unwrap(), Message::Close(4000, "asked to".to_string()));
+    assert!(socket.recv().is_err());
+}
+
+#[test]
+fn handshakes() {
+    let addr = start();
+    let send = |request: &str| {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        stream.shutdown(Shutdown::Write).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    };
+    let handshake = "GET /echo HTTP/1.1\r\nHost: localhost\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+                     Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n\r\n";
+    let no_upgrade = send("GET /echo HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n");
+    assert!(no_upgrade.starts_with("HTTP/1.1 400 Bad Request\r\n"), "{no_upgrade}");
+    let post = send(&handshake.replace("GET", "POST"));
+    assert!(post.starts_with("HTTP/1.1 400 Bad Request\r\n"), "{post}");
+    let old = send(&handshake.replace(": 13", ": 8"));
+    assert!(old.starts_with("HTTP/1.1 426 ") && old.contains("\r\nSec-WebSocket-Version: 13\r\n"), "{old}");
+
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.set_read_timeout(Some(Duration::from_secs(10))).unwrap();
+    stream.write_all(handshake.as_bytes()).unwrap();
+    let mut reader = BufReader::new(&stream);
+    let mut head = String::new();
+    while !head.ends_with("\r\n\r\n") {
+        assert_ne!(reader.read_line(&mut head).unwrap(), 0, "the connection closed after {head:?}");
+    }
+    assert!(head.starts_with("HTTP/1.1 101 Switching Protocols\r\n"), "{head}");
+    assert!(head.contains("\r\nSec-WebSocket-Accept: s3pPLMBiTxaQ9kYGzzhZRbK+xOo=\r\n"));
+    assert!(head.contains("\r\nConnection: Upgrade\r\n") && !head.contains("Content-Length"));
+
+    // A frame a client did not mask is answered with a protocol error, and
+    // the end of the connection.
+    (&stream).write_all(&[0x81, 0x02, b'h', b'i']).unwrap();
+    let mut rest = Vec::new();
+    reader.read_to_end(&mut rest).unwrap();
+    assert_eq!(&rest[..4], [0x88, rest[1], 0x03, 0xea]);
+}
+
+/// Opens a WebSocket by hand, for frames a client would not send.
+fn open(addr: SocketAddr) -> (TcpStream, BufReader<TcpStream>) {
+    let stream = TcpStream::connect(addr).unwrap();
+    stream.set_read_timeout(Some(Duration::from_secs(10))).unwrap();
+    write!(
+        &stream,
+        "GET /echo HTTP/1.1\r\nHost: localhost\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+         Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n\r\n"
+    )
+    .unwrap();
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut line = String::new();
+    while line != "\r\n" {
+        line.clear();
+        assert_ne!(reader.read_line(&mut line).unwrap(), 0);
+    }
+    (stream, reader)
+}
+
+/// A frame masked with a key of zeros, which leaves it as it is.
+fn frame(head: u8, payload: &[u8]) -> Vec<u8> {
+    let length = match payload.len() {
+        length @ 0..=125 => vec![0x80 | length as u8],
+        length => [&[0x80 | 126][..], &(length as u16).to_be_bytes()].concat(),
+    };
+    [&[head][..], &length, &[0; 4], payload].concat()
+}
+
+/// The code of the close the server ends the connection with.
+fn close_code(reader: &mut impl Read) -> u16 {
+    let mut rest = Vec::new();
+    reader.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest[0], 0x88, "{rest:?}");
+    u16::from_be_bytes([rest[2], rest[3]])
+}
+
+#[test]
+fn fragments() {
+    let (mut stream, mut reader) = open(start());
+    // "Hel", a ping in between, then "lo".
+    stream.write_all(&[frame(0x01, b"Hel"), frame(0x89, b"?"), frame(0x80, b"lo")].concat()).unwrap();
+    let mut answer = [0; 10];
+    reader.read_exact(&mut answer).unwrap();
+    assert_eq!(answer, [0x8a, 0x01, b'?', 0x81, 0x05, b'H', b'e', b'l', b'l', b'o']);
+
+    // A continuation of no message is a protocol error.
+    stream.write_all(&frame(0x80, b"lo")).unwrap();
+    assert_eq!(close_code(&mut reader), close::PROTOCOL_ERROR);
+}
+
+#[test]
+fn invalid_messages() {
+    let addr = start();
+    let (mut stream, mut reader) = open(addr);
+    stream.write_all(&frame(0x81, &[b'a', 0xff])).unwrap();
+    assert_eq!(close_code(&mut reader), close::INVALID_DATA);
+
+    // Two fragments the server takes, which make a message it does not.
+    let (mut stream, mut reader) = open(addr);
+    stream.write_all(&[frame(0x02, &[0; 40_000]), frame(0x80, &[0; 40_000])].concat()).unwrap();
+    assert_eq!(close_code(&mut reader), close::TOO_BIG);
+
+    let (mut stream, mut reader) = open(addr);
+    stream.write_all(&frame(0x88, &[0x03])).unwrap();
+    assert_eq!(close_code(&mut reader), close::PROTOCOL_ERROR);
+
+    assert!(websocket::connect(addr, "/nowhere").is_err());
+}
@@ -0,0 +1,380 @@
+//! Lists the book listings, runs one of them, or verifies them against the
+//! output documented next to them.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use std::process::{Command, ExitCode, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use rust_book_examples::runner::{self, Filter, Listing, Run};
+
+const USAGE: &str = "\
+usage: book-run [list | run | verify] [options] [PATH] [-- ARGS...]
+
+commands:
+    list        print the listings that match the filters
+    run         run the one matching listing with ARGS, in a child process
+    verify      run every matching listing that documents its output and
+                compare what it prints (the default)
+
+options:
+    --book trpl|rbe     only listings of one book
+    --chapter N|NAME    chapter number, or part of the chapter module name
+    --listing N-M       the book's listing number, such as 12-5
+    --title TEXT        part of the title, case-insensitive
+    --timeout SECS      kill a listing that runs longer (verify, default 10)
+    -j N                listings verified at once (default: CPU count)
+    --in-process        run: call the listing's main in this process
+    PATH                start of the listing's module path";
+
+/// Set on the child processes to say which listing they run.
+const LISTING_VAR: &str = "BOOK_RUN_LISTING";
+
+/// Files the book's transcripts read, written to the directory of each run.
+const FILES: [(&str, &str); 1] = [("poem.txt", include_str!("../../minigrep/poem.txt"))];
+
+/// Output kept per run; a listing that prints more is cut short.
+const CAPTURE_LIMIT: usize = 1 << 20;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    List,
+    Run,
+    Verify,
+}
+
+struct Options {
+    mode: Mode,
+    filter: Filter,
+    timeout: Duration,
+    jobs: usize,
+    in_process: bool,
+    args: Vec<String>,
+}
+
+impl Options {
+    fn parse(args: &[String]) -> Result<Options, String> {
+        let mut options = Options {
+            mode: Mode::Verify,
+            filter: Filter::default(),
+            timeout: Duration::from_secs(10),
+            jobs: thread::available_parallelism().map_or(1, |n| n.get()),
+            in_process: false,
+            args: Vec::new(),
+        };
+        let mut args = args.iter();
+        let mut first = true;
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .cloned()
+                    .ok_or_else(|| format!("{name} needs a value"))
+            };
+            match arg.as_str() {
+                "list" if first => options.mode = Mode::List,
+                "run" if first => options.mode = Mode::Run,
+                "verify" if first => options.mode = Mode::Verify,
+                "--book" => options.filter.book = Some(value(arg)?),
+                "--chapter" => options.filter.chapter = Some(value(arg)?),
+                "--listing" => options.filter.listing = Some(value(arg)?),
+                "--title" => options.filter.title = Some(value(arg)?),
+                "--timeout" => {
+                    let secs = value(arg)?;
+                    let secs: f64 = secs.parse().map_err(|_| format!("bad --timeout: {secs}"))?;
+                    options.timeout = Duration::from_secs_f64(secs);
+                }
+                "-j" => {
+                    let jobs = value(arg)?;
+                    options.jobs = jobs
+                        .parse()
+                        .ok()
+                        .filter(|&n| n > 0)
+                        .ok_or_else(|| format!("bad -j: {jobs}"))?;
+                }
+                "--in-process" => options.in_process = true,
+                "--" => {
+                    options.args = args.cloned().collect();
+                    break;
+                }
+                "-h" | "--help" => return Err(String::new()),
+                flag if flag.starts_with('-') => return Err(format!("unknown option {flag}")),
+                path if options.filter.path.is_none() => options.filter.path = Some(path.to_string()),
+                extra => return Err(format!("unexpected argument {extra}")),
+            }
+            first = false;
+        }
+        Ok(options)
+    }
+}
+
+fn main() -> ExitCode {
+    if let Ok(path) = env::var(LISTING_VAR) {
+        return match runner::find(&path).and_then(|l| l.main) {
+            Some(main) => main(),
+            None => {
+                eprintln!("book-run: no runnable listing {path}");
+                ExitCode::from(2)
+            }
+        };
+    }
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match Options::parse(&args) {
+        Ok(options) => options,
+        Err(err) => {
+            if !err.is_empty() {
+                eprintln!("book-run: {err}\n");
+            }
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let selected: Vec<&'static Listing> = runner::LISTINGS
+        .iter()
+        .filter(|l| options.filter.matches(l))
+        .collect();
+    match options.mode {
+        Mode::List => list(&selected),
+        Mode::Run => run(&selected, &options),
+        Mode::Verify => verify(&selected, &options),
+    }
+}
+
+fn list(selected: &[&Listing]) -> ExitCode {
+    for listing in selected {
+        let kind = match (listing.main.is_some(), listing.runs().is_empty()) {
+            (true, false) => "output",
+            (true, true) => "main",
+            (false, _) => "-",
+        };
+        println!("{:<6}  {}  {}", kind, listing.path, listing.title);
+    }
+    ExitCode::SUCCESS
+}
+
+fn run(selected: &[&Listing], options: &Options) -> ExitCode {
+    let path = options.filter.path.as_deref();
+    let listing = match selected {
+        [one] => *one,
+        _ => match selected.iter().find(|l| Some(l.path) == path) {
+            Some(exact) => *exact,
+            None => {
+                eprintln!("book-run: {} listings match, run needs exactly one", selected.len());
+                for listing in selected.iter().take(20) {
+                    eprintln!("    {}", listing.path);
+                }
+                return ExitCode::from(2);
+            }
+        },
+    };
+    let Some(main) = listing.main else {
+        eprintln!("book-run: {} has no main", listing.path);
+        return ExitCode::from(2);
+    };
+    if options.in_process {
+        return main();
+    }
+
+    let status = env::current_exe().and_then(|exe| {
+        Command::new(exe)
+            .args(&options.args)
+            .env(LISTING_VAR, listing.path)
+            .status()
+    });
+    match status {
+        Ok(status) if status.success() => ExitCode::SUCCESS,
+        Ok(status) => ExitCode::from(status.code().unwrap_or(1).clamp(1, 255) as u8),
+        Err(err) => {
+            eprintln!("book-run: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// How one verified listing did.
+enum Verdict {
+    Pass,
+    /// The command line and the diff of every run that did not match.
+    Fail(Vec<(String, String)>),
+    TimedOut(String),
+    Error(String),
+}
+
+fn verify(selected: &[&'static Listing], options: &Options) -> ExitCode {
+    let queue: Vec<(&Listing, Vec<Run>)> = selected
+        .iter()
+        .filter(|l| l.main.is_some())
+        .map(|l| (*l, l.runs()))
+        .filter(|(_, runs)| !runs.is_empty())
+        .collect();
+    let undocumented = selected.len() - queue.len();
+
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    let (mut passed, mut failed, mut timed_out) = (0, 0, 0);
+    thread::scope(|scope| {
+        for worker in 0..options.jobs.min(queue.len()) {
+            let tx = tx.clone();
+            let (queue, next) = (&queue, &next);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some((listing, runs)) = queue.get(i) else {
+                    break;
+                };
+                let dir = env::temp_dir().join(format!("book-run-{}-{worker}", std::process::id()));
+                let verdict = check(listing, runs, &dir, options.timeout);
+                let _ = fs::remove_dir_all(&dir);
+                if tx.send((i, verdict)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(tx);
+
+        // Workers finish out of order; report in book order.
+        let mut pending = BTreeMap::new();
+        let mut reported = 0;
+        for (i, verdict) in rx {
+            pending.insert(i, verdict);
+            while let Some(verdict) = pending.remove(&reported) {
+                let listing = queue[reported].0;
+                match &verdict {
+                    Verdict::Pass => passed += 1,
+                    Verdict::TimedOut(_) => timed_out += 1,
+                    Verdict::Fail(_) | Verdict::Error(_) => failed += 1,
+                }
+                report(listing, &verdict);
+                reported += 1;
+            }
+        }
+    });
+
+    println!(
+        "\n{passed} passed, {failed} failed, {timed_out} timed out; \
+         {undocumented} listings without documented output"
+    );
+    if failed + timed_out == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn report(listing: &Listing, verdict: &Verdict) {
+    let tag = match verdict {
+        Verdict::Pass => "PASS",
+        Verdict::Fail(_) => "FAIL",
+        Verdict::TimedOut(_) => "TIME",
+        Verdict::Error(_) => "ERR ",
+    };
+    println!("{tag}  {}  {}", listing.path, listing.title);
+    match verdict {
+        Verdict::Pass => {}
+        Verdict::Fail(diffs) => {
+            for (command, diff) in diffs {
+                println!("      $ {command}");
+                for line in diff.lines() {
+                    println!("      {line}");
+                }
+            }
+        }
+        Verdict::TimedOut(command) => println!("      $ {command}: still running, killed"),
+        Verdict::Error(err) => println!("      {err}"),
+    }
+}
+
+fn check(listing: &Listing, runs: &[Run], dir: &Path, timeout: Duration) -> Verdict {
+    let mut diffs = Vec::new();
+    for run in runs {
+        let command = describe(run);
+        let _ = fs::remove_dir_all(dir);
+        let written = fs::create_dir_all(dir)
+            .and_then(|()| FILES.iter().try_for_each(|(name, text)| fs::write(dir.join(name), text)));
+        if let Err(err) = written {
+            return Verdict::Error(format!("{}: {err}", dir.display()));
+        }
+        match spawn(listing, run, dir, timeout) {
+            Ok(Some(output)) => {
+                if let Some(diff) = runner::diff(&run.expected, &runner::program_text(&output)) {
+                    diffs.push((command, diff));
+                }
+            }
+            Ok(None) => return Verdict::TimedOut(command),
+            Err(err) => return Verdict::Error(format!("$ {command}: {err}")),
+        }
+    }
+    if diffs.is_empty() {
+        Verdict::Pass
+    } else {
+        Verdict::Fail(diffs)
+    }
+}
+
+/// Runs a listing in a child process in `dir` and returns what it printed,
+/// stdout and stderr interleaved as in a terminal, or `None` if it had to be
+/// killed.
+fn spawn(listing: &Listing, run: &Run, dir: &Path, timeout: Duration) -> io::Result<Option<String>> {
+    let mut command = Command::new(env::current_exe()?);
+    command
+        .args(&run.args)
+        .envs(run.env.iter().map(|(k, v)| (k, v)))
+        .env(LISTING_VAR, listing.path)
+        .current_dir(dir)
+        .stdin(Stdio::null());
+    let (mut output, writer) = io::pipe()?;
+    command.stdout(writer.try_clone()?).stderr(writer);
+    #[cfg(unix)]
+    if let Some(program) = &run.program {
+        use std::os::unix::process::CommandExt;
+        command.arg0(program);
+    }
+    let mut child = command.spawn()?;
+    // Our copies of the write end must go, or the reader never sees the end.
+    drop(command);
+
+    let reader = thread::spawn(move || {
+        let mut kept = Vec::new();
+        let mut buf = [0; 8192];
+        while let Ok(n) = output.read(&mut buf) {
+            if n == 0 {
+                break;
+            }
+            if kept.len() < CAPTURE_LIMIT {
+                kept.extend_from_slice(&buf[..n]);
+            }
+        }
+        String::from_utf8_lossy(&kept).into_owned()
+    });
+
+    let deadline = Instant::now() + timeout;
+    let finished = loop {
+        if child.try_wait()?.is_some() {
+            break true;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            break false;
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+    let output = reader.join().unwrap_or_default();
+    Ok(finished.then_some(output))
+}
+
+fn describe(run: &Run) -> String {
+    let mut words: Vec<String> = run.env.iter().map(|(k, v)| format!("{k}={v}")).collect();
+    words.push(run.program.clone().unwrap_or_else(|| "cargo run".to_string()));
+    if run.program.is_none() && !run.args.is_empty() {
+        words.push("--".to_string());
+    }
+    words.extend(run.args.iter().cloned());
+    words.join(" ")
+}
@@ -0,0 +1,26 @@
+//! Code examples from "The Rust Programming Language" and "Rust by Example".
+//!
+//! Every section of the books is a module and every listing in a section is a
+//! submodule of it: `listing_12_05` when the book numbers the listing,
+//! `example_NN` otherwise. Runnable listings expose their `main` as a `pub fn`.
+//!
+//! Listings that the books show failing to compile are kept as `compile_fail`
+//! doctests. Fragments that only make sense next to an earlier listing are
+//! doctests too, with the items they need in hidden lines, and programs that
+//! read their arguments or files are `no_run`. The few left as `ignore` are
+//! pseudo-code, or need files, crates or nightly features a doctest can't
+//! have.
+
+// The listings are reproduced the way the books print them, unused bindings
+// and unidiomatic spellings included.
+#[allow(unused, nonstandard_style, clippy::all)]
+#[allow(missing_abi, static_mut_refs, invalid_from_utf8, asm_sub_register)]
+#[path = "the_rust_programming_language/mod.rs"]
+pub mod trpl;
+
+#[allow(unused, nonstandard_style, clippy::all)]
+#[allow(missing_abi, static_mut_refs, invalid_from_utf8, asm_sub_register)]
+#[path = "rust_by_example/mod.rs"]
+pub mod rbe;
+
+pub mod runner;
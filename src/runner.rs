@@ -0,0 +1,538 @@
+//! Discovering listings and checking them against their documented output.
+//!
+//! The build script collects every listing module into [`LISTINGS`]. A listing
+//! whose body ends with a transcript comment, or is followed by one in the
+//! prose, such as
+//!
+//! ```text
+//! /*
+//!     Output:
+//!
+//!     1 + 2 = 3
+//! */
+//! ```
+//!
+//! or a `$ cargo run` session, has its expected output parsed by [`runs`].
+//! The `book-run` binary runs the listings and compares what they print with
+//! that text.
+
+use std::fmt::Write as _;
+use std::process::ExitCode;
+
+/// One listing module of the books.
+#[derive(Debug)]
+pub struct Listing {
+    /// Module path below the crate root, such as `rbe::macros::variadics::example_01`.
+    pub path: &'static str,
+    pub title: &'static str,
+    /// Source file, relative to the crate root.
+    pub file: &'static str,
+    /// Line of the `pub mod` declaration.
+    pub line: usize,
+    /// The comments where the output is documented: those closing the
+    /// listing's body, or the transcript in the prose that follows it.
+    pub notes: Option<&'static str>,
+    /// Runs the listing's `main`, for listings that have one.
+    pub main: Option<fn() -> ExitCode>,
+}
+
+/// Every listing of both books, in book order.
+// Most listings' `main` returns `()`, which the generated entries pass on to
+// `Termination::report`.
+#[allow(clippy::unit_arg)]
+pub static LISTINGS: &[Listing] = include!(concat!(env!("OUT_DIR"), "/listings.rs"));
+
+impl Listing {
+    /// `"trpl"` or `"rbe"`.
+    pub fn book(&self) -> &'static str {
+        self.path.split("::").next().unwrap()
+    }
+
+    /// Name of the chapter module, such as `ch12_minigrep` or `error_handling`.
+    pub fn chapter_name(&self) -> &'static str {
+        self.path.split("::").nth(1).unwrap()
+    }
+
+    /// Chapter number, taken from the section file name.
+    pub fn chapter(&self) -> u32 {
+        let name = self.file.rsplit('/').next().unwrap();
+        name.split('_').next().and_then(|n| n.parse().ok()).unwrap_or(0)
+    }
+
+    /// The book's `(chapter, listing)` number, for listings the book numbers.
+    pub fn number(&self) -> Option<(u32, u32)> {
+        let name = self.path.rsplit("::").next().unwrap();
+        let (chapter, listing) = name.strip_prefix("listing_")?.split_once('_')?;
+        Some((chapter.parse().ok()?, listing.parse().ok()?))
+    }
+
+    /// The documented runs of this listing.
+    pub fn runs(&self) -> Vec<Run> {
+        self.notes.map(runs).unwrap_or_default()
+    }
+}
+
+/// Looks a listing up by its full module path.
+pub fn find(path: &str) -> Option<&'static Listing> {
+    LISTINGS.iter().find(|l| l.path == path)
+}
+
+/// Selects listings by book, chapter, listing number, title or path.
+#[derive(Debug, Default)]
+pub struct Filter {
+    pub book: Option<String>,
+    /// A chapter number, or part of a chapter module name.
+    pub chapter: Option<String>,
+    /// A listing number such as `12-5`.
+    pub listing: Option<String>,
+    /// Part of the title, case-insensitive.
+    pub title: Option<String>,
+    /// Start of the module path.
+    pub path: Option<String>,
+}
+
+impl Filter {
+    pub fn matches(&self, listing: &Listing) -> bool {
+        if let Some(book) = &self.book {
+            if listing.book() != book {
+                return false;
+            }
+        }
+        if let Some(chapter) = &self.chapter {
+            let hit = match chapter.parse::<u32>() {
+                Ok(n) => listing.chapter() == n,
+                Err(_) => listing.chapter_name().contains(chapter.as_str()),
+            };
+            if !hit {
+                return false;
+            }
+        }
+        if let Some(number) = &self.listing {
+            let wanted = number
+                .split_once('-')
+                .and_then(|(c, l)| Some((c.parse().ok()?, l.parse().ok()?)));
+            if wanted.is_none() || listing.number() != wanted {
+                return false;
+            }
+        }
+        if let Some(title) = &self.title {
+            if !listing.title.to_lowercase().contains(&title.to_lowercase()) {
+                return false;
+            }
+        }
+        if let Some(path) = &self.path {
+            if !listing.path.starts_with(path.as_str()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// One documented invocation of a listing and the output it printed.
+#[derive(Debug, Default, PartialEq)]
+pub struct Run {
+    /// The program name the transcript ran, such as `./args`.
+    pub program: Option<String>,
+    pub args: Vec<String>,
+    pub env: Vec<(String, String)>,
+    pub expected: String,
+}
+
+/// Parses the documented runs out of a listing's closing comments.
+///
+/// A transcript made of `$ cargo run` or `$ ./program` sessions gives one run
+/// per session, with the arguments and environment of its command line and
+/// cargo's own progress lines left out; a session in which the program does
+/// not compile, as the book shows for code it goes on to fix, gives none. A
+/// comment starting with `Output` gives a single run without arguments.
+/// Anything else documents no run.
+pub fn runs(notes: &str) -> Vec<Run> {
+    let text = uncomment(notes);
+    let lines: Vec<&str> = text.lines().collect();
+    if !lines.iter().any(|l| l.starts_with("$ ")) {
+        let mut rest = lines.iter().skip_while(|l| l.trim().is_empty());
+        return match rest.next() {
+            Some(first) if first.trim_start().starts_with("Output") => {
+                let mut body: Vec<&str> = rest.copied().collect();
+                // The book's prose sometimes carries on after the output.
+                if let Some(k) = (1..body.len()).find(|&k| body[k - 1].is_empty() && is_prose(body[k])) {
+                    body.truncate(k);
+                }
+                if let Some(inline) = first.split_once(':').map(|(_, r)| r.trim()) {
+                    if !inline.is_empty() {
+                        body.insert(0, inline);
+                    }
+                }
+                vec![Run {
+                    expected: normalize(&body.join("\n")),
+                    ..Run::default()
+                }]
+            }
+            _ => Vec::new(),
+        };
+    }
+
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let Some(command) = lines[i].strip_prefix("$ ") else {
+            i += 1;
+            continue;
+        };
+        let end = (i + 1..lines.len())
+            .find(|&k| lines[k].starts_with("$ "))
+            .unwrap_or(lines.len());
+        let session = &lines[i + 1..end];
+        let compiles = !session.iter().any(|l| l.starts_with("error: could not compile"));
+        if let Some(mut run) = command_line(command).filter(|_| compiles) {
+            if run.program.is_none() {
+                run.program = session.iter().find_map(|l| {
+                    let rest = l.trim_start().strip_prefix("Running `")?;
+                    rest.split([' ', '`']).next().map(str::to_string)
+                });
+            }
+            run.expected = normalize(&program_output(session));
+            out.push(run);
+        }
+        i = end;
+    }
+    out
+}
+
+/// Whether a line reads like a sentence of the book rather than output.
+fn is_prose(line: &str) -> bool {
+    line.starts_with(|c: char| c.is_uppercase())
+        && line.ends_with(['.', ':'])
+        && line.split_whitespace().count() >= 8
+}
+
+/// Strips `/* */` and `//` markers and the indentation they share.
+fn uncomment(notes: &str) -> String {
+    let mut lines = Vec::new();
+    for line in notes.lines() {
+        let mut line = line;
+        let trimmed = line.trim_start();
+        if let Some(rest) = trimmed.strip_prefix("//") {
+            line = rest.strip_prefix(' ').unwrap_or(rest);
+        } else if let Some(rest) = trimmed.strip_prefix("/*") {
+            line = rest.strip_prefix(' ').unwrap_or(rest);
+        }
+        if let Some(rest) = line.trim_end().strip_suffix("*/") {
+            line = rest;
+        }
+        lines.push(line.trim_end());
+    }
+    let indent = lines
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|l| l.get(indent..).unwrap_or("").trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parses a transcript command line. Returns `None` for commands that do not
+/// run the program, or whose output is redirected.
+fn command_line(command: &str) -> Option<Run> {
+    if command.contains(['>', '|', '<']) || command.contains("&&") {
+        return None;
+    }
+    let words = split_words(command);
+    let mut words = words.into_iter().peekable();
+    let mut env = Vec::new();
+    while let Some((key, value)) = words.peek().and_then(|w| w.split_once('=')) {
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            break;
+        }
+        env.push((key.to_string(), value.to_string()));
+        words.next();
+    }
+    let program = words.next()?;
+    let (program, args) = if program == "cargo" {
+        if words.next()? != "run" {
+            return None;
+        }
+        let args = words.skip_while(|w| w != "--").skip(1).collect();
+        (None, args)
+    } else if program.starts_with("./") {
+        (Some(program), words.collect())
+    } else {
+        return None;
+    };
+    Some(Run {
+        program,
+        args,
+        env,
+        expected: String::new(),
+    })
+}
+
+/// Splits a command line into words, honouring single and double quotes.
+fn split_words(command: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut quote = None;
+    let mut in_word = false;
+    for c in command.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), c) => word.push(c),
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                in_word = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            (None, c) => {
+                word.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    words
+}
+
+/// Drops the lines of a transcript that the program did not print itself:
+/// cargo's progress and warnings, panic reports and backtraces.
+fn program_output(session: &[&str]) -> String {
+    const CARGO: [&str; 9] = [
+        "Compiling ",
+        "Finished ",
+        "Running ",
+        "Blocking ",
+        "Updating ",
+        "Downloading ",
+        "Downloaded ",
+        "Locking ",
+        "Adding ",
+    ];
+    let mut out = Vec::new();
+    let mut lines = session.iter();
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+        let indented = line.len() > trimmed.len();
+        if indented && CARGO.iter().any(|p| trimmed.starts_with(p)) {
+            continue;
+        }
+        if trimmed.starts_with("warning:") {
+            // A compiler warning runs until the next blank line.
+            for rest in lines.by_ref() {
+                if rest.trim().is_empty() {
+                    break;
+                }
+            }
+            continue;
+        }
+        if trimmed.starts_with("thread '") && trimmed.contains("panicked at") {
+            if trimmed.ends_with(':') {
+                lines.next();
+            }
+            continue;
+        }
+        if trimmed == "stack backtrace:" {
+            for rest in lines.by_ref() {
+                if rest.starts_with("note: ") {
+                    break;
+                }
+            }
+            continue;
+        }
+        if trimmed.starts_with("note: run with `RUST_BACKTRACE")
+            || trimmed.starts_with("error: process didn't exit successfully")
+        {
+            continue;
+        }
+        out.push(*line);
+    }
+    out.join("\n")
+}
+
+/// Applies the filtering done to documented sessions to what a listing
+/// actually printed, so panic reports do not count against it.
+pub fn program_text(output: &str) -> String {
+    program_output(&output.lines().collect::<Vec<_>>())
+}
+
+/// Trims trailing whitespace from every line and blank lines from both ends,
+/// and hides the source location in front of `dbg!` output, which differs
+/// between the book's `src/main.rs` and this crate.
+pub fn normalize(text: &str) -> String {
+    let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    let start = lines.iter().position(|l| !l.is_empty()).unwrap_or(lines.len());
+    let end = lines.iter().rposition(|l| !l.is_empty()).map_or(start, |e| e + 1);
+    lines[start..end]
+        .iter()
+        .map(|line| match line.strip_prefix('[').and_then(|l| l.split_once("] ")) {
+            Some((location, rest)) if location.contains(".rs:") => format!("[..] {rest}"),
+            _ => line.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns `None` if `actual` matches `expected` once both are normalized,
+/// otherwise a line diff with `-` for expected and `+` for actual lines.
+pub fn diff(expected: &str, actual: &str) -> Option<String> {
+    let expected = normalize(expected);
+    let actual = normalize(actual);
+    if expected == actual {
+        return None;
+    }
+    let a: Vec<&str> = expected.lines().collect();
+    let b: Vec<&str> = actual.lines().collect();
+
+    let mut out = String::new();
+    if a.len() * b.len() > 4_000_000 {
+        for line in &a {
+            writeln!(out, "-{line}").unwrap();
+        }
+        for line in &b {
+            writeln!(out, "+{line}").unwrap();
+        }
+        return Some(out);
+    }
+
+    // Longest common subsequence, filled from the end so the walk below can
+    // go forwards.
+    let mut lcs = vec![vec![0u32; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            writeln!(out, " {}", a[i]).unwrap();
+            i += 1;
+            j += 1;
+        } else if i < a.len() && (j == b.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            writeln!(out, "-{}", a[i]).unwrap();
+            i += 1;
+        } else {
+            writeln!(out, "+{}", b[j]).unwrap();
+            j += 1;
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_block() {
+        let notes = "/*\n    Output:\n\n    1 + 2 = 3\n    3 + 4 = 7\n*/";
+        assert_eq!(
+            runs(notes),
+            vec![Run {
+                expected: "1 + 2 = 3\n3 + 4 = 7".to_string(),
+                ..Run::default()
+            }]
+        );
+    }
+
+    #[test]
+    fn cargo_session() {
+        let notes = "/*\n$ cargo run -- needle haystack\n   Compiling minigrep v0.1.0 (file:///projects/minigrep)\n    Finished dev [unoptimized + debuginfo] target(s) in 0.0s\n     Running `target/debug/minigrep needle haystack`\nSearching for needle\nIn file haystack\n*/";
+        let runs = runs(notes);
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].args, ["needle", "haystack"]);
+        assert_eq!(runs[0].program.as_deref(), Some("target/debug/minigrep"));
+        assert_eq!(runs[0].expected, "Searching for needle\nIn file haystack");
+    }
+
+    #[test]
+    fn several_sessions_with_env() {
+        let notes = "/*\n    $ ./args 1 2\n    I got 2 arguments.\n    $ IGNORE_CASE=1 ./args 'a b'\n    I got 1 arguments.\n*/";
+        let runs = runs(notes);
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].program.as_deref(), Some("./args"));
+        assert_eq!(runs[0].args, ["1", "2"]);
+        assert_eq!(runs[1].env, [("IGNORE_CASE".to_string(), "1".to_string())]);
+        assert_eq!(runs[1].args, ["a b"]);
+        assert_eq!(runs[1].expected, "I got 1 arguments.");
+    }
+
+    #[test]
+    fn panics_and_redirects_are_not_program_output() {
+        let notes = "/*\n$ cargo run\nbefore\nthread 'main' panicked at src/main.rs:2:5:\ncrash and burn\nnote: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n$ cargo run > output.txt\n*/";
+        let runs = runs(notes);
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].expected, "before");
+
+        let printed = "before\nthread 'main' panicked at src/main.rs:2:5:\nboom\nstack backtrace:\n   0: main\nnote: Some details are omitted.";
+        assert_eq!(program_text(printed), "before");
+    }
+
+    #[test]
+    fn prose_after_output_is_dropped() {
+        let notes = "/*\nOutput:\n\n1 + 2 = 3\n\nThis was a very simple example, but much more is possible.\n*/";
+        assert_eq!(runs(notes)[0].expected, "1 + 2 = 3");
+    }
+
+    #[test]
+    fn compile_errors_document_no_run() {
+        let notes = "/*\n$ cargo run\n   Compiling threads v0.1.0 (file:///projects/threads)\nerror[E0382]: use of moved value: `v`\n\nerror: could not compile `threads` due to previous error\n*/";
+        assert!(runs(notes).is_empty());
+    }
+
+    #[test]
+    fn transcript_after_the_listing() {
+        // The book shows this listing's output in the prose after it.
+        let listing = find("trpl::ch15_smart_pointers::interior_mutability::listing_15_24").unwrap();
+        assert!(listing.main.is_some());
+        let runs = listing.runs();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].program.as_deref(), Some("target/debug/cons-list"));
+        assert_eq!(
+            runs[0].expected,
+            "a after = Cons(RefCell { value: 15 }, Nil)\n\
+             b after = Cons(RefCell { value: 3 }, Cons(RefCell { value: 15 }, Nil))\n\
+             c after = Cons(RefCell { value: 4 }, Cons(RefCell { value: 15 }, Nil))"
+        );
+    }
+
+    #[test]
+    fn prose_documents_no_run() {
+        assert!(runs("// This same technique can be used with Option.").is_empty());
+    }
+
+    #[test]
+    fn diff_marks_changed_lines() {
+        assert_eq!(diff("a\nb\n", "a\nb   \n\n"), None);
+        assert_eq!(diff("a\nb\nc", "a\nx\nc").unwrap(), " a\n-b\n+x\n c\n");
+        assert_eq!(diff("[src/main.rs:10] x = 1", "[src/book/5_2.rs:172:20] x = 1"), None);
+    }
+
+    #[test]
+    fn filters() {
+        let variadics = find("rbe::macros::variadics::example_01").unwrap();
+        assert!(variadics.main.is_some());
+        assert_eq!(variadics.chapter(), 17);
+        assert!(Filter { chapter: Some("macros".into()), ..Filter::default() }.matches(variadics));
+        assert!(!Filter { book: Some("trpl".into()), ..Filter::default() }.matches(variadics));
+
+        let listing = Filter { listing: Some("12-5".into()), ..Filter::default() };
+        let found: Vec<_> = LISTINGS.iter().filter(|l| listing.matches(l)).collect();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].number(), Some((12, 5)));
+    }
+}
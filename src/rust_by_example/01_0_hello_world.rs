@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/hello.html
 
 
+
+
 /*
     This is the source code of the traditional Hello World program.
 
@@ -15,17 +17,18 @@
     This is the main function
 */
 
-fn main() {
-    // Statements here are executed when the compiled binary is called
+pub mod example_01 {
+    pub fn main() {
+        // Statements here are executed when the compiled binary is called
 
-    // Print text to the console
-    println!("Hello World!");
+        // Print text to the console
+        println!("Hello World!");
+    }
 }
 
 
 
 
-
 /*
     println! is a macro that prints text to the console.
 
@@ -3,6 +3,7 @@
 
 
 
+
 /*
     Printing is handled by a series of macros defined in std::fmt some of which include:
 
@@ -15,66 +16,67 @@
     All parse text in the same fashion. As a plus, Rust checks formatting correctness at compile time.
 */
 
-
-fn main() {
-    // In general, the `{}` will be automatically replaced with any
-    // arguments. These will be stringified.
-    println!("{} days", 31);
-
-    // Positional arguments can be used. Specifying an integer inside `{}`
-    // determines which additional argument will be replaced. Arguments start
-    // at 0 immediately after the format string
-    println!("{0}, this is {1}. {1}, this is {0}", "Alice", "Bob");
-
-    // As can named arguments.
-    println!("{subject} {verb} {object}",
-             object="the lazy dog",
-             subject="the quick brown fox",
-             verb="jumps over");
-
-    // Different formatting can invoked by specified format character after a
-    // `:`.
-    println!("Base 10 repr:               {}",   69420);
-    println!("Base 2 (binary) repr:       {:b}", 69420);
-    println!("Base 8 (octal) repr:        {:o}", 69420);
-    println!("Base 16 (hexadecimal) repr: {:x}", 69420);
-    println!("Base 16 (hexadecimal) repr: {:X}", 69420);
-
-    // You can right-align text with a specified width. This will output
-    // "     1". 5 white spaces and a "1".
-    println!("{number:>5}", number=1);
-
-    // You can pad numbers with extra zeroes. This will output "000001".
-    println!("{number:0>5}", number=1);
-
-    // You can use named arguments in the format specifier by appending a `$`
-    println!("{number:0>width$}", number=1, width=5);
-
-
-    // Rust even checks to make sure the correct number of arguments are
-    // used.
-    println!("My name is {0}, {1} {0}", "Bond");
-    // FIXME ^ Add the missing argument: "James"
-
-    // Only types that implement fmt::Display can be formatted with `{}`. User-
-    // defined types to not implement fmt::Display by default
-
-    #[allow(dead_code)]
-    struct Structure(i32);
-
-    // This will not compile because `Structure` does not implement
-    // fmt::Display
-    println!("This struct `{}` won't print...", Structure(3));
-    // FIXME ^ Comment out this line.
-
-    // For Rust 1.58 and above, you can directly capture the argument from
-    // surrounding variable. Just like the above, this will output
-    // "     1". 5 white spaces and a "1".
-    let number: f64 = 1.0;
-    let width: usize = 6;
-    println!("{number:>width$}");
-}
-
+/// ```compile_fail
+/// fn main() {
+///     // In general, the `{}` will be automatically replaced with any
+///     // arguments. These will be stringified.
+///     println!("{} days", 31);
+///
+///     // Positional arguments can be used. Specifying an integer inside `{}`
+///     // determines which additional argument will be replaced. Arguments start
+///     // at 0 immediately after the format string
+///     println!("{0}, this is {1}. {1}, this is {0}", "Alice", "Bob");
+///
+///     // As can named arguments.
+///     println!("{subject} {verb} {object}",
+///              object="the lazy dog",
+///              subject="the quick brown fox",
+///              verb="jumps over");
+///
+///     // Different formatting can invoked by specified format character after a
+///     // `:`.
+///     println!("Base 10 repr:               {}",   69420);
+///     println!("Base 2 (binary) repr:       {:b}", 69420);
+///     println!("Base 8 (octal) repr:        {:o}", 69420);
+///     println!("Base 16 (hexadecimal) repr: {:x}", 69420);
+///     println!("Base 16 (hexadecimal) repr: {:X}", 69420);
+///
+///     // You can right-align text with a specified width. This will output
+///     // "     1". 5 white spaces and a "1".
+///     println!("{number:>5}", number=1);
+///
+///     // You can pad numbers with extra zeroes. This will output "000001".
+///     println!("{number:0>5}", number=1);
+///
+///     // You can use named arguments in the format specifier by appending a `$`
+///     println!("{number:0>width$}", number=1, width=5);
+///
+///
+///     // Rust even checks to make sure the correct number of arguments are
+///     // used.
+///     println!("My name is {0}, {1} {0}", "Bond");
+///     // FIXME ^ Add the missing argument: "James"
+///
+///     // Only types that implement fmt::Display can be formatted with `{}`. User-
+///     // defined types to not implement fmt::Display by default
+///
+///     #[allow(dead_code)]
+///     struct Structure(i32);
+///
+///     // This will not compile because `Structure` does not implement
+///     // fmt::Display
+///     println!("This struct `{}` won't print...", Structure(3));
+///     // FIXME ^ Comment out this line.
+///
+///     // For Rust 1.58 and above, you can directly capture the argument from
+///     // surrounding variable. Just like the above, this will output
+///     // "     1". 5 white spaces and a "1".
+///     let number: f64 = 1.0;
+///     let width: usize = 6;
+///     println!("{number:>width$}");
+/// }
+/// ```
+pub mod example_01 {}
 
 
 
@@ -89,4 +91,3 @@ fn main() {
 
     Implementing the fmt::Display trait automatically implements the ToString trait which allows us to convert the type to String.
 */
-
@@ -12,13 +12,15 @@
 
 // This structure cannot be printed either with `fmt::Display` or
 // with `fmt::Debug`.
-struct UnPrintable(i32);
 
-// The `derive` attribute automatically creates the implementation
-// required to make this `struct` printable with `fmt::Debug`.
-#[derive(Debug)]
-struct DebugPrintable(i32);
+pub mod example_01 {
+    struct UnPrintable(i32);
 
+    // The `derive` attribute automatically creates the implementation
+    // required to make this `struct` printable with `fmt::Debug`.
+    #[derive(Debug)]
+    struct DebugPrintable(i32);
+}
 
 
 
@@ -27,31 +29,32 @@ struct DebugPrintable(i32);
 
 // Derive the `fmt::Debug` implementation for `Structure`. `Structure`
 // is a structure which contains a single `i32`.
-#[derive(Debug)]
-struct Structure(i32);
-
-// Put a `Structure` inside of the structure `Deep`. Make it printable
-// also.
-#[derive(Debug)]
-struct Deep(Structure);
-
-fn main() {
-    // Printing with `{:?}` is similar to with `{}`.
-    println!("{:?} months in a year.", 12);
-    println!("{1:?} {0:?} is the {actor:?} name.",
-             "Slater",
-             "Christian",
-             actor="actor's");
-
-    // `Structure` is printable!
-    println!("Now {:?} will print!", Structure(3));
-    
-    // The problem with `derive` is there is no control over how
-    // the results look. What if I want this to just show a `7`?
-    println!("Now {:?} will print!", Deep(Structure(7)));
-}
-
 
+pub mod example_02 {
+    #[derive(Debug)]
+    struct Structure(i32);
+
+    // Put a `Structure` inside of the structure `Deep`. Make it printable
+    // also.
+    #[derive(Debug)]
+    struct Deep(Structure);
+
+    pub fn main() {
+        // Printing with `{:?}` is similar to with `{}`.
+        println!("{:?} months in a year.", 12);
+        println!("{1:?} {0:?} is the {actor:?} name.",
+                 "Slater",
+                 "Christian",
+                 actor="actor's");
+
+        // `Structure` is printable!
+        println!("Now {:?} will print!", Structure(3));
+
+        // The problem with `derive` is there is no control over how
+        // the results look. What if I want this to just show a `7`?
+        println!("Now {:?} will print!", Deep(Structure(7)));
+    }
+}
 
 
 
@@ -60,23 +63,24 @@ fn main() {
     So fmt::Debug definitely makes this printable but sacrifices some elegance. Rust also provides "pretty printing" with {:#?}.
 */
 
-#[derive(Debug)]
-struct Person<'a> {
-    name: &'a str,
-    age: u8
+pub mod example_03 {
+    #[derive(Debug)]
+    struct Person<'a> {
+        name: &'a str,
+        age: u8
+    }
+
+    pub fn main() {
+        let name = "Peter";
+        let age = 27;
+        let peter = Person { name, age };
+
+        // Pretty print
+        println!("{:#?}", peter);
+    }
 }
 
-fn main() {
-    let name = "Peter";
-    let age = 27;
-    let peter = Person { name, age };
-
-    // Pretty print
-    println!("{:#?}", peter);
-}
 
 
 
 // One can manually implement fmt::Display to control the display.
-
-
@@ -4,7 +4,6 @@
 
 
 
-
 /*
     Implementing fmt::Display for a structure where the elements must each be handled sequentially is tricky. The problem is that each write! generates a fmt::Result. Proper handling of this requires dealing with all the results. Rust provides the ? operator for exactly this purpose.
 
@@ -13,45 +12,51 @@
 
 // Try `write!` to see if it errors. If it errors, return
 // the error. Otherwise continue.
-write!(f, "{}", value)?;
-
-
 
+/// ```
+/// # use std::fmt;
+/// # fn fmt(f: &mut fmt::Formatter, value: i32) -> fmt::Result {
+/// write!(f, "{}", value)?;
+/// # Ok(())
+/// # }
+/// ```
+pub mod example_01 {}
 
 
 
 
 // With ? available, implementing fmt::Display for a Vec is straightforward:
 
-use std::fmt; // Import the `fmt` module.
+pub mod example_02 {
+    use std::fmt; // Import the `fmt` module.
 
-// Define a structure named `List` containing a `Vec`.
-struct List(Vec<i32>);
+    // Define a structure named `List` containing a `Vec`.
+    struct List(Vec<i32>);
 
-impl fmt::Display for List {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        // Extract the value using tuple indexing,
-        // and create a reference to `vec`.
-        let vec = &self.0;
+    impl fmt::Display for List {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            // Extract the value using tuple indexing,
+            // and create a reference to `vec`.
+            let vec = &self.0;
 
-        write!(f, "[")?;
+            write!(f, "[")?;
 
-        // Iterate over `v` in `vec` while enumerating the iteration
-        // count in `count`.
-        for (count, v) in vec.iter().enumerate() {
-            // For every element except the first, add a comma.
-            // Use the ? operator to return on errors.
-            if count != 0 { write!(f, ", ")?; }
-            write!(f, "{}", v)?;
-        }
+            // Iterate over `v` in `vec` while enumerating the iteration
+            // count in `count`.
+            for (count, v) in vec.iter().enumerate() {
+                // For every element except the first, add a comma.
+                // Use the ? operator to return on errors.
+                if count != 0 { write!(f, ", ")?; }
+                write!(f, "{}", v)?;
+            }
 
-        // Close the opened bracket and return a fmt::Result value.
-        write!(f, "]")
+            // Close the opened bracket and return a fmt::Result value.
+            write!(f, "]")
+        }
     }
-}
 
-fn main() {
-    let v = List(vec![1, 2, 3]);
-    println!("{}", v);
+    pub fn main() {
+        let v = List(vec![1, 2, 3]);
+        println!("{}", v);
+    }
 }
-
@@ -28,30 +28,31 @@
     Variables can always be type annotated. Numbers may additionally be annotated via a suffix or by default. Integers default to i32 and floats to f64. Note that Rust can also infer types from context.
 */
 
-fn main() {
-    // Variables can be type annotated.
-    let logical: bool = true;
-
-    let a_float: f64 = 1.0;  // Regular annotation
-    let an_integer   = 5i32; // Suffix annotation
-
-    // Or a default will be used.
-    let default_float   = 3.0; // `f64`
-    let default_integer = 7;   // `i32`
-    
-    // A type can also be inferred from context 
-    let mut inferred_type = 12; // Type i64 is inferred from another line
-    inferred_type = 4294967296i64;
-    
-    // A mutable variable's value can be changed.
-    let mut mutable = 12; // Mutable `i32`
-    mutable = 21;
-    
-    // Error! The type of a variable can't be changed.
-    mutable = true;
-    
-    // Variables can be overwritten with shadowing.
-    let mutable = true;
-}
-
-
+/// ```compile_fail
+/// fn main() {
+///     // Variables can be type annotated.
+///     let logical: bool = true;
+///
+///     let a_float: f64 = 1.0;  // Regular annotation
+///     let an_integer   = 5i32; // Suffix annotation
+///
+///     // Or a default will be used.
+///     let default_float   = 3.0; // `f64`
+///     let default_integer = 7;   // `i32`
+///
+///     // A type can also be inferred from context 
+///     let mut inferred_type = 12; // Type i64 is inferred from another line
+///     inferred_type = 4294967296i64;
+///
+///     // A mutable variable's value can be changed.
+///     let mut mutable = 12; // Mutable `i32`
+///     mutable = 21;
+///
+///     // Error! The type of a variable can't be changed.
+///     mutable = true;
+///
+///     // Variables can be overwritten with shadowing.
+///     let mutable = true;
+/// }
+/// ```
+pub mod example_01 {}
@@ -3,6 +3,7 @@
 
 
 
+
 /*
     Custom Types
 
@@ -17,13 +18,12 @@
 
 
 
-
-
-
 // Structures
 // https://doc.rust-lang.org/rust-by-example/custom_types/structs.html
 
 
+
+
 /*
     There are three types of structures ("structs") that can be created using the struct keyword:
 
@@ -34,79 +34,80 @@
 */
 
 // An attribute to hide warnings for unused code.
-#![allow(dead_code)]
 
-#[derive(Debug)]
-struct Person {
-    name: String,
-    age: u8,
-}
-
-// A unit struct
-struct Unit;
+pub mod example_01 {
+    #![allow(dead_code)]
 
-// A tuple struct
-struct Pair(i32, f32);
+    #[derive(Debug)]
+    struct Person {
+        name: String,
+        age: u8,
+    }
 
-// A struct with two fields
-struct Point {
-    x: f32,
-    y: f32,
-}
+    // A unit struct
+    struct Unit;
 
-// Structs can be reused as fields of another struct
-struct Rectangle {
-    // A rectangle can be specified by where the top left and bottom right
-    // corners are in space.
-    top_left: Point,
-    bottom_right: Point,
-}
+    // A tuple struct
+    struct Pair(i32, f32);
 
-fn main() {
-    // Create struct with field init shorthand
-    let name = String::from("Peter");
-    let age = 27;
-    let peter = Person { name, age };
+    // A struct with two fields
+    struct Point {
+        x: f32,
+        y: f32,
+    }
 
-    // Print debug struct
-    println!("{:?}", peter);
+    // Structs can be reused as fields of another struct
+    struct Rectangle {
+        // A rectangle can be specified by where the top left and bottom right
+        // corners are in space.
+        top_left: Point,
+        bottom_right: Point,
+    }
 
-    // Instantiate a `Point`
-    let point: Point = Point { x: 10.3, y: 0.4 };
+    pub fn main() {
+        // Create struct with field init shorthand
+        let name = String::from("Peter");
+        let age = 27;
+        let peter = Person { name, age };
 
-    // Access the fields of the point
-    println!("point coordinates: ({}, {})", point.x, point.y);
+        // Print debug struct
+        println!("{:?}", peter);
 
-    // Make a new point by using struct update syntax to use the fields of our
-    // other one
-    let bottom_right = Point { x: 5.2, ..point };
+        // Instantiate a `Point`
+        let point: Point = Point { x: 10.3, y: 0.4 };
 
-    // `bottom_right.y` will be the same as `point.y` because we used that field
-    // from `point`
-    println!("second point: ({}, {})", bottom_right.x, bottom_right.y);
+        // Access the fields of the point
+        println!("point coordinates: ({}, {})", point.x, point.y);
 
-    // Destructure the point using a `let` binding
-    let Point { x: left_edge, y: top_edge } = point;
+        // Make a new point by using struct update syntax to use the fields of our
+        // other one
+        let bottom_right = Point { x: 5.2, ..point };
 
-    let _rectangle = Rectangle {
-        // struct instantiation is an expression too
-        top_left: Point { x: left_edge, y: top_edge },
-        bottom_right: bottom_right,
-    };
+        // `bottom_right.y` will be the same as `point.y` because we used that field
+        // from `point`
+        println!("second point: ({}, {})", bottom_right.x, bottom_right.y);
 
-    // Instantiate a unit struct
-    let _unit = Unit;
+        // Destructure the point using a `let` binding
+        let Point { x: left_edge, y: top_edge } = point;
 
-    // Instantiate a tuple struct
-    let pair = Pair(1, 0.1);
+        let _rectangle = Rectangle {
+            // struct instantiation is an expression too
+            top_left: Point { x: left_edge, y: top_edge },
+            bottom_right: bottom_right,
+        };
 
-    // Access the fields of a tuple struct
-    println!("pair contains {:?} and {:?}", pair.0, pair.1);
+        // Instantiate a unit struct
+        let _unit = Unit;
 
-    // Destructure a tuple struct
-    let Pair(integer, decimal) = pair;
+        // Instantiate a tuple struct
+        let pair = Pair(1, 0.1);
 
-    println!("pair contains {:?} and {:?}", integer, decimal);
-}
+        // Access the fields of a tuple struct
+        println!("pair contains {:?} and {:?}", pair.0, pair.1);
 
+        // Destructure a tuple struct
+        let Pair(integer, decimal) = pair;
 
+        println!("pair contains {:?} and {:?}", integer, decimal);
+    }
+}
@@ -3,43 +3,46 @@
 
 
 
+
 // The use declaration can be used so manual scoping isn't needed:
 
 // An attribute to hide warnings for unused code.
-#![allow(dead_code)]
 
-enum Status {
-    Rich,
-    Poor,
-}
+pub mod example_01 {
+    #![allow(dead_code)]
 
-enum Work {
-    Civilian,
-    Soldier,
-}
+    enum Status {
+        Rich,
+        Poor,
+    }
 
-fn main() {
-    // Explicitly `use` each name so they are available without
-    // manual scoping.
-    use crate::Status::{Poor, Rich};
-    // Automatically `use` each name inside `Work`.
-    use crate::Work::*;
-
-    // Equivalent to `Status::Poor`.
-    let status = Poor;
-    // Equivalent to `Work::Civilian`.
-    let work = Civilian;
-
-    match status {
-        // Note the lack of scoping because of the explicit `use` above.
-        Rich => println!("The rich have lots of money!"),
-        Poor => println!("The poor have no money..."),
+    enum Work {
+        Civilian,
+        Soldier,
     }
 
-    match work {
-        // Note again the lack of scoping.
-        Civilian => println!("Civilians work!"),
-        Soldier  => println!("Soldiers fight!"),
+    pub fn main() {
+        // Explicitly `use` each name so they are available without
+        // manual scoping.
+        use crate::rbe::custom_types::enum_use::example_01::Status::{Poor, Rich};
+        // Automatically `use` each name inside `Work`.
+        use crate::rbe::custom_types::enum_use::example_01::Work::*;
+
+        // Equivalent to `Status::Poor`.
+        let status = Poor;
+        // Equivalent to `Work::Civilian`.
+        let work = Civilian;
+
+        match status {
+            // Note the lack of scoping because of the explicit `use` above.
+            Rich => println!("The rich have lots of money!"),
+            Poor => println!("The poor have no money..."),
+        }
+
+        match work {
+            // Note again the lack of scoping.
+            Civilian => println!("Civilians work!"),
+            Soldier  => println!("Soldiers fight!"),
+        }
     }
 }
-
@@ -2,75 +2,78 @@
 // https://doc.rust-lang.org/rust-by-example/custom_types/enum/testcase_linked_list.html
 
 
-// A common way to implement a linked-list is via enums:
 
 
-use crate::List::*;
+// A common way to implement a linked-list is via enums:
 
-enum List {
-    // Cons: Tuple struct that wraps an element and a pointer to the next node
-    Cons(u32, Box<List>),
-    // Nil: A node that signifies the end of the linked list
-    Nil,
-}
+pub mod example_01 {
+    use crate::rbe::custom_types::testcase_linked_list::example_01::List::*;
 
-// Methods can be attached to an enum
-impl List {
-    // Create an empty list
-    fn new() -> List {
-        // `Nil` has type `List`
-        Nil
+    enum List {
+        // Cons: Tuple struct that wraps an element and a pointer to the next node
+        Cons(u32, Box<List>),
+        // Nil: A node that signifies the end of the linked list
+        Nil,
     }
 
-    // Consume a list, and return the same list with a new element at its front
-    fn prepend(self, elem: u32) -> List {
-        // `Cons` also has type List
-        Cons(elem, Box::new(self))
-    }
+    // Methods can be attached to an enum
+    impl List {
+        // Create an empty list
+        fn new() -> List {
+            // `Nil` has type `List`
+            Nil
+        }
 
-    // Return the length of the list
-    fn len(&self) -> u32 {
-        // `self` has to be matched, because the behavior of this method
-        // depends on the variant of `self`
-        // `self` has type `&List`, and `*self` has type `List`, matching on a
-        // concrete type `T` is preferred over a match on a reference `&T`
-        // after Rust 2018 you can use self here and tail (with no ref) below as well,
-        // rust will infer &s and ref tail. 
-        // See https://doc.rust-lang.org/edition-guide/rust-2018/ownership-and-lifetimes/default-match-bindings.html
-        match *self {
-            // Can't take ownership of the tail, because `self` is borrowed;
-            // instead take a reference to the tail
-            Cons(_, ref tail) => 1 + tail.len(),
-            // Base Case: An empty list has zero length
-            Nil => 0
+        // Consume a list, and return the same list with a new element at its front
+        fn prepend(self, elem: u32) -> List {
+            // `Cons` also has type List
+            Cons(elem, Box::new(self))
+        }
+
+        // Return the length of the list
+        fn len(&self) -> u32 {
+            // `self` has to be matched, because the behavior of this method
+            // depends on the variant of `self`
+            // `self` has type `&List`, and `*self` has type `List`, matching on a
+            // concrete type `T` is preferred over a match on a reference `&T`
+            // after Rust 2018 you can use self here and tail (with no ref) below as well,
+            // rust will infer &s and ref tail. 
+            // See https://doc.rust-lang.org/edition-guide/rust-2018/ownership-and-lifetimes/default-match-bindings.html
+            match *self {
+                // Can't take ownership of the tail, because `self` is borrowed;
+                // instead take a reference to the tail
+                Cons(_, ref tail) => 1 + tail.len(),
+                // Base Case: An empty list has zero length
+                Nil => 0
+            }
         }
-    }
 
-    // Return representation of the list as a (heap allocated) string
-    fn stringify(&self) -> String {
-        match *self {
-            Cons(head, ref tail) => {
-                // `format!` is similar to `print!`, but returns a heap
-                // allocated string instead of printing to the console
-                format!("{}, {}", head, tail.stringify())
-            },
-            Nil => {
-                format!("Nil")
-            },
+        // Return representation of the list as a (heap allocated) string
+        fn stringify(&self) -> String {
+            match *self {
+                Cons(head, ref tail) => {
+                    // `format!` is similar to `print!`, but returns a heap
+                    // allocated string instead of printing to the console
+                    format!("{}, {}", head, tail.stringify())
+                },
+                Nil => {
+                    format!("Nil")
+                },
+            }
         }
     }
-}
 
-fn main() {
-    // Create an empty linked list
-    let mut list = List::new();
+    pub fn main() {
+        // Create an empty linked list
+        let mut list = List::new();
 
-    // Prepend some elements
-    list = list.prepend(1);
-    list = list.prepend(2);
-    list = list.prepend(3);
+        // Prepend some elements
+        list = list.prepend(1);
+        list = list.prepend(2);
+        list = list.prepend(3);
 
-    // Show the final state of the list
-    println!("linked list has length: {}", list.len());
-    println!("{}", list.stringify());
+        // Show the final state of the list
+        println!("linked list has length: {}", list.len());
+        println!("{}", list.stringify());
+    }
 }
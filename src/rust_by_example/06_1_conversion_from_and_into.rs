@@ -3,6 +3,7 @@
 
 
 
+
 /*
     Primitive types can be converted to each other through casting.
 
@@ -12,18 +13,19 @@
 
 
 
-
 // From and Into
 // https://doc.rust-lang.org/rust-by-example/conversion/from_into.html
 
 
 
+
 /*
     The From and Into traits are inherently linked, and this is actually part of its implementation. If you are able to convert type A from type B, then it should be easy to believe that we should be able to convert type B to type A.
 */
 
 
 
+
 /*
     From
 
@@ -32,42 +34,42 @@
     For example we can easily convert a str into a String
 */
 
-#![allow(unused)]
-fn main() {
-let my_str = "hello";
-let my_string = String::from(my_str);
+pub mod example_01 {
+    #![allow(unused)]
+    pub fn main() {
+    let my_str = "hello";
+    let my_string = String::from(my_str);
+    }
 }
 
 
 
 
-
 // We can do similar for defining a conversion for our own type.
 
-use std::convert::From;
+pub mod example_02 {
+    use std::convert::From;
 
-#[derive(Debug)]
-struct Number {
-    value: i32,
-}
+    #[derive(Debug)]
+    struct Number {
+        value: i32,
+    }
 
-impl From<i32> for Number {
-    fn from(item: i32) -> Self {
-        Number { value: item }
+    impl From<i32> for Number {
+        fn from(item: i32) -> Self {
+            Number { value: item }
+        }
     }
-}
 
-fn main() {
-    let num = Number::from(30);
-    println!("My number is {:?}", num);
+    pub fn main() {
+        let num = Number::from(30);
+        println!("My number is {:?}", num);
+    }
 }
 
 
 
 
-
-
-
 /*
     Into
 
@@ -76,25 +78,24 @@ fn main() {
     Using the Into trait will typically require specification of the type to convert into as the compiler is unable to determine this most of the time. However this is a small trade-off considering we get the functionality for free.
 */
 
+pub mod example_03 {
+    use std::convert::From;
 
-use std::convert::From;
-
-#[derive(Debug)]
-struct Number {
-    value: i32,
-}
+    #[derive(Debug)]
+    struct Number {
+        value: i32,
+    }
 
-impl From<i32> for Number {
-    fn from(item: i32) -> Self {
-        Number { value: item }
+    impl From<i32> for Number {
+        fn from(item: i32) -> Self {
+            Number { value: item }
+        }
     }
-}
 
-fn main() {
-    let int = 5;
-    // Try removing the type declaration
-    let num: Number = int.into();
-    println!("My number is {:?}", num);
+    pub fn main() {
+        let int = 5;
+        // Try removing the type declaration
+        let num: Number = int.into();
+        println!("My number is {:?}", num);
+    }
 }
-
-
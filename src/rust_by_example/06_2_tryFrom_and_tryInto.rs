@@ -2,42 +2,45 @@
 // https://doc.rust-lang.org/rust-by-example/conversion/try_from_try_into.html
 
 
+
+
 /*
     Similar to From and Into, TryFrom and TryInto are generic traits for converting between types. Unlike From/Into, the TryFrom/TryInto traits are used for fallible conversions, and as such, return Results.
 */
 
 
 
-use std::convert::TryFrom;
-use std::convert::TryInto;
 
-#[derive(Debug, PartialEq)]
-struct EvenNumber(i32);
+pub mod example_01 {
+    use std::convert::TryFrom;
+    use std::convert::TryInto;
 
-impl TryFrom<i32> for EvenNumber {
-    type Error = ();
+    #[derive(Debug, PartialEq)]
+    struct EvenNumber(i32);
 
-    fn try_from(value: i32) -> Result<Self, Self::Error> {
-        if value % 2 == 0 {
-            Ok(EvenNumber(value))
-        } else {
-            Err(())
+    impl TryFrom<i32> for EvenNumber {
+        type Error = ();
+
+        fn try_from(value: i32) -> Result<Self, Self::Error> {
+            if value % 2 == 0 {
+                Ok(EvenNumber(value))
+            } else {
+                Err(())
+            }
         }
     }
-}
 
-fn main() {
-    // TryFrom
+    pub fn main() {
+        // TryFrom
 
-    assert_eq!(EvenNumber::try_from(8), Ok(EvenNumber(8)));
-    assert_eq!(EvenNumber::try_from(5), Err(()));
+        assert_eq!(EvenNumber::try_from(8), Ok(EvenNumber(8)));
+        assert_eq!(EvenNumber::try_from(5), Err(()));
 
-    // TryInto
+        // TryInto
 
-    let result: Result<EvenNumber, ()> = 8i32.try_into();
-    assert_eq!(result, Ok(EvenNumber(8)));
-    let result: Result<EvenNumber, ()> = 5i32.try_into();
-    assert_eq!(result, Err(()));
+        let result: Result<EvenNumber, ()> = 8i32.try_into();
+        assert_eq!(result, Ok(EvenNumber(8)));
+        let result: Result<EvenNumber, ()> = 5i32.try_into();
+        assert_eq!(result, Err(()));
+    }
 }
-
-
@@ -1,6 +1,9 @@
 // Flow of Control
 // https://doc.rust-lang.org/rust-by-example/flow_control.html
 
+
+
+
 /*
     An essential part of any programming languages are ways to modify control flow: if/else, for, and others. Let's talk about them in Rust.
 */
@@ -8,42 +11,43 @@
 
 
 
-
 // if/else
 // https://doc.rust-lang.org/rust-by-example/flow_control/if_else.html
 
 
+
+
 /*
     Branching with if-else is similar to other languages. Unlike many of them, the boolean condition doesn't need to be surrounded by parentheses, and each condition is followed by a block. if-else conditionals are expressions, and, all branches must return the same type.
 */
 
+pub mod example_01 {
+    pub fn main() {
+        let n = 5;
 
-fn main() {
-    let n = 5;
-
-    if n < 0 {
-        print!("{} is negative", n);
-    } else if n > 0 {
-        print!("{} is positive", n);
-    } else {
-        print!("{} is zero", n);
-    }
+        if n < 0 {
+            print!("{} is negative", n);
+        } else if n > 0 {
+            print!("{} is positive", n);
+        } else {
+            print!("{} is zero", n);
+        }
 
-    let big_n =
-        if n < 10 && n > -10 {
-            println!(", and is a small number, increase ten-fold");
+        let big_n =
+            if n < 10 && n > -10 {
+                println!(", and is a small number, increase ten-fold");
 
-            // This expression returns an `i32`.
-            10 * n
-        } else {
-            println!(", and is a big number, halve the number");
+                // This expression returns an `i32`.
+                10 * n
+            } else {
+                println!(", and is a big number, halve the number");
 
-            // This expression must return an `i32` as well.
-            n / 2
-            // TODO ^ Try suppressing this expression with a semicolon.
-        };
-    //   ^ Don't forget to put a semicolon here! All `let` bindings need it.
+                // This expression must return an `i32` as well.
+                n / 2
+                // TODO ^ Try suppressing this expression with a semicolon.
+            };
+        //   ^ Don't forget to put a semicolon here! All `let` bindings need it.
 
-    println!("{} -> {}", n, big_n);
+        println!("{} -> {}", n, big_n);
+    }
 }
-
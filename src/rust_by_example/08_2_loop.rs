@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/flow_control/loop.html
 
 
+
+
 /*
     Rust provides a loop keyword to indicate an infinite loop.
 
@@ -10,29 +12,32 @@
 
 
 
-fn main() {
-    let mut count = 0u32;
 
-    println!("Let's count until infinity!");
+pub mod example_01 {
+    pub fn main() {
+        let mut count = 0u32;
 
-    // Infinite loop
-    loop {
-        count += 1;
+        println!("Let's count until infinity!");
 
-        if count == 3 {
-            println!("three");
+        // Infinite loop
+        loop {
+            count += 1;
 
-            // Skip the rest of this iteration
-            continue;
-        }
+            if count == 3 {
+                println!("three");
 
-        println!("{}", count);
+                // Skip the rest of this iteration
+                continue;
+            }
 
-        if count == 5 {
-            println!("OK, that's enough");
+            println!("{}", count);
 
-            // Exit this loop
-            break;
+            if count == 5 {
+                println!("OK, that's enough");
+
+                // Exit this loop
+                break;
+            }
         }
     }
 }
@@ -40,65 +45,65 @@ fn main() {
 
 
 
-
-
-
 // Nesting and labels
 // https://doc.rust-lang.org/rust-by-example/flow_control/loop/nested.html
 
 
+
+
 /*
     It's possible to break or continue outer loops when dealing with nested loops. In these cases, the loops must be annotated with some 'label, and the label must be passed to the break/continue statement.
 */
 
+pub mod example_02 {
+    #![allow(unreachable_code)]
 
-#![allow(unreachable_code)]
+    pub fn main() {
+        'outer: loop {
+            println!("Entered the outer loop");
 
-fn main() {
-    'outer: loop {
-        println!("Entered the outer loop");
+            'inner: loop {
+                println!("Entered the inner loop");
 
-        'inner: loop {
-            println!("Entered the inner loop");
+                // This would break only the inner loop
+                //break;
 
-            // This would break only the inner loop
-            //break;
+                // This breaks the outer loop
+                break 'outer;
+            }
 
-            // This breaks the outer loop
-            break 'outer;
+            println!("This point will never be reached");
         }
 
-        println!("This point will never be reached");
+        println!("Exited the outer loop");
     }
-
-    println!("Exited the outer loop");
 }
 
 
 
 
-
-
 // Returning from loops
 // https://doc.rust-lang.org/rust-by-example/flow_control/loop/return.html
 
 
+
+
 /*
     One of the uses of a loop is to retry an operation until it succeeds. If the operation returns a value though, you might need to pass it to the rest of the code: put it after the break, and it will be returned by the loop expression.
 */
 
+pub mod example_03 {
+    pub fn main() {
+        let mut counter = 0;
 
-fn main() {
-    let mut counter = 0;
+        let result = loop {
+            counter += 1;
 
-    let result = loop {
-        counter += 1;
+            if counter == 10 {
+                break counter * 2;
+            }
+        };
 
-        if counter == 10 {
-            break counter * 2;
-        }
-    };
-
-    assert_eq!(result, 20);
+        assert_eq!(result, 20);
+    }
 }
-
@@ -12,17 +12,19 @@
     Let's write FizzBuzz using for instead of while.
 */
 
-fn main() {
-    // `n` will take the values: 1, 2, ..., 100 in each iteration
-    for n in 1..101 {
-        if n % 15 == 0 {
-            println!("fizzbuzz");
-        } else if n % 3 == 0 {
-            println!("fizz");
-        } else if n % 5 == 0 {
-            println!("buzz");
-        } else {
-            println!("{}", n);
+pub mod example_01 {
+    pub fn main() {
+        // `n` will take the values: 1, 2, ..., 100 in each iteration
+        for n in 1..101 {
+            if n % 15 == 0 {
+                println!("fizzbuzz");
+            } else if n % 3 == 0 {
+                println!("fizz");
+            } else if n % 5 == 0 {
+                println!("buzz");
+            } else {
+                println!("{}", n);
+            }
         }
     }
 }
@@ -34,17 +36,19 @@ fn main() {
     Alternatively, a..=b can be used for a range that is inclusive on both ends. The above can be written as:
 */
 
-fn main() {
-    // `n` will take the values: 1, 2, ..., 100 in each iteration
-    for n in 1..=100 {
-        if n % 15 == 0 {
-            println!("fizzbuzz");
-        } else if n % 3 == 0 {
-            println!("fizz");
-        } else if n % 5 == 0 {
-            println!("buzz");
-        } else {
-            println!("{}", n);
+pub mod example_02 {
+    pub fn main() {
+        // `n` will take the values: 1, 2, ..., 100 in each iteration
+        for n in 1..=100 {
+            if n % 15 == 0 {
+                println!("fizzbuzz");
+            } else if n % 3 == 0 {
+                println!("fizz");
+            } else if n % 5 == 0 {
+                println!("buzz");
+            } else {
+                println!("{}", n);
+            }
         }
     }
 }
@@ -52,10 +56,6 @@ fn main() {
 
 
 
-
-
-
-
 /*
     for and iterators
 
@@ -65,42 +65,46 @@ fn main() {
 
         iter - This borrows each element of the collection through each iteration. Thus leaving the collection untouched and available for reuse after the loop.
 */
-fn main() {
-    let names = vec!["Bob", "Frank", "Ferris"];
-
-    for name in names.iter() {
-        match name {
-            &"Ferris" => println!("There is a rustacean among us!"),
-            // TODO ^ Try deleting the & and matching just "Ferris"
-            _ => println!("Hello {}", name),
+
+pub mod example_03 {
+    pub fn main() {
+        let names = vec!["Bob", "Frank", "Ferris"];
+
+        for name in names.iter() {
+            match name {
+                &"Ferris" => println!("There is a rustacean among us!"),
+                // TODO ^ Try deleting the & and matching just "Ferris"
+                _ => println!("Hello {}", name),
+            }
         }
+
+        println!("names: {:?}", names);
     }
-    
-    println!("names: {:?}", names);
 }
 
 
 
 
-
 /*
     into_iter - This consumes the collection so that on each iteration the exact data is provided. Once the collection has been consumed it is no longer available for reuse as it has been 'moved' within the loop.
 */
 
-fn main() {
-    let names = vec!["Bob", "Frank", "Ferris"];
-
-    for name in names.into_iter() {
-        match name {
-            "Ferris" => println!("There is a rustacean among us!"),
-            _ => println!("Hello {}", name),
-        }
-    }
-    
-    println!("names: {:?}", names);
-    // FIXME ^ Comment out this line
-}
-
+/// ```compile_fail
+/// fn main() {
+///     let names = vec!["Bob", "Frank", "Ferris"];
+///
+///     for name in names.into_iter() {
+///         match name {
+///             "Ferris" => println!("There is a rustacean among us!"),
+///             _ => println!("Hello {}", name),
+///         }
+///     }
+///
+///     println!("names: {:?}", names);
+///     // FIXME ^ Comment out this line
+/// }
+/// ```
+pub mod example_04 {}
 
 
 
@@ -108,22 +112,25 @@ fn main() {
 /*
     iter_mut - This mutably borrows each element of the collection, allowing for the collection to be modified in place.
 */
-fn main() {
-    let mut names = vec!["Bob", "Frank", "Ferris"];
 
-    for name in names.iter_mut() {
-        *name = match name {
-            &mut "Ferris" => "There is a rustacean among us!",
-            _ => "Hello",
+pub mod example_05 {
+    pub fn main() {
+        let mut names = vec!["Bob", "Frank", "Ferris"];
+
+        for name in names.iter_mut() {
+            *name = match name {
+                &mut "Ferris" => "There is a rustacean among us!",
+                _ => "Hello",
+            }
         }
-    }
 
-    println!("names: {:?}", names);
+        println!("names: {:?}", names);
+    }
 }
 
 
 
+
 /*
     In the above snippets note the type of match branch, that is the key difference in the types of iteration. The difference in type then of course implies differing actions that are able to be performed.
 */
-
@@ -3,22 +3,24 @@
 
 // A match guard can be added to filter the arm.
 
-enum Temperature {
-    Celsius(i32),
-    Farenheit(i32),
-}
+pub mod example_01 {
+    enum Temperature {
+        Celsius(i32),
+        Farenheit(i32),
+    }
 
-fn main() {
-    let temperature = Temperature::Celsius(35);
-    // ^ TODO try different values for `temperature`
+    pub fn main() {
+        let temperature = Temperature::Celsius(35);
+        // ^ TODO try different values for `temperature`
 
-    match temperature {
-        Temperature::Celsius(t) if t > 30 => println!("{}C is above 30 Celsius", t),
-        // The `if condition` part ^ is a guard
-        Temperature::Celsius(t) => println!("{}C is below 30 Celsius", t),
+        match temperature {
+            Temperature::Celsius(t) if t > 30 => println!("{}C is above 30 Celsius", t),
+            // The `if condition` part ^ is a guard
+            Temperature::Celsius(t) => println!("{}C is below 30 Celsius", t),
 
-        Temperature::Farenheit(t) if t > 86 => println!("{}F is above 86 Farenheit", t),
-        Temperature::Farenheit(t) => println!("{}F is below 86 Farenheit", t),
+            Temperature::Farenheit(t) if t > 86 => println!("{}F is above 86 Farenheit", t),
+            Temperature::Farenheit(t) => println!("{}F is below 86 Farenheit", t),
+        }
     }
 }
 
@@ -29,14 +31,16 @@ fn main() {
     Note that the compiler won't take guard conditions into account when checking if all patterns are covered by the match expression.
 */
 
-fn main() {
-    let number: u8 = 4;
-
-    match number {
-        i if i == 0 => println!("Zero"),
-        i if i > 0 => println!("Greater than zero"),
-        // _ => unreachable!("Should never happen."),
-        // TODO ^ uncomment to fix compilation
-    }
-}
-
+/// ```compile_fail
+/// fn main() {
+///     let number: u8 = 4;
+///
+///     match number {
+///         i if i == 0 => println!("Zero"),
+///         i if i > 0 => println!("Greater than zero"),
+///         // _ => unreachable!("Should never happen."),
+///         // TODO ^ uncomment to fix compilation
+///     }
+/// }
+/// ```
+pub mod example_02 {}
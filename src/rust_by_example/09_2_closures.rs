@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/fn/closures.html
 
 
+
+
 /*
     Closures are functions that can capture the enclosing environment. For example, a closure that captures the x variable:
 
@@ -16,41 +18,41 @@
         the ability to capture the outer environment variables.
 */
 
-
-fn main() {
-    // Increment via closures and functions.
-    fn function(i: i32) -> i32 { i + 1 }
-
-    // Closures are anonymous, here we are binding them to references
-    // Annotation is identical to function annotation but is optional
-    // as are the `{}` wrapping the body. These nameless functions
-    // are assigned to appropriately named variables.
-    let closure_annotated = |i: i32| -> i32 { i + 1 };
-    let closure_inferred  = |i     |          i + 1  ;
-
-    let i = 1;
-    // Call the function and closures.
-    println!("function: {}", function(i));
-    println!("closure_annotated: {}", closure_annotated(i));
-    println!("closure_inferred: {}", closure_inferred(i));
-
-    // A closure taking no arguments which returns an `i32`.
-    // The return type is inferred.
-    let one = || 1;
-    println!("closure returning one: {}", one());
-
+pub mod example_01 {
+    pub fn main() {
+        // Increment via closures and functions.
+        fn function(i: i32) -> i32 { i + 1 }
+
+        // Closures are anonymous, here we are binding them to references
+        // Annotation is identical to function annotation but is optional
+        // as are the `{}` wrapping the body. These nameless functions
+        // are assigned to appropriately named variables.
+        let closure_annotated = |i: i32| -> i32 { i + 1 };
+        let closure_inferred  = |i     |          i + 1  ;
+
+        let i = 1;
+        // Call the function and closures.
+        println!("function: {}", function(i));
+        println!("closure_annotated: {}", closure_annotated(i));
+        println!("closure_inferred: {}", closure_inferred(i));
+
+        // A closure taking no arguments which returns an `i32`.
+        // The return type is inferred.
+        let one = || 1;
+        println!("closure returning one: {}", one());
+
+    }
 }
 
 
 
 
-
-
 // Capturing
 // https://doc.rust-lang.org/rust-by-example/fn/closures/capture.html
 
 
 
+
 /*
     Closures are inherently flexible and will do what the functionality requires to make the closure work without annotation. This allows capturing to flexibly adapt to the use case, sometimes moving and sometimes borrowing. Closures can capture variables:
 
@@ -61,107 +63,102 @@ fn main() {
     They preferentially capture variables by reference and only go lower when required.
 */
 
-fn main() {
-    use std::mem;
-    
-    let color = String::from("green");
-
-    // A closure to print `color` which immediately borrows (`&`) `color` and
-    // stores the borrow and closure in the `print` variable. It will remain
-    // borrowed until `print` is used the last time. 
-    //
-    // `println!` only requires arguments by immutable reference so it doesn't
-    // impose anything more restrictive.
-    let print = || println!("`color`: {}", color);
-
-    // Call the closure using the borrow.
-    print();
-
-    // `color` can be borrowed immutably again, because the closure only holds
-    // an immutable reference to `color`. 
-    let _reborrow = &color;
-    print();
-
-    // A move or reborrow is allowed after the final use of `print`
-    let _color_moved = color;
-
-
-    let mut count = 0;
-    // A closure to increment `count` could take either `&mut count` or `count`
-    // but `&mut count` is less restrictive so it takes that. Immediately
-    // borrows `count`.
-    //
-    // A `mut` is required on `inc` because a `&mut` is stored inside. Thus,
-    // calling the closure mutates the closure which requires a `mut`.
-    let mut inc = || {
-        count += 1;
-        println!("`count`: {}", count);
-    };
-
-    // Call the closure using a mutable borrow.
-    inc();
-
-    // The closure still mutably borrows `count` because it is called later.
-    // An attempt to reborrow will lead to an error.
-    // let _reborrow = &count; 
-    // ^ TODO: try uncommenting this line.
-    inc();
-
-    // The closure no longer needs to borrow `&mut count`. Therefore, it is
-    // possible to reborrow without an error
-    let _count_reborrowed = &mut count; 
-
-    
-    // A non-copy type.
-    let movable = Box::new(3);
-
-    // `mem::drop` requires `T` so this must take by value. A copy type
-    // would copy into the closure leaving the original untouched.
-    // A non-copy must move and so `movable` immediately moves into
-    // the closure.
-    let consume = || {
-        println!("`movable`: {:?}", movable);
-        mem::drop(movable);
-    };
-
-    // `consume` consumes the variable so this can only be called once.
-    consume();
-    // consume();
-    // ^ TODO: Try uncommenting this line.
+pub mod example_02 {
+    pub fn main() {
+        use std::mem;
+
+        let color = String::from("green");
+
+        // A closure to print `color` which immediately borrows (`&`) `color` and
+        // stores the borrow and closure in the `print` variable. It will remain
+        // borrowed until `print` is used the last time. 
+        //
+        // `println!` only requires arguments by immutable reference so it doesn't
+        // impose anything more restrictive.
+        let print = || println!("`color`: {}", color);
+
+        // Call the closure using the borrow.
+        print();
+
+        // `color` can be borrowed immutably again, because the closure only holds
+        // an immutable reference to `color`. 
+        let _reborrow = &color;
+        print();
+
+        // A move or reborrow is allowed after the final use of `print`
+        let _color_moved = color;
+
+
+        let mut count = 0;
+        // A closure to increment `count` could take either `&mut count` or `count`
+        // but `&mut count` is less restrictive so it takes that. Immediately
+        // borrows `count`.
+        //
+        // A `mut` is required on `inc` because a `&mut` is stored inside. Thus,
+        // calling the closure mutates the closure which requires a `mut`.
+        let mut inc = || {
+            count += 1;
+            println!("`count`: {}", count);
+        };
+
+        // Call the closure using a mutable borrow.
+        inc();
+
+        // The closure still mutably borrows `count` because it is called later.
+        // An attempt to reborrow will lead to an error.
+        // let _reborrow = &count; 
+        // ^ TODO: try uncommenting this line.
+        inc();
+
+        // The closure no longer needs to borrow `&mut count`. Therefore, it is
+        // possible to reborrow without an error
+        let _count_reborrowed = &mut count; 
+
+
+        // A non-copy type.
+        let movable = Box::new(3);
+
+        // `mem::drop` requires `T` so this must take by value. A copy type
+        // would copy into the closure leaving the original untouched.
+        // A non-copy must move and so `movable` immediately moves into
+        // the closure.
+        let consume = || {
+            println!("`movable`: {:?}", movable);
+            mem::drop(movable);
+        };
+
+        // `consume` consumes the variable so this can only be called once.
+        consume();
+        // consume();
+        // ^ TODO: Try uncommenting this line.
+    }
 }
 
 
 
 
-
 // Using move before vertical pipes forces closure to take ownership of captured variables:
 
-fn main() {
-    // `Vec` has non-copy semantics.
-    let haystack = vec![1, 2, 3];
-
-    let contains = move |needle| haystack.contains(needle);
-
-    println!("{}", contains(&1));
-    println!("{}", contains(&4));
-
-    // println!("There're {} elements in vec", haystack.len());
-    // ^ Uncommenting above line will result in compile-time error
-    // because borrow checker doesn't allow re-using variable after it
-    // has been moved.
-    
-    // Removing `move` from closure's signature will cause closure
-    // to borrow _haystack_ variable immutably, hence _haystack_ is still
-    // available and uncommenting above line will not cause an error.
-}
-
-
-
-
+pub mod example_03 {
+    pub fn main() {
+        // `Vec` has non-copy semantics.
+        let haystack = vec![1, 2, 3];
 
+        let contains = move |needle| haystack.contains(needle);
 
+        println!("{}", contains(&1));
+        println!("{}", contains(&4));
 
+        // println!("There're {} elements in vec", haystack.len());
+        // ^ Uncommenting above line will result in compile-time error
+        // because borrow checker doesn't allow re-using variable after it
+        // has been moved.
 
+        // Removing `move` from closure's signature will cause closure
+        // to borrow _haystack_ variable immutably, hence _haystack_ is still
+        // available and uncommenting above line will not cause an error.
+    }
+}
 
 
 
@@ -170,6 +167,8 @@ fn main() {
 // https://doc.rust-lang.org/rust-by-example/fn/closures/input_parameters.html
 
 
+
+
 /*
     While Rust chooses how to capture variables on the fly mostly without type annotation, this ambiguity is not allowed when writing functions. When taking a closure as an input parameter, the closure's complete type must be annotated using one of a few traits, and they're determined by what the closure does with captured value. In order of decreasing restriction, they are:
 
@@ -187,63 +186,62 @@ fn main() {
 */
 
 
-// A function which takes a closure as an argument and calls it.
-// <F> denotes that F is a "Generic type parameter"
-fn apply<F>(f: F) where
-    // The closure takes no input and returns nothing.
-    F: FnOnce() {
-    // ^ TODO: Try changing this to `Fn` or `FnMut`.
-
-    f();
-}
-
-// A function which takes a closure and returns an `i32`.
-fn apply_to_3<F>(f: F) -> i32 where
-    // The closure takes an `i32` and returns an `i32`.
-    F: Fn(i32) -> i32 {
-
-    f(3)
-}
 
-fn main() {
-    use std::mem;
 
-    let greeting = "hello";
-    // A non-copy type.
-    // `to_owned` creates owned data from borrowed one
-    let mut farewell = "goodbye".to_owned();
+// A function which takes a closure as an argument and calls it.
+// <F> denotes that F is a "Generic type parameter"
 
-    // Capture 2 variables: `greeting` by reference and
-    // `farewell` by value.
-    let diary = || {
-        // `greeting` is by reference: requires `Fn`.
-        println!("I said {}.", greeting);
+pub mod example_04 {
+    fn apply<F>(f: F) where
+        // The closure takes no input and returns nothing.
+        F: FnOnce() {
+        // ^ TODO: Try changing this to `Fn` or `FnMut`.
 
-        // Mutation forces `farewell` to be captured by
-        // mutable reference. Now requires `FnMut`.
-        farewell.push_str("!!!");
-        println!("Then I screamed {}.", farewell);
-        println!("Now I can sleep. zzzzz");
+        f();
+    }
 
-        // Manually calling drop forces `farewell` to
-        // be captured by value. Now requires `FnOnce`.
-        mem::drop(farewell);
-    };
+    // A function which takes a closure and returns an `i32`.
+    fn apply_to_3<F>(f: F) -> i32 where
+        // The closure takes an `i32` and returns an `i32`.
+        F: Fn(i32) -> i32 {
 
-    // Call the function which applies the closure.
-    apply(diary);
+        f(3)
+    }
 
-    // `double` satisfies `apply_to_3`'s trait bound
-    let double = |x| 2 * x;
+    pub fn main() {
+        use std::mem;
 
-    println!("3 doubled: {}", apply_to_3(double));
-}
+        let greeting = "hello";
+        // A non-copy type.
+        // `to_owned` creates owned data from borrowed one
+        let mut farewell = "goodbye".to_owned();
 
+        // Capture 2 variables: `greeting` by reference and
+        // `farewell` by value.
+        let diary = || {
+            // `greeting` is by reference: requires `Fn`.
+            println!("I said {}.", greeting);
 
+            // Mutation forces `farewell` to be captured by
+            // mutable reference. Now requires `FnMut`.
+            farewell.push_str("!!!");
+            println!("Then I screamed {}.", farewell);
+            println!("Now I can sleep. zzzzz");
 
+            // Manually calling drop forces `farewell` to
+            // be captured by value. Now requires `FnOnce`.
+            mem::drop(farewell);
+        };
 
+        // Call the function which applies the closure.
+        apply(diary);
 
+        // `double` satisfies `apply_to_3`'s trait bound
+        let double = |x| 2 * x;
 
+        println!("3 doubled: {}", apply_to_3(double));
+    }
+}
 
 
 
@@ -252,22 +250,26 @@ fn main() {
 // https://doc.rust-lang.org/rust-by-example/fn/closures/anonymity.html
 
 
+
+
 /*
     Closures succinctly capture variables from enclosing scopes. Does this have any consequences? It surely does. Observe how using a closure as a function parameter requires generics, which is necessary because of how they are defined:
 */
 
-
-#![allow(unused)]
-fn main() {
-// `F` must be generic.
-fn apply<F>(f: F) where
-    F: FnOnce() {
-    f();
-}
+pub mod example_05 {
+    #![allow(unused)]
+    pub fn main() {
+    // `F` must be generic.
+    fn apply<F>(f: F) where
+        F: FnOnce() {
+        f();
+    }
+    }
 }
 
 
 
+
 /*
     When a closure is defined, the compiler implicitly creates a new anonymous structure to store the captured variables inside, meanwhile implementing the functionality via one of the traits: Fn, FnMut, or FnOnce for this unknown type. This type is assigned to the variable which is stored until calling.
 
@@ -275,27 +277,28 @@ fn apply<F>(f: F) where
 */
 
 
+
+
 // `F` must implement `Fn` for a closure which takes no
 // inputs and returns nothing - exactly what is required
 // for `print`.
-fn apply<F>(f: F) where
-    F: Fn() {
-    f();
-}
-
-fn main() {
-    let x = 7;
-
-    // Capture `x` into an anonymous type and implement
-    // `Fn` for it. Store it in `print`.
-    let print = || println!("{}", x);
-
-    apply(print);
-}
 
+pub mod example_06 {
+    fn apply<F>(f: F) where
+        F: Fn() {
+        f();
+    }
 
+    pub fn main() {
+        let x = 7;
 
+        // Capture `x` into an anonymous type and implement
+        // `Fn` for it. Store it in `print`.
+        let print = || println!("{}", x);
 
+        apply(print);
+    }
+}
 
 
 
@@ -304,30 +307,37 @@ fn main() {
 // https://doc.rust-lang.org/rust-by-example/fn/closures/input_functions.html
 
 
+
+
 /*
     Since closures may be used as arguments, you might wonder if the same can be said about functions. And indeed they can! If you declare a function that takes a closure as parameter, then any function that satisfies the trait bound of that closure can be passed as a parameter.
 */
 
 // Define a function which takes a generic `F` argument
 // bounded by `Fn`, and calls it
-fn call_me<F: Fn()>(f: F) {
-    f();
-}
 
-// Define a wrapper function satisfying the `Fn` bound
-fn function() {
-    println!("I'm a function!");
-}
+pub mod example_07 {
+    fn call_me<F: Fn()>(f: F) {
+        f();
+    }
+
+    // Define a wrapper function satisfying the `Fn` bound
+    fn function() {
+        println!("I'm a function!");
+    }
 
-fn main() {
-    // Define a closure satisfying the `Fn` bound
-    let closure = || println!("I'm a closure!");
+    pub fn main() {
+        // Define a closure satisfying the `Fn` bound
+        let closure = || println!("I'm a closure!");
 
-    call_me(closure);
-    call_me(function);
+        call_me(closure);
+        call_me(function);
+    }
 }
 
 
+
+
 /*
     As an additional note, the Fn, FnMut, and FnOnce traits dictate how a closure captures variables from the enclosing scope.
 */
@@ -335,14 +345,12 @@ fn main() {
 
 
 
-
-
-
-
 // As output parameters
 // https://doc.rust-lang.org/rust-by-example/fn/closures/output_parameters.html
 
 
+
+
 /*
     Closures as input parameters are possible, so returning closures as output parameters should also be possible. However, anonymous closure types are, by definition, unknown, so we have to use impl Trait to return them.
 
@@ -355,41 +363,39 @@ fn main() {
     Beyond this, the move keyword must be used, which signals that all captures occur by value. This is required because any captures by reference would be dropped as soon as the function exited, leaving invalid references in the closure.
 */
 
-fn create_fn() -> impl Fn() {
-    let text = "Fn".to_owned();
+pub mod example_08 {
+    fn create_fn() -> impl Fn() {
+        let text = "Fn".to_owned();
 
-    move || println!("This is a: {}", text)
-}
+        move || println!("This is a: {}", text)
+    }
 
-fn create_fnmut() -> impl FnMut() {
-    let text = "FnMut".to_owned();
+    fn create_fnmut() -> impl FnMut() {
+        let text = "FnMut".to_owned();
 
-    move || println!("This is a: {}", text)
-}
+        move || println!("This is a: {}", text)
+    }
 
-fn create_fnonce() -> impl FnOnce() {
-    let text = "FnOnce".to_owned();
+    fn create_fnonce() -> impl FnOnce() {
+        let text = "FnOnce".to_owned();
 
-    move || println!("This is a: {}", text)
-}
+        move || println!("This is a: {}", text)
+    }
 
-fn main() {
-    let fn_plain = create_fn();
-    let mut fn_mut = create_fnmut();
-    let fn_once = create_fnonce();
+    pub fn main() {
+        let fn_plain = create_fn();
+        let mut fn_mut = create_fnmut();
+        let fn_once = create_fnonce();
 
-    fn_plain();
-    fn_mut();
-    fn_once();
+        fn_plain();
+        fn_mut();
+        fn_once();
+    }
 }
 
 
 
 
-
-
-
-
 // Examples in std
 // https://doc.rust-lang.org/rust-by-example/fn/closures/closure_examples.html
 
@@ -401,95 +407,109 @@ fn main() {
 // Iterator::any
 // https://doc.rust-lang.org/rust-by-example/fn/closures/closure_examples/iter_any.html
 
+
+
+
 /*
     Iterator::any is a function which when passed an iterator, will return true if any element satisfies the predicate. Otherwise false. Its signature:
 */
 
-
-pub trait Iterator {
-    // The type being iterated over.
-    type Item;
-
-    // `any` takes `&mut self` meaning the caller may be borrowed
-    // and modified, but not consumed.
-    fn any<F>(&mut self, f: F) -> bool where
-        // `FnMut` meaning any captured variable may at most be
-        // modified, not consumed. `Self::Item` states it takes
-        // arguments to the closure by value.
-        F: FnMut(Self::Item) -> bool {}
+/// ```compile_fail
+/// pub trait Iterator {
+///     // The type being iterated over.
+///     type Item;
+///
+///     // `any` takes `&mut self` meaning the caller may be borrowed
+///     // and modified, but not consumed.
+///     fn any<F>(&mut self, f: F) -> bool where
+///         // `FnMut` meaning any captured variable may at most be
+///         // modified, not consumed. `Self::Item` states it takes
+///         // arguments to the closure by value.
+///         F: FnMut(Self::Item) -> bool {}
+/// }
+/// ```
+pub mod example_09 {}
+
+
+
+
+pub mod example_10 {
+    pub fn main() {
+        let vec1 = vec![1, 2, 3];
+        let vec2 = vec![4, 5, 6];
+
+        // `iter()` for vecs yields `&i32`. Destructure to `i32`.
+        println!("2 in vec1: {}", vec1.iter()     .any(|&x| x == 2));
+        // `into_iter()` for vecs yields `i32`. No destructuring required.
+        println!("2 in vec2: {}", vec2.into_iter().any(| x| x == 2));
+
+        let array1 = [1, 2, 3];
+        let array2 = [4, 5, 6];
+
+        // `iter()` for arrays yields `&i32`.
+        println!("2 in array1: {}", array1.iter()     .any(|&x| x == 2));
+        // `into_iter()` for arrays yields `i32`.
+        println!("2 in array2: {}", array2.into_iter().any(|x| x == 2));
+    }
 }
 
 
 
 
-fn main() {
-    let vec1 = vec![1, 2, 3];
-    let vec2 = vec![4, 5, 6];
-
-    // `iter()` for vecs yields `&i32`. Destructure to `i32`.
-    println!("2 in vec1: {}", vec1.iter()     .any(|&x| x == 2));
-    // `into_iter()` for vecs yields `i32`. No destructuring required.
-    println!("2 in vec2: {}", vec2.into_iter().any(| x| x == 2));
-
-    let array1 = [1, 2, 3];
-    let array2 = [4, 5, 6];
-
-    // `iter()` for arrays yields `&i32`.
-    println!("2 in array1: {}", array1.iter()     .any(|&x| x == 2));
-    // `into_iter()` for arrays yields `i32`.
-    println!("2 in array2: {}", array2.into_iter().any(|x| x == 2));
-}
-
-
-
+// Searching through iterators
+// https://doc.rust-lang.org/rust-by-example/fn/closures/closure_examples/iter_find.html
 
 
 
-// Searching through iterators
-// https://doc.rust-lang.org/rust-by-example/fn/closures/closure_examples/iter_find.html
 
 /*
     Iterator::find is a function which iterates over an iterator and searches for the first value which satisfies some condition. If none of the values satisfy the condition, it returns None. Its signature:
 */
 
-pub trait Iterator {
-    // The type being iterated over.
-    type Item;
-
-    // `find` takes `&mut self` meaning the caller may be borrowed
-    // and modified, but not consumed.
-    fn find<P>(&mut self, predicate: P) -> Option<Self::Item> where
-        // `FnMut` meaning any captured variable may at most be
-        // modified, not consumed. `&Self::Item` states it takes
-        // arguments to the closure by reference.
-        P: FnMut(&Self::Item) -> bool {}
-}
-
-
-
-fn main() {
-    let vec1 = vec![1, 2, 3];
-    let vec2 = vec![4, 5, 6];
-
-    // `iter()` for vecs yields `&i32`.
-    let mut iter = vec1.iter();
-    // `into_iter()` for vecs yields `i32`.
-    let mut into_iter = vec2.into_iter();
-
-    // `iter()` for vecs yields `&i32`, and we want to reference one of its
-    // items, so we have to destructure `&&i32` to `i32`
-    println!("Find 2 in vec1: {:?}", iter     .find(|&&x| x == 2));
-    // `into_iter()` for vecs yields `i32`, and we want to reference one of
-    // its items, so we have to destructure `&i32` to `i32`
-    println!("Find 2 in vec2: {:?}", into_iter.find(| &x| x == 2));
-
-    let array1 = [1, 2, 3];
-    let array2 = [4, 5, 6];
-
-    // `iter()` for arrays yields `&i32`
-    println!("Find 2 in array1: {:?}", array1.iter()     .find(|&&x| x == 2));
-    // `into_iter()` for arrays yields `i32`
-    println!("Find 2 in array2: {:?}", array2.into_iter().find(|&x| x == 2));
+/// ```compile_fail
+/// pub trait Iterator {
+///     // The type being iterated over.
+///     type Item;
+///
+///     // `find` takes `&mut self` meaning the caller may be borrowed
+///     // and modified, but not consumed.
+///     fn find<P>(&mut self, predicate: P) -> Option<Self::Item> where
+///         // `FnMut` meaning any captured variable may at most be
+///         // modified, not consumed. `&Self::Item` states it takes
+///         // arguments to the closure by reference.
+///         P: FnMut(&Self::Item) -> bool {}
+/// }
+/// ```
+pub mod example_11 {}
+
+
+
+
+pub mod example_12 {
+    pub fn main() {
+        let vec1 = vec![1, 2, 3];
+        let vec2 = vec![4, 5, 6];
+
+        // `iter()` for vecs yields `&i32`.
+        let mut iter = vec1.iter();
+        // `into_iter()` for vecs yields `i32`.
+        let mut into_iter = vec2.into_iter();
+
+        // `iter()` for vecs yields `&i32`, and we want to reference one of its
+        // items, so we have to destructure `&&i32` to `i32`
+        println!("Find 2 in vec1: {:?}", iter     .find(|&&x| x == 2));
+        // `into_iter()` for vecs yields `i32`, and we want to reference one of
+        // its items, so we have to destructure `&i32` to `i32`
+        println!("Find 2 in vec2: {:?}", into_iter.find(| &x| x == 2));
+
+        let array1 = [1, 2, 3];
+        let array2 = [4, 5, 6];
+
+        // `iter()` for arrays yields `&i32`
+        println!("Find 2 in array1: {:?}", array1.iter()     .find(|&&x| x == 2));
+        // `into_iter()` for arrays yields `i32`
+        println!("Find 2 in array2: {:?}", array2.into_iter().find(|&x| x == 2));
+    }
 }
 
 
@@ -499,18 +519,20 @@ fn main() {
     Iterator::find gives you a reference to the item. But if you want the index of the item, use Iterator::position.
 */
 
-fn main() {
-    let vec = vec![1, 9, 3, 3, 13, 2];
-
-    // `iter()` for vecs yields `&i32` and `position()` does not take a reference, so
-    // we have to destructure `&i32` to `i32`
-    let index_of_first_even_number = vec.iter().position(|&x| x % 2 == 0);
-    assert_eq!(index_of_first_even_number, Some(5));
-    
-    // `into_iter()` for vecs yields `i32` and `position()` does not take a reference, so
-    // we do not have to destructure    
-    let index_of_first_negative_number = vec.into_iter().position(|x| x < 0);
-    assert_eq!(index_of_first_negative_number, None);
+pub mod example_13 {
+    pub fn main() {
+        let vec = vec![1, 9, 3, 3, 13, 2];
+
+        // `iter()` for vecs yields `&i32` and `position()` does not take a reference, so
+        // we have to destructure `&i32` to `i32`
+        let index_of_first_even_number = vec.iter().position(|&x| x % 2 == 0);
+        assert_eq!(index_of_first_even_number, Some(5));
+
+        // `into_iter()` for vecs yields `i32` and `position()` does not take a reference, so
+        // we do not have to destructure    
+        let index_of_first_negative_number = vec.into_iter().position(|x| x < 0);
+        assert_eq!(index_of_first_negative_number, None);
+    }
 }
 
 
@@ -527,6 +549,3 @@ fn main() {
 
     [`std::iter::Iterator::rposition`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.rposition)
 */
-
-
-
@@ -2,60 +2,75 @@
 // https://doc.rust-lang.org/rust-by-example/mod/use.html
 
 
+
+
 /*
     The use declaration can be used to bind a full path to a new name, for easier access. It is often used like this:
 */
 
-use crate::deeply::nested::{
-    my_first_function,
-    my_second_function,
-    AndATraitType
-};
-
-fn main() {
-    my_first_function();
-}
+/// ```
+/// # mod deeply {
+/// #     pub mod nested {
+/// #         pub fn my_first_function() {}
+/// #         pub fn my_second_function() {}
+/// #         pub trait AndATraitType {}
+/// #     }
+/// # }
+/// #
+/// use crate::deeply::nested::{
+///     my_first_function,
+///     my_second_function,
+///     AndATraitType
+/// };
+///
+/// fn main() {
+///     my_first_function();
+/// }
+/// ```
+pub mod example_01 {}
 
 
 
 
+// You can use the as keyword to bind imports to a different name:
 
 
-// You can use the as keyword to bind imports to a different name:
 
 
 // Bind the `deeply::nested::function` path to `other_function`.
-use deeply::nested::function as other_function;
 
-fn function() {
-    println!("called `function()`");
-}
+pub mod example_02 {
+    use deeply::nested::function as other_function;
+
+    fn function() {
+        println!("called `function()`");
+    }
 
-mod deeply {
-    pub mod nested {
-        pub fn function() {
-            println!("called `deeply::nested::function()`");
+    mod deeply {
+        pub mod nested {
+            pub fn function() {
+                println!("called `deeply::nested::function()`");
+            }
         }
     }
-}
 
-fn main() {
-    // Easier access to `deeply::nested::function`
-    other_function();
+    pub fn main() {
+        // Easier access to `deeply::nested::function`
+        other_function();
 
-    println!("Entering block");
-    {
-        // This is equivalent to `use deeply::nested::function as function`.
-        // This `function()` will shadow the outer one.
-        use crate::deeply::nested::function;
+        println!("Entering block");
+        {
+            // This is equivalent to `use deeply::nested::function as function`.
+            // This `function()` will shadow the outer one.
+            use crate::rbe::modules::use_declaration::example_02::deeply::nested::function;
 
-        // `use` bindings have a local scope. In this case, the
-        // shadowing of `function()` is only in this block.
-        function();
+            // `use` bindings have a local scope. In this case, the
+            // shadowing of `function()` is only in this block.
+            function();
 
-        println!("Leaving block");
-    }
+            println!("Leaving block");
+        }
 
-    function();
+        function();
+    }
 }
-
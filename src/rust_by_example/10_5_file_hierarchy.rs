@@ -3,11 +3,13 @@
 
 
 
+
 // Modules can be mapped to a file/directory hierarchy. Let's break down 
 // the visibility example in files:
 
 
 
+
 /*
     $ tree .
     .
@@ -20,27 +22,33 @@
 
 
 
+
 /*
     In split.rs:
 
     This declaration will look for a file named `my.rs` and will
     insert its contents inside a module named `my` under this scope
 */
-mod my;
 
-fn function() {
-    println!("called `function()`");
-}
-
-fn main() {
-    my::function();
+/// ```ignore
+/// mod my;
+///
+/// fn function() {
+///     println!("called `function()`");
+/// }
+///
+/// fn main() {
+///     my::function();
+///
+///     function();
+///
+///     my::indirect_access();
+///
+///     my::nested::function();
+/// }
+/// ```
+pub mod example_01 {}
 
-    function();
-
-    my::indirect_access();
-
-    my::nested::function();
-}
 
 
 
@@ -51,54 +59,58 @@ fn main() {
     and `inaccessible.rs` files and insert them here under their respective
     modules
 */
-mod inaccessible;
-pub mod nested;
-
-pub fn function() {
-    println!("called `my::function()`");
-}
-
-fn private_function() {
-    println!("called `my::private_function()`");
-}
-
-pub fn indirect_access() {
-    print!("called `my::indirect_access()`, that\n> ");
-
-    private_function();
-}
 
+/// ```ignore
+/// mod inaccessible;
+/// pub mod nested;
+///
+/// pub fn function() {
+///     println!("called `my::function()`");
+/// }
+///
+/// fn private_function() {
+///     println!("called `my::private_function()`");
+/// }
+///
+/// pub fn indirect_access() {
+///     print!("called `my::indirect_access()`, that\n> ");
+///
+///     private_function();
+/// }
+/// ```
+pub mod example_02 {}
 
 
 
 
 // In my/nested.rs:
 
-pub fn function() {
-    println!("called `my::nested::function()`");
-}
+pub mod example_03 {
+    pub fn function() {
+        println!("called `my::nested::function()`");
+    }
 
-#[allow(dead_code)]
-fn private_function() {
-    println!("called `my::nested::private_function()`");
+    #[allow(dead_code)]
+    fn private_function() {
+        println!("called `my::nested::private_function()`");
+    }
 }
 
 
 
 
-
-
 // In my/inaccessible.rs:
 
-#[allow(dead_code)]
-pub fn public_function() {
-    println!("called `my::inaccessible::public_function()`");
+pub mod example_04 {
+    #[allow(dead_code)]
+    pub fn public_function() {
+        println!("called `my::inaccessible::public_function()`");
+    }
 }
 
 
 
 
-
 /*
     Let's check that things still work as before:
 
@@ -110,5 +122,3 @@ pub fn public_function() {
     > called `my::private_function()`
     called `my::nested::function()`
 */
-
-
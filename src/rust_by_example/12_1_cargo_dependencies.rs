@@ -1,6 +1,9 @@
 // Cargo
 // https://doc.rust-lang.org/rust-by-example/cargo.html
 
+
+
+
 /*
     cargo is the official Rust package management tool. It has lots of really useful features to improve code quality and developer velocity! These include
 
@@ -14,11 +17,12 @@
 
 
 
-
-
 // Dependencies
 // https://doc.rust-lang.org/rust-by-example/cargo/deps.html
 
+
+
+
 /*
     Most programs have dependencies on some libraries. If you have ever managed dependencies by hand, you know how much of a pain this can be. Luckily, the Rust ecosystem comes standard with cargo! cargo can manage dependencies for a project.
 
@@ -47,6 +51,7 @@
 
 
 
+
 /*
     The main.rs is the root source file for your new project -- nothing new there. The Cargo.toml is the config file for cargo for this project (foo). If you look inside it, you should see something like this:
 
@@ -61,7 +66,6 @@
 
 
 
-
 /*
     The name field under [package] determines the name of the project. This is used by crates.io if you publish the crate (more later). It is also the name of the output binary when you compile.
 
@@ -88,6 +92,7 @@
 
 
 
+
 /*
     cargo is more than a dependency manager. All of the available configuration options are listed in the format specification of Cargo.toml.
 
@@ -95,4 +100,3 @@
 
     Voila! That's all there is to it!
 */
-
@@ -1,6 +1,9 @@
 // Conventions
 // https://doc.rust-lang.org/rust-by-example/cargo/conventions.html
 
+
+
+
 /*
     In the previous chapter, we saw the following directory hierarchy:
 
@@ -36,6 +39,3 @@
 
     In the next chapter, we will look more closely at tests.
 */
-
-
-
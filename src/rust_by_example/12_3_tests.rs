@@ -3,6 +3,7 @@
 
 
 
+
 /*
     As we know testing is integral to any piece of software! Rust has first-class support for unit and integration testing (see this chapter in TRPL).
 
@@ -33,6 +34,7 @@
 
 
 
+
 /*
     You should see output like this:
 
@@ -71,55 +73,58 @@
 */
 
 
+
+
 /*
     One word of caution: Cargo may run multiple tests concurrently, so make sure that they don't race with each other.
 
     One example of this concurrency causing issues is if two tests output to a file, such as below:
 */
 
-
-#![allow(unused)]
-fn main() {
-#[cfg(test)]
-mod tests {
-    // Import the necessary modules
-    use std::fs::OpenOptions;
-    use std::io::Write;
-
-    // This test writes to a file
-    #[test]
-    fn test_file() {
-        // Opens the file ferris.txt or creates one if it doesn't exist.
-        let mut file = OpenOptions::new()
-            .append(true)
-            .create(true)
-            .open("ferris.txt")
-            .expect("Failed to open ferris.txt");
-
-        // Print "Ferris" 5 times.
-        for _ in 0..5 {
-            file.write_all("Ferris\n".as_bytes())
-                .expect("Could not write to ferris.txt");
+pub mod example_01 {
+    #![allow(unused)]
+    pub fn main() {
+    #[cfg(test)]
+    mod tests {
+        // Import the necessary modules
+        use std::fs::OpenOptions;
+        use std::io::Write;
+
+        // This test writes to a file
+        #[test]
+        fn test_file() {
+            // Opens the file ferris.txt or creates one if it doesn't exist.
+            let mut file = OpenOptions::new()
+                .append(true)
+                .create(true)
+                .open("ferris.txt")
+                .expect("Failed to open ferris.txt");
+
+            // Print "Ferris" 5 times.
+            for _ in 0..5 {
+                file.write_all("Ferris\n".as_bytes())
+                    .expect("Could not write to ferris.txt");
+            }
         }
-    }
 
-    // This test tries to write to the same file
-    #[test]
-    fn test_file_also() {
-        // Opens the file ferris.txt or creates one if it doesn't exist.
-        let mut file = OpenOptions::new()
-            .append(true)
-            .create(true)
-            .open("ferris.txt")
-            .expect("Failed to open ferris.txt");
-
-        // Print "Corro" 5 times.
-        for _ in 0..5 {
-            file.write_all("Corro\n".as_bytes())
-                .expect("Could not write to ferris.txt");
+        // This test tries to write to the same file
+        #[test]
+        fn test_file_also() {
+            // Opens the file ferris.txt or creates one if it doesn't exist.
+            let mut file = OpenOptions::new()
+                .append(true)
+                .create(true)
+                .open("ferris.txt")
+                .expect("Failed to open ferris.txt");
+
+            // Print "Corro" 5 times.
+            for _ in 0..5 {
+                file.write_all("Corro\n".as_bytes())
+                    .expect("Could not write to ferris.txt");
+            }
         }
     }
-}
+    }
 }
 
 
@@ -159,6 +164,3 @@ mod tests {
     Corro
     Ferris
 */
-
-
-
@@ -19,7 +19,6 @@
 
 
 
-
 /*
     How to use a build script
 
@@ -31,5 +30,3 @@
 
     For further specification and examples have a read of the Cargo specification.
 */
-
-
@@ -26,18 +26,14 @@
     Attributes can have multiple values and can be separated over multiple lines, too:
 */
 
-#[attribute(value, value2)]
-
-
-#[attribute(value, value2, value3,
-            value4, value5)]
-
-
-
-
-
-
-
+/// ```
+/// #[attribute(value, value2)]
+///
+///
+/// #[attribute(value, value2, value3,
+///             value4, value5)]
+/// ```
+pub mod example_01 {}
 
 
 
@@ -46,24 +42,28 @@
 // https://doc.rust-lang.org/rust-by-example/attribute/unused.html
 
 
+
+
 // The compiler provides a dead_code lint that will warn about unused functions. 
 // An attribute can be used to disable the lint.
 
-fn used_function() {}
+pub mod example_02 {
+    fn used_function() {}
 
-// `#[allow(dead_code)]` is an attribute that disables the `dead_code` lint
-#[allow(dead_code)]
-fn unused_function() {}
+    // `#[allow(dead_code)]` is an attribute that disables the `dead_code` lint
+    #[allow(dead_code)]
+    fn unused_function() {}
 
-fn noisy_unused_function() {}
-// FIXME ^ Add an attribute to suppress the warning
+    fn noisy_unused_function() {}
+    // FIXME ^ Add an attribute to suppress the warning
 
-fn main() {
-    used_function();
+    pub fn main() {
+        used_function();
+    }
 }
 
 
-// Note that in real programs, you should eliminate dead code. In these examples we'll 
-// allow dead code in some places because of the interactive nature of the examples.
 
 
+// Note that in real programs, you should eliminate dead code. In these examples we'll 
+// allow dead code in some places because of the interactive nature of the examples.
@@ -11,24 +11,26 @@
 */
 
 // This crate is a library
-#![crate_type = "lib"]
-// The library is named "rary"
-#![crate_name = "rary"]
 
-pub fn public_function() {
-    println!("called rary's `public_function()`");
-}
+pub mod example_01 {
+    #![crate_type = "lib"]
+    // The library is named "rary"
+    #![crate_name = "rary"]
 
-fn private_function() {
-    println!("called rary's `private_function()`");
-}
+    pub fn public_function() {
+        println!("called rary's `public_function()`");
+    }
 
-pub fn indirect_access() {
-    print!("called rary's `indirect_access()`, that\n> ");
+    fn private_function() {
+        println!("called rary's `private_function()`");
+    }
 
-    private_function();
-}
+    pub fn indirect_access() {
+        print!("called rary's `indirect_access()`, that\n> ");
 
+        private_function();
+    }
+}
 
 
 
@@ -40,5 +42,3 @@ pub fn indirect_access() {
     $ ls lib*
     library.rlib
 */
-
-
@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/attribute/cfg.html
 
 
+
+
 /*
     Configuration conditional checks are possible through two different operators:
 
@@ -14,48 +16,56 @@
 */
 
 // This function only gets compiled if the target OS is linux
-#[cfg(target_os = "linux")]
-fn are_you_on_linux() {
-    println!("You are running linux!");
-}
 
-// And this function only gets compiled if the target OS is *not* linux
-#[cfg(not(target_os = "linux"))]
-fn are_you_on_linux() {
-    println!("You are *not* running linux!");
-}
+pub mod example_01 {
+    #[cfg(target_os = "linux")]
+    fn are_you_on_linux() {
+        println!("You are running linux!");
+    }
+
+    // And this function only gets compiled if the target OS is *not* linux
+    #[cfg(not(target_os = "linux"))]
+    fn are_you_on_linux() {
+        println!("You are *not* running linux!");
+    }
 
-fn main() {
-    are_you_on_linux();
+    pub fn main() {
+        are_you_on_linux();
 
-    println!("Are you sure?");
-    if cfg!(target_os = "linux") {
-        println!("Yes. It's definitely linux!");
-    } else {
-        println!("Yes. It's definitely *not* linux!");
+        println!("Are you sure?");
+        if cfg!(target_os = "linux") {
+            println!("Yes. It's definitely linux!");
+        } else {
+            println!("Yes. It's definitely *not* linux!");
+        }
     }
 }
 
 
 
 
-
 // Custom
 // https://doc.rust-lang.org/rust-by-example/attribute/cfg/custom.html
 
 
+
+
 /*
     Some conditionals like target_os are implicitly provided by rustc, but custom conditionals must be passed to rustc using the --cfg flag.
 */
 
-#[cfg(some_condition)]
-fn conditional_function() {
-    println!("condition met!");
-}
+/// ```compile_fail
+/// #[cfg(some_condition)]
+/// fn conditional_function() {
+///     println!("condition met!");
+/// }
+///
+/// fn main() {
+///     conditional_function();
+/// }
+/// ```
+pub mod example_02 {}
 
-fn main() {
-    conditional_function();
-}
 
 
 
@@ -67,4 +77,3 @@ fn main() {
     $ rustc --cfg some_condition custom.rs && ./custom
     condition met!
 */
-
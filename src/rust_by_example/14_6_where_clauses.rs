@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/generics/where.html
 
 
+
+
 /*
     A bound can also be expressed using a where clause immediately before the opening {, rather than at the type's first mention. Additionally, where clauses can apply bounds to arbitrary types, rather than just to type parameters.
 
@@ -10,12 +12,25 @@
         When specifying generic types and bounds separately is clearer:
 */
 
-impl <A: TraitB + TraitC, D: TraitE + TraitF> MyTrait<A, D> for YourType {}
-
-// Expressing bounds with a `where` clause
-impl <A, D> MyTrait<A, D> for YourType where
-    A: TraitB + TraitC,
-    D: TraitE + TraitF {}
+/// ```
+/// # trait TraitB {}
+/// # trait TraitC {}
+/// # trait TraitE {}
+/// # trait TraitF {}
+/// # trait MyTrait<A, D> {}
+/// # struct YourType;
+/// #
+/// # mod before { use super::{MyTrait, TraitB, TraitC, TraitE, TraitF}; struct YourType;
+/// impl <A: TraitB + TraitC, D: TraitE + TraitF> MyTrait<A, D> for YourType {}
+/// # }
+///
+/// // Expressing bounds with a `where` clause
+/// impl <A, D> MyTrait<A, D> for YourType where
+///     A: TraitB + TraitC,
+///     D: TraitE + TraitF {}
+/// # fn main() {}
+/// ```
+pub mod example_01 {}
 
 
 
@@ -24,27 +39,27 @@ impl <A, D> MyTrait<A, D> for YourType where
     When using a where clause is more expressive than using normal syntax. The impl in this example cannot be directly expressed without a where clause:
 */
 
-use std::fmt::Debug;
+pub mod example_02 {
+    use std::fmt::Debug;
 
-trait PrintInOption {
-    fn print_in_option(self);
-}
+    trait PrintInOption {
+        fn print_in_option(self);
+    }
 
-// Because we would otherwise have to express this as `T: Debug` or 
-// use another method of indirect approach, this requires a `where` clause:
-impl<T> PrintInOption for T where
-    Option<T>: Debug {
-    // We want `Option<T>: Debug` as our bound because that is what's
-    // being printed. Doing otherwise would be using the wrong bound.
-    fn print_in_option(self) {
-        println!("{:?}", Some(self));
+    // Because we would otherwise have to express this as `T: Debug` or 
+    // use another method of indirect approach, this requires a `where` clause:
+    impl<T> PrintInOption for T where
+        Option<T>: Debug {
+        // We want `Option<T>: Debug` as our bound because that is what's
+        // being printed. Doing otherwise would be using the wrong bound.
+        fn print_in_option(self) {
+            println!("{:?}", Some(self));
+        }
     }
-}
 
-fn main() {
-    let vec = vec![1, 2, 3];
+    pub fn main() {
+        let vec = vec![1, 2, 3];
 
-    vec.print_in_option();
+        vec.print_in_option();
+    }
 }
-
-
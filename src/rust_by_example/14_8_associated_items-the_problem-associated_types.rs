@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/generics/assoc_items.html
 
 
+
+
 /*
     "Associated Items" refers to a set of rules pertaining to items of various types. It is an extension to trait generics, and allows traits to internally define new items.
 
@@ -11,14 +13,12 @@
 
 
 
-
-
-
-
 // The Problem
 // https://doc.rust-lang.org/rust-by-example/generics/assoc_items/the_problem.html
 
 
+
+
 /*
     A trait that is generic over its container type has type specification requirements - users of the trait must specify all of its generic types.
 
@@ -27,54 +27,52 @@
     Because Contains is generic, we are forced to explicitly state all of the generic types for fn difference(). In practice, we want a way to express that A and B are determined by the input C. As you will see in the next section, associated types provide exactly that capability.
 */
 
-struct Container(i32, i32);
-
-// A trait which checks if 2 items are stored inside of container.
-// Also retrieves first or last value.
-trait Contains<A, B> {
-    fn contains(&self, _: &A, _: &B) -> bool; // Explicitly requires `A` and `B`.
-    fn first(&self) -> i32; // Doesn't explicitly require `A` or `B`.
-    fn last(&self) -> i32;  // Doesn't explicitly require `A` or `B`.
-}
+pub mod example_01 {
+    struct Container(i32, i32);
 
-impl Contains<i32, i32> for Container {
-    // True if the numbers stored are equal.
-    fn contains(&self, number_1: &i32, number_2: &i32) -> bool {
-        (&self.0 == number_1) && (&self.1 == number_2)
+    // A trait which checks if 2 items are stored inside of container.
+    // Also retrieves first or last value.
+    trait Contains<A, B> {
+        fn contains(&self, _: &A, _: &B) -> bool; // Explicitly requires `A` and `B`.
+        fn first(&self) -> i32; // Doesn't explicitly require `A` or `B`.
+        fn last(&self) -> i32;  // Doesn't explicitly require `A` or `B`.
     }
 
-    // Grab the first number.
-    fn first(&self) -> i32 { self.0 }
+    impl Contains<i32, i32> for Container {
+        // True if the numbers stored are equal.
+        fn contains(&self, number_1: &i32, number_2: &i32) -> bool {
+            (&self.0 == number_1) && (&self.1 == number_2)
+        }
 
-    // Grab the last number.
-    fn last(&self) -> i32 { self.1 }
-}
-
-// `C` contains `A` and `B`. In light of that, having to express `A` and
-// `B` again is a nuisance.
-fn difference<A, B, C>(container: &C) -> i32 where
-    C: Contains<A, B> {
-    container.last() - container.first()
-}
-
-fn main() {
-    let number_1 = 3;
-    let number_2 = 10;
-
-    let container = Container(number_1, number_2);
+        // Grab the first number.
+        fn first(&self) -> i32 { self.0 }
 
-    println!("Does container contain {} and {}: {}",
-        &number_1, &number_2,
-        container.contains(&number_1, &number_2));
-    println!("First number: {}", container.first());
-    println!("Last number: {}", container.last());
+        // Grab the last number.
+        fn last(&self) -> i32 { self.1 }
+    }
 
-    println!("The difference is: {}", difference(&container));
-}
+    // `C` contains `A` and `B`. In light of that, having to express `A` and
+    // `B` again is a nuisance.
+    fn difference<A, B, C>(container: &C) -> i32 where
+        C: Contains<A, B> {
+        container.last() - container.first()
+    }
 
+    pub fn main() {
+        let number_1 = 3;
+        let number_2 = 10;
 
+        let container = Container(number_1, number_2);
 
+        println!("Does container contain {} and {}: {}",
+            &number_1, &number_2,
+            container.contains(&number_1, &number_2));
+        println!("First number: {}", container.first());
+        println!("Last number: {}", container.last());
 
+        println!("The difference is: {}", difference(&container));
+    }
+}
 
 
 
@@ -84,96 +82,97 @@ fn main() {
 
 
 
+
 /*
     The use of "Associated types" improves the overall readability of code by moving inner types locally into a trait as output types. Syntax for the trait definition is as follows:
 */
 
-
-#![allow(unused)]
-fn main() {
-// `A` and `B` are defined in the trait via the `type` keyword.
-// (Note: `type` in this context is different from `type` when used for
-// aliases).
-trait Contains {
-    type A;
-    type B;
-
-    // Updated syntax to refer to these new types generically.
-    fn contains(&self, _: &Self::A, _: &Self::B) -> bool;
-}
+pub mod example_02 {
+    #![allow(unused)]
+    pub fn main() {
+    // `A` and `B` are defined in the trait via the `type` keyword.
+    // (Note: `type` in this context is different from `type` when used for
+    // aliases).
+    trait Contains {
+        type A;
+        type B;
+
+        // Updated syntax to refer to these new types generically.
+        fn contains(&self, _: &Self::A, _: &Self::B) -> bool;
+    }
+    }
 }
 
 
 
 
-
-
-
-
 // Note that functions that use the trait Contains are no longer required to express A or B at all:
 
 // Without using associated types
-fn difference<A, B, C>(container: &C) -> i32 where
-    C: Contains<A, B> { ... }
-
-// Using associated types
-fn difference<C: Contains>(container: &C) -> i32 { ... }
-
 
+/// ```ignore
+/// fn difference<A, B, C>(container: &C) -> i32 where
+///     C: Contains<A, B> { ... }
+///
+/// // Using associated types
+/// fn difference<C: Contains>(container: &C) -> i32 { ... }
+/// ```
+pub mod example_03 {}
 
 
 
 
 // Let's rewrite the example from the previous section using associated types:
 
-struct Container(i32, i32);
+pub mod example_04 {
+    struct Container(i32, i32);
 
-// A trait which checks if 2 items are stored inside of container.
-// Also retrieves first or last value.
-trait Contains {
-    // Define generic types here which methods will be able to utilize.
-    type A;
-    type B;
+    // A trait which checks if 2 items are stored inside of container.
+    // Also retrieves first or last value.
+    trait Contains {
+        // Define generic types here which methods will be able to utilize.
+        type A;
+        type B;
 
-    fn contains(&self, _: &Self::A, _: &Self::B) -> bool;
-    fn first(&self) -> i32;
-    fn last(&self) -> i32;
-}
-
-impl Contains for Container {
-    // Specify what types `A` and `B` are. If the `input` type
-    // is `Container(i32, i32)`, the `output` types are determined
-    // as `i32` and `i32`.
-    type A = i32;
-    type B = i32;
+        fn contains(&self, _: &Self::A, _: &Self::B) -> bool;
+        fn first(&self) -> i32;
+        fn last(&self) -> i32;
+    }
 
-    // `&Self::A` and `&Self::B` are also valid here.
-    fn contains(&self, number_1: &i32, number_2: &i32) -> bool {
-        (&self.0 == number_1) && (&self.1 == number_2)
+    impl Contains for Container {
+        // Specify what types `A` and `B` are. If the `input` type
+        // is `Container(i32, i32)`, the `output` types are determined
+        // as `i32` and `i32`.
+        type A = i32;
+        type B = i32;
+
+        // `&Self::A` and `&Self::B` are also valid here.
+        fn contains(&self, number_1: &i32, number_2: &i32) -> bool {
+            (&self.0 == number_1) && (&self.1 == number_2)
+        }
+        // Grab the first number.
+        fn first(&self) -> i32 { self.0 }
+
+        // Grab the last number.
+        fn last(&self) -> i32 { self.1 }
     }
-    // Grab the first number.
-    fn first(&self) -> i32 { self.0 }
 
-    // Grab the last number.
-    fn last(&self) -> i32 { self.1 }
-}
+    fn difference<C: Contains>(container: &C) -> i32 {
+        container.last() - container.first()
+    }
 
-fn difference<C: Contains>(container: &C) -> i32 {
-    container.last() - container.first()
-}
+    pub fn main() {
+        let number_1 = 3;
+        let number_2 = 10;
 
-fn main() {
-    let number_1 = 3;
-    let number_2 = 10;
+        let container = Container(number_1, number_2);
 
-    let container = Container(number_1, number_2);
+        println!("Does container contain {} and {}: {}",
+            &number_1, &number_2,
+            container.contains(&number_1, &number_2));
+        println!("First number: {}", container.first());
+        println!("Last number: {}", container.last());
 
-    println!("Does container contain {} and {}: {}",
-        &number_1, &number_2,
-        container.contains(&number_1, &number_2));
-    println!("First number: {}", container.first());
-    println!("Last number: {}", container.last());
-    
-    println!("The difference is: {}", difference(&container));
+        println!("The difference is: {}", difference(&container));
+    }
 }
-
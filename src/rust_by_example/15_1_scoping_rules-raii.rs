@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/scope.html
 
 
+
+
 /*
     Scopes play an important part in ownership, borrowing, and lifetimes. That is, they indicate to the compiler when borrows are valid, when resources can be freed, and when variables are created or destroyed.
 */
@@ -9,13 +11,12 @@
 
 
 
-
-
-
 // RAII
 // https://doc.rust-lang.org/rust-by-example/scope/raii.html
 
 
+
+
 /*
     Variables in Rust do more than just hold data in the stack: they also own resources, e.g. Box<T> owns memory in the heap. Rust enforces RAII (Resource Acquisition Is Initialization), so whenever an object goes out of scope, its destructor is called and its owned resources are freed.
 
@@ -23,40 +24,44 @@
 */
 
 // raii.rs
-fn create_box() {
-    // Allocate an integer on the heap
-    let _box1 = Box::new(3i32);
 
-    // `_box1` is destroyed here, and memory gets freed
-}
+pub mod example_01 {
+    fn create_box() {
+        // Allocate an integer on the heap
+        let _box1 = Box::new(3i32);
 
-fn main() {
-    // Allocate an integer on the heap
-    let _box2 = Box::new(5i32);
+        // `_box1` is destroyed here, and memory gets freed
+    }
 
-    // A nested scope:
-    {
+    pub fn main() {
         // Allocate an integer on the heap
-        let _box3 = Box::new(4i32);
+        let _box2 = Box::new(5i32);
 
-        // `_box3` is destroyed here, and memory gets freed
-    }
+        // A nested scope:
+        {
+            // Allocate an integer on the heap
+            let _box3 = Box::new(4i32);
 
-    // Creating lots of boxes just for fun
-    // There's no need to manually free memory!
-    for _ in 0u32..1_000 {
-        create_box();
-    }
+            // `_box3` is destroyed here, and memory gets freed
+        }
 
-    // `_box2` is destroyed here, and memory gets freed
+        // Creating lots of boxes just for fun
+        // There's no need to manually free memory!
+        for _ in 0u32..1_000 {
+            create_box();
+        }
+
+        // `_box2` is destroyed here, and memory gets freed
+    }
 }
 
 
 
 
+// Of course, we can double check for memory errors using valgrind:
+
 
 
-// Of course, we can double check for memory errors using valgrind:
 
 /*
     $ rustc raii.rs && valgrind ./raii
@@ -81,9 +86,6 @@ fn main() {
 
 
 
-
-
-
 /*
     Destructor
 
@@ -92,17 +94,17 @@ fn main() {
     Run the below example to see how the Drop trait works. When the variable in the main function goes out of scope the custom destructor will be invoked.
 */
 
-struct ToDrop;
+pub mod example_02 {
+    struct ToDrop;
 
-impl Drop for ToDrop {
-    fn drop(&mut self) {
-        println!("ToDrop is being dropped");
+    impl Drop for ToDrop {
+        fn drop(&mut self) {
+            println!("ToDrop is being dropped");
+        }
     }
-}
 
-fn main() {
-    let x = ToDrop;
-    println!("Made a ToDrop!");
+    pub fn main() {
+        let x = ToDrop;
+        println!("Made a ToDrop!");
+    }
 }
-
-
@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/scope/move.html
 
 
+
+
 /*
     Because variables are in charge of freeing their own resources, resources can only have one owner. This also prevents resources from being freed more than once. Note that not all variables own resources (e.g. references).
 
@@ -11,50 +13,50 @@
 */
 
 // This function takes ownership of the heap allocated memory
-fn destroy_box(c: Box<i32>) {
-    println!("Destroying a box that contains {}", c);
-
-    // `c` is destroyed and the memory freed
-}
 
-fn main() {
-    // _Stack_ allocated integer
-    let x = 5u32;
+pub mod example_01 {
+    fn destroy_box(c: Box<i32>) {
+        println!("Destroying a box that contains {}", c);
 
-    // *Copy* `x` into `y` - no resources are moved
-    let y = x;
+        // `c` is destroyed and the memory freed
+    }
 
-    // Both values can be independently used
-    println!("x is {}, and y is {}", x, y);
+    pub fn main() {
+        // _Stack_ allocated integer
+        let x = 5u32;
 
-    // `a` is a pointer to a _heap_ allocated integer
-    let a = Box::new(5i32);
+        // *Copy* `x` into `y` - no resources are moved
+        let y = x;
 
-    println!("a contains: {}", a);
+        // Both values can be independently used
+        println!("x is {}, and y is {}", x, y);
 
-    // *Move* `a` into `b`
-    let b = a;
-    // The pointer address of `a` is copied (not the data) into `b`.
-    // Both are now pointers to the same heap allocated data, but
-    // `b` now owns it.
-    
-    // Error! `a` can no longer access the data, because it no longer owns the
-    // heap memory
-    //println!("a contains: {}", a);
-    // TODO ^ Try uncommenting this line
+        // `a` is a pointer to a _heap_ allocated integer
+        let a = Box::new(5i32);
 
-    // This function takes ownership of the heap allocated memory from `b`
-    destroy_box(b);
+        println!("a contains: {}", a);
 
-    // Since the heap memory has been freed at this point, this action would
-    // result in dereferencing freed memory, but it's forbidden by the compiler
-    // Error! Same reason as the previous Error
-    //println!("b contains: {}", b);
-    // TODO ^ Try uncommenting this line
-}
+        // *Move* `a` into `b`
+        let b = a;
+        // The pointer address of `a` is copied (not the data) into `b`.
+        // Both are now pointers to the same heap allocated data, but
+        // `b` now owns it.
 
+        // Error! `a` can no longer access the data, because it no longer owns the
+        // heap memory
+        //println!("a contains: {}", a);
+        // TODO ^ Try uncommenting this line
 
+        // This function takes ownership of the heap allocated memory from `b`
+        destroy_box(b);
 
+        // Since the heap memory has been freed at this point, this action would
+        // result in dereferencing freed memory, but it's forbidden by the compiler
+        // Error! Same reason as the previous Error
+        //println!("b contains: {}", b);
+        // TODO ^ Try uncommenting this line
+    }
+}
 
 
 
@@ -63,31 +65,30 @@ fn main() {
 // https://doc.rust-lang.org/rust-by-example/scope/move/mut.html
 
 
-// Mutability of data can be changed when ownership is transferred.
-
-fn main() {
-    let immutable_box = Box::new(5u32);
-
-    println!("immutable_box contains {}", immutable_box);
 
-    // Mutability error
-    //*immutable_box = 4;
 
-    // *Move* the box, changing the ownership (and mutability)
-    let mut mutable_box = immutable_box;
+// Mutability of data can be changed when ownership is transferred.
 
-    println!("mutable_box contains {}", mutable_box);
+pub mod example_02 {
+    pub fn main() {
+        let immutable_box = Box::new(5u32);
 
-    // Modify the contents of the box
-    *mutable_box = 4;
-
-    println!("mutable_box now contains {}", mutable_box);
-}
+        println!("immutable_box contains {}", immutable_box);
 
+        // Mutability error
+        //*immutable_box = 4;
 
+        // *Move* the box, changing the ownership (and mutability)
+        let mut mutable_box = immutable_box;
 
+        println!("mutable_box contains {}", mutable_box);
 
+        // Modify the contents of the box
+        *mutable_box = 4;
 
+        println!("mutable_box now contains {}", mutable_box);
+    }
+}
 
 
 
@@ -96,42 +97,43 @@ fn main() {
 // https://doc.rust-lang.org/rust-by-example/scope/move/partial_move.html
 
 
+
+
 /*
     Within the destructuring of a single variable, both by-move and by-reference pattern bindings can be used at the same time. Doing this will result in a partial move of the variable, which means that parts of the variable will be moved while other parts stay. In such a case, the parent variable cannot be used afterwards as a whole, however the parts that are only referenced (and not moved) can still be used.
 */
 
-fn main() {
-    #[derive(Debug)]
-    struct Person {
-        name: String,
-        age: Box<u8>,
-    }
+pub mod example_03 {
+    pub fn main() {
+        #[derive(Debug)]
+        struct Person {
+            name: String,
+            age: Box<u8>,
+        }
 
-    let person = Person {
-        name: String::from("Alice"),
-        age: Box::new(20),
-    };
+        let person = Person {
+            name: String::from("Alice"),
+            age: Box::new(20),
+        };
 
-    // `name` is moved out of person, but `age` is referenced
-    let Person { name, ref age } = person;
+        // `name` is moved out of person, but `age` is referenced
+        let Person { name, ref age } = person;
 
-    println!("The person's age is {}", age);
+        println!("The person's age is {}", age);
 
-    println!("The person's name is {}", name);
+        println!("The person's name is {}", name);
 
-    // Error! borrow of partially moved value: `person` partial move occurs
-    //println!("The person struct is {:?}", person);
+        // Error! borrow of partially moved value: `person` partial move occurs
+        //println!("The person struct is {:?}", person);
 
-    // `person` cannot be used but `person.age` can be used as it is not moved
-    println!("The person's age from person struct is {}", person.age);
+        // `person` cannot be used but `person.age` can be used as it is not moved
+        println!("The person's age from person struct is {}", person.age);
+    }
 }
 
 
 
+
 /*
     (In this example, we store the age variable on the heap to illustrate the partial move: deleting ref in the above code would give an error as the ownership of person.age would be moved to the variable age. If Person.age were stored on the stack, ref would not be required as the definition of age would copy the data from person.age without moving it.)
 */
-
-
-
-
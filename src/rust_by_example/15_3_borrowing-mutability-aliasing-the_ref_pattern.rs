@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/scope/borrow.html
 
 
+
+
 /*
     Most of the time, we'd like to access data without taking ownership over it. To accomplish this, Rust uses a borrowing mechanism. Instead of passing objects by value (T), objects can be passed by reference (&T).
 
@@ -9,46 +11,46 @@
 */
 
 // This function takes ownership of a box and destroys it
-fn eat_box_i32(boxed_i32: Box<i32>) {
-    println!("Destroying box that contains {}", boxed_i32);
-}
-
-// This function borrows an i32
-fn borrow_i32(borrowed_i32: &i32) {
-    println!("This int is: {}", borrowed_i32);
-}
-
-fn main() {
-    // Create a boxed i32, and a stacked i32
-    let boxed_i32 = Box::new(5_i32);
-    let stacked_i32 = 6_i32;
-
-    // Borrow the contents of the box. Ownership is not taken,
-    // so the contents can be borrowed again.
-    borrow_i32(&boxed_i32);
-    borrow_i32(&stacked_i32);
-
-    {
-        // Take a reference to the data contained inside the box
-        let _ref_to_i32: &i32 = &boxed_i32;
-
-        // Error!
-        // Can't destroy `boxed_i32` while the inner value is borrowed later in scope.
-        eat_box_i32(boxed_i32);
-        // FIXME ^ Comment out this line
-
-        // Attempt to borrow `_ref_to_i32` after inner value is destroyed
-        borrow_i32(_ref_to_i32);
-        // `_ref_to_i32` goes out of scope and is no longer borrowed.
-    }
-
-    // `boxed_i32` can now give up ownership to `eat_box` and be destroyed
-    eat_box_i32(boxed_i32);
-}
-
-
-
 
+/// ```compile_fail
+/// fn eat_box_i32(boxed_i32: Box<i32>) {
+///     println!("Destroying box that contains {}", boxed_i32);
+/// }
+///
+/// // This function borrows an i32
+/// fn borrow_i32(borrowed_i32: &i32) {
+///     println!("This int is: {}", borrowed_i32);
+/// }
+///
+/// fn main() {
+///     // Create a boxed i32, and a stacked i32
+///     let boxed_i32 = Box::new(5_i32);
+///     let stacked_i32 = 6_i32;
+///
+///     // Borrow the contents of the box. Ownership is not taken,
+///     // so the contents can be borrowed again.
+///     borrow_i32(&boxed_i32);
+///     borrow_i32(&stacked_i32);
+///
+///     {
+///         // Take a reference to the data contained inside the box
+///         let _ref_to_i32: &i32 = &boxed_i32;
+///
+///         // Error!
+///         // Can't destroy `boxed_i32` while the inner value is borrowed later in scope.
+///         eat_box_i32(boxed_i32);
+///         // FIXME ^ Comment out this line
+///
+///         // Attempt to borrow `_ref_to_i32` after inner value is destroyed
+///         borrow_i32(_ref_to_i32);
+///         // `_ref_to_i32` goes out of scope and is no longer borrowed.
+///     }
+///
+///     // `boxed_i32` can now give up ownership to `eat_box` and be destroyed
+///     eat_box_i32(boxed_i32);
+/// }
+/// ```
+pub mod example_01 {}
 
 
 
@@ -57,68 +59,60 @@ fn main() {
 // https://doc.rust-lang.org/rust-by-example/scope/borrow/mut.html
 
 
+
+
 /*
     Mutable data can be mutably borrowed using &mut T. This is called a mutable reference and gives read/write access to the borrower. In contrast, &T borrows the data via an immutable reference, and the borrower can read the data but not modify it:
 */
 
-#[allow(dead_code)]
-#[derive(Clone, Copy)]
-struct Book {
-    // `&'static str` is a reference to a string allocated in read only memory
-    author: &'static str,
-    title: &'static str,
-    year: u32,
-}
-
-// This function takes a reference to a book
-fn borrow_book(book: &Book) {
-    println!("I immutably borrowed {} - {} edition", book.title, book.year);
-}
-
-// This function takes a reference to a mutable book and changes `year` to 2014
-fn new_edition(book: &mut Book) {
-    book.year = 2014;
-    println!("I mutably borrowed {} - {} edition", book.title, book.year);
-}
-
-fn main() {
-    // Create an immutable Book named `immutabook`
-    let immutabook = Book {
-        // string literals have type `&'static str`
-        author: "Douglas Hofstadter",
-        title: "Gödel, Escher, Bach",
-        year: 1979,
-    };
-
-    // Create a mutable copy of `immutabook` and call it `mutabook`
-    let mut mutabook = immutabook;
-    
-    // Immutably borrow an immutable object
-    borrow_book(&immutabook);
-
-    // Immutably borrow a mutable object
-    borrow_book(&mutabook);
-    
-    // Borrow a mutable object as mutable
-    new_edition(&mut mutabook);
-    
-    // Error! Cannot borrow an immutable object as mutable
-    new_edition(&mut immutabook);
-    // FIXME ^ Comment out this line
-}
-
-
-
-
-
-
-
-
-
-
-
-
-
+/// ```compile_fail
+/// #[allow(dead_code)]
+/// #[derive(Clone, Copy)]
+/// struct Book {
+///     // `&'static str` is a reference to a string allocated in read only memory
+///     author: &'static str,
+///     title: &'static str,
+///     year: u32,
+/// }
+///
+/// // This function takes a reference to a book
+/// fn borrow_book(book: &Book) {
+///     println!("I immutably borrowed {} - {} edition", book.title, book.year);
+/// }
+///
+/// // This function takes a reference to a mutable book and changes `year` to 2014
+/// fn new_edition(book: &mut Book) {
+///     book.year = 2014;
+///     println!("I mutably borrowed {} - {} edition", book.title, book.year);
+/// }
+///
+/// fn main() {
+///     // Create an immutable Book named `immutabook`
+///     let immutabook = Book {
+///         // string literals have type `&'static str`
+///         author: "Douglas Hofstadter",
+///         title: "Gödel, Escher, Bach",
+///         year: 1979,
+///     };
+///
+///     // Create a mutable copy of `immutabook` and call it `mutabook`
+///     let mut mutabook = immutabook;
+///
+///     // Immutably borrow an immutable object
+///     borrow_book(&immutabook);
+///
+///     // Immutably borrow a mutable object
+///     borrow_book(&mutabook);
+///
+///     // Borrow a mutable object as mutable
+///     new_edition(&mut mutabook);
+///
+///     // Error! Cannot borrow an immutable object as mutable
+///     new_edition(&mut immutabook);
+///     // FIXME ^ Comment out this line
+/// }
+/// ```
+pub mod example_02 {}
 
 
 
@@ -127,131 +121,125 @@ fn main() {
 // https://doc.rust-lang.org/rust-by-example/scope/borrow/alias.html
 
 
+
+
 /*
     Data can be immutably borrowed any number of times, but while immutably borrowed, the original data can't be mutably borrowed. On the other hand, only one mutable borrow is allowed at a time. The original data can be borrowed again only after the mutable reference has been used for the last time.
 */
 
+pub mod example_03 {
+    struct Point { x: i32, y: i32, z: i32 }
 
-struct Point { x: i32, y: i32, z: i32 }
+    pub fn main() {
+        let mut point = Point { x: 0, y: 0, z: 0 };
 
-fn main() {
-    let mut point = Point { x: 0, y: 0, z: 0 };
+        let borrowed_point = &point;
+        let another_borrow = &point;
 
-    let borrowed_point = &point;
-    let another_borrow = &point;
+        // Data can be accessed via the references and the original owner
+        println!("Point has coordinates: ({}, {}, {})",
+                    borrowed_point.x, another_borrow.y, point.z);
 
-    // Data can be accessed via the references and the original owner
-    println!("Point has coordinates: ({}, {}, {})",
-                borrowed_point.x, another_borrow.y, point.z);
+        // Error! Can't borrow `point` as mutable because it's currently
+        // borrowed as immutable.
+        // let mutable_borrow = &mut point;
+        // TODO ^ Try uncommenting this line
 
-    // Error! Can't borrow `point` as mutable because it's currently
-    // borrowed as immutable.
-    // let mutable_borrow = &mut point;
-    // TODO ^ Try uncommenting this line
+        // The borrowed values are used again here
+        println!("Point has coordinates: ({}, {}, {})",
+                    borrowed_point.x, another_borrow.y, point.z);
 
-    // The borrowed values are used again here
-    println!("Point has coordinates: ({}, {}, {})",
-                borrowed_point.x, another_borrow.y, point.z);
+        // The immutable references are no longer used for the rest of the code so
+        // it is possible to reborrow with a mutable reference.
+        let mutable_borrow = &mut point;
 
-    // The immutable references are no longer used for the rest of the code so
-    // it is possible to reborrow with a mutable reference.
-    let mutable_borrow = &mut point;
+        // Change data via mutable reference
+        mutable_borrow.x = 5;
+        mutable_borrow.y = 2;
+        mutable_borrow.z = 1;
 
-    // Change data via mutable reference
-    mutable_borrow.x = 5;
-    mutable_borrow.y = 2;
-    mutable_borrow.z = 1;
+        // Error! Can't borrow `point` as immutable because it's currently
+        // borrowed as mutable.
+        // let y = &point.y;
+        // TODO ^ Try uncommenting this line
 
-    // Error! Can't borrow `point` as immutable because it's currently
-    // borrowed as mutable.
-    // let y = &point.y;
-    // TODO ^ Try uncommenting this line
+        // Error! Can't print because `println!` takes an immutable reference.
+        // println!("Point Z coordinate is {}", point.z);
+        // TODO ^ Try uncommenting this line
 
-    // Error! Can't print because `println!` takes an immutable reference.
-    // println!("Point Z coordinate is {}", point.z);
-    // TODO ^ Try uncommenting this line
+        // Ok! Mutable references can be passed as immutable to `println!`
+        println!("Point has coordinates: ({}, {}, {})",
+                    mutable_borrow.x, mutable_borrow.y, mutable_borrow.z);
 
-    // Ok! Mutable references can be passed as immutable to `println!`
-    println!("Point has coordinates: ({}, {}, {})",
-                mutable_borrow.x, mutable_borrow.y, mutable_borrow.z);
-
-    // The mutable reference is no longer used for the rest of the code so it
-    // is possible to reborrow
-    let new_borrowed_point = &point;
-    println!("Point now has coordinates: ({}, {}, {})",
-             new_borrowed_point.x, new_borrowed_point.y, new_borrowed_point.z);
+        // The mutable reference is no longer used for the rest of the code so it
+        // is possible to reborrow
+        let new_borrowed_point = &point;
+        println!("Point now has coordinates: ({}, {}, {})",
+                 new_borrowed_point.x, new_borrowed_point.y, new_borrowed_point.z);
+    }
 }
 
 
 
 
+// The ref pattern
+// https://doc.rust-lang.org/rust-by-example/scope/borrow/ref.html
 
 
 
 
-
-
-
-
-
-
-
-// The ref pattern
-// https://doc.rust-lang.org/rust-by-example/scope/borrow/ref.html
-
 /*
     When doing pattern matching or destructuring via the let binding, the ref keyword can be used to take references to the fields of a struct/tuple. The example below shows a few instances where this can be useful:
 */
 
-#[derive(Clone, Copy)]
-struct Point { x: i32, y: i32 }
+pub mod example_04 {
+    #[derive(Clone, Copy)]
+    struct Point { x: i32, y: i32 }
 
-fn main() {
-    let c = 'Q';
+    pub fn main() {
+        let c = 'Q';
 
-    // A `ref` borrow on the left side of an assignment is equivalent to
-    // an `&` borrow on the right side.
-    let ref ref_c1 = c;
-    let ref_c2 = &c;
+        // A `ref` borrow on the left side of an assignment is equivalent to
+        // an `&` borrow on the right side.
+        let ref ref_c1 = c;
+        let ref_c2 = &c;
 
-    println!("ref_c1 equals ref_c2: {}", *ref_c1 == *ref_c2);
+        println!("ref_c1 equals ref_c2: {}", *ref_c1 == *ref_c2);
 
-    let point = Point { x: 0, y: 0 };
+        let point = Point { x: 0, y: 0 };
 
-    // `ref` is also valid when destructuring a struct.
-    let _copy_of_x = {
-        // `ref_to_x` is a reference to the `x` field of `point`.
-        let Point { x: ref ref_to_x, y: _ } = point;
+        // `ref` is also valid when destructuring a struct.
+        let _copy_of_x = {
+            // `ref_to_x` is a reference to the `x` field of `point`.
+            let Point { x: ref ref_to_x, y: _ } = point;
 
-        // Return a copy of the `x` field of `point`.
-        *ref_to_x
-    };
+            // Return a copy of the `x` field of `point`.
+            *ref_to_x
+        };
 
-    // A mutable copy of `point`
-    let mut mutable_point = point;
+        // A mutable copy of `point`
+        let mut mutable_point = point;
 
-    {
-        // `ref` can be paired with `mut` to take mutable references.
-        let Point { x: _, y: ref mut mut_ref_to_y } = mutable_point;
+        {
+            // `ref` can be paired with `mut` to take mutable references.
+            let Point { x: _, y: ref mut mut_ref_to_y } = mutable_point;
 
-        // Mutate the `y` field of `mutable_point` via a mutable reference.
-        *mut_ref_to_y = 1;
-    }
+            // Mutate the `y` field of `mutable_point` via a mutable reference.
+            *mut_ref_to_y = 1;
+        }
 
-    println!("point is ({}, {})", point.x, point.y);
-    println!("mutable_point is ({}, {})", mutable_point.x, mutable_point.y);
-
-    // A mutable tuple that includes a pointer
-    let mut mutable_tuple = (Box::new(5u32), 3u32);
-    
-    {
-        // Destructure `mutable_tuple` to change the value of `last`.
-        let (_, ref mut last) = mutable_tuple;
-        *last = 2u32;
-    }
-    
-    println!("tuple is {:?}", mutable_tuple);
-}
+        println!("point is ({}, {})", point.x, point.y);
+        println!("mutable_point is ({}, {})", mutable_point.x, mutable_point.y);
 
+        // A mutable tuple that includes a pointer
+        let mut mutable_tuple = (Box::new(5u32), 3u32);
 
+        {
+            // Destructure `mutable_tuple` to change the value of `last`.
+            let (_, ref mut last) = mutable_tuple;
+            *last = 2u32;
+        }
 
+        println!("tuple is {:?}", mutable_tuple);
+    }
+}
@@ -1,6 +1,9 @@
 // Lifetimes
 // https://doc.rust-lang.org/rust-by-example/scope/lifetime.html
 
+
+
+
 /*
     A lifetime is a construct the compiler (or more specifically, its borrow checker) uses to ensure all borrows are valid. Specifically, a variable's lifetime begins when it is created and ends when it is destroyed. While lifetimes and scopes are often referred to together, they are not the same.
 
@@ -9,6 +12,9 @@
     In the following example and in the rest of this section, we will see how lifetimes relate to scopes, as well as how the two differ.
 */
 
+
+
+
 /*
     Lifetimes are annotated below with lines denoting the creation
     and destruction of each variable.
@@ -16,33 +22,33 @@
     both `borrow1` and `borrow2`. The duration of `borrow1` compared 
     to `borrow2` is irrelevant since they are disjoint.
 */
-fn main() {
-    let i = 3; // Lifetime for `i` starts. ────────────────┐
-    //                                                     │
-    { //                                                   │
-        let borrow1 = &i; // `borrow1` lifetime starts. ──┐│
-        //                                                ││
-        println!("borrow1: {}", borrow1); //              ││
-    } // `borrow1 ends. ──────────────────────────────────┘│
-    //                                                     │
-    //                                                     │
-    { //                                                   │
-        let borrow2 = &i; // `borrow2` lifetime starts. ──┐│
-        //                                                ││
-        println!("borrow2: {}", borrow2); //              ││
-    } // `borrow2` ends. ─────────────────────────────────┘│
-    //                                                     │
-}   // Lifetime ends. ─────────────────────────────────────┘
-
-
-/*
-Note that no names or types are assigned to label lifetimes. This restricts how lifetimes will be able to be used as we will see.
-*/
 
+pub mod example_01 {
+    pub fn main() {
+        let i = 3; // Lifetime for `i` starts. ────────────────┐
+        //                                                     │
+        { //                                                   │
+            let borrow1 = &i; // `borrow1` lifetime starts. ──┐│
+            //                                                ││
+            println!("borrow1: {}", borrow1); //              ││
+        } // `borrow1 ends. ──────────────────────────────────┘│
+        //                                                     │
+        //                                                     │
+        { //                                                   │
+            let borrow2 = &i; // `borrow2` lifetime starts. ──┐│
+            //                                                ││
+            println!("borrow2: {}", borrow2); //              ││
+        } // `borrow2` ends. ─────────────────────────────────┘│
+        //                                                     │
+    }   // Lifetime ends. ─────────────────────────────────────┘
+}
 
 
 
 
+/*
+Note that no names or types are assigned to label lifetimes. This restricts how lifetimes will be able to be used as we will see.
+*/
 
 
 
@@ -51,6 +57,8 @@ Note that no names or types are assigned to label lifetimes. This restricts how
 // https://doc.rust-lang.org/rust-by-example/scope/lifetime/explicit.html
 
 
+
+
 /*
     The borrow checker uses explicit lifetime annotations to determine how long references should be valid. In cases where lifetimes are not elided1, Rust requires explicit annotations to determine what the lifetime of a reference should be. The syntax for explicitly annotating a lifetime uses an apostrophe character as follows:
 
@@ -69,51 +77,57 @@ Note that no names or types are assigned to label lifetimes. This restricts how
     See the following example for explicit lifetime annotation in use:
 */
 
+
+
+
 /*
     `print_refs` takes two references to `i32` which have different
     lifetimes `'a` and `'b`. These two lifetimes must both be at
     least as long as the function `print_refs`.
 */
-fn print_refs<'a, 'b>(x: &'a i32, y: &'b i32) {
-    println!("x is {} and y is {}", x, y);
-}
-
-// A function which takes no arguments, but has a lifetime parameter `'a`.
-fn failed_borrow<'a>() {
-    let _x = 12;
-
-    // ERROR: `_x` does not live long enough
-    let y: &'a i32 = &_x;
-    // Attempting to use the lifetime `'a` as an explicit type annotation 
-    // inside the function will fail because the lifetime of `&_x` is shorter
-    // than that of `y`. A short lifetime cannot be coerced into a longer one.
-}
-
-fn main() {
-    // Create variables to be borrowed below.
-    let (four, nine) = (4, 9);
-    
-    // Borrows (`&`) of both variables are passed into the function.
-    print_refs(&four, &nine);
-    // Any input which is borrowed must outlive the borrower. 
-    // In other words, the lifetime of `four` and `nine` must 
-    // be longer than that of `print_refs`.
-    
-    failed_borrow();
-    // `failed_borrow` contains no references to force `'a` to be 
-    // longer than the lifetime of the function, but `'a` is longer.
-    // Because the lifetime is never constrained, it defaults to `'static`.
-}
 
+/// ```compile_fail
+/// fn print_refs<'a, 'b>(x: &'a i32, y: &'b i32) {
+///     println!("x is {} and y is {}", x, y);
+/// }
+///
+/// // A function which takes no arguments, but has a lifetime parameter `'a`.
+/// fn failed_borrow<'a>() {
+///     let _x = 12;
+///
+///     // ERROR: `_x` does not live long enough
+///     let y: &'a i32 = &_x;
+///     // Attempting to use the lifetime `'a` as an explicit type annotation 
+///     // inside the function will fail because the lifetime of `&_x` is shorter
+///     // than that of `y`. A short lifetime cannot be coerced into a longer one.
+/// }
+///
+/// fn main() {
+///     // Create variables to be borrowed below.
+///     let (four, nine) = (4, 9);
+///
+///     // Borrows (`&`) of both variables are passed into the function.
+///     print_refs(&four, &nine);
+///     // Any input which is borrowed must outlive the borrower. 
+///     // In other words, the lifetime of `four` and `nine` must 
+///     // be longer than that of `print_refs`.
+///
+///     failed_borrow();
+///     // `failed_borrow` contains no references to force `'a` to be 
+///     // longer than the lifetime of the function, but `'a` is longer.
+///     // Because the lifetime is never constrained, it defaults to `'static`.
+/// }
+/// ```
+pub mod example_02 {}
 
 
 
 
+// Functions
+// https://doc.rust-lang.org/rust-by-example/scope/lifetime/fn.html
 
 
 
-// Functions
-// https://doc.rust-lang.org/rust-by-example/scope/lifetime/fn.html
 
 /*
     Ignoring elision, function signatures with lifetimes have a few constraints:
@@ -126,27 +140,39 @@ fn main() {
 
 // One input reference with lifetime `'a` which must live
 // at least as long as the function.
-fn print_one<'a>(x: &'a i32) {
-    println!("`print_one`: x is {}", x);
-}
 
-// Mutable references are possible with lifetimes as well.
-fn add_one<'a>(x: &'a mut i32) {
-    *x += 1;
+pub mod example_03 {
+    fn print_one<'a>(x: &'a i32) {
+        println!("`print_one`: x is {}", x);
+    }
+
+    // Mutable references are possible with lifetimes as well.
+    fn add_one<'a>(x: &'a mut i32) {
+        *x += 1;
+    }
 }
 
+
+
+
 /*
 Multiple elements with different lifetimes. In this case, it
 would be fine for both to have the same lifetime `'a`, but
 in more complex cases, different lifetimes may be required.
 */
-fn print_multi<'a, 'b>(x: &'a i32, y: &'b i32) {
-    println!("`print_multi`: x is {}, y is {}", x, y);
+
+pub mod example_04 {
+    fn print_multi<'a, 'b>(x: &'a i32, y: &'b i32) {
+        println!("`print_multi`: x is {}, y is {}", x, y);
+    }
+
+    // Returning references that have been passed in is acceptable.
+    // However, the correct lifetime must be returned.
+    fn pass_x<'a, 'b>(x: &'a i32, _: &'b i32) -> &'a i32 { x }
 }
 
-// Returning references that have been passed in is acceptable.
-// However, the correct lifetime must be returned.
-fn pass_x<'a, 'b>(x: &'a i32, _: &'b i32) -> &'a i32 { x }
+
+
 
 /*
     fn invalid_output<'a>() -> &'a String { &String::from("foo") }
@@ -155,26 +181,38 @@ fn pass_x<'a, 'b>(x: &'a i32, _: &'b i32) -> &'a i32 { x }
     reference. Then the data is dropped upon exiting the scope, leaving
     a reference to invalid data to be returned.
 */
-fn main() {
-    let x = 7;
-    let y = 9;
-    
-    print_one(&x);
-    print_multi(&x, &y);
-    
-    let z = pass_x(&x, &y);
-    print_one(z);
-
-    let mut t = 3;
-    add_one(&mut t);
-    print_one(&t);
-}
-
-
-
-
-
 
+/// ```
+/// # fn print_one<'a>(x: &'a i32) {
+/// #     println!("`print_one`: x is {}", x);
+/// # }
+/// #
+/// # fn add_one<'a>(x: &'a mut i32) {
+/// #     *x += 1;
+/// # }
+/// #
+/// # fn print_multi<'a, 'b>(x: &'a i32, y: &'b i32) {
+/// #     println!("`print_multi`: x is {}, y is {}", x, y);
+/// # }
+/// #
+/// # fn pass_x<'a, 'b>(x: &'a i32, _: &'b i32) -> &'a i32 { x }
+/// #
+/// fn main() {
+///     let x = 7;
+///     let y = 9;
+///
+///     print_one(&x);
+///     print_multi(&x, &y);
+///
+///     let z = pass_x(&x, &y);
+///     print_one(z);
+///
+///     let mut t = 3;
+///     add_one(&mut t);
+///     print_one(&t);
+/// }
+/// ```
+pub mod example_05 {}
 
 
 
@@ -184,32 +222,28 @@ fn main() {
 
 // Methods are annotated similarly to functions:
 
-struct Owner(i32);
+pub mod example_06 {
+    struct Owner(i32);
 
-impl Owner {
-    // Annotate lifetimes as in a standalone function.
-    fn add_one<'a>(&'a mut self) { self.0 += 1; }
-    fn print<'a>(&'a self) {
-        println!("`print`: {}", self.0);
+    impl Owner {
+        // Annotate lifetimes as in a standalone function.
+        fn add_one<'a>(&'a mut self) { self.0 += 1; }
+        fn print<'a>(&'a self) {
+            println!("`print`: {}", self.0);
+        }
     }
-}
 
-fn main() {
-    let mut owner = Owner(18);
+    pub fn main() {
+        let mut owner = Owner(18);
 
-    owner.add_one();
-    owner.print();
+        owner.add_one();
+        owner.print();
+    }
 }
 
 
 
 
-
-
-
-
-
-
 // Structs
 // https://doc.rust-lang.org/rust-by-example/scope/lifetime/struct.html
 
@@ -217,86 +251,86 @@ fn main() {
 
 // A type `Borrowed` which houses a reference to an
 // `i32`. The reference to `i32` must outlive `Borrowed`.
-#[derive(Debug)]
-struct Borrowed<'a>(&'a i32);
-
-// Similarly, both references here must outlive this structure.
-#[derive(Debug)]
-struct NamedBorrowed<'a> {
-    x: &'a i32,
-    y: &'a i32,
-}
 
-// An enum which is either an `i32` or a reference to one.
-#[derive(Debug)]
-enum Either<'a> {
-    Num(i32),
-    Ref(&'a i32),
-}
+pub mod example_07 {
+    #[derive(Debug)]
+    struct Borrowed<'a>(&'a i32);
 
-fn main() {
-    let x = 18;
-    let y = 15;
-
-    let single = Borrowed(&x);
-    let double = NamedBorrowed { x: &x, y: &y };
-    let reference = Either::Ref(&x);
-    let number    = Either::Num(y);
-
-    println!("x is borrowed in {:?}", single);
-    println!("x and y are borrowed in {:?}", double);
-    println!("x is borrowed in {:?}", reference);
-    println!("y is *not* borrowed in {:?}", number);
-}
+    // Similarly, both references here must outlive this structure.
+    #[derive(Debug)]
+    struct NamedBorrowed<'a> {
+        x: &'a i32,
+        y: &'a i32,
+    }
 
+    // An enum which is either an `i32` or a reference to one.
+    #[derive(Debug)]
+    enum Either<'a> {
+        Num(i32),
+        Ref(&'a i32),
+    }
 
+    pub fn main() {
+        let x = 18;
+        let y = 15;
 
+        let single = Borrowed(&x);
+        let double = NamedBorrowed { x: &x, y: &y };
+        let reference = Either::Ref(&x);
+        let number    = Either::Num(y);
 
+        println!("x is borrowed in {:?}", single);
+        println!("x and y are borrowed in {:?}", double);
+        println!("x is borrowed in {:?}", reference);
+        println!("y is *not* borrowed in {:?}", number);
+    }
+}
 
 
 
 
+// Traits
+// https://doc.rust-lang.org/rust-by-example/scope/lifetime/trait.html
 
 
 
-// Traits
-// https://doc.rust-lang.org/rust-by-example/scope/lifetime/trait.html
 
 /*
     Annotation of lifetimes in trait methods basically are similar to functions. Note that impl may have annotation of lifetimes too.
 */
 
 // A struct with annotation of lifetimes.
-#[derive(Debug)]
-struct Borrowed<'a> {
-    x: &'a i32,
-}
 
-// Annotate lifetimes to impl.
-impl<'a> Default for Borrowed<'a> {
-    fn default() -> Self {
-        Self {
-            x: &10,
+pub mod example_08 {
+    #[derive(Debug)]
+    struct Borrowed<'a> {
+        x: &'a i32,
+    }
+
+    // Annotate lifetimes to impl.
+    impl<'a> Default for Borrowed<'a> {
+        fn default() -> Self {
+            Self {
+                x: &10,
+            }
         }
     }
-}
 
-fn main() {
-    let b: Borrowed = Default::default();
-    println!("b is {:?}", b);
+    pub fn main() {
+        let b: Borrowed = Default::default();
+        println!("b is {:?}", b);
+    }
 }
 
 
 
 
+// Bounds
+// https://doc.rust-lang.org/rust-by-example/scope/lifetime/lifetime_bounds.html
 
 
 
 
-
-// Bounds
-// https://doc.rust-lang.org/rust-by-example/scope/lifetime/lifetime_bounds.html
-
 /*
     Just like generic types can be bounded, lifetimes (themselves generic) use bounds as well. The : character has a slightly different meaning here, but + is the same. Note how the following read:
 
@@ -306,76 +340,77 @@ fn main() {
     The example below shows the above syntax in action used after keyword where:
 */
 
-use std::fmt::Debug; // Trait to bound with.
+pub mod example_09 {
+    use std::fmt::Debug; // Trait to bound with.
 
-#[derive(Debug)]
-struct Ref<'a, T: 'a>(&'a T);
-// `Ref` contains a reference to a generic type `T` that has
-// an unknown lifetime `'a`. `T` is bounded such that any
-// *references* in `T` must outlive `'a`. Additionally, the lifetime
-// of `Ref` may not exceed `'a`.
+    #[derive(Debug)]
+    struct Ref<'a, T: 'a>(&'a T);
+    // `Ref` contains a reference to a generic type `T` that has
+    // an unknown lifetime `'a`. `T` is bounded such that any
+    // *references* in `T` must outlive `'a`. Additionally, the lifetime
+    // of `Ref` may not exceed `'a`.
 
-// A generic function which prints using the `Debug` trait.
-fn print<T>(t: T) where
-    T: Debug {
-    println!("`print`: t is {:?}", t);
-}
+    // A generic function which prints using the `Debug` trait.
+    fn print<T>(t: T) where
+        T: Debug {
+        println!("`print`: t is {:?}", t);
+    }
 
-// Here a reference to `T` is taken where `T` implements
-// `Debug` and all *references* in `T` outlive `'a`. In
-// addition, `'a` must outlive the function.
-fn print_ref<'a, T>(t: &'a T) where
-    T: Debug + 'a {
-    println!("`print_ref`: t is {:?}", t);
-}
+    // Here a reference to `T` is taken where `T` implements
+    // `Debug` and all *references* in `T` outlive `'a`. In
+    // addition, `'a` must outlive the function.
+    fn print_ref<'a, T>(t: &'a T) where
+        T: Debug + 'a {
+        println!("`print_ref`: t is {:?}", t);
+    }
 
-fn main() {
-    let x = 7;
-    let ref_x = Ref(&x);
+    pub fn main() {
+        let x = 7;
+        let ref_x = Ref(&x);
 
-    print_ref(&ref_x);
-    print(ref_x);
+        print_ref(&ref_x);
+        print(ref_x);
+    }
 }
 
 
 
 
+// Coercion
+// https://doc.rust-lang.org/rust-by-example/scope/lifetime/lifetime_coercion.html
 
 
 
 
-// Coercion
-// https://doc.rust-lang.org/rust-by-example/scope/lifetime/lifetime_coercion.html
-
 /*
     A longer lifetime can be coerced into a shorter one so that it works inside a scope it normally wouldn't work in. This comes in the form of inferred coercion by the Rust compiler, and also in the form of declaring a lifetime difference:
 */
 
 // Here, Rust infers a lifetime that is as short as possible.
 // The two references are then coerced to that lifetime.
-fn multiply<'a>(first: &'a i32, second: &'a i32) -> i32 {
-    first * second
-}
 
-// `<'a: 'b, 'b>` reads as lifetime `'a` is at least as long as `'b`.
-// Here, we take in an `&'a i32` and return a `&'b i32` as a result of coercion.
-fn choose_first<'a: 'b, 'b>(first: &'a i32, _: &'b i32) -> &'b i32 {
-    first
-}
+pub mod example_10 {
+    fn multiply<'a>(first: &'a i32, second: &'a i32) -> i32 {
+        first * second
+    }
 
-fn main() {
-    let first = 2; // Longer lifetime
-    
-    {
-        let second = 3; // Shorter lifetime
-        
-        println!("The product is {}", multiply(&first, &second));
-        println!("{} is the first", choose_first(&first, &second));
-    };
-}
+    // `<'a: 'b, 'b>` reads as lifetime `'a` is at least as long as `'b`.
+    // Here, we take in an `&'a i32` and return a `&'b i32` as a result of coercion.
+    fn choose_first<'a: 'b, 'b>(first: &'a i32, _: &'b i32) -> &'b i32 {
+        first
+    }
 
+    pub fn main() {
+        let first = 2; // Longer lifetime
 
+        {
+            let second = 3; // Shorter lifetime
 
+            println!("The product is {}", multiply(&first, &second));
+            println!("{} is the first", choose_first(&first, &second));
+        };
+    }
+}
 
 
 
@@ -384,15 +419,25 @@ fn main() {
 // https://doc.rust-lang.org/rust-by-example/scope/lifetime/static_lifetime.html
 
 
+
+
 /*
     Rust has a few reserved lifetime names. One of those is 'static. You might encounter it in two situations:
 */
 
 // A reference with 'static lifetime:
-let s: &'static str = "hello world";
 
-// 'static as part of a trait bound:
-fn generic<T>(x: T) where T: 'static {}
+pub mod example_11 {
+    pub fn main() {
+        let s: &'static str = "hello world";
+
+        // 'static as part of a trait bound:
+        fn generic<T>(x: T) where T: 'static {}
+    }
+}
+
+
+
 
 /*
     Both are related but subtly different and this is a common source for confusion when learning Rust. Here are some examples for each situation:
@@ -401,13 +446,11 @@ fn generic<T>(x: T) where T: 'static {}
 
 
 
+// Reference lifetime
 
 
 
 
-
-// Reference lifetime
-
 /*
     As a reference lifetime 'static indicates that the data pointed to by the reference lives for the entire lifetime of the running program. It can still be coerced to a shorter lifetime.
 
@@ -420,46 +463,47 @@ fn generic<T>(x: T) where T: 'static {}
 */
 
 // Make a constant with `'static` lifetime.
-static NUM: i32 = 18;
 
-// Returns a reference to `NUM` where its `'static`
-// lifetime is coerced to that of the input argument.
-fn coerce_static<'a>(_: &'a i32) -> &'a i32 {
-    &NUM
-}
+pub mod example_12 {
+    static NUM: i32 = 18;
 
-fn main() {
-    {
-        // Make a `string` literal and print it:
-        let static_string = "I'm in read-only memory";
-        println!("static_string: {}", static_string);
-
-        // When `static_string` goes out of scope, the reference
-        // can no longer be used, but the data remains in the binary.
+    // Returns a reference to `NUM` where its `'static`
+    // lifetime is coerced to that of the input argument.
+    fn coerce_static<'a>(_: &'a i32) -> &'a i32 {
+        &NUM
     }
 
-    {
-        // Make an integer to use for `coerce_static`:
-        let lifetime_num = 9;
+    pub fn main() {
+        {
+            // Make a `string` literal and print it:
+            let static_string = "I'm in read-only memory";
+            println!("static_string: {}", static_string);
 
-        // Coerce `NUM` to lifetime of `lifetime_num`:
-        let coerced_static = coerce_static(&lifetime_num);
+            // When `static_string` goes out of scope, the reference
+            // can no longer be used, but the data remains in the binary.
+        }
 
-        println!("coerced_static: {}", coerced_static);
-    }
+        {
+            // Make an integer to use for `coerce_static`:
+            let lifetime_num = 9;
 
-    println!("NUM: {} stays accessible!", NUM);
-}
+            // Coerce `NUM` to lifetime of `lifetime_num`:
+            let coerced_static = coerce_static(&lifetime_num);
 
+            println!("coerced_static: {}", coerced_static);
+        }
 
+        println!("NUM: {} stays accessible!", NUM);
+    }
+}
 
 
 
 
+// Trait bound
 
 
 
-// Trait bound
 
 /*
     As a trait bound, it means the type does not contain any non-static references. Eg. the receiver can hold on to the type for as long as they want and it will never become invalid until they drop it.
@@ -467,22 +511,24 @@ fn main() {
     It's important to understand this means that any owned data always passes a 'static lifetime bound, but a reference to that owned data generally does not:
 */
 
-use std::fmt::Debug;
-
-fn print_it( input: impl Debug + 'static ) {
-    println!( "'static value passed in is: {:?}", input );
-}
-
-fn main() {
-    // i is owned and contains no references, thus it's 'static:
-    let i = 5;
-    print_it(i);
-
-    // oops, &i only has the lifetime defined by the scope of
-    // main(), so it's not 'static:
-    print_it(&i);
-}
-
+/// ```compile_fail
+/// use std::fmt::Debug;
+///
+/// fn print_it( input: impl Debug + 'static ) {
+///     println!( "'static value passed in is: {:?}", input );
+/// }
+///
+/// fn main() {
+///     // i is owned and contains no references, thus it's 'static:
+///     let i = 5;
+///     print_it(i);
+///
+///     // oops, &i only has the lifetime defined by the scope of
+///     // main(), so it's not 'static:
+///     print_it(&i);
+/// }
+/// ```
+pub mod example_13 {}
 
 
 
@@ -505,16 +551,12 @@ fn main() {
 
 
 
+// Elision
+// https://doc.rust-lang.org/rust-by-example/scope/lifetime/elision.html
 
 
 
 
-
-
-
-// Elision
-// https://doc.rust-lang.org/rust-by-example/scope/lifetime/elision.html
-
 /*
     Some lifetime patterns are overwhelmingly common and so the borrow checker will allow you to omit them to save typing and to improve readability. This is known as elision. Elision exists in Rust solely because these patterns are common.
 
@@ -523,29 +565,29 @@ fn main() {
 
 // `elided_input` and `annotated_input` essentially have identical signatures
 // because the lifetime of `elided_input` is inferred by the compiler:
-fn elided_input(x: &i32) {
-    println!("`elided_input`: {}", x);
-}
 
-fn annotated_input<'a>(x: &'a i32) {
-    println!("`annotated_input`: {}", x);
-}
-
-// Similarly, `elided_pass` and `annotated_pass` have identical signatures
-// because the lifetime is added implicitly to `elided_pass`:
-fn elided_pass(x: &i32) -> &i32 { x }
-
-fn annotated_pass<'a>(x: &'a i32) -> &'a i32 { x }
+pub mod example_14 {
+    fn elided_input(x: &i32) {
+        println!("`elided_input`: {}", x);
+    }
 
-fn main() {
-    let x = 3;
+    fn annotated_input<'a>(x: &'a i32) {
+        println!("`annotated_input`: {}", x);
+    }
 
-    elided_input(&x);
-    annotated_input(&x);
+    // Similarly, `elided_pass` and `annotated_pass` have identical signatures
+    // because the lifetime is added implicitly to `elided_pass`:
+    fn elided_pass(x: &i32) -> &i32 { x }
 
-    println!("`elided_pass`: {}", elided_pass(&x));
-    println!("`annotated_pass`: {}", annotated_pass(&x));
-}
+    fn annotated_pass<'a>(x: &'a i32) -> &'a i32 { x }
 
+    pub fn main() {
+        let x = 3;
 
+        elided_input(&x);
+        annotated_input(&x);
 
+        println!("`elided_pass`: {}", elided_pass(&x));
+        println!("`annotated_pass`: {}", annotated_pass(&x));
+    }
+}
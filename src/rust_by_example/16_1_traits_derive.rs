@@ -2,96 +2,94 @@
 // https://doc.rust-lang.org/rust-by-example/trait.html
 
 
+
+
 /*
     A trait is a collection of methods defined for an unknown type: Self. They can access other methods declared in the same trait.
 
     Traits can be implemented for any data type. In the example below, we define Animal, a group of methods. The Animal trait is then implemented for the Sheep data type, allowing the use of methods from Animal with a Sheep.
 */
 
-struct Sheep { naked: bool, name: &'static str }
+pub mod example_01 {
+    struct Sheep { naked: bool, name: &'static str }
 
-trait Animal {
-    // Associated function signature; `Self` refers to the implementor type.
-    fn new(name: &'static str) -> Self;
+    trait Animal {
+        // Associated function signature; `Self` refers to the implementor type.
+        fn new(name: &'static str) -> Self;
 
-    // Method signatures; these will return a string.
-    fn name(&self) -> &'static str;
-    fn noise(&self) -> &'static str;
+        // Method signatures; these will return a string.
+        fn name(&self) -> &'static str;
+        fn noise(&self) -> &'static str;
 
-    // Traits can provide default method definitions.
-    fn talk(&self) {
-        println!("{} says {}", self.name(), self.noise());
+        // Traits can provide default method definitions.
+        fn talk(&self) {
+            println!("{} says {}", self.name(), self.noise());
+        }
     }
-}
 
-impl Sheep {
-    fn is_naked(&self) -> bool {
-        self.naked
-    }
+    impl Sheep {
+        fn is_naked(&self) -> bool {
+            self.naked
+        }
 
-    fn shear(&mut self) {
-        if self.is_naked() {
-            // Implementor methods can use the implementor's trait methods.
-            println!("{} is already naked...", self.name());
-        } else {
-            println!("{} gets a haircut!", self.name);
+        fn shear(&mut self) {
+            if self.is_naked() {
+                // Implementor methods can use the implementor's trait methods.
+                println!("{} is already naked...", self.name());
+            } else {
+                println!("{} gets a haircut!", self.name);
 
-            self.naked = true;
+                self.naked = true;
+            }
         }
     }
-}
 
-// Implement the `Animal` trait for `Sheep`.
-impl Animal for Sheep {
-    // `Self` is the implementor type: `Sheep`.
-    fn new(name: &'static str) -> Sheep {
-        Sheep { name: name, naked: false }
-    }
+    // Implement the `Animal` trait for `Sheep`.
+    impl Animal for Sheep {
+        // `Self` is the implementor type: `Sheep`.
+        fn new(name: &'static str) -> Sheep {
+            Sheep { name: name, naked: false }
+        }
 
-    fn name(&self) -> &'static str {
-        self.name
-    }
+        fn name(&self) -> &'static str {
+            self.name
+        }
 
-    fn noise(&self) -> &'static str {
-        if self.is_naked() {
-            "baaaaah?"
-        } else {
-            "baaaaah!"
+        fn noise(&self) -> &'static str {
+            if self.is_naked() {
+                "baaaaah?"
+            } else {
+                "baaaaah!"
+            }
+        }
+
+        // Default trait methods can be overridden.
+        fn talk(&self) {
+            // For example, we can add some quiet contemplation.
+            println!("{} pauses briefly... {}", self.name, self.noise());
         }
     }
-    
-    // Default trait methods can be overridden.
-    fn talk(&self) {
-        // For example, we can add some quiet contemplation.
-        println!("{} pauses briefly... {}", self.name, self.noise());
-    }
-}
 
-fn main() {
-    // Type annotation is necessary in this case.
-    let mut dolly: Sheep = Animal::new("Dolly");
-    // TODO ^ Try removing the type annotations.
+    pub fn main() {
+        // Type annotation is necessary in this case.
+        let mut dolly: Sheep = Animal::new("Dolly");
+        // TODO ^ Try removing the type annotations.
 
-    dolly.talk();
-    dolly.shear();
-    dolly.talk();
+        dolly.talk();
+        dolly.shear();
+        dolly.talk();
+    }
 }
 
 
 
 
-
-
-
-
-
-
-
-
 // Derive
 // https://doc.rust-lang.org/rust-by-example/trait/derive.html
 
 
+
+
 /*
     The compiler is capable of providing basic implementations for some traits via the #[derive] attribute. These traits can still be manually implemented if a more complex behavior is required.
 
@@ -106,52 +104,50 @@ fn main() {
 */
 
 // `Centimeters`, a tuple struct that can be compared
-#[derive(PartialEq, PartialOrd)]
-struct Centimeters(f64);
 
-// `Inches`, a tuple struct that can be printed
-#[derive(Debug)]
-struct Inches(i32);
+pub mod example_02 {
+    #[derive(PartialEq, PartialOrd)]
+    struct Centimeters(f64);
+
+    // `Inches`, a tuple struct that can be printed
+    #[derive(Debug)]
+    struct Inches(i32);
 
-impl Inches {
-    fn to_centimeters(&self) -> Centimeters {
-        let &Inches(inches) = self;
+    impl Inches {
+        fn to_centimeters(&self) -> Centimeters {
+            let &Inches(inches) = self;
 
-        Centimeters(inches as f64 * 2.54)
+            Centimeters(inches as f64 * 2.54)
+        }
     }
-}
 
-// `Seconds`, a tuple struct with no additional attributes
-struct Seconds(i32);
+    // `Seconds`, a tuple struct with no additional attributes
+    struct Seconds(i32);
 
-fn main() {
-    let _one_second = Seconds(1);
+    pub fn main() {
+        let _one_second = Seconds(1);
 
-    // Error: `Seconds` can't be printed; it doesn't implement the `Debug` trait
-    //println!("One second looks like: {:?}", _one_second);
-    // TODO ^ Try uncommenting this line
+        // Error: `Seconds` can't be printed; it doesn't implement the `Debug` trait
+        //println!("One second looks like: {:?}", _one_second);
+        // TODO ^ Try uncommenting this line
 
-    // Error: `Seconds` can't be compared; it doesn't implement the `PartialEq` trait
-    //let _this_is_true = (_one_second == _one_second);
-    // TODO ^ Try uncommenting this line
+        // Error: `Seconds` can't be compared; it doesn't implement the `PartialEq` trait
+        //let _this_is_true = (_one_second == _one_second);
+        // TODO ^ Try uncommenting this line
 
-    let foot = Inches(12);
+        let foot = Inches(12);
 
-    println!("One foot equals {:?}", foot);
+        println!("One foot equals {:?}", foot);
 
-    let meter = Centimeters(100.0);
+        let meter = Centimeters(100.0);
 
-    let cmp =
-        if foot.to_centimeters() < meter {
-            "smaller"
-        } else {
-            "bigger"
-        };
+        let cmp =
+            if foot.to_centimeters() < meter {
+                "smaller"
+            } else {
+                "bigger"
+            };
 
-    println!("One foot is {} than one meter.", cmp);
+        println!("One foot is {} than one meter.", cmp);
+    }
 }
-
-
-
-
-
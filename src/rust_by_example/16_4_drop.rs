@@ -1,6 +1,9 @@
 // Drop
 // https://doc.rust-lang.org/rust-by-example/trait/drop.html
 
+
+
+
 /*
     The Drop trait only has one method: drop, which is called automatically when an object goes out of scope. The main use of the Drop trait is to free the resources that the implementor instance owns.
 
@@ -9,44 +12,45 @@
     The following example adds a print to console to the drop function to announce when it is called.
 */
 
-struct Droppable {
-    name: &'static str,
-}
-
-// This trivial implementation of `drop` adds a print to console.
-impl Drop for Droppable {
-    fn drop(&mut self) {
-        println!("> Dropping {}", self.name);
+pub mod example_01 {
+    struct Droppable {
+        name: &'static str,
     }
-}
 
-fn main() {
-    let _a = Droppable { name: "a" };
+    // This trivial implementation of `drop` adds a print to console.
+    impl Drop for Droppable {
+        fn drop(&mut self) {
+            println!("> Dropping {}", self.name);
+        }
+    }
 
-    // block A
-    {
-        let _b = Droppable { name: "b" };
+    pub fn main() {
+        let _a = Droppable { name: "a" };
 
-        // block B
+        // block A
         {
-            let _c = Droppable { name: "c" };
-            let _d = Droppable { name: "d" };
+            let _b = Droppable { name: "b" };
 
-            println!("Exiting block B");
-        }
-        println!("Just exited block B");
+            // block B
+            {
+                let _c = Droppable { name: "c" };
+                let _d = Droppable { name: "d" };
 
-        println!("Exiting block A");
-    }
-    println!("Just exited block A");
+                println!("Exiting block B");
+            }
+            println!("Just exited block B");
 
-    // Variable can be manually dropped using the `drop` function
-    drop(_a);
-    // TODO ^ Try commenting this line
+            println!("Exiting block A");
+        }
+        println!("Just exited block A");
 
-    println!("end of the main function");
+        // Variable can be manually dropped using the `drop` function
+        drop(_a);
+        // TODO ^ Try commenting this line
 
-    // `_a` *won't* be `drop`ed again here, because it already has been
-    // (manually) `drop`ed
-}
+        println!("end of the main function");
 
+        // `_a` *won't* be `drop`ed again here, because it already has been
+        // (manually) `drop`ed
+    }
+}
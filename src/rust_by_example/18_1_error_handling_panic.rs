@@ -1,6 +1,9 @@
 // Error handling
 // https://doc.rust-lang.org/rust-by-example/error.html
 
+
+
+
 /*
     Error handling is the process of handling the possibility of failure. For example, failing to read a file and then continuing to use that bad input would clearly be problematic. Noticing and explicitly managing those errors saves the rest of the program from various pitfalls.
 
@@ -18,24 +21,26 @@
 
 
 
-
-
 // panic
 // https://doc.rust-lang.org/rust-by-example/error/panic.html
 
 
+
+
 /*
     The simplest error handling mechanism we will see is panic. It prints an error message, starts unwinding the stack, and usually exits the program. Here, we explicitly call panic on our error condition:
 */
 
-fn drink(beverage: &str) {
-    // You shouldn't drink too much sugary beverages.
-    if beverage == "lemonade" { panic!("AAAaaaaa!!!!"); }
+pub mod example_01 {
+    fn drink(beverage: &str) {
+        // You shouldn't drink too much sugary beverages.
+        if beverage == "lemonade" { panic!("AAAaaaaa!!!!"); }
 
-    println!("Some refreshing {} is all I need.", beverage);
-}
+        println!("Some refreshing {} is all I need.", beverage);
+    }
 
-fn main() {
-    drink("water");
-    drink("lemonade");
+    pub fn main() {
+        drink("water");
+        drink("lemonade");
+    }
 }
@@ -2,26 +2,30 @@
 // https://doc.rust-lang.org/rust-by-example/error/abort_unwind.html
 
 
+
+
 /*
     The previous section illustrates the error handling mechanism panic. Different code paths can be conditionally compiled based on the panic setting. The current values available are unwind and abort.
 
     Building on the prior lemonade example, we explicitly use the panic strategy to execise different lines of code.
 */
 
+pub mod example_01 {
+    fn drink(beverage: &str) {
+       // You shouldn't drink too much sugary beverages.
+        if beverage == "lemonade" {
+            if cfg!(panic="abort"){ println!("This is not your party. Run!!!!");}
+            else{ println!("Spit it out!!!!");}
+        }
+        else{ println!("Some refreshing {} is all I need.", beverage); }
+    }
 
-fn drink(beverage: &str) {
-   // You shouldn't drink too much sugary beverages.
-    if beverage == "lemonade" {
-        if cfg!(panic="abort"){ println!("This is not your party. Run!!!!");}
-        else{ println!("Spit it out!!!!");}
+    pub fn main() {
+        drink("water");
+        drink("lemonade");
     }
-    else{ println!("Some refreshing {} is all I need.", beverage); }
 }
 
-fn main() {
-    drink("water");
-    drink("lemonade");
-}
 
 
 
@@ -29,21 +33,22 @@ fn main() {
     Here is another example focusing on rewriting drink() and explicitly use the unwind keyword.
 */
 
+pub mod example_02 {
+    #[cfg(panic = "unwind")]
+    fn ah(){ println!("Spit it out!!!!");}
 
-#[cfg(panic = "unwind")]
-fn ah(){ println!("Spit it out!!!!");}
-
-#[cfg(not(panic="unwind"))]
-fn ah(){ println!("This is not your party. Run!!!!");}
+    #[cfg(not(panic="unwind"))]
+    fn ah(){ println!("This is not your party. Run!!!!");}
 
-fn drink(beverage: &str){
-    if beverage == "lemonade"{ ah();}
-    else{println!("Some refreshing {} is all I need.", beverage);}
-}
+    fn drink(beverage: &str){
+        if beverage == "lemonade"{ ah();}
+        else{println!("Some refreshing {} is all I need.", beverage);}
+    }
 
-fn main() {
-    drink("water");
-    drink("lemonade");
+    pub fn main() {
+        drink("water");
+        drink("lemonade");
+    }
 }
 
 
@@ -54,5 +59,3 @@ fn main() {
 
     rustc  lemonade.rs -C panic=abort
 */
-
-
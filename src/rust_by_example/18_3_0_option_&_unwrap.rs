@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/error/option_unwrap.html
 
 
+
+
 /*
     In the last example, we showed that we can induce program failure at will. We told our program to panic if we drink a sugary lemonade. But what if we expect some drink but don't receive one? That case would be just as bad, so it needs to be handled!
 
@@ -18,42 +20,44 @@
 */
 
 
+
+
 // The adult has seen it all, and can handle any drink well.
 // All drinks are handled explicitly using `match`.
-fn give_adult(drink: Option<&str>) {
-    // Specify a course of action for each case.
-    match drink {
-        Some("lemonade") => println!("Yuck! Too sugary."),
-        Some(inner)   => println!("{}? How nice.", inner),
-        None          => println!("No drink? Oh well."),
+
+pub mod example_01 {
+    fn give_adult(drink: Option<&str>) {
+        // Specify a course of action for each case.
+        match drink {
+            Some("lemonade") => println!("Yuck! Too sugary."),
+            Some(inner)   => println!("{}? How nice.", inner),
+            None          => println!("No drink? Oh well."),
+        }
     }
-}
 
-// Others will `panic` before drinking sugary drinks.
-// All drinks are handled implicitly using `unwrap`.
-fn drink(drink: Option<&str>) {
-    // `unwrap` returns a `panic` when it receives a `None`.
-    let inside = drink.unwrap();
-    if inside == "lemonade" { panic!("AAAaaaaa!!!!"); }
+    // Others will `panic` before drinking sugary drinks.
+    // All drinks are handled implicitly using `unwrap`.
+    fn drink(drink: Option<&str>) {
+        // `unwrap` returns a `panic` when it receives a `None`.
+        let inside = drink.unwrap();
+        if inside == "lemonade" { panic!("AAAaaaaa!!!!"); }
 
-    println!("I love {}s!!!!!", inside);
-}
+        println!("I love {}s!!!!!", inside);
+    }
 
-fn main() {
-    let water  = Some("water");
-    let lemonade = Some("lemonade");
-    let void  = None;
+    pub fn main() {
+        let water  = Some("water");
+        let lemonade = Some("lemonade");
+        let void  = None;
 
-    give_adult(water);
-    give_adult(lemonade);
-    give_adult(void);
+        give_adult(water);
+        give_adult(lemonade);
+        give_adult(void);
 
-    let coffee = Some("coffee");
-    let nothing = None;
+        let coffee = Some("coffee");
+        let nothing = None;
 
-    drink(coffee);
-    drink(nothing);
+        drink(coffee);
+        drink(nothing);
+    }
 }
-
-
-
@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/error/result.html
 
 
+
+
 /*
     Result is a richer version of the Option type that describes possible error instead of possible absence.
 
@@ -19,21 +21,23 @@
     Let's see what happens when we successfully and unsuccessfully parse() a string:
 */
 
-
-fn multiply(first_number_str: &str, second_number_str: &str) -> i32 {
-    // Let's try using `unwrap()` to get the number out. Will it bite us?
-    let first_number = first_number_str.parse::<i32>().unwrap();
-    let second_number = second_number_str.parse::<i32>().unwrap();
-    first_number * second_number
+pub mod example_01 {
+    fn multiply(first_number_str: &str, second_number_str: &str) -> i32 {
+        // Let's try using `unwrap()` to get the number out. Will it bite us?
+        let first_number = first_number_str.parse::<i32>().unwrap();
+        let second_number = second_number_str.parse::<i32>().unwrap();
+        first_number * second_number
+    }
+
+    pub fn main() {
+        let twenty = multiply("10", "2");
+        println!("double is {}", twenty);
+
+        let tt = multiply("t", "2");
+        println!("double is {}", tt);
+    }
 }
 
-fn main() {
-    let twenty = multiply("10", "2");
-    println!("double is {}", twenty);
-
-    let tt = multiply("t", "2");
-    println!("double is {}", tt);
-}
 
 
 
@@ -46,37 +50,35 @@ fn main() {
 
 
 
-
-
-
-
-
-
 /*
     Using Result in main
 
     The Result type can also be the return type of the main function if specified explicitly. Typically the main function will be of the form:
 */
 
-fn main() {
-    println!("Hello World!");
+pub mod example_02 {
+    pub fn main() {
+        println!("Hello World!");
+    }
 }
 
 
 
+
 /*
     However main is also able to have a return type of Result. If an error occurs within the main function it will return an error code and print a debug representation of the error (using the Debug trait). The following example shows such a scenario and touches on aspects covered in the following section.
 */
-use std::num::ParseIntError;
-
-fn main() -> Result<(), ParseIntError> {
-    let number_str = "10";
-    let number = match number_str.parse::<i32>() {
-        Ok(number)  => number,
-        Err(e) => return Err(e),
-    };
-    println!("{}", number);
-    Ok(())
-}
-
 
+pub mod example_03 {
+    use std::num::ParseIntError;
+
+    pub fn main() -> Result<(), ParseIntError> {
+        let number_str = "10";
+        let number = match number_str.parse::<i32>() {
+            Ok(number)  => number,
+            Err(e) => return Err(e),
+        };
+        println!("{}", number);
+        Ok(())
+    }
+}
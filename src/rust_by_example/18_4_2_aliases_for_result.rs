@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/error/result/result_alias.html
 
 
+
+
 /*
     How about when we want to reuse a specific Result type many times? Recall that Rust allows us to create aliases. Conveniently, we can define one for the specific Result in question.
 
@@ -10,31 +12,29 @@
     Here's a quick example to show off the syntax:
 */
 
-use std::num::ParseIntError;
+pub mod example_01 {
+    use std::num::ParseIntError;
 
-// Define a generic alias for a `Result` with the error type `ParseIntError`.
-type AliasedResult<T> = Result<T, ParseIntError>;
+    // Define a generic alias for a `Result` with the error type `ParseIntError`.
+    type AliasedResult<T> = Result<T, ParseIntError>;
 
-// Use the above alias to refer to our specific `Result` type.
-fn multiply(first_number_str: &str, second_number_str: &str) -> AliasedResult<i32> {
-    first_number_str.parse::<i32>().and_then(|first_number| {
-        second_number_str.parse::<i32>().map(|second_number| first_number * second_number)
-    })
-}
+    // Use the above alias to refer to our specific `Result` type.
+    fn multiply(first_number_str: &str, second_number_str: &str) -> AliasedResult<i32> {
+        first_number_str.parse::<i32>().and_then(|first_number| {
+            second_number_str.parse::<i32>().map(|second_number| first_number * second_number)
+        })
+    }
 
-// Here, the alias again allows us to save some space.
-fn print(result: AliasedResult<i32>) {
-    match result {
-        Ok(n)  => println!("n is {}", n),
-        Err(e) => println!("Error: {}", e),
+    // Here, the alias again allows us to save some space.
+    fn print(result: AliasedResult<i32>) {
+        match result {
+            Ok(n)  => println!("n is {}", n),
+            Err(e) => println!("Error: {}", e),
+        }
     }
-}
 
-fn main() {
-    print(multiply("10", "2"));
-    print(multiply("t", "2"));
+    pub fn main() {
+        print(multiply("10", "2"));
+        print(multiply("t", "2"));
+    }
 }
-
-
-
-
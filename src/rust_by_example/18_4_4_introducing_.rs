@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/error/result/enter_question_mark.html
 
 
+
+
 /*
     Sometimes we just want the simplicity of unwrap without the possibility of a panic. Until now, unwrap has forced us to nest deeper and deeper when what we really wanted was to get the variable out. This is exactly the purpose of ?.
 
@@ -13,34 +15,32 @@
     ? is almost1 exactly equivalent to an unwrap which returns instead of panicking on Errs. Let's see how we can simplify the earlier example that used combinators:
 */
 
+pub mod example_01 {
+    use std::num::ParseIntError;
 
-use std::num::ParseIntError;
-
-fn multiply(first_number_str: &str, second_number_str: &str) -> Result<i32, ParseIntError> {
-    let first_number = first_number_str.parse::<i32>()?;
-    let second_number = second_number_str.parse::<i32>()?;
+    fn multiply(first_number_str: &str, second_number_str: &str) -> Result<i32, ParseIntError> {
+        let first_number = first_number_str.parse::<i32>()?;
+        let second_number = second_number_str.parse::<i32>()?;
 
-    Ok(first_number * second_number)
-}
+        Ok(first_number * second_number)
+    }
 
-fn print(result: Result<i32, ParseIntError>) {
-    match result {
-        Ok(n)  => println!("n is {}", n),
-        Err(e) => println!("Error: {}", e),
+    fn print(result: Result<i32, ParseIntError>) {
+        match result {
+            Ok(n)  => println!("n is {}", n),
+            Err(e) => println!("Error: {}", e),
+        }
     }
-}
 
-fn main() {
-    print(multiply("10", "2"));
-    print(multiply("t", "2"));
+    pub fn main() {
+        print(multiply("10", "2"));
+        print(multiply("t", "2"));
+    }
 }
 
 
 
 
-
-
-
 /*
     The try! macro
 
@@ -50,27 +50,26 @@ fn main() {
 // To compile and run this example without errors, while using Cargo, change the value 
 // of the `edition` field, in the `[package]` section of the `Cargo.toml` file, to "2015".
 
-use std::num::ParseIntError;
-
-fn multiply(first_number_str: &str, second_number_str: &str) -> Result<i32, ParseIntError> {
-    let first_number = try!(first_number_str.parse::<i32>());
-    let second_number = try!(second_number_str.parse::<i32>());
-
-    Ok(first_number * second_number)
-}
-
-fn print(result: Result<i32, ParseIntError>) {
-    match result {
-        Ok(n)  => println!("n is {}", n),
-        Err(e) => println!("Error: {}", e),
-    }
-}
-
-fn main() {
-    print(multiply("10", "2"));
-    print(multiply("t", "2"));
-}
-
-
-
-
+/// ```edition2015
+/// use std::num::ParseIntError;
+///
+/// fn multiply(first_number_str: &str, second_number_str: &str) -> Result<i32, ParseIntError> {
+///     let first_number = try!(first_number_str.parse::<i32>());
+///     let second_number = try!(second_number_str.parse::<i32>());
+///
+///     Ok(first_number * second_number)
+/// }
+///
+/// fn print(result: Result<i32, ParseIntError>) {
+///     match result {
+///         Ok(n)  => println!("n is {}", n),
+///         Err(e) => println!("Error: {}", e),
+///     }
+/// }
+///
+/// fn main() {
+///     print(multiply("10", "2"));
+///     print(multiply("t", "2"));
+/// }
+/// ```
+pub mod example_02 {}
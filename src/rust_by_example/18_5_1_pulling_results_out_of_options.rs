@@ -2,31 +2,34 @@
 // https://doc.rust-lang.org/rust-by-example/error/multiple_error_types/option_result.html
 
 
+
+
 /*
     The most basic way of handling mixed error types is to just embed them in each other.
 */
 
+pub mod example_01 {
+    use std::num::ParseIntError;
 
-use std::num::ParseIntError;
-
-fn double_first(vec: Vec<&str>) -> Option<Result<i32, ParseIntError>> {
-    vec.first().map(|first| {
-        first.parse::<i32>().map(|n| 2 * n)
-    })
-}
+    fn double_first(vec: Vec<&str>) -> Option<Result<i32, ParseIntError>> {
+        vec.first().map(|first| {
+            first.parse::<i32>().map(|n| 2 * n)
+        })
+    }
 
-fn main() {
-    let numbers = vec!["42", "93", "18"];
-    let empty = vec![];
-    let strings = vec!["tofu", "93", "18"];
+    pub fn main() {
+        let numbers = vec!["42", "93", "18"];
+        let empty = vec![];
+        let strings = vec!["tofu", "93", "18"];
 
-    println!("The first doubled is {:?}", double_first(numbers));
+        println!("The first doubled is {:?}", double_first(numbers));
 
-    println!("The first doubled is {:?}", double_first(empty));
-    // Error 1: the input vector is empty
+        println!("The first doubled is {:?}", double_first(empty));
+        // Error 1: the input vector is empty
 
-    println!("The first doubled is {:?}", double_first(strings));
-    // Error 2: the element doesn't parse to a number
+        println!("The first doubled is {:?}", double_first(strings));
+        // Error 2: the element doesn't parse to a number
+    }
 }
 
 
@@ -36,25 +39,24 @@ fn main() {
     There are times when we'll want to stop processing on errors (like with ?) but keep going when the Option is None. A couple of combinators come in handy to swap the Result and Option.
 */
 
+pub mod example_02 {
+    use std::num::ParseIntError;
 
-use std::num::ParseIntError;
+    fn double_first(vec: Vec<&str>) -> Result<Option<i32>, ParseIntError> {
+        let opt = vec.first().map(|first| {
+            first.parse::<i32>().map(|n| 2 * n)
+        });
 
-fn double_first(vec: Vec<&str>) -> Result<Option<i32>, ParseIntError> {
-    let opt = vec.first().map(|first| {
-        first.parse::<i32>().map(|n| 2 * n)
-    });
+        opt.map_or(Ok(None), |r| r.map(Some))
+    }
 
-    opt.map_or(Ok(None), |r| r.map(Some))
-}
-
-fn main() {
-    let numbers = vec!["42", "93", "18"];
-    let empty = vec![];
-    let strings = vec!["tofu", "93", "18"];
+    pub fn main() {
+        let numbers = vec!["42", "93", "18"];
+        let empty = vec![];
+        let strings = vec!["tofu", "93", "18"];
 
-    println!("The first doubled is {:?}", double_first(numbers));
-    println!("The first doubled is {:?}", double_first(empty));
-    println!("The first doubled is {:?}", double_first(strings));
+        println!("The first doubled is {:?}", double_first(numbers));
+        println!("The first doubled is {:?}", double_first(empty));
+        println!("The first doubled is {:?}", double_first(strings));
+    }
 }
-
-
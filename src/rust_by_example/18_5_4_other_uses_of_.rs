@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/error/multiple_error_types/reenter_question_mark.html
 
 
+
+
 /*
     Notice in the previous example that our immediate reaction to calling parse is to map the error from a library error into a boxed error:
 
@@ -15,55 +17,53 @@
     Here, we rewrite the previous example using ?. As a result, the map_err will go away when From::from is implemented for our error type:
 */
 
-use std::error;
-use std::fmt;
+pub mod example_01 {
+    use std::error;
+    use std::fmt;
 
-// Change the alias to `Box<dyn error::Error>`.
-type Result<T> = std::result::Result<T, Box<dyn error::Error>>;
+    // Change the alias to `Box<dyn error::Error>`.
+    type Result<T> = std::result::Result<T, Box<dyn error::Error>>;
 
-#[derive(Debug)]
-struct EmptyVec;
+    #[derive(Debug)]
+    struct EmptyVec;
 
-impl fmt::Display for EmptyVec {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "invalid first item to double")
+    impl fmt::Display for EmptyVec {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "invalid first item to double")
+        }
     }
-}
 
-impl error::Error for EmptyVec {}
+    impl error::Error for EmptyVec {}
 
-// The same structure as before but rather than chain all `Results`
-// and `Options` along, we `?` to get the inner value out immediately.
-fn double_first(vec: Vec<&str>) -> Result<i32> {
-    let first = vec.first().ok_or(EmptyVec)?;
-    let parsed = first.parse::<i32>()?;
-    Ok(2 * parsed)
-}
+    // The same structure as before but rather than chain all `Results`
+    // and `Options` along, we `?` to get the inner value out immediately.
+    fn double_first(vec: Vec<&str>) -> Result<i32> {
+        let first = vec.first().ok_or(EmptyVec)?;
+        let parsed = first.parse::<i32>()?;
+        Ok(2 * parsed)
+    }
 
-fn print(result: Result<i32>) {
-    match result {
-        Ok(n)  => println!("The first doubled is {}", n),
-        Err(e) => println!("Error: {}", e),
+    fn print(result: Result<i32>) {
+        match result {
+            Ok(n)  => println!("The first doubled is {}", n),
+            Err(e) => println!("Error: {}", e),
+        }
     }
-}
 
-fn main() {
-    let numbers = vec!["42", "93", "18"];
-    let empty = vec![];
-    let strings = vec!["tofu", "93", "18"];
+    pub fn main() {
+        let numbers = vec!["42", "93", "18"];
+        let empty = vec![];
+        let strings = vec!["tofu", "93", "18"];
 
-    print(double_first(numbers));
-    print(double_first(empty));
-    print(double_first(strings));
+        print(double_first(numbers));
+        print(double_first(empty));
+        print(double_first(strings));
+    }
 }
 
 
 
+
 /*
     This is actually fairly clean now. Compared with the original panic, it is very similar to replacing the unwrap calls with ? except that the return types are Result. As a result, they must be destructured at the top level.
 */
-
-
-
-
-
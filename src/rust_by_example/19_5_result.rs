@@ -1,6 +1,9 @@
 // Result
 // https://doc.rust-lang.org/rust-by-example/std/result.html
 
+
+
+
 /*
     We've seen that the Option enum can be used as a return value from functions that may fail, where None can be returned to indicate failure. However, sometimes it is important to express why an operation failed. To do this we have the Result enum.
 
@@ -11,142 +14,138 @@
 
 */
 
-mod checked {
-    // Mathematical "errors" we want to catch
-    #[derive(Debug)]
-    pub enum MathError {
-        DivisionByZero,
-        NonPositiveLogarithm,
-        NegativeSquareRoot,
-    }
-
-    pub type MathResult = Result<f64, MathError>;
+pub mod example_01 {
+    mod checked {
+        // Mathematical "errors" we want to catch
+        #[derive(Debug)]
+        pub enum MathError {
+            DivisionByZero,
+            NonPositiveLogarithm,
+            NegativeSquareRoot,
+        }
 
-    pub fn div(x: f64, y: f64) -> MathResult {
-        if y == 0.0 {
-            // This operation would `fail`, instead let's return the reason of
-            // the failure wrapped in `Err`
-            Err(MathError::DivisionByZero)
-        } else {
-            // This operation is valid, return the result wrapped in `Ok`
-            Ok(x / y)
+        pub type MathResult = Result<f64, MathError>;
+
+        pub fn div(x: f64, y: f64) -> MathResult {
+            if y == 0.0 {
+                // This operation would `fail`, instead let's return the reason of
+                // the failure wrapped in `Err`
+                Err(MathError::DivisionByZero)
+            } else {
+                // This operation is valid, return the result wrapped in `Ok`
+                Ok(x / y)
+            }
         }
-    }
 
-    pub fn sqrt(x: f64) -> MathResult {
-        if x < 0.0 {
-            Err(MathError::NegativeSquareRoot)
-        } else {
-            Ok(x.sqrt())
+        pub fn sqrt(x: f64) -> MathResult {
+            if x < 0.0 {
+                Err(MathError::NegativeSquareRoot)
+            } else {
+                Ok(x.sqrt())
+            }
         }
-    }
 
-    pub fn ln(x: f64) -> MathResult {
-        if x <= 0.0 {
-            Err(MathError::NonPositiveLogarithm)
-        } else {
-            Ok(x.ln())
+        pub fn ln(x: f64) -> MathResult {
+            if x <= 0.0 {
+                Err(MathError::NonPositiveLogarithm)
+            } else {
+                Ok(x.ln())
+            }
         }
     }
-}
 
-// `op(x, y)` === `sqrt(ln(x / y))`
-fn op(x: f64, y: f64) -> f64 {
-    // This is a three level match pyramid!
-    match checked::div(x, y) {
-        Err(why) => panic!("{:?}", why),
-        Ok(ratio) => match checked::ln(ratio) {
+    // `op(x, y)` === `sqrt(ln(x / y))`
+    fn op(x: f64, y: f64) -> f64 {
+        // This is a three level match pyramid!
+        match checked::div(x, y) {
             Err(why) => panic!("{:?}", why),
-            Ok(ln) => match checked::sqrt(ln) {
+            Ok(ratio) => match checked::ln(ratio) {
                 Err(why) => panic!("{:?}", why),
-                Ok(sqrt) => sqrt,
+                Ok(ln) => match checked::sqrt(ln) {
+                    Err(why) => panic!("{:?}", why),
+                    Ok(sqrt) => sqrt,
+                },
             },
-        },
+        }
     }
-}
 
-fn main() {
-    // Will this fail?
-    println!("{}", op(1.0, 10.0));
+    pub fn main() {
+        // Will this fail?
+        println!("{}", op(1.0, 10.0));
+    }
 }
 
 
 
 
-
-
-
-
-
-
 /*
     ?
 
     Chaining results using match can get pretty untidy; luckily, the ? operator can be used to make things pretty again. ? is used at the end of an expression returning a Result, and is equivalent to a match expression, where the Err(err) branch expands to an early return Err(From::from(err)), and the Ok(ok) branch expands to an ok expression.
 */
 
-mod checked {
-    #[derive(Debug)]
-    enum MathError {
-        DivisionByZero,
-        NonPositiveLogarithm,
-        NegativeSquareRoot,
-    }
+pub mod example_02 {
+    mod checked {
+        #[derive(Debug)]
+        enum MathError {
+            DivisionByZero,
+            NonPositiveLogarithm,
+            NegativeSquareRoot,
+        }
 
-    type MathResult = Result<f64, MathError>;
+        type MathResult = Result<f64, MathError>;
 
-    fn div(x: f64, y: f64) -> MathResult {
-        if y == 0.0 {
-            Err(MathError::DivisionByZero)
-        } else {
-            Ok(x / y)
+        fn div(x: f64, y: f64) -> MathResult {
+            if y == 0.0 {
+                Err(MathError::DivisionByZero)
+            } else {
+                Ok(x / y)
+            }
         }
-    }
 
-    fn sqrt(x: f64) -> MathResult {
-        if x < 0.0 {
-            Err(MathError::NegativeSquareRoot)
-        } else {
-            Ok(x.sqrt())
+        fn sqrt(x: f64) -> MathResult {
+            if x < 0.0 {
+                Err(MathError::NegativeSquareRoot)
+            } else {
+                Ok(x.sqrt())
+            }
         }
-    }
 
-    fn ln(x: f64) -> MathResult {
-        if x <= 0.0 {
-            Err(MathError::NonPositiveLogarithm)
-        } else {
-            Ok(x.ln())
+        fn ln(x: f64) -> MathResult {
+            if x <= 0.0 {
+                Err(MathError::NonPositiveLogarithm)
+            } else {
+                Ok(x.ln())
+            }
         }
-    }
 
-    // Intermediate function
-    fn op_(x: f64, y: f64) -> MathResult {
-        // if `div` "fails", then `DivisionByZero` will be `return`ed
-        let ratio = div(x, y)?;
+        // Intermediate function
+        fn op_(x: f64, y: f64) -> MathResult {
+            // if `div` "fails", then `DivisionByZero` will be `return`ed
+            let ratio = div(x, y)?;
 
-        // if `ln` "fails", then `NonPositiveLogarithm` will be `return`ed
-        let ln = ln(ratio)?;
+            // if `ln` "fails", then `NonPositiveLogarithm` will be `return`ed
+            let ln = ln(ratio)?;
 
-        sqrt(ln)
-    }
+            sqrt(ln)
+        }
 
-    pub fn op(x: f64, y: f64) {
-        match op_(x, y) {
-            Err(why) => panic!("{}", match why {
-                MathError::NonPositiveLogarithm
-                    => "logarithm of non-positive number",
-                MathError::DivisionByZero
-                    => "division by zero",
-                MathError::NegativeSquareRoot
-                    => "square root of negative number",
-            }),
-            Ok(value) => println!("{}", value),
+        pub fn op(x: f64, y: f64) {
+            match op_(x, y) {
+                Err(why) => panic!("{}", match why {
+                    MathError::NonPositiveLogarithm
+                        => "logarithm of non-positive number",
+                    MathError::DivisionByZero
+                        => "division by zero",
+                    MathError::NegativeSquareRoot
+                        => "square root of negative number",
+                }),
+                Ok(value) => println!("{}", value),
+            }
         }
     }
-}
 
-fn main() {
-    checked::op(1.0, 10.0);
+    pub fn main() {
+        checked::op(1.0, 10.0);
+    }
 }
-
-
@@ -1,6 +1,9 @@
 // panic!
 // https://doc.rust-lang.org/rust-by-example/std/panic.html
 
+
+
+
 /*
     panic!
 
@@ -10,26 +13,29 @@
 */
 
 // Re-implementation of integer division (/)
-fn division(dividend: i32, divisor: i32) -> i32 {
-    if divisor == 0 {
-        // Division by zero triggers a panic
-        panic!("division by zero");
-    } else {
-        dividend / divisor
+
+pub mod example_01 {
+    fn division(dividend: i32, divisor: i32) -> i32 {
+        if divisor == 0 {
+            // Division by zero triggers a panic
+            panic!("division by zero");
+        } else {
+            dividend / divisor
+        }
     }
-}
 
-// The `main` task
-fn main() {
-    // Heap allocated integer
-    let _x = Box::new(0i32);
+    // The `main` task
+    pub fn main() {
+        // Heap allocated integer
+        let _x = Box::new(0i32);
 
-    // This operation will trigger a task failure
-    division(3, 0);
+        // This operation will trigger a task failure
+        division(3, 0);
 
-    println!("This point won't be reached!");
+        println!("This point won't be reached!");
 
-    // `_x` should get destroyed at this point
+        // `_x` should get destroyed at this point
+    }
 }
 
 
@@ -55,6 +61,3 @@ fn main() {
     ==4401== For counts of detected and suppressed errors, rerun with: -v
     ==4401== ERROR SUMMARY: 0 errors from 0 contexts (suppressed: 0 from 0)
 */
-
-
-
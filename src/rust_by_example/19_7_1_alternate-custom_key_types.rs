@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/std/hash/alt_key_types.html
 
 
+
+
 /*
     Any type that implements the Eq and Hash traits can be a key in HashMap. This includes:
 
@@ -20,61 +22,61 @@
     To play around with using a struct in HashMap, let's try making a very simple user logon system:
 */
 
-use std::collections::HashMap;
+pub mod example_01 {
+    use std::collections::HashMap;
 
-// Eq requires that you derive PartialEq on the type.
-#[derive(PartialEq, Eq, Hash)]
-struct Account<'a>{
-    username: &'a str,
-    password: &'a str,
-}
+    // Eq requires that you derive PartialEq on the type.
+    #[derive(PartialEq, Eq, Hash)]
+    struct Account<'a>{
+        username: &'a str,
+        password: &'a str,
+    }
 
-struct AccountInfo<'a>{
-    name: &'a str,
-    email: &'a str,
-}
+    struct AccountInfo<'a>{
+        name: &'a str,
+        email: &'a str,
+    }
 
-type Accounts<'a> = HashMap<Account<'a>, AccountInfo<'a>>;
-
-fn try_logon<'a>(accounts: &Accounts<'a>,
-        username: &'a str, password: &'a str){
-    println!("Username: {}", username);
-    println!("Password: {}", password);
-    println!("Attempting logon...");
-
-    let logon = Account {
-        username,
-        password,
-    };
-
-    match accounts.get(&logon) {
-        Some(account_info) => {
-            println!("Successful logon!");
-            println!("Name: {}", account_info.name);
-            println!("Email: {}", account_info.email);
-        },
-        _ => println!("Login failed!"),
+    type Accounts<'a> = HashMap<Account<'a>, AccountInfo<'a>>;
+
+    fn try_logon<'a>(accounts: &Accounts<'a>,
+            username: &'a str, password: &'a str){
+        println!("Username: {}", username);
+        println!("Password: {}", password);
+        println!("Attempting logon...");
+
+        let logon = Account {
+            username,
+            password,
+        };
+
+        match accounts.get(&logon) {
+            Some(account_info) => {
+                println!("Successful logon!");
+                println!("Name: {}", account_info.name);
+                println!("Email: {}", account_info.email);
+            },
+            _ => println!("Login failed!"),
+        }
     }
-}
 
-fn main(){
-    let mut accounts: Accounts = HashMap::new();
+    pub fn main(){
+        let mut accounts: Accounts = HashMap::new();
 
-    let account = Account {
-        username: "j.everyman",
-        password: "password123",
-    };
+        let account = Account {
+            username: "j.everyman",
+            password: "password123",
+        };
 
-    let account_info = AccountInfo {
-        name: "John Everyman",
-        email: "j.everyman@email.com",
-    };
+        let account_info = AccountInfo {
+            name: "John Everyman",
+            email: "j.everyman@email.com",
+        };
 
-    accounts.insert(account, account_info);
+        accounts.insert(account, account_info);
 
-    try_logon(&accounts, "j.everyman", "psasword123");
+        try_logon(&accounts, "j.everyman", "psasword123");
 
-    try_logon(&accounts, "j.everyman", "password123");
+        try_logon(&accounts, "j.everyman", "password123");
+    }
 }
-
-
@@ -1,6 +1,9 @@
 // HashSet
 // https://doc.rust-lang.org/rust-by-example/std/hash/hashset.html
 
+
+
+
 /*
     Consider a HashSet as a HashMap where we just care about the keys ( HashSet<T> is, in actuality, just a wrapper around HashMap<T, ()>).
 
@@ -27,41 +30,40 @@
     Try all of these in the following example:
 */
 
-use std::collections::HashSet;
+pub mod example_01 {
+    use std::collections::HashSet;
 
-fn main() {
-    let mut a: HashSet<i32> = vec![1i32, 2, 3].into_iter().collect();
-    let mut b: HashSet<i32> = vec![2i32, 3, 4].into_iter().collect();
+    pub fn main() {
+        let mut a: HashSet<i32> = vec![1i32, 2, 3].into_iter().collect();
+        let mut b: HashSet<i32> = vec![2i32, 3, 4].into_iter().collect();
 
-    assert!(a.insert(4));
-    assert!(a.contains(&4));
+        assert!(a.insert(4));
+        assert!(a.contains(&4));
 
-    // `HashSet::insert()` returns false if
-    // there was a value already present.
-    assert!(b.insert(4), "Value 4 is already in set B!");
-    // FIXME ^ Comment out this line
+        // `HashSet::insert()` returns false if
+        // there was a value already present.
+        assert!(b.insert(4), "Value 4 is already in set B!");
+        // FIXME ^ Comment out this line
 
-    b.insert(5);
+        b.insert(5);
 
-    // If a collection's element type implements `Debug`,
-    // then the collection implements `Debug`.
-    // It usually prints its elements in the format `[elem1, elem2, ...]`
-    println!("A: {:?}", a);
-    println!("B: {:?}", b);
+        // If a collection's element type implements `Debug`,
+        // then the collection implements `Debug`.
+        // It usually prints its elements in the format `[elem1, elem2, ...]`
+        println!("A: {:?}", a);
+        println!("B: {:?}", b);
 
-    // Print [1, 2, 3, 4, 5] in arbitrary order
-    println!("Union: {:?}", a.union(&b).collect::<Vec<&i32>>());
+        // Print [1, 2, 3, 4, 5] in arbitrary order
+        println!("Union: {:?}", a.union(&b).collect::<Vec<&i32>>());
 
-    // This should print [1]
-    println!("Difference: {:?}", a.difference(&b).collect::<Vec<&i32>>());
+        // This should print [1]
+        println!("Difference: {:?}", a.difference(&b).collect::<Vec<&i32>>());
 
-    // Print [2, 3, 4] in arbitrary order.
-    println!("Intersection: {:?}", a.intersection(&b).collect::<Vec<&i32>>());
+        // Print [2, 3, 4] in arbitrary order.
+        println!("Intersection: {:?}", a.intersection(&b).collect::<Vec<&i32>>());
 
-    // Print [1, 5]
-    println!("Symmetric Difference: {:?}",
-             a.symmetric_difference(&b).collect::<Vec<&i32>>());
+        // Print [1, 5]
+        println!("Symmetric Difference: {:?}",
+                 a.symmetric_difference(&b).collect::<Vec<&i32>>());
+    }
 }
-
-
-
@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/std_misc.html
 
 
+
+
 /*
     Many other types are provided by the std library to support things such as:
 
@@ -15,51 +17,49 @@
 
 
 
-
-
-
-
 // Threads
 // https://doc.rust-lang.org/rust-by-example/std_misc/threads.html
 
 
+
+
 /*
     Rust provides a mechanism for spawning native OS threads via the spawn function, the argument of this function is a moving closure.
 */
 
-use std::thread;
+pub mod example_01 {
+    use std::thread;
 
-const NTHREADS: u32 = 10;
+    const NTHREADS: u32 = 10;
 
-// This is the `main` thread
-fn main() {
-    // Make a vector to hold the children which are spawned.
-    let mut children = vec![];
+    // This is the `main` thread
+    pub fn main() {
+        // Make a vector to hold the children which are spawned.
+        let mut children = vec![];
 
-    for i in 0..NTHREADS {
-        // Spin up another thread
-        children.push(thread::spawn(move || {
-            println!("this is thread number {}", i);
-        }));
-    }
+        for i in 0..NTHREADS {
+            // Spin up another thread
+            children.push(thread::spawn(move || {
+                println!("this is thread number {}", i);
+            }));
+        }
 
-    for child in children {
-        // Wait for the thread to finish. Returns a result.
-        let _ = child.join();
+        for child in children {
+            // Wait for the thread to finish. Returns a result.
+            let _ = child.join();
+        }
     }
 }
 
 
 
 
+// Testcase: map-reduce
+// https://doc.rust-lang.org/rust-by-example/std_misc/threads/testcase_mapreduce.html
 
 
 
 
-
-// Testcase: map-reduce
-// https://doc.rust-lang.org/rust-by-example/std_misc/threads/testcase_mapreduce.html
-
 /*
     Rust makes it very easy to parallelise data processing, without many of the headaches traditionally associated with such an attempt.
 
@@ -72,17 +72,18 @@ fn main() {
     Note that, although we're passing references across thread boundaries, Rust understands that we're only passing read-only references, and that thus no unsafety or data races can occur. Because we're move-ing the data segments into the thread, Rust will also ensure the data is kept alive until the threads exit, so no dangling pointers occur.
 */
 
-use std::thread;
+pub mod example_02 {
+    use std::thread;
 
-// This is the `main` thread
-fn main() {
+    // This is the `main` thread
+    pub fn main() {
 
-    // This is our data to process.
-    // We will calculate the sum of all digits via a threaded  map-reduce algorithm.
-    // Each whitespace separated chunk will be handled in a different thread.
-    //
-    // TODO: see what happens to the output if you insert spaces!
-    let data = "86967897737416471853297327050364959
+        // This is our data to process.
+        // We will calculate the sum of all digits via a threaded  map-reduce algorithm.
+        // Each whitespace separated chunk will be handled in a different thread.
+        //
+        // TODO: see what happens to the output if you insert spaces!
+        let data = "86967897737416471853297327050364959
 11861322575564723963297542624962850
 70856234701860851907960690014725639
 38397966707106094172783238747669219
@@ -91,87 +92,85 @@ fn main() {
 69920216438980873548808413720956532
 16278424637452589860345374828574668";
 
-    // Make a vector to hold the child-threads which we will spawn.
-    let mut children = vec![];
-
-    /*************************************************************************
-     * "Map" phase
-     *
-     * Divide our data into segments, and apply initial processing
-     ************************************************************************/
-
-    // split our data into segments for individual calculation
-    // each chunk will be a reference (&str) into the actual data
-    let chunked_data = data.split_whitespace();
-
-    // Iterate over the data segments.
-    // .enumerate() adds the current loop index to whatever is iterated
-    // the resulting tuple "(index, element)" is then immediately
-    // "destructured" into two variables, "i" and "data_segment" with a
-    // "destructuring assignment"
-    for (i, data_segment) in chunked_data.enumerate() {
-        println!("data segment {} is \"{}\"", i, data_segment);
-
-        // Process each data segment in a separate thread
-        //
-        // spawn() returns a handle to the new thread,
-        // which we MUST keep to access the returned value
+        // Make a vector to hold the child-threads which we will spawn.
+        let mut children = vec![];
+
+        /*************************************************************************
+         * "Map" phase
+         *
+         * Divide our data into segments, and apply initial processing
+         ************************************************************************/
+
+        // split our data into segments for individual calculation
+        // each chunk will be a reference (&str) into the actual data
+        let chunked_data = data.split_whitespace();
+
+        // Iterate over the data segments.
+        // .enumerate() adds the current loop index to whatever is iterated
+        // the resulting tuple "(index, element)" is then immediately
+        // "destructured" into two variables, "i" and "data_segment" with a
+        // "destructuring assignment"
+        for (i, data_segment) in chunked_data.enumerate() {
+            println!("data segment {} is \"{}\"", i, data_segment);
+
+            // Process each data segment in a separate thread
+            //
+            // spawn() returns a handle to the new thread,
+            // which we MUST keep to access the returned value
+            //
+            // 'move || -> u32' is syntax for a closure that:
+            // * takes no arguments ('||')
+            // * takes ownership of its captured variables ('move') and
+            // * returns an unsigned 32-bit integer ('-> u32')
+            //
+            // Rust is smart enough to infer the '-> u32' from
+            // the closure itself so we could have left that out.
+            //
+            // TODO: try removing the 'move' and see what happens
+            children.push(thread::spawn(move || -> u32 {
+                // Calculate the intermediate sum of this segment:
+                let result = data_segment
+                            // iterate over the characters of our segment..
+                            .chars()
+                            // .. convert text-characters to their number value..
+                            .map(|c| c.to_digit(10).expect("should be a digit"))
+                            // .. and sum the resulting iterator of numbers
+                            .sum();
+
+                // println! locks stdout, so no text-interleaving occurs
+                println!("processed segment {}, result={}", i, result);
+
+                // "return" not needed, because Rust is an "expression language", the
+                // last evaluated expression in each block is automatically its value.
+                result
+
+            }));
+        }
+
+
+        /*************************************************************************
+         * "Reduce" phase
+         *
+         * Collect our intermediate results, and combine them into a final result
+         ************************************************************************/
+
+        // combine each thread's intermediate results into a single final sum.
         //
-        // 'move || -> u32' is syntax for a closure that:
-        // * takes no arguments ('||')
-        // * takes ownership of its captured variables ('move') and
-        // * returns an unsigned 32-bit integer ('-> u32')
+        // we use the "turbofish" ::<> to provide sum() with a type hint.
         //
-        // Rust is smart enough to infer the '-> u32' from
-        // the closure itself so we could have left that out.
-        //
-        // TODO: try removing the 'move' and see what happens
-        children.push(thread::spawn(move || -> u32 {
-            // Calculate the intermediate sum of this segment:
-            let result = data_segment
-                        // iterate over the characters of our segment..
-                        .chars()
-                        // .. convert text-characters to their number value..
-                        .map(|c| c.to_digit(10).expect("should be a digit"))
-                        // .. and sum the resulting iterator of numbers
-                        .sum();
-
-            // println! locks stdout, so no text-interleaving occurs
-            println!("processed segment {}, result={}", i, result);
-
-            // "return" not needed, because Rust is an "expression language", the
-            // last evaluated expression in each block is automatically its value.
-            result
-
-        }));
-    }
+        // TODO: try without the turbofish, by instead explicitly
+        // specifying the type of final_result
+        let final_result = children.into_iter().map(|c| c.join().unwrap()).sum::<u32>();
 
-
-    /*************************************************************************
-     * "Reduce" phase
-     *
-     * Collect our intermediate results, and combine them into a final result
-     ************************************************************************/
-
-    // combine each thread's intermediate results into a single final sum.
-    //
-    // we use the "turbofish" ::<> to provide sum() with a type hint.
-    //
-    // TODO: try without the turbofish, by instead explicitly
-    // specifying the type of final_result
-    let final_result = children.into_iter().map(|c| c.join().unwrap()).sum::<u32>();
-
-    println!("Final sum result: {}", final_result);
+        println!("Final sum result: {}", final_result);
+    }
 }
 
 
 
 
-
 /*
     Assignments
 
     It is not wise to let our number of threads depend on user inputted data. What if the user decides to insert a lot of spaces? Do we really want to spawn 2,000 threads? Modify the program so that the data is always chunked into a limited number of chunks, defined by a static constant at the beginning of the program.
 */
-
-
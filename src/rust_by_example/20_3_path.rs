@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/std_misc/path.html
 
 
+
+
 /*
     The Path struct represents file paths in the underlying filesystem. There are two flavors of Path: posix::Path, for UNIX-like systems, and windows::Path, for Windows. The prelude exports the appropriate platform-specific Path variant.
 
@@ -12,37 +14,38 @@
     Note that a Path is not internally represented as an UTF-8 string, but instead is stored as a vector of bytes (Vec<u8>). Therefore, converting a Path to a &str is not free and may fail (an Option is returned).
 */
 
-use std::path::Path;
+pub mod example_01 {
+    use std::path::Path;
 
-fn main() {
-    // Create a `Path` from an `&'static str`
-    let path = Path::new(".");
+    pub fn main() {
+        // Create a `Path` from an `&'static str`
+        let path = Path::new(".");
 
-    // The `display` method returns a `Display`able structure
-    let _display = path.display();
+        // The `display` method returns a `Display`able structure
+        let _display = path.display();
 
-    // `join` merges a path with a byte container using the OS specific
-    // separator, and returns a `PathBuf`
-    let mut new_path = path.join("a").join("b");
+        // `join` merges a path with a byte container using the OS specific
+        // separator, and returns a `PathBuf`
+        let mut new_path = path.join("a").join("b");
 
-    // `push` extends the `PathBuf` with a `&Path`
-    new_path.push("c");
-    new_path.push("myfile.tar.gz");
+        // `push` extends the `PathBuf` with a `&Path`
+        new_path.push("c");
+        new_path.push("myfile.tar.gz");
 
-    // `set_file_name` updates the file name of the `PathBuf`
-    new_path.set_file_name("package.tgz");
+        // `set_file_name` updates the file name of the `PathBuf`
+        new_path.set_file_name("package.tgz");
 
-    // Convert the `PathBuf` into a string slice
-    match new_path.to_str() {
-        None => panic!("new path is not a valid UTF-8 sequence"),
-        Some(s) => println!("new path is {}", s),
+        // Convert the `PathBuf` into a string slice
+        match new_path.to_str() {
+            None => panic!("new path is not a valid UTF-8 sequence"),
+            Some(s) => println!("new path is {}", s),
+        }
     }
 }
 
 
+
+
 /*
     Be sure to check at other Path methods (posix::Path or windows::Path) and the Metadata struct.
 */
-
-
-
@@ -1,13 +1,16 @@
 // create
 // https://doc.rust-lang.org/rust-by-example/std_misc/file/create.html
 
+
+
+
 /*
     The create function opens a file in write-only mode. If the file already existed, the old content is destroyed. Otherwise, a new file is created.
 */
 
-
-static LOREM_IPSUM: &str =
-    "Lorem ipsum dolor sit amet, consectetur adipisicing elit, sed do eiusmod
+pub mod example_01 {
+    static LOREM_IPSUM: &str =
+        "Lorem ipsum dolor sit amet, consectetur adipisicing elit, sed do eiusmod
 tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam,
 quis nostrud exercitation ullamco laboris nisi ut aliquip ex ea commodo
 consequat. Duis aute irure dolor in reprehenderit in voluptate velit esse
@@ -15,24 +18,25 @@ cillum dolore eu fugiat nulla pariatur. Excepteur sint occaecat cupidatat non
 proident, sunt in culpa qui officia deserunt mollit anim id est laborum.
 ";
 
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
-
-fn main() {
-    let path = Path::new("lorem_ipsum.txt");
-    let display = path.display();
-
-    // Open a file in write-only mode, returns `io::Result<File>`
-    let mut file = match File::create(&path) {
-        Err(why) => panic!("couldn't create {}: {}", display, why),
-        Ok(file) => file,
-    };
-
-    // Write the `LOREM_IPSUM` string to `file`, returns `io::Result<()>`
-    match file.write_all(LOREM_IPSUM.as_bytes()) {
-        Err(why) => panic!("couldn't write to {}: {}", display, why),
-        Ok(_) => println!("successfully wrote to {}", display),
+    use std::fs::File;
+    use std::io::prelude::*;
+    use std::path::Path;
+
+    pub fn main() {
+        let path = Path::new("lorem_ipsum.txt");
+        let display = path.display();
+
+        // Open a file in write-only mode, returns `io::Result<File>`
+        let mut file = match File::create(&path) {
+            Err(why) => panic!("couldn't create {}: {}", display, why),
+            Ok(file) => file,
+        };
+
+        // Write the `LOREM_IPSUM` string to `file`, returns `io::Result<()>`
+        match file.write_all(LOREM_IPSUM.as_bytes()) {
+            Err(why) => panic!("couldn't write to {}: {}", display, why),
+            Ok(_) => println!("successfully wrote to {}", display),
+        }
     }
 }
 
@@ -56,4 +60,3 @@ fn main() {
 
     There is OpenOptions struct that can be used to configure how a file is opened.
 */
-
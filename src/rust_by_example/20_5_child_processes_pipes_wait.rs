@@ -1,31 +1,38 @@
 // Child processes
 // https://doc.rust-lang.org/rust-by-example/std_misc/process.html
 
+
+
+
 /*
     The process::Output struct represents the output of a finished child process, and the process::Command struct is a process builder.
 */
 
-use std::process::Command;
+pub mod example_01 {
+    use std::process::Command;
 
-fn main() {
-    let output = Command::new("rustc")
-        .arg("--version")
-        .output().unwrap_or_else(|e| {
-            panic!("failed to execute process: {}", e)
-    });
+    pub fn main() {
+        let output = Command::new("rustc")
+            .arg("--version")
+            .output().unwrap_or_else(|e| {
+                panic!("failed to execute process: {}", e)
+        });
 
-    if output.status.success() {
-        let s = String::from_utf8_lossy(&output.stdout);
+        if output.status.success() {
+            let s = String::from_utf8_lossy(&output.stdout);
 
-        print!("rustc succeeded and stdout was:\n{}", s);
-    } else {
-        let s = String::from_utf8_lossy(&output.stderr);
+            print!("rustc succeeded and stdout was:\n{}", s);
+        } else {
+            let s = String::from_utf8_lossy(&output.stderr);
 
-        print!("rustc failed and stderr was:\n{}", s);
+            print!("rustc failed and stderr was:\n{}", s);
+        }
     }
 }
 
 
+
+
 /*
     (You are encouraged to try the previous example with an incorrect flag passed to rustc)
 */
@@ -33,86 +40,83 @@ fn main() {
 
 
 
+// Pipes
+// https://doc.rust-lang.org/rust-by-example/std_misc/process/pipe.html
 
 
 
 
-
-
-
-// Pipes
-// https://doc.rust-lang.org/rust-by-example/std_misc/process/pipe.html
-
 /*  
     The std::Child struct represents a running child process, and exposes the stdin, stdout and stderr handles for interaction with the underlying process via pipes.
 */
 
-use std::io::prelude::*;
-use std::process::{Command, Stdio};
-
-static PANGRAM: &'static str =
-"the quick brown fox jumped over the lazy dog\n";
-
-fn main() {
-    // Spawn the `wc` command
-    let process = match Command::new("wc")
-                                .stdin(Stdio::piped())
-                                .stdout(Stdio::piped())
-                                .spawn() {
-        Err(why) => panic!("couldn't spawn wc: {}", why),
-        Ok(process) => process,
-    };
-
-    // Write a string to the `stdin` of `wc`.
-    //
-    // `stdin` has type `Option<ChildStdin>`, but since we know this instance
-    // must have one, we can directly `unwrap` it.
-    match process.stdin.unwrap().write_all(PANGRAM.as_bytes()) {
-        Err(why) => panic!("couldn't write to wc stdin: {}", why),
-        Ok(_) => println!("sent pangram to wc"),
-    }
-
-    // Because `stdin` does not live after the above calls, it is `drop`ed,
-    // and the pipe is closed.
-    //
-    // This is very important, otherwise `wc` wouldn't start processing the
-    // input we just sent.
-
-    // The `stdout` field also has type `Option<ChildStdout>` so must be unwrapped.
-    let mut s = String::new();
-    match process.stdout.unwrap().read_to_string(&mut s) {
-        Err(why) => panic!("couldn't read wc stdout: {}", why),
-        Ok(_) => print!("wc responded with:\n{}", s),
+pub mod example_02 {
+    use std::io::prelude::*;
+    use std::process::{Command, Stdio};
+
+    static PANGRAM: &'static str =
+    "the quick brown fox jumped over the lazy dog\n";
+
+    pub fn main() {
+        // Spawn the `wc` command
+        let process = match Command::new("wc")
+                                    .stdin(Stdio::piped())
+                                    .stdout(Stdio::piped())
+                                    .spawn() {
+            Err(why) => panic!("couldn't spawn wc: {}", why),
+            Ok(process) => process,
+        };
+
+        // Write a string to the `stdin` of `wc`.
+        //
+        // `stdin` has type `Option<ChildStdin>`, but since we know this instance
+        // must have one, we can directly `unwrap` it.
+        match process.stdin.unwrap().write_all(PANGRAM.as_bytes()) {
+            Err(why) => panic!("couldn't write to wc stdin: {}", why),
+            Ok(_) => println!("sent pangram to wc"),
+        }
+
+        // Because `stdin` does not live after the above calls, it is `drop`ed,
+        // and the pipe is closed.
+        //
+        // This is very important, otherwise `wc` wouldn't start processing the
+        // input we just sent.
+
+        // The `stdout` field also has type `Option<ChildStdout>` so must be unwrapped.
+        let mut s = String::new();
+        match process.stdout.unwrap().read_to_string(&mut s) {
+            Err(why) => panic!("couldn't read wc stdout: {}", why),
+            Ok(_) => print!("wc responded with:\n{}", s),
+        }
     }
 }
 
 
 
 
+// Wait
+// https://doc.rust-lang.org/rust-by-example/std_misc/process/wait.html
 
 
 
-// Wait
-// https://doc.rust-lang.org/rust-by-example/std_misc/process/wait.html
 
 /*
     If you'd like to wait for a process::Child to finish, you must call Child::wait, which will return a process::ExitStatus.
 */
 
-use std::process::Command;
-
-fn main() {
-    let mut child = Command::new("sleep").arg("5").spawn().unwrap();
-    let _result = child.wait().unwrap();
+pub mod example_03 {
+    use std::process::Command;
 
-    println!("reached end of main");
-}
-
-
-/*
-    $ rustc wait.rs && ./wait
-    # `wait` keeps running for 5 seconds until the `sleep 5` command finishes
-    reached end of main
-*/
+    pub fn main() {
+        let mut child = Command::new("sleep").arg("5").spawn().unwrap();
+        let _result = child.wait().unwrap();
 
+        println!("reached end of main");
+    }
 
+    /*
+        $ rustc wait.rs && ./wait
+        # `wait` keeps running for 5 seconds until the `sleep 5` command finishes
+        reached end of main
+    */
+}
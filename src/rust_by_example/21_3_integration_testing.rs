@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/testing/integration_testing.html
 
 
+
+
 /*
     Unit tests are testing one module in isolation at a time: they're small and can test private code. Integration tests are external to your crate and use only its public interface in the same way any other code would. Their purpose is to test that many parts of your library work correctly together.
 
@@ -11,19 +13,25 @@
 */
 
 // Define this in a crate called `adder`.
-pub fn add(a: i32, b: i32) -> i32 {
-    a + b
+
+pub mod example_01 {
+    pub fn add(a: i32, b: i32) -> i32 {
+        a + b
+    }
 }
 
 
-// File with test: tests/integration_test.rs:
 
-#[test]
-fn test_add() {
-    assert_eq!(adder::add(3, 2), 5);
-}
 
+// File with test: tests/integration_test.rs:
 
+/// ```
+/// #[test]
+/// fn test_add() {
+///     assert_eq!(adder::add(3, 2), 5);
+/// }
+/// ```
+pub mod example_02 {}
 
 /*
     Running tests with cargo test command:
@@ -54,9 +62,11 @@ fn test_add() {
 
 // File tests/common.rs:
 
-pub fn setup() {
-    // some setup code, like creating required files/directories, starting
-    // servers, etc.
+pub mod example_03 {
+    pub fn setup() {
+        // some setup code, like creating required files/directories, starting
+        // servers, etc.
+    }
 }
 
 
@@ -65,15 +75,18 @@ pub fn setup() {
 // File with test: tests/integration_test.rs
 
 // importing common module.
-mod common;
-
-#[test]
-fn test_add() {
-    // using common code.
-    common::setup();
-    assert_eq!(adder::add(3, 2), 5);
-}
 
+/// ```ignore
+/// mod common;
+///
+/// #[test]
+/// fn test_add() {
+///     // using common code.
+///     common::setup();
+///     assert_eq!(adder::add(3, 2), 5);
+/// }
+/// ```
+pub mod example_04 {}
 
 
 
@@ -81,5 +94,3 @@ fn test_add() {
 /*
     Modules with common code follow the ordinary modules rules, so it's ok to create common module as tests/common/mod.rs.
 */
-
-
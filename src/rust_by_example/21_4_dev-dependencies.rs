@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/testing/dev_dependencies.html
 
 
+
+
 /*
     Sometimes there is a need to have dependencies for tests (or examples, or benchmarks) only. Such dependencies are added to Cargo.toml in the [dev-dependencies] section. These dependencies are not propagated to other packages which depend on this package.
 
@@ -9,27 +11,32 @@
     File Cargo.toml:
 */
 
-# standard crate data is left out
-[dev-dependencies]
-pretty_assertions = "1"
+/// ```toml
+/// # standard crate data is left out
+/// [dev-dependencies]
+/// pretty_assertions = "1"
+/// ```
+pub mod example_01 {}
 
 
 
-// File src/lib.rs:
-
-pub fn add(a: i32, b: i32) -> i32 {
-    a + b
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use pretty_assertions::assert_eq; // crate for test-only use. Cannot be used in non-test code.
-
-    #[test]
-    fn test_add() {
-        assert_eq!(add(2, 3), 5);
-    }
-}
 
+// File src/lib.rs:
 
+/// ```
+/// pub fn add(a: i32, b: i32) -> i32 {
+///     a + b
+/// }
+///
+/// #[cfg(test)]
+/// mod tests {
+///     use super::*;
+///     use pretty_assertions::assert_eq; // crate for test-only use. Cannot be used in non-test code.
+///
+///     #[test]
+///     fn test_add() {
+///         assert_eq!(add(2, 3), 5);
+///     }
+/// }
+/// ```
+pub mod example_02 {}
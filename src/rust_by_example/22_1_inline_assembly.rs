@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/unsafe/asm.html
 
 
+
+
 /*
     Rust provides support for inline assembly via the asm! macro. It can be used to embed handwritten assembly in the assembly output generated by the compiler. Generally this should not be necessary, but might be where the required performance or timing cannot be otherwise achieved. Accessing low level hardware primitives, e.g. in kernel code, may also demand this functionality.
 
@@ -18,21 +20,24 @@
 
 
 
-
 /*
     Basic usage
 
     Let us start with the simplest possible example:
 */
 
+/// ```
+/// # #![allow(unused)]
+/// # fn main() {
+/// use std::arch::asm;
+///
+/// unsafe {
+///     asm!("nop");
+/// }
+/// # }
+/// ```
+pub mod example_01 {}
 
-#![allow(unused)]
-fn main() {
-use std::arch::asm;
-
-unsafe {
-    asm!("nop");
-}
 
 
 
@@ -43,23 +48,23 @@ unsafe {
 
 
 
-
 /*
     Inputs and outputs
 
     Now inserting an instruction that does nothing is rather boring. Let us do something that actually acts on data:
 */
 
+pub mod example_02 {
+    pub fn main() {
+        use std::arch::asm;
 
-use std::arch::asm;
-
-let x: u64;
-unsafe {
-    asm!("mov {}, 5", out(reg) x);
+        let x: u64;
+        unsafe {
+            asm!("mov {}, 5", out(reg) x);
+        }
+        assert_eq!(x, 5);
+    }
 }
-assert_eq!(x, 5);
-
-
 
 
 
@@ -70,20 +75,23 @@ assert_eq!(x, 5);
     Let us see another example that also uses an input:
 */
 
-use std::arch::asm;
-
-let i: u64 = 3;
-let o: u64;
-unsafe {
-    asm!(
-        "mov {0}, {1}",
-        "add {0}, 5",
-        out(reg) o,
-        in(reg) i,
-    );
+pub mod example_03 {
+    pub fn main() {
+        use std::arch::asm;
+
+        let i: u64 = 3;
+        let o: u64;
+        unsafe {
+            asm!(
+                "mov {0}, {1}",
+                "add {0}, 5",
+                out(reg) o,
+                in(reg) i,
+            );
+        }
+        assert_eq!(o, 8);
+    }
 }
-assert_eq!(o, 8);
-
 
 
 
@@ -102,15 +110,17 @@ assert_eq!(o, 8);
     We can further refine the above example to avoid the mov instruction:
 */
 
+pub mod example_04 {
+    pub fn main() {
+        use std::arch::asm;
 
-use std::arch::asm;
-
-let mut x: u64 = 3;
-unsafe {
-    asm!("add {0}, 5", inout(reg) x);
+        let mut x: u64 = 3;
+        unsafe {
+            asm!("add {0}, 5", inout(reg) x);
+        }
+        assert_eq!(x, 8);
+    }
 }
-assert_eq!(x, 8);
-
 
 
 
@@ -121,19 +131,18 @@ assert_eq!(x, 8);
     It is also possible to specify different variables for the input and output parts of an inout operand:
 */
 
+pub mod example_05 {
+    pub fn main() {
+        use std::arch::asm;
 
-use std::arch::asm;
-
-let x: u64 = 3;
-let y: u64;
-unsafe {
-    asm!("add {0}, 5", inout(reg) x => y);
+        let x: u64 = 3;
+        let y: u64;
+        unsafe {
+            asm!("add {0}, 5", inout(reg) x => y);
+        }
+        assert_eq!(y, 8);
+    }
 }
-assert_eq!(y, 8);
-
-
-
-
 
 
 
@@ -146,21 +155,25 @@ assert_eq!(y, 8);
     Here is an example where inlateout cannot be used in release mode or other optimized cases:
 */
 
-use std::arch::asm;
-
-let mut a: u64 = 4;
-let b: u64 = 4;
-let c: u64 = 4;
-unsafe {
-    asm!(
-        "add {0}, {1}",
-        "add {0}, {2}",
-        inout(reg) a,
-        in(reg) b,
-        in(reg) c,
-    );
+pub mod example_06 {
+    pub fn main() {
+        use std::arch::asm;
+
+        let mut a: u64 = 4;
+        let b: u64 = 4;
+        let c: u64 = 4;
+        unsafe {
+            asm!(
+                "add {0}, {1}",
+                "add {0}, {2}",
+                inout(reg) a,
+                in(reg) b,
+                in(reg) c,
+            );
+        }
+        assert_eq!(a, 12);
+    }
 }
-assert_eq!(a, 12);
 
 
 
@@ -173,14 +186,19 @@ assert_eq!(a, 12);
     However the following example can use inlateout since the output is only modified after all input registers have been read:
 */
 
-use std::arch::asm;
+pub mod example_07 {
+    pub fn main() {
+        use std::arch::asm;
 
-let mut a: u64 = 4;
-let b: u64 = 4;
-unsafe {
-    asm!("add {0}, {1}", inlateout(reg) a, in(reg) b);
+        let mut a: u64 = 4;
+        let b: u64 = 4;
+        unsafe {
+            asm!("add {0}, {1}", inlateout(reg) a, in(reg) b);
+        }
+        assert_eq!(a, 8);
+    }
 }
-assert_eq!(a, 8);
+
 
 
 
@@ -191,25 +209,26 @@ assert_eq!(a, 8);
 
 
 
-
-
 /*
     Explicit register operands
 
     Some instructions require that the operands be in a specific register. Therefore, Rust inline assembly provides some more specific constraint specifiers. While reg is generally available on any architecture, explicit registers are highly architecture specific. E.g. for x86 the general purpose registers eax, ebx, ecx, edx, ebp, esi, and edi among others can be addressed by their name.
 */
 
-use std::arch::asm;
+pub mod example_08 {
+    pub fn main() {
+        use std::arch::asm;
 
-let cmd = 0xd1;
-unsafe {
-    asm!("out 0x64, eax", in("eax") cmd);
+        let cmd = 0xd1;
+        unsafe {
+            asm!("out 0x64, eax", in("eax") cmd);
+        }
+    }
 }
 
 
 
 
-
 /*
     In this example we call the out instruction to output the content of the cmd variable to port 0x64. Since the out instruction only accepts eax (and its sub registers) as operand we had to use the eax constraint specifier.
 
@@ -218,30 +237,31 @@ unsafe {
     Consider this example which uses the x86 mul instruction:
 */
 
-use std::arch::asm;
-
-fn mul(a: u64, b: u64) -> u128 {
-    let lo: u64;
-    let hi: u64;
-
-    unsafe {
-        asm!(
-            // The x86 mul instruction takes rax as an implicit input and writes
-            // the 128-bit result of the multiplication to rax:rdx.
-            "mul {}",
-            in(reg) a,
-            inlateout("rax") b => lo,
-            lateout("rdx") hi
-        );
+pub mod example_09 {
+    use std::arch::asm;
+
+    fn mul(a: u64, b: u64) -> u128 {
+        let lo: u64;
+        let hi: u64;
+
+        unsafe {
+            asm!(
+                // The x86 mul instruction takes rax as an implicit input and writes
+                // the 128-bit result of the multiplication to rax:rdx.
+                "mul {}",
+                in(reg) a,
+                inlateout("rax") b => lo,
+                lateout("rdx") hi
+            );
+        }
+
+        ((hi as u128) << 64) + lo as u128
     }
-
-    ((hi as u128) << 64) + lo as u128
 }
 
 
 
 
-
 /*
     This uses the mul instruction to multiply two 64-bit inputs with a 128-bit result. The only explicit operand is a register, that we fill from the variable a. The second operand is implicit, and must be the rax register, which we fill from the variable b. The lower 64 bits of the result are stored in rax from which we fill the variable lo. The higher 64 bits are stored in rdx from which we fill the variable hi.
 */
@@ -249,48 +269,48 @@ fn mul(a: u64, b: u64) -> u128 {
 
 
 
-
-
 /*
     Clobbered registers
 
     In many cases inline assembly will modify state that is not needed as an output. Usually this is either because we have to use a scratch register in the assembly or because instructions modify state that we don't need to further examine. This state is generally referred to as being "clobbered". We need to tell the compiler about this since it may need to save and restore this state around the inline assembly block.
 */
 
-use core::arch::asm;
-
-fn main() {
-    // three entries of four bytes each
-    let mut name_buf = [0_u8; 12];
-    // String is stored as ascii in ebx, edx, ecx in order
-    // Because ebx is reserved, the asm needs to preserve the value of it.
-    // So we push and pop it around the main asm.
-    // (in 64 bit mode for 64 bit processors, 32 bit processors would use ebx)
-
-    unsafe {
-        asm!(
-            "push rbx",
-            "cpuid",
-            "mov [rdi], ebx",
-            "mov [rdi + 4], edx",
-            "mov [rdi + 8], ecx",
-            "pop rbx",
-            // We use a pointer to an array for storing the values to simplify
-            // the Rust code at the cost of a couple more asm instructions
-            // This is more explicit with how the asm works however, as opposed
-            // to explicit register outputs such as `out("ecx") val`
-            // The *pointer itself* is only an input even though it's written behind
-            in("rdi") name_buf.as_mut_ptr(),
-            // select cpuid 0, also specify eax as clobbered
-            inout("eax") 0 => _,
-            // cpuid clobbers these registers too
-            out("ecx") _,
-            out("edx") _,
-        );
+pub mod example_10 {
+    use core::arch::asm;
+
+    pub fn main() {
+        // three entries of four bytes each
+        let mut name_buf = [0_u8; 12];
+        // String is stored as ascii in ebx, edx, ecx in order
+        // Because ebx is reserved, the asm needs to preserve the value of it.
+        // So we push and pop it around the main asm.
+        // (in 64 bit mode for 64 bit processors, 32 bit processors would use ebx)
+
+        unsafe {
+            asm!(
+                "push rbx",
+                "cpuid",
+                "mov [rdi], ebx",
+                "mov [rdi + 4], edx",
+                "mov [rdi + 8], ecx",
+                "pop rbx",
+                // We use a pointer to an array for storing the values to simplify
+                // the Rust code at the cost of a couple more asm instructions
+                // This is more explicit with how the asm works however, as opposed
+                // to explicit register outputs such as `out("ecx") val`
+                // The *pointer itself* is only an input even though it's written behind
+                in("rdi") name_buf.as_mut_ptr(),
+                // select cpuid 0, also specify eax as clobbered
+                inout("eax") 0 => _,
+                // cpuid clobbers these registers too
+                out("ecx") _,
+                out("edx") _,
+            );
+        }
+
+        let name = core::str::from_utf8(&name_buf).unwrap();
+        println!("CPU Manufacturer ID: {}", name);
     }
-
-    let name = core::str::from_utf8(&name_buf).unwrap();
-    println!("CPU Manufacturer ID: {}", name);
 }
 
 
@@ -308,23 +328,25 @@ fn main() {
     This can also be used with a general register class to obtain a scratch register for use inside the asm code:
 */
 
-
-use std::arch::asm;
-
-// Multiply x by 6 using shifts and adds
-let mut x: u64 = 4;
-unsafe {
-    asm!(
-        "mov {tmp}, {x}",
-        "shl {tmp}, 1",
-        "shl {x}, 2",
-        "add {x}, {tmp}",
-        x = inout(reg) x,
-        tmp = out(reg) _,
-    );
+pub mod example_11 {
+    pub fn main() {
+        use std::arch::asm;
+
+        // Multiply x by 6 using shifts and adds
+        let mut x: u64 = 4;
+        unsafe {
+            asm!(
+                "mov {tmp}, {x}",
+                "shl {tmp}, 1",
+                "shl {x}, 2",
+                "add {x}, {tmp}",
+                x = inout(reg) x,
+                tmp = out(reg) _,
+            );
+        }
+        assert_eq!(x, 4 * 6);
+    }
 }
-assert_eq!(x, 4 * 6);
-
 
 
 
@@ -335,37 +357,37 @@ assert_eq!(x, 4 * 6);
     By default, asm! assumes that any register not specified as an output will have its contents preserved by the assembly code. The clobber_abi argument to asm! tells the compiler to automatically insert the necessary clobber operands according to the given calling convention ABI: any register which is not fully preserved in that ABI will be treated as clobbered. Multiple clobber_abi arguments may be provided and all clobbers from all specified ABIs will be inserted.
 */
 
-use std::arch::asm;
+pub mod example_12 {
+    use std::arch::asm;
 
-extern "C" fn foo(arg: i32) -> i32 {
-    println!("arg = {}", arg);
-    arg * 2
-}
+    extern "C" fn foo(arg: i32) -> i32 {
+        println!("arg = {}", arg);
+        arg * 2
+    }
 
-fn call_foo(arg: i32) -> i32 {
-    unsafe {
-        let result;
-        asm!(
-            "call *{}",
-            // Function pointer to call
-            in(reg) foo,
-            // 1st argument in rdi
-            in("rdi") arg,
-            // Return value in rax
-            out("rax") result,
-            // Mark all registers which are not preserved by the "C" calling
-            // convention as clobbered.
-            clobber_abi("C"),
-        );
-        result
+    fn call_foo(arg: i32) -> i32 {
+        unsafe {
+            let result;
+            asm!(
+                "call *{}",
+                // Function pointer to call
+                in(reg) foo,
+                // 1st argument in rdi
+                in("rdi") arg,
+                // Return value in rax
+                out("rax") result,
+                // Mark all registers which are not preserved by the "C" calling
+                // convention as clobbered.
+                clobber_abi("C"),
+            );
+            result
+        }
     }
 }
 
 
 
 
-
-
 /*
     Register template modifiers
 
@@ -376,15 +398,22 @@ fn call_foo(arg: i32) -> i32 {
     This default can be overriden by using modifiers on the template string operands, just like you would with format strings:
 */
 
-use std::arch::asm;
+pub mod example_13 {
+    pub fn main() {
+        use std::arch::asm;
 
-let mut x: u16 = 0xab;
+        let mut x: u16 = 0xab;
 
-unsafe {
-    asm!("mov {0:h}, {0:l}", inout(reg_abcd) x);
+        unsafe {
+            asm!("mov {0:h}, {0:l}", inout(reg_abcd) x);
+        }
+
+        assert_eq!(x, 0xabab);
+    }
 }
 
-assert_eq!(x, 0xabab);
+
+
 
 /*
     In this example, we use the reg_abcd register class to restrict the register allocator to the 4 legacy x86 registers (ax, bx, cx, dx) of which the first two bytes can be addressed independently.
@@ -397,28 +426,25 @@ assert_eq!(x, 0xabab);
 
 
 
-
-
 /*
     Memory address operands
 
     Sometimes assembly instructions require operands passed via memory addresses/memory locations. You have to manually use the memory address syntax specified by the target architecture. For example, on x86/x86_64 using Intel assembly syntax, you should wrap inputs/outputs in [] to indicate they are memory operands:
 */
 
-use std::arch::asm;
+pub mod example_14 {
+    use std::arch::asm;
 
-fn load_fpu_control_word(control: u16) {
-    unsafe {
-        asm!("fldcw [{}]", in(reg) &control, options(nostack));
+    fn load_fpu_control_word(control: u16) {
+        unsafe {
+            asm!("fldcw [{}]", in(reg) &control, options(nostack));
+        }
     }
 }
 
 
 
 
-
-
-
 /*
     Labels
 
@@ -433,23 +459,30 @@ fn load_fpu_control_word(control: u16) {
     Moreover, on x86 when using the default Intel syntax, due to an LLVM bug, you shouldn't use labels exclusively made of 0 and 1 digits, e.g. 0, 11 or 101010, as they may end up being interpreted as binary values. Using options(att_syntax) will avoid any ambiguity, but that affects the syntax of the entire asm! block. (See Options, below, for more on options.)
 */
 
-use std::arch::asm;
-
-let mut a = 0;
-unsafe {
-    asm!(
-        "mov {0}, 10",
-        "2:",
-        "sub {0}, 1",
-        "cmp {0}, 3",
-        "jle 2f",
-        "jmp 2b",
-        "2:",
-        "add {0}, 2",
-        out(reg) a
-    );
+pub mod example_15 {
+    pub fn main() {
+        use std::arch::asm;
+
+        let mut a = 0;
+        unsafe {
+            asm!(
+                "mov {0}, 10",
+                "2:",
+                "sub {0}, 1",
+                "cmp {0}, 3",
+                "jle 2f",
+                "jmp 2b",
+                "2:",
+                "add {0}, 2",
+                out(reg) a
+            );
+        }
+        assert_eq!(a, 5);
+    }
 }
-assert_eq!(a, 5);
+
+
+
 
 /*
     This will decrement the {0} register value from 10 to 3, then add 2 and store it in a.
@@ -463,8 +496,6 @@ assert_eq!(a, 5);
 
 
 
-
-
 /*
     Options
 
@@ -473,18 +504,24 @@ assert_eq!(a, 5);
     Let's take our previous example of an add instruction:
 */
 
-use std::arch::asm;
-
-let mut a: u64 = 4;
-let b: u64 = 4;
-unsafe {
-    asm!(
-        "add {0}, {1}",
-        inlateout(reg) a, in(reg) b,
-        options(pure, nomem, nostack),
-    );
+pub mod example_16 {
+    pub fn main() {
+        use std::arch::asm;
+
+        let mut a: u64 = 4;
+        let b: u64 = 4;
+        unsafe {
+            asm!(
+                "add {0}, {1}",
+                inlateout(reg) a, in(reg) b,
+                options(pure, nomem, nostack),
+            );
+        }
+        assert_eq!(a, 8);
+    }
 }
-assert_eq!(a, 8);
+
+
 
 
 /*
@@ -498,4 +535,3 @@ assert_eq!(a, 8);
 
     See the reference for the full list of available options and their effects.
 */
-
@@ -2,6 +2,8 @@
 // https://doc.rust-lang.org/rust-by-example/compatibility/raw_identifiers.html
 
 
+
+
 /*
     The Rust language is fastly evolving, and because of this certain compatibility issues can arise, despite efforts to ensure forwards-compatibility wherever possible.
 
@@ -10,6 +12,7 @@
 
 
 
+
 /*
     Raw identifiers
 
@@ -18,12 +21,16 @@
     For example, consider a crate foo compiled with the 2015 edition of Rust that exports a function named try. This keyword is reserved for a new feature in the 2018 edition, so without raw identifiers, we would have no way to name the function.
 */
 
+/// ```ignore
+/// extern crate foo;
+///
+/// fn main() {
+///     foo::try();
+/// }
+/// ```
+pub mod example_01 {}
 
-extern crate foo;
 
-fn main() {
-    foo::try();
-}
 
 
 /*
@@ -38,12 +45,14 @@ fn main() {
 
 
 
-// You can write this with a raw identifier:
-
-extern crate foo;
-
-fn main() {
-    foo::r#try();
-}
 
+// You can write this with a raw identifier:
 
+/// ```ignore
+/// extern crate foo;
+///
+/// fn main() {
+///     foo::r#try();
+/// }
+/// ```
+pub mod example_02 {}
@@ -1,6 +1,9 @@
 // Meta
 // https://doc.rust-lang.org/rust-by-example/meta/doc.html
 
+
+
+
 /*
     Some topics aren't exactly relevant to how you program but provide you tooling or infrastructure support which just makes things better for everyone. These topics include:
 
@@ -9,6 +12,8 @@
 */
 
 
+
+
 /*
     Documentation
 
@@ -20,57 +25,63 @@
 */
 
 
+
+
 /*
     Doc comments
 
     Doc comments are very useful for big projects that require documentation. When running rustdoc, these are the comments that get compiled into documentation. They are denoted by a ///, and support Markdown.
 */
 
-#![crate_name = "doc"]
+pub mod example_01 {
+    #![crate_name = "doc"]
 
-/// A human being is represented here
-pub struct Person {
-    /// A person must have a name, no matter how much Juliet may hate it
-    name: String,
-}
+    /// A human being is represented here
+    pub struct Person {
+        /// A person must have a name, no matter how much Juliet may hate it
+        name: String,
+    }
 
-impl Person {
-    /// Returns a person with the name given them
-    ///
-    /// # Arguments
-    ///
-    /// * `name` - A string slice that holds the name of the person
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// // You can have rust code between fences inside the comments
-    /// // If you pass --test to `rustdoc`, it will even test it for you!
-    /// use doc::Person;
-    /// let person = Person::new("name");
-    /// ```
-    pub fn new(name: &str) -> Person {
-        Person {
-            name: name.to_string(),
+    impl Person {
+        /// Returns a person with the name given them
+        ///
+        /// # Arguments
+        ///
+        /// * `name` - A string slice that holds the name of the person
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use rust_book_examples::rbe::meta::documentation::example_01 as doc;
+        /// // You can have rust code between fences inside the comments
+        /// // If you pass --test to `rustdoc`, it will even test it for you!
+        /// use doc::Person;
+        /// let person = Person::new("name");
+        /// ```
+        pub fn new(name: &str) -> Person {
+            Person {
+                name: name.to_string(),
+            }
         }
-    }
 
-    /// Gives a friendly hello!
-    ///
-    /// Says "Hello, [name]" to the `Person` it is called on.
-    pub fn hello(& self) {
-        println!("Hello, {}!", self.name);
+        /// Gives a friendly hello!
+        ///
+        /// Says "Hello, [name]" to the `Person` it is called on.
+        pub fn hello(& self) {
+            println!("Hello, {}!", self.name);
+        }
     }
-}
 
-fn main() {
-    let john = Person::new("John");
+    pub fn main() {
+        let john = Person::new("John");
 
-    john.hello();
+        john.hello();
+    }
 }
 
 
 
+
 /*
     To run the tests, first build the code as a library, then tell rustdoc where to find the library so it can link it into each doctest program:
 
@@ -81,7 +92,6 @@ fn main() {
 
 
 
-
 /*
     Doc attributes
 
@@ -91,19 +101,20 @@ fn main() {
     Used to inline docs, instead of linking out to separate page.
 */
 
-#[doc(inline)]
-pub use bar::Bar;
+pub mod example_02 {
+    #[doc(inline)]
+    pub use bar::Bar;
 
-/// bar docs
-mod bar {
-    /// the docs for Bar
-    pub struct Bar;
+    /// bar docs
+    mod bar {
+        /// the docs for Bar
+        pub struct Bar;
+    }
 }
 
 
 
 
-
 /*
     no_inline
 
@@ -111,11 +122,17 @@ mod bar {
 */
 
 // Example from libcore/prelude
-#[doc(no_inline)]
-pub use crate::mem::drop;
-
-
 
+/// ```
+/// # mod mem {
+/// #     pub use std::mem::drop;
+/// # }
+/// #
+/// #[doc(no_inline)]
+/// pub use crate::mem::drop;
+/// # fn main() {}
+/// ```
+pub mod example_03 {}
 
 
 
@@ -127,8 +144,16 @@ pub use crate::mem::drop;
 */
 
 // Example from the futures-rs library
-#[doc(hidden)]
-pub use self::async_await::*;
+
+/// ```
+/// # mod async_await {}
+/// #
+/// #[doc(hidden)]
+/// pub use self::async_await::*;
+/// # fn main() {}
+/// ```
+pub mod example_04 {}
+
 
 
 
@@ -143,4 +168,3 @@ pub use self::async_await::*;
         RFC 1946: Relative links to other items from doc comments (intra-rustdoc links)
         Is there any documentation style guide for comments? (reddit)
 */
-
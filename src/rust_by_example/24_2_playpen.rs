@@ -1,19 +1,26 @@
 // Playpen
 // https://doc.rust-lang.org/rust-by-example/meta/playpen.html
 
+
+
+
 /*
     The Rust Playpen is a way to experiment with Rust code through a web interface. This project is now commonly referred to as Rust Playground.
 */
 
 
+
+
 /*
     Using it with mdbook
 
     In mdbook, you can make code examples playable and editable.
 */
 
-fn main() {
-    println!("Hello World!");
+pub mod example_01 {
+    pub fn main() {
+        println!("Hello World!");
+    }
 }
 
 
@@ -28,6 +35,8 @@ fn main() {
 */
 
 
+
+
 /*
     Additionally, you can add ignore if you want mdbook to skip your code when it builds and tests.
 
@@ -38,6 +47,7 @@ fn main() {
 
 
 
+
 /*
     Using it with docs
 
@@ -48,4 +58,3 @@ fn main() {
         The next-gen playpen
         The rustdoc Book
 */
-
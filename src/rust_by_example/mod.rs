@@ -0,0 +1,466 @@
+//! Examples from "Rust by Example", one module per chapter and one
+//! submodule per section.
+//!
+//! https://doc.rust-lang.org/rust-by-example/
+
+#[path = "."]
+pub mod hello_world {
+    #[path = "01_0_hello_world.rs"]
+    pub mod intro;
+
+    #[path = "01_1_comments.rs"]
+    pub mod comments;
+
+    #[path = "01_2_0_formatted_print.rs"]
+    pub mod formatted_print;
+
+    #[path = "01_2_1_debug.rs"]
+    pub mod debug;
+
+    #[path = "01_2_2_display.rs"]
+    pub mod display;
+
+    #[path = "01_2_2_testcase_list.rs"]
+    pub mod testcase_list;
+
+    #[path = "01_2_3_formatting.rs"]
+    pub mod formatting;
+}
+
+#[path = "."]
+pub mod primitives {
+    #[path = "02_0_primitives.rs"]
+    pub mod intro;
+
+    #[path = "02_1_literals_and_operators.rs"]
+    pub mod literals_and_operators;
+
+    #[path = "02_2_tuples.rs"]
+    pub mod tuples;
+
+    #[path = "02_3_arrays_and_slices.rs"]
+    pub mod arrays_and_slices;
+}
+
+#[path = "."]
+pub mod custom_types {
+    #[path = "03_1_custom_types_structures.rs"]
+    pub mod structures;
+
+    #[path = "03_2_0_enums.rs"]
+    pub mod enums;
+
+    #[path = "03_2_1_use.rs"]
+    pub mod enum_use;
+
+    #[path = "03_2_2_c-like.rs"]
+    pub mod c_like;
+
+    #[path = "03_2_3_testcase_linked-list.rs"]
+    pub mod testcase_linked_list;
+
+    #[path = "03_3_constants.rs"]
+    pub mod constants;
+}
+
+#[path = "."]
+pub mod variable_bindings {
+    #[path = "04_0_variable_bindings.rs"]
+    pub mod intro;
+
+    #[path = "04_1_mutability.rs"]
+    pub mod mutability;
+
+    #[path = "04_2_scope_and_shadowing.rs"]
+    pub mod scope_and_shadowing;
+
+    #[path = "04_3_declare_first.rs"]
+    pub mod declare_first;
+
+    #[path = "04_4_freezing.rs"]
+    pub mod freezing;
+}
+
+#[path = "."]
+pub mod types {
+    #[path = "05_1_types_casting.rs"]
+    pub mod casting;
+
+    #[path = "05_2_literals.rs"]
+    pub mod literals;
+
+    #[path = "05_3_inference.rs"]
+    pub mod inference;
+
+    #[path = "05_4_aliasing.rs"]
+    pub mod aliasing;
+}
+
+#[path = "."]
+pub mod conversion {
+    #[path = "06_1_conversion_from_and_into.rs"]
+    pub mod from_and_into;
+
+    #[path = "06_2_tryFrom_and_tryInto.rs"]
+    pub mod try_from_and_try_into;
+
+    #[path = "06_3_to_and_from_strings.rs"]
+    pub mod to_and_from_strings;
+}
+
+#[path = "07_expressions.rs"]
+pub mod expressions;
+
+#[path = "."]
+pub mod flow_control {
+    #[path = "08_1_flow_of_control_if-else.rs"]
+    pub mod if_else;
+
+    #[path = "08_2_loop.rs"]
+    pub mod loops;
+
+    #[path = "08_3_while.rs"]
+    pub mod while_loops;
+
+    #[path = "08_4_for_and_range.rs"]
+    pub mod for_and_range;
+
+    #[path = "08_5_1_match_destructuring.rs"]
+    pub mod match_destructuring;
+
+    #[path = "08_5_2_guards.rs"]
+    pub mod guards;
+
+    #[path = "08_5_3_binding.rs"]
+    pub mod binding;
+
+    #[path = "08_6_if_let.rs"]
+    pub mod if_let;
+
+    #[path = "08_7_while_let.rs"]
+    pub mod while_let;
+}
+
+#[path = "."]
+pub mod functions {
+    #[path = "09_0_functions.rs"]
+    pub mod intro;
+
+    #[path = "09_1_methods.rs"]
+    pub mod methods;
+
+    #[path = "09_2_closures.rs"]
+    pub mod closures;
+
+    #[path = "09_3_higher_order_functions.rs"]
+    pub mod higher_order_functions;
+
+    #[path = "09_4_diverging_functions.rs"]
+    pub mod diverging_functions;
+}
+
+#[path = "."]
+pub mod modules {
+    #[path = "10_1_modules_visibility.rs"]
+    pub mod visibility;
+
+    #[path = "10_2_struct_visibility.rs"]
+    pub mod struct_visibility;
+
+    #[path = "10_3_the_use_declaration.rs"]
+    pub mod use_declaration;
+
+    #[path = "10_4_super_and_self.rs"]
+    pub mod super_and_self;
+
+    #[path = "10_5_file_hierarchy.rs"]
+    pub mod file_hierarchy;
+}
+
+#[path = "."]
+pub mod cargo {
+    #[path = "12_1_cargo_dependencies.rs"]
+    pub mod dependencies;
+
+    #[path = "12_2_conventions.rs"]
+    pub mod conventions;
+
+    #[path = "12_3_tests.rs"]
+    pub mod tests;
+
+    #[path = "12_4_build_scripts.rs"]
+    pub mod build_scripts;
+}
+
+#[path = "."]
+pub mod attributes {
+    #[path = "13_1_attributes_dead_code.rs"]
+    pub mod dead_code;
+
+    #[path = "13_2_crates.rs"]
+    pub mod crates;
+
+    #[path = "13_3_cfg_custom.rs"]
+    pub mod cfg;
+}
+
+#[path = "."]
+pub mod generics {
+    #[path = "14_0_generics.rs"]
+    pub mod intro;
+
+    #[path = "14_1_functions.rs"]
+    pub mod functions;
+
+    #[path = "14_2_implementation.rs"]
+    pub mod implementation;
+
+    #[path = "14_3_Traits.rs"]
+    pub mod traits;
+
+    #[path = "14_4_bounds_testcase_empty_bounds.rs"]
+    pub mod bounds;
+
+    #[path = "14_5_multiple_bounds.rs"]
+    pub mod multiple_bounds;
+
+    #[path = "14_6_where_clauses.rs"]
+    pub mod where_clauses;
+
+    #[path = "14_7_new_type_idiom.rs"]
+    pub mod new_type_idiom;
+
+    #[path = "14_8_associated_items-the_problem-associated_types.rs"]
+    pub mod associated_items;
+
+    #[path = "14_9_phantom_type_parameters-testcase_unit_clarification.rs"]
+    pub mod phantom_type_parameters;
+}
+
+#[path = "."]
+pub mod scoping_rules {
+    #[path = "15_1_scoping_rules-raii.rs"]
+    pub mod raii;
+
+    #[path = "15_2_ownership_and_moves-mutability-partial_moves.rs"]
+    pub mod ownership_and_moves;
+
+    #[path = "15_3_borrowing-mutability-aliasing-the_ref_pattern.rs"]
+    pub mod borrowing;
+
+    #[path = "15_4_lifetimes.rs"]
+    pub mod lifetimes;
+}
+
+#[path = "."]
+pub mod traits {
+    #[path = "16_1_traits_derive.rs"]
+    pub mod derive;
+
+    #[path = "16_2_returning_traits_with_dyn.rs"]
+    pub mod returning_traits_with_dyn;
+
+    #[path = "16_3_operator_overloading.rs"]
+    pub mod operator_overloading;
+
+    #[path = "16_4_drop.rs"]
+    pub mod drop;
+
+    #[path = "16_5_iterators.rs"]
+    pub mod iterators;
+
+    #[path = "16_6_impl_trait.rs"]
+    pub mod impl_trait;
+
+    #[path = "16_7_clone.rs"]
+    pub mod clone;
+
+    #[path = "16_8_supertraits.rs"]
+    pub mod supertraits;
+
+    #[path = "16_9_disambiguating_overlapping_traits.rs"]
+    pub mod disambiguating_overlapping_traits;
+}
+
+#[path = "."]
+pub mod macros {
+    #[path = "17_0_macro_rules!.rs"]
+    pub mod macro_rules;
+
+    #[path = "17_1_syntax_designators_overload_repeat.rs"]
+    pub mod syntax;
+
+    #[path = "17_2_dry_(don't_repeat_yourself).rs"]
+    pub mod dry;
+
+    #[path = "17_3_dsl_(domain_specific_languages).rs"]
+    pub mod dsl;
+
+    #[path = "17_4_variadics.rs"]
+    pub mod variadics;
+}
+
+#[path = "."]
+pub mod error_handling {
+    #[path = "18_1_error_handling_panic.rs"]
+    pub mod panic;
+
+    #[path = "18_2_abort_&_unwind.rs"]
+    pub mod abort_and_unwind;
+
+    #[path = "18_3_0_option_&_unwrap.rs"]
+    pub mod option_and_unwrap;
+
+    #[path = "18_3_1_unpacking_options_with_.rs"]
+    pub mod unpacking_options_with_question_mark;
+
+    #[path = "18_3_2_combinators_map.rs"]
+    pub mod combinators_map;
+
+    #[path = "18_3_3_combinators_and_then.rs"]
+    pub mod combinators_and_then;
+
+    #[path = "18_4_0_result.rs"]
+    pub mod result;
+
+    #[path = "18_4_1_map_for_result.rs"]
+    pub mod map_for_result;
+
+    #[path = "18_4_2_aliases_for_result.rs"]
+    pub mod aliases_for_result;
+
+    #[path = "18_4_3_early_returns.rs"]
+    pub mod early_returns;
+
+    #[path = "18_4_4_introducing_.rs"]
+    pub mod introducing_question_mark;
+
+    #[path = "18_5_0_multiple_error_types.rs"]
+    pub mod multiple_error_types;
+
+    #[path = "18_5_1_pulling_results_out_of_options.rs"]
+    pub mod pulling_results_out_of_options;
+
+    #[path = "18_5_2_defining_an_error_type.rs"]
+    pub mod defining_an_error_type;
+
+    #[path = "18_5_3_boxing_errors.rs"]
+    pub mod boxing_errors;
+
+    #[path = "18_5_4_other_uses_of_.rs"]
+    pub mod other_uses_of_question_mark;
+
+    #[path = "18_5_5_wrapping_errors.rs"]
+    pub mod wrapping_errors;
+
+    #[path = "18_6_iterating_over_results.rs"]
+    pub mod iterating_over_results;
+}
+
+#[path = "."]
+pub mod std_library_types {
+    #[path = "19_1_std_library_types-box,_stack_and_heap.rs"]
+    pub mod box_stack_and_heap;
+
+    #[path = "19_2_vectors.rs"]
+    pub mod vectors;
+
+    #[path = "19_3_strings.rs"]
+    pub mod strings;
+
+    #[path = "19_4_option.rs"]
+    pub mod option;
+
+    #[path = "19_5_result.rs"]
+    pub mod result;
+
+    #[path = "19_6_panic!.rs"]
+    pub mod panic;
+
+    #[path = "19_7_0_hashmap.rs"]
+    pub mod hashmap;
+
+    #[path = "19_7_1_alternate-custom_key_types.rs"]
+    pub mod alternate_custom_key_types;
+
+    #[path = "19_7_2_hashset.rs"]
+    pub mod hashset;
+
+    #[path = "19_8_rc.rs"]
+    pub mod rc;
+
+    #[path = "19_9_arc.rs"]
+    pub mod arc;
+}
+
+#[path = "."]
+pub mod std_misc {
+    #[path = "20_1_std_misc_threads.rs"]
+    pub mod threads;
+
+    #[path = "20_2_channels.rs"]
+    pub mod channels;
+
+    #[path = "20_3_path.rs"]
+    pub mod path;
+
+    #[path = "20_4_1_file_io_open.rs"]
+    pub mod file_io_open;
+
+    #[path = "20_4_2_create.rs"]
+    pub mod create;
+
+    #[path = "20_4_3_read_lines.rs"]
+    pub mod read_lines;
+
+    #[path = "20_5_child_processes_pipes_wait.rs"]
+    pub mod child_processes;
+
+    #[path = "20_6_filesystem_operations.rs"]
+    pub mod filesystem_operations;
+
+    #[path = "20_7_program_arguments.rs"]
+    pub mod program_arguments;
+
+    #[path = "20_8_foreign_function_interface.rs"]
+    pub mod foreign_function_interface;
+}
+
+#[path = "."]
+pub mod testing {
+    #[path = "21_1_testing-unit_testing.rs"]
+    pub mod unit_testing;
+
+    #[path = "21_2_documentation_testing.rs"]
+    pub mod documentation_testing;
+
+    #[path = "21_3_integration_testing.rs"]
+    pub mod integration_testing;
+
+    #[path = "21_4_dev-dependencies.rs"]
+    pub mod dev_dependencies;
+}
+
+#[path = "."]
+pub mod unsafe_operations {
+    #[path = "22_0_unsafe_operations.rs"]
+    pub mod intro;
+
+    #[path = "22_1_inline_assembly.rs"]
+    pub mod inline_assembly;
+}
+
+#[path = "."]
+pub mod compatibility {
+    #[path = "23_1_compatibility-raw_identifiers.rs"]
+    pub mod raw_identifiers;
+}
+
+#[path = "."]
+pub mod meta {
+    #[path = "24_1_meta-documentation.rs"]
+    pub mod documentation;
+
+    #[path = "24_2_playpen.rs"]
+    pub mod playpen;
+}
@@ -4,91 +4,88 @@
 
 
 
-
-
-
 // We begin with the short program in Listing 10-1 that finds the largest number in a list.
 // Filename: src/main.rs
-fn main() {
-    let number_list = vec![34, 50, 25, 100, 65];
 
-    let mut largest = &number_list[0];
+pub mod listing_10_01 {
+    pub fn main() {
+        let number_list = vec![34, 50, 25, 100, 65];
 
-    for number in &number_list {
-        if number > largest {
-            largest = number;
+        let mut largest = &number_list[0];
+
+        for number in &number_list {
+            if number > largest {
+                largest = number;
+            }
         }
-    }
 
-    println!("The largest number is {}", largest);
-    assert_eq!(*largest, 100);
+        println!("The largest number is {}", largest);
+        assert_eq!(*largest, 100);
+    }
 }
 
 
 
 
 // Listing 10-2: Code to find the largest number in two lists of numbers
-fn main() {
-    let number_list = vec![34, 50, 25, 100, 65];
 
-    let mut largest = &number_list[0];
+pub mod listing_10_02 {
+    pub fn main() {
+        let number_list = vec![34, 50, 25, 100, 65];
 
-    for number in &number_list {
-        if number > largest {
-            largest = number;
+        let mut largest = &number_list[0];
+
+        for number in &number_list {
+            if number > largest {
+                largest = number;
+            }
         }
-    }
 
-    println!("The largest number is {}", largest);
+        println!("The largest number is {}", largest);
 
-    let number_list = vec![102, 34, 6000, 89, 54, 2, 43, 8];
+        let number_list = vec![102, 34, 6000, 89, 54, 2, 43, 8];
 
-    let mut largest = &number_list[0];
+        let mut largest = &number_list[0];
 
-    for number in &number_list {
-        if number > largest {
-            largest = number;
+        for number in &number_list {
+            if number > largest {
+                largest = number;
+            }
         }
-    }
 
-    println!("The largest number is {}", largest);
+        println!("The largest number is {}", largest);
+    }
 }
 
 
 
 
 // Listing 10-3: Abstracted code to find the largest number in two lists
-fn largest(list: &[i32]) -> &i32 {
-    let mut largest = &list[0];
 
-    for item in list {
-        if item > largest {
-            largest = item;
+pub mod listing_10_03 {
+    fn largest(list: &[i32]) -> &i32 {
+        let mut largest = &list[0];
+
+        for item in list {
+            if item > largest {
+                largest = item;
+            }
         }
-    }
 
-    largest
-}
+        largest
+    }
 
-fn main() {
-    let number_list = vec![34, 50, 25, 100, 65];
+    pub fn main() {
+        let number_list = vec![34, 50, 25, 100, 65];
 
-    let result = largest(&number_list);
-    println!("The largest number is {}", result);
-    assert_eq!(*result, 100);
+        let result = largest(&number_list);
+        println!("The largest number is {}", result);
+        assert_eq!(*result, 100);
 
-    let number_list = vec![102, 34, 6000, 89, 54, 2, 43, 8];
+        let number_list = vec![102, 34, 6000, 89, 54, 2, 43, 8];
 
-    let result = largest(&number_list);
-    println!("The largest number is {}", result);
-    assert_eq!(*result, 6000);
+        let result = largest(&number_list);
+        println!("The largest number is {}", result);
+        assert_eq!(*result, 6000);
+    }
 }
-
-
-
-
-
-
-
-
-
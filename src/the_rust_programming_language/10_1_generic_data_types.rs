@@ -5,76 +5,78 @@
 
 
 // Listing 10-4: Two functions that differ only in their names and the types in their signatures
-fn largest_i32(list: &[i32]) -> &i32 {
-    let mut largest = &list[0];
 
-    for item in list {
-        if item > largest {
-            largest = item;
+pub mod listing_10_04 {
+    fn largest_i32(list: &[i32]) -> &i32 {
+        let mut largest = &list[0];
+
+        for item in list {
+            if item > largest {
+                largest = item;
+            }
         }
-    }
 
-    largest
-}
+        largest
+    }
 
-fn largest_char(list: &[char]) -> &char {
-    let mut largest = &list[0];
+    fn largest_char(list: &[char]) -> &char {
+        let mut largest = &list[0];
 
-    for item in list {
-        if item > largest {
-            largest = item;
+        for item in list {
+            if item > largest {
+                largest = item;
+            }
         }
-    }
 
-    largest
-}
+        largest
+    }
 
-fn main() {
-    let number_list = vec![34, 50, 25, 100, 65];
+    pub fn main() {
+        let number_list = vec![34, 50, 25, 100, 65];
 
-    let result = largest_i32(&number_list);
-    println!("The largest number is {}", result);
-    assert_eq!(*result, 100);
+        let result = largest_i32(&number_list);
+        println!("The largest number is {}", result);
+        assert_eq!(*result, 100);
 
-    let char_list = vec!['y', 'm', 'a', 'q'];
+        let char_list = vec!['y', 'm', 'a', 'q'];
 
-    let result = largest_char(&char_list);
-    println!("The largest char is {}", result);
-    assert_eq!(*result, 'y');
+        let result = largest_char(&char_list);
+        println!("The largest char is {}", result);
+        assert_eq!(*result, 'y');
+    }
 }
 
 
 
 
-
-
-
-
-
 // Listing 10-5: The largest function using generic type parameters; this doesn’t yet compile
-fn largest<T>(list: &[T]) -> &T {
-    let mut largest = &list[0];
 
-    for item in list {
-        if item > largest {
-            largest = item;
-        }
-    }
-
-    largest
-}
-
-fn main() {
-    let number_list = vec![34, 50, 25, 100, 65];
-
-    let result = largest(&number_list);
-    println!("The largest number is {}", result);
-
-    let char_list = vec!['y', 'm', 'a', 'q'];
-
-    let result = largest(&char_list);
-    println!("The largest char is {}", result);
-}
+/// ```compile_fail
+/// fn largest<T>(list: &[T]) -> &T {
+///     let mut largest = &list[0];
+///
+///     for item in list {
+///         if item > largest {
+///             largest = item;
+///         }
+///     }
+///
+///     largest
+/// }
+///
+/// fn main() {
+///     let number_list = vec![34, 50, 25, 100, 65];
+///
+///     let result = largest(&number_list);
+///     println!("The largest number is {}", result);
+///
+///     let char_list = vec!['y', 'm', 'a', 'q'];
+///
+///     let result = largest(&char_list);
+///     println!("The largest char is {}", result);
+/// }
+/// ```
+pub mod listing_10_05 {}
 
 
 
@@ -104,33 +106,40 @@ error: could not compile `chapter10` due to previous error
 
 
 
-
-
-
 // In Struct Definitions
 
 // Listing 10-6: A Point<T> struct that holds x and y values of type T
-struct Point<T> {
-    x: T,
-    y: T,
-}
 
-fn main() {
-    let integer = Point { x: 5, y: 10 };
-    let float = Point { x: 1.0, y: 4.0 };
+pub mod listing_10_06 {
+    struct Point<T> {
+        x: T,
+        y: T,
+    }
+
+    pub fn main() {
+        let integer = Point { x: 5, y: 10 };
+        let float = Point { x: 1.0, y: 4.0 };
+    }
 }
 
 
 
+
 // Listing 10-7: The fields x and y must be the same type because both have the same generic data type T.
-struct Point<T> {
-    x: T,
-    y: T,
-}
 
-fn main() {
-    let wont_work = Point { x: 5, y: 4.0 };
-}
+/// ```compile_fail
+/// struct Point<T> {
+///     x: T,
+///     y: T,
+/// }
+///
+/// fn main() {
+///     let wont_work = Point { x: 5, y: 4.0 };
+/// }
+/// ```
+pub mod listing_10_07 {}
+
+
 
 
 // Then when we specify 4.0 for y, which we’ve defined to have the same type as x, 
@@ -153,19 +162,19 @@ error: could not compile `chapter10` due to previous error
 
 
 // Listing 10-8: A Point<T, U> generic over two types so that x and y can be values of different types
-struct Point<T, U> {
-    x: T,
-    y: U,
-}
-
-fn main() {
-    let both_integer = Point { x: 5, y: 10 };
-    let both_float = Point { x: 1.0, y: 4.0 };
-    let integer_and_float = Point { x: 5, y: 4.0 };
-}
-
 
+pub mod listing_10_08 {
+    struct Point<T, U> {
+        x: T,
+        y: U,
+    }
 
+    pub fn main() {
+        let both_integer = Point { x: 5, y: 10 };
+        let both_float = Point { x: 1.0, y: 4.0 };
+        let integer_and_float = Point { x: 5, y: 4.0 };
+    }
+}
 
 
 
@@ -175,27 +184,31 @@ fn main() {
 // As we did with structs, we can define enums to hold generic data types in their 
 // variants. Let’s take another look at the Option<T> enum that the standard library 
 // provides, which we used in Chapter 6:
-#![allow(unused)]
-fn main() {
-enum Option<T> {
-    Some(T),
-    None,
-}
-}
-
 
+pub mod example_06 {
+    #![allow(unused)]
+    pub fn main() {
+    enum Option<T> {
+        Some(T),
+        None,
+    }
+    }
+}
 
 
 
 
 // Enums can use multiple generic types as well. The definition of the Result enum 
 // that we used in Chapter 9 is one example:
-#![allow(unused)]
-fn main() {
-enum Result<T, E> {
-    Ok(T),
-    Err(E),
-}
+
+pub mod example_07 {
+    #![allow(unused)]
+    pub fn main() {
+    enum Result<T, E> {
+        Ok(T),
+        Err(E),
+    }
+    }
 }
 
 
@@ -205,139 +218,121 @@ enum Result<T, E> {
 
 // Listing 10-9: Implementing a method named x on the Point<T> struct that will 
 // return a reference to the x field of type T
-struct Point<T> {
-    x: T,
-    y: T,
-}
 
-impl<T> Point<T> {
-    fn x(&self) -> &T {
-        &self.x
+pub mod listing_10_09 {
+    struct Point<T> {
+        x: T,
+        y: T,
     }
-}
-
-fn main() {
-    let p = Point { x: 5, y: 10 };
-
-    println!("p.x = {}", p.x());
-}
 
+    impl<T> Point<T> {
+        fn x(&self) -> &T {
+            &self.x
+        }
+    }
 
+    pub fn main() {
+        let p = Point { x: 5, y: 10 };
 
+        println!("p.x = {}", p.x());
+    }
+}
 
 
 
 
 // Listing 10-10: An impl block that only applies to a struct with a particular concrete 
 // type for the generic type parameter T
-struct Point<T> {
-    x: T,
-    y: T,
-}
 
-impl<T> Point<T> {
-    fn x(&self) -> &T {
-        &self.x
+pub mod listing_10_10 {
+    struct Point<T> {
+        x: T,
+        y: T,
     }
-}
 
-impl Point<f32> {
-    fn distance_from_origin(&self) -> f32 {
-        (self.x.powi(2) + self.y.powi(2)).sqrt()
+    impl<T> Point<T> {
+        fn x(&self) -> &T {
+            &self.x
+        }
     }
-}
 
-fn main() {
-    let p = Point { x: 5, y: 10 };
+    impl Point<f32> {
+        fn distance_from_origin(&self) -> f32 {
+            (self.x.powi(2) + self.y.powi(2)).sqrt()
+        }
+    }
 
-    println!("p.x = {}", p.x());
-}
+    pub fn main() {
+        let p = Point { x: 5, y: 10 };
 
+        println!("p.x = {}", p.x());
+    }
+}
 
 
 
 
 // Listing 10-11: A method that uses generic types different from its struct’s definition
-struct Point<X1, Y1> {
-    x: X1,
-    y: Y1,
-}
 
-impl<X1, Y1> Point<X1, Y1> {
-    fn mixup<X2, Y2>(self, other: Point<X2, Y2>) -> Point<X1, Y2> {
-        Point {
-            x: self.x,
-            y: other.y,
+pub mod listing_10_11 {
+    struct Point<X1, Y1> {
+        x: X1,
+        y: Y1,
+    }
+
+    impl<X1, Y1> Point<X1, Y1> {
+        fn mixup<X2, Y2>(self, other: Point<X2, Y2>) -> Point<X1, Y2> {
+            Point {
+                x: self.x,
+                y: other.y,
+            }
         }
     }
-}
 
-fn main() {
-    let p1 = Point { x: 5, y: 10.4 };
-    let p2 = Point { x: "Hello", y: 'c' };
+    pub fn main() {
+        let p1 = Point { x: 5, y: 10.4 };
+        let p2 = Point { x: "Hello", y: 'c' };
 
-    let p3 = p1.mixup(p2);
+        let p3 = p1.mixup(p2);
 
-    println!("p3.x = {}, p3.y = {}", p3.x, p3.y);
+        println!("p3.x = {}, p3.y = {}", p3.x, p3.y);
+    }
 }
 
 
 
 
-
-
-
-
 // Performance of Code Using Generics
 
 // Let’s look at how this works by using the standard library’s generic Option<T> enum:
-#![allow(unused)]
-fn main() {
-let integer = Some(5);
-let float = Some(5.0);
-}
-
-
-
-// The monomorphized version of the code looks similar to the following (the compiler 
-// uses different names than what we’re using here for illustration):
-enum Option_i32 {
-    Some(i32),
-    None,
-}
 
-enum Option_f64 {
-    Some(f64),
-    None,
-}
-
-fn main() {
-    let integer = Option_i32::Some(5);
-    let float = Option_f64::Some(5.0);
+pub mod example_11 {
+    #![allow(unused)]
+    pub fn main() {
+    let integer = Some(5);
+    let float = Some(5.0);
+    }
 }
 
 
 
 
+// The monomorphized version of the code looks similar to the following (the compiler 
+// uses different names than what we’re using here for illustration):
 
+pub mod example_12 {
+    enum Option_i32 {
+        Some(i32),
+        None,
+    }
 
+    enum Option_f64 {
+        Some(f64),
+        None,
+    }
 
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
+    pub fn main() {
+        let integer = Option_i32::Some(5);
+        let float = Option_f64::Some(5.0);
+    }
+}
@@ -4,13 +4,13 @@
 
 
 
-
 // Listing 10-12: A Summary trait that consists of the behavior provided by a summarize method
-pub trait Summary {
-    fn summarize(&self) -> String;
-}
-
 
+pub mod listing_10_12 {
+    pub trait Summary {
+        fn summarize(&self) -> String;
+    }
+}
 
 
 
@@ -18,58 +18,63 @@ pub trait Summary {
 // Implementing a Trait on a Type
 
 // Listing 10-13: Implementing the Summary trait on the NewsArticle and Tweet types
-pub trait Summary {
-    fn summarize(&self) -> String;
-}
-
-pub struct NewsArticle {
-    pub headline: String,
-    pub location: String,
-    pub author: String,
-    pub content: String,
-}
 
-impl Summary for NewsArticle {
-    fn summarize(&self) -> String {
-        format!("{}, by {} ({})", self.headline, self.author, self.location)
+pub mod listing_10_13 {
+    pub trait Summary {
+        fn summarize(&self) -> String;
     }
-}
 
-pub struct Tweet {
-    pub username: String,
-    pub content: String,
-    pub reply: bool,
-    pub retweet: bool,
-}
+    pub struct NewsArticle {
+        pub headline: String,
+        pub location: String,
+        pub author: String,
+        pub content: String,
+    }
 
-impl Summary for Tweet {
-    fn summarize(&self) -> String {
-        format!("{}: {}", self.username, self.content)
+    impl Summary for NewsArticle {
+        fn summarize(&self) -> String {
+            format!("{}, by {} ({})", self.headline, self.author, self.location)
+        }
     }
-}
 
+    pub struct Tweet {
+        pub username: String,
+        pub content: String,
+        pub reply: bool,
+        pub retweet: bool,
+    }
 
+    impl Summary for Tweet {
+        fn summarize(&self) -> String {
+            format!("{}: {}", self.username, self.content)
+        }
+    }
+}
 
 
 
 
 // The only difference is that the user must bring the trait into scope as well 
 // as the types. Here’s an example of how a binary crate could use our aggregator library crate:
-use aggregator::{Summary, Tweet};
-
-fn main() {
-    let tweet = Tweet {
-        username: String::from("horse_ebooks"),
-        content: String::from(
-            "of course, as you probably already know, people",
-        ),
-        reply: false,
-        retweet: false,
-    };
-
-    println!("1 new tweet: {}", tweet.summarize());
-}
 
+/// ```
+/// # mod aggregator { pub use rust_book_examples::trpl::ch10_generics::traits::listing_10_13::*; }
+/// use aggregator::{Summary, Tweet};
+///
+/// fn main() {
+///     let tweet = Tweet {
+///         username: String::from("horse_ebooks"),
+///         content: String::from(
+///             "of course, as you probably already know, people",
+///         ),
+///         reply: false,
+///         retweet: false,
+///     };
+///
+///     println!("1 new tweet: {}", tweet.summarize());
+/// }
+/// ```
+pub mod example_03 {}
 
 
 
@@ -77,57 +82,64 @@ fn main() {
 // Default Implementations
 
 // Listing 10-14: Defining a Summary trait with a default implementation of the summarize method
-pub trait Summary {
-    fn summarize(&self) -> String {
-        String::from("(Read more...)")
+
+pub mod listing_10_14 {
+    pub trait Summary {
+        fn summarize(&self) -> String {
+            String::from("(Read more...)")
+        }
     }
-}
 
-pub struct NewsArticle {
-    pub headline: String,
-    pub location: String,
-    pub author: String,
-    pub content: String,
-}
+    pub struct NewsArticle {
+        pub headline: String,
+        pub location: String,
+        pub author: String,
+        pub content: String,
+    }
 
-impl Summary for NewsArticle {}
+    impl Summary for NewsArticle {}
 
-pub struct Tweet {
-    pub username: String,
-    pub content: String,
-    pub reply: bool,
-    pub retweet: bool,
-}
+    pub struct Tweet {
+        pub username: String,
+        pub content: String,
+        pub reply: bool,
+        pub retweet: bool,
+    }
 
-impl Summary for Tweet {
-    fn summarize(&self) -> String {
-        format!("{}: {}", self.username, self.content)
+    impl Summary for Tweet {
+        fn summarize(&self) -> String {
+            format!("{}: {}", self.username, self.content)
+        }
     }
 }
 
 
 
 
-
-
-
 // As a result, we can still call the summarize method on an instance of NewsArticle, like this:
-use aggregator::{self, NewsArticle, Summary};
-
-fn main() {
-    let article = NewsArticle {
-        headline: String::from("Penguins win the Stanley Cup Championship!"),
-        location: String::from("Pittsburgh, PA, USA"),
-        author: String::from("Iceburgh"),
-        content: String::from(
-            "The Pittsburgh Penguins once again are the best \
-             hockey team in the NHL.",
-        ),
-    };
-
-    println!("New article available! {}", article.summarize());
-}
 
+/// ```
+/// # mod aggregator { pub use rust_book_examples::trpl::ch10_generics::traits::listing_10_14::*; }
+/// # mod bin { use super::*;
+/// use aggregator::{self, NewsArticle, Summary};
+///
+/// fn main() {
+///     let article = NewsArticle {
+///         headline: String::from("Penguins win the Stanley Cup Championship!"),
+///         location: String::from("Pittsburgh, PA, USA"),
+///         author: String::from("Iceburgh"),
+///         content: String::from(
+///             "The Pittsburgh Penguins once again are the best \
+///              hockey team in the NHL.",
+///         ),
+///     };
+///
+///     println!("New article available! {}", article.summarize());
+/// }
+/// # }
+/// # fn main() {}
+/// ```
+pub mod example_05 {}
 
 
 
@@ -135,113 +147,162 @@ fn main() {
 // For example, we could define the Summary trait to have a summarize_author method 
 // whose implementation is required, and then define a summarize method that has a default 
 // implementation that calls the summarize_author method:
-pub trait Summary {
-    fn summarize_author(&self) -> String;
 
-    fn summarize(&self) -> String {
-        format!("(Read more from {}...)", self.summarize_author())
+pub mod example_06 {
+    pub trait Summary {
+        fn summarize_author(&self) -> String;
+
+        fn summarize(&self) -> String {
+            format!("(Read more from {}...)", self.summarize_author())
+        }
     }
-}
 
-pub struct Tweet {
-    pub username: String,
-    pub content: String,
-    pub reply: bool,
-    pub retweet: bool,
-}
+    pub struct Tweet {
+        pub username: String,
+        pub content: String,
+        pub reply: bool,
+        pub retweet: bool,
+    }
 
-impl Summary for Tweet {
-    fn summarize_author(&self) -> String {
-        format!("@{}", self.username)
+    impl Summary for Tweet {
+        fn summarize_author(&self) -> String {
+            format!("@{}", self.username)
+        }
     }
 }
 
 
+
+
 // To use this version of Summary, we only need to define summarize_author when 
 // we implement the trait on a type:
-pub trait Summary {
-    fn summarize_author(&self) -> String;
 
-    fn summarize(&self) -> String {
-        format!("(Read more from {}...)", self.summarize_author())
+pub mod example_07 {
+    pub trait Summary {
+        fn summarize_author(&self) -> String;
+
+        fn summarize(&self) -> String {
+            format!("(Read more from {}...)", self.summarize_author())
+        }
     }
-}
 
-pub struct Tweet {
-    pub username: String,
-    pub content: String,
-    pub reply: bool,
-    pub retweet: bool,
-}
+    pub struct Tweet {
+        pub username: String,
+        pub content: String,
+        pub reply: bool,
+        pub retweet: bool,
+    }
 
-impl Summary for Tweet {
-    fn summarize_author(&self) -> String {
-        format!("@{}", self.username)
+    impl Summary for Tweet {
+        fn summarize_author(&self) -> String {
+            format!("@{}", self.username)
+        }
     }
 }
 
 
 
 
-
 // Because we’ve implemented summarize_author, the Summary trait has given us 
 // the behavior of the summarize method without requiring us to write any more code.
-use aggregator::{self, Summary, Tweet};
-
-fn main() {
-    let tweet = Tweet {
-        username: String::from("horse_ebooks"),
-        content: String::from(
-            "of course, as you probably already know, people",
-        ),
-        reply: false,
-        retweet: false,
-    };
-
-    println!("1 new tweet: {}", tweet.summarize());
-}
-// This code prints 1 new tweet: (Read more from @horse_ebooks...).
-
 
+/// ```
+/// # mod aggregator { pub use rust_book_examples::trpl::ch10_generics::traits::example_07::*; }
+/// # mod bin { use super::*;
+/// use aggregator::{self, Summary, Tweet};
+///
+/// fn main() {
+///     let tweet = Tweet {
+///         username: String::from("horse_ebooks"),
+///         content: String::from(
+///             "of course, as you probably already know, people",
+///         ),
+///         reply: false,
+///         retweet: false,
+///     };
+///
+///     println!("1 new tweet: {}", tweet.summarize());
+/// }
+/// # }
+/// # fn main() {}
+/// ```
+pub mod example_08 {}
 
+// This code prints 1 new tweet: (Read more from @horse_ebooks...).
 
 
 
 
+// Traits as Parameters
 
+/// ```
+/// # use rust_book_examples::trpl::ch10_generics::traits::listing_10_13::Summary;
+/// pub fn notify(item: &impl Summary) {
+///     println!("Breaking news! {}", item.summarize());
+/// }
+/// ```
+pub mod example_09 {}
 
-// Traits as Parameters
-pub fn notify(item: &impl Summary) {
-    println!("Breaking news! {}", item.summarize());
-}
 
 
 
 // Trait Bound Syntax
-pub fn notify<T: Summary>(item: &T) {
-    println!("Breaking news! {}", item.summarize());
-}
+
+/// ```
+/// # use rust_book_examples::trpl::ch10_generics::traits::listing_10_13::Summary;
+/// pub fn notify<T: Summary>(item: &T) {
+///     println!("Breaking news! {}", item.summarize());
+/// }
+/// ```
+pub mod example_10 {}
 
 
 
 
 // Doing so with the impl Trait syntax looks like this:
-pub fn notify(item1: &impl Summary, item2: &impl Summary) {}
+
+/// ```
+/// # use rust_book_examples::trpl::ch10_generics::traits::listing_10_13::Summary;
+/// pub fn notify(item1: &impl Summary, item2: &impl Summary) {}
+/// ```
+pub mod example_11 {}
+
+
 
 
 // If we want to force both parameters to have the same type, however, we must use a trait bound, like this:
-pub fn notify<T: Summary>(item1: &T, item2: &T) {}
+
+/// ```
+/// # use rust_book_examples::trpl::ch10_generics::traits::listing_10_13::Summary;
+/// pub fn notify<T: Summary>(item1: &T, item2: &T) {}
+/// ```
+pub mod example_12 {}
+
 
 
 
 // Specifying Multiple Trait Bounds with the + Syntax
 
 // We can do so using the + syntax:
-pub fn notify(item: &(impl Summary + Display)) {}
+
+/// ```
+/// # use std::fmt::Display;
+/// # use rust_book_examples::trpl::ch10_generics::traits::listing_10_13::Summary;
+/// pub fn notify(item: &(impl Summary + Display)) {}
+/// ```
+pub mod example_13 {}
+
+
 
 
 // The + syntax is also valid with trait bounds on generic types:
-pub fn notify<T: Summary + Display>(item: &T) {}
+
+/// ```
+/// # use std::fmt::Display;
+/// # use rust_book_examples::trpl::ch10_generics::traits::listing_10_13::Summary;
+/// pub fn notify<T: Summary + Display>(item: &T) {}
+/// ```
+pub mod example_14 {}
 
 
 
@@ -249,60 +310,82 @@ pub fn notify<T: Summary + Display>(item: &T) {}
 // Clearer Trait Bounds with where Clauses
 
 // So instead of writing this:
-fn some_function<T: Display + Clone, U: Clone + Debug>(t: &T, u: &U) -> i32 {}
-
-// we can use a where clause, like this:
-fn some_function<T, U>(t: &T, u: &U) -> i32 {
-   where T: Display + Clone,
-         U: Clone + Debug
-}
 
+/// ```
+/// # use std::fmt::{Debug, Display};
+/// # mod before { use super::*;
+/// fn some_function<T: Display + Clone, U: Clone + Debug>(t: &T, u: &U) -> i32 {
+/// #     0
+/// }
+/// # }
+///
+/// // we can use a where clause, like this:
+/// fn some_function<T, U>(t: &T, u: &U) -> i32
+/// where
+///     T: Display + Clone,
+///     U: Clone + Debug,
+/// {
+/// #     0
+/// }
+/// # fn main() {}
+/// ```
+pub mod example_15 {}
 
 
 
 
 // Returning Types that Implement Traits
-fn returns_summarizable() -> impl Summary {
-    Tweet {
-        username: String::from("horse_ebooks"),
-        content: String::from(
-            "of course, as you probably already know, people",
-        ),
-        reply: false,
-        retweet: false,
-    }
-}
+
+/// ```
+/// # use rust_book_examples::trpl::ch10_generics::traits::listing_10_13::{Summary, Tweet};
+/// fn returns_summarizable() -> impl Summary {
+///     Tweet {
+///         username: String::from("horse_ebooks"),
+///         content: String::from(
+///             "of course, as you probably already know, people",
+///         ),
+///         reply: false,
+///         retweet: false,
+///     }
+/// }
+/// ```
+pub mod example_16 {}
+
 
 
 
 // However, you can only use impl Trait if you’re returning a single type. For example, 
 // this code that returns either a NewsArticle or a Tweet with the return type specified 
 // as impl Summary wouldn’t work:
-fn returns_summarizable(switch: bool) -> impl Summary {
-    if switch {
-        NewsArticle {
-            headline: String::from(
-                "Penguins win the Stanley Cup Championship!",
-            ),
-            location: String::from("Pittsburgh, PA, USA"),
-            author: String::from("Iceburgh"),
-            content: String::from(
-                "The Pittsburgh Penguins once again are the best \
-                 hockey team in the NHL.",
-            ),
-        }
-    } else {
-        Tweet {
-            username: String::from("horse_ebooks"),
-            content: String::from(
-                "of course, as you probably already know, people",
-            ),
-            reply: false,
-            retweet: false,
-        }
-    }
-}
 
+/// ```compile_fail
+/// # use rust_book_examples::trpl::ch10_generics::traits::listing_10_13::{NewsArticle, Summary, Tweet};
+/// fn returns_summarizable(switch: bool) -> impl Summary {
+///     if switch {
+///         NewsArticle {
+///             headline: String::from(
+///                 "Penguins win the Stanley Cup Championship!",
+///             ),
+///             location: String::from("Pittsburgh, PA, USA"),
+///             author: String::from("Iceburgh"),
+///             content: String::from(
+///                 "The Pittsburgh Penguins once again are the best \
+///                  hockey team in the NHL.",
+///             ),
+///         }
+///     } else {
+///         Tweet {
+///             username: String::from("horse_ebooks"),
+///             content: String::from(
+///                 "of course, as you probably already know, people",
+///             ),
+///             reply: false,
+///             retweet: false,
+///         }
+///     }
+/// }
+/// ```
+pub mod example_17 {}
 
 
 
@@ -310,65 +393,53 @@ fn returns_summarizable(switch: bool) -> impl Summary {
 // Using Trait Bounds to Conditionally Implement Methods
 
 // Listing 10-15: Conditionally implementing methods on a generic type depending on trait bounds
-use std::fmt::Display;
 
-struct Pair<T> {
-    x: T,
-    y: T,
-}
+pub mod listing_10_15 {
+    use std::fmt::Display;
 
-impl<T> Pair<T> {
-    fn new(x: T, y: T) -> Self {
-        Self { x, y }
+    struct Pair<T> {
+        x: T,
+        y: T,
     }
-}
 
-impl<T: Display + PartialOrd> Pair<T> {
-    fn cmp_display(&self) {
-        if self.x >= self.y {
-            println!("The largest member is x = {}", self.x);
-        } else {
-            println!("The largest member is y = {}", self.y);
+    impl<T> Pair<T> {
+        fn new(x: T, y: T) -> Self {
+            Self { x, y }
         }
     }
-}
-
-
-
-
 
-// The impl block in the standard library looks similar to this code:
-impl<T: Display> ToString for T {
-    // --snip--
-}
-
-
-// For example, we can turn integers into their corresponding String values like 
-// this because integers implement Display:
-#![allow(unused)]
-fn main() {
-let s = 3.to_string();
+    impl<T: Display + PartialOrd> Pair<T> {
+        fn cmp_display(&self) {
+            if self.x >= self.y {
+                println!("The largest member is x = {}", self.x);
+            } else {
+                println!("The largest member is y = {}", self.y);
+            }
+        }
+    }
 }
 
 
 
 
+// The impl block in the standard library looks similar to this code:
 
+/// ```ignore
+/// impl<T: Display> ToString for T {
+///     // --snip--
+/// }
+/// ```
+pub mod example_19 {}
 
 
 
 
+// For example, we can turn integers into their corresponding String values like 
+// this because integers implement Display:
 
-
-
-
-
-
-
-
-
-
-
-
-
-
+pub mod example_20 {
+    #![allow(unused)]
+    pub fn main() {
+    let s = 3.to_string();
+    }
+}
@@ -13,13 +13,18 @@ $ cd adder
 */
 
 
+
+
 // Listing 11-1: The test module and function generated automatically by cargo new
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn it_works() {
-        let result = 2 + 2;
-        assert_eq!(result, 4);
+
+pub mod listing_11_01 {
+    #[cfg(test)]
+    mod tests {
+        #[test]
+        fn it_works() {
+            let result = 2 + 2;
+            assert_eq!(result, 4);
+        }
     }
 }
 
@@ -48,14 +53,18 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; fini
 
 
 
+
 // Let’s start to customize the test to our own needs. First change the name of the it_works 
 // function to a different name, such as exploration, like so:
 // Filename: src/lib.rs
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn exploration() {
-        assert_eq!(2 + 2, 4);
+
+pub mod example_02 {
+    #[cfg(test)]
+    mod tests {
+        #[test]
+        fn exploration() {
+            assert_eq!(2 + 2, 4);
+        }
     }
 }
 
@@ -85,18 +94,21 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; fini
 
 
 
-
 // Listing 11-3: Adding a second test that will fail because we call the panic! macro
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn exploration() {
-        assert_eq!(2 + 2, 4);
-    }
 
-    #[test]
-    fn another() {
-        panic!("Make this test fail");
+pub mod listing_11_03 {
+    #[cfg(test)]
+    mod tests {
+        #[test]
+        fn exploration() {
+            assert_eq!(2 + 2, 4);
+        }
+
+        #[test]
+        #[ignore = "fails on purpose, as the text after it shows"]
+        fn another() {
+            panic!("Make this test fail");
+        }
     }
 }
 
@@ -134,84 +146,82 @@ error: test failed, to rerun pass '--lib'
 
 
 
-
 // Checking Results with the assert! Macro
 
 // Listing 11-5: Using the Rectangle struct and its can_hold method from Chapter 5
-#[derive(Debug)]
-struct Rectangle {
-    width: u32,
-    height: u32,
-}
 
-impl Rectangle {
-    fn can_hold(&self, other: &Rectangle) -> bool {
-        self.width > other.width && self.height > other.height
+pub mod listing_11_05 {
+    #[derive(Debug)]
+    struct Rectangle {
+        width: u32,
+        height: u32,
+    }
+
+    impl Rectangle {
+        fn can_hold(&self, other: &Rectangle) -> bool {
+            self.width > other.width && self.height > other.height
+        }
     }
 }
 
 
 
+
 // Listing 11-6: A test for can_hold that checks whether a larger rectangle can indeed hold a smaller rectangle
 // Filename: src/lib.rs
-#[derive(Debug)]
-struct Rectangle {
-    width: u32,
-    height: u32,
-}
 
-impl Rectangle {
-    fn can_hold(&self, other: &Rectangle) -> bool {
-        self.width > other.width && self.height > other.height
+pub mod listing_11_06 {
+    #[derive(Debug)]
+    struct Rectangle {
+        width: u32,
+        height: u32,
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn larger_can_hold_smaller() {
-        let larger = Rectangle {
-            width: 8,
-            height: 7,
-        };
-        let smaller = Rectangle {
-            width: 5,
-            height: 1,
-        };
-
-        assert!(larger.can_hold(&smaller));
+    impl Rectangle {
+        fn can_hold(&self, other: &Rectangle) -> bool {
+            self.width > other.width && self.height > other.height
+        }
     }
-}
-
 
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn larger_can_hold_smaller() {
+            let larger = Rectangle {
+                width: 8,
+                height: 7,
+            };
+            let smaller = Rectangle {
+                width: 5,
+                height: 1,
+            };
+
+            assert!(larger.can_hold(&smaller));
+        }
+    }
 
+    /*
+    $ cargo test
+       Compiling rectangle v0.1.0 (file:///projects/rectangle)
+        Finished test [unoptimized + debuginfo] target(s) in 0.66s
+         Running unittests src/lib.rs (target/debug/deps/rectangle-6584c4561e48942e)
 
+    running 1 test
+    test tests::larger_can_hold_smaller ... ok
 
+    test result: ok. 1 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
 
+       Doc-tests rectangle
 
+    running 0 tests
 
-/*
-$ cargo test
-   Compiling rectangle v0.1.0 (file:///projects/rectangle)
-    Finished test [unoptimized + debuginfo] target(s) in 0.66s
-     Running unittests src/lib.rs (target/debug/deps/rectangle-6584c4561e48942e)
-
-running 1 test
-test tests::larger_can_hold_smaller ... ok
-
-test result: ok. 1 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
-
-   Doc-tests rectangle
-
-running 0 tests
-
-test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
-
+    test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
 
-*/
 
+    */
+}
 
 
 
@@ -219,54 +229,58 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; fini
 // It does pass! Let’s add another test, this time asserting that a smaller rectangle 
 // cannot hold a larger rectangle:
 // Filename: src/lib.rs
-#[derive(Debug)]
-struct Rectangle {
-    width: u32,
-    height: u32,
-}
 
-impl Rectangle {
-    fn can_hold(&self, other: &Rectangle) -> bool {
-        self.width > other.width && self.height > other.height
+pub mod example_06 {
+    #[derive(Debug)]
+    struct Rectangle {
+        width: u32,
+        height: u32,
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn larger_can_hold_smaller() {
-        // --snip--
-        let larger = Rectangle {
-            width: 8,
-            height: 7,
-        };
-        let smaller = Rectangle {
-            width: 5,
-            height: 1,
-        };
-
-        assert!(larger.can_hold(&smaller));
+    impl Rectangle {
+        fn can_hold(&self, other: &Rectangle) -> bool {
+            self.width > other.width && self.height > other.height
+        }
     }
 
-    #[test]
-    fn smaller_cannot_hold_larger() {
-        let larger = Rectangle {
-            width: 8,
-            height: 7,
-        };
-        let smaller = Rectangle {
-            width: 5,
-            height: 1,
-        };
-
-        assert!(!smaller.can_hold(&larger));
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn larger_can_hold_smaller() {
+            // --snip--
+            let larger = Rectangle {
+                width: 8,
+                height: 7,
+            };
+            let smaller = Rectangle {
+                width: 5,
+                height: 1,
+            };
+
+            assert!(larger.can_hold(&smaller));
+        }
+
+        #[test]
+        fn smaller_cannot_hold_larger() {
+            let larger = Rectangle {
+                width: 8,
+                height: 7,
+            };
+            let smaller = Rectangle {
+                width: 5,
+                height: 1,
+            };
+
+            assert!(!smaller.can_hold(&larger));
+        }
     }
 }
 
 
 
+
 /*
 Because the correct result of the can_hold function in this case is false, we need to negate that result before we pass it to the assert! macro. As a result, our test will pass if can_hold returns false:
 
@@ -291,19 +305,26 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; fini
 
 
 
+
 /*
 Two tests that pass! Now let’s see what happens to our test results when we introduce a bug in our code. We’ll change the implementation of the can_hold method by replacing the greater-than sign with a less-than sign when it compares the widths:
 */
 
 // --snip--
-impl Rectangle {
-    fn can_hold(&self, other: &Rectangle) -> bool {
-        self.width < other.width && self.height > other.height
-    }
-}
-
-
 
+/// ```
+/// # struct Rectangle {
+/// #     width: u32,
+/// #     height: u32,
+/// # }
+/// #
+/// impl Rectangle {
+///     fn can_hold(&self, other: &Rectangle) -> bool {
+///         self.width < other.width && self.height > other.height
+///     }
+/// }
+/// ```
+pub mod example_07 {}
 
 /*
 $ cargo test
@@ -334,9 +355,9 @@ error: test failed, to rerun pass '--lib'
 
 
 
+// Testing Equality with the assert_eq! and assert_ne! Macros
 
 
-// Testing Equality with the assert_eq! and assert_ne! Macros
 
 
 /*
@@ -361,6 +382,7 @@ mod tests {
 
 
 
+
 /*
 Let’s check that it passes!
 
@@ -385,65 +407,72 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; fini
 
 
 
+
 /*
 Let’s introduce a bug into our code to see what assert_eq! looks like when it fails. Change the implementation of the add_two function to instead add 3:
 */
-pub fn add_two(a: i32) -> i32 {
-    a + 3
-}
 
+pub mod example_08 {
+    pub fn add_two(a: i32) -> i32 {
+        a + 3
+    }
 
+    /*
+    $ cargo test
+       Compiling adder v0.1.0 (file:///projects/adder)
+        Finished test [unoptimized + debuginfo] target(s) in 0.61s
+         Running unittests src/lib.rs (target/debug/deps/adder-92948b65e88960b4)
 
-/*
-$ cargo test
-   Compiling adder v0.1.0 (file:///projects/adder)
-    Finished test [unoptimized + debuginfo] target(s) in 0.61s
-     Running unittests src/lib.rs (target/debug/deps/adder-92948b65e88960b4)
+    running 1 test
+    test tests::it_adds_two ... FAILED
 
-running 1 test
-test tests::it_adds_two ... FAILED
+    failures:
 
-failures:
+    ---- tests::it_adds_two stdout ----
+    thread 'main' panicked at 'assertion failed: `(left == right)`
+      left: `4`,
+     right: `5`', src/lib.rs:11:9
+    note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
 
----- tests::it_adds_two stdout ----
-thread 'main' panicked at 'assertion failed: `(left == right)`
-  left: `4`,
- right: `5`', src/lib.rs:11:9
-note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
 
+    failures:
+        tests::it_adds_two
 
-failures:
-    tests::it_adds_two
+    test result: FAILED. 0 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
 
-test result: FAILED. 0 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+    error: test failed, to rerun pass '--lib'
+
+    */
+}
 
-error: test failed, to rerun pass '--lib'
 
-*/
 
 
+// Adding Custom Failure Messages
 
 
 
-// Adding Custom Failure Messages
 
 /*
 For example, let’s say we have a function that greets people by name and we want to test that the name we pass into the function appears in the output:
 
 Filename: src/lib.rs
 */
-pub fn greeting(name: &str) -> String {
-    format!("Hello {}!", name)
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub mod example_09 {
+    pub fn greeting(name: &str) -> String {
+        format!("Hello {}!", name)
+    }
 
-    #[test]
-    fn greeting_contains_name() {
-        let result = greeting("Carol");
-        assert!(result.contains("Carol"));
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn greeting_contains_name() {
+            let result = greeting("Carol");
+            assert!(result.contains("Carol"));
+        }
     }
 }
 
@@ -453,34 +482,37 @@ mod tests {
 /*
 Now let’s introduce a bug into this code by changing greeting to exclude name to see what the default test failure looks like:
 */
-pub fn greeting(name: &str) -> String {
-    String::from("Hello!")
-}
 
-/*
-$ cargo test
-   Compiling greeter v0.1.0 (file:///projects/greeter)
-    Finished test [unoptimized + debuginfo] target(s) in 0.91s
-     Running unittests src/lib.rs (target/debug/deps/greeter-170b942eb5bf5e3a)
+pub mod example_10 {
+    pub fn greeting(name: &str) -> String {
+        String::from("Hello!")
+    }
 
-running 1 test
-test tests::greeting_contains_name ... FAILED
+    /*
+    $ cargo test
+       Compiling greeter v0.1.0 (file:///projects/greeter)
+        Finished test [unoptimized + debuginfo] target(s) in 0.91s
+         Running unittests src/lib.rs (target/debug/deps/greeter-170b942eb5bf5e3a)
 
-failures:
+    running 1 test
+    test tests::greeting_contains_name ... FAILED
 
----- tests::greeting_contains_name stdout ----
-thread 'main' panicked at 'assertion failed: result.contains(\"Carol\")', src/lib.rs:12:9
-note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+    failures:
 
+    ---- tests::greeting_contains_name stdout ----
+    thread 'main' panicked at 'assertion failed: result.contains(\"Carol\")', src/lib.rs:12:9
+    note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
 
-failures:
-    tests::greeting_contains_name
 
-test result: FAILED. 0 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+    failures:
+        tests::greeting_contains_name
 
-error: test failed, to rerun pass '--lib'
+    test result: FAILED. 0 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
 
-*/
+    error: test failed, to rerun pass '--lib'
+
+    */
+}
 
 
 
@@ -488,29 +520,32 @@ error: test failed, to rerun pass '--lib'
 /*
 This result just indicates that the assertion failed and which line the assertion is on. A more useful failure message would print the value from the greeting function. Let’s add a custom failure message composed of a format string with a placeholder filled in with the actual value we got from the greeting function:
 */
-pub fn greeting(name: &str) -> String {
-    String::from("Hello!")
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub mod example_11 {
+    pub fn greeting(name: &str) -> String {
+        String::from("Hello!")
+    }
 
-    #[test]
-    fn greeting_contains_name() {
-        let result = greeting("Carol");
-        assert!(
-            result.contains("Carol"),
-            "Greeting did not contain name, value was `{}`",
-            result
-        );
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        #[ignore = "fails on purpose, as the text after it shows"]
+        fn greeting_contains_name() {
+            let result = greeting("Carol");
+            assert!(
+                result.contains("Carol"),
+                "Greeting did not contain name, value was `{}`",
+                result
+            );
+        }
     }
 }
 
 
 
 
-
 /*
 Now when we run the test, we’ll get a more informative error message:
 
@@ -541,36 +576,41 @@ error: test failed, to rerun pass '--lib'
 
 
 
-
 // Checking for Panics with should_panic
 
+
+
+
 /*
 Listing 11-8 shows a test that checks that the error conditions of Guess::new happen when we expect them to.
 
 Filename: src/lib.rs
 */
-pub struct Guess {
-    value: i32,
-}
 
-impl Guess {
-    pub fn new(value: i32) -> Guess {
-        if value < 1 || value > 100 {
-            panic!("Guess value must be between 1 and 100, got {}.", value);
-        }
+pub mod listing_11_08 {
+    pub struct Guess {
+        value: i32,
+    }
 
-        Guess { value }
+    impl Guess {
+        pub fn new(value: i32) -> Guess {
+            if value < 1 || value > 100 {
+                panic!("Guess value must be between 1 and 100, got {}.", value);
+            }
+
+            Guess { value }
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[cfg(test)]
+    mod tests {
+        use super::*;
 
-    #[test]
-    #[should_panic]
-    fn greater_than_100() {
-        Guess::new(200);
+        #[test]
+        #[should_panic]
+        fn greater_than_100() {
+            Guess::new(200);
+        }
     }
 }
 
@@ -605,15 +645,23 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; fini
 Looks good! Now let’s introduce a bug in our code by removing the condition that the new function will panic if the value is greater than 100:
 */
 // --snip--
-impl Guess {
-    pub fn new(value: i32) -> Guess {
-        if value < 1 {
-            panic!("Guess value must be between 1 and 100, got {}.", value);
-        }
 
-        Guess { value }
-    }
-}
+/// ```
+/// # pub struct Guess {
+/// #     value: i32,
+/// # }
+/// #
+/// impl Guess {
+///     pub fn new(value: i32) -> Guess {
+///         if value < 1 {
+///             panic!("Guess value must be between 1 and 100, got {}.", value);
+///         }
+///
+///         Guess { value }
+///     }
+/// }
+/// ```
+pub mod example_13 {}
 
 
 
@@ -644,6 +692,8 @@ error: test failed, to rerun pass '--lib'
 */
 
 
+
+
 /*
 The test harness will make sure that the failure message contains the provided text. For example, consider the modified code for Guess in Listing 11-9 where the new function panics with different messages depending on whether the value is too small or too large.
 
@@ -651,36 +701,41 @@ Filename: src/lib.rs
 */
 // --snip--
 
-impl Guess {
-    pub fn new(value: i32) -> Guess {
-        if value < 1 {
-            panic!(
-                "Guess value must be greater than or equal to 1, got {}.",
-                value
-            );
-        } else if value > 100 {
-            panic!(
-                "Guess value must be less than or equal to 100, got {}.",
-                value
-            );
-        }
-
-        Guess { value }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    #[should_panic(expected = "less than or equal to 100")]
-    fn greater_than_100() {
-        Guess::new(200);
-    }
-}
-
-
+/// ```
+/// # pub struct Guess {
+/// #     value: i32,
+/// # }
+/// #
+/// impl Guess {
+///     pub fn new(value: i32) -> Guess {
+///         if value < 1 {
+///             panic!(
+///                 "Guess value must be greater than or equal to 1, got {}.",
+///                 value
+///             );
+///         } else if value > 100 {
+///             panic!(
+///                 "Guess value must be less than or equal to 100, got {}.",
+///                 value
+///             );
+///         }
+///
+///         Guess { value }
+///     }
+/// }
+///
+/// #[cfg(test)]
+/// mod tests {
+///     use super::*;
+///
+///     #[test]
+///     #[should_panic(expected = "less than or equal to 100")]
+///     fn greater_than_100() {
+///         Guess::new(200);
+///     }
+/// }
+/// ```
+pub mod listing_11_09 {}
 
 
 
@@ -688,45 +743,50 @@ mod tests {
 /*
 To see what happens when a should_panic test with an expected message fails, let’s again introduce a bug into our code by swapping the bodies of the if value < 1 and the else if value > 100 blocks:
 */
-pub struct Guess {
-    value: i32,
-}
 
-impl Guess {
-    pub fn new(value: i32) -> Guess {
-    
-    // snip
-        if value < 1 {
-            panic!(
-                "Guess value must be less than or equal to 100, got {}.",
-                value
-            );
-        } else if value > 100 {
-            panic!(
-                "Guess value must be greater than or equal to 1, got {}.",
-                value
-            );
-        }
-        
-    // snip
+pub mod example_15 {
+    pub struct Guess {
+        value: i32,
+    }
 
-        Guess { value }
+    impl Guess {
+        pub fn new(value: i32) -> Guess {
+
+        // snip
+            if value < 1 {
+                panic!(
+                    "Guess value must be less than or equal to 100, got {}.",
+                    value
+                );
+            } else if value > 100 {
+                panic!(
+                    "Guess value must be greater than or equal to 1, got {}.",
+                    value
+                );
+            }
+
+        // snip
+
+            Guess { value }
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[cfg(test)]
+    mod tests {
+        use super::*;
 
-    #[test]
-    #[should_panic(expected = "less than or equal to 100")]
-    fn greater_than_100() {
-        Guess::new(200);
+        #[test]
+        #[ignore = "fails on purpose, as the text after it shows"]
+        #[should_panic(expected = "less than or equal to 100")]
+        fn greater_than_100() {
+            Guess::new(200);
+        }
     }
 }
 
 
 
+
 /*
 This time when we run the should_panic test, it will fail:
 
@@ -758,27 +818,26 @@ error: test failed, to rerun pass '--lib'
 
 
 
+
 // Using Result<T, E> in Tests
 
+
+
+
 /*
 Our tests so far all panic when they fail. We can also write tests that use Result<T, E>! Here’s the test from Listing 11-1, rewritten to use Result<T, E> and return an Err instead of panicking:
 */
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn it_works() -> Result<(), String> {
-        if 2 + 2 == 4 {
-            Ok(())
-        } else {
-            Err(String::from("two plus two does not equal four"))
+
+pub mod example_16 {
+    #[cfg(test)]
+    mod tests {
+        #[test]
+        fn it_works() -> Result<(), String> {
+            if 2 + 2 == 4 {
+                Ok(())
+            } else {
+                Err(String::from("two plus two does not equal four"))
+            }
         }
     }
 }
-
-
-
-
-
-
-
-
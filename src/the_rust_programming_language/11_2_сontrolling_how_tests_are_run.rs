@@ -4,7 +4,6 @@
 
 
 
-
 /*
 If you don’t want to run the tests in parallel or if you want more fine-grained control over the number of threads used, you can send the --test-threads flag and the number of threads you want to use to the test binary. Take a look at the following example:
 
@@ -15,33 +14,39 @@ $ cargo test -- --test-threads=1
 
 
 
-
 // Showing Function Output
 
+
+
+
 /*
 As an example, Listing 11-10 has a silly function that prints the value of its parameter and returns 10, as well as a test that passes and a test that fails.
 
 Filename: src/lib.rs
 */
-fn prints_and_returns_10(a: i32) -> i32 {
-    println!("I got the value {}", a);
-    10
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    #[test]
-    fn this_test_will_pass() {
-        let value = prints_and_returns_10(4);
-        assert_eq!(10, value);
+pub mod listing_11_10 {
+    fn prints_and_returns_10(a: i32) -> i32 {
+        println!("I got the value {}", a);
+        10
     }
 
-    #[test]
-    fn this_test_will_fail() {
-        let value = prints_and_returns_10(8);
-        assert_eq!(5, value);
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn this_test_will_pass() {
+            let value = prints_and_returns_10(4);
+            assert_eq!(10, value);
+        }
+
+        #[test]
+        #[ignore = "fails on purpose, as the text after it shows"]
+        fn this_test_will_fail() {
+            let value = prints_and_returns_10(8);
+            assert_eq!(5, value);
+        }
     }
 }
 
@@ -81,6 +86,7 @@ error: test failed, to rerun pass '--lib'
 
 
 
+
 /*
 If we want to see printed values for passing tests as well, we can tell Rust to also show the output of successful tests with --show-output.
 
@@ -134,42 +140,46 @@ error: test failed, to rerun pass '--lib'
 
 
 
-
 // Running a Subset of Tests by Name
 
+
+
+
 /*
 To demonstrate how to run a subset of tests, we’ll first create three tests for our add_two function, as shown in Listing 11-11, and choose which ones to run.
 
 Filename: src/lib.rs
 */
-pub fn add_two(a: i32) -> i32 {
-    a + 2
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn add_two_and_two() {
-        assert_eq!(4, add_two(2));
+pub mod listing_11_11 {
+    pub fn add_two(a: i32) -> i32 {
+        a + 2
     }
 
-    #[test]
-    fn add_three_and_two() {
-        assert_eq!(5, add_two(3));
-    }
+    #[cfg(test)]
+    mod tests {
+        use super::*;
 
-    #[test]
-    fn one_hundred() {
-        assert_eq!(102, add_two(100));
+        #[test]
+        fn add_two_and_two() {
+            assert_eq!(4, add_two(2));
+        }
+
+        #[test]
+        fn add_three_and_two() {
+            assert_eq!(5, add_two(3));
+        }
+
+        #[test]
+        fn one_hundred() {
+            assert_eq!(102, add_two(100));
+        }
     }
 }
 
 
 
 
-
 /*
 If we run the tests without passing any arguments, as we saw earlier, all the tests will run in parallel:
 
@@ -197,9 +207,10 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; fini
 
 
 
+// Running Single Tests
+
 
 
-// Running Single Tests
 
 /*
 We can pass the name of any test function to cargo test to run only that test:
@@ -223,6 +234,7 @@ test result: ok. 1 passed; 0 failed; 0 ignored; 0 measured; 2 filtered out; fini
 
 
 
+
 /*
 We can specify part of a test name, and any test whose name matches that value will be run. For example, because two of our tests’ names contain add, we can run those two by running cargo test add:
 
@@ -243,15 +255,18 @@ test result: ok. 2 passed; 0 failed; 0 ignored; 0 measured; 1 filtered out; fini
 
 
 // Ignoring Some Tests Unless Specifically Requested
-#[test]
-fn it_works() {
-    assert_eq!(2 + 2, 4);
-}
 
-#[test]
-#[ignore]
-fn expensive_test() {
-    // code that takes an hour to run
+pub mod example_03 {
+    #[test]
+    fn it_works() {
+        assert_eq!(2 + 2, 4);
+    }
+
+    #[test]
+    #[ignore]
+    fn expensive_test() {
+        // code that takes an hour to run
+    }
 }
 
 
@@ -281,6 +296,7 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; fini
 
 
 
+
 /*
 The expensive_test function is listed as ignored. If we want to run only the ignored tests, we can use cargo test -- --ignored:
 
@@ -301,7 +317,3 @@ running 0 tests
 test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
 
 */
-
-
-
-
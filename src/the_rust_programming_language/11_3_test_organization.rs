@@ -4,22 +4,27 @@
 
 
 
-
 // Unit Tests
 
 // The Tests Module and #[cfg(test)]
 
+
+
+
 /*
 Recall that when we generated the new adder project in the first section of this chapter, Cargo generated this code for us:
 
 Filename: src/lib.rs
 */
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn it_works() {
-        let result = 2 + 2;
-        assert_eq!(result, 4);
+
+pub mod example_01 {
+    #[cfg(test)]
+    mod tests {
+        #[test]
+        fn it_works() {
+            let result = 2 + 2;
+            assert_eq!(result, 4);
+        }
     }
 }
 
@@ -28,38 +33,44 @@ mod tests {
 
 // Testing Private Functions
 
+
+
+
 /*
 There’s debate within the testing community about whether or not private functions should be tested directly, and other languages make it difficult or impossible to test private functions. Regardless of which testing ideology you adhere to, Rust’s privacy rules do allow you to test private functions. Consider the code in Listing 11-12 with the private function internal_adder.
 
 Filename: src/lib.rs
 */
-pub fn add_two(a: i32) -> i32 {
-    internal_adder(a, 2)
-}
 
-fn internal_adder(a: i32, b: i32) -> i32 {
-    a + b
-}
+pub mod listing_11_12 {
+    pub fn add_two(a: i32) -> i32 {
+        internal_adder(a, 2)
+    }
+
+    fn internal_adder(a: i32, b: i32) -> i32 {
+        a + b
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[cfg(test)]
+    mod tests {
+        use super::*;
 
-    #[test]
-    fn internal() {
-        assert_eq!(4, internal_adder(2, 2));
+        #[test]
+        fn internal() {
+            assert_eq!(4, internal_adder(2, 2));
+        }
     }
 }
 
 
 
 
+// Integration Tests
 
+// The tests Directory
 
 
-// Integration Tests
 
-// The tests Directory
 
 /*
 Let’s create an integration test. With the code in Listing 11-12 still in the src/lib.rs file, make a tests directory, and create a new file named tests/integration_test.rs. Your directory structure should look like this:
@@ -75,14 +86,26 @@ adder
 */
 
 
-// Listing 11-13: An integration test of a function in the adder crate
-use adder;
 
-#[test]
-fn it_adds_two() {
-    assert_eq!(4, adder::add_two(2));
-}
 
+// Listing 11-13: An integration test of a function in the adder crate
+
+/// ```
+/// # mod adder {
+/// #     pub use rust_book_examples::trpl::ch11_testing::test_organization::listing_11_12::add_two;
+/// # }
+/// #
+/// # mod integration_test { use super::*;
+/// use adder;
+///
+/// #[test]
+/// fn it_adds_two() {
+///     assert_eq!(4, adder::add_two(2));
+/// }
+/// # }
+/// # fn main() {}
+/// ```
+pub mod listing_11_13 {}
 
 
 
@@ -117,6 +140,7 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; fini
 
 
 
+
 /*
 We can still run a particular integration test function by specifying the test function’s name as an argument to cargo test. To run all the tests in a particular integration test file, use the --test argument of cargo test followed by the name of the file:
 
@@ -138,13 +162,19 @@ test result: ok. 1 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; fini
 
 // Submodules in Integration Tests
 
+
+
+
 /*
 The different behavior of tests directory files is most noticeable when you have a set of helper functions to use in multiple integration test files and you try to follow the steps in the “Separating Modules into Different Files” section of Chapter 7 to extract them into a common module. For example, if we create tests/common.rs and place a function named setup in it, we can add some code to setup that we want to call from multiple test functions in multiple test files:
 
 Filename: tests/common.rs
 */
-pub fn setup() {
-    // setup code specific to your library's tests would go here
+
+pub mod example_04 {
+    pub fn setup() {
+        // setup code specific to your library's tests would go here
+    }
 }
 
 
@@ -186,6 +216,7 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; fini
 
 
 
+
 /*
 To avoid having common appear in the test output, instead of creating tests/common.rs, we’ll create tests/common/mod.rs. The project directory now looks like this:
 
@@ -203,25 +234,21 @@ To avoid having common appear in the test output, instead of creating tests/comm
 
 
 
-
 /*
 After we’ve created tests/common/mod.rs, we can use it from any of the integration test files as a module. Here’s an example of calling the setup function from the it_adds_two test in tests/integration_test.rs:
 
 Filename: tests/integration_test.rs
 */
-use adder;
-
-mod common;
-
-#[test]
-fn it_adds_two() {
-    common::setup();
-    assert_eq!(4, adder::add_two(2));
-}
-
-
-
-
-
-
 
+/// ```ignore
+/// use adder;
+///
+/// mod common;
+///
+/// #[test]
+/// fn it_adds_two() {
+///     common::setup();
+///     assert_eq!(4, adder::add_two(2));
+/// }
+/// ```
+pub mod example_05 {}
@@ -11,6 +11,8 @@ $ cd minigrep
 */
 
 
+
+
 /*
 $ cargo run -- searchstring example-filename.txt
 */
@@ -18,20 +20,24 @@ $ cargo run -- searchstring example-filename.txt
 
 
 
+// Reading the Argument Values
+
 
 
-// Reading the Argument Values
 
 /*
 The code in Listing 12-1 allows your minigrep program to read any command line arguments passed to it and then collect the values into a vector.
 
 Filename: src/main.rs
 */
-use std::env;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    dbg!(args);
+pub mod listing_12_01 {
+    use std::env;
+
+    pub fn main() {
+        let args: Vec<String> = env::args().collect();
+        dbg!(args);
+    }
 }
 
 
@@ -64,25 +70,29 @@ $ cargo run -- needle haystack
 
 
 
+// Saving the Argument Values in Variables
+
 
 
-// Saving the Argument Values in Variables
 
 /*
 The program is currently able to access the values specified as command line arguments. Now we need to save the values of the two arguments in variables so we can use the values throughout the rest of the program. We do that in Listing 12-2.
 
 Filename: src/main.rs
 */
-use std::env;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub mod listing_12_02 {
+    use std::env;
+
+    pub fn main() {
+        let args: Vec<String> = env::args().collect();
 
-    let query = &args[1];
-    let file_path = &args[2];
+        let query = &args[1];
+        let file_path = &args[2];
 
-    println!("Searching for {}", query);
-    println!("In file {}", file_path);
+        println!("Searching for {}", query);
+        println!("In file {}", file_path);
+    }
 }
 
 
@@ -99,8 +109,3 @@ Searching for test
 In file sample.txt
 
 */
-
-
-
-
-
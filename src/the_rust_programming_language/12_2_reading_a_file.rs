@@ -4,8 +4,6 @@
 
 
 
-
-
 /*
 Filename: poem.txt
 
@@ -29,27 +27,31 @@ With the text in place, edit src/main.rs and add code to read the file, as shown
 
 Filename: src/main.rs
 */
-use std::env;
-use std::fs;
 
-fn main() {
-    // --snip--
-    let args: Vec<String> = env::args().collect();
+pub mod listing_12_04 {
+    use std::env;
+    use std::fs;
 
-    let query = &args[1];
-    let file_path = &args[2];
+    pub fn main() {
+        // --snip--
+        let args: Vec<String> = env::args().collect();
 
-    println!("Searching for {}", query);
-    println!("In file {}", file_path);
+        let query = &args[1];
+        let file_path = &args[2];
 
-    let contents = fs::read_to_string(file_path)
-        .expect("Should have been able to read the file");
+        println!("Searching for {}", query);
+        println!("In file {}", file_path);
 
-    println!("With text:\n{contents}");
+        let contents = fs::read_to_string(file_path)
+            .expect("Should have been able to read the file");
+
+        println!("With text:\n{contents}");
+    }
 }
 
 
 
+
 /*
 Let’s run this code with any string as the first command line argument (because we haven’t implemented the searching part yet) and the poem.txt file as the second argument:
 
@@ -71,6 +73,3 @@ To tell your name the livelong day
 To an admiring bog!
 
 */
-
-
-
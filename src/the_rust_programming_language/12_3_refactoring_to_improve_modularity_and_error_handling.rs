@@ -6,136 +6,148 @@
 
 // Extracting the Argument Parser
 
+
+
+
 /*
 We’ll extract the functionality for parsing arguments into a function that main will call to prepare for moving the command line parsing logic to src/lib.rs. Listing 12-5 shows the new start of main that calls a new function parse_config, which we’ll define in src/main.rs for the moment.
 
 Filename: src/main.rs
 */
-use std::env;
-use std::fs;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub mod listing_12_05 {
+    use std::env;
+    use std::fs;
 
-    let (query, file_path) = parse_config(&args);
+    pub fn main() {
+        let args: Vec<String> = env::args().collect();
 
-    // --snip--
+        let (query, file_path) = parse_config(&args);
 
-    println!("Searching for {}", query);
-    println!("In file {}", file_path);
+        // --snip--
 
-    let contents = fs::read_to_string(file_path)
-        .expect("Should have been able to read the file");
+        println!("Searching for {}", query);
+        println!("In file {}", file_path);
 
-    println!("With text:\n{contents}");
-}
+        let contents = fs::read_to_string(file_path)
+            .expect("Should have been able to read the file");
 
-fn parse_config(args: &[String]) -> (&str, &str) {
-    let query = &args[1];
-    let file_path = &args[2];
+        println!("With text:\n{contents}");
+    }
 
-    (query, file_path)
+    fn parse_config(args: &[String]) -> (&str, &str) {
+        let query = &args[1];
+        let file_path = &args[2];
+
+        (query, file_path)
+    }
 }
 
 
 
 
+// Grouping Configuration Values
+
 
 
-// Grouping Configuration Values
 
 /*
 Listing 12-6 shows the improvements to the parse_config function.
 
 Filename: src/main.rs
 */
-use std::env;
-use std::fs;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub mod listing_12_06 {
+    use std::env;
+    use std::fs;
 
-    let config = parse_config(&args);
+    pub fn main() {
+        let args: Vec<String> = env::args().collect();
 
-    println!("Searching for {}", config.query);
-    println!("In file {}", config.file_path);
+        let config = parse_config(&args);
 
-    let contents = fs::read_to_string(config.file_path)
-        .expect("Should have been able to read the file");
+        println!("Searching for {}", config.query);
+        println!("In file {}", config.file_path);
 
-    // --snip--
+        let contents = fs::read_to_string(config.file_path)
+            .expect("Should have been able to read the file");
 
-    println!("With text:\n{contents}");
-}
+        // --snip--
 
-struct Config {
-    query: String,
-    file_path: String,
-}
+        println!("With text:\n{contents}");
+    }
 
-fn parse_config(args: &[String]) -> Config {
-    let query = args[1].clone();
-    let file_path = args[2].clone();
+    struct Config {
+        query: String,
+        file_path: String,
+    }
 
-    Config { query, file_path }
-}
+    fn parse_config(args: &[String]) -> Config {
+        let query = args[1].clone();
+        let file_path = args[2].clone();
 
+        Config { query, file_path }
+    }
+}
 
 
 
 
+// Creating a Constructor for Config
 
 
 
-// Creating a Constructor for Config
 
 /*
 So now that the purpose of the parse_config function is to create a Config instance, we can change parse_config from a plain function to a function named new that is associated with the Config struct. Making this change will make the code more idiomatic. We can create instances of types in the standard library, such as String, by calling String::new. Similarly, by changing parse_config into a new function associated with Config, we’ll be able to create instances of Config by calling Config::new. Listing 12-7 shows the changes we need to make.
 
 Filename: src/main.rs
 */
-use std::env;
-use std::fs;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub mod listing_12_07 {
+    use std::env;
+    use std::fs;
 
-    let config = Config::new(&args);
+    pub fn main() {
+        let args: Vec<String> = env::args().collect();
 
-    println!("Searching for {}", config.query);
-    println!("In file {}", config.file_path);
+        let config = Config::new(&args);
 
-    let contents = fs::read_to_string(config.file_path)
-        .expect("Should have been able to read the file");
+        println!("Searching for {}", config.query);
+        println!("In file {}", config.file_path);
 
-    println!("With text:\n{contents}");
+        let contents = fs::read_to_string(config.file_path)
+            .expect("Should have been able to read the file");
 
-    // --snip--
-}
+        println!("With text:\n{contents}");
 
-// --snip--
+        // --snip--
+    }
 
-struct Config {
-    query: String,
-    file_path: String,
-}
+    // --snip--
 
-impl Config {
-    fn new(args: &[String]) -> Config {
-        let query = args[1].clone();
-        let file_path = args[2].clone();
+    struct Config {
+        query: String,
+        file_path: String,
+    }
 
-        Config { query, file_path }
+    impl Config {
+        fn new(args: &[String]) -> Config {
+            let query = args[1].clone();
+            let file_path = args[2].clone();
+
+            Config { query, file_path }
+        }
     }
 }
 
 
 
 
+// Fixing the Error Handling
 
 
 
-// Fixing the Error Handling
 
 /*
 $ cargo run
@@ -150,21 +162,38 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
 
 
 
+// Improving the Error Message
+
 
 
-// Improving the Error Message
 
 /*
 In Listing 12-8, we add a check in the new function that will verify that the slice is long enough before accessing index 1 and 2. If the slice isn’t long enough, the program panics and displays a better error message.
 
 Filename: src/main.rs
 */
-    // --snip--
-    fn new(args: &[String]) -> Config {
-        if args.len() < 3 {
-            panic!("not enough arguments");
-        }
-        // --snip--
+
+/// ```
+/// # struct Config {
+/// #     query: String,
+/// #     file_path: String,
+/// # }
+/// #
+/// # impl Config {
+///     // --snip--
+///     fn new(args: &[String]) -> Config {
+///         if args.len() < 3 {
+///             panic!("not enough arguments");
+///         }
+///         // --snip--
+/// #         let query = args[1].clone();
+/// #         let file_path = args[2].clone();
+/// #
+/// #         Config { query, file_path }
+/// #     }
+/// # }
+/// ```
+pub mod listing_12_08 {}
 
 
 
@@ -184,51 +213,85 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
 
 
 
+// Returning a Result Instead of Calling panic!
 
 
 
-// Returning a Result Instead of Calling panic!
 
 /*
 Listing 12-9 shows the changes we need to make to the return value of the function we’re now calling Config::build and the body of the function needed to return a Result. Note that this won’t compile until we update main as well, which we’ll do in the next listing.
 
 Filename: src/main.rs
 */
-impl Config {
-    fn build(args: &[String]) -> Result<Config, &'static str> {
-        if args.len() < 3 {
-            return Err("not enough arguments");
-        }
 
-        let query = args[1].clone();
-        let file_path = args[2].clone();
+/// ```
+/// # struct Config {
+/// #     query: String,
+/// #     file_path: String,
+/// # }
+/// #
+/// impl Config {
+///     fn build(args: &[String]) -> Result<Config, &'static str> {
+///         if args.len() < 3 {
+///             return Err("not enough arguments");
+///         }
+///
+///         let query = args[1].clone();
+///         let file_path = args[2].clone();
+///
+///         Ok(Config { query, file_path })
+///     }
+/// }
+/// ```
+pub mod listing_12_09 {}
 
-        Ok(Config { query, file_path })
-    }
-}
 
 
 
+// Calling Config::build and Handling Errors
+
 
 
-// Calling Config::build and Handling Errors
 
 /*
 To handle the error case and print a user-friendly message, we need to update main to handle the Result being returned by Config::build, as shown in Listing 12-10. We’ll also take the responsibility of exiting the command line tool with a nonzero error code away from panic! and instead implement it by hand. A nonzero exit status is a convention to signal to the process that called our program that the program exited with an error state.
 
 Filename: src/main.rs
 */
-use std::process;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    let config = Config::build(&args).unwrap_or_else(|err| {
-        println!("Problem parsing arguments: {err}");
-        process::exit(1);
-    });
-
-    // --snip--
+/// ```no_run
+/// # use std::env;
+/// # struct Config {
+/// #     query: String,
+/// #     file_path: String,
+/// # }
+/// # impl Config {
+/// #     fn build(args: &[String]) -> Result<Config, &'static str> {
+/// #         if args.len() < 3 {
+/// #             return Err("not enough arguments");
+/// #         }
+/// #
+/// #         let query = args[1].clone();
+/// #         let file_path = args[2].clone();
+/// #
+/// #         Ok(Config { query, file_path })
+/// #     }
+/// # }
+/// #
+/// use std::process;
+///
+/// fn main() {
+///     let args: Vec<String> = env::args().collect();
+///
+///     let config = Config::build(&args).unwrap_or_else(|err| {
+///         println!("Problem parsing arguments: {err}");
+///         process::exit(1);
+///     });
+///
+///     // --snip--
+/// # }
+/// ```
+pub mod listing_12_10 {}
 
 
 
@@ -247,54 +310,98 @@ Problem parsing arguments: not enough arguments
 
 
 
-
 // Extracting Logic from main
 
 
+
+
 /*
 Listing 12-11 shows the extracted run function. For now, we’re just making the small, incremental improvement of extracting the function. We’re still defining the function in src/main.rs.
 
 Filename: src/main.rs
 */
-fn main() {
-    // --snip--
 
-    println!("Searching for {}", config.query);
-    println!("In file {}", config.file_path);
+/// ```no_run
+/// # use std::{env, fs, process};
+/// # struct Config {
+/// #     query: String,
+/// #     file_path: String,
+/// # }
+/// # impl Config {
+/// #     fn build(args: &[String]) -> Result<Config, &'static str> {
+/// #         if args.len() < 3 {
+/// #             return Err("not enough arguments");
+/// #         }
+/// #
+/// #         let query = args[1].clone();
+/// #         let file_path = args[2].clone();
+/// #
+/// #         Ok(Config { query, file_path })
+/// #     }
+/// # }
+/// #
+/// fn main() {
+///     // --snip--
+/// #     let args: Vec<String> = env::args().collect();
+/// #
+/// #     let config = Config::build(&args).unwrap_or_else(|err| {
+/// #         println!("Problem parsing arguments: {err}");
+/// #         process::exit(1);
+/// #     });
+///
+///     println!("Searching for {}", config.query);
+///     println!("In file {}", config.file_path);
+///
+///     run(config);
+/// }
+///
+/// fn run(config: Config) {
+///     let contents = fs::read_to_string(config.file_path)
+///         .expect("Should have been able to read the file");
+///
+///     println!("With text:\n{contents}");
+/// }
+/// ```
+pub mod listing_12_11 {}
 
-    run(config);
-}
+// --snip--
 
-fn run(config: Config) {
-    let contents = fs::read_to_string(config.file_path)
-        .expect("Should have been able to read the file");
 
-    println!("With text:\n{contents}");
-}
 
-// --snip--
 
+// Returning Errors from the run Function
 
 
 
-// Returning Errors from the run Function
 
 /*
 With the remaining program logic separated into the run function, we can improve the error handling, as we did with Config::build in Listing 12-9. Instead of allowing the program to panic by calling expect, the run function will return a Result<T, E> when something goes wrong. This will let us further consolidate the logic around handling errors into main in a user-friendly way. Listing 12-12 shows the changes we need to make to the signature and body of run.
 
 Filename: src/main.rs
 */
-use std::error::Error;
 
-// --snip--
+/// ```
+/// # use std::fs;
+/// #
+/// use std::error::Error;
+///
+/// // --snip--
+/// # struct Config {
+/// #     query: String,
+/// #     file_path: String,
+/// # }
+///
+/// fn run(config: Config) -> Result<(), Box<dyn Error>> {
+///     let contents = fs::read_to_string(config.file_path)?;
+///
+///     println!("With text:\n{contents}");
+///
+///     Ok(())
+/// }
+/// ```
+pub mod listing_12_12 {}
 
-fn run(config: Config) -> Result<(), Box<dyn Error>> {
-    let contents = fs::read_to_string(config.file_path)?;
 
-    println!("With text:\n{contents}");
-
-    Ok(())
-}
 
 
 /*
@@ -335,54 +442,97 @@ To an admiring bog!
 
 // Handling Errors Returned from run in main
 
+
+
+
 /*
 We’ll check for errors and handle them using a technique similar to one we used with Config::build in Listing 12-10, but with a slight difference:
 
 Filename: src/main.rs
 */
-fn main() {
-    // --snip--
 
-    println!("Searching for {}", config.query);
-    println!("In file {}", config.file_path);
+/// ```no_run
+/// # use std::error::Error;
+/// # use std::{env, fs, process};
+/// # struct Config {
+/// #     query: String,
+/// #     file_path: String,
+/// # }
+/// # impl Config {
+/// #     fn build(args: &[String]) -> Result<Config, &'static str> {
+/// #         if args.len() < 3 {
+/// #             return Err("not enough arguments");
+/// #         }
+/// #
+/// #         let query = args[1].clone();
+/// #         let file_path = args[2].clone();
+/// #
+/// #         Ok(Config { query, file_path })
+/// #     }
+/// # }
+/// # fn run(config: Config) -> Result<(), Box<dyn Error>> {
+/// #     let contents = fs::read_to_string(config.file_path)?;
+/// #
+/// #     println!("With text:\n{contents}");
+/// #
+/// #     Ok(())
+/// # }
+/// #
+/// fn main() {
+///     // --snip--
+/// #     let args: Vec<String> = env::args().collect();
+/// #
+/// #     let config = Config::build(&args).unwrap_or_else(|err| {
+/// #         println!("Problem parsing arguments: {err}");
+/// #         process::exit(1);
+/// #     });
+///
+///     println!("Searching for {}", config.query);
+///     println!("In file {}", config.file_path);
+///
+///     if let Err(e) = run(config) {
+///         println!("Application error: {e}");
+///
+///         process::exit(1);
+///     }
+/// }
+/// ```
+pub mod example_09 {}
 
-    if let Err(e) = run(config) {
-        println!("Application error: {e}");
 
-        process::exit(1);
-    }
-}
 
 
+// Splitting Code into a Library Crate
 
 
 
 
-// Splitting Code into a Library Crate
-
 /*
 The contents of src/lib.rs should have the signatures shown in Listing 12-13 (we’ve omitted the bodies of the functions for brevity). Note that this won’t compile until we modify src/main.rs in Listing 12-14.
 
 Filename: src/lib.rs
 */
-use std::error::Error;
-use std::fs;
-
-pub struct Config {
-    pub query: String,
-    pub file_path: String,
-}
-
-impl Config {
-    pub fn build(args: &[String]) -> Result<Config, &'static str> {
-        // --snip--
-    }
-}
-
-pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
-    // --snip--
-}
 
+/// ```compile_fail
+/// use std::error::Error;
+/// use std::fs;
+///
+/// pub struct Config {
+///     pub query: String,
+///     pub file_path: String,
+/// }
+///
+/// impl Config {
+///     pub fn build(args: &[String]) -> Result<Config, &'static str> {
+///         // --snip--
+///     }
+/// }
+///
+/// pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
+///     // --snip--
+/// }
+/// ```
+pub mod listing_12_13 {}
 
 
 
@@ -394,21 +544,57 @@ Now we need to bring the code we moved to src/lib.rs into the scope of the binar
 
 Filename: src/main.rs
 */
-use std::env;
-use std::process;
-
-use minigrep::Config;
-
-fn main() {
-    // --snip--
-    if let Err(e) = minigrep::run(config) {
-        // --snip--
-    }
-}
-
-
-
-
-
-
 
+/// ```no_run
+/// # mod minigrep {
+/// #     use std::error::Error;
+/// #     use std::fs;
+/// #
+/// #     pub struct Config {
+/// #         pub query: String,
+/// #         pub file_path: String,
+/// #     }
+/// #
+/// #     impl Config {
+/// #         pub fn build(args: &[String]) -> Result<Config, &'static str> {
+/// #             if args.len() < 3 {
+/// #                 return Err("not enough arguments");
+/// #             }
+/// #
+/// #             let query = args[1].clone();
+/// #             let file_path = args[2].clone();
+/// #
+/// #             Ok(Config { query, file_path })
+/// #         }
+/// #     }
+/// #
+/// #     pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
+/// #         let contents = fs::read_to_string(config.file_path)?;
+/// #
+/// #         println!("With text:\n{contents}");
+/// #
+/// #         Ok(())
+/// #     }
+/// #
+/// # }
+/// #
+/// use std::env;
+/// use std::process;
+///
+/// use minigrep::Config;
+///
+/// fn main() {
+///     // --snip--
+/// #     let args: Vec<String> = env::args().collect();
+/// #
+/// #     let config = Config::build(&args).unwrap_or_else(|err| {
+/// #         println!("Problem parsing arguments: {err}");
+/// #         process::exit(1);
+/// #     });
+/// #
+///     if let Err(e) = minigrep::run(config) {
+///         // --snip--
+///     }
+/// }
+/// ```
+pub mod listing_12_14 {}
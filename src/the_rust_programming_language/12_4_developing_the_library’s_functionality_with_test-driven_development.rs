@@ -7,31 +7,41 @@
 // Writing a Failing Test
 
 // Listing 12-15: Creating a failing test for the search function we wish we had
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn one_result() {
-        let query = "duct";
-        let contents = "\
-Rust:
-safe, fast, productive.
-Pick three.";
-
-        assert_eq!(vec!["safe, fast, productive."], search(query, contents));
-    }
-}
+
+/// ```
+/// #[cfg(test)]
+/// mod tests {
+///     use super::*;
+///
+///     #[test]
+///     fn one_result() {
+///         let query = "duct";
+///         let contents = "\
+/// Rust:
+/// safe, fast, productive.
+/// Pick three.";
+///
+///         assert_eq!(vec!["safe, fast, productive."], search(query, contents));
+///     }
+/// }
+/// ```
+pub mod listing_12_15 {}
+
+
 
 
 // Listing 12-16: Defining just enough of the search function so our test will compile
 // Filename: src/lib.rs
-pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
-    vec![]
+
+pub mod listing_12_16 {
+    pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+        vec![]
+    }
 }
 
 
 
+
 /*
 If we forget the lifetime annotations and try to compile this function, we’ll get this error:
 
@@ -56,6 +66,7 @@ error: could not compile `minigrep` due to previous error
 
 
 
+
 /*
 Other programming languages don’t require you to connect arguments to return values in the signature, but this practice will get easier over time. You might want to compare this example with the “Validating References with Lifetimes” section in Chapter 10.
 
@@ -94,60 +105,80 @@ error: test failed, to rerun pass '--lib'
 
 // Iterating Through Lines with the lines Method
 
+
+
+
 /*
 Rust has a helpful method to handle line-by-line iteration of strings, conveniently named lines, that works as shown in Listing 12-17. Note this won’t compile yet.
 
 Filename: src/lib.rs
 */
-pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
-    for line in contents.lines() {
-        // do something with line
-    }
-}
+
+/// ```compile_fail
+/// pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+///     for line in contents.lines() {
+///         // do something with line
+///     }
+/// }
+/// ```
+pub mod listing_12_17 {}
 
 
 
 
 // Searching Each Line for the Query
 
+
+
+
 /*
 Next, we’ll check whether the current line contains our query string. Fortunately, strings have a helpful method named contains that does this for us! Add a call to the contains method in the search function, as shown in Listing 12-18. Note this still won’t compile yet.
 
 Filename: src/lib.rs
 */
-pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
-    for line in contents.lines() {
-        if line.contains(query) {
-            // do something with line
-        }
-    }
-}
-
 
+/// ```compile_fail
+/// pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+///     for line in contents.lines() {
+///         if line.contains(query) {
+///             // do something with line
+///         }
+///     }
+/// }
+/// ```
+pub mod listing_12_18 {}
 
 
 
 
 // Storing Matching Lines
 
+
+
+
 /*
 To finish this function, we need a way to store the matching lines that we want to return. For that, we can make a mutable vector before the for loop and call the push method to store a line in the vector. After the for loop, we return the vector, as shown in Listing 12-19.
 
 Filename: src/lib.rs
 */
-pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
-    let mut results = Vec::new();
 
-    for line in contents.lines() {
-        if line.contains(query) {
-            results.push(line);
+pub mod listing_12_19 {
+    pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+        let mut results = Vec::new();
+
+        for line in contents.lines() {
+            if line.contains(query) {
+                results.push(line);
+            }
         }
-    }
 
-    results
+        results
+    }
 }
 
 
+
+
 /*
 Now the search function should return only the lines that contain query, and our test should pass. Let’s 
 run the test:
@@ -179,23 +210,39 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; fini
 
 
 
-
 // Using the search Function in the run Function
 
+
+
+
 /*
 Now that the search function is working and tested, we need to call search from our run function. We need to pass the config.query value and the contents that run reads from the file to the search function. Then run will print each line returned from search:
 
 Filename: src/lib.rs
 */
-pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
-    let contents = fs::read_to_string(config.file_path)?;
 
-    for line in search(&config.query, &contents) {
-        println!("{line}");
-    }
+/// ```
+/// # use rust_book_examples::trpl::ch12_minigrep::testing_the_librarys_functionality::listing_12_19::search;
+/// # use std::error::Error;
+/// # use std::fs;
+/// #
+/// # pub struct Config {
+/// #     pub query: String,
+/// #     pub file_path: String,
+/// # }
+/// #
+/// pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
+///     let contents = fs::read_to_string(config.file_path)?;
+///
+///     for line in search(&config.query, &contents) {
+///         println!("{line}");
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub mod example_06 {}
 
-    Ok(())
-}
 
 
 
@@ -213,6 +260,8 @@ How public, like a frog
 */
 
 
+
+
 /*
 Cool! Now let’s try a word that will match multiple lines, like “body”:
 
@@ -227,6 +276,8 @@ How dreary to be somebody!
 */
 
 
+
+
 /*
 And finally, let’s make sure that we don’t get any lines when we search for a word that isn’t anywhere in the poem, such as “monomorphization”:
 
@@ -236,5 +287,3 @@ $ cargo run -- monomorphization poem.txt
      Running `target/debug/minigrep monomorphization poem.txt`
 
 */
-
-
@@ -6,72 +6,85 @@
 
 // Writing a Failing Test for the Case-Insensitive search Function
 
+
+
+
 /*
 We first add a new search_case_insensitive function that will be called when the environment variable has a value. We’ll continue to follow the TDD process, so the first step is again to write a failing test. We’ll add a new test for the new search_case_insensitive function and rename our old test from one_result to case_sensitive to clarify the differences between the two tests, as shown in Listing 12-20.
 
 Filename: src/lib.rs
 */
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn case_sensitive() {
-        let query = "duct";
-        let contents = "\
-Rust:
-safe, fast, productive.
-Pick three.
-Duct tape.";
-
-        assert_eq!(vec!["safe, fast, productive."], search(query, contents));
-    }
-
-    #[test]
-    fn case_insensitive() {
-        let query = "rUsT";
-        let contents = "\
-Rust:
-safe, fast, productive.
-Pick three.
-Trust me.";
-
-        assert_eq!(
-            vec!["Rust:", "Trust me."],
-            search_case_insensitive(query, contents)
-        );
-    }
-}
 
+/// ```
+/// #[cfg(test)]
+/// mod tests {
+///     use super::*;
+///
+///     #[test]
+///     fn case_sensitive() {
+///         let query = "duct";
+///         let contents = "\
+/// Rust:
+/// safe, fast, productive.
+/// Pick three.
+/// Duct tape.";
+///
+///         assert_eq!(vec!["safe, fast, productive."], search(query, contents));
+///     }
+///
+///     #[test]
+///     fn case_insensitive() {
+///         let query = "rUsT";
+///         let contents = "\
+/// Rust:
+/// safe, fast, productive.
+/// Pick three.
+/// Trust me.";
+///
+///         assert_eq!(
+///             vec!["Rust:", "Trust me."],
+///             search_case_insensitive(query, contents)
+///         );
+///     }
+/// }
+/// ```
+pub mod listing_12_20 {}
 
 
 
 
 // Implementing the search_case_insensitive Function
 
+
+
+
 /*
 The search_case_insensitive function, shown in Listing 12-21, will be almost the same as the search function. The only difference is that we’ll lowercase the query and each line so whatever the case of the input arguments, they’ll be the same case when we check whether the line contains the query.
 
 Filename: src/lib.rs
 */
-pub fn search_case_insensitive<'a>(
-    query: &str,
-    contents: &'a str,
-) -> Vec<&'a str> {
-    let query = query.to_lowercase();
-    let mut results = Vec::new();
-
-    for line in contents.lines() {
-        if line.to_lowercase().contains(&query) {
-            results.push(line);
+
+pub mod listing_12_21 {
+    pub fn search_case_insensitive<'a>(
+        query: &str,
+        contents: &'a str,
+    ) -> Vec<&'a str> {
+        let query = query.to_lowercase();
+        let mut results = Vec::new();
+
+        for line in contents.lines() {
+            if line.to_lowercase().contains(&query) {
+                results.push(line);
+            }
         }
-    }
 
-    results
+        results
+    }
 }
 
 
 
+
 /*
 Let’s see if this implementation passes the tests:
 
@@ -103,39 +116,54 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; fini
 
 
 
+
 /*
 Great! They passed. Now, let’s call the new search_case_insensitive function from the run function. First, we’ll add a configuration option to the Config struct to switch between case-sensitive and case-insensitive search. Adding this field will cause compiler errors because we aren’t initializing this field anywhere yet:
 
 Filename: src/lib.rs
 */
-pub struct Config {
-    pub query: String,
-    pub file_path: String,
-    pub ignore_case: bool,
+
+pub mod example_03 {
+    pub struct Config {
+        pub query: String,
+        pub file_path: String,
+        pub ignore_case: bool,
+    }
 }
 
 
 
+
 /*
 We added the ignore_case field that holds a Boolean. Next, we need the run function to check the ignore_case field’s value and use that to decide whether to call the search function or the search_case_insensitive function, as shown in Listing 12-22. This still won’t compile yet.
 
 Filename: src/lib.rs
 */
-pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
-    let contents = fs::read_to_string(config.file_path)?;
-
-    let results = if config.ignore_case {
-        search_case_insensitive(&config.query, &contents)
-    } else {
-        search(&config.query, &contents)
-    };
-
-    for line in results {
-        println!("{line}");
-    }
 
-    Ok(())
-}
+/// ```
+/// # use rust_book_examples::trpl::ch12_minigrep::testing_the_librarys_functionality::listing_12_19::search;
+/// # use rust_book_examples::trpl::ch12_minigrep::working_with_environment_variables::example_03::Config;
+/// # use rust_book_examples::trpl::ch12_minigrep::working_with_environment_variables::listing_12_21::search_case_insensitive;
+/// # use std::error::Error;
+/// # use std::fs;
+/// #
+/// pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
+///     let contents = fs::read_to_string(config.file_path)?;
+///
+///     let results = if config.ignore_case {
+///         search_case_insensitive(&config.query, &contents)
+///     } else {
+///         search(&config.query, &contents)
+///     };
+///
+///     for line in results {
+///         println!("{line}");
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub mod listing_12_22 {}
 
 
 
@@ -145,27 +173,38 @@ Finally, we need to check for the environment variable. The functions for workin
 
 Filename: src/lib.rs
 */
-use std::env;
-// --snip--
-
-impl Config {
-    pub fn build(args: &[String]) -> Result<Config, &'static str> {
-        if args.len() < 3 {
-            return Err("not enough arguments");
-        }
 
-        let query = args[1].clone();
-        let file_path = args[2].clone();
+/// ```
+/// use std::env;
+/// // --snip--
+/// #
+/// # pub struct Config {
+/// #     pub query: String,
+/// #     pub file_path: String,
+/// #     pub ignore_case: bool,
+/// # }
+///
+/// impl Config {
+///     pub fn build(args: &[String]) -> Result<Config, &'static str> {
+///         if args.len() < 3 {
+///             return Err("not enough arguments");
+///         }
+///
+///         let query = args[1].clone();
+///         let file_path = args[2].clone();
+///
+///         let ignore_case = env::var("IGNORE_CASE").is_ok();
+///
+///         Ok(Config {
+///             query,
+///             file_path,
+///             ignore_case,
+///         })
+///     }
+/// }
+/// ```
+pub mod listing_12_23 {}
 
-        let ignore_case = env::var("IGNORE_CASE").is_ok();
-
-        Ok(Config {
-            query,
-            file_path,
-            ignore_case,
-        })
-    }
-}
 
 
 
@@ -182,24 +221,35 @@ How dreary to be somebody!
 */
 
 
+
+
 /*
 Looks like that still works! Now, let’s run the program with IGNORE_CASE set to 1 but with the same query to.
 
 $ IGNORE_CASE=1 cargo run -- to poem.txt
 */
 
+
+
+
 /*
 If you’re using PowerShell, you will need to set the environment variable and run the program as separate commands:
 
 PS> $Env:IGNORE_CASE=1; cargo run -- to poem.txt
 */
 
+
+
+
 /*
 This will make IGNORE_CASE persist for the remainder of your shell session. It can be unset with the Remove-Item cmdlet:
 
 PS> Remove-Item Env:IGNORE_CASE
 */
 
+
+
+
 /*
 We should get lines that contain “to” that might have uppercase letters:
 
@@ -208,7 +258,3 @@ How dreary to be somebody!
 To tell your name the livelong day
 To an admiring bog!
 */
-
-
-
-
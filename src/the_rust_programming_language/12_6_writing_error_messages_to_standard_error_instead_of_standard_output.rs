@@ -6,12 +6,18 @@
 
 // Checking Where Errors Are Written
 
+
+
+
 /*
 To demonstrate this behavior, we’ll run the program with > and the file path, output.txt, that we want to redirect the standard output stream to. We won’t pass any arguments, which should cause an error:
 
 $ cargo run > output.txt
 */
 
+
+
+
 /*
 The > syntax tells the shell to write the contents of standard output to output.txt instead of the screen. We didn’t see the error message we were expecting printed to the screen, so that means it must have ended up in the file. This is what output.txt contains:
 
@@ -21,28 +27,61 @@ Problem parsing arguments: not enough arguments
 
 
 
-
 // Printing Errors to Standard Error
 
+
+
+
 /*
 We’ll use the code in Listing 12-24 to change how error messages are printed. Because of the refactoring we did earlier in this chapter, all the code that prints error messages is in one function, main. The standard library provides the eprintln! macro that prints to the standard error stream, so let’s change the two places we were calling println! to print errors to use eprintln! instead.
 
 Filename: src/main.rs
 */
-fn main() {
-    let args: Vec<String> = env::args().collect();
 
-    let config = Config::build(&args).unwrap_or_else(|err| {
-        eprintln!("Problem parsing arguments: {err}");
-        process::exit(1);
-    });
+/// ```no_run
+/// # mod minigrep {
+/// #     use std::error::Error;
+/// #
+/// #     pub struct Config {
+/// #         pub query: String,
+/// #         pub file_path: String,
+/// #     }
+/// #
+/// #     impl Config {
+/// #         pub fn build(args: &[String]) -> Result<Config, &'static str> {
+/// #             if args.len() < 3 {
+/// #                 return Err("not enough arguments");
+/// #             }
+/// #
+/// #             Ok(Config { query: args[1].clone(), file_path: args[2].clone() })
+/// #         }
+/// #     }
+/// #
+/// #     pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
+/// #         Ok(())
+/// #     }
+/// # }
+/// #
+/// # use minigrep::Config;
+/// # use std::{env, process};
+/// #
+/// fn main() {
+///     let args: Vec<String> = env::args().collect();
+///
+///     let config = Config::build(&args).unwrap_or_else(|err| {
+///         eprintln!("Problem parsing arguments: {err}");
+///         process::exit(1);
+///     });
+///
+///     if let Err(e) = minigrep::run(config) {
+///         eprintln!("Application error: {e}");
+///
+///         process::exit(1);
+///     }
+/// }
+/// ```
+pub mod listing_12_24 {}
 
-    if let Err(e) = minigrep::run(config) {
-        eprintln!("Application error: {e}");
-
-        process::exit(1);
-    }
-}
 
 
 
@@ -53,12 +92,18 @@ $ cargo run > output.txt
 Problem parsing arguments: not enough arguments
 */
 
+
+
+
 /*
 Let’s run the program again with arguments that don’t cause an error but still redirect standard output to a file, like so:
 
 $ cargo run -- to poem.txt > output.txt
 */
 
+
+
+
 /*
 We won’t see any output to the terminal, and output.txt will contain our results:
 
@@ -67,6 +112,3 @@ Filename: output.txt
 Are you nobody, too?
 How dreary to be somebody!
 */
-
-
-
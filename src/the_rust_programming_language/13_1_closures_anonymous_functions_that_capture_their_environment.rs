@@ -4,78 +4,76 @@
 
 
 
-
-
 // Capturing the Environment with Closures
 
 // Listing 13-1: Shirt company giveaway situation
-#[derive(Debug, PartialEq, Copy, Clone)]
-enum ShirtColor {
-    Red,
-    Blue,
-}
 
-struct Inventory {
-    shirts: Vec<ShirtColor>,
-}
+pub mod listing_13_01 {
+    #[derive(Debug, PartialEq, Copy, Clone)]
+    enum ShirtColor {
+        Red,
+        Blue,
+    }
 
-impl Inventory {
-    fn giveaway(&self, user_preference: Option<ShirtColor>) -> ShirtColor {
-        user_preference.unwrap_or_else(|| self.most_stocked())
+    struct Inventory {
+        shirts: Vec<ShirtColor>,
     }
 
-    fn most_stocked(&self) -> ShirtColor {
-        let mut num_red = 0;
-        let mut num_blue = 0;
+    impl Inventory {
+        fn giveaway(&self, user_preference: Option<ShirtColor>) -> ShirtColor {
+            user_preference.unwrap_or_else(|| self.most_stocked())
+        }
+
+        fn most_stocked(&self) -> ShirtColor {
+            let mut num_red = 0;
+            let mut num_blue = 0;
 
-        for color in &self.shirts {
-            match color {
-                ShirtColor::Red => num_red += 1,
-                ShirtColor::Blue => num_blue += 1,
+            for color in &self.shirts {
+                match color {
+                    ShirtColor::Red => num_red += 1,
+                    ShirtColor::Blue => num_blue += 1,
+                }
+            }
+            if num_red > num_blue {
+                ShirtColor::Red
+            } else {
+                ShirtColor::Blue
             }
-        }
-        if num_red > num_blue {
-            ShirtColor::Red
-        } else {
-            ShirtColor::Blue
         }
     }
-}
-
-fn main() {
-    let store = Inventory {
-        shirts: vec![ShirtColor::Blue, ShirtColor::Red, ShirtColor::Blue],
-    };
-
-    let user_pref1 = Some(ShirtColor::Red);
-    let giveaway1 = store.giveaway(user_pref1);
-    println!(
-        "The user with preference {:?} gets {:?}",
-        user_pref1, giveaway1
-    );
-
-    let user_pref2 = None;
-    let giveaway2 = store.giveaway(user_pref2);
-    println!(
-        "The user with preference {:?} gets {:?}",
-        user_pref2, giveaway2
-    );
-}
-
 
+    pub fn main() {
+        let store = Inventory {
+            shirts: vec![ShirtColor::Blue, ShirtColor::Red, ShirtColor::Blue],
+        };
+
+        let user_pref1 = Some(ShirtColor::Red);
+        let giveaway1 = store.giveaway(user_pref1);
+        println!(
+            "The user with preference {:?} gets {:?}",
+            user_pref1, giveaway1
+        );
+
+        let user_pref2 = None;
+        let giveaway2 = store.giveaway(user_pref2);
+        println!(
+            "The user with preference {:?} gets {:?}",
+            user_pref2, giveaway2
+        );
+    }
 
-/*
-Running this code prints:
+    /*
+    Running this code prints:
 
-$ cargo run
-   Compiling shirt-company v0.1.0 (file:///projects/shirt-company)
-    Finished dev [unoptimized + debuginfo] target(s) in 0.27s
-     Running `target/debug/shirt-company`
-The user with preference Some(Red) gets Red
-The user with preference None gets Blue
-
-*/
+    $ cargo run
+       Compiling shirt-company v0.1.0 (file:///projects/shirt-company)
+        Finished dev [unoptimized + debuginfo] target(s) in 0.27s
+         Running `target/debug/shirt-company`
+    The user with preference Some(Red) gets Red
+    The user with preference None gets Blue
 
+    */
+}
 
 
 
@@ -84,35 +82,47 @@ The user with preference None gets Blue
 
 // Listing 13-2: Adding optional type annotations of the parameter and return value types in the closure
 // Filename: src/main.rs
-    let expensive_closure = |num: u32| -> u32 {
-        println!("calculating slowly...");
-        thread::sleep(Duration::from_secs(2));
-        num
-    };
 
+/// ```
+/// # use std::thread;
+/// # use std::time::Duration;
+/// #
+///     let expensive_closure = |num: u32| -> u32 {
+///         println!("calculating slowly...");
+///         thread::sleep(Duration::from_secs(2));
+///         num
+///     };
+/// ```
+pub mod listing_13_02 {}
 
 
 
 
 // This illustrates how closure syntax is similar to function syntax except for the use of 
 // pipes and the amount of syntax that is optional:
-fn  add_one_v1   (x: u32) -> u32 { x + 1 }
-let add_one_v2 = |x: u32| -> u32 { x + 1 };
-let add_one_v3 = |x|             { x + 1 };
-let add_one_v4 = |x|               x + 1  ;
-
-
 
+/// ```compile_fail
+/// fn  add_one_v1   (x: u32) -> u32 { x + 1 }
+/// let add_one_v2 = |x: u32| -> u32 { x + 1 };
+/// let add_one_v3 = |x|             { x + 1 };
+/// let add_one_v4 = |x|               x + 1  ;
+/// ```
+pub mod example_03 {}
 
 
 
 
 // Listing 13-3: Attempting to call a closure whose types are inferred with two different types
 // Filename: src/main.rs
-    let example_closure = |x| x;
 
-    let s = example_closure(String::from("hello"));
-    let n = example_closure(5);
+/// ```compile_fail
+///     let example_closure = |x| x;
+///
+///     let s = example_closure(String::from("hello"));
+///     let n = example_closure(5);
+/// ```
+pub mod listing_13_03 {}
+
 
 
 
@@ -137,26 +147,28 @@ error: could not compile `closure-example` due to previous error
 
 
 
-
-
 // Capturing References or Moving Ownership
 
 // In Listing 13-4, we define a closure that captures an immutable reference to the vector 
 // named list because it only needs an immutable reference to print the value:
 // Filename: src/main.rs
-fn main() {
-    let list = vec![1, 2, 3];
-    println!("Before defining closure: {:?}", list);
 
-    let only_borrows = || println!("From closure: {:?}", list);
+pub mod listing_13_04 {
+    pub fn main() {
+        let list = vec![1, 2, 3];
+        println!("Before defining closure: {:?}", list);
+
+        let only_borrows = || println!("From closure: {:?}", list);
 
-    println!("Before calling closure: {:?}", list);
-    only_borrows();
-    println!("After calling closure: {:?}", list);
+        println!("Before calling closure: {:?}", list);
+        only_borrows();
+        println!("After calling closure: {:?}", list);
+    }
 }
 
 
 
+
 /*
 This code compiles, runs, and prints:
 
@@ -174,23 +186,25 @@ After calling closure: [1, 2, 3]
 
 
 
-
-
-
 // Next, in Listing 13-5, we change the closure body so that it adds an element to 
 // the list vector. The closure now captures a mutable reference:
 // Filename: src/main.rs
-fn main() {
-    let mut list = vec![1, 2, 3];
-    println!("Before defining closure: {:?}", list);
 
-    let mut borrows_mutably = || list.push(7);
+pub mod listing_13_05 {
+    pub fn main() {
+        let mut list = vec![1, 2, 3];
+        println!("Before defining closure: {:?}", list);
+
+        let mut borrows_mutably = || list.push(7);
 
-    borrows_mutably();
-    println!("After calling closure: {:?}", list);
+        borrows_mutably();
+        println!("After calling closure: {:?}", list);
+    }
 }
 
 
+
+
 /*
 This code compiles, runs, and prints:
 
@@ -206,25 +220,25 @@ After calling closure: [1, 2, 3, 7]
 
 
 
-
-
-
-
-
 // Moving Captured Values Out of Closures and the Fn Traits
 
 // Let’s look at the definition of the unwrap_or_else method on Option<T> that we used in Listing 13-6:
-impl<T> Option<T> {
-    pub fn unwrap_or_else<F>(self, f: F) -> T
-    where
-        F: FnOnce() -> T
-    {
-        match self {
-            Some(x) => x,
-            None => f(),
-        }
-    }
-}
+
+/// ```compile_fail
+/// impl<T> Option<T> {
+///     pub fn unwrap_or_else<F>(self, f: F) -> T
+///     where
+///         F: FnOnce() -> T
+///     {
+///         match self {
+///             Some(x) => x,
+///             None => f(),
+///         }
+///     }
+/// }
+/// ```
+pub mod listing_13_06 {}
+
 
 
 
@@ -233,21 +247,24 @@ This function is useful when you want to sort a slice by a particular attribute
 
 Filename: src/main.rs
 */
-#[derive(Debug)]
-struct Rectangle {
-    width: u32,
-    height: u32,
-}
 
-fn main() {
-    let mut list = [
-        Rectangle { width: 10, height: 1 },
-        Rectangle { width: 3, height: 5 },
-        Rectangle { width: 7, height: 12 },
-    ];
+pub mod example_08 {
+    #[derive(Debug)]
+    struct Rectangle {
+        width: u32,
+        height: u32,
+    }
+
+    pub fn main() {
+        let mut list = [
+            Rectangle { width: 10, height: 1 },
+            Rectangle { width: 3, height: 5 },
+            Rectangle { width: 7, height: 12 },
+        ];
 
-    list.sort_by_key(|r| r.width);
-    println!("{:#?}", list);
+        list.sort_by_key(|r| r.width);
+        println!("{:#?}", list);
+    }
 }
 
 
@@ -280,38 +297,37 @@ $ cargo run
 
 
 
-
-
-
 /*
 In contrast, Listing 13-8 shows an example of a closure that implements just the FnOnce trait, because it moves a value out of the environment. The compiler won’t let us use this closure with sort_by_key:
 
 Filename: src/main.rs
 */
-#[derive(Debug)]
-struct Rectangle {
-    width: u32,
-    height: u32,
-}
-
-fn main() {
-    let mut list = [
-        Rectangle { width: 10, height: 1 },
-        Rectangle { width: 3, height: 5 },
-        Rectangle { width: 7, height: 12 },
-    ];
-
-    let mut sort_operations = vec![];
-    let value = String::from("by key called");
-
-    list.sort_by_key(|r| {
-        sort_operations.push(value);
-        r.width
-    });
-    println!("{:#?}", list);
-}
-
 
+/// ```compile_fail
+/// #[derive(Debug)]
+/// struct Rectangle {
+///     width: u32,
+///     height: u32,
+/// }
+///
+/// fn main() {
+///     let mut list = [
+///         Rectangle { width: 10, height: 1 },
+///         Rectangle { width: 3, height: 5 },
+///         Rectangle { width: 7, height: 12 },
+///     ];
+///
+///     let mut sort_operations = vec![];
+///     let value = String::from("by key called");
+///
+///     list.sort_by_key(|r| {
+///         sort_operations.push(value);
+///         r.width
+///     });
+///     println!("{:#?}", list);
+/// }
+/// ```
+pub mod listing_13_08 {}
 
 
 
@@ -343,34 +359,31 @@ error: could not compile `rectangles` due to previous error
 
 
 
-
-
-
 /*
 The closure in Listing 13-x works with sort_by_key because it is only capturing a mutable reference to the num_sort_operations counter and can therefore be called more than once:
 
 Filename: src/main.rs
 */
-#[derive(Debug)]
-struct Rectangle {
-    width: u32,
-    height: u32,
-}
-
-fn main() {
-    let mut list = [
-        Rectangle { width: 10, height: 1 },
-        Rectangle { width: 3, height: 5 },
-        Rectangle { width: 7, height: 12 },
-    ];
-
-    let mut num_sort_operations = 0;
-    list.sort_by_key(|r| {
-        num_sort_operations += 1;
-        r.width
-    });
-    println!("{:#?}, sorted in {num_sort_operations} operations", list);
-}
-
 
+pub mod example_10 {
+    #[derive(Debug)]
+    struct Rectangle {
+        width: u32,
+        height: u32,
+    }
 
+    pub fn main() {
+        let mut list = [
+            Rectangle { width: 10, height: 1 },
+            Rectangle { width: 3, height: 5 },
+            Rectangle { width: 7, height: 12 },
+        ];
+
+        let mut num_sort_operations = 0;
+        list.sort_by_key(|r| {
+            num_sort_operations += 1;
+            r.width
+        });
+        println!("{:#?}, sorted in {num_sort_operations} operations", list);
+    }
+}
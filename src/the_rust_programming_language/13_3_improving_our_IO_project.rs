@@ -6,114 +6,120 @@
 
 // Removing a clone Using an Iterator
 
+
+
+
 /*
 In Listing 12-6, we added code that took a slice of String values and created an instance of the Config struct by indexing into the slice and cloning the values, allowing the Config struct to own those values. In Listing 13-17, we’ve reproduced the implementation of the Config::build function as it was in Listing 12-23:
 
 Filename: src/lib.rs
 Listing 13-17: Reproduction of the Config::build function from Listing 12-23
 */
-use std::env;
-use std::error::Error;
-use std::fs;
-
-pub struct Config {
-    pub query: String,
-    pub file_path: String,
-    pub ignore_case: bool,
-}
-
-impl Config {
-    pub fn build(args: &[String]) -> Result<Config, &'static str> {
-        if args.len() < 3 {
-            return Err("not enough arguments");
-        }
 
-        let query = args[1].clone();
-        let file_path = args[2].clone();
+pub mod listing_13_17 {
+    use std::env;
+    use std::error::Error;
+    use std::fs;
 
-        let ignore_case = env::var("IGNORE_CASE").is_ok();
-
-        Ok(Config {
-            query,
-            file_path,
-            ignore_case,
-        })
+    pub struct Config {
+        pub query: String,
+        pub file_path: String,
+        pub ignore_case: bool,
     }
-}
 
-pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
-    let contents = fs::read_to_string(config.file_path)?;
+    impl Config {
+        pub fn build(args: &[String]) -> Result<Config, &'static str> {
+            if args.len() < 3 {
+                return Err("not enough arguments");
+            }
+
+            let query = args[1].clone();
+            let file_path = args[2].clone();
 
-    let results = if config.ignore_case {
-        search_case_insensitive(&config.query, &contents)
-    } else {
-        search(&config.query, &contents)
-    };
+            let ignore_case = env::var("IGNORE_CASE").is_ok();
 
-    for line in results {
-        println!("{line}");
+            Ok(Config {
+                query,
+                file_path,
+                ignore_case,
+            })
+        }
     }
 
-    Ok(())
-}
+    pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
+        let contents = fs::read_to_string(config.file_path)?;
 
-pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
-    let mut results = Vec::new();
+        let results = if config.ignore_case {
+            search_case_insensitive(&config.query, &contents)
+        } else {
+            search(&config.query, &contents)
+        };
 
-    for line in contents.lines() {
-        if line.contains(query) {
-            results.push(line);
+        for line in results {
+            println!("{line}");
         }
-    }
 
-    results
-}
+        Ok(())
+    }
 
-pub fn search_case_insensitive<'a>(
-    query: &str,
-    contents: &'a str,
-) -> Vec<&'a str> {
-    let query = query.to_lowercase();
-    let mut results = Vec::new();
+    pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+        let mut results = Vec::new();
 
-    for line in contents.lines() {
-        if line.to_lowercase().contains(&query) {
-            results.push(line);
+        for line in contents.lines() {
+            if line.contains(query) {
+                results.push(line);
+            }
         }
+
+        results
     }
 
-    results
-}
+    pub fn search_case_insensitive<'a>(
+        query: &str,
+        contents: &'a str,
+    ) -> Vec<&'a str> {
+        let query = query.to_lowercase();
+        let mut results = Vec::new();
+
+        for line in contents.lines() {
+            if line.to_lowercase().contains(&query) {
+                results.push(line);
+            }
+        }
+
+        results
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[cfg(test)]
+    mod tests {
+        use super::*;
 
-    #[test]
-    fn case_sensitive() {
-        let query = "duct";
-        let contents = "\
-Rust:
+        #[test]
+        fn case_sensitive() {
+            let query = "duct";
+            let contents = "\
+    Rust:
 safe, fast, productive.
 Pick three.
 Duct tape.";
 
-        assert_eq!(vec!["safe, fast, productive."], search(query, contents));
-    }
+            assert_eq!(vec!["safe, fast, productive."], search(query, contents));
+        }
 
-    #[test]
-    fn case_insensitive() {
-        let query = "rUsT";
-        let contents = "\
-Rust:
+        #[test]
+        fn case_insensitive() {
+            let query = "rUsT";
+            let contents = "\
+    Rust:
 safe, fast, productive.
 Pick three.
 Trust me.";
 
-        assert_eq!(
-            vec!["Rust:", "Trust me."],
-            search_case_insensitive(query, contents)
-        );
+            assert_eq!(
+                vec!["Rust:", "Trust me."],
+                search_case_insensitive(query, contents)
+            );
+        }
     }
 }
 
@@ -124,41 +130,89 @@ Trust me.";
 
 // Open your I/O project’s src/main.rs file, which should look like this:
 // Filename: src/main.rs
-fn main() {
-    let args: Vec<String> = env::args().collect();
 
-    let config = Config::build(&args).unwrap_or_else(|err| {
-        eprintln!("Problem parsing arguments: {err}");
-        process::exit(1);
-    });
+/// ```no_run
+/// # use rust_book_examples::trpl::ch13_functional_features::improving_our_io_project::listing_13_17::Config;
+/// # use std::{env, process};
+/// #
+/// fn main() {
+///     let args: Vec<String> = env::args().collect();
+///
+///     let config = Config::build(&args).unwrap_or_else(|err| {
+///         eprintln!("Problem parsing arguments: {err}");
+///         process::exit(1);
+///     });
+///
+///     // --snip--
+/// }
+/// ```
+pub mod example_02 {}
 
-    // --snip--
-}
 
 
 
 // Listing 13-18: Passing the return value of env::args to Config::build
-fn main() {
-    let config = Config::build(env::args()).unwrap_or_else(|err| {
-        eprintln!("Problem parsing arguments: {err}");
-        process::exit(1);
-    });
-
-    // --snip--
-}
 
+/// ```no_run
+/// # use std::{env, process};
+/// #
+/// # pub struct Config {
+/// #     pub query: String,
+/// #     pub file_path: String,
+/// #     pub ignore_case: bool,
+/// # }
+/// #
+/// # impl Config {
+/// #     pub fn build(
+/// #         mut args: impl Iterator<Item = String>,
+/// #     ) -> Result<Config, &'static str> {
+/// #         args.next();
+/// #
+/// #         let query = args.next().ok_or("Didn't get a query string")?;
+/// #         let file_path = args.next().ok_or("Didn't get a file path")?;
+/// #         let ignore_case = env::var("IGNORE_CASE").is_ok();
+/// #
+/// #         Ok(Config {
+/// #             query,
+/// #             file_path,
+/// #             ignore_case,
+/// #         })
+/// #     }
+/// # }
+/// #
+/// fn main() {
+///     let config = Config::build(env::args()).unwrap_or_else(|err| {
+///         eprintln!("Problem parsing arguments: {err}");
+///         process::exit(1);
+///     });
+///
+///     // --snip--
+/// }
+/// ```
+pub mod listing_13_18 {}
 
 
 
 
 // Listing 13-19: Updating the signature of Config::build to expect an iterator
-impl Config {
-    pub fn build(
-        mut args: impl Iterator<Item = String>,
-    ) -> Result<Config, &'static str> {
-        // --snip--
-
 
+/// ```
+/// # pub struct Config {
+/// #     pub query: String,
+/// #     pub file_path: String,
+/// #     pub ignore_case: bool,
+/// # }
+/// #
+/// impl Config {
+///     pub fn build(
+///         mut args: impl Iterator<Item = String>,
+///     ) -> Result<Config, &'static str> {
+///         // --snip--
+/// #         unimplemented!()
+/// #     }
+/// # }
+/// ```
+pub mod listing_13_19 {}
 
 
 
@@ -166,68 +220,83 @@ impl Config {
 // Using Iterator Trait Methods Instead of Indexing
 
 // Listing 13-20: Changing the body of Config::build to use iterator methods
-impl Config {
-    pub fn build(
-        mut args: impl Iterator<Item = String>,
-    ) -> Result<Config, &'static str> {
-        args.next();
-
-        let query = match args.next() {
-            Some(arg) => arg,
-            None => return Err("Didn't get a query string"),
-        };
 
-        let file_path = match args.next() {
-            Some(arg) => arg,
-            None => return Err("Didn't get a file path"),
-        };
+/// ```
+/// # use std::env;
+/// #
+/// # pub struct Config {
+/// #     pub query: String,
+/// #     pub file_path: String,
+/// #     pub ignore_case: bool,
+/// # }
+/// #
+/// impl Config {
+///     pub fn build(
+///         mut args: impl Iterator<Item = String>,
+///     ) -> Result<Config, &'static str> {
+///         args.next();
+///
+///         let query = match args.next() {
+///             Some(arg) => arg,
+///             None => return Err("Didn't get a query string"),
+///         };
+///
+///         let file_path = match args.next() {
+///             Some(arg) => arg,
+///             None => return Err("Didn't get a file path"),
+///         };
+///
+///         let ignore_case = env::var("IGNORE_CASE").is_ok();
+///
+///         Ok(Config {
+///             query,
+///             file_path,
+///             ignore_case,
+///         })
+///     }
+/// }
+/// ```
+pub mod listing_13_20 {}
 
-        let ignore_case = env::var("IGNORE_CASE").is_ok();
 
-        Ok(Config {
-            query,
-            file_path,
-            ignore_case,
-        })
-    }
-}
 
 
+// Making Code Clearer with Iterator Adaptors
 
 
 
 
-// Making Code Clearer with Iterator Adaptors
-
 /*
 We can also take advantage of iterators in the search function in our I/O project, which is reproduced here in Listing 13-21 as it was in Listing 12-19:
 
 Filename: src/lib.rs
 Listing 13-21: The implementation of the search function from Listing 12-19
 */
-pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
-    let mut results = Vec::new();
 
-    for line in contents.lines() {
-        if line.contains(query) {
-            results.push(line);
+pub mod listing_13_21 {
+    pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+        let mut results = Vec::new();
+
+        for line in contents.lines() {
+            if line.contains(query) {
+                results.push(line);
+            }
         }
-    }
 
-    results
+        results
+    }
 }
 
 
 
 
 // Listing 13-22: Using iterator adaptor methods in the implementation of the search function
-pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
-    contents
-        .lines()
-        .filter(|line| line.contains(query))
-        .collect()
-}
-
-
-
 
+pub mod listing_13_22 {
+    pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+        contents
+            .lines()
+            .filter(|line| line.contains(query))
+            .collect()
+    }
+}
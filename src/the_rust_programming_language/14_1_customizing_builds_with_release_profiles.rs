@@ -4,7 +4,6 @@
 
 
 
-
 /*
 These profile names might be familiar from the output of your builds:
 
@@ -17,6 +16,7 @@ $ cargo build --release
 
 
 
+
 /*
 Filename: Cargo.toml
 
@@ -40,8 +40,3 @@ Filename: Cargo.toml
 opt-level = 1
 
 */
-
-
-
-
-
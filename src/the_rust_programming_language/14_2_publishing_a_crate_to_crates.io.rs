@@ -4,9 +4,10 @@
 
 
 
+// Making Useful Documentation Comments
+
 
 
-// Making Useful Documentation Comments
 
 /*
 Documentation comments use three slashes, ///, instead of two and support Markdown notation for formatting the text. Place documentation comments just before the item they’re documenting. Listing 14-1 shows documentation comments for an add_one function in a crate named my_crate.
@@ -14,28 +15,33 @@ Documentation comments use three slashes, ///, instead of two and support Markdo
 Filename: src/lib.rs
 */
 
-/// Adds one to the number given.
-///
-/// # Examples
-///
-/// ```
-/// let arg = 5;
-/// let answer = my_crate::add_one(arg);
-///
-/// assert_eq!(6, answer);
-/// ```
-pub fn add_one(x: i32) -> i32 {
-    x + 1
+pub mod listing_14_01 {
+    /// Adds one to the number given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_book_examples::trpl::ch14_cargo::publishing_to_crates_io::listing_14_01 as my_crate;
+    /// let arg = 5;
+    /// let answer = my_crate::add_one(arg);
+    ///
+    /// assert_eq!(6, answer);
+    /// ```
+    pub fn add_one(x: i32) -> i32 {
+        x + 1
+    }
 }
 
 
 
 
-
 // Commonly Used Sections
 
 // Documentation Comments as Tests
 
+
+
+
 /*
 If we run cargo test with the documentation for the add_one function from Listing 14-1, we will see a section in the test results like this:
 
@@ -51,10 +57,10 @@ test result: ok. 1 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; fini
 
 
 
+// Commenting Contained Items
 
 
 
-// Commenting Contained Items
 
 /*
 For example, to add documentation that describes the purpose of the my_crate crate that contains the add_one function, we add documentation comments that start with //! to the beginning of the src/lib.rs file, as shown in Listing 14-2:
@@ -62,78 +68,87 @@ For example, to add documentation that describes the purpose of the my_crate cra
 Filename: src/lib.rs
 */
 
-//! # My Crate
-//!
-//! `my_crate` is a collection of utilities to make performing certain
-//! calculations more convenient.
+/// ```
+/// //! # My Crate
+/// //!
+/// //! `my_crate` is a collection of utilities to make performing certain
+/// //! calculations more convenient.
+///
+/// /// Adds one to the number given.
+/// ```
+pub mod listing_14_02 {}
 
-/// Adds one to the number given.
 // --snip--
 
 
 
 
-
 // Exporting a Convenient Public API with pub use
 
+
+
+
 /*
 For example, say we made a library named art for modeling artistic concepts. Within this library are two modules: a kinds module containing two enums named PrimaryColor and SecondaryColor and a utils module containing a function named mix, as shown in Listing 14-3:
 
 Filename: src/lib.rs
 */
 
-//! # Art
-//!
-//! A library for modeling artistic concepts.
-
-pub mod kinds {
-    /// The primary colors according to the RYB color model.
-    pub enum PrimaryColor {
-        Red,
-        Yellow,
-        Blue,
-    }
-
-    /// The secondary colors according to the RYB color model.
-    pub enum SecondaryColor {
-        Orange,
-        Green,
-        Purple,
+pub mod listing_14_03 {
+    //! # Art
+    //!
+    //! A library for modeling artistic concepts.
+
+    pub mod kinds {
+        /// The primary colors according to the RYB color model.
+        pub enum PrimaryColor {
+            Red,
+            Yellow,
+            Blue,
+        }
+
+        /// The secondary colors according to the RYB color model.
+        pub enum SecondaryColor {
+            Orange,
+            Green,
+            Purple,
+        }
     }
-}
 
-pub mod utils {
-    use crate::kinds::*;
+    pub mod utils {
+        use crate::trpl::ch14_cargo::publishing_to_crates_io::listing_14_03::kinds::*;
 
-    /// Combines two primary colors in equal amounts to create
-    /// a secondary color.
-    pub fn mix(c1: PrimaryColor, c2: PrimaryColor) -> SecondaryColor {
-        // --snip--
-        unimplemented!();
+        /// Combines two primary colors in equal amounts to create
+        /// a secondary color.
+        pub fn mix(c1: PrimaryColor, c2: PrimaryColor) -> SecondaryColor {
+            // --snip--
+            unimplemented!();
+        }
     }
 }
 
 
 
 
-
-
-
-
-
 /*
 Another crate that depends on this library would need use statements that bring the items from art into scope, specifying the module structure that’s currently defined. Listing 14-4 shows an example of a crate that uses the PrimaryColor and mix items from the art crate:
 
 Filename: src/main.rs
 */
-use art::kinds::PrimaryColor;
-use art::utils::mix;
 
-fn main() {
-    let red = PrimaryColor::Red;
-    let yellow = PrimaryColor::Yellow;
-    mix(red, yellow);
-}
+/// ```no_run
+/// # use rust_book_examples::trpl::ch14_cargo::publishing_to_crates_io::listing_14_03 as art;
+/// #
+/// use art::kinds::PrimaryColor;
+/// use art::utils::mix;
+///
+/// fn main() {
+///     let red = PrimaryColor::Red;
+///     let yellow = PrimaryColor::Yellow;
+///     mix(red, yellow);
+/// }
+/// ```
+pub mod listing_14_04 {}
 
 
 
@@ -144,22 +159,42 @@ To remove the internal organization from the public API, we can modify the art c
 Filename: src/lib.rs
 */
 
-//! # Art
-//!
-//! A library for modeling artistic concepts.
-
-pub use self::kinds::PrimaryColor;
-pub use self::kinds::SecondaryColor;
-pub use self::utils::mix;
-
-pub mod kinds {
-    // --snip--
-}
-
-pub mod utils {
-    // --snip--
-}
-
+/// ```
+/// //! # Art
+/// //!
+/// //! A library for modeling artistic concepts.
+///
+/// pub use self::kinds::PrimaryColor;
+/// pub use self::kinds::SecondaryColor;
+/// pub use self::utils::mix;
+///
+/// pub mod kinds {
+///     // --snip--
+/// #     pub enum PrimaryColor {
+/// #         Red,
+/// #         Yellow,
+/// #         Blue,
+/// #     }
+/// #
+/// #     pub enum SecondaryColor {
+/// #         Orange,
+/// #         Green,
+/// #         Purple,
+/// #     }
+/// }
+///
+/// pub mod utils {
+///     // --snip--
+/// #     use crate::kinds::*;
+/// #
+/// #     pub fn mix(c1: PrimaryColor, c2: PrimaryColor) -> SecondaryColor {
+/// #         unimplemented!();
+/// #     }
+/// }
+/// #
+/// # fn main() {}
+/// ```
+pub mod listing_14_05 {}
 
 
 
@@ -170,12 +205,20 @@ The art crate users can still see and use the internal structure from Listing 14
 Filename: src/main.rs
 */
 
-use art::mix;
-use art::PrimaryColor;
-
-fn main() {
-    // --snip--
-}
+/// ```
+/// # mod art {
+/// #     pub use rust_book_examples::trpl::ch14_cargo::publishing_to_crates_io::listing_14_03::kinds::PrimaryColor;
+/// #     pub use rust_book_examples::trpl::ch14_cargo::publishing_to_crates_io::listing_14_03::utils::mix;
+/// # }
+/// #
+/// use art::mix;
+/// use art::PrimaryColor;
+///
+/// fn main() {
+///     // --snip--
+/// }
+/// ```
+pub mod listing_14_06 {}
 
 
 
@@ -187,10 +230,10 @@ fn main() {
 
 
 
+// Adding Metadata to a New Crate
 
 
 
-// Adding Metadata to a New Crate
 
 /*
 If the name has been used, you will need to find another name and edit the name field in the Cargo.toml file under the [package] section to use the new name for publishing, like so:
@@ -202,6 +245,8 @@ name = "guessing_game"
 */
 
 
+
+
 /*
 $ cargo publish
     Updating crates.io index
@@ -218,7 +263,6 @@ Caused by:
 
 
 
-
 /*
 The Linux Foundation’s Software Package Data Exchange (SPDX) lists the identifiers you can use for this value. For example, to specify that you’ve licensed your crate using the MIT License, add the MIT identifier:
 
@@ -232,8 +276,6 @@ license = "MIT"
 
 
 
-
-
 /*
 With a unique name, the version, your description, and a license added, the Cargo.toml file for a project that is ready to publish might look like this:
 
@@ -253,9 +295,10 @@ license = "MIT OR Apache-2.0"
 
 
 
+// Publishing to Crates.io
+
 
 
-// Publishing to Crates.io
 
 /*
 Run the cargo publish command again. It should succeed now:
@@ -274,13 +317,11 @@ $ cargo publish
 
 
 
+// Deprecating Versions from Crates.io with cargo yank
 
 
 
 
-
-// Deprecating Versions from Crates.io with cargo yank
-
 /*
 To yank a version of a crate, in the directory of the crate that you’ve previously published, run cargo yank and specify which version you want to yank. For example, if we've published a crate named guessing_game version 1.0.1 and we want to yank it, in the project directory for guessing_game we'd run:
 
@@ -295,6 +336,3 @@ $ cargo yank --vers 1.0.1 --undo
     Updating crates.io index
       Unyank guessing_game_:1.0.1
 */
-
-
-
@@ -4,9 +4,11 @@
 
 
 
-
 // Creating a Workspace
 
+
+
+
 /*
 $ mkdir add
 $ cd add
@@ -14,6 +16,8 @@ $ cd add
 */
 
 
+
+
 /*
 Filename: Cargo.toml
 
@@ -52,15 +56,11 @@ At this point, we can build the workspace by running cargo build. The files in y
 
 
 
-
-
-
-
-
 // Creating the Second Package in the Workspace
 
 
 
+
 /*
 Next, let’s create another member package in the workspace and call it add_one. Change the top-level Cargo.toml to specify the add_one path in the members list:
 
@@ -76,6 +76,7 @@ members = [
 
 
 
+
 /*
 Then generate a new library crate named add_one:
 
@@ -148,6 +149,7 @@ fn main() {
 
 
 
+
 /*
 Let’s build the workspace by running cargo build in the top-level add directory!
 
@@ -172,13 +174,11 @@ Hello, world! 10 plus one is 11!
 
 
 
-
-
-
 // Depending on an External Package in a Workspace
 
 
 
+
 /*
 Filename: add_one/Cargo.toml
 
@@ -187,6 +187,8 @@ rand = "0.8.3"
 */
 
 
+
+
 /*
 We can now add use rand; to the add_one/src/lib.rs file, and building the whole workspace by running cargo build in the add directory will bring in and compile the rand crate. We will get one warning because we aren’t referring to the rand we brought into scope:
 
@@ -213,8 +215,6 @@ warning: 1 warning emitted
 
 
 
-
-
 /*
 The top-level Cargo.lock now contains information about the dependency of add_one on rand. However, even though rand is used somewhere in the workspace, we can’t use it in other crates in the workspace unless we add rand to their Cargo.toml files as well. For example, if we add use rand; to the adder/src/main.rs file for the adder package, we’ll get an error:
 
@@ -231,17 +231,11 @@ error[E0432]: unresolved import `rand`
 
 
 
-
-
-
-
-
 // Adding a Test to a Workspace
 
 
 
 
-
 /*
 For another enhancement, let’s add a test of the add_one::add_one function within the add_one crate:
 
@@ -295,7 +289,6 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; fini
 
 
 
-
 /*
 We can also run tests for one particular crate in a workspace from the top-level directory by using the -p flag and specifying the name of the crate we want to test:
 
@@ -314,8 +307,3 @@ running 0 tests
 
 test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
 */
-
-
-
-
-
@@ -4,28 +4,34 @@
 
 
 
-
 // Using a Box<T> to Store Data on the Heap
 
+
+
+
 /*
 Before we discuss the heap storage use case for Box<T>, we’ll cover the syntax and how to interact with values stored within a Box<T>.
 
 Listing 15-1 shows how to use a box to store an i32 value on the heap:
 
 Filename: src/main.rs
-
-fn main() {
-    let b = Box::new(5);
-    println!("b = {}", b);
-}
 */
 
+pub mod listing_15_01 {
+    pub fn main() {
+        let b = Box::new(5);
+        println!("b = {}", b);
+    }
+}
 
 
 
 
 // More Information About the Cons List
 
+
+
+
 /*
 For example, here's a pseudocode representation of a cons list containing the list 1, 2, 3 with each pair in parentheses:
 
@@ -33,18 +39,24 @@ For example, here's a pseudocode representation of a cons list containing the li
 */
 
 
+
+
 /*
 Listing 15-2 contains an enum definition for a cons list. Note that this code won’t compile yet because the List type doesn’t have a known size, which we’ll demonstrate.
 
 Filename: src/main.rs
-
- [This code does not compile!] 
-enum List {
-    Cons(i32, List),
-    Nil,
-}
 */
 
+// [This code does not compile!]
+
+/// ```compile_fail
+/// enum List {
+///     Cons(i32, List),
+///     Nil,
+/// }
+/// ```
+pub mod listing_15_02 {}
+
 
 
 
@@ -52,14 +64,24 @@ enum List {
 Using the List type to store the list 1, 2, 3 would look like the code in Listing 15-3:
 
 Filename: src/main.rs
+*/
 
- [This code does not compile!] 
-use crate::List::{Cons, Nil};
+// [This code does not compile!]
+
+/// ```compile_fail
+/// # enum List {
+/// #     Cons(i32, List),
+/// #     Nil,
+/// # }
+/// #
+/// use crate::List::{Cons, Nil};
+///
+/// fn main() {
+///     let list = Cons(1, Cons(2, Cons(3, Nil)));
+/// }
+/// ```
+pub mod listing_15_03 {}
 
-fn main() {
-    let list = Cons(1, Cons(2, Cons(3, Nil)));
-}
-*/
 
 
 
@@ -101,10 +123,10 @@ error: could not compile `cons-list` due to 2 previous errors
 
 
 
+// Computing the Size of a Non-Recursive Type
 
 
 
-// Computing the Size of a Non-Recursive Type
 
 /*
 Recall the Message enum we defined in Listing 6-2 when we discussed enum definitions in Chapter 6:
@@ -120,10 +142,10 @@ enum Message {
 
 
 
+// Using Box<T> to Get a Recursive Type with a Known Size
 
 
 
-// Using Box<T> to Get a Recursive Type with a Known Size
 
 /*
 Because Rust can’t figure out how much space to allocate for recursively defined types, the compiler gives an error with this helpful suggestion:
@@ -137,22 +159,21 @@ help: insert some indirection (e.g., a `Box`, `Rc`, or `&`) to make `List` repre
 
 
 
-
 /*
 We can change the definition of the List enum in Listing 15-2 and the usage of the List in Listing 15-3 to the code in Listing 15-5, which will compile:
 
 Filename: src/main.rs
+*/
 
-enum List {
-    Cons(i32, Box<List>),
-    Nil,
-}
+pub mod listing_15_05 {
+    enum List {
+        Cons(i32, Box<List>),
+        Nil,
+    }
 
-use crate::List::{Cons, Nil};
+    use crate::trpl::ch15_smart_pointers::using_box::listing_15_05::List::{Cons, Nil};
 
-fn main() {
-    let list = Cons(1, Box::new(Cons(2, Box::new(Cons(3, Box::new(Nil))))));
+    pub fn main() {
+        let list = Cons(1, Box::new(Cons(2, Box::new(Cons(3, Box::new(Nil))))));
+    }
 }
-*/
-
-
@@ -4,22 +4,27 @@
 
 
 
-
 // Following the Pointer to the Value
 
+
+
+
 /*
 A regular reference is a type of pointer, and one way to think of a pointer is as an arrow to a value stored somewhere else. In Listing 15-6, we create a reference to an i32 value and then use the dereference operator to follow the reference to the value:
 
 Filename: src/main.rs
+*/
 
-fn main() {
-    let x = 5;
-    let y = &x;
+pub mod listing_15_06 {
+    pub fn main() {
+        let x = 5;
+        let y = &x;
 
-    assert_eq!(5, x);
-    assert_eq!(5, *y);
+        assert_eq!(5, x);
+        assert_eq!(5, *y);
+    }
 }
-*/
+
 
 
 
@@ -44,31 +49,35 @@ error: could not compile `deref-example` due to previous error
 
 
 
+// Using Box<T> Like a Reference
 
 
 
-// Using Box<T> Like a Reference
 
 /*
 We can rewrite the code in Listing 15-6 to use a Box<T> instead of a reference; the dereference operator used on the Box<T> in Listing 15-7 functions in the same way as the dereference operator used on the reference in Listing 15-6:
 
 Filename: src/main.rs
-
-fn main() {
-    let x = 5;
-    let y = Box::new(x);
-
-    assert_eq!(5, x);
-    assert_eq!(5, *y);
 */
 
+/// ```
+/// fn main() {
+///     let x = 5;
+///     let y = Box::new(x);
+///
+///     assert_eq!(5, x);
+///     assert_eq!(5, *y);
+/// }
+/// ```
+pub mod example_02 {}
+
 
 
 
+// Defining Our Own Smart Pointer
 
 
 
-// Defining Our Own Smart Pointer
 
 /*
 Let’s build a smart pointer similar to the Box<T> type provided by the standard library to experience how smart pointers behave differently from references by default. Then we’ll look at how to add the ability to use the dereference operator.
@@ -76,31 +85,49 @@ Let’s build a smart pointer similar to the Box<T> type provided by the standar
 The Box<T> type is ultimately defined as a tuple struct with one element, so Listing 15-8 defines a MyBox<T> type in the same way. We’ll also define a new function to match the new function defined on Box<T>.
 
 Filename: src/main.rs
+*/
 
-struct MyBox<T>(T);
+pub mod listing_15_08 {
+    struct MyBox<T>(T);
 
-impl<T> MyBox<T> {
-    fn new(x: T) -> MyBox<T> {
-        MyBox(x)
+    impl<T> MyBox<T> {
+        fn new(x: T) -> MyBox<T> {
+            MyBox(x)
+        }
     }
 }
-*/
+
+
 
 
 /*
 Let’s try adding the main function in Listing 15-7 to Listing 15-8 and changing it to use the MyBox<T> type we’ve defined instead of Box<T>. The code in Listing 15-9 won’t compile because Rust doesn’t know how to dereference MyBox.
 
 Filename: src/main.rs
+*/
+
+// [This code does not compile!]
+
+/// ```compile_fail
+/// # struct MyBox<T>(T);
+/// #
+/// # impl<T> MyBox<T> {
+/// #     fn new(x: T) -> MyBox<T> {
+/// #         MyBox(x)
+/// #     }
+/// # }
+/// #
+/// fn main() {
+///     let x = 5;
+///     let y = MyBox::new(x);
+///
+///     assert_eq!(5, x);
+///     assert_eq!(5, *y);
+/// }
+/// ```
+pub mod listing_15_09 {}
 
- [This code does not compile!] 
-fn main() {
-    let x = 5;
-    let y = MyBox::new(x);
 
-    assert_eq!(5, x);
-    assert_eq!(5, *y);
-}
-*/
 
 
 /*
@@ -121,27 +148,31 @@ error: could not compile `deref-example` due to previous error
 
 
 
+// Treating a Type Like a Reference by Implementing the Deref Trait
 
 
 
-// Treating a Type Like a Reference by Implementing the Deref Trait
 
 /*
 As discussed in the “Implementing a Trait on a Type” section of Chapter 10, to implement a trait, we need to provide implementations for the trait’s required methods. The Deref trait, provided by the standard library, requires us to implement one method named deref that borrows self and returns a reference to the inner data. Listing 15-10 contains an implementation of Deref to add to the definition of MyBox:
 
 Filename: src/main.rs
-
-use std::ops::Deref;
-
-impl<T> Deref for MyBox<T> {
-    type Target = T;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
 */
 
+/// ```
+/// # struct MyBox<T>(T);
+/// #
+/// use std::ops::Deref;
+///
+/// impl<T> Deref for MyBox<T> {
+///     type Target = T;
+///
+///     fn deref(&self) -> &Self::Target {
+///         &self.0
+///     }
+/// }
+/// ```
+pub mod listing_15_10 {}
 
 
 
@@ -157,19 +188,23 @@ When we entered *y in Listing 15-9, behind the scenes Rust actually ran this cod
 
 
 
+// Implicit Deref Coercions with Functions and Methods
+
 
 
-// Implicit Deref Coercions with Functions and Methods
 
 /*
 To see deref coercion in action, let’s use the MyBox<T> type we defined in Listing 15-8 as well as the implementation of Deref that we added in Listing 15-10. Listing 15-11 shows the definition of a function that has a string slice parameter:
 
 Filename: src/main.rs
+*/
 
-fn hello(name: &str) {
-    println!("Hello, {name}!");
+pub mod listing_15_11 {
+    fn hello(name: &str) {
+        println!("Hello, {name}!");
+    }
 }
-*/
+
 
 
 
@@ -177,31 +212,85 @@ fn hello(name: &str) {
 We can call the hello function with a string slice as an argument, such as hello("Rust"); for example. Deref coercion makes it possible to call hello with a reference to a value of type MyBox<String>, as shown in Listing 15-12:
 
 Filename: src/main.rs
-
-fn main() {
-    let m = MyBox::new(String::from("Rust"));
-    hello(&m);
-}
 */
 
+/// ```
+/// # struct MyBox<T>(T);
+/// #
+/// # impl<T> MyBox<T> {
+/// #     fn new(x: T) -> MyBox<T> {
+/// #         MyBox(x)
+/// #     }
+/// # }
+/// #
+/// # use std::ops::Deref;
+/// #
+/// # impl<T> Deref for MyBox<T> {
+/// #     type Target = T;
+/// #
+/// #     fn deref(&self) -> &Self::Target {
+/// #         &self.0
+/// #     }
+/// # }
+/// #
+/// # fn hello(name: &str) {
+/// #     println!("Hello, {name}!");
+/// # }
+/// #
+/// fn main() {
+///     let m = MyBox::new(String::from("Rust"));
+///     hello(&m);
+/// }
+/// ```
+pub mod listing_15_12 {}
+
+
 
 
 /*
 If Rust didn’t implement deref coercion, we would have to write the code in Listing 15-13 instead of the code in Listing 15-12 to call hello with a value of type &MyBox<String>.
 
 Filename: src/main.rs
-
-fn main() {
-    let m = MyBox::new(String::from("Rust"));
-    hello(&(*m)[..]);
-}
 */
 
+/// ```
+/// # struct MyBox<T>(T);
+/// #
+/// # impl<T> MyBox<T> {
+/// #     fn new(x: T) -> MyBox<T> {
+/// #         MyBox(x)
+/// #     }
+/// # }
+/// #
+/// # use std::ops::Deref;
+/// #
+/// # impl<T> Deref for MyBox<T> {
+/// #     type Target = T;
+/// #
+/// #     fn deref(&self) -> &Self::Target {
+/// #         &self.0
+/// #     }
+/// # }
+/// #
+/// # fn hello(name: &str) {
+/// #     println!("Hello, {name}!");
+/// # }
+/// #
+/// fn main() {
+///     let m = MyBox::new(String::from("Rust"));
+///     hello(&(*m)[..]);
+/// }
+/// ```
+pub mod example_08 {}
+
 
 
 
 // How Deref Coercion Interacts with Mutability
 
+
+
+
 /*
 Rust does deref coercion when it finds types and trait implementations in three cases:
 
@@ -210,6 +299,3 @@ Rust does deref coercion when it finds types and trait implementations in three
     From &mut T to &U when T: Deref<Target=U>
 
 */
-
-
-
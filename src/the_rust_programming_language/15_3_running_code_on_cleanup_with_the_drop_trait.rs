@@ -8,28 +8,29 @@
 Listing 15-14 shows a CustomSmartPointer struct whose only custom functionality is that it will print Dropping CustomSmartPointer! when the instance goes out of scope, to show when Rust runs the drop function.
 
 Filename: src/main.rs
+*/
 
-struct CustomSmartPointer {
-    data: String,
-}
+pub mod listing_15_14 {
+    struct CustomSmartPointer {
+        data: String,
+    }
 
-impl Drop for CustomSmartPointer {
-    fn drop(&mut self) {
-        println!("Dropping CustomSmartPointer with data `{}`!", self.data);
+    impl Drop for CustomSmartPointer {
+        fn drop(&mut self) {
+            println!("Dropping CustomSmartPointer with data `{}`!", self.data);
+        }
     }
-}
 
-fn main() {
-    let c = CustomSmartPointer {
-        data: String::from("my stuff"),
-    };
-    let d = CustomSmartPointer {
-        data: String::from("other stuff"),
-    };
-    println!("CustomSmartPointers created.");
+    pub fn main() {
+        let c = CustomSmartPointer {
+            data: String::from("my stuff"),
+        };
+        let d = CustomSmartPointer {
+            data: String::from("other stuff"),
+        };
+        println!("CustomSmartPointers created.");
+    }
 }
-*/
-
 
 
 
@@ -51,28 +52,43 @@ Dropping CustomSmartPointer with data `my stuff`!
 
 
 
+// Dropping a Value Early with std::mem::drop
 
 
 
 
-// Dropping a Value Early with std::mem::drop
-
 /*
 If we try to call the Drop trait’s drop method manually by modifying the main function from Listing 15-14, as shown in Listing 15-15, we’ll get a compiler error:
 
 Filename: src/main.rs
-
- [This code does not compile!] 
-fn main() {
-    let c = CustomSmartPointer {
-        data: String::from("some data"),
-    };
-    println!("CustomSmartPointer created.");
-    c.drop();
-    println!("CustomSmartPointer dropped before the end of main.");
-}
 */
 
+// [This code does not compile!]
+
+/// ```compile_fail
+/// # struct CustomSmartPointer {
+/// #     data: String,
+/// # }
+/// #
+/// # impl Drop for CustomSmartPointer {
+/// #     fn drop(&mut self) {
+/// #         println!("Dropping CustomSmartPointer with data `{}`!", self.data);
+/// #     }
+/// # }
+/// #
+/// fn main() {
+///     let c = CustomSmartPointer {
+///         data: String::from("some data"),
+///     };
+///     println!("CustomSmartPointer created.");
+///     c.drop();
+///     println!("CustomSmartPointer dropped before the end of main.");
+/// }
+/// ```
+pub mod listing_15_15 {}
+
+
+
 
 /*
 When we try to compile this code, we’ll get this error:
@@ -101,17 +117,29 @@ We can’t disable the automatic insertion of drop when a value goes out of scop
 The std::mem::drop function is different from the drop method in the Drop trait. We call it by passing as an argument the value we want to force drop. The function is in the prelude, so we can modify main in Listing 15-15 to call the drop function, as shown in Listing 15-16:
 
 Filename: src/main.rs
-
-fn main() {
-    let c = CustomSmartPointer {
-        data: String::from("some data"),
-    };
-    println!("CustomSmartPointer created.");
-    drop(c);
-    println!("CustomSmartPointer dropped before the end of main.");
-}
 */
 
+/// ```
+/// # struct CustomSmartPointer {
+/// #     data: String,
+/// # }
+/// #
+/// # impl Drop for CustomSmartPointer {
+/// #     fn drop(&mut self) {
+/// #         println!("Dropping CustomSmartPointer with data `{}`!", self.data);
+/// #     }
+/// # }
+/// #
+/// fn main() {
+///     let c = CustomSmartPointer {
+///         data: String::from("some data"),
+///     };
+///     println!("CustomSmartPointer created.");
+///     drop(c);
+///     println!("CustomSmartPointer dropped before the end of main.");
+/// }
+/// ```
+pub mod listing_15_16 {}
 
 /*
 Running this code will print the following:
@@ -124,6 +152,3 @@ CustomSmartPointer created.
 Dropping CustomSmartPointer with data `some data`!
 CustomSmartPointer dropped before the end of main.
 */
-
-
-
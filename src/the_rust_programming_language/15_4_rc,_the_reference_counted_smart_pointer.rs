@@ -10,21 +10,26 @@ We’ll create list a that contains 5 and then 10. Then we’ll make two more li
 Trying to implement this scenario using our definition of List with Box<T> won’t work, as shown in Listing 15-17:
 
 Filename: src/main.rs
+*/
 
- [This code does not compile!] 
-enum List {
-    Cons(i32, Box<List>),
-    Nil,
-}
+// [This code does not compile!]
 
-use crate::List::{Cons, Nil};
+/// ```compile_fail
+/// enum List {
+///     Cons(i32, Box<List>),
+///     Nil,
+/// }
+///
+/// use crate::List::{Cons, Nil};
+///
+/// fn main() {
+///     let a = Cons(5, Box::new(Cons(10, Box::new(Nil))));
+///     let b = Cons(3, Box::new(a));
+///     let c = Cons(4, Box::new(a));
+/// }
+/// ```
+pub mod listing_15_17 {}
 
-fn main() {
-    let a = Cons(5, Box::new(Cons(10, Box::new(Nil))));
-    let b = Cons(3, Box::new(a));
-    let c = Cons(4, Box::new(a));
-}
-*/
 
 
 
@@ -54,29 +59,31 @@ error: could not compile `cons-list` due to previous error
 Instead, we’ll change our definition of List to use Rc<T> in place of Box<T>, as shown in Listing 15-18. Each Cons variant will now hold a value and an Rc<T> pointing to a List. When we create b, instead of taking ownership of a, we’ll clone the Rc<List> that a is holding, thereby increasing the number of references from one to two and letting a and b share ownership of the data in that Rc<List>. We’ll also clone a when creating c, increasing the number of references from two to three. Every time we call Rc::clone, the reference count to the data within the Rc<List> will increase, and the data won’t be cleaned up unless there are zero references to it.
 
 Filename: src/main.rs
+*/
 
-enum List {
-    Cons(i32, Rc<List>),
-    Nil,
-}
+pub mod listing_15_18 {
+    enum List {
+        Cons(i32, Rc<List>),
+        Nil,
+    }
 
-use crate::List::{Cons, Nil};
-use std::rc::Rc;
+    use crate::trpl::ch15_smart_pointers::rc::listing_15_18::List::{Cons, Nil};
+    use std::rc::Rc;
 
-fn main() {
-    let a = Rc::new(Cons(5, Rc::new(Cons(10, Rc::new(Nil)))));
-    let b = Cons(3, Rc::clone(&a));
-    let c = Cons(4, Rc::clone(&a));
+    pub fn main() {
+        let a = Rc::new(Cons(5, Rc::new(Cons(10, Rc::new(Nil)))));
+        let b = Cons(3, Rc::clone(&a));
+        let c = Cons(4, Rc::clone(&a));
+    }
 }
-*/
 
 
 
 
+// Cloning an Rc<T> Increases the Reference Count
 
 
 
-// Cloning an Rc<T> Increases the Reference Count
 
 /*
 Let’s change our working example in Listing 15-18 so we can see the reference counts changing as we create and drop references to the Rc<List> in a.
@@ -84,20 +91,31 @@ Let’s change our working example in Listing 15-18 so we can see the reference
 In Listing 15-19, we’ll change main so it has an inner scope around list c; then we can see how the reference count changes when c goes out of scope.
 
 Filename: src/main.rs
-
-fn main() {
-    let a = Rc::new(Cons(5, Rc::new(Cons(10, Rc::new(Nil)))));
-    println!("count after creating a = {}", Rc::strong_count(&a));
-    let b = Cons(3, Rc::clone(&a));
-    println!("count after creating b = {}", Rc::strong_count(&a));
-    {
-        let c = Cons(4, Rc::clone(&a));
-        println!("count after creating c = {}", Rc::strong_count(&a));
-    }
-    println!("count after c goes out of scope = {}", Rc::strong_count(&a));
-}
 */
 
+/// ```
+/// # enum List {
+/// #     Cons(i32, Rc<List>),
+/// #     Nil,
+/// # }
+/// #
+/// # use crate::List::{Cons, Nil};
+/// # use std::rc::Rc;
+/// #
+/// fn main() {
+///     let a = Rc::new(Cons(5, Rc::new(Cons(10, Rc::new(Nil)))));
+///     println!("count after creating a = {}", Rc::strong_count(&a));
+///     let b = Cons(3, Rc::clone(&a));
+///     println!("count after creating b = {}", Rc::strong_count(&a));
+///     {
+///         let c = Cons(4, Rc::clone(&a));
+///         println!("count after creating c = {}", Rc::strong_count(&a));
+///     }
+///     println!("count after c goes out of scope = {}", Rc::strong_count(&a));
+/// }
+/// ```
+pub mod listing_15_19 {}
+
 
 
 
@@ -115,6 +133,3 @@ count after creating b = 2
 count after creating c = 3
 count after c goes out of scope = 2
 */
-
-
-
@@ -4,9 +4,11 @@
 
 
 
-
 // Enforcing Borrowing Rules at Runtime with RefCell<T>
 
+
+
+
 /*
 Unlike Rc<T>, the RefCell<T> type represents single ownership over the data it holds. So, what makes RefCell<T> different from a type like Box<T>? Recall the borrowing rules you learned in Chapter 4:
 
@@ -16,6 +18,8 @@ Unlike Rc<T>, the RefCell<T> type represents single ownership over the data it h
 */
 
 
+
+
 /*
 Similar to Rc<T>, RefCell<T> is only for use in single-threaded scenarios and will give you a compile-time error if you try using it in a multithreaded context. We’ll talk about how to get the functionality of RefCell<T> in a multithreaded program in Chapter 16.
 
@@ -30,10 +34,10 @@ Here is a recap of the reasons to choose Box<T>, Rc<T>, or RefCell<T>:
 
 
 
+// Interior Mutability: A Mutable Borrow to an Immutable Value
 
 
 
-// Interior Mutability: A Mutable Borrow to an Immutable Value
 
 /*
 A consequence of the borrowing rules is that when you have an immutable value, you can’t borrow it mutably. For example, this code won’t compile:
@@ -46,6 +50,8 @@ fn main() {
 */
 
 
+
+
 /*
 If you tried to compile this code, you’d get the following error:
 
@@ -66,51 +72,54 @@ error: could not compile `borrowing` due to previous error
 
 
 
+// A Use Case for Interior Mutability: Mock Objects
 
 
 
-// A Use Case for Interior Mutability: Mock Objects
 
 /*
 Our library will only provide the functionality of tracking how close to the maximum a value is and what the messages should be at what times. Applications that use our library will be expected to provide the mechanism for sending the messages: the application could put a message in the application, send an email, send a text message, or something else. The library doesn’t need to know that detail. All it needs is something that implements a trait we’ll provide called Messenger. Listing 15-20 shows the library code:
 
 Filename: src/lib.rs
 */
-pub trait Messenger {
-    fn send(&self, msg: &str);
-}
 
-pub struct LimitTracker<'a, T: Messenger> {
-    messenger: &'a T,
-    value: usize,
-    max: usize,
-}
+pub mod listing_15_20 {
+    pub trait Messenger {
+        fn send(&self, msg: &str);
+    }
 
-impl<'a, T> LimitTracker<'a, T>
-where
-    T: Messenger,
-{
-    pub fn new(messenger: &'a T, max: usize) -> LimitTracker<'a, T> {
-        LimitTracker {
-            messenger,
-            value: 0,
-            max,
-        }
+    pub struct LimitTracker<'a, T: Messenger> {
+        messenger: &'a T,
+        value: usize,
+        max: usize,
     }
 
-    pub fn set_value(&mut self, value: usize) {
-        self.value = value;
+    impl<'a, T> LimitTracker<'a, T>
+    where
+        T: Messenger,
+    {
+        pub fn new(messenger: &'a T, max: usize) -> LimitTracker<'a, T> {
+            LimitTracker {
+                messenger,
+                value: 0,
+                max,
+            }
+        }
+
+        pub fn set_value(&mut self, value: usize) {
+            self.value = value;
 
-        let percentage_of_max = self.value as f64 / self.max as f64;
+            let percentage_of_max = self.value as f64 / self.max as f64;
 
-        if percentage_of_max >= 1.0 {
-            self.messenger.send("Error: You are over your quota!");
-        } else if percentage_of_max >= 0.9 {
-            self.messenger
-                .send("Urgent warning: You've used up over 90% of your quota!");
-        } else if percentage_of_max >= 0.75 {
-            self.messenger
-                .send("Warning: You've used up over 75% of your quota!");
+            if percentage_of_max >= 1.0 {
+                self.messenger.send("Error: You are over your quota!");
+            } else if percentage_of_max >= 0.9 {
+                self.messenger
+                    .send("Urgent warning: You've used up over 90% of your quota!");
+            } else if percentage_of_max >= 0.75 {
+                self.messenger
+                    .send("Warning: You've used up over 75% of your quota!");
+            }
         }
     }
 }
@@ -118,85 +127,86 @@ where
 
 
 
-
-
-
 /*
 We need a mock object that, instead of sending an email or text message when we call send, will only keep track of the messages it’s told to send. We can create a new instance of the mock object, create a LimitTracker that uses the mock object, call the set_value method on LimitTracker, and then check that the mock object has the messages we expect. Listing 15-21 shows an attempt to implement a mock object to do just that, but the borrow checker won’t allow it:
 
 Filename: src/lib.rs
 */
-pub trait Messenger {
-    fn send(&self, msg: &str);
-}
-
-pub struct LimitTracker<'a, T: Messenger> {
-    messenger: &'a T,
-    value: usize,
-    max: usize,
-}
-
-impl<'a, T> LimitTracker<'a, T>
-where
-    T: Messenger,
-{
-    pub fn new(messenger: &'a T, max: usize) -> LimitTracker<'a, T> {
-        LimitTracker {
-            messenger,
-            value: 0,
-            max,
-        }
-    }
-
-    pub fn set_value(&mut self, value: usize) {
-        self.value = value;
-
-        let percentage_of_max = self.value as f64 / self.max as f64;
-
-        if percentage_of_max >= 1.0 {
-            self.messenger.send("Error: You are over your quota!");
-        } else if percentage_of_max >= 0.9 {
-            self.messenger
-                .send("Urgent warning: You've used up over 90% of your quota!");
-        } else if percentage_of_max >= 0.75 {
-            self.messenger
-                .send("Warning: You've used up over 75% of your quota!");
-        }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    struct MockMessenger {
-        sent_messages: Vec<String>,
-    }
-
-    impl MockMessenger {
-        fn new() -> MockMessenger {
-            MockMessenger {
-                sent_messages: vec![],
-            }
-        }
-    }
-
-    impl Messenger for MockMessenger {
-        fn send(&self, message: &str) {
-            self.sent_messages.push(String::from(message));
-        }
-    }
 
-    #[test]
-    fn it_sends_an_over_75_percent_warning_message() {
-        let mock_messenger = MockMessenger::new();
-        let mut limit_tracker = LimitTracker::new(&mock_messenger, 100);
-
-        limit_tracker.set_value(80);
-
-        assert_eq!(mock_messenger.sent_messages.len(), 1);
-    }
-}
+/// ```
+/// pub trait Messenger {
+///     fn send(&self, msg: &str);
+/// }
+///
+/// pub struct LimitTracker<'a, T: Messenger> {
+///     messenger: &'a T,
+///     value: usize,
+///     max: usize,
+/// }
+///
+/// impl<'a, T> LimitTracker<'a, T>
+/// where
+///     T: Messenger,
+/// {
+///     pub fn new(messenger: &'a T, max: usize) -> LimitTracker<'a, T> {
+///         LimitTracker {
+///             messenger,
+///             value: 0,
+///             max,
+///         }
+///     }
+///
+///     pub fn set_value(&mut self, value: usize) {
+///         self.value = value;
+///
+///         let percentage_of_max = self.value as f64 / self.max as f64;
+///
+///         if percentage_of_max >= 1.0 {
+///             self.messenger.send("Error: You are over your quota!");
+///         } else if percentage_of_max >= 0.9 {
+///             self.messenger
+///                 .send("Urgent warning: You've used up over 90% of your quota!");
+///         } else if percentage_of_max >= 0.75 {
+///             self.messenger
+///                 .send("Warning: You've used up over 75% of your quota!");
+///         }
+///     }
+/// }
+///
+/// #[cfg(test)]
+/// mod tests {
+///     use super::*;
+///
+///     struct MockMessenger {
+///         sent_messages: Vec<String>,
+///     }
+///
+///     impl MockMessenger {
+///         fn new() -> MockMessenger {
+///             MockMessenger {
+///                 sent_messages: vec![],
+///             }
+///         }
+///     }
+///
+///     impl Messenger for MockMessenger {
+///         fn send(&self, message: &str) {
+///             self.sent_messages.push(String::from(message));
+///         }
+///     }
+///
+///     #[test]
+///     fn it_sends_an_over_75_percent_warning_message() {
+///         let mock_messenger = MockMessenger::new();
+///         let mut limit_tracker = LimitTracker::new(&mock_messenger, 100);
+///
+///         limit_tracker.set_value(80);
+///
+///         assert_eq!(mock_messenger.sent_messages.len(), 1);
+///     }
+/// }
+/// ```
+pub mod listing_15_21 {}
 
 
 
@@ -228,101 +238,120 @@ This is a situation in which interior mutability can help! We’ll store the sen
 
 Filename: src/lib.rs
 */
-pub trait Messenger {
-    fn send(&self, msg: &str);
-}
 
-pub struct LimitTracker<'a, T: Messenger> {
-    messenger: &'a T,
-    value: usize,
-    max: usize,
-}
+pub mod listing_15_22 {
+    pub trait Messenger {
+        fn send(&self, msg: &str);
+    }
 
-impl<'a, T> LimitTracker<'a, T>
-where
-    T: Messenger,
-{
-    pub fn new(messenger: &'a T, max: usize) -> LimitTracker<'a, T> {
-        LimitTracker {
-            messenger,
-            value: 0,
-            max,
-        }
+    pub struct LimitTracker<'a, T: Messenger> {
+        messenger: &'a T,
+        value: usize,
+        max: usize,
     }
 
-    pub fn set_value(&mut self, value: usize) {
-        self.value = value;
+    impl<'a, T> LimitTracker<'a, T>
+    where
+        T: Messenger,
+    {
+        pub fn new(messenger: &'a T, max: usize) -> LimitTracker<'a, T> {
+            LimitTracker {
+                messenger,
+                value: 0,
+                max,
+            }
+        }
 
-        let percentage_of_max = self.value as f64 / self.max as f64;
+        pub fn set_value(&mut self, value: usize) {
+            self.value = value;
 
-        if percentage_of_max >= 1.0 {
-            self.messenger.send("Error: You are over your quota!");
-        } else if percentage_of_max >= 0.9 {
-            self.messenger
-                .send("Urgent warning: You've used up over 90% of your quota!");
-        } else if percentage_of_max >= 0.75 {
-            self.messenger
-                .send("Warning: You've used up over 75% of your quota!");
+            let percentage_of_max = self.value as f64 / self.max as f64;
+
+            if percentage_of_max >= 1.0 {
+                self.messenger.send("Error: You are over your quota!");
+            } else if percentage_of_max >= 0.9 {
+                self.messenger
+                    .send("Urgent warning: You've used up over 90% of your quota!");
+            } else if percentage_of_max >= 0.75 {
+                self.messenger
+                    .send("Warning: You've used up over 75% of your quota!");
+            }
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::cell::RefCell;
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::cell::RefCell;
 
-    struct MockMessenger {
-        sent_messages: RefCell<Vec<String>>,
-    }
+        struct MockMessenger {
+            sent_messages: RefCell<Vec<String>>,
+        }
 
-    impl MockMessenger {
-        fn new() -> MockMessenger {
-            MockMessenger {
-                sent_messages: RefCell::new(vec![]),
+        impl MockMessenger {
+            fn new() -> MockMessenger {
+                MockMessenger {
+                    sent_messages: RefCell::new(vec![]),
+                }
             }
         }
-    }
 
-    impl Messenger for MockMessenger {
-        fn send(&self, message: &str) {
-            self.sent_messages.borrow_mut().push(String::from(message));
+        impl Messenger for MockMessenger {
+            fn send(&self, message: &str) {
+                self.sent_messages.borrow_mut().push(String::from(message));
+            }
         }
-    }
 
-    #[test]
-    fn it_sends_an_over_75_percent_warning_message() {
-        // --snip--
-        let mock_messenger = MockMessenger::new();
-        let mut limit_tracker = LimitTracker::new(&mock_messenger, 100);
+        #[test]
+        fn it_sends_an_over_75_percent_warning_message() {
+            // --snip--
+            let mock_messenger = MockMessenger::new();
+            let mut limit_tracker = LimitTracker::new(&mock_messenger, 100);
 
-        limit_tracker.set_value(80);
+            limit_tracker.set_value(80);
 
-        assert_eq!(mock_messenger.sent_messages.borrow().len(), 1);
+            assert_eq!(mock_messenger.sent_messages.borrow().len(), 1);
+        }
     }
 }
 
 
 
 
+// Keeping Track of Borrows at Runtime with RefCell<T>
+
 
 
-// Keeping Track of Borrows at Runtime with RefCell<T>
 
 /*
 If we try to violate these rules, rather than getting a compiler error as we would with references, the implementation of RefCell<T> will panic at runtime. Listing 15-23 shows a modification of the implementation of send in Listing 15-22. We’re deliberately trying to create two mutable borrows active for the same scope to illustrate that RefCell<T> prevents us from doing this at runtime.
 
 Filename: src/lib.rs
 */
-    impl Messenger for MockMessenger {
-        fn send(&self, message: &str) {
-            let mut one_borrow = self.sent_messages.borrow_mut();
-            let mut two_borrow = self.sent_messages.borrow_mut();
 
-            one_borrow.push(String::from(message));
-            two_borrow.push(String::from(message));
-        }
-    }
+/// ```
+/// # use std::cell::RefCell;
+/// #
+/// # pub trait Messenger {
+/// #     fn send(&self, msg: &str);
+/// # }
+/// #
+/// # struct MockMessenger {
+/// #     sent_messages: RefCell<Vec<String>>,
+/// # }
+/// #
+///     impl Messenger for MockMessenger {
+///         fn send(&self, message: &str) {
+///             let mut one_borrow = self.sent_messages.borrow_mut();
+///             let mut two_borrow = self.sent_messages.borrow_mut();
+///
+///             one_borrow.push(String::from(message));
+///             two_borrow.push(String::from(message));
+///         }
+///     }
+/// ```
+pub mod listing_15_23 {}
+
 
 
 
@@ -355,39 +384,42 @@ error: test failed, to rerun pass '--lib'
 
 
 
+// Having Multiple Owners of Mutable Data by Combining Rc<T> and RefCell<T>
 
 
 
-// Having Multiple Owners of Mutable Data by Combining Rc<T> and RefCell<T>
 
 /*
 For example, recall the cons list example in Listing 15-18 where we used Rc<T> to allow multiple lists to share ownership of another list. Because Rc<T> holds only immutable values, we can’t change any of the values in the list once we’ve created them. Let’s add in RefCell<T> to gain the ability to change the values in the lists. Listing 15-24 shows that by using a RefCell<T> in the Cons definition, we can modify the value stored in all the lists:
 
 Filename: src/main.rs
 */
-#[derive(Debug)]
-enum List {
-    Cons(Rc<RefCell<i32>>, Rc<List>),
-    Nil,
-}
 
-use crate::List::{Cons, Nil};
-use std::cell::RefCell;
-use std::rc::Rc;
+pub mod listing_15_24 {
+    #[derive(Debug)]
+    enum List {
+        Cons(Rc<RefCell<i32>>, Rc<List>),
+        Nil,
+    }
 
-fn main() {
-    let value = Rc::new(RefCell::new(5));
+    use crate::trpl::ch15_smart_pointers::interior_mutability::listing_15_24::List::{Cons, Nil};
+    use std::cell::RefCell;
+    use std::rc::Rc;
 
-    let a = Rc::new(Cons(Rc::clone(&value), Rc::new(Nil)));
+    pub fn main() {
+        let value = Rc::new(RefCell::new(5));
 
-    let b = Cons(Rc::new(RefCell::new(3)), Rc::clone(&a));
-    let c = Cons(Rc::new(RefCell::new(4)), Rc::clone(&a));
+        let a = Rc::new(Cons(Rc::clone(&value), Rc::new(Nil)));
 
-    *value.borrow_mut() += 10;
+        let b = Cons(Rc::new(RefCell::new(3)), Rc::clone(&a));
+        let c = Cons(Rc::new(RefCell::new(4)), Rc::clone(&a));
 
-    println!("a after = {:?}", a);
-    println!("b after = {:?}", b);
-    println!("c after = {:?}", c);
+        *value.borrow_mut() += 10;
+
+        println!("a after = {:?}", a);
+        println!("b after = {:?}", b);
+        println!("c after = {:?}", c);
+    }
 }
 
 
@@ -406,6 +438,3 @@ a after = Cons(RefCell { value: 15 }, Nil)
 b after = Cons(RefCell { value: 3 }, Cons(RefCell { value: 15 }, Nil))
 c after = Cons(RefCell { value: 4 }, Cons(RefCell { value: 15 }, Nil))
 */
-
-
-
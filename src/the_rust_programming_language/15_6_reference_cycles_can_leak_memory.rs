@@ -6,33 +6,37 @@
 
 // Creating a Reference Cycle
 
+
+
+
 /*
 Let’s look at how a reference cycle might happen and how to prevent it, starting with the definition of the List enum and a tail method in Listing 15-25:
 
 Filename: src/main.rs
 */
-use crate::List::{Cons, Nil};
-use std::cell::RefCell;
-use std::rc::Rc;
-
-#[derive(Debug)]
-enum List {
-    Cons(i32, RefCell<Rc<List>>),
-    Nil,
-}
 
-impl List {
-    fn tail(&self) -> Option<&RefCell<Rc<List>>> {
-        match self {
-            Cons(_, item) => Some(item),
-            Nil => None,
-        }
-    }
-}
+pub mod listing_15_25 {
+    use crate::trpl::ch15_smart_pointers::reference_cycles::listing_15_25::List::{Cons, Nil};
+    use std::cell::RefCell;
+    use std::rc::Rc;
 
-fn main() {}
+    #[derive(Debug)]
+    enum List {
+        Cons(i32, RefCell<Rc<List>>),
+        Nil,
+    }
 
+    impl List {
+        fn tail(&self) -> Option<&RefCell<Rc<List>>> {
+            match self {
+                Cons(_, item) => Some(item),
+                Nil => None,
+            }
+        }
+    }
 
+    pub fn main() {}
+}
 
 
 
@@ -42,33 +46,52 @@ In Listing 15-26, we’re adding a main function that uses the definitions in Li
 
 Filename: src/main.rs
 */
-fn main() {
-    let a = Rc::new(Cons(5, RefCell::new(Rc::new(Nil))));
-
-    println!("a initial rc count = {}", Rc::strong_count(&a));
-    println!("a next item = {:?}", a.tail());
-
-    let b = Rc::new(Cons(10, RefCell::new(Rc::clone(&a))));
-
-    println!("a rc count after b creation = {}", Rc::strong_count(&a));
-    println!("b initial rc count = {}", Rc::strong_count(&b));
-    println!("b next item = {:?}", b.tail());
-
-    if let Some(link) = a.tail() {
-        *link.borrow_mut() = Rc::clone(&b);
-    }
-
-    println!("b rc count after changing a = {}", Rc::strong_count(&b));
-    println!("a rc count after changing a = {}", Rc::strong_count(&a));
-
-    // Uncomment the next line to see that we have a cycle;
-    // it will overflow the stack
-    // println!("a next item = {:?}", a.tail());
-}
-
-
-
 
+/// ```
+/// # use crate::List::{Cons, Nil};
+/// # use std::cell::RefCell;
+/// # use std::rc::Rc;
+/// #
+/// # #[derive(Debug)]
+/// # enum List {
+/// #     Cons(i32, RefCell<Rc<List>>),
+/// #     Nil,
+/// # }
+/// #
+/// # impl List {
+/// #     fn tail(&self) -> Option<&RefCell<Rc<List>>> {
+/// #         match self {
+/// #             Cons(_, item) => Some(item),
+/// #             Nil => None,
+/// #         }
+/// #     }
+/// # }
+/// #
+/// fn main() {
+///     let a = Rc::new(Cons(5, RefCell::new(Rc::new(Nil))));
+///
+///     println!("a initial rc count = {}", Rc::strong_count(&a));
+///     println!("a next item = {:?}", a.tail());
+///
+///     let b = Rc::new(Cons(10, RefCell::new(Rc::clone(&a))));
+///
+///     println!("a rc count after b creation = {}", Rc::strong_count(&a));
+///     println!("b initial rc count = {}", Rc::strong_count(&b));
+///     println!("b next item = {:?}", b.tail());
+///
+///     if let Some(link) = a.tail() {
+///         *link.borrow_mut() = Rc::clone(&b);
+///     }
+///
+///     println!("b rc count after changing a = {}", Rc::strong_count(&b));
+///     println!("a rc count after changing a = {}", Rc::strong_count(&a));
+///
+///     // Uncomment the next line to see that we have a cycle;
+///     // it will overflow the stack
+///     // println!("a next item = {:?}", a.tail());
+/// }
+/// ```
+pub mod example_02 {}
 
 
 
@@ -94,24 +117,26 @@ a rc count after changing a = 2
 
 
 
+// Creating a Tree Data Structure: a Node with Child Nodes
 
 
 
 
-// Creating a Tree Data Structure: a Node with Child Nodes
-
 /*
 To start, we’ll build a tree with nodes that know about their child nodes. We’ll create a struct named Node that holds its own i32 value as well as references to its children Node values:
 
 Filename: src/main.rs
 */
-use std::cell::RefCell;
-use std::rc::Rc;
 
-#[derive(Debug)]
-struct Node {
-    value: i32,
-    children: RefCell<Vec<Rc<Node>>>,
+pub mod example_03 {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[derive(Debug)]
+    struct Node {
+        value: i32,
+        children: RefCell<Vec<Rc<Node>>>,
+    }
 }
 
 
@@ -122,24 +147,39 @@ Next, we’ll use our struct definition and create one Node instance named leaf
 
 Filename: src/main.rs
 */
-fn main() {
-    let leaf = Rc::new(Node {
-        value: 3,
-        children: RefCell::new(vec![]),
-    });
-
-    let branch = Rc::new(Node {
-        value: 5,
-        children: RefCell::new(vec![Rc::clone(&leaf)]),
-    });
-}
 
+/// ```
+/// # use std::cell::RefCell;
+/// # use std::rc::Rc;
+/// #
+/// # #[derive(Debug)]
+/// # struct Node {
+/// #     value: i32,
+/// #     children: RefCell<Vec<Rc<Node>>>,
+/// # }
+/// #
+/// fn main() {
+///     let leaf = Rc::new(Node {
+///         value: 3,
+///         children: RefCell::new(vec![]),
+///     });
+///
+///     let branch = Rc::new(Node {
+///         value: 5,
+///         children: RefCell::new(vec![Rc::clone(&leaf)]),
+///     });
+/// }
+/// ```
+pub mod listing_15_27 {}
 
 
 
 
 // Adding a Reference from a Child to Its Parent
 
+
+
+
 /*
 Thinking about the relationships another way, a parent node should own its children: if a parent node is dropped, its child nodes should be dropped as well. However, a child should not own its parent: if we drop a child node, the parent should still exist. This is a case for weak references!
 
@@ -147,16 +187,18 @@ So instead of Rc<T>, we’ll make the type of parent use Weak<T>, specifically a
 
 Filename: src/main.rs
 */
-use std::cell::RefCell;
-use std::rc::{Rc, Weak};
-
-#[derive(Debug)]
-struct Node {
-    value: i32,
-    parent: RefCell<Weak<Node>>,
-    children: RefCell<Vec<Rc<Node>>>,
-}
 
+pub mod example_05 {
+    use std::cell::RefCell;
+    use std::rc::{Rc, Weak};
+
+    #[derive(Debug)]
+    struct Node {
+        value: i32,
+        parent: RefCell<Weak<Node>>,
+        children: RefCell<Vec<Rc<Node>>>,
+    }
+}
 
 
 
@@ -166,25 +208,39 @@ A node will be able to refer to its parent node but doesn’t own its parent. In
 
 Filename: src/main.rs
 */
-fn main() {
-    let leaf = Rc::new(Node {
-        value: 3,
-        parent: RefCell::new(Weak::new()),
-        children: RefCell::new(vec![]),
-    });
-
-    println!("leaf parent = {:?}", leaf.parent.borrow().upgrade());
 
-    let branch = Rc::new(Node {
-        value: 5,
-        parent: RefCell::new(Weak::new()),
-        children: RefCell::new(vec![Rc::clone(&leaf)]),
-    });
-
-    *leaf.parent.borrow_mut() = Rc::downgrade(&branch);
-
-    println!("leaf parent = {:?}", leaf.parent.borrow().upgrade());
-}
+/// ```
+/// # use std::cell::RefCell;
+/// # use std::rc::{Rc, Weak};
+/// #
+/// # #[derive(Debug)]
+/// # struct Node {
+/// #     value: i32,
+/// #     parent: RefCell<Weak<Node>>,
+/// #     children: RefCell<Vec<Rc<Node>>>,
+/// # }
+/// #
+/// fn main() {
+///     let leaf = Rc::new(Node {
+///         value: 3,
+///         parent: RefCell::new(Weak::new()),
+///         children: RefCell::new(vec![]),
+///     });
+///
+///     println!("leaf parent = {:?}", leaf.parent.borrow().upgrade());
+///
+///     let branch = Rc::new(Node {
+///         value: 5,
+///         parent: RefCell::new(Weak::new()),
+///         children: RefCell::new(vec![Rc::clone(&leaf)]),
+///     });
+///
+///     *leaf.parent.borrow_mut() = Rc::downgrade(&branch);
+///
+///     println!("leaf parent = {:?}", leaf.parent.borrow().upgrade());
+/// }
+/// ```
+pub mod listing_15_28 {}
 
 
 
@@ -197,6 +253,7 @@ leaf parent = None
 
 
 
+
 /*
 When we print the parent of leaf again, this time we’ll get a Some variant holding branch: now leaf can access its parent! When we print leaf, we also avoid the cycle that eventually ended in a stack overflow like we had in Listing 15-26; the Weak<Node> references are printed as (Weak):
 
@@ -208,57 +265,69 @@ children: RefCell { value: [] } }] } })
 
 
 
+// Visualizing Changes to strong_count and weak_count
+
 
 
-// Visualizing Changes to strong_count and weak_count
 
 /*
 Let’s look at how the strong_count and weak_count values of the Rc<Node> instances change by creating a new inner scope and moving the creation of branch into that scope. By doing so, we can see what happens when branch is created and then dropped when it goes out of scope. The modifications are shown in Listing 15-29:
 
 Filename: src/main.rs
 */
-fn main() {
-    let leaf = Rc::new(Node {
-        value: 3,
-        parent: RefCell::new(Weak::new()),
-        children: RefCell::new(vec![]),
-    });
-
-    println!(
-        "leaf strong = {}, weak = {}",
-        Rc::strong_count(&leaf),
-        Rc::weak_count(&leaf),
-    );
-
-    {
-        let branch = Rc::new(Node {
-            value: 5,
-            parent: RefCell::new(Weak::new()),
-            children: RefCell::new(vec![Rc::clone(&leaf)]),
-        });
-
-        *leaf.parent.borrow_mut() = Rc::downgrade(&branch);
-
-        println!(
-            "branch strong = {}, weak = {}",
-            Rc::strong_count(&branch),
-            Rc::weak_count(&branch),
-        );
-
-        println!(
-            "leaf strong = {}, weak = {}",
-            Rc::strong_count(&leaf),
-            Rc::weak_count(&leaf),
-        );
-    }
-
-    println!("leaf parent = {:?}", leaf.parent.borrow().upgrade());
-    println!(
-        "leaf strong = {}, weak = {}",
-        Rc::strong_count(&leaf),
-        Rc::weak_count(&leaf),
-    );
-}
-
-
 
+/// ```
+/// # use std::cell::RefCell;
+/// # use std::rc::{Rc, Weak};
+/// #
+/// # #[derive(Debug)]
+/// # struct Node {
+/// #     value: i32,
+/// #     parent: RefCell<Weak<Node>>,
+/// #     children: RefCell<Vec<Rc<Node>>>,
+/// # }
+/// #
+/// fn main() {
+///     let leaf = Rc::new(Node {
+///         value: 3,
+///         parent: RefCell::new(Weak::new()),
+///         children: RefCell::new(vec![]),
+///     });
+///
+///     println!(
+///         "leaf strong = {}, weak = {}",
+///         Rc::strong_count(&leaf),
+///         Rc::weak_count(&leaf),
+///     );
+///
+///     {
+///         let branch = Rc::new(Node {
+///             value: 5,
+///             parent: RefCell::new(Weak::new()),
+///             children: RefCell::new(vec![Rc::clone(&leaf)]),
+///         });
+///
+///         *leaf.parent.borrow_mut() = Rc::downgrade(&branch);
+///
+///         println!(
+///             "branch strong = {}, weak = {}",
+///             Rc::strong_count(&branch),
+///             Rc::weak_count(&branch),
+///         );
+///
+///         println!(
+///             "leaf strong = {}, weak = {}",
+///             Rc::strong_count(&leaf),
+///             Rc::weak_count(&leaf),
+///         );
+///     }
+///
+///     println!("leaf parent = {:?}", leaf.parent.borrow().upgrade());
+///     println!(
+///         "leaf strong = {}, weak = {}",
+///         Rc::strong_count(&leaf),
+///         Rc::weak_count(&leaf),
+///     );
+/// }
+/// ```
+pub mod listing_15_29 {}
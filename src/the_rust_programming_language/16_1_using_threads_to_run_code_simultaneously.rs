@@ -3,27 +3,34 @@
 
 
 
+
 // Creating a New Thread with spawn
 
+
+
+
 /*
 To create a new thread, we call the thread::spawn function and pass it a closure (we talked about closures in Chapter 13) containing the code we want to run in the new thread. The example in Listing 16-1 prints some text from a main thread and other text from a new thread:
 
 Filename: src/main.rs
 */
-use std::thread;
-use std::time::Duration;
 
-fn main() {
-    thread::spawn(|| {
-        for i in 1..10 {
-            println!("hi number {} from the spawned thread!", i);
+pub mod listing_16_01 {
+    use std::thread;
+    use std::time::Duration;
+
+    pub fn main() {
+        thread::spawn(|| {
+            for i in 1..10 {
+                println!("hi number {} from the spawned thread!", i);
+                thread::sleep(Duration::from_millis(1));
+            }
+        });
+
+        for i in 1..5 {
+            println!("hi number {} from the main thread!", i);
             thread::sleep(Duration::from_millis(1));
         }
-    });
-
-    for i in 1..5 {
-        println!("hi number {} from the main thread!", i);
-        thread::sleep(Duration::from_millis(1));
     }
 }
 
@@ -47,37 +54,36 @@ hi number 5 from the spawned thread!
 
 
 
+// Waiting for All Threads to Finish Using join Handles
 
 
 
 
-
-
-
-// Waiting for All Threads to Finish Using join Handles
-
 /*
 We can fix the problem of the spawned thread not running or ending prematurely by saving the return value of thread::spawn in a variable. The return type of thread::spawn is JoinHandle. A JoinHandle is an owned value that, when we call the join method on it, will wait for its thread to finish. Listing 16-2 shows how to use the JoinHandle of the thread we created in Listing 16-1 and call join to make sure the spawned thread finishes before main exits:
 
 Filename: src/main.rs
 */
-use std::thread;
-use std::time::Duration;
 
-fn main() {
-    let handle = thread::spawn(|| {
-        for i in 1..10 {
-            println!("hi number {} from the spawned thread!", i);
+pub mod listing_16_02 {
+    use std::thread;
+    use std::time::Duration;
+
+    pub fn main() {
+        let handle = thread::spawn(|| {
+            for i in 1..10 {
+                println!("hi number {} from the spawned thread!", i);
+                thread::sleep(Duration::from_millis(1));
+            }
+        });
+
+        for i in 1..5 {
+            println!("hi number {} from the main thread!", i);
             thread::sleep(Duration::from_millis(1));
         }
-    });
 
-    for i in 1..5 {
-        println!("hi number {} from the main thread!", i);
-        thread::sleep(Duration::from_millis(1));
+        handle.join().unwrap();
     }
-
-    handle.join().unwrap();
 }
 
 
@@ -111,30 +117,31 @@ But let’s see what happens when we instead move handle.join() before the for l
 
 Filename: src/main.rs
 */
-use std::thread;
-use std::time::Duration;
 
-fn main() {
-    let handle = thread::spawn(|| {
-        for i in 1..10 {
-            println!("hi number {} from the spawned thread!", i);
-            thread::sleep(Duration::from_millis(1));
-        }
-    });
+pub mod example_03 {
+    use std::thread;
+    use std::time::Duration;
 
-    handle.join().unwrap();
+    pub fn main() {
+        let handle = thread::spawn(|| {
+            for i in 1..10 {
+                println!("hi number {} from the spawned thread!", i);
+                thread::sleep(Duration::from_millis(1));
+            }
+        });
 
-    for i in 1..5 {
-        println!("hi number {} from the main thread!", i);
-        thread::sleep(Duration::from_millis(1));
+        handle.join().unwrap();
+
+        for i in 1..5 {
+            println!("hi number {} from the main thread!", i);
+            thread::sleep(Duration::from_millis(1));
+        }
     }
 }
 
 
 
 
-
-
 /*
 The main thread will wait for the spawned thread to finish and then run its for loop, so the output won’t be interleaved anymore, as shown here:
 
@@ -156,28 +163,33 @@ hi number 4 from the main thread!
 
 
 
+// Using move Closures with Threads
 
 
 
-// Using move Closures with Threads
 
 /*
 Notice in Listing 16-1 that the closure we pass to thread::spawn takes no arguments: we’re not using any data from the main thread in the spawned thread’s code. To use data from the main thread in the spawned thread, the spawned thread’s closure must capture the values it needs. Listing 16-3 shows an attempt to create a vector in the main thread and use it in the spawned thread. However, this won’t yet work, as you’ll see in a moment.
 
 Filename: src/main.rs
 */
- [This code does not compile!] 
-use std::thread;
-
-fn main() {
-    let v = vec![1, 2, 3];
+// [This code does not compile!]
 
-    let handle = thread::spawn(|| {
-        println!("Here's a vector: {:?}", v);
-    });
+/// ```compile_fail
+/// use std::thread;
+///
+/// fn main() {
+///     let v = vec![1, 2, 3];
+///
+///     let handle = thread::spawn(|| {
+///         println!("Here's a vector: {:?}", v);
+///     });
+///
+///     handle.join().unwrap();
+/// }
+/// ```
+pub mod listing_16_03 {}
 
-    handle.join().unwrap();
-}
 
 
 
@@ -214,9 +226,6 @@ error: could not compile `threads` due to previous error
 
 
 
-
-
-
 /*
 Rust infers how to capture v, and because println! only needs a reference to v, the closure tries to borrow v. However, there’s a problem: Rust can’t tell how long the spawned thread will run, so it doesn’t know if the reference to v will always be valid.
 
@@ -224,20 +233,25 @@ Listing 16-4 provides a scenario that’s more likely to have a reference to v t
 
 Filename: src/main.rs
 */
- [This code does not compile!] 
-use std::thread;
+// [This code does not compile!]
 
-fn main() {
-    let v = vec![1, 2, 3];
+/// ```compile_fail
+/// use std::thread;
+///
+/// fn main() {
+///     let v = vec![1, 2, 3];
+///
+///     let handle = thread::spawn(|| {
+///         println!("Here's a vector: {:?}", v);
+///     });
+///
+///     drop(v); // oh no!
+///
+///     handle.join().unwrap();
+/// }
+/// ```
+pub mod listing_16_04 {}
 
-    let handle = thread::spawn(|| {
-        println!("Here's a vector: {:?}", v);
-    });
-
-    drop(v); // oh no!
-
-    handle.join().unwrap();
-}
 
 
 
@@ -253,27 +267,25 @@ help: to force the closure to take ownership of `v` (and any other referenced va
 
 
 
-
 /*
 By adding the move keyword before the closure, we force the closure to take ownership of the values it’s using rather than allowing Rust to infer that it should borrow the values. The modification to Listing 16-3 shown in Listing 16-5 will compile and run as we intend:
 
 Filename: src/main.rs
 */
-use std::thread;
-
-fn main() {
-    let v = vec![1, 2, 3];
-
-    let handle = thread::spawn(move || {
-        println!("Here's a vector: {:?}", v);
-    });
-
-    handle.join().unwrap();
-}
 
+pub mod listing_16_05 {
+    use std::thread;
 
+    pub fn main() {
+        let v = vec![1, 2, 3];
 
+        let handle = thread::spawn(move || {
+            println!("Here's a vector: {:?}", v);
+        });
 
+        handle.join().unwrap();
+    }
+}
 
 
 
@@ -300,10 +312,3 @@ error[E0382]: use of moved value: `v`
 For more information about this error, try `rustc --explain E0382`.
 error: could not compile `threads` due to previous error
 */
-
-
-
-
-
-
-
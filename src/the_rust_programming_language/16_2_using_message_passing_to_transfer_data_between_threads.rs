@@ -4,20 +4,20 @@
 
 
 
-
-
 /*
 First, in Listing 16-6, we’ll create a channel but not do anything with it. Note that this won’t compile yet because Rust can’t tell what type of values we want to send over the channel.
 
 Filename: src/main.rs
 */
-use std::sync::mpsc;
-
-fn main() {
-    let (tx, rx) = mpsc::channel();
-}
-
 
+/// ```compile_fail
+/// use std::sync::mpsc;
+///
+/// fn main() {
+///     let (tx, rx) = mpsc::channel();
+/// }
+/// ```
+pub mod listing_16_06 {}
 
 
 
@@ -27,16 +27,19 @@ Let’s move the transmitting end into a spawned thread and have it send one str
 
 Filename: src/main.rs
 */
-use std::sync::mpsc;
-use std::thread;
 
-fn main() {
-    let (tx, rx) = mpsc::channel();
+pub mod listing_16_07 {
+    use std::sync::mpsc;
+    use std::thread;
+
+    pub fn main() {
+        let (tx, rx) = mpsc::channel();
 
-    thread::spawn(move || {
-        let val = String::from("hi");
-        tx.send(val).unwrap();
-    });
+        thread::spawn(move || {
+            let val = String::from("hi");
+            tx.send(val).unwrap();
+        });
+    }
 }
 
 
@@ -47,19 +50,22 @@ In Listing 16-8, we’ll get the value from the receiver in the main thread. Thi
 
 Filename: src/main.rs
 */
-use std::sync::mpsc;
-use std::thread;
 
-fn main() {
-    let (tx, rx) = mpsc::channel();
+pub mod listing_16_08 {
+    use std::sync::mpsc;
+    use std::thread;
 
-    thread::spawn(move || {
-        let val = String::from("hi");
-        tx.send(val).unwrap();
-    });
+    pub fn main() {
+        let (tx, rx) = mpsc::channel();
 
-    let received = rx.recv().unwrap();
-    println!("Got: {}", received);
+        thread::spawn(move || {
+            let val = String::from("hi");
+            tx.send(val).unwrap();
+        });
+
+        let received = rx.recv().unwrap();
+        println!("Got: {}", received);
+    }
 }
 
 
@@ -76,31 +82,35 @@ Got: hi
 
 
 
+// Channels and Ownership Transference
 
 
 
-// Channels and Ownership Transference
 
 /*
 The ownership rules play a vital role in message sending because they help you write safe, concurrent code. Preventing errors in concurrent programming is the advantage of thinking about ownership throughout your Rust programs. Let’s do an experiment to show how channels and ownership work together to prevent problems: we’ll try to use a val value in the spawned thread after we’ve sent it down the channel. Try compiling the code in Listing 16-9 to see why this code isn’t allowed:
 
 Filename: src/main.rs
 */
-use std::sync::mpsc;
-use std::thread;
-
-fn main() {
-    let (tx, rx) = mpsc::channel();
-
-    thread::spawn(move || {
-        let val = String::from("hi");
-        tx.send(val).unwrap();
-        println!("val is {}", val);
-    });
 
-    let received = rx.recv().unwrap();
-    println!("Got: {}", received);
-}
+/// ```compile_fail
+/// use std::sync::mpsc;
+/// use std::thread;
+///
+/// fn main() {
+///     let (tx, rx) = mpsc::channel();
+///
+///     thread::spawn(move || {
+///         let val = String::from("hi");
+///         tx.send(val).unwrap();
+///         println!("val is {}", val);
+///     });
+///
+///     let received = rx.recv().unwrap();
+///     println!("Got: {}", received);
+/// }
+/// ```
+pub mod listing_16_09 {}
 
 
 
@@ -129,42 +139,48 @@ error: could not compile `message-passing` due to previous error
 
 
 
-
 // Sending Multiple Values and Seeing the Receiver Waiting
 
+
+
+
 /*
 The code in Listing 16-8 compiled and ran, but it didn’t clearly show us that two separate threads were talking to each other over the channel. In Listing 16-10 we’ve made some modifications that will prove the code in Listing 16-8 is running concurrently: the spawned thread will now send multiple messages and pause for a second between each message.
 
 Filename: src/main.rs
 */
-use std::sync::mpsc;
-use std::thread;
-use std::time::Duration;
-
-fn main() {
-    let (tx, rx) = mpsc::channel();
-
-    thread::spawn(move || {
-        let vals = vec![
-            String::from("hi"),
-            String::from("from"),
-            String::from("the"),
-            String::from("thread"),
-        ];
-
-        for val in vals {
-            tx.send(val).unwrap();
-            thread::sleep(Duration::from_secs(1));
-        }
-    });
 
-    for received in rx {
-        println!("Got: {}", received);
+pub mod example_05 {
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    pub fn main() {
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let vals = vec![
+                String::from("hi"),
+                String::from("from"),
+                String::from("the"),
+                String::from("thread"),
+            ];
+
+            for val in vals {
+                tx.send(val).unwrap();
+                thread::sleep(Duration::from_secs(1));
+            }
+        });
+
+        for received in rx {
+            println!("Got: {}", received);
+        }
     }
 }
 
 
 
+
 /*
 When running the code in Listing 16-10, you should see the following output with a 1-second pause in between each line:
 
@@ -179,55 +195,62 @@ Because we don’t have any code that pauses or delays in the for loop in the ma
 
 
 
+// Creating Multiple Producers by Cloning the Transmitter
 
 
 
-// Creating Multiple Producers by Cloning the Transmitter
 
 /*
 Earlier we mentioned that mpsc was an acronym for multiple producer, single consumer. Let’s put mpsc to use and expand the code in Listing 16-10 to create multiple threads that all send values to the same receiver. We can do so by cloning the transmitter, as shown in Listing 16-11:
 
 Filename: src/main.rs
 */
-    // --snip--
-
-    let (tx, rx) = mpsc::channel();
-
-    let tx1 = tx.clone();
-    thread::spawn(move || {
-        let vals = vec![
-            String::from("hi"),
-            String::from("from"),
-            String::from("the"),
-            String::from("thread"),
-        ];
-
-        for val in vals {
-            tx1.send(val).unwrap();
-            thread::sleep(Duration::from_secs(1));
-        }
-    });
-
-    thread::spawn(move || {
-        let vals = vec![
-            String::from("more"),
-            String::from("messages"),
-            String::from("for"),
-            String::from("you"),
-        ];
-
-        for val in vals {
-            tx.send(val).unwrap();
-            thread::sleep(Duration::from_secs(1));
-        }
-    });
-
-    for received in rx {
-        println!("Got: {}", received);
-    }
-
-    // --snip--
 
+/// ```
+/// # use std::sync::mpsc;
+/// # use std::thread;
+/// # use std::time::Duration;
+/// #
+///     // --snip--
+///
+///     let (tx, rx) = mpsc::channel();
+///
+///     let tx1 = tx.clone();
+///     thread::spawn(move || {
+///         let vals = vec![
+///             String::from("hi"),
+///             String::from("from"),
+///             String::from("the"),
+///             String::from("thread"),
+///         ];
+///
+///         for val in vals {
+///             tx1.send(val).unwrap();
+///             thread::sleep(Duration::from_secs(1));
+///         }
+///     });
+///
+///     thread::spawn(move || {
+///         let vals = vec![
+///             String::from("more"),
+///             String::from("messages"),
+///             String::from("for"),
+///             String::from("you"),
+///         ];
+///
+///         for val in vals {
+///             tx.send(val).unwrap();
+///             thread::sleep(Duration::from_secs(1));
+///         }
+///     });
+///
+///     for received in rx {
+///         println!("Got: {}", received);
+///     }
+///
+///     // --snip--
+/// ```
+pub mod listing_16_11 {}
 
 
 
@@ -246,7 +269,3 @@ Got: the
 Got: thread
 Got: you
 */
-
-
-
-
@@ -4,9 +4,11 @@
 
 
 
-
 // Using Mutexes to Allow Access to Data from One Thread at a Time
 
+
+
+
 /*
 Mutex is an abbreviation for mutual exclusion, as in, a mutex allows only one thread to access some data at any given time. To access the data in a mutex, a thread must first signal that it wants access by asking to acquire the mutex’s lock. The lock is a data structure that is part of the mutex that keeps track of who currently has exclusive access to the data. Therefore, the mutex is described as guarding the data it holds via the locking system.
 
@@ -20,72 +22,72 @@ Mutexes have a reputation for being difficult to use because you have to remembe
 
 
 
+// The API of Mutex<T>
 
 
 
 
-
-
-// The API of Mutex<T>
-
 /*
 As an example of how to use a mutex, let’s start by using a mutex in a single-threaded context, as shown in Listing 16-12:
 
 Filename: src/main.rs
 */
-use std::sync::Mutex;
 
-fn main() {
-    let m = Mutex::new(5);
+pub mod listing_16_12 {
+    use std::sync::Mutex;
 
-    {
-        let mut num = m.lock().unwrap();
-        *num = 6;
-    }
+    pub fn main() {
+        let m = Mutex::new(5);
 
-    println!("m = {:?}", m);
-}
+        {
+            let mut num = m.lock().unwrap();
+            *num = 6;
+        }
 
+        println!("m = {:?}", m);
+    }
+}
 
 
 
 
+// Sharing a Mutex<T> Between Multiple Threads
 
 
 
-// Sharing a Mutex<T> Between Multiple Threads
 
 /*
 Now, let’s try to share a value between multiple threads using Mutex<T>. We’ll spin up 10 threads and have them each increment a counter value by 1, so the counter goes from 0 to 10. The next example in Listing 16-13 will have a compiler error, and we’ll use that error to learn more about using Mutex<T> and how Rust helps us use it correctly.
 
 Filename: src/main.rs
 */
- [This code does not compile!] 
-use std::sync::Mutex;
-use std::thread;
-
-fn main() {
-    let counter = Mutex::new(0);
-    let mut handles = vec![];
-
-    for _ in 0..10 {
-        let handle = thread::spawn(move || {
-            let mut num = counter.lock().unwrap();
-
-            *num += 1;
-        });
-        handles.push(handle);
-    }
-
-    for handle in handles {
-        handle.join().unwrap();
-    }
-
-    println!("Result: {}", *counter.lock().unwrap());
-}
-
-
-
+// [This code does not compile!]
+
+/// ```compile_fail
+/// use std::sync::Mutex;
+/// use std::thread;
+///
+/// fn main() {
+///     let counter = Mutex::new(0);
+///     let mut handles = vec![];
+///
+///     for _ in 0..10 {
+///         let handle = thread::spawn(move || {
+///             let mut num = counter.lock().unwrap();
+///
+///             *num += 1;
+///         });
+///         handles.push(handle);
+///     }
+///
+///     for handle in handles {
+///         handle.join().unwrap();
+///     }
+///
+///     println!("Result: {}", *counter.lock().unwrap());
+/// }
+/// ```
+pub mod listing_16_13 {}
 
 
 
@@ -115,42 +117,46 @@ error: could not compile `shared-state` due to previous error
 
 
 
+// Multiple Ownership with Multiple Threads
 
 
 
 
-// Multiple Ownership with Multiple Threads
-
 /*
 In Chapter 15, we gave a value multiple owners by using the smart pointer Rc<T> to create a reference counted value. Let’s do the same here and see what happens. We’ll wrap the Mutex<T> in Rc<T> in Listing 16-14 and clone the Rc<T> before moving ownership to the thread.
 
 Filename: src/main.rs
 */
- [This code does not compile!] 
-use std::rc::Rc;
-use std::sync::Mutex;
-use std::thread;
-
-fn main() {
-    let counter = Rc::new(Mutex::new(0));
-    let mut handles = vec![];
-
-    for _ in 0..10 {
-        let counter = Rc::clone(&counter);
-        let handle = thread::spawn(move || {
-            let mut num = counter.lock().unwrap();
-
-            *num += 1;
-        });
-        handles.push(handle);
-    }
+// [This code does not compile!]
+
+/// ```compile_fail
+/// use std::rc::Rc;
+/// use std::sync::Mutex;
+/// use std::thread;
+///
+/// fn main() {
+///     let counter = Rc::new(Mutex::new(0));
+///     let mut handles = vec![];
+///
+///     for _ in 0..10 {
+///         let counter = Rc::clone(&counter);
+///         let handle = thread::spawn(move || {
+///             let mut num = counter.lock().unwrap();
+///
+///             *num += 1;
+///         });
+///         handles.push(handle);
+///     }
+///
+///     for handle in handles {
+///         handle.join().unwrap();
+///     }
+///
+///     println!("Result: {}", *counter.lock().unwrap());
+/// }
+/// ```
+pub mod listing_16_14 {}
 
-    for handle in handles {
-        handle.join().unwrap();
-    }
-
-    println!("Result: {}", *counter.lock().unwrap());
-}
 
 
 
@@ -183,40 +189,46 @@ error: could not compile `shared-state` due to previous error
 
 
 
+// Atomic Reference Counting with Arc<T>
+
 
 
-// Atomic Reference Counting with Arc<T>
 
 /*
 Let’s return to our example: Arc<T> and Rc<T> have the same API, so we fix our program by changing the use line, the call to new, and the call to clone. The code in Listing 16-15 will finally compile and run:
 
 Filename: src/main.rs
 */
-use std::sync::{Arc, Mutex};
-use std::thread;
 
-fn main() {
-    let counter = Arc::new(Mutex::new(0));
-    let mut handles = vec![];
+pub mod listing_16_15 {
+    use std::sync::{Arc, Mutex};
+    use std::thread;
 
-    for _ in 0..10 {
-        let counter = Arc::clone(&counter);
-        let handle = thread::spawn(move || {
-            let mut num = counter.lock().unwrap();
+    pub fn main() {
+        let counter = Arc::new(Mutex::new(0));
+        let mut handles = vec![];
 
-            *num += 1;
-        });
-        handles.push(handle);
-    }
+        for _ in 0..10 {
+            let counter = Arc::clone(&counter);
+            let handle = thread::spawn(move || {
+                let mut num = counter.lock().unwrap();
 
-    for handle in handles {
-        handle.join().unwrap();
-    }
+                *num += 1;
+            });
+            handles.push(handle);
+        }
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
 
-    println!("Result: {}", *counter.lock().unwrap());
+        println!("Result: {}", *counter.lock().unwrap());
+    }
 }
 
 
+
+
 /*
 This code will print the following:
 
@@ -230,6 +242,9 @@ We did it! We counted from 0 to 10, which may not seem very impressive, but it d
 
 // Similarities Between RefCell<T>/Rc<T> and Mutex<T>/Arc<T>
 
+
+
+
 /*
 You might have noticed that counter is immutable but we could get a mutable reference to the value inside it; this means Mutex<T> provides interior mutability, as the Cell family does. In the same way we used RefCell<T> in Chapter 15 to allow us to mutate contents inside an Rc<T>, we use Mutex<T> to mutate contents inside an Arc<T>.
 
@@ -237,14 +252,3 @@ Another detail to note is that Rust can’t protect you from all kinds of logic
 
 We’ll round out this chapter by talking about the Send and Sync traits and how we can use them with custom types.
 */
-
-
-
-
-
-
-
-
-
-
-
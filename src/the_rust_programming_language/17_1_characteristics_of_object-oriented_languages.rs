@@ -4,17 +4,22 @@
 
 
 
-
 // Encapsulation that Hides Implementation Details
 
+
+
+
 /*
 We discussed how to control encapsulation in Chapter 7: we can use the pub keyword to decide which modules, types, functions, and methods in our code should be public, and by default everything else is private. For example, we can define a struct AveragedCollection that has a field containing a vector of i32 values. The struct can also have a field that contains the average of the values in the vector, meaning the average doesn’t have to be computed on demand whenever anyone needs it. In other words, AveragedCollection will cache the calculated average for us. Listing 17-1 has the definition of the AveragedCollection struct:
 
 Filename: src/lib.rs
 */
-pub struct AveragedCollection {
-    list: Vec<i32>,
-    average: f64,
+
+pub mod listing_17_01 {
+    pub struct AveragedCollection {
+        list: Vec<i32>,
+        average: f64,
+    }
 }
 
 
@@ -25,40 +30,49 @@ The struct is marked pub so that other code can use it, but the fields within th
 
 Filename: src/lib.rs
 */
-impl AveragedCollection {
-    pub fn add(&mut self, value: i32) {
-        self.list.push(value);
-        self.update_average();
-    }
 
-    pub fn remove(&mut self) -> Option<i32> {
-        let result = self.list.pop();
-        match result {
-            Some(value) => {
-                self.update_average();
-                Some(value)
-            }
-            None => None,
-        }
-    }
-
-    pub fn average(&self) -> f64 {
-        self.average
-    }
-
-    fn update_average(&mut self) {
-        let total: i32 = self.list.iter().sum();
-        self.average = total as f64 / self.list.len() as f64;
-    }
-}
+/// ```
+/// # pub struct AveragedCollection {
+/// #     list: Vec<i32>,
+/// #     average: f64,
+/// # }
+/// #
+/// impl AveragedCollection {
+///     pub fn add(&mut self, value: i32) {
+///         self.list.push(value);
+///         self.update_average();
+///     }
+///
+///     pub fn remove(&mut self) -> Option<i32> {
+///         let result = self.list.pop();
+///         match result {
+///             Some(value) => {
+///                 self.update_average();
+///                 Some(value)
+///             }
+///             None => None,
+///         }
+///     }
+///
+///     pub fn average(&self) -> f64 {
+///         self.average
+///     }
+///
+///     fn update_average(&mut self) {
+///         let total: i32 = self.list.iter().sum();
+///         self.average = total as f64 / self.list.len() as f64;
+///     }
+/// }
+/// ```
+pub mod listing_17_02 {}
 
 
 
 
+// Inheritance as a Type System and as Code Sharing
 
 
 
-// Inheritance as a Type System and as Code Sharing
 
 /*
 
@@ -69,7 +83,3 @@ impl AveragedCollection {
     Rust instead uses generics to abstract over different possible types and trait bounds to impose constraints on what those types must provide. This is sometimes called bounded parametric polymorphism.
 
 */
-
-
-
-
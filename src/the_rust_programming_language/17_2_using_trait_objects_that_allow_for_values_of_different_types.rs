@@ -4,9 +4,11 @@
 
 
 
-
 // Defining a Trait for Common Behavior
 
+
+
+
 /*
 We’ve mentioned that, in Rust, we refrain from calling structs and enums “objects” to distinguish them from other languages’ objects. In a struct or enum, the data in the struct fields and the behavior in impl blocks are separated, whereas in other languages, the data and behavior combined into one concept is often labeled an object. However, trait objects are more like objects in other languages in the sense that they combine data and behavior. But trait objects differ from traditional objects in that we can’t add data to a trait object. Trait objects aren’t as generally useful as objects in other languages: their specific purpose is to allow abstraction across common behavior.
 
@@ -15,8 +17,11 @@ Listing 17-3 shows how to define a trait named Draw with one method named draw:
 Listing 17-3: Definition of the Draw trait
 Filename: src/lib.rs
 */
-pub trait Draw {
-    fn draw(&self);
+
+pub mod listing_17_03 {
+    pub trait Draw {
+        fn draw(&self);
+    }
 }
 
 
@@ -28,9 +33,15 @@ This syntax should look familiar from our discussions on how to define traits in
 Listing 17-4: Definition of the Screen struct with a components field holding a vector of trait objects that implement the Draw trait
 Filename: src/lib.rs
 */
-pub struct Screen {
-    pub components: Vec<Box<dyn Draw>>,
-}
+
+/// ```
+/// # use rust_book_examples::trpl::ch17_oop::trait_objects::listing_17_03::Draw;
+/// #
+/// pub struct Screen {
+///     pub components: Vec<Box<dyn Draw>>,
+/// }
+/// ```
+pub mod listing_17_04 {}
 
 
 
@@ -41,14 +52,23 @@ On the Screen struct, we’ll define a method named run that will call the draw
 Listing 17-5: A run method on Screen that calls the draw method on each component
 Filename: src/lib.rs
 */
-impl Screen {
-    pub fn run(&self) {
-        for component in self.components.iter() {
-            component.draw();
-        }
-    }
-}
 
+/// ```
+/// # use rust_book_examples::trpl::ch17_oop::trait_objects::listing_17_03::Draw;
+/// #
+/// # pub struct Screen {
+/// #     pub components: Vec<Box<dyn Draw>>,
+/// # }
+/// #
+/// impl Screen {
+///     pub fn run(&self) {
+///         for component in self.components.iter() {
+///             component.draw();
+///         }
+///     }
+/// }
+/// ```
+pub mod listing_17_05 {}
 
 
 
@@ -59,28 +79,34 @@ This works differently from defining a struct that uses a generic type parameter
 Listing 17-6: An alternate implementation of the Screen struct and its run method using generics and trait bounds
 Filename: src/lib.rs
 */
-pub struct Screen<T: Draw> {
-    pub components: Vec<T>,
-}
 
-impl<T> Screen<T>
-where
-    T: Draw,
-{
-    pub fn run(&self) {
-        for component in self.components.iter() {
-            component.draw();
-        }
-    }
-}
+/// ```
+/// # use rust_book_examples::trpl::ch17_oop::trait_objects::listing_17_03::Draw;
+/// #
+/// pub struct Screen<T: Draw> {
+///     pub components: Vec<T>,
+/// }
+///
+/// impl<T> Screen<T>
+/// where
+///     T: Draw,
+/// {
+///     pub fn run(&self) {
+///         for component in self.components.iter() {
+///             component.draw();
+///         }
+///     }
+/// }
+/// ```
+pub mod listing_17_06 {}
 
 
 
 
+// Implementing the Trait
 
 
 
-// Implementing the Trait
 
 /*
 Now we’ll add some types that implement the Draw trait. We’ll provide the Button type. Again, actually implementing a GUI library is beyond the scope of this book, so the draw method won’t have any useful implementation in its body. To imagine what the implementation might look like, a Button struct might have fields for width, height, and label, as shown in Listing 17-7:
@@ -88,18 +114,23 @@ Now we’ll add some types that implement the Draw trait. We’ll provide the Bu
 Listing 17-7: A Button struct that implements the Draw trait
 Filename: src/lib.rs
 */
-pub struct Button {
-    pub width: u32,
-    pub height: u32,
-    pub label: String,
-}
-
-impl Draw for Button {
-    fn draw(&self) {
-        // code to actually draw a button
-    }
-}
 
+/// ```
+/// # use rust_book_examples::trpl::ch17_oop::trait_objects::listing_17_03::Draw;
+/// #
+/// pub struct Button {
+///     pub width: u32,
+///     pub height: u32,
+///     pub label: String,
+/// }
+///
+/// impl Draw for Button {
+///     fn draw(&self) {
+///         // code to actually draw a button
+///     }
+/// }
+/// ```
+pub mod listing_17_07 {}
 
 
 
@@ -110,23 +141,27 @@ If someone using our library decides to implement a SelectBox struct that has wi
 Listing 17-8: Another crate using gui and implementing the Draw trait on a SelectBox struct
 Filename: src/main.rs
 */
-use gui::Draw;
-
-struct SelectBox {
-    width: u32,
-    height: u32,
-    options: Vec<String>,
-}
-
-impl Draw for SelectBox {
-    fn draw(&self) {
-        // code to actually draw a select box
-    }
-}
-
-
-
 
+/// ```
+/// # mod gui {
+/// #     pub use rust_book_examples::trpl::ch17_oop::trait_objects::listing_17_03::Draw;
+/// # }
+/// #
+/// use gui::Draw;
+///
+/// struct SelectBox {
+///     width: u32,
+///     height: u32,
+///     options: Vec<String>,
+/// }
+///
+/// impl Draw for SelectBox {
+///     fn draw(&self) {
+///         // code to actually draw a select box
+///     }
+/// }
+/// ```
+pub mod listing_17_08 {}
 
 
 
@@ -137,32 +172,70 @@ Our library’s user can now write their main function to create a Screen instan
 Listing 17-9: Using trait objects to store values of different types that implement the same trait
 Filename: src/main.rs
 */
-use gui::{Button, Screen};
-
-fn main() {
-    let screen = Screen {
-        components: vec![
-            Box::new(SelectBox {
-                width: 75,
-                height: 10,
-                options: vec![
-                    String::from("Yes"),
-                    String::from("Maybe"),
-                    String::from("No"),
-                ],
-            }),
-            Box::new(Button {
-                width: 50,
-                height: 10,
-                label: String::from("OK"),
-            }),
-        ],
-    };
-
-    screen.run();
-}
-
 
+/// ```
+/// # mod gui {
+/// #     pub use rust_book_examples::trpl::ch17_oop::trait_objects::listing_17_03::Draw;
+/// #
+/// #     pub struct Screen {
+/// #         pub components: Vec<Box<dyn Draw>>,
+/// #     }
+/// #
+/// #     impl Screen {
+/// #         pub fn run(&self) {
+/// #             for component in self.components.iter() {
+/// #                 component.draw();
+/// #             }
+/// #         }
+/// #     }
+/// #
+/// #     pub struct Button {
+/// #         pub width: u32,
+/// #         pub height: u32,
+/// #         pub label: String,
+/// #     }
+/// #
+/// #     impl Draw for Button {
+/// #         fn draw(&self) {}
+/// #     }
+/// # }
+/// #
+/// # struct SelectBox {
+/// #     width: u32,
+/// #     height: u32,
+/// #     options: Vec<String>,
+/// # }
+/// #
+/// # impl gui::Draw for SelectBox {
+/// #     fn draw(&self) {}
+/// # }
+/// #
+/// use gui::{Button, Screen};
+///
+/// fn main() {
+///     let screen = Screen {
+///         components: vec![
+///             Box::new(SelectBox {
+///                 width: 75,
+///                 height: 10,
+///                 options: vec![
+///                     String::from("Yes"),
+///                     String::from("Maybe"),
+///                     String::from("No"),
+///                 ],
+///             }),
+///             Box::new(Button {
+///                 width: 50,
+///                 height: 10,
+///                 label: String::from("OK"),
+///             }),
+///         ],
+///     };
+///
+///     screen.run();
+/// }
+/// ```
+pub mod listing_17_09 {}
 
 
 
@@ -173,16 +246,47 @@ For example, Listing 17-10 shows what happens if we try to create a Screen with
 Listing 17-10: Attempting to use a type that doesn’t implement the trait object’s trait
 Filename: src/main.rs
 */
- [This code does not compile!] 
-use gui::Screen;
+// [This code does not compile!]
+
+/// ```compile_fail
+/// # mod gui {
+/// #     pub use rust_book_examples::trpl::ch17_oop::trait_objects::listing_17_03::Draw;
+/// #
+/// #     pub struct Screen {
+/// #         pub components: Vec<Box<dyn Draw>>,
+/// #     }
+/// #
+/// #     impl Screen {
+/// #         pub fn run(&self) {
+/// #             for component in self.components.iter() {
+/// #                 component.draw();
+/// #             }
+/// #         }
+/// #     }
+/// #
+/// #     pub struct Button {
+/// #         pub width: u32,
+/// #         pub height: u32,
+/// #         pub label: String,
+/// #     }
+/// #
+/// #     impl Draw for Button {
+/// #         fn draw(&self) {}
+/// #     }
+/// # }
+/// #
+/// use gui::Screen;
+///
+/// fn main() {
+///     let screen = Screen {
+///         components: vec![Box::new(String::from("Hi"))],
+///     };
+///
+///     screen.run();
+/// }
+/// ```
+pub mod listing_17_10 {}
 
-fn main() {
-    let screen = Screen {
-        components: vec![Box::new(String::from("Hi"))],
-    };
-
-    screen.run();
-}
 
 
 
@@ -202,5 +306,3 @@ error[E0277]: the trait bound `String: Draw` is not satisfied
 For more information about this error, try `rustc --explain E0277`.
 error: could not compile `gui` due to previous error
 */
-
-
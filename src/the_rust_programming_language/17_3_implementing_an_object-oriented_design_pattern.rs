@@ -4,7 +4,6 @@
 
 
 
-
 /*
 First, we’re going to implement the state pattern in a more traditional object-oriented way, then we’ll use an approach that’s a bit more natural in Rust. Let’s dig in to incrementally implementing a blog post workflow using the state pattern.
 
@@ -28,28 +27,33 @@ Listing 17-11 shows this workflow in code form: this is an example usage of the
 Listing 17-11: Code that demonstrates the desired behavior we want our blog crate to have
 Filename: src/main.rs
 */
- [This code does not compile!] 
-use blog::Post;
-
-fn main() {
-    let mut post = Post::new();
+// [This code does not compile!]
 
-    post.add_text("I ate a salad for lunch today");
-    assert_eq!("", post.content());
+/// ```compile_fail
+/// use blog::Post;
+///
+/// fn main() {
+///     let mut post = Post::new();
+///
+///     post.add_text("I ate a salad for lunch today");
+///     assert_eq!("", post.content());
+///
+///     post.request_review();
+///     assert_eq!("", post.content());
+///
+///     post.approve();
+///     assert_eq!("I ate a salad for lunch today", post.content());
+/// }
+/// ```
+pub mod listing_17_11 {}
 
-    post.request_review();
-    assert_eq!("", post.content());
-
-    post.approve();
-    assert_eq!("I ate a salad for lunch today", post.content());
-}
 
 
 
+// Defining Post and Creating a New Instance in the Draft State
 
 
 
-// Defining Post and Creating a New Instance in the Draft State
 
 /*
 Then Post will hold a trait object of Box<dyn State> inside an Option<T> in a private field named state to hold the state object. You’ll see why the Option<T> is necessary in a bit.
@@ -57,33 +61,36 @@ Then Post will hold a trait object of Box<dyn State> inside an Option<T> in a pr
 Listing 17-12: Definition of a Post struct and a new function that creates a new Post instance, a State trait, and a Draft struct
 Filename: src/lib.rs
 */
-pub struct Post {
-    state: Option<Box<dyn State>>,
-    content: String,
-}
 
-impl Post {
-    pub fn new() -> Post {
-        Post {
-            state: Some(Box::new(Draft {})),
-            content: String::new(),
+pub mod listing_17_12 {
+    pub struct Post {
+        state: Option<Box<dyn State>>,
+        content: String,
+    }
+
+    impl Post {
+        pub fn new() -> Post {
+            Post {
+                state: Some(Box::new(Draft {})),
+                content: String::new(),
+            }
         }
     }
-}
 
-trait State {}
+    trait State {}
 
-struct Draft {}
+    struct Draft {}
 
-impl State for Draft {}
+    impl State for Draft {}
+}
 
 
 
 
+// Storing the Text of the Post Content
 
 
 
-// Storing the Text of the Post Content
 
 /*
 We saw in Listing 17-11 that we want to be able to call a method named add_text and pass it a &str that is then added as the text content of the blog post. We implement this as a method, rather than exposing the content field as pub, so that later we can implement a method that will control how the content field’s data is read. The add_text method is pretty straightforward, so let’s add the implementation in Listing 17-13 to the impl Post block:
@@ -91,78 +98,107 @@ We saw in Listing 17-11 that we want to be able to call a method named add_text
 Listing 17-13: Implementing the add_text method to add text to a post’s content
 Filename: src/lib.rs
 */
-impl Post {
-    // --snip--
-    pub fn add_text(&mut self, text: &str) {
-        self.content.push_str(text);
-    }
-}
+
+/// ```
+/// # pub struct Post {
+/// #     content: String,
+/// # }
+/// #
+/// impl Post {
+///     // --snip--
+///     pub fn add_text(&mut self, text: &str) {
+///         self.content.push_str(text);
+///     }
+/// }
+/// ```
+pub mod listing_17_13 {}
 
 
 
 
 // Ensuring the Content of a Draft Post Is Empty
 
+
+
+
 /*
 Even after we’ve called add_text and added some content to our post, we still want the content method to return an empty string slice because the post is still in the draft state, as shown on line 7 of Listing 17-11. For now, let’s implement the content method with the simplest thing that will fulfill this requirement: always returning an empty string slice. We’ll change this later once we implement the ability to change a post’s state so it can be published. So far, posts can only be in the draft state, so the post content should always be empty. Listing 17-14 shows this placeholder implementation:
 
 Listing 17-14: Adding a placeholder implementation for the content method on Post that always returns an empty string slice
 Filename: src/lib.rs
 */
-impl Post {
-    // --snip--
-    pub fn content(&self) -> &str {
-        ""
-    }
-}
-
 
+/// ```
+/// # pub struct Post {}
+/// #
+/// impl Post {
+///     // --snip--
+///     pub fn content(&self) -> &str {
+///         ""
+///     }
+/// }
+/// ```
+pub mod listing_17_14 {}
 
 
 
 
 // Requesting a Review of the Post Changes Its State
 
+
+
+
 /*
 Next, we need to add functionality to request a review of a post, which should change its state from Draft to PendingReview. Listing 17-15 shows this code:
 
 Listing 17-15: Implementing request_review methods on Post and the State trait
 Filename: src/lib.rs
 */
-impl Post {
-    // --snip--
-    pub fn request_review(&mut self) {
-        if let Some(s) = self.state.take() {
-            self.state = Some(s.request_review())
-        }
-    }
-}
 
-trait State {
-    fn request_review(self: Box<Self>) -> Box<dyn State>;
-}
+/// ```
+/// # pub struct Post {
+/// #     state: Option<Box<dyn State>>,
+/// #     content: String,
+/// # }
+/// #
+/// impl Post {
+///     // --snip--
+///     pub fn request_review(&mut self) {
+///         if let Some(s) = self.state.take() {
+///             self.state = Some(s.request_review())
+///         }
+///     }
+/// }
+///
+/// trait State {
+///     fn request_review(self: Box<Self>) -> Box<dyn State>;
+/// }
+///
+/// struct Draft {}
+///
+/// impl State for Draft {
+///     fn request_review(self: Box<Self>) -> Box<dyn State> {
+///         Box::new(PendingReview {})
+///     }
+/// }
+///
+/// struct PendingReview {}
+///
+/// impl State for PendingReview {
+///     fn request_review(self: Box<Self>) -> Box<dyn State> {
+///         self
+///     }
+/// }
+/// ```
+pub mod listing_17_15 {}
 
-struct Draft {}
 
-impl State for Draft {
-    fn request_review(self: Box<Self>) -> Box<dyn State> {
-        Box::new(PendingReview {})
-    }
-}
-
-struct PendingReview {}
-
-impl State for PendingReview {
-    fn request_review(self: Box<Self>) -> Box<dyn State> {
-        self
-    }
-}
 
 
+// Adding approve to Change the Behavior of content
 
 
 
-// Adding approve to Change the Behavior of content
 
 /*
 The approve method will be similar to the request_review method: it will set state to the value that the current state says it should have when that state is approved, as shown in Listing 17-16:
@@ -170,50 +206,66 @@ The approve method will be similar to the request_review method: it will set sta
 Listing 17-16: Implementing the approve method on Post and the State trait
 Filename: src/lib.rs
 */
-impl Post {
-    // --snip--
-    pub fn approve(&mut self) {
-        if let Some(s) = self.state.take() {
-            self.state = Some(s.approve())
-        }
-    }
-}
-
-trait State {
-    fn request_review(self: Box<Self>) -> Box<dyn State>;
-    fn approve(self: Box<Self>) -> Box<dyn State>;
-}
-
-struct Draft {}
-
-impl State for Draft {
-    // --snip--
-    fn approve(self: Box<Self>) -> Box<dyn State> {
-        self
-    }
-}
-
-struct PendingReview {}
-
-impl State for PendingReview {
-    // --snip--
-    fn approve(self: Box<Self>) -> Box<dyn State> {
-        Box::new(Published {})
-    }
-}
-
-struct Published {}
-
-impl State for Published {
-    fn request_review(self: Box<Self>) -> Box<dyn State> {
-        self
-    }
-
-    fn approve(self: Box<Self>) -> Box<dyn State> {
-        self
-    }
-}
 
+/// ```
+/// # pub struct Post {
+/// #     state: Option<Box<dyn State>>,
+/// #     content: String,
+/// # }
+/// #
+/// impl Post {
+///     // --snip--
+///     pub fn approve(&mut self) {
+///         if let Some(s) = self.state.take() {
+///             self.state = Some(s.approve())
+///         }
+///     }
+/// }
+///
+/// trait State {
+///     fn request_review(self: Box<Self>) -> Box<dyn State>;
+///     fn approve(self: Box<Self>) -> Box<dyn State>;
+/// }
+///
+/// struct Draft {}
+///
+/// impl State for Draft {
+///     // --snip--
+/// #     fn request_review(self: Box<Self>) -> Box<dyn State> {
+/// #         Box::new(PendingReview {})
+/// #     }
+/// #
+///     fn approve(self: Box<Self>) -> Box<dyn State> {
+///         self
+///     }
+/// }
+///
+/// struct PendingReview {}
+///
+/// impl State for PendingReview {
+///     // --snip--
+/// #     fn request_review(self: Box<Self>) -> Box<dyn State> {
+/// #         self
+/// #     }
+/// #
+///     fn approve(self: Box<Self>) -> Box<dyn State> {
+///         Box::new(Published {})
+///     }
+/// }
+///
+/// struct Published {}
+///
+/// impl State for Published {
+///     fn request_review(self: Box<Self>) -> Box<dyn State> {
+///         self
+///     }
+///
+///     fn approve(self: Box<Self>) -> Box<dyn State> {
+///         self
+///     }
+/// }
+/// ```
+pub mod listing_17_16 {}
 
 
 
@@ -224,16 +276,25 @@ Now we need to update the content method on Post. We want the value returned fro
 Listing 17-17: Updating the content method on Post to delegate to a content method on State
 Filename: src/lib.rs
 */
- [This code does not compile!] 
-impl Post {
-    // --snip--
-    pub fn content(&self) -> &str {
-        self.state.as_ref().unwrap().content(self)
-    }
-    // --snip--
-}
-
+// [This code does not compile!]
 
+/// ```compile_fail
+/// # pub struct Post {
+/// #     state: Option<Box<dyn State>>,
+/// #     content: String,
+/// # }
+/// #
+/// # trait State {}
+/// #
+/// impl Post {
+///     // --snip--
+///     pub fn content(&self) -> &str {
+///         self.state.as_ref().unwrap().content(self)
+///     }
+///     // --snip--
+/// }
+/// ```
+pub mod listing_17_17 {}
 
 
 
@@ -244,24 +305,30 @@ At this point, when we call content on the &Box<dyn State>, deref coercion will
 Listing 17-18: Adding the content method to the State trait
 Filename: src/lib.rs
 */
-trait State {
-    // --snip--
-    fn content<'a>(&self, post: &'a Post) -> &'a str {
-        ""
-    }
-}
-
-// --snip--
-struct Published {}
-
-impl State for Published {
-    // --snip--
-    fn content<'a>(&self, post: &'a Post) -> &'a str {
-        &post.content
-    }
-}
-
 
+/// ```
+/// # pub struct Post {
+/// #     content: String,
+/// # }
+/// #
+/// trait State {
+///     // --snip--
+///     fn content<'a>(&self, post: &'a Post) -> &'a str {
+///         ""
+///     }
+/// }
+///
+/// // --snip--
+/// struct Published {}
+///
+/// impl State for Published {
+///     // --snip--
+///     fn content<'a>(&self, post: &'a Post) -> &'a str {
+///         &post.content
+///     }
+/// }
+/// ```
+pub mod listing_17_18 {}
 
 
 
@@ -277,30 +344,44 @@ impl State for Published {
 
 
 
+// Encoding States and Behavior as Types
 
 
 
 
-
-
-
-
-// Encoding States and Behavior as Types
-
 /*
 Let’s consider the first part of main in Listing 17-11:
 
 Filename: src/main.rs
 */
-fn main() {
-    let mut post = Post::new();
-
-    post.add_text("I ate a salad for lunch today");
-    assert_eq!("", post.content());
-}
-
-
 
+/// ```
+/// # pub struct Post {
+/// #     content: String,
+/// # }
+/// #
+/// # impl Post {
+/// #     pub fn new() -> Post {
+/// #         Post { content: String::new() }
+/// #     }
+/// #
+/// #     pub fn add_text(&mut self, text: &str) {
+/// #         self.content.push_str(text);
+/// #     }
+/// #
+/// #     pub fn content(&self) -> &str {
+/// #         ""
+/// #     }
+/// # }
+/// #
+/// fn main() {
+///     let mut post = Post::new();
+///
+///     post.add_text("I ate a salad for lunch today");
+///     assert_eq!("", post.content());
+/// }
+/// ```
+pub mod example_09 {}
 
 
 
@@ -311,74 +392,81 @@ We still enable the creation of new posts in the draft state using Post::new and
 Listing 17-19: A Post with a content method and a DraftPost without a content method
 Filename: src/lib.rs
 */
-pub struct Post {
-    content: String,
-}
 
-pub struct DraftPost {
-    content: String,
-}
+pub mod listing_17_19 {
+    pub struct Post {
+        content: String,
+    }
 
-impl Post {
-    pub fn new() -> DraftPost {
-        DraftPost {
-            content: String::new(),
-        }
+    pub struct DraftPost {
+        content: String,
     }
 
-    pub fn content(&self) -> &str {
-        &self.content
+    impl Post {
+        pub fn new() -> DraftPost {
+            DraftPost {
+                content: String::new(),
+            }
+        }
+
+        pub fn content(&self) -> &str {
+            &self.content
+        }
     }
-}
 
-impl DraftPost {
-    pub fn add_text(&mut self, text: &str) {
-        self.content.push_str(text);
+    impl DraftPost {
+        pub fn add_text(&mut self, text: &str) {
+            self.content.push_str(text);
+        }
     }
 }
 
 
 
 
+// Implementing Transitions as Transformations into Different Types
 
 
 
 
-
-
-
-// Implementing Transitions as Transformations into Different Types
-
 /*
 So how do we get a published post? We want to enforce the rule that a draft post has to be reviewed and approved before it can be published. A post in the pending review state should still not display any content. Let’s implement these constraints by adding another struct, PendingReviewPost, defining the request_review method on DraftPost to return a PendingReviewPost, and defining an approve method on PendingReviewPost to return a Post, as shown in Listing 17-20:
 
 Listing 17-20: A PendingReviewPost that gets created by calling request_review on DraftPost and an approve method that turns a PendingReviewPost into a published Post
 Filename: src/lib.rs
 */
-impl DraftPost {
-    // --snip--
-    pub fn request_review(self) -> PendingReviewPost {
-        PendingReviewPost {
-            content: self.content,
-        }
-    }
-}
-
-pub struct PendingReviewPost {
-    content: String,
-}
-
-impl PendingReviewPost {
-    pub fn approve(self) -> Post {
-        Post {
-            content: self.content,
-        }
-    }
-}
-
-
-
 
+/// ```
+/// # pub struct DraftPost {
+/// #     content: String,
+/// # }
+/// #
+/// # pub struct Post {
+/// #     content: String,
+/// # }
+/// #
+/// impl DraftPost {
+///     // --snip--
+///     pub fn request_review(self) -> PendingReviewPost {
+///         PendingReviewPost {
+///             content: self.content,
+///         }
+///     }
+/// }
+///
+/// pub struct PendingReviewPost {
+///     content: String,
+/// }
+///
+/// impl PendingReviewPost {
+///     pub fn approve(self) -> Post {
+///         Post {
+///             content: self.content,
+///         }
+///     }
+/// }
+/// ```
+pub mod listing_17_20 {}
 
 
 
@@ -389,20 +477,60 @@ But we also have to make some small changes to main. The request_review and appr
 Listing 17-21: Modifications to main to use the new implementation of the blog post workflow
 Filename: src/main.rs
 */
-use blog::Post;
-
-fn main() {
-    let mut post = Post::new();
-
-    post.add_text("I ate a salad for lunch today");
-
-    let post = post.request_review();
-
-    let post = post.approve();
-
-    assert_eq!("I ate a salad for lunch today", post.content());
-}
-
-
-
 
+/// ```
+/// # mod blog {
+/// #     pub struct Post {
+/// #         content: String,
+/// #     }
+/// #
+/// #     pub struct DraftPost {
+/// #         content: String,
+/// #     }
+/// #
+/// #     impl Post {
+/// #         pub fn new() -> DraftPost {
+/// #             DraftPost { content: String::new() }
+/// #         }
+/// #
+/// #         pub fn content(&self) -> &str {
+/// #             &self.content
+/// #         }
+/// #     }
+/// #
+/// #     impl DraftPost {
+/// #         pub fn add_text(&mut self, text: &str) {
+/// #             self.content.push_str(text);
+/// #         }
+/// #
+/// #         pub fn request_review(self) -> PendingReviewPost {
+/// #             PendingReviewPost { content: self.content }
+/// #         }
+/// #     }
+/// #
+/// #     pub struct PendingReviewPost {
+/// #         content: String,
+/// #     }
+/// #
+/// #     impl PendingReviewPost {
+/// #         pub fn approve(self) -> Post {
+/// #             Post { content: self.content }
+/// #         }
+/// #     }
+/// # }
+/// #
+/// use blog::Post;
+///
+/// fn main() {
+///     let mut post = Post::new();
+///
+///     post.add_text("I ate a salad for lunch today");
+///
+///     let post = post.request_review();
+///
+///     let post = post.approve();
+///
+///     assert_eq!("I ate a salad for lunch today", post.content());
+/// }
+/// ```
+pub mod listing_17_21 {}
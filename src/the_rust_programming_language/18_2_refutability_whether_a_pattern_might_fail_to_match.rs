@@ -4,13 +4,17 @@
 
 
 
-
 /*
 Let’s look at an example of what happens when we try to use a refutable pattern where Rust requires an irrefutable pattern and vice versa. Listing 18-8 shows a let statement, but for the pattern we’ve specified Some(x), a refutable pattern. As you might expect, this code will not compile.
 
 Listing 18-8: Attempting to use a refutable pattern with let
 */
-    let Some(x) = some_option_value;
+
+/// ```compile_fail
+/// # let some_option_value: Option<i32> = None;
+///     let Some(x) = some_option_value;
+/// ```
+pub mod listing_18_08 {}
 
 
 
@@ -42,17 +46,19 @@ error: could not compile `patterns` due to previous error
 
 
 
-
-
-
 /*
 If we have a refutable pattern where an irrefutable pattern is needed, we can fix it by changing the code that uses the pattern: instead of using let, we can use if let. Then if the pattern doesn’t match, the code will just skip the code in the curly brackets, giving it a way to continue validly. Listing 18-9 shows how to fix the code in Listing 18-8.
 
 Listing 18-9: Using if let and a block with refutable patterns instead of let
 */
-    if let Some(x) = some_option_value {
-        println!("{}", x);
-    }
+
+/// ```
+/// # let some_option_value: Option<i32> = None;
+///     if let Some(x) = some_option_value {
+///         println!("{}", x);
+///     }
+/// ```
+pub mod listing_18_09 {}
 
 
 
@@ -77,7 +83,3 @@ warning: `patterns` (bin "patterns") generated 1 warning
      Running `target/debug/patterns`
 5
 */
-
-
-
-
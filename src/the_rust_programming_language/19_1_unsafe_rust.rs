@@ -6,6 +6,9 @@
 
 // Unsafe Superpowers
 
+
+
+
 /*
 To switch to unsafe Rust, use the unsafe keyword and then start a new block that holds the unsafe code. You can take five actions in unsafe Rust that you can’t in safe Rust, which we call unsafe superpowers. Those superpowers include the ability to:
 
@@ -20,9 +23,10 @@ To switch to unsafe Rust, use the unsafe keyword and then start a new block that
 
 
 
+// Dereferencing a Raw Pointer
+
 
 
-// Dereferencing a Raw Pointer
 
 /*
 Different from references and smart pointers, raw pointers:
@@ -35,6 +39,8 @@ Different from references and smart pointers, raw pointers:
 */
 
 
+
+
 /*
 By opting out of having Rust enforce these guarantees, you can give up guaranteed safety in exchange for greater performance or the ability to interface with another language or hardware where Rust’s guarantees don’t apply.
 
@@ -42,14 +48,15 @@ Listing 19-1 shows how to create an immutable and a mutable raw pointer from ref
 
 Listing 19-1: Creating raw pointers from references
 */
-fn main() {
-    let mut num = 5;
-
-    let r1 = &num as *const i32;
-    let r2 = &mut num as *mut i32;
-}
 
+pub mod listing_19_01 {
+    pub fn main() {
+        let mut num = 5;
 
+        let r1 = &num as *const i32;
+        let r2 = &mut num as *mut i32;
+    }
+}
 
 
 
@@ -59,9 +66,12 @@ To demonstrate this, next we’ll create a raw pointer whose validity we can’t
 
 Listing 19-2: Creating a raw pointer to an arbitrary memory address
 */
-fn main() {
-    let address = 0x012345usize;
-    let r = address as *const i32;
+
+pub mod listing_19_02 {
+    pub fn main() {
+        let address = 0x012345usize;
+        let r = address as *const i32;
+    }
 }
 
 
@@ -72,39 +82,46 @@ Recall that we can create raw pointers in safe code, but we can’t dereference
 
 Listing 19-3: Dereferencing raw pointers within an unsafe block
 */
-fn main() {
-    let mut num = 5;
 
-    let r1 = &num as *const i32;
-    let r2 = &mut num as *mut i32;
+pub mod listing_19_03 {
+    pub fn main() {
+        let mut num = 5;
 
-    unsafe {
-        println!("r1 is: {}", *r1);
-        println!("r2 is: {}", *r2);
+        let r1 = &num as *const i32;
+        let r2 = &mut num as *mut i32;
+
+        unsafe {
+            println!("r1 is: {}", *r1);
+            println!("r2 is: {}", *r2);
+        }
     }
 }
 
 
 
 
+// Calling an Unsafe Function or Method
 
 
 
-// Calling an Unsafe Function or Method
 
 /*
 Here is an unsafe function named dangerous that doesn’t do anything in its body:
 */
-fn main() {
-    unsafe fn dangerous() {}
 
-    unsafe {
-        dangerous();
+pub mod example_04 {
+    pub fn main() {
+        unsafe fn dangerous() {}
+
+        unsafe {
+            dangerous();
+        }
     }
 }
 
 
 
+
 /*
 We must call the dangerous function within a separate unsafe block. If we try to call dangerous without the unsafe block, we’ll get an error:
 
@@ -125,24 +142,28 @@ error: could not compile `unsafe-example` due to previous error
 
 
 
+// Creating a Safe Abstraction over Unsafe Code
+
 
 
-// Creating a Safe Abstraction over Unsafe Code
 
 /*
 Just because a function contains unsafe code doesn’t mean we need to mark the entire function as unsafe. In fact, wrapping unsafe code in a safe function is a common abstraction. As an example, let’s study the split_at_mut function from the standard library, which requires some unsafe code. We’ll explore how we might implement it. This safe method is defined on mutable slices: it takes one slice and makes it two by splitting the slice at the index given as an argument. Listing 19-4 shows how to use split_at_mut.
 
 Listing 19-4: Using the safe split_at_mut function
 */
-fn main() {
-    let mut v = vec![1, 2, 3, 4, 5, 6];
 
-    let r = &mut v[..];
+pub mod listing_19_04 {
+    pub fn main() {
+        let mut v = vec![1, 2, 3, 4, 5, 6];
+
+        let r = &mut v[..];
 
-    let (a, b) = r.split_at_mut(3);
+        let (a, b) = r.split_at_mut(3);
 
-    assert_eq!(a, &mut [1, 2, 3]);
-    assert_eq!(b, &mut [4, 5, 6]);
+        assert_eq!(a, &mut [1, 2, 3]);
+        assert_eq!(b, &mut [4, 5, 6]);
+    }
 }
 
 
@@ -153,14 +174,20 @@ We can’t implement this function using only safe Rust. An attempt might look s
 
 Listing 19-5: An attempted implementation of split_at_mut using only safe Rust
 */
- [This code does not compile!] 
-fn split_at_mut(values: &mut [i32], mid: usize) -> (&mut [i32], &mut [i32]) {
-    let len = values.len();
+// [This code does not compile!]
+
+/// ```compile_fail
+/// fn split_at_mut(values: &mut [i32], mid: usize) -> (&mut [i32], &mut [i32]) {
+///     let len = values.len();
+///
+///     assert!(mid <= len);
+///
+///     (&mut values[..mid], &mut values[mid..])
+/// }
+/// ```
+pub mod listing_19_05 {}
 
-    assert!(mid <= len);
 
-    (&mut values[..mid], &mut values[mid..])
-}
 
 
 /*
@@ -188,63 +215,62 @@ error: could not compile `unsafe-example` due to previous error
 
 
 
-
-
-
 /*
 Listing 19-6 shows how to use an unsafe block, a raw pointer, and some calls to unsafe functions to make the implementation of split_at_mut work.
 
 Listing 19-6: Using unsafe code in the implementation of the split_at_mut function
 */
-use std::slice;
 
-fn split_at_mut(values: &mut [i32], mid: usize) -> (&mut [i32], &mut [i32]) {
-    let len = values.len();
-    let ptr = values.as_mut_ptr();
+pub mod listing_19_06 {
+    use std::slice;
 
-    assert!(mid <= len);
+    fn split_at_mut(values: &mut [i32], mid: usize) -> (&mut [i32], &mut [i32]) {
+        let len = values.len();
+        let ptr = values.as_mut_ptr();
 
-    unsafe {
-        (
-            slice::from_raw_parts_mut(ptr, mid),
-            slice::from_raw_parts_mut(ptr.add(mid), len - mid),
-        )
+        assert!(mid <= len);
+
+        unsafe {
+            (
+                slice::from_raw_parts_mut(ptr, mid),
+                slice::from_raw_parts_mut(ptr.add(mid), len - mid),
+            )
+        }
     }
-}
 
-fn main() {
-    let mut vector = vec![1, 2, 3, 4, 5, 6];
-    let (left, right) = split_at_mut(&mut vector, 3);
+    pub fn main() {
+        let mut vector = vec![1, 2, 3, 4, 5, 6];
+        let (left, right) = split_at_mut(&mut vector, 3);
+    }
 }
 
 
 
 
-
-
-
-
-
 /*
 In contrast, the use of slice::from_raw_parts_mut in Listing 19-7 would likely crash when the slice is used. This code takes an arbitrary memory location and creates a slice 10,000 items long.
 
 Listing 19-7: Creating a slice from an arbitrary memory location
 */
-fn main() {
-    use std::slice;
 
-    let address = 0x01234usize;
-    let r = address as *mut i32;
+pub mod listing_19_07 {
+    pub fn main() {
+        use std::slice;
 
-    let values: &[i32] = unsafe { slice::from_raw_parts_mut(r, 10000) };
+        let address = 0x01234usize;
+        let r = address as *mut i32;
+
+        let values: &[i32] = unsafe { slice::from_raw_parts_mut(r, 10000) };
+    }
 }
 
 
 
 
+// Using extern Functions to Call External Code
+
 
 
-// Using extern Functions to Call External Code
 
 /*
 Listing 19-8 demonstrates how to set up an integration with the abs function from the C standard library. Functions declared within extern blocks are always unsafe to call from Rust code. The reason is that other languages don’t enforce Rust’s rules and guarantees, and Rust can’t check them, so responsibility falls on the programmer to ensure safety.
@@ -252,42 +278,50 @@ Listing 19-8 demonstrates how to set up an integration with the abs function fro
 Listing 19-8: Declaring and calling an extern function defined in another language
 Filename: src/main.rs
 */
-extern "C" {
-    fn abs(input: i32) -> i32;
-}
 
-fn main() {
-    unsafe {
-        println!("Absolute value of -3 according to C: {}", abs(-3));
+pub mod listing_19_08 {
+    extern "C" {
+        fn abs(input: i32) -> i32;
+    }
+
+    pub fn main() {
+        unsafe {
+            println!("Absolute value of -3 according to C: {}", abs(-3));
+        }
     }
 }
 
 
 
 
+// Calling Rust Functions from Other Languages
+
 
 
-// Calling Rust Functions from Other Languages
 
 /*
 In the following example, we make the call_from_c function accessible from C code, after it’s compiled to a shared library and linked from C:
 
 This usage of extern does not require unsafe.
 */
-#![allow(unused)]
-fn main() {
-#[no_mangle]
-pub extern "C" fn call_from_c() {
-    println!("Just called a Rust function from C!");
-}
+
+pub mod example_10 {
+    #![allow(unused)]
+    pub fn main() {
+    #[no_mangle]
+    pub extern "C" fn call_from_c() {
+        println!("Just called a Rust function from C!");
+    }
+    }
 }
 
 
 
 
+// Accessing or Modifying a Mutable Static Variable
+
 
 
-// Accessing or Modifying a Mutable Static Variable
 
 /*
 In Rust, global variables are called static variables. Listing 19-9 shows an example declaration and use of a static variable with a string slice as a value.
@@ -295,12 +329,14 @@ In Rust, global variables are called static variables. Listing 19-9 shows an exa
 Listing 19-9: Defining and using an immutable static variable
 Filename: src/main.rs
 */
-static HELLO_WORLD: &str = "Hello, world!";
 
-fn main() {
-    println!("name is: {}", HELLO_WORLD);
-}
+pub mod listing_19_09 {
+    static HELLO_WORLD: &str = "Hello, world!";
 
+    pub fn main() {
+        println!("name is: {}", HELLO_WORLD);
+    }
+}
 
 
 
@@ -311,45 +347,47 @@ A subtle difference between constants and immutable static variables is that val
 Listing 19-10: Reading from or writing to a mutable static variable is unsafe
 Filename: src/main.rs
 */
-static mut COUNTER: u32 = 0;
 
-fn add_to_count(inc: u32) {
-    unsafe {
-        COUNTER += inc;
+pub mod listing_19_10 {
+    static mut COUNTER: u32 = 0;
+
+    fn add_to_count(inc: u32) {
+        unsafe {
+            COUNTER += inc;
+        }
     }
-}
 
-fn main() {
-    add_to_count(3);
+    pub fn main() {
+        add_to_count(3);
 
-    unsafe {
-        println!("COUNTER: {}", COUNTER);
+        unsafe {
+            println!("COUNTER: {}", COUNTER);
+        }
     }
 }
 
 
 
 
+// Implementing an Unsafe Trait
 
 
 
 
-// Implementing an Unsafe Trait
-
 /*
 We can use unsafe to implement an unsafe trait. A trait is unsafe when at least one of its methods has some invariant that the compiler can’t verify. We declare that a trait is unsafe by adding the unsafe keyword before trait and marking the implementation of the trait as unsafe too, as shown in Listing 19-11.
 
 Listing 19-11: Defining and implementing an unsafe trait
 */
-unsafe trait Foo {
-    // methods go here
-}
-
-unsafe impl Foo for i32 {
-    // method implementations go here
-}
-
-fn main() {}
 
+pub mod listing_19_11 {
+    unsafe trait Foo {
+        // methods go here
+    }
 
+    unsafe impl Foo for i32 {
+        // method implementations go here
+    }
 
+    pub fn main() {}
+}
@@ -6,15 +6,21 @@
 
 // Specifying Placeholder Types in Trait Definitions with Associated Types
 
+
+
+
 /*
 One example of a trait with an associated type is the Iterator trait that the standard library provides. The associated type is named Item and stands in for the type of the values the type implementing the Iterator trait is iterating over. The definition of the Iterator trait is as shown in Listing 19-12.
 
 Listing 19-12: The definition of the Iterator trait that has an associated type Item
 */
-pub trait Iterator {
-    type Item;
 
-    fn next(&mut self) -> Option<Self::Item>;
+pub mod listing_19_12 {
+    pub trait Iterator {
+        type Item;
+
+        fn next(&mut self) -> Option<Self::Item>;
+    }
 }
 
 
@@ -25,11 +31,20 @@ Associated types might seem like a similar concept to generics, in that the latt
 
 Filename: src/lib.rs
 */
-impl Iterator for Counter {
-    type Item = u32;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        // --snip--
+/// ```
+/// # struct Counter;
+/// #
+/// impl Iterator for Counter {
+///     type Item = u32;
+///
+///     fn next(&mut self) -> Option<Self::Item> {
+///         // --snip--
+/// #         None
+/// #     }
+/// # }
+/// ```
+pub mod example_02 {}
 
 
 
@@ -39,18 +54,20 @@ This syntax seems comparable to that of generics. So why not just define the Ite
 
 Listing 19-13: A hypothetical definition of the Iterator trait using generics
 */
-pub trait Iterator<T> {
-    fn next(&mut self) -> Option<T>;
-}
 
+pub mod listing_19_13 {
+    pub trait Iterator<T> {
+        fn next(&mut self) -> Option<T>;
+    }
+}
 
 
 
 
+// Default Generic Type Parameters and Operator Overloading
 
 
 
-// Default Generic Type Parameters and Operator Overloading
 
 /*
 Rust doesn’t allow you to create your own operators or overload arbitrary operators. But you can overload the operations and corresponding traits listed in std::ops by implementing the traits associated with the operator. For example, in Listing 19-14 we overload the + operator to add two Point instances together. We do this by implementing the Add trait on a Point struct:
@@ -58,45 +75,49 @@ Rust doesn’t allow you to create your own operators or overload arbitrary oper
 Listing 19-14: Implementing the Add trait to overload the + operator for Point instances
 Filename: src/main.rs
 */
-use std::ops::Add;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
-struct Point {
-    x: i32,
-    y: i32,
-}
+pub mod listing_19_14 {
+    use std::ops::Add;
 
-impl Add for Point {
-    type Output = Point;
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    impl Add for Point {
+        type Output = Point;
 
-    fn add(self, other: Point) -> Point {
-        Point {
-            x: self.x + other.x,
-            y: self.y + other.y,
+        fn add(self, other: Point) -> Point {
+            Point {
+                x: self.x + other.x,
+                y: self.y + other.y,
+            }
         }
     }
-}
 
-fn main() {
-    assert_eq!(
-        Point { x: 1, y: 0 } + Point { x: 2, y: 3 },
-        Point { x: 3, y: 3 }
-    );
+    pub fn main() {
+        assert_eq!(
+            Point { x: 1, y: 0 } + Point { x: 2, y: 3 },
+            Point { x: 3, y: 3 }
+        );
+    }
 }
 
 
 
 
-
 /*
 The default generic type in this code is within the Add trait. Here is its definition:
 */
-trait Add<Rhs=Self> {
-    type Output;
 
-    fn add(self, rhs: Rhs) -> Self::Output;
-}
+pub mod example_05 {
+    trait Add<Rhs=Self> {
+        type Output;
 
+        fn add(self, rhs: Rhs) -> Self::Output;
+    }
+}
 
 
 
@@ -107,20 +128,25 @@ We have two structs, Millimeters and Meters, holding values in different units.
 Listing 19-15: Implementing the Add trait on Millimeters to add Millimeters to Meters
 Filename: src/lib.rs
 */
-use std::ops::Add;
 
-struct Millimeters(u32);
-struct Meters(u32);
+pub mod listing_19_15 {
+    use std::ops::Add;
+
+    struct Millimeters(u32);
+    struct Meters(u32);
 
-impl Add<Meters> for Millimeters {
-    type Output = Millimeters;
+    impl Add<Meters> for Millimeters {
+        type Output = Millimeters;
 
-    fn add(self, other: Meters) -> Millimeters {
-        Millimeters(self.0 + (other.0 * 1000))
+        fn add(self, other: Meters) -> Millimeters {
+            Millimeters(self.0 + (other.0 * 1000))
+        }
     }
 }
 
 
+
+
 /*
 To add Millimeters and Meters, we specify impl Add<Meters> to set the value of the Rhs type parameter instead of using the default of Self.
 
@@ -134,63 +160,93 @@ You’ll use default type parameters in two main ways:
 
 
 
+// Fully Qualified Syntax for Disambiguation: Calling Methods with the Same Name
 
 
 
 
-// Fully Qualified Syntax for Disambiguation: Calling Methods with the Same Name
-
 /*
 When calling methods with the same name, you’ll need to tell Rust which one you want to use. Consider the code in Listing 19-16 where we’ve defined two traits, Pilot and Wizard, that both have a method called fly. We then implement both traits on a type Human that already has a method named fly implemented on it. Each fly method does something different.
 
 Listing 19-16: Two traits are defined to have a fly method and are implemented on the Human type, and a fly method is implemented on Human directly
 Filename: src/main.rs
 */
-trait Pilot {
-    fn fly(&self);
-}
 
-trait Wizard {
-    fn fly(&self);
-}
+pub mod listing_19_16 {
+    trait Pilot {
+        fn fly(&self);
+    }
+
+    trait Wizard {
+        fn fly(&self);
+    }
 
-struct Human;
+    struct Human;
 
-impl Pilot for Human {
-    fn fly(&self) {
-        println!("This is your captain speaking.");
+    impl Pilot for Human {
+        fn fly(&self) {
+            println!("This is your captain speaking.");
+        }
     }
-}
 
-impl Wizard for Human {
-    fn fly(&self) {
-        println!("Up!");
+    impl Wizard for Human {
+        fn fly(&self) {
+            println!("Up!");
+        }
     }
-}
 
-impl Human {
-    fn fly(&self) {
-        println!("*waving arms furiously*");
+    impl Human {
+        fn fly(&self) {
+            println!("*waving arms furiously*");
+        }
     }
 }
 
 
 
 
-
-
-
-
 /*
 When we call fly on an instance of Human, the compiler defaults to calling the method that is directly implemented on the type, as shown in Listing 19-17.
 
 Listing 19-17: Calling fly on an instance of Human
 Filename: src/main.rs
 */
-fn main() {
-    let person = Human;
-    person.fly();
-}
+
+/// ```
+/// # trait Pilot {
+/// #     fn fly(&self);
+/// # }
+/// #
+/// # trait Wizard {
+/// #     fn fly(&self);
+/// # }
+/// #
+/// # struct Human;
+/// #
+/// # impl Pilot for Human {
+/// #     fn fly(&self) {
+/// #         println!("This is your captain speaking.");
+/// #     }
+/// # }
+/// #
+/// # impl Wizard for Human {
+/// #     fn fly(&self) {
+/// #         println!("Up!");
+/// #     }
+/// # }
+/// #
+/// # impl Human {
+/// #     fn fly(&self) {
+/// #         println!("*waving arms furiously*");
+/// #     }
+/// # }
+/// #
+/// fn main() {
+///     let person = Human;
+///     person.fly();
+/// }
+/// ```
+pub mod listing_19_17 {}
 
 
 
@@ -203,13 +259,44 @@ To call the fly methods from either the Pilot trait or the Wizard trait, we need
 Listing 19-18: Specifying which trait’s fly method we want to call
 Filename: src/main.rs
 */
-fn main() {
-    let person = Human;
-    Pilot::fly(&person);
-    Wizard::fly(&person);
-    person.fly();
-}
 
+/// ```
+/// # trait Pilot {
+/// #     fn fly(&self);
+/// # }
+/// #
+/// # trait Wizard {
+/// #     fn fly(&self);
+/// # }
+/// #
+/// # struct Human;
+/// #
+/// # impl Pilot for Human {
+/// #     fn fly(&self) {
+/// #         println!("This is your captain speaking.");
+/// #     }
+/// # }
+/// #
+/// # impl Wizard for Human {
+/// #     fn fly(&self) {
+/// #         println!("Up!");
+/// #     }
+/// # }
+/// #
+/// # impl Human {
+/// #     fn fly(&self) {
+/// #         println!("*waving arms furiously*");
+/// #     }
+/// # }
+/// #
+/// fn main() {
+///     let person = Human;
+///     Pilot::fly(&person);
+///     Wizard::fly(&person);
+///     person.fly();
+/// }
+/// ```
+pub mod listing_19_18 {}
 
 /*
 Running this code prints the following:
@@ -226,38 +313,40 @@ Up!
 
 
 
-
-
 /*
 However, associated functions that are not methods don’t have a self parameter. When there are multiple types or traits that define non-method functions with the same function name, Rust doesn't always know which type you mean unless you use fully qualified syntax. For example, in Listing 19-19 we create a trait for an animal shelter that wants to name all baby dogs Spot. We make an Animal trait with an associated non-method function baby_name. The Animal trait is implemented for the struct Dog, on which we also provide an associated non-method function baby_name directly.
 
 Listing 19-19: A trait with an associated function and a type with an associated function of the same name that also implements the trait
 Filename: src/main.rs
 */
-trait Animal {
-    fn baby_name() -> String;
-}
 
-struct Dog;
+pub mod listing_19_19 {
+    trait Animal {
+        fn baby_name() -> String;
+    }
+
+    struct Dog;
 
-impl Dog {
-    fn baby_name() -> String {
-        String::from("Spot")
+    impl Dog {
+        fn baby_name() -> String {
+            String::from("Spot")
+        }
     }
-}
 
-impl Animal for Dog {
-    fn baby_name() -> String {
-        String::from("puppy")
+    impl Animal for Dog {
+        fn baby_name() -> String {
+            String::from("puppy")
+        }
     }
-}
 
-fn main() {
-    println!("A baby dog is called a {}", Dog::baby_name());
+    pub fn main() {
+        println!("A baby dog is called a {}", Dog::baby_name());
+    }
 }
 
 
 
+
 /*
 In main, we call the Dog::baby_name function, which calls the associated function defined on Dog directly. This code prints the following:
 
@@ -270,15 +359,39 @@ A baby dog is called a Spot
 
 
 
+
 /*
 This output isn’t what we wanted. We want to call the baby_name function that is part of the Animal trait that we implemented on Dog so the code prints A baby dog is called a puppy. The technique of specifying the trait name that we used in Listing 19-18 doesn’t help here; if we change main to the code in Listing 19-20, we’ll get a compilation error.
 
 Listing 19-20: Attempting to call the baby_name function from the Animal trait, but Rust doesn’t know which implementation to use
 Filename: src/main.rs
 */
-fn main() {
-    println!("A baby dog is called a {}", Animal::baby_name());
-}
+
+/// ```compile_fail
+/// # trait Animal {
+/// #     fn baby_name() -> String;
+/// # }
+/// #
+/// # struct Dog;
+/// #
+/// # impl Dog {
+/// #     fn baby_name() -> String {
+/// #         String::from("Spot")
+/// #     }
+/// # }
+/// #
+/// # impl Animal for Dog {
+/// #     fn baby_name() -> String {
+/// #         String::from("puppy")
+/// #     }
+/// # }
+/// #
+/// fn main() {
+///     println!("A baby dog is called a {}", Animal::baby_name());
+/// }
+/// ```
+pub mod listing_19_20 {}
+
 
 
 
@@ -308,9 +421,33 @@ To disambiguate and tell Rust that we want to use the implementation of Animal f
 Listing 19-21: Using fully qualified syntax to specify that we want to call the baby_name function from the Animal trait as implemented on Dog
 Filename: src/main.rs
 */
-fn main() {
-    println!("A baby dog is called a {}", <Dog as Animal>::baby_name());
-}
+
+/// ```
+/// # trait Animal {
+/// #     fn baby_name() -> String;
+/// # }
+/// #
+/// # struct Dog;
+/// #
+/// # impl Dog {
+/// #     fn baby_name() -> String {
+/// #         String::from("Spot")
+/// #     }
+/// # }
+/// #
+/// # impl Animal for Dog {
+/// #     fn baby_name() -> String {
+/// #         String::from("puppy")
+/// #     }
+/// # }
+/// #
+/// fn main() {
+///     println!("A baby dog is called a {}", <Dog as Animal>::baby_name());
+/// }
+/// ```
+pub mod listing_19_21 {}
+
+
 
 
 /*
@@ -324,6 +461,8 @@ A baby dog is called a puppy
 */
 
 
+
+
 /*
 In general, fully qualified syntax is defined as follows:
 
@@ -333,9 +472,10 @@ In general, fully qualified syntax is defined as follows:
 
 
 
+// Using Supertraits to Require One Trait’s Functionality Within Another Trait
+
 
 
-// Using Supertraits to Require One Trait’s Functionality Within Another Trait
 
 /*
 For example, let’s say we want to make an OutlinePrint trait with an outline_print method that will print a given value formatted so that it's framed in asterisks. That is, given a Point struct that implements the standard library trait Display to result in (x, y), when we call outline_print on a Point instance that has 1 for x and 3 for y, it should print the following:
@@ -349,23 +489,27 @@ For example, let’s say we want to make an OutlinePrint trait with an outline_p
 
 
 
+
 /*
 In the implementation of the outline_print method, we want to use the Display trait’s functionality. Therefore, we need to specify that the OutlinePrint trait will work only for types that also implement Display and provide the functionality that OutlinePrint needs. We can do that in the trait definition by specifying OutlinePrint: Display. This technique is similar to adding a trait bound to the trait. Listing 19-22 shows an implementation of the OutlinePrint trait.
 
 Listing 19-22: Implementing the OutlinePrint trait that requires the functionality from Display
 Filename: src/main.rs
 */
-use std::fmt;
-
-trait OutlinePrint: fmt::Display {
-    fn outline_print(&self) {
-        let output = self.to_string();
-        let len = output.len();
-        println!("{}", "*".repeat(len + 4));
-        println!("*{}*", " ".repeat(len + 2));
-        println!("* {} *", output);
-        println!("*{}*", " ".repeat(len + 2));
-        println!("{}", "*".repeat(len + 4));
+
+pub mod listing_19_22 {
+    use std::fmt;
+
+    trait OutlinePrint: fmt::Display {
+        fn outline_print(&self) {
+            let output = self.to_string();
+            let len = output.len();
+            println!("{}", "*".repeat(len + 4));
+            println!("*{}*", " ".repeat(len + 2));
+            println!("* {} *", output);
+            println!("*{}*", " ".repeat(len + 2));
+            println!("{}", "*".repeat(len + 4));
+        }
     }
 }
 
@@ -377,13 +521,32 @@ Let’s see what happens when we try to implement OutlinePrint on a type that do
 
 Filename: src/main.rs
 */
- [This code does not compile!] 
-struct Point {
-    x: i32,
-    y: i32,
-}
+// [This code does not compile!]
+
+/// ```compile_fail
+/// # use std::fmt;
+/// #
+/// # trait OutlinePrint: fmt::Display {
+/// #     fn outline_print(&self) {
+/// #         let output = self.to_string();
+/// #         let len = output.len();
+/// #         println!("{}", "*".repeat(len + 4));
+/// #         println!("*{}*", " ".repeat(len + 2));
+/// #         println!("* {} *", output);
+/// #         println!("*{}*", " ".repeat(len + 2));
+/// #         println!("{}", "*".repeat(len + 4));
+/// #     }
+/// # }
+/// #
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl OutlinePrint for Point {}
+/// ```
+pub mod example_14 {}
 
-impl OutlinePrint for Point {}
 
 
 
@@ -413,28 +576,35 @@ error: could not compile `traits-example` due to previous error
 
 
 
-
 /*
 To fix this, we implement Display on Point and satisfy the constraint that OutlinePrint requires, like so:
 
 Filename: src/main.rs
 */
-use std::fmt;
-
-impl fmt::Display for Point {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
-    }
-}
 
+/// ```
+/// # struct Point {
+/// #     x: i32,
+/// #     y: i32,
+/// # }
+/// #
+/// use std::fmt;
+///
+/// impl fmt::Display for Point {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "({}, {})", self.x, self.y)
+///     }
+/// }
+/// ```
+pub mod example_15 {}
 
 
 
 
+// Using the Newtype Pattern to Implement External Traits on External Types
 
 
 
-// Using the Newtype Pattern to Implement External Traits on External Types
 
 /*
 As an example, let’s say we want to implement Display on Vec<T>, which the orphan rule prevents us from doing directly because the Display trait and the Vec<T> type are defined outside our crate. We can make a Wrapper struct that holds an instance of Vec<T>; then we can implement Display on Wrapper and use the Vec<T> value, as shown in Listing 19-23.
@@ -442,23 +612,20 @@ As an example, let’s say we want to implement Display on Vec<T>, which the orp
 Listing 19-23: Creating a Wrapper type around Vec<String> to implement Display
 Filename: src/main.rs
 */
-use std::fmt;
 
-struct Wrapper(Vec<String>);
+pub mod listing_19_23 {
+    use std::fmt;
 
-impl fmt::Display for Wrapper {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "[{}]", self.0.join(", "))
+    struct Wrapper(Vec<String>);
+
+    impl fmt::Display for Wrapper {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "[{}]", self.0.join(", "))
+        }
     }
-}
 
-fn main() {
-    let w = Wrapper(vec![String::from("hello"), String::from("world")]);
-    println!("w = {}", w);
+    pub fn main() {
+        let w = Wrapper(vec![String::from("hello"), String::from("world")]);
+        println!("w = {}", w);
+    }
 }
-
-
-
-
-
-
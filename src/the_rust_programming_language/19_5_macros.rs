@@ -4,13 +4,21 @@
 
 
 
-
 // Declarative Macros with macro_rules! for General Metaprogramming
 
+
+
+
 /*
 To define a macro, you use the macro_rules! construct. Let’s explore how to use macro_rules! by looking at how the vec! macro is defined. Chapter 8 covered how we can use the vec! macro to create a new vector with particular values. For example, the following macro creates a new vector containing three integers:
 */
-let v: Vec<u32> = vec![1, 2, 3];
+
+pub mod example_01 {
+    pub fn main() {
+        let v: Vec<u32> = vec![1, 2, 3];
+    }
+}
+
 
 
 
@@ -22,20 +30,21 @@ Listing 19-28 shows a slightly simplified definition of the vec! macro.
 Listing 19-28: A simplified version of the vec! macro definition
 Filename: src/lib.rs
 */
-#[macro_export]
-macro_rules! vec {
-    ( $( $x:expr ),* ) => {
-        {
-            let mut temp_vec = Vec::new();
-            $(
-                temp_vec.push($x);
-            )*
-            temp_vec
-        }
-    };
-}
-
 
+pub mod listing_19_28 {
+    #[macro_export]
+    macro_rules! vec {
+        ( $( $x:expr ),* ) => {
+            {
+                let mut temp_vec = Vec::new();
+                $(
+                    temp_vec.push($x);
+                )*
+                temp_vec
+            }
+        };
+    }
+}
 
 
 
@@ -43,22 +52,25 @@ macro_rules! vec {
 /*
 Now let’s look at the pattern in the body of the code associated with this arm: temp_vec.push() within $()* is generated for each part that matches $() in the pattern zero or more times depending on how many times the pattern matches. The $x is replaced with each expression matched. When we call this macro with vec![1, 2, 3];, the code generated that replaces this macro call will be the following:
 */
-{
-    let mut temp_vec = Vec::new();
-    temp_vec.push(1);
-    temp_vec.push(2);
-    temp_vec.push(3);
-    temp_vec
-}
 
+/// ```compile_fail
+/// {
+///     let mut temp_vec = Vec::new();
+///     temp_vec.push(1);
+///     temp_vec.push(2);
+///     temp_vec.push(3);
+///     temp_vec
+/// }
+/// ```
+pub mod example_03 {}
 
 
 
 
+// Procedural Macros for Generating Code from Attributes
 
 
 
-// Procedural Macros for Generating Code from Attributes
 
 /*
 When creating procedural macros, the definitions must reside in their own crate with a special crate type. This is for complex technical reasons that we hope to eliminate in the future. In Listing 19-29, we show how to define a procedural macro, where some_attribute is a placeholder for using a specific macro variety.
@@ -66,19 +78,23 @@ When creating procedural macros, the definitions must reside in their own crate
 Listing 19-29: An example of defining a procedural macro
 Filename: src/lib.rs
 */
-use proc_macro;
 
-#[some_attribute]
-pub fn some_name(input: TokenStream) -> TokenStream {
-}
+/// ```ignore
+/// use proc_macro;
+///
+/// #[some_attribute]
+/// pub fn some_name(input: TokenStream) -> TokenStream {
+/// }
+/// ```
+pub mod listing_19_29 {}
 
 
 
 
+// How to Write a Custom derive Macro
 
 
 
-// How to Write a Custom derive Macro
 
 /*
 Let’s create a crate named hello_macro that defines a trait named HelloMacro with one associated function named hello_macro. Rather than making our users implement the HelloMacro trait for each of their types, we’ll provide a procedural macro so users can annotate their type with #[derive(HelloMacro)] to get a default implementation of the hello_macro function. The default implementation will print Hello, Macro! My name is TypeName! where TypeName is the name of the type on which this trait has been defined. In other words, we’ll write a crate that enables another programmer to write code like Listing 19-30 using our crate.
@@ -86,16 +102,22 @@ Let’s create a crate named hello_macro that defines a trait named HelloMacro w
 Listing 19-30: The code a user of our crate will be able to write when using our procedural macro
 Filename: src/main.rs
 */
- [This code does not compile!] 
-use hello_macro::HelloMacro;
-use hello_macro_derive::HelloMacro;
+// [This code does not compile!]
+
+/// ```compile_fail
+/// use hello_macro::HelloMacro;
+/// use hello_macro_derive::HelloMacro;
+///
+/// #[derive(HelloMacro)]
+/// struct Pancakes;
+///
+/// fn main() {
+///     Pancakes::hello_macro();
+/// }
+/// ```
+pub mod listing_19_30 {}
 
-#[derive(HelloMacro)]
-struct Pancakes;
 
-fn main() {
-    Pancakes::hello_macro();
-}
 
 
 /*
@@ -112,29 +134,40 @@ Next, we’ll define the HelloMacro trait and its associated function:
 
 Filename: src/lib.rs
 */
-pub trait HelloMacro {
-    fn hello_macro();
+
+pub mod example_06 {
+    pub trait HelloMacro {
+        fn hello_macro();
+    }
 }
 
 
 
+
 /*
 We have a trait and its function. At this point, our crate user could implement the trait to achieve the desired functionality, like so:
 */
-use hello_macro::HelloMacro;
-
-struct Pancakes;
-
-impl HelloMacro for Pancakes {
-    fn hello_macro() {
-        println!("Hello, Macro! My name is Pancakes!");
-    }
-}
-
-fn main() {
-    Pancakes::hello_macro();
-}
 
+/// ```
+/// # mod hello_macro {
+/// #     pub use rust_book_examples::trpl::ch19_advanced_features::macros::example_06::HelloMacro;
+/// # }
+/// #
+/// use hello_macro::HelloMacro;
+///
+/// struct Pancakes;
+///
+/// impl HelloMacro for Pancakes {
+///     fn hello_macro() {
+///         println!("Hello, Macro! My name is Pancakes!");
+///     }
+/// }
+///
+/// fn main() {
+///     Pancakes::hello_macro();
+/// }
+/// ```
+pub mod example_07 {}
 
 
 
@@ -148,7 +181,6 @@ $ cargo new hello_macro_derive --lib
 
 
 
-
 /*
 We need to declare the hello_macro_derive crate as a procedural macro crate. We’ll also need functionality from the syn and quote crates, as you’ll see in a moment, so we need to add them as dependencies. Add the following to the Cargo.toml file for hello_macro_derive:
 
@@ -165,28 +197,30 @@ quote = "1.0"
 
 
 
-
 /*
 To start defining the procedural macro, place the code in Listing 19-31 into your src/lib.rs file for the hello_macro_derive crate. Note that this code won’t compile until we add a definition for the impl_hello_macro function.
 
 Listing 19-31: Code that most procedural macro crates will require in order to process Rust code
 Filename: hello_macro_derive/src/lib.rs
 */
- [This code does not compile!] 
-use proc_macro::TokenStream;
-use quote::quote;
-use syn;
-
-#[proc_macro_derive(HelloMacro)]
-pub fn hello_macro_derive(input: TokenStream) -> TokenStream {
-    // Construct a representation of Rust code as a syntax tree
-    // that we can manipulate
-    let ast = syn::parse(input).unwrap();
-
-    // Build the trait implementation
-    impl_hello_macro(&ast)
-}
+// [This code does not compile!]
 
+/// ```compile_fail
+/// use proc_macro::TokenStream;
+/// use quote::quote;
+/// use syn;
+///
+/// #[proc_macro_derive(HelloMacro)]
+/// pub fn hello_macro_derive(input: TokenStream) -> TokenStream {
+///     // Construct a representation of Rust code as a syntax tree
+///     // that we can manipulate
+///     let ast = syn::parse(input).unwrap();
+///
+///     // Build the trait implementation
+///     impl_hello_macro(&ast)
+/// }
+/// ```
+pub mod listing_19_31 {}
 
 
 
@@ -218,8 +252,6 @@ DeriveInput {
 
 
 
-
-
 /*
 
 Now that we have the code to turn the annotated Rust code from a TokenStream into a DeriveInput instance, let’s generate the code that implements the HelloMacro trait on the annotated type, as shown in Listing 19-33.
@@ -243,7 +275,6 @@ fn impl_hello_macro(ast: &syn::DeriveInput) -> TokenStream {
 
 
 
-
 /*
 At this point, cargo build should complete successfully in both hello_macro and hello_macro_derive. Let’s hook up these crates to the code in Listing 19-30 to see the procedural macro in action! Create a new binary project in your projects directory using cargo new pancakes. We need to add hello_macro and hello_macro_derive as dependencies in the pancakes crate’s Cargo.toml. If you’re publishing your versions of hello_macro and hello_macro_derive to crates.io, they would be regular dependencies; if not, you can specify them as path dependencies as follows:
 
@@ -254,10 +285,10 @@ hello_macro_derive = { path = "../hello_macro/hello_macro_derive" }
 
 
 
+// Attribute-like macros
 
 
 
-// Attribute-like macros
 
 /*
 Attribute-like macros are similar to custom derive macros, but instead of generating code for the derive attribute, they allow you to create new attributes. They’re also more flexible: derive only works for structs and enums; attributes can be applied to other items as well, such as functions. Here’s an example of using an attribute-like macro: say you have an attribute named route that annotates functions when using a web application framework:
@@ -279,9 +310,10 @@ pub fn route(attr: TokenStream, item: TokenStream) -> TokenStream {
 
 
 
+// Function-like macros
+
 
 
-// Function-like macros
 
 /*
 Function-like macros define macros that look like function calls. Similarly to macro_rules! macros, they’re more flexible than functions; for example, they can take an unknown number of arguments. However, macro_rules! macros can be defined only using the match-like syntax we discussed in the section “Declarative Macros with macro_rules! for General Metaprogramming” earlier. Function-like macros take a TokenStream parameter and their definition manipulates that TokenStream using Rust code as the other two types of procedural macros do. An example of a function-like macro is an sql! macro that might be called like so:
@@ -290,6 +322,8 @@ let sql = sql!(SELECT * FROM posts WHERE id=1);
 */
 
 
+
+
 /*
 This macro would parse the SQL statement inside it and check that it’s syntactically correct, which is much more complex processing than a macro_rules! macro can do. The sql! macro would be defined like this:
 
@@ -298,9 +332,3 @@ pub fn sql(input: TokenStream) -> TokenStream {
 
 This definition is similar to the custom derive macro’s signature: we receive the tokens that are inside the parentheses and return the code we wanted to generate.
 */
-
-
-
-
-
-
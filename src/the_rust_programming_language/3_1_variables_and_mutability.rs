@@ -1,55 +1,51 @@
-/// Variables and mutability
-/// https://doc.rust-lang.org/stable/book/ch03-01-variables-and-mutability.html
+// Variables and mutability
+// https://doc.rust-lang.org/stable/book/ch03-01-variables-and-mutability.html
 
 
 
-// Error - cannot assign twice to immutable variable
-fn main() {
-    let x = 5;
-    println!("The value of x is: {x}");
-    x = 6;
-    println!("The value of x is: {x}");
-}
 
+// Error - cannot assign twice to immutable variable
 
-// We’re allowed to change the value bound to x from 5 to 6 when mut is used.
-fn main() {
-    let mut x = 5;
-    println!("The value of x is: {x}");
-    x = 6;
-    println!("The value of x is: {x}");
-}
+/// ```compile_fail
+/// fn main() {
+///     let x = 5;
+///     println!("The value of x is: {x}");
+///     x = 6;
+///     println!("The value of x is: {x}");
+/// }
+/// ```
+pub mod example_01 {}
 
 
 
-// Shadowing
-fn main() {
-    let x = 5;
 
-    let x = x + 1;
+// We’re allowed to change the value bound to x from 5 to 6 when mut is used.
 
-    {
-        let x = x * 2;
-        println!("The value of x in the inner scope is: {x}");
+pub mod example_02 {
+    pub fn main() {
+        let mut x = 5;
+        println!("The value of x is: {x}");
+        x = 6;
+        println!("The value of x is: {x}");
     }
-
-    println!("The value of x is: {x}");
 }
 
 
 
 
+// Shadowing
 
+pub mod example_03 {
+    pub fn main() {
+        let x = 5;
 
+        let x = x + 1;
 
+        {
+            let x = x * 2;
+            println!("The value of x in the inner scope is: {x}");
+        }
 
-
-
-
-
-
-
-
-
-
-
+        println!("The value of x is: {x}");
+    }
+}
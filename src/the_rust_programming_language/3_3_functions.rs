@@ -1,84 +1,104 @@
 // Functions
 // https://doc.rust-lang.org/stable/book/ch03-03-how-functions-work.html
 
+pub mod example_01 {
+    pub fn main() {
+        println!("Hello, world!");
 
-fn main() {
-    println!("Hello, world!");
+        another_function();
+    }
 
-    another_function();
+    fn another_function() {
+        println!("Another function.");
+    }
 }
 
-fn another_function() {
-    println!("Another function.");
-}
 
 
 
 // Parameters
-fn main() {
-    another_function(5);
-}
 
-fn another_function(x: i32) {
-    println!("The value of x is: {x}");
-}
+pub mod example_02 {
+    pub fn main() {
+        another_function(5);
+    }
 
-
-// When defining multiple parameters, separate the parameter declarations with commas, like this:
-fn main() {
-    print_labeled_measurement(5, 'h');
+    fn another_function(x: i32) {
+        println!("The value of x is: {x}");
+    }
 }
 
-fn print_labeled_measurement(value: i32, unit_label: char) {
-    println!("The measurement is: {value}{unit_label}");
-}
 
 
 
+// When defining multiple parameters, separate the parameter declarations with commas, like this:
 
-// Statements and Expressions
-fn main() {
-    let y = {
-        let x = 3;
-        x + 1
-    };
+pub mod example_03 {
+    pub fn main() {
+        print_labeled_measurement(5, 'h');
+    }
 
-    println!("The value of y is: {y}");
+    fn print_labeled_measurement(value: i32, unit_label: char) {
+        println!("The measurement is: {value}{unit_label}");
+    }
 }
 
 
-// This expression:
-{
-    let x = 3;
-    x + 1
-}
 
 
-// Functions with Return Values
-fn five() -> i32 {
-    5
-}
+// Statements and Expressions
 
-fn main() {
-    let x = five();
+pub mod example_04 {
+    pub fn main() {
+        let y = {
+            let x = 3;
+            x + 1
+        };
 
-    println!("The value of x is: {x}");
+        println!("The value of y is: {y}");
+    }
 }
 
 
-fn main() {
-    let x = plus_one(5);
 
-    println!("The value of x is: {x}");
-}
-
-fn plus_one(x: i32) -> i32 {
-    x + 1
-}
 
+// This expression:
 
+/// ```compile_fail
+/// {
+///     let x = 3;
+///     x + 1
+/// }
+/// ```
+pub mod example_05 {}
 
 
 
 
+// Functions with Return Values
 
+/// ```
+/// # mod five {
+/// fn five() -> i32 {
+///     5
+/// }
+///
+/// fn main() {
+///     let x = five();
+///
+///     println!("The value of x is: {x}");
+/// }
+/// # }
+///
+///
+/// fn main() {
+///     let x = plus_one(5);
+///
+///     println!("The value of x is: {x}");
+/// }
+///
+/// fn plus_one(x: i32) -> i32 {
+///     x + 1
+/// }
+/// ```
+pub mod example_06 {}
@@ -4,7 +4,6 @@
 
 
 
-
 // So we’re doing something complicated here, long enough that we need
 // multiple lines of comments to do it! Whew! Hopefully, this comment will
 // explain what’s going on.
@@ -12,21 +11,19 @@
 
 
 
-let lucky_number = 7; // I’m feeling lucky today
-
+pub mod example_01 {
+    pub fn main() {
+        let lucky_number = 7; // I’m feeling lucky today
+    }
+}
 
 
 
 
 // I’m feeling lucky today
-let lucky_number = 7;
-
-
-
-
-
-
-
-
-
 
+pub mod example_02 {
+    pub fn main() {
+        let lucky_number = 7;
+    }
+}
@@ -3,24 +3,31 @@
 
 
 
+
 // if Expressions
-fn main() {
-    let number = 3;
 
-    if number < 5 {
-        println!("condition was true");
-    } else {
-        println!("condition was false");
+pub mod example_01 {
+    pub fn main() {
+        let number = 3;
+
+        if number < 5 {
+            println!("condition was true");
+        } else {
+            println!("condition was false");
+        }
     }
 }
 
 
 
-fn main() {
-    let number = 3;
 
-    if number != 0 {
-        println!("number was something other than zero");
+pub mod example_02 {
+    pub fn main() {
+        let number = 3;
+
+        if number != 0 {
+            println!("number was something other than zero");
+        }
     }
 }
 
@@ -28,47 +35,63 @@ fn main() {
 
 
 // Handling Multiple Conditions with else if
-fn main() {
-    let number = 6;
-
-    if number % 4 == 0 {
-        println!("number is divisible by 4");
-    } else if number % 3 == 0 {
-        println!("number is divisible by 3");
-    } else if number % 2 == 0 {
-        println!("number is divisible by 2");
-    } else {
-        println!("number is not divisible by 4, 3, or 2");
+
+pub mod example_03 {
+    pub fn main() {
+        let number = 6;
+
+        if number % 4 == 0 {
+            println!("number is divisible by 4");
+        } else if number % 3 == 0 {
+            println!("number is divisible by 3");
+        } else if number % 2 == 0 {
+            println!("number is divisible by 2");
+        } else {
+            println!("number is not divisible by 4, 3, or 2");
+        }
     }
 }
 
 
 
+
 // Using if in a let Statement
-fn main() {
-    let condition = true;
-    let number = if condition { 5 } else { 6 };
 
-    println!("The value of number is: {number}");
+pub mod example_04 {
+    pub fn main() {
+        let condition = true;
+        let number = if condition { 5 } else { 6 };
+
+        println!("The value of number is: {number}");
+    }
 }
 
 
 
+
 // Error - expected integer, found `&str`
-fn main() {
-    let condition = true;
 
-    let number = if condition { 5 } else { "six" };
+/// ```compile_fail
+/// fn main() {
+///     let condition = true;
+///
+///     let number = if condition { 5 } else { "six" };
+///
+///     println!("The value of number is: {number}");
+/// }
+/// ```
+pub mod example_05 {}
 
-    println!("The value of number is: {number}");
-}
 
 
 
 // Repeating Code with loop
-fn main() {
-    loop {
-        println!("again!");
+
+pub mod example_06 {
+    pub fn main() {
+        loop {
+            println!("again!");
+        }
     }
 }
 
@@ -76,72 +99,86 @@ fn main() {
 
 
 // Returning Values from Loops
-fn main() {
-    let mut counter = 0;
 
-    let result = loop {
-        counter += 1;
+pub mod example_07 {
+    pub fn main() {
+        let mut counter = 0;
 
-        if counter == 10 {
-            break counter * 2;
-        }
-    };
+        let result = loop {
+            counter += 1;
 
-    println!("The result is {result}");
+            if counter == 10 {
+                break counter * 2;
+            }
+        };
+
+        println!("The result is {result}");
+    }
 }
 
 
 
+
 // Loop Labels to Disambiguate Between Multiple Loops
-fn main() {
-    let mut count = 0;
-    'counting_up: loop {
-        println!("count = {count}");
-        let mut remaining = 10;
 
-        loop {
-            println!("remaining = {remaining}");
-            if remaining == 9 {
-                break;
-            }
-            if count == 2 {
-                break 'counting_up;
+pub mod example_08 {
+    pub fn main() {
+        let mut count = 0;
+        'counting_up: loop {
+            println!("count = {count}");
+            let mut remaining = 10;
+
+            loop {
+                println!("remaining = {remaining}");
+                if remaining == 9 {
+                    break;
+                }
+                if count == 2 {
+                    break 'counting_up;
+                }
+                remaining -= 1;
             }
-            remaining -= 1;
-        }
 
-        count += 1;
+            count += 1;
+        }
+        println!("End count = {count}");
     }
-    println!("End count = {count}");
 }
 
 
 
+
 // Conditional Loops with while
-fn main() {
-    let mut number = 3;
 
-    while number != 0 {
-        println!("{number}!");
+pub mod example_09 {
+    pub fn main() {
+        let mut number = 3;
 
-        number -= 1;
-    }
+        while number != 0 {
+            println!("{number}!");
 
-    println!("LIFTOFF!!!");
+            number -= 1;
+        }
+
+        println!("LIFTOFF!!!");
+    }
 }
 
 
 
 
 // Looping Through a Collection with for
-fn main() {
-    let a = [10, 20, 30, 40, 50];
-    let mut index = 0;
 
-    while index < 5 {
-        println!("the value is: {}", a[index]);
+pub mod example_10 {
+    pub fn main() {
+        let a = [10, 20, 30, 40, 50];
+        let mut index = 0;
+
+        while index < 5 {
+            println!("the value is: {}", a[index]);
 
-        index += 1;
+            index += 1;
+        }
     }
 }
 
@@ -149,35 +186,28 @@ fn main() {
 
 
 // Looping through each element of a collection using a for loop
-fn main() {
-    let a = [10, 20, 30, 40, 50];
 
-    for element in a {
-        println!("the value is: {element}");
-    }
-}
+pub mod example_11 {
+    pub fn main() {
+        let a = [10, 20, 30, 40, 50];
 
-
-// Here’s what the countdown would look like using a for loop and 
-// another method we’ve not yet talked about, rev, to reverse the range:
-fn main() {
-    for number in (1..4).rev() {
-        println!("{number}!");
+        for element in a {
+            println!("the value is: {element}");
+        }
     }
-    println!("LIFTOFF!!!");
 }
 
 
 
 
+// Here’s what the countdown would look like using a for loop and 
+// another method we’ve not yet talked about, rev, to reverse the range:
 
-
-
-
-
-
-
-
-
-
-
+pub mod example_12 {
+    pub fn main() {
+        for number in (1..4).rev() {
+            println!("{number}!");
+        }
+        println!("LIFTOFF!!!");
+    }
+}
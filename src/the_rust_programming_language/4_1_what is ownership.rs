@@ -4,174 +4,175 @@
 
 
 
-fn main() {
-    {                      // s is not valid here, it’s not yet declared
-        let s = "hello";   // s is valid from this point forward
-
-        // do stuff with s
-    }                      // this scope is now over, and s is no longer valid
+pub mod example_01 {
+    pub fn main() {
+        {                      // s is not valid here, it’s not yet declared
+            let s = "hello";   // s is valid from this point forward
+
+            // do stuff with s
+        }                      // this scope is now over, and s is no longer valid
+    }
 }
 
 
 
+
 // The String Type
-fn main() {
-    let mut s = String::from("hello");
 
-    s.push_str(", world!"); // push_str() appends a literal to a String
+pub mod example_02 {
+    pub fn main() {
+        let mut s = String::from("hello");
+
+        s.push_str(", world!"); // push_str() appends a literal to a String
 
-    println!("{}", s); // This will print `hello, world!`
+        println!("{}", s); // This will print `hello, world!`
+    }
 }
 
 
 
 
 // Memory and Allocation
-fn main() {
-    {
-        let s = String::from("hello"); // s is valid from this point forward
 
-        // do stuff with s
-    }                                  // this scope is now over, and s is no
-                                       // longer valid
+pub mod example_03 {
+    pub fn main() {
+        {
+            let s = String::from("hello"); // s is valid from this point forward
+
+            // do stuff with s
+        }                                  // this scope is now over, and s is no
+                                           // longer valid
+    }
 }
 
 
 
 
 // Error - value borrowed here after move
-fn main() {
-    let s1 = String::from("hello");
-    let s2 = s1;
 
-    println!("{}, world!", s1); // value borrowed here after move
-}
+/// ```compile_fail
+/// fn main() {
+///     let s1 = String::from("hello");
+///     let s2 = s1;
+///
+///     println!("{}, world!", s1); // value borrowed here after move
+/// }
+/// ```
+pub mod example_04 {}
 
 
 
-// Ways Variables and Data Interact: Clone
-fn main() {
-    let s1 = String::from("hello");
-    let s2 = s1.clone();
-
-    println!("s1 = {}, s2 = {}", s1, s2);
-}
-
 
+// Ways Variables and Data Interact: Clone
 
-// Stack-Only Data: Copy
-fn main() {
-    let x = 5;
-    let y = x;
+pub mod example_05 {
+    pub fn main() {
+        let s1 = String::from("hello");
+        let s2 = s1.clone();
 
-    println!("x = {}, y = {}", x, y);
+        println!("s1 = {}, s2 = {}", s1, s2);
+    }
 }
 
 
 
-// Ownership and Functions
-fn main() {
-    let s = String::from("hello");  // s comes into scope
-
-    takes_ownership(s);             // s's value moves into the function...
-                                    // ... and so is no longer valid here
-
-    let x = 5;                      // x comes into scope
-
-    makes_copy(x);                  // x would move into the function,
-                                    // but i32 is Copy, so it's okay to still
-                                    // use x afterward
-
-} // Here, x goes out of scope, then s. But because s's value was moved, nothing
-  // special happens.
-
-fn takes_ownership(some_string: String) { // some_string comes into scope
-    println!("{}", some_string);
-} // Here, some_string goes out of scope and `drop` is called. The backing
-  // memory is freed.
-
-fn makes_copy(some_integer: i32) { // some_integer comes into scope
-    println!("{}", some_integer);
-} // Here, some_integer goes out of scope. Nothing special happens.
-
-
-
 
+// Stack-Only Data: Copy
 
-// Return Values and Scope
-fn main() {
-    let s1 = gives_ownership();         // gives_ownership moves its return
-                                        // value into s1
-
-    let s2 = String::from("hello");     // s2 comes into scope
-
-    let s3 = takes_and_gives_back(s2);  // s2 is moved into
-                                        // takes_and_gives_back, which also
-                                        // moves its return value into s3
-} // Here, s3 goes out of scope and is dropped. s2 was moved, so nothing
-  // happens. s1 goes out of scope and is dropped.
-
-fn gives_ownership() -> String {             // gives_ownership will move its
-                                             // return value into the function
-                                             // that calls it
-
-    let some_string = String::from("yours"); // some_string comes into scope
-
-    some_string                              // some_string is returned and
-                                             // moves out to the calling
-                                             // function
-}
-
-// This function takes a String and returns one
-fn takes_and_gives_back(a_string: String) -> String { // a_string comes into
-                                                      // scope
+pub mod example_06 {
+    pub fn main() {
+        let x = 5;
+        let y = x;
 
-    a_string  // a_string is returned and moves out to the calling function
+        println!("x = {}, y = {}", x, y);
+    }
 }
 
 
 
 
+// Ownership and Functions
 
+pub mod example_07 {
+    pub fn main() {
+        let s = String::from("hello");  // s comes into scope
 
+        takes_ownership(s);             // s's value moves into the function...
+                                        // ... and so is no longer valid here
 
+        let x = 5;                      // x comes into scope
 
+        makes_copy(x);                  // x would move into the function,
+                                        // but i32 is Copy, so it's okay to still
+                                        // use x afterward
 
-// Returning ownership of parameters
-fn main() {
-    let s1 = String::from("hello");
-
-    let (s2, len) = calculate_length(s1);
-
-    println!("The length of '{}' is {}.", s2, len);
-}
+    } // Here, x goes out of scope, then s. But because s's value was moved, nothing
+      // special happens.
 
-fn calculate_length(s: String) -> (String, usize) {
-    let length = s.len(); // len() returns the length of a String
+    fn takes_ownership(some_string: String) { // some_string comes into scope
+        println!("{}", some_string);
+    } // Here, some_string goes out of scope and `drop` is called. The backing
+      // memory is freed.
 
-    (s, length)
+    fn makes_copy(some_integer: i32) { // some_integer comes into scope
+        println!("{}", some_integer);
+    } // Here, some_integer goes out of scope. Nothing special happens.
 }
 
 
 
 
+// Return Values and Scope
 
+pub mod example_08 {
+    pub fn main() {
+        let s1 = gives_ownership();         // gives_ownership moves its return
+                                            // value into s1
 
+        let s2 = String::from("hello");     // s2 comes into scope
 
+        let s3 = takes_and_gives_back(s2);  // s2 is moved into
+                                            // takes_and_gives_back, which also
+                                            // moves its return value into s3
+    } // Here, s3 goes out of scope and is dropped. s2 was moved, so nothing
+      // happens. s1 goes out of scope and is dropped.
 
+    fn gives_ownership() -> String {             // gives_ownership will move its
+                                                 // return value into the function
+                                                 // that calls it
 
+        let some_string = String::from("yours"); // some_string comes into scope
 
+        some_string                              // some_string is returned and
+                                                 // moves out to the calling
+                                                 // function
+    }
 
+    // This function takes a String and returns one
+    fn takes_and_gives_back(a_string: String) -> String { // a_string comes into
+                                                          // scope
 
+        a_string  // a_string is returned and moves out to the calling function
+    }
+}
 
 
 
 
+// Returning ownership of parameters
 
+pub mod example_09 {
+    pub fn main() {
+        let s1 = String::from("hello");
 
+        let (s2, len) = calculate_length(s1);
 
+        println!("The length of '{}' is {}.", s2, len);
+    }
 
+    fn calculate_length(s: String) -> (String, usize) {
+        let length = s.len(); // len() returns the length of a String
 
-
-
-
+        (s, length)
+    }
+}
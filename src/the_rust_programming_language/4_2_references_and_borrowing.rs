@@ -3,77 +3,82 @@
 
 
 
+
 // Here is how you would define and use a calculate_length function that has a 
 // reference to an object as a parameter instead of taking ownership of the value:
-fn main() {
-    let s1 = String::from("hello");
 
-    let len = calculate_length(&s1);
+pub mod example_01 {
+    pub fn main() {
+        let s1 = String::from("hello");
 
-    println!("The length of '{}' is {}.", s1, len);
-}
+        let len = calculate_length(&s1);
+
+        println!("The length of '{}' is {}.", s1, len);
+    }
 
-fn calculate_length(s: &String) -> usize {
-    s.len()
+    fn calculate_length(s: &String) -> usize {
+        s.len()
+    }
 }
 
 
 
 
 // Let’s take a closer look at the function call here:
-fn main() {
-    let s1 = String::from("hello");
 
-    let len = calculate_length(&s1);
+pub mod example_02 {
+    pub fn main() {
+        let s1 = String::from("hello");
 
-    println!("The length of '{}' is {}.", s1, len);
-}
-
-fn calculate_length(s: &String) -> usize {
-    s.len()
-}
+        let len = calculate_length(&s1);
 
+        println!("The length of '{}' is {}.", s1, len);
+    }
 
+    fn calculate_length(s: &String) -> usize {
+        s.len()
+    }
+}
 
 
 
 
 // Likewise, the signature of the function uses & to indicate that the 
 // type of the parameter s is a reference. Let’s add some explanatory annotations:
-fn main() {
-    let s1 = String::from("hello");
 
-    let len = calculate_length(&s1);
-
-    println!("The length of '{}' is {}.", s1, len);
-}
-
-fn calculate_length(s: &String) -> usize { // s is a reference to a String
-    s.len()
-} // Here, s goes out of scope. But because it does not have ownership of what
-  // it refers to, it is not dropped.
+pub mod example_03 {
+    pub fn main() {
+        let s1 = String::from("hello");
 
+        let len = calculate_length(&s1);
 
+        println!("The length of '{}' is {}.", s1, len);
+    }
 
+    fn calculate_length(s: &String) -> usize { // s is a reference to a String
+        s.len()
+    } // Here, s goes out of scope. But because it does not have ownership of what
+      // it refers to, it is not dropped.
+}
 
 
 
 
 // So what happens if we try to modify something we’re borrowing? Try the code 
 // in Listing 4-6. Spoiler alert: it doesn’t work!
-fn main() {
-    let s = String::from("hello");
-
-    change(&s);
-}
-
-fn change(some_string: &String) {
-    some_string.push_str(", world");
-}
-
-
-
 
+/// ```compile_fail
+/// fn main() {
+///     let s = String::from("hello");
+///
+///     change(&s);
+/// }
+///
+/// fn change(some_string: &String) {
+///     some_string.push_str(", world");
+/// }
+/// ```
+pub mod listing_04_06 {}
 
 
 
@@ -95,20 +100,19 @@ error: could not compile `ownership` due to previous error */
 
 
 
-
-
 // Mutable References
-fn main() {
-    let mut s = String::from("hello");
 
-    change(&mut s);
-}
-
-fn change(some_string: &mut String) {
-    some_string.push_str(", world");
-}
+pub mod example_05 {
+    pub fn main() {
+        let mut s = String::from("hello");
 
+        change(&mut s);
+    }
 
+    fn change(some_string: &mut String) {
+        some_string.push_str(", world");
+    }
+}
 
 
 
@@ -116,17 +120,18 @@ fn change(some_string: &mut String) {
 // Mutable references have one big restriction: if you have a mutable reference 
 // to a value, you can have no other references to that value. This code that 
 // attempts to create two mutable references to s will fail:
-fn main() {
-    let mut s = String::from("hello");
-
-    let r1 = &mut s;
-    let r2 = &mut s;
-
-    println!("{}, {}", r1, r2);
-}
-
-
 
+/// ```compile_fail
+/// fn main() {
+///     let mut s = String::from("hello");
+///
+///     let r1 = &mut s;
+///     let r2 = &mut s;
+///
+///     println!("{}, {}", r1, r2);
+/// }
+/// ```
+pub mod example_06 {}
 
 /* $ cargo run
    Compiling ownership v0.1.0 (file:///projects/ownership)
@@ -147,21 +152,21 @@ error: could not compile `ownership` due to previous error */
 
 
 
-
-
-
 // Rust enforces a similar rule for combining mutable and immutable 
 // references. This code results in an error:
-fn main() {
-    let mut s = String::from("hello");
-
-    let r1 = &s; // no problem
-    let r2 = &s; // no problem
-    let r3 = &mut s; // BIG PROBLEM
-
-    println!("{}, {}, and {}", r1, r2, r3);
-}
 
+/// ```compile_fail
+/// fn main() {
+///     let mut s = String::from("hello");
+///
+///     let r1 = &s; // no problem
+///     let r2 = &s; // no problem
+///     let r3 = &mut s; // BIG PROBLEM
+///
+///     println!("{}, {}, and {}", r1, r2, r3);
+/// }
+/// ```
+pub mod example_07 {}
 
 /* $ cargo run
    Compiling ownership v0.1.0 (file:///projects/ownership)
@@ -183,43 +188,37 @@ error: could not compile `ownership` due to previous error */
 
 
 
+pub mod example_08 {
+    pub fn main() {
+        let mut s = String::from("hello");
 
+        let r1 = &s; // no problem
+        let r2 = &s; // no problem
+        println!("{} and {}", r1, r2);
+        // variables r1 and r2 will not be used after this point
 
-
-
-
-
-fn main() {
-    let mut s = String::from("hello");
-
-    let r1 = &s; // no problem
-    let r2 = &s; // no problem
-    println!("{} and {}", r1, r2);
-    // variables r1 and r2 will not be used after this point
-
-    let r3 = &mut s; // no problem
-    println!("{}", r3);
+        let r3 = &mut s; // no problem
+        println!("{}", r3);
+    }
 }
 
 
 
 
-
-
-
-
 // Dangling References
-fn main() {
-    let reference_to_nothing = dangle();
-}
-
-fn dangle() -> &String {
-    let s = String::from("hello");
-
-    &s
-}
-
 
+/// ```compile_fail
+/// fn main() {
+///     let reference_to_nothing = dangle();
+/// }
+///
+/// fn dangle() -> &String {
+///     let s = String::from("hello");
+///
+///     &s
+/// }
+/// ```
+pub mod example_09 {}
 
 /* $ cargo run
    Compiling ownership v0.1.0 (file:///projects/ownership)
@@ -241,42 +240,36 @@ error: could not compile `ownership` due to previous error */
 
 
 
-
-
-
 // Let’s take a closer look at exactly what’s happening at each stage of our dangle code:
-fn main() {
-    let reference_to_nothing = dangle();
-}
-
-fn dangle() -> &String { // dangle returns a reference to a String
-
-    let s = String::from("hello"); // s is a new String
-
-    &s // we return a reference to the String, s
-} // Here, s goes out of scope, and is dropped. Its memory goes away.
-  // Danger!
-
 
+/// ```compile_fail
+/// fn main() {
+///     let reference_to_nothing = dangle();
+/// }
+///
+/// fn dangle() -> &String { // dangle returns a reference to a String
+///
+///     let s = String::from("hello"); // s is a new String
+///
+///     &s // we return a reference to the String, s
+/// } // Here, s goes out of scope, and is dropped. Its memory goes away.
+///   // Danger!
+/// ```
+pub mod example_10 {}
 
 
 
 
 // The solution here is to return the String directly:
-fn main() {
-    let string = no_dangle();
-}
-
-fn no_dangle() -> String {
-    let s = String::from("hello");
-
-    s
-}
-
-
-
-
-
 
+pub mod example_11 {
+    pub fn main() {
+        let string = no_dangle();
+    }
 
+    fn no_dangle() -> String {
+        let s = String::from("hello");
 
+        s
+    }
+}
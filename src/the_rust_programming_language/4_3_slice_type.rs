@@ -3,151 +3,161 @@
 
 
 
+
 // The first_word function that returns a byte index value into the String parameter
-fn first_word(s: &String) -> usize {
-    let bytes = s.as_bytes();
 
-    for (i, &item) in bytes.iter().enumerate() {
-        if item == b' ' {
-            return i;
+pub mod example_01 {
+    fn first_word(s: &String) -> usize {
+        let bytes = s.as_bytes();
+
+        for (i, &item) in bytes.iter().enumerate() {
+            if item == b' ' {
+                return i;
+            }
         }
+
+        s.len()
     }
 
-    s.len()
+    pub fn main() {}
 }
 
-fn main() {}
-
-
-
 
 
 
 // Listing 4-8: Storing the result from calling the first_word 
 // function and then changing the String contents
-fn first_word(s: &String) -> usize {
-    let bytes = s.as_bytes();
 
-    for (i, &item) in bytes.iter().enumerate() {
-        if item == b' ' {
-            return i;
+pub mod listing_04_08 {
+    fn first_word(s: &String) -> usize {
+        let bytes = s.as_bytes();
+
+        for (i, &item) in bytes.iter().enumerate() {
+            if item == b' ' {
+                return i;
+            }
         }
-    }
 
-    s.len()
-}
+        s.len()
+    }
 
-fn main() {
-    let mut s = String::from("hello world");
+    pub fn main() {
+        let mut s = String::from("hello world");
 
-    let word = first_word(&s); // word will get the value 5
+        let word = first_word(&s); // word will get the value 5
 
-    s.clear(); // this empties the String, making it equal to ""
+        s.clear(); // this empties the String, making it equal to ""
 
-    // word still has the value 5 here, but there's no more string that
-    // we could meaningfully use the value 5 with. word is now totally invalid!
+        // word still has the value 5 here, but there's no more string that
+        // we could meaningfully use the value 5 with. word is now totally invalid!
+    }
 }
 
 
 
 
-
-
 // String Slices
-fn main() {
-    let s = String::from("hello world");
-
-    let hello = &s[0..5];
-    let world = &s[6..11];
-}
 
+pub mod example_03 {
+    pub fn main() {
+        let s = String::from("hello world");
 
-
-
-
-#![allow(unused)]
-fn main() {
-let s = String::from("hello");
-
-let slice = &s[0..2];
-let slice = &s[..2];
+        let hello = &s[0..5];
+        let world = &s[6..11];
+    }
 }
 
 
 
 
+pub mod example_04 {
+    #![allow(unused)]
+    pub fn main() {
+    let s = String::from("hello");
 
-#![allow(unused)]
-fn main() {
-let s = String::from("hello");
-
-let len = s.len();
-
-let slice = &s[3..len];
-let slice = &s[3..];
+    let slice = &s[0..2];
+    let slice = &s[..2];
+    }
 }
 
 
 
 
-#![allow(unused)]
-fn main() {
-let s = String::from("hello");
+pub mod example_05 {
+    #![allow(unused)]
+    pub fn main() {
+    let s = String::from("hello");
 
-let len = s.len();
+    let len = s.len();
 
-let slice = &s[0..len];
-let slice = &s[..];
+    let slice = &s[3..len];
+    let slice = &s[3..];
+    }
 }
 
 
 
 
+pub mod example_06 {
+    #![allow(unused)]
+    pub fn main() {
+    let s = String::from("hello");
 
-fn first_word(s: &String) -> &str {
-    let bytes = s.as_bytes();
+    let len = s.len();
 
-    for (i, &item) in bytes.iter().enumerate() {
-        if item == b' ' {
-            return &s[0..i];
-        }
+    let slice = &s[0..len];
+    let slice = &s[..];
     }
-
-    &s[..]
 }
 
-fn main() {}
-
-
-
-
 
 
 
+pub mod example_07 {
+    fn first_word(s: &String) -> &str {
+        let bytes = s.as_bytes();
 
-// Here’s the compiler error:
-fn first_word(s: &String) -> &str {
-    let bytes = s.as_bytes();
-
-    for (i, &item) in bytes.iter().enumerate() {
-        if item == b' ' {
-            return &s[0..i];
+        for (i, &item) in bytes.iter().enumerate() {
+            if item == b' ' {
+                return &s[0..i];
+            }
         }
+
+        &s[..]
     }
 
-    &s[..]
+    pub fn main() {}
 }
 
-fn main() {
-    let mut s = String::from("hello world");
 
-    let word = first_word(&s);
 
-    s.clear(); // error!
 
-    println!("the first word is: {}", word);
-}
+// Here’s the compiler error:
 
+/// ```compile_fail
+/// fn first_word(s: &String) -> &str {
+///     let bytes = s.as_bytes();
+///
+///     for (i, &item) in bytes.iter().enumerate() {
+///         if item == b' ' {
+///             return &s[0..i];
+///         }
+///     }
+///
+///     &s[..]
+/// }
+///
+/// fn main() {
+///     let mut s = String::from("hello world");
+///
+///     let word = first_word(&s);
+///
+///     s.clear(); // error!
+///
+///     println!("the first word is: {}", word);
+/// }
+/// ```
+pub mod example_08 {}
 
 /* $ cargo run
    Compiling ownership v0.1.0 (file:///projects/ownership)
@@ -169,134 +179,127 @@ error: could not compile `ownership` due to previous error */
 
 
 
-
-
-
-
 // String Literals Are Slices
-#![allow(unused)]
-fn main() {
-let s = "Hello, world!";
-}
 
-
-
-
-
-
-
-
-// String Slices as Parameters
-fn first_word(s: &String) -> &str {}
-
-// Listing 4-9: Improving the first_word function by using a string 
-// slice for the type of the s parameter
-fn first_word(s: &str) -> &str {
-    let bytes = s.as_bytes();
-
-    for (i, &item) in bytes.iter().enumerate() {
-        if item == b' ' {
-            return &s[0..i];
-        }
+pub mod example_09 {
+    #![allow(unused)]
+    pub fn main() {
+    let s = "Hello, world!";
     }
-
-    &s[..]
 }
 
-fn main() {
-    let my_string = String::from("hello world");
-
-    // `first_word` works on slices of `String`s, whether partial or whole
-    let word = first_word(&my_string[0..6]);
-    let word = first_word(&my_string[..]);
-    // `first_word` also works on references to `String`s, which are equivalent
-    // to whole slices of `String`s
-    let word = first_word(&my_string);
 
-    let my_string_literal = "hello world";
 
-    // `first_word` works on slices of string literals, whether partial or whole
-    let word = first_word(&my_string_literal[0..6]);
-    let word = first_word(&my_string_literal[..]);
 
-    // Because string literals *are* string slices already,
-    // this works too, without the slice syntax!
-    let word = first_word(my_string_literal);
-}
+// String Slices as Parameters
 
+/// ```
+/// # mod before {
+/// fn first_word(s: &String) -> &str {
+/// #     &s[..]
+/// }
+/// # }
+///
+/// // Listing 4-9: Improving the first_word function by using a string 
+/// // slice for the type of the s parameter
+/// fn first_word(s: &str) -> &str {
+///     let bytes = s.as_bytes();
+///
+///     for (i, &item) in bytes.iter().enumerate() {
+///         if item == b' ' {
+///             return &s[0..i];
+///         }
+///     }
+///
+///     &s[..]
+/// }
+///
+/// fn main() {
+///     let my_string = String::from("hello world");
+///
+///     // `first_word` works on slices of `String`s, whether partial or whole
+///     let word = first_word(&my_string[0..6]);
+///     let word = first_word(&my_string[..]);
+///     // `first_word` also works on references to `String`s, which are equivalent
+///     // to whole slices of `String`s
+///     let word = first_word(&my_string);
+///
+///     let my_string_literal = "hello world";
+///
+///     // `first_word` works on slices of string literals, whether partial or whole
+///     let word = first_word(&my_string_literal[0..6]);
+///     let word = first_word(&my_string_literal[..]);
+///
+///     // Because string literals *are* string slices already,
+///     // this works too, without the slice syntax!
+///     let word = first_word(my_string_literal);
+/// }
+/// ```
+pub mod example_10 {}
 
 
 
 
 // Defining a function to take a string slice instead of a reference 
 // to a String makes our API more general and useful without losing any functionality:
-fn first_word(s: &str) -> &str {
-    let bytes = s.as_bytes();
 
-    for (i, &item) in bytes.iter().enumerate() {
-        if item == b' ' {
-            return &s[0..i];
+pub mod example_11 {
+    fn first_word(s: &str) -> &str {
+        let bytes = s.as_bytes();
+
+        for (i, &item) in bytes.iter().enumerate() {
+            if item == b' ' {
+                return &s[0..i];
+            }
         }
-    }
 
-    &s[..]
-}
+        &s[..]
+    }
 
-fn main() {
-    let my_string = String::from("hello world");
+    pub fn main() {
+        let my_string = String::from("hello world");
 
-    // `first_word` works on slices of `String`s, whether partial or whole
-    let word = first_word(&my_string[0..6]);
-    let word = first_word(&my_string[..]);
-    // `first_word` also works on references to `String`s, which are equivalent
-    // to whole slices of `String`s
-    let word = first_word(&my_string);
+        // `first_word` works on slices of `String`s, whether partial or whole
+        let word = first_word(&my_string[0..6]);
+        let word = first_word(&my_string[..]);
+        // `first_word` also works on references to `String`s, which are equivalent
+        // to whole slices of `String`s
+        let word = first_word(&my_string);
 
-    let my_string_literal = "hello world";
+        let my_string_literal = "hello world";
 
-    // `first_word` works on slices of string literals, whether partial or whole
-    let word = first_word(&my_string_literal[0..6]);
-    let word = first_word(&my_string_literal[..]);
+        // `first_word` works on slices of string literals, whether partial or whole
+        let word = first_word(&my_string_literal[0..6]);
+        let word = first_word(&my_string_literal[..]);
 
-    // Because string literals *are* string slices already,
-    // this works too, without the slice syntax!
-    let word = first_word(my_string_literal);
+        // Because string literals *are* string slices already,
+        // this works too, without the slice syntax!
+        let word = first_word(my_string_literal);
+    }
 }
 
 
 
 
-
-
-
-
-
 // Other Slices
 
-#![allow(unused)]
-fn main() {
-let a = [1, 2, 3, 4, 5];
-}
-
-
-
-#![allow(unused)]
-fn main() {
-let a = [1, 2, 3, 4, 5];
-
-let slice = &a[1..3];
-
-assert_eq!(slice, &[2, 3]);
+pub mod example_12 {
+    #![allow(unused)]
+    pub fn main() {
+    let a = [1, 2, 3, 4, 5];
+    }
 }
 
 
 
 
+pub mod example_13 {
+    #![allow(unused)]
+    pub fn main() {
+    let a = [1, 2, 3, 4, 5];
 
+    let slice = &a[1..3];
 
-
-
-
-
-
-
+    assert_eq!(slice, &[2, 3]);
+    }
+}
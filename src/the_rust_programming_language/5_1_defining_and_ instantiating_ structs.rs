@@ -3,93 +3,97 @@
 
 
 
-// For example, Listing 5-1 shows a struct that stores information about a user account.
-struct User {
-    active: bool,
-    username: String,
-    email: String,
-    sign_in_count: u64,
-}
-
-fn main() {}
 
+// For example, Listing 5-1 shows a struct that stores information about a user account.
 
+pub mod listing_05_01 {
+    struct User {
+        active: bool,
+        username: String,
+        email: String,
+        sign_in_count: u64,
+    }
 
+    pub fn main() {}
+}
 
 
 
 
 // Listing 5-2: Creating an instance of the User struct
-struct User {
-    active: bool,
-    username: String,
-    email: String,
-    sign_in_count: u64,
-}
 
-fn main() {
-    let user1 = User {
-        email: String::from("someone@example.com"),
-        username: String::from("someusername123"),
-        active: true,
-        sign_in_count: 1,
-    };
+pub mod listing_05_02 {
+    struct User {
+        active: bool,
+        username: String,
+        email: String,
+        sign_in_count: u64,
+    }
+
+    pub fn main() {
+        let user1 = User {
+            email: String::from("someone@example.com"),
+            username: String::from("someusername123"),
+            active: true,
+            sign_in_count: 1,
+        };
+    }
 }
 
 
 
 
 // Listing 5-3: Changing the value in the email field of a User instance
-struct User {
-    active: bool,
-    username: String,
-    email: String,
-    sign_in_count: u64,
-}
-
-fn main() {
-    let mut user1 = User {
-        email: String::from("someone@example.com"),
-        username: String::from("someusername123"),
-        active: true,
-        sign_in_count: 1,
-    };
-
-    user1.email = String::from("anotheremail@example.com");
-}
-
 
+pub mod listing_05_03 {
+    struct User {
+        active: bool,
+        username: String,
+        email: String,
+        sign_in_count: u64,
+    }
 
+    pub fn main() {
+        let mut user1 = User {
+            email: String::from("someone@example.com"),
+            username: String::from("someusername123"),
+            active: true,
+            sign_in_count: 1,
+        };
 
+        user1.email = String::from("anotheremail@example.com");
+    }
+}
 
 
 
 
 // Listing 5-4: A build_user function that takes an email and username and returns a User instance
-struct User {
-    active: bool,
-    username: String,
-    email: String,
-    sign_in_count: u64,
-}
 
-fn build_user(email: String, username: String) -> User {
-    User {
-        email: email,
-        username: username,
-        active: true,
-        sign_in_count: 1,
+pub mod listing_05_04 {
+    struct User {
+        active: bool,
+        username: String,
+        email: String,
+        sign_in_count: u64,
     }
-}
-
-fn main() {
-    let user1 = build_user(
-        String::from("someone@example.com"),
-        String::from("someusername123"),
-    );
-}
 
+    fn build_user(email: String, username: String) -> User {
+        User {
+            email: email,
+            username: username,
+            active: true,
+            sign_in_count: 1,
+        }
+    }
 
+    pub fn main() {
+        let user1 = build_user(
+            String::from("someone@example.com"),
+            String::from("someusername123"),
+        );
+    }
+}
 
 
 
@@ -98,29 +102,31 @@ fn main() {
 
 // Listing 5-5: A build_user function that uses field init shorthand 
 // because the email and username parameters have the same name as struct fields
-struct User {
-    active: bool,
-    username: String,
-    email: String,
-    sign_in_count: u64,
-}
 
-fn build_user(email: String, username: String) -> User {
-    User {
-        email,
-        username,
-        active: true,
-        sign_in_count: 1,
+pub mod listing_05_05 {
+    struct User {
+        active: bool,
+        username: String,
+        email: String,
+        sign_in_count: u64,
     }
-}
 
-fn main() {
-    let user1 = build_user(
-        String::from("someone@example.com"),
-        String::from("someusername123"),
-    );
-}
+    fn build_user(email: String, username: String) -> User {
+        User {
+            email,
+            username,
+            active: true,
+            sign_in_count: 1,
+        }
+    }
 
+    pub fn main() {
+        let user1 = build_user(
+            String::from("someone@example.com"),
+            String::from("someusername123"),
+        );
+    }
+}
 
 
 
@@ -128,29 +134,32 @@ fn main() {
 // Creating Instances From Other Instances With Struct Update Syntax
 
 // Listing 5-6: Creating a new User instance using one of the values from user1
-struct User {
-    active: bool,
-    username: String,
-    email: String,
-    sign_in_count: u64,
-}
 
-fn main() {
-    // --snip--
-
-    let user1 = User {
-        email: String::from("someone@example.com"),
-        username: String::from("someusername123"),
-        active: true,
-        sign_in_count: 1,
-    };
-
-    let user2 = User {
-        active: user1.active,
-        username: user1.username,
-        email: String::from("another@example.com"),
-        sign_in_count: user1.sign_in_count,
-    };
+pub mod listing_05_06 {
+    struct User {
+        active: bool,
+        username: String,
+        email: String,
+        sign_in_count: u64,
+    }
+
+    pub fn main() {
+        // --snip--
+
+        let user1 = User {
+            email: String::from("someone@example.com"),
+            username: String::from("someusername123"),
+            active: true,
+            sign_in_count: 1,
+        };
+
+        let user2 = User {
+            active: user1.active,
+            username: user1.username,
+            email: String::from("another@example.com"),
+            sign_in_count: user1.sign_in_count,
+        };
+    }
 }
 
 
@@ -158,29 +167,31 @@ fn main() {
 
 // Listing 5-7: Using struct update syntax to set a new email value 
 // for a User instance but use the rest of the values from user1
-struct User {
-    active: bool,
-    username: String,
-    email: String,
-    sign_in_count: u64,
-}
 
-fn main() {
-    // --snip--
+pub mod listing_05_07 {
+    struct User {
+        active: bool,
+        username: String,
+        email: String,
+        sign_in_count: u64,
+    }
 
-    let user1 = User {
-        email: String::from("someone@example.com"),
-        username: String::from("someusername123"),
-        active: true,
-        sign_in_count: 1,
-    };
+    pub fn main() {
+        // --snip--
 
-    let user2 = User {
-        email: String::from("another@example.com"),
-        ..user1
-    };
-}
+        let user1 = User {
+            email: String::from("someone@example.com"),
+            username: String::from("someusername123"),
+            active: true,
+            sign_in_count: 1,
+        };
 
+        let user2 = User {
+            email: String::from("another@example.com"),
+            ..user1
+        };
+    }
+}
 
 
 
@@ -190,55 +201,57 @@ fn main() {
 // To define a tuple struct, start with the struct keyword and the struct 
 // name followed by the types in the tuple. For example, here we define and 
 // use two tuple structs named Color and Point:
-struct Color(i32, i32, i32);
-struct Point(i32, i32, i32);
-
-fn main() {
-    let black = Color(0, 0, 0);
-    let origin = Point(0, 0, 0);
-}
 
+pub mod example_08 {
+    struct Color(i32, i32, i32);
+    struct Point(i32, i32, i32);
 
+    pub fn main() {
+        let black = Color(0, 0, 0);
+        let origin = Point(0, 0, 0);
+    }
+}
 
 
 
 
 // Unit-Like Structs Without Any Fields
 
-struct AlwaysEqual;
+pub mod example_09 {
+    struct AlwaysEqual;
 
-fn main() {
-    let subject = AlwaysEqual;
+    pub fn main() {
+        let subject = AlwaysEqual;
+    }
 }
 
 
 
 
-
 // Ownership of Struct Data
 
 // Lifetimes ensure that the data referenced by a struct is valid for as long as 
 // the struct is. Let’s say you try to store a reference in a struct without specifying 
 // lifetimes, like the following; this won’t work:
-struct User {
-    active: bool,
-    username: &str,
-    email: &str,
-    sign_in_count: u64,
-}
-
-fn main() {
-    let user1 = User {
-        email: "someone@example.com",
-        username: "someusername123",
-        active: true,
-        sign_in_count: 1,
-    };
-}
-
-
-
 
+/// ```compile_fail
+/// struct User {
+///     active: bool,
+///     username: &str,
+///     email: &str,
+///     sign_in_count: u64,
+/// }
+///
+/// fn main() {
+///     let user1 = User {
+///         email: "someone@example.com",
+///         username: "someusername123",
+///         active: true,
+///         sign_in_count: 1,
+///     };
+/// }
+/// ```
+pub mod example_10 {}
 
 /* $ cargo run
    Compiling structs v0.1.0 (file:///projects/structs)
@@ -271,13 +284,3 @@ help: consider introducing a named lifetime parameter
 
 For more information about this error, try `rustc --explain E0106`.
 error: could not compile `structs` due to 2 previous errors */
-
-
-
-
-
-
-
-
-
-
@@ -4,40 +4,44 @@
 
 
 
-
 // Listing 5-8: Calculating the area of a rectangle specified by separate width and height variables
-fn main() {
-    let width1 = 30;
-    let height1 = 50;
-
-    println!(
-        "The area of the rectangle is {} square pixels.",
-        area(width1, height1)
-    );
-}
 
-fn area(width: u32, height: u32) -> u32 {
-    width * height
+pub mod listing_05_08 {
+    pub fn main() {
+        let width1 = 30;
+        let height1 = 50;
+
+        println!(
+            "The area of the rectangle is {} square pixels.",
+            area(width1, height1)
+        );
+    }
+
+    fn area(width: u32, height: u32) -> u32 {
+        width * height
+    }
 }
 
 
 
 
 // The issue with this code is evident in the signature of area:
-fn main() {
-    let width1 = 30;
-    let height1 = 50;
-
-    println!(
-        "The area of the rectangle is {} square pixels.",
-        area(width1, height1)
-    );
-}
 
-fn area(width: u32, height: u32) -> u32 {
-    width * height
-}
+pub mod example_02 {
+    pub fn main() {
+        let width1 = 30;
+        let height1 = 50;
 
+        println!(
+            "The area of the rectangle is {} square pixels.",
+            area(width1, height1)
+        );
+    }
+
+    fn area(width: u32, height: u32) -> u32 {
+        width * height
+    }
+}
 
 
 
@@ -45,17 +49,20 @@ fn area(width: u32, height: u32) -> u32 {
 // Refactoring with Tuples
 
 // Listing 5-9: Specifying the width and height of the rectangle with a tuple
-fn main() {
-    let rect1 = (30, 50);
 
-    println!(
-        "The area of the rectangle is {} square pixels.",
-        area(rect1)
-    );
-}
+pub mod listing_05_09 {
+    pub fn main() {
+        let rect1 = (30, 50);
 
-fn area(dimensions: (u32, u32)) -> u32 {
-    dimensions.0 * dimensions.1
+        println!(
+            "The area of the rectangle is {} square pixels.",
+            area(rect1)
+        );
+    }
+
+    fn area(dimensions: (u32, u32)) -> u32 {
+        dimensions.0 * dimensions.1
+    }
 }
 
 
@@ -64,27 +71,29 @@ fn area(dimensions: (u32, u32)) -> u32 {
 // Refactoring with Structs: Adding More Meaning
 
 // Listing 5-10: Defining a Rectangle struct
-struct Rectangle {
-    width: u32,
-    height: u32,
-}
 
-fn main() {
-    let rect1 = Rectangle {
-        width: 30,
-        height: 50,
-    };
+pub mod listing_05_10 {
+    struct Rectangle {
+        width: u32,
+        height: u32,
+    }
 
-    println!(
-        "The area of the rectangle is {} square pixels.",
-        area(&rect1)
-    );
-}
+    pub fn main() {
+        let rect1 = Rectangle {
+            width: 30,
+            height: 50,
+        };
 
-fn area(rectangle: &Rectangle) -> u32 {
-    rectangle.width * rectangle.height
-}
+        println!(
+            "The area of the rectangle is {} square pixels.",
+            area(&rect1)
+        );
+    }
 
+    fn area(rectangle: &Rectangle) -> u32 {
+        rectangle.width * rectangle.height
+    }
+}
 
 
 
@@ -92,21 +101,23 @@ fn area(rectangle: &Rectangle) -> u32 {
 // Adding Useful Functionality with Derived Traits
 
 // Listing 5-11 tries using the println! macro as we have used in previous chapters. This won’t work, however.
-struct Rectangle {
-    width: u32,
-    height: u32,
-}
-
-fn main() {
-    let rect1 = Rectangle {
-        width: 30,
-        height: 50,
-    };
-
-    println!("rect1 is {}", rect1);
-}
-
 
+/// ```compile_fail
+/// struct Rectangle {
+///     width: u32,
+///     height: u32,
+/// }
+///
+/// fn main() {
+///     let rect1 = Rectangle {
+///         width: 30,
+///         height: 50,
+///     };
+///
+///     println!("rect1 is {}", rect1);
+/// }
+/// ```
+pub mod listing_05_11 {}
 
 /* error[E0277]: `Rectangle` doesn't implement `std::fmt::Display`
 
@@ -124,61 +135,55 @@ error[E0277]: `Rectangle` doesn't implement `Debug`
 
 // Listing 5-12: Adding the attribute to derive the Debug trait and printing the 
 // Rectangle instance using debug formatting
-#[derive(Debug)]
-struct Rectangle {
-    width: u32,
-    height: u32,
-}
 
-fn main() {
-    let rect1 = Rectangle {
-        width: 30,
-        height: 50,
-    };
-
-    println!("rect1 is {:?}", rect1);
-}
+pub mod listing_05_12 {
+    #[derive(Debug)]
+    struct Rectangle {
+        width: u32,
+        height: u32,
+    }
 
+    pub fn main() {
+        let rect1 = Rectangle {
+            width: 30,
+            height: 50,
+        };
 
+        println!("rect1 is {:?}", rect1);
+    }
+}
 
 
 
 
 // Here’s an example where we’re interested in the value that gets assigned to 
 // the width field, as well as the value of the whole struct in rect1:
-#[derive(Debug)]
-struct Rectangle {
-    width: u32,
-    height: u32,
-}
 
-fn main() {
-    let scale = 2;
-    let rect1 = Rectangle {
-        width: dbg!(30 * scale),
+pub mod example_07 {
+    #[derive(Debug)]
+    struct Rectangle {
+        width: u32,
+        height: u32,
+    }
+
+    pub fn main() {
+        let scale = 2;
+        let rect1 = Rectangle {
+            width: dbg!(30 * scale),
+            height: 50,
+        };
+
+        dbg!(&rect1);
+    }
+
+    /* $ cargo run
+       Compiling rectangles v0.1.0 (file:///projects/rectangles)
+        Finished dev [unoptimized + debuginfo] target(s) in 0.61s
+         Running `target/debug/rectangles`
+    [src/main.rs:10] 30 * scale = 60
+    [src/main.rs:14] &rect1 = Rectangle {
+        width: 60,
         height: 50,
-    };
-
-    dbg!(&rect1);
+    }
+    */
 }
-
-/* $ cargo run
-   Compiling rectangles v0.1.0 (file:///projects/rectangles)
-    Finished dev [unoptimized + debuginfo] target(s) in 0.61s
-     Running `target/debug/rectangles`
-[src/main.rs:10] 30 * scale = 60
-[src/main.rs:14] &rect1 = Rectangle {
-    width: 60,
-    height: 50,
-}
-*/
-
-
-
-
-
-
-
-
-
-
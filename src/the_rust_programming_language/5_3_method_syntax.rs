@@ -4,30 +4,32 @@
 
 
 
-
 // Listing 5-13: Defining an area method on the Rectangle struct
-#[derive(Debug)]
-struct Rectangle {
-    width: u32,
-    height: u32,
-}
 
-impl Rectangle {
-    fn area(&self) -> u32 {
-        self.width * self.height
+pub mod listing_05_13 {
+    #[derive(Debug)]
+    struct Rectangle {
+        width: u32,
+        height: u32,
+    }
+
+    impl Rectangle {
+        fn area(&self) -> u32 {
+            self.width * self.height
+        }
     }
-}
 
-fn main() {
-    let rect1 = Rectangle {
-        width: 30,
-        height: 50,
-    };
+    pub fn main() {
+        let rect1 = Rectangle {
+            width: 30,
+            height: 50,
+        };
 
-    println!(
-        "The area of the rectangle is {} square pixels.",
-        rect1.area()
-    );
+        println!(
+            "The area of the rectangle is {} square pixels.",
+            rect1.area()
+        );
+    }
 }
 
 
@@ -35,30 +37,31 @@ fn main() {
 
 // Note that we can choose to give a method the same name as one of the 
 // struct’s fields. For example, we can define a method on Rectangle also named width:
-#[derive(Debug)]
-struct Rectangle {
-    width: u32,
-    height: u32,
-}
 
-impl Rectangle {
-    fn width(&self) -> bool {
-        self.width > 0
+pub mod example_02 {
+    #[derive(Debug)]
+    struct Rectangle {
+        width: u32,
+        height: u32,
     }
-}
-
-fn main() {
-    let rect1 = Rectangle {
-        width: 30,
-        height: 50,
-    };
 
-    if rect1.width() {
-        println!("The rectangle has a nonzero width; it is {}", rect1.width);
+    impl Rectangle {
+        fn width(&self) -> bool {
+            self.width > 0
+        }
     }
-}
 
+    pub fn main() {
+        let rect1 = Rectangle {
+            width: 30,
+            height: 50,
+        };
 
+        if rect1.width() {
+            println!("The rectangle has a nonzero width; it is {}", rect1.width);
+        }
+    }
+}
 
 
 
@@ -66,23 +69,40 @@ fn main() {
 // Methods with More Parameters
 
 // Listing 5-14: Using the as-yet-unwritten can_hold method
-fn main() {
-    let rect1 = Rectangle {
-        width: 30,
-        height: 50,
-    };
-    let rect2 = Rectangle {
-        width: 10,
-        height: 40,
-    };
-    let rect3 = Rectangle {
-        width: 60,
-        height: 45,
-    };
-
-    println!("Can rect1 hold rect2? {}", rect1.can_hold(&rect2));
-    println!("Can rect1 hold rect3? {}", rect1.can_hold(&rect3));
-}
+
+/// ```
+/// # struct Rectangle {
+/// #     width: u32,
+/// #     height: u32,
+/// # }
+/// #
+/// # impl Rectangle {
+/// #     fn can_hold(&self, other: &Rectangle) -> bool {
+/// #         self.width > other.width && self.height > other.height
+/// #     }
+/// # }
+/// #
+/// fn main() {
+///     let rect1 = Rectangle {
+///         width: 30,
+///         height: 50,
+///     };
+///     let rect2 = Rectangle {
+///         width: 10,
+///         height: 40,
+///     };
+///     let rect3 = Rectangle {
+///         width: 60,
+///         height: 45,
+///     };
+///
+///     println!("Can rect1 hold rect2? {}", rect1.can_hold(&rect2));
+///     println!("Can rect1 hold rect3? {}", rect1.can_hold(&rect3));
+/// }
+/// ```
+pub mod listing_05_14 {}
+
+
 
 
 /* 
@@ -93,127 +113,117 @@ fn main() {
 
 
 
-
 // Listing 5-15: Implementing the can_hold method on Rectangle that takes another 
 // Rectangle instance as a parameter
-#[derive(Debug)]
-struct Rectangle {
-    width: u32,
-    height: u32,
-}
 
-impl Rectangle {
-    fn area(&self) -> u32 {
-        self.width * self.height
+pub mod listing_05_15 {
+    #[derive(Debug)]
+    struct Rectangle {
+        width: u32,
+        height: u32,
     }
 
-    fn can_hold(&self, other: &Rectangle) -> bool {
-        self.width > other.width && self.height > other.height
+    impl Rectangle {
+        fn area(&self) -> u32 {
+            self.width * self.height
+        }
+
+        fn can_hold(&self, other: &Rectangle) -> bool {
+            self.width > other.width && self.height > other.height
+        }
     }
-}
 
-fn main() {
-    let rect1 = Rectangle {
-        width: 30,
-        height: 50,
-    };
-    let rect2 = Rectangle {
-        width: 10,
-        height: 40,
-    };
-    let rect3 = Rectangle {
-        width: 60,
-        height: 45,
-    };
-
-    println!("Can rect1 hold rect2? {}", rect1.can_hold(&rect2));
-    println!("Can rect1 hold rect3? {}", rect1.can_hold(&rect3));
+    pub fn main() {
+        let rect1 = Rectangle {
+            width: 30,
+            height: 50,
+        };
+        let rect2 = Rectangle {
+            width: 10,
+            height: 40,
+        };
+        let rect3 = Rectangle {
+            width: 60,
+            height: 45,
+        };
+
+        println!("Can rect1 hold rect2? {}", rect1.can_hold(&rect2));
+        println!("Can rect1 hold rect3? {}", rect1.can_hold(&rect3));
+    }
 }
 
 
 
 
-
-
-
-
 // Associated Functions
 
 // For example, we could choose to provide an associated function named square that would 
 // have one dimension parameter and use that as both width and height, thus making it easier 
 // to create a square Rectangle rather than having to specify the same value twice:
-#[derive(Debug)]
-struct Rectangle {
-    width: u32,
-    height: u32,
-}
 
-impl Rectangle {
-    fn square(size: u32) -> Self {
-        Self {
-            width: size,
-            height: size,
+pub mod example_05 {
+    #[derive(Debug)]
+    struct Rectangle {
+        width: u32,
+        height: u32,
+    }
+
+    impl Rectangle {
+        fn square(size: u32) -> Self {
+            Self {
+                width: size,
+                height: size,
+            }
         }
     }
-}
 
-fn main() {
-    let sq = Rectangle::square(3);
+    pub fn main() {
+        let sq = Rectangle::square(3);
+    }
 }
 
 
 
 
-
-
-
-
 // Multiple impl Blocks
 
 // Each struct is allowed to have multiple impl blocks. For example, Listing 5-15 
 // is equivalent to the code shown in Listing 5-16, which has each method in its own impl block.
-#[derive(Debug)]
-struct Rectangle {
-    width: u32,
-    height: u32,
-}
 
-impl Rectangle {
-    fn area(&self) -> u32 {
-        self.width * self.height
+pub mod listing_05_16 {
+    #[derive(Debug)]
+    struct Rectangle {
+        width: u32,
+        height: u32,
     }
-}
 
-impl Rectangle {
-    fn can_hold(&self, other: &Rectangle) -> bool {
-        self.width > other.width && self.height > other.height
+    impl Rectangle {
+        fn area(&self) -> u32 {
+            self.width * self.height
+        }
     }
-}
-
-fn main() {
-    let rect1 = Rectangle {
-        width: 30,
-        height: 50,
-    };
-    let rect2 = Rectangle {
-        width: 10,
-        height: 40,
-    };
-    let rect3 = Rectangle {
-        width: 60,
-        height: 45,
-    };
-
-    println!("Can rect1 hold rect2? {}", rect1.can_hold(&rect2));
-    println!("Can rect1 hold rect3? {}", rect1.can_hold(&rect3));
-}
-
-
-
-
-
-
-
-
 
+    impl Rectangle {
+        fn can_hold(&self, other: &Rectangle) -> bool {
+            self.width > other.width && self.height > other.height
+        }
+    }
 
+    pub fn main() {
+        let rect1 = Rectangle {
+            width: 30,
+            height: 50,
+        };
+        let rect2 = Rectangle {
+            width: 10,
+            height: 40,
+        };
+        let rect3 = Rectangle {
+            width: 60,
+            height: 45,
+        };
+
+        println!("Can rect1 hold rect2? {}", rect1.can_hold(&rect2));
+        println!("Can rect1 hold rect3? {}", rect1.can_hold(&rect3));
+    }
+}
@@ -3,23 +3,26 @@
 
 
 
+
 // We can express this concept in code by defining an IpAddrKind enumeration and 
 // listing the possible kinds an IP address can be, V4 and V6. These are the variants of the enum:
-enum IpAddrKind {
-    V4,
-    V6,
-}
 
-fn main() {
-    let four = IpAddrKind::V4;
-    let six = IpAddrKind::V6;
+pub mod example_01 {
+    enum IpAddrKind {
+        V4,
+        V6,
+    }
 
-    route(IpAddrKind::V4);
-    route(IpAddrKind::V6);
-}
+    pub fn main() {
+        let four = IpAddrKind::V4;
+        let six = IpAddrKind::V6;
 
-fn route(ip_kind: IpAddrKind) {}
+        route(IpAddrKind::V4);
+        route(IpAddrKind::V6);
+    }
 
+    fn route(ip_kind: IpAddrKind) {}
+}
 
 
 
@@ -27,136 +30,120 @@ fn route(ip_kind: IpAddrKind) {}
 // Enum Values
 
 // We can create instances of each of the two variants of IpAddrKind like this:
-enum IpAddrKind {
-    V4,
-    V6,
-}
-
-fn main() {
-    let four = IpAddrKind::V4;
-    let six = IpAddrKind::V6;
 
-    route(IpAddrKind::V4);
-    route(IpAddrKind::V6);
-}
-
-fn route(ip_kind: IpAddrKind) {}
-
-
-
-
-// Listing 6-1: Storing the data and IpAddrKind variant of an IP address using a struct
-fn main() {
+pub mod example_02 {
     enum IpAddrKind {
         V4,
         V6,
     }
 
-    struct IpAddr {
-        kind: IpAddrKind,
-        address: String,
-    }
+    pub fn main() {
+        let four = IpAddrKind::V4;
+        let six = IpAddrKind::V6;
 
-    let home = IpAddr {
-        kind: IpAddrKind::V4,
-        address: String::from("127.0.0.1"),
-    };
+        route(IpAddrKind::V4);
+        route(IpAddrKind::V6);
+    }
 
-    let loopback = IpAddr {
-        kind: IpAddrKind::V6,
-        address: String::from("::1"),
-    };
+    fn route(ip_kind: IpAddrKind) {}
 }
 
 
 
 
-// This new definition of the IpAddr enum says that both V4 and V6 variants will 
-// have associated String values:
-fn main() {
-    enum IpAddr {
-        V4(String),
-        V6(String),
-    }
-
-    let home = IpAddr::V4(String::from("127.0.0.1"));
-
-    let loopback = IpAddr::V6(String::from("::1"));
-}
+// Listing 6-1: Storing the data and IpAddrKind variant of an IP address using a struct
 
+pub mod listing_06_01 {
+    pub fn main() {
+        enum IpAddrKind {
+            V4,
+            V6,
+        }
 
+        struct IpAddr {
+            kind: IpAddrKind,
+            address: String,
+        }
 
+        let home = IpAddr {
+            kind: IpAddrKind::V4,
+            address: String::from("127.0.0.1"),
+        };
 
-// If we wanted to store V4 addresses as four u8 values but still express V6 
-// addresses as one String value, we wouldn’t be able to with a struct. Enums 
-// handle this case with ease:
-fn main() {
-    enum IpAddr {
-        V4(u8, u8, u8, u8),
-        V6(String),
+        let loopback = IpAddr {
+            kind: IpAddrKind::V6,
+            address: String::from("::1"),
+        };
     }
-
-    let home = IpAddr::V4(127, 0, 0, 1);
-
-    let loopback = IpAddr::V6(String::from("::1"));
 }
 
 
 
 
+// This new definition of the IpAddr enum says that both V4 and V6 variants will 
+// have associated String values:
 
+pub mod example_04 {
+    pub fn main() {
+        enum IpAddr {
+            V4(String),
+            V6(String),
+        }
 
+        let home = IpAddr::V4(String::from("127.0.0.1"));
 
-#![allow(unused)]
-fn main() {
-struct Ipv4Addr {
-    // --snip--
+        let loopback = IpAddr::V6(String::from("::1"));
+    }
 }
 
-struct Ipv6Addr {
-    // --snip--
-}
 
-enum IpAddr {
-    V4(Ipv4Addr),
-    V6(Ipv6Addr),
-}
-}
 
 
+// If we wanted to store V4 addresses as four u8 values but still express V6 
+// addresses as one String value, we wouldn’t be able to with a struct. Enums 
+// handle this case with ease:
+
+pub mod example_05 {
+    pub fn main() {
+        enum IpAddr {
+            V4(u8, u8, u8, u8),
+            V6(String),
+        }
 
+        let home = IpAddr::V4(127, 0, 0, 1);
 
-// Let’s look at another example of an enum in Listing 6-2: this one has a wide 
-// variety of types embedded in its variants.
-enum Message {
-    Quit,
-    Move { x: i32, y: i32 },
-    Write(String),
-    ChangeColor(i32, i32, i32),
+        let loopback = IpAddr::V6(String::from("::1"));
+    }
 }
 
-fn main() {}
 
 
 
+pub mod example_06 {
+    #![allow(unused)]
+    pub fn main() {
+    struct Ipv4Addr {
+        // --snip--
+    }
 
+    struct Ipv6Addr {
+        // --snip--
+    }
 
-struct QuitMessage; // unit struct
-struct MoveMessage {
-    x: i32,
-    y: i32,
+    enum IpAddr {
+        V4(Ipv4Addr),
+        V6(Ipv6Addr),
+    }
+    }
 }
-struct WriteMessage(String); // tuple struct
-struct ChangeColorMessage(i32, i32, i32); // tuple struct
-
-fn main() {}
-
 
 
 
 
+// Let’s look at another example of an enum in Listing 6-2: this one has a wide 
+// variety of types embedded in its variants.
 
-fn main() {
+pub mod listing_06_02 {
     enum Message {
         Quit,
         Move { x: i32, y: i32 },
@@ -164,19 +151,46 @@ fn main() {
         ChangeColor(i32, i32, i32),
     }
 
-    impl Message {
-        fn call(&self) {
-            // method body would be defined here
-        }
+    pub fn main() {}
+}
+
+
+
+
+pub mod example_08 {
+    struct QuitMessage; // unit struct
+    struct MoveMessage {
+        x: i32,
+        y: i32,
     }
+    struct WriteMessage(String); // tuple struct
+    struct ChangeColorMessage(i32, i32, i32); // tuple struct
 
-    let m = Message::Write(String::from("hello"));
-    m.call();
+    pub fn main() {}
 }
 
 
 
 
+pub mod example_09 {
+    pub fn main() {
+        enum Message {
+            Quit,
+            Move { x: i32, y: i32 },
+            Write(String),
+            ChangeColor(i32, i32, i32),
+        }
+
+        impl Message {
+            fn call(&self) {
+                // method body would be defined here
+            }
+        }
+
+        let m = Message::Write(String::from("hello"));
+        m.call();
+    }
+}
 
 
 
@@ -185,37 +199,43 @@ fn main() {
 
 
 
-#![allow(unused)]
-fn main() {
-enum Option<T> {
-    None,
-    Some(T),
-}
+
+pub mod example_10 {
+    #![allow(unused)]
+    pub fn main() {
+    enum Option<T> {
+        None,
+        Some(T),
+    }
+    }
 }
 
 
 
 
-fn main() {
-    let some_number = Some(5);
-    let some_char = Some('e');
+pub mod example_11 {
+    pub fn main() {
+        let some_number = Some(5);
+        let some_char = Some('e');
 
-    let absent_number: Option<i32> = None;
+        let absent_number: Option<i32> = None;
+    }
 }
 
 
 
 
-
-
 // For example, this code won’t compile because it’s trying to add an i8 to an Option<i8>:
-fn main() {
-    let x: i8 = 5;
-    let y: Option<i8> = Some(5);
-
-    let sum = x + y;
-}
 
+/// ```compile_fail
+/// fn main() {
+///     let x: i8 = 5;
+///     let y: Option<i8> = Some(5);
+///
+///     let sum = x + y;
+/// }
+/// ```
+pub mod example_12 {}
 
 /* $ cargo run
    Compiling enums v0.1.0 (file:///projects/enums)
@@ -230,14 +250,3 @@ error[E0277]: cannot add `Option<i8>` to `i8`
 For more information about this error, try `rustc --explain E0277`.
 error: could not compile `enums` due to previous error
 */
-
-
-
-
-
-
-
-
-
-
-
@@ -3,6 +3,7 @@
 
 
 
+
 /*
 backyard
 ├── Cargo.lock
@@ -16,29 +17,40 @@ backyard
 
 
 
-// The crate root file in this case is src/main.rs, and it contains:
-use crate::garden::vegetables::Asparagus;
 
-pub mod garden;
+// The crate root file in this case is src/main.rs, and it contains:
 
-fn main() {
-    let plant = Asparagus {};
-    println!("I'm growing {:?}!", plant);
-}
+/// ```ignore
+/// use crate::garden::vegetables::Asparagus;
+///
+/// pub mod garden;
+///
+/// fn main() {
+///     let plant = Asparagus {};
+///     println!("I'm growing {:?}!", plant);
+/// }
+/// ```
+pub mod example_01 {}
 
 
 
 
 // Filename: src/garden.rs
-pub mod vegetables;
+
+/// ```ignore
+/// pub mod vegetables;
+/// ```
+pub mod example_02 {}
 
 
 
-// Here, pub mod vegetables; means the code in src/garden/vegetables.rs is included too. That code is:
-#[derive(Debug)]
-pub struct Asparagus {}
 
+// Here, pub mod vegetables; means the code in src/garden/vegetables.rs is included too. That code is:
 
+pub mod example_03 {
+    #[derive(Debug)]
+    pub struct Asparagus {}
+}
 
 
 
@@ -46,19 +58,22 @@ pub struct Asparagus {}
 // Grouping Related Code in Modules
 
 // Listing 7-1: A front_of_house module containing other modules that then contain functions
-mod front_of_house {
-    mod hosting {
-        fn add_to_waitlist() {}
 
-        fn seat_at_table() {}
-    }
+pub mod listing_07_01 {
+    mod front_of_house {
+        mod hosting {
+            fn add_to_waitlist() {}
 
-    mod serving {
-        fn take_order() {}
+            fn seat_at_table() {}
+        }
 
-        fn serve_order() {}
+        mod serving {
+            fn take_order() {}
 
-        fn take_payment() {}
+            fn serve_order() {}
+
+            fn take_payment() {}
+        }
     }
 }
 
@@ -67,6 +82,9 @@ mod front_of_house {
 
 // Listing 7-2 shows the module tree for the structure in Listing 7-1.
 
+
+
+
 /*
 crate
  └── front_of_house
@@ -79,36 +97,3 @@ crate
          └── take_payment
 
 */
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
-
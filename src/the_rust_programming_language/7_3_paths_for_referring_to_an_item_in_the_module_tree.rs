@@ -5,27 +5,33 @@
 
 
 // Listing 7-3: Calling the add_to_waitlist function using absolute and relative paths
-mod front_of_house {
-    mod hosting {
-        fn add_to_waitlist() {}
-    }
-}
 
-pub fn eat_at_restaurant() {
-    // Absolute path
-    crate::front_of_house::hosting::add_to_waitlist();
+/// ```compile_fail
+/// mod front_of_house {
+///     mod hosting {
+///         fn add_to_waitlist() {}
+///     }
+/// }
+///
+/// pub fn eat_at_restaurant() {
+///     // Absolute path
+///     crate::front_of_house::hosting::add_to_waitlist();
+///
+///     // Relative path
+///     front_of_house::hosting::add_to_waitlist();
+/// }
+/// # fn main() {}
+/// ```
+pub mod listing_07_03 {}
 
-    // Relative path
-    front_of_house::hosting::add_to_waitlist();
-}
 
 
 
+// Let’s try to compile Listing 7-3 and find out why it won’t compile yet! The error 
+// we get is shown in Listing 7-4.
 
 
 
-// Let’s try to compile Listing 7-3 and find out why it won’t compile yet! The error 
-// we get is shown in Listing 7-4.
 
 /*
 $ cargo build
@@ -62,28 +68,36 @@ error: could not compile `restaurant` due to 2 previous errors
 
 
 
-
 // Exposing Paths with the pub Keyword
 
 // Listing 7-5: Declaring the hosting module as pub to use it from eat_at_restaurant
-mod front_of_house {
-    pub mod hosting {
-        fn add_to_waitlist() {}
-    }
-}
 
-pub fn eat_at_restaurant() {
-    // Absolute path
-    crate::front_of_house::hosting::add_to_waitlist();
+/// ```compile_fail
+/// mod front_of_house {
+///     pub mod hosting {
+///         fn add_to_waitlist() {}
+///     }
+/// }
+///
+/// pub fn eat_at_restaurant() {
+///     // Absolute path
+///     crate::front_of_house::hosting::add_to_waitlist();
+///
+///     // Relative path
+///     front_of_house::hosting::add_to_waitlist();
+/// }
+/// # fn main() {}
+/// ```
+pub mod listing_07_05 {}
 
-    // Relative path
-    front_of_house::hosting::add_to_waitlist();
-}
 
 
 
 // Unfortunately, the code in Listing 7-5 still results in an error, as shown in Listing 7-6.
 
+
+
+
 /*
 $ cargo build
    Compiling restaurant v0.1.0 (file:///projects/restaurant)
@@ -121,38 +135,40 @@ error: could not compile `restaurant` due to 2 previous errors
 
 // Listing 7-7: Adding the pub keyword to mod hosting and fn add_to_waitlist 
 // lets us call the function from eat_at_restaurant
-mod front_of_house {
-    pub mod hosting {
-        pub fn add_to_waitlist() {}
+
+pub mod listing_07_07 {
+    mod front_of_house {
+        pub mod hosting {
+            pub fn add_to_waitlist() {}
+        }
     }
-}
 
-pub fn eat_at_restaurant() {
-    // Absolute path
-    crate::front_of_house::hosting::add_to_waitlist();
+    pub fn eat_at_restaurant() {
+        // Absolute path
+        crate::trpl::ch07_modules::paths::listing_07_07::front_of_house::hosting::add_to_waitlist();
 
-    // Relative path
-    front_of_house::hosting::add_to_waitlist();
+        // Relative path
+        front_of_house::hosting::add_to_waitlist();
+    }
 }
 
 
 
 
-
 // Listing 7-8: Calling a function using a relative path starting with super
-fn deliver_order() {}
-
-mod back_of_house {
-    fn fix_incorrect_order() {
-        cook_order();
-        super::deliver_order();
-    }
-
-    fn cook_order() {}
-}
 
+pub mod listing_07_08 {
+    fn deliver_order() {}
 
+    mod back_of_house {
+        fn fix_incorrect_order() {
+            cook_order();
+            super::deliver_order();
+        }
 
+        fn cook_order() {}
+    }
+}
 
 
 
@@ -160,57 +176,52 @@ mod back_of_house {
 // Making Structs and Enums Public
 
 // Listing 7-9: A struct with some public fields and some private fields
-mod back_of_house {
-    pub struct Breakfast {
-        pub toast: String,
-        seasonal_fruit: String,
-    }
 
-    impl Breakfast {
-        pub fn summer(toast: &str) -> Breakfast {
-            Breakfast {
-                toast: String::from(toast),
-                seasonal_fruit: String::from("peaches"),
+pub mod listing_07_09 {
+    mod back_of_house {
+        pub struct Breakfast {
+            pub toast: String,
+            seasonal_fruit: String,
+        }
+
+        impl Breakfast {
+            pub fn summer(toast: &str) -> Breakfast {
+                Breakfast {
+                    toast: String::from(toast),
+                    seasonal_fruit: String::from("peaches"),
+                }
             }
         }
     }
-}
 
-pub fn eat_at_restaurant() {
-    // Order a breakfast in the summer with Rye toast
-    let mut meal = back_of_house::Breakfast::summer("Rye");
-    // Change our mind about what bread we'd like
-    meal.toast = String::from("Wheat");
-    println!("I'd like {} toast please", meal.toast);
+    pub fn eat_at_restaurant() {
+        // Order a breakfast in the summer with Rye toast
+        let mut meal = back_of_house::Breakfast::summer("Rye");
+        // Change our mind about what bread we'd like
+        meal.toast = String::from("Wheat");
+        println!("I'd like {} toast please", meal.toast);
 
-    // The next line won't compile if we uncomment it; we're not allowed
-    // to see or modify the seasonal fruit that comes with the meal
-    // meal.seasonal_fruit = String::from("blueberries");
+        // The next line won't compile if we uncomment it; we're not allowed
+        // to see or modify the seasonal fruit that comes with the meal
+        // meal.seasonal_fruit = String::from("blueberries");
+    }
 }
 
 
 
 
-
 // Listing 7-10: Designating an enum as public makes all its variants public
-mod back_of_house {
-    pub enum Appetizer {
-        Soup,
-        Salad,
+
+pub mod listing_07_10 {
+    mod back_of_house {
+        pub enum Appetizer {
+            Soup,
+            Salad,
+        }
     }
-}
 
-pub fn eat_at_restaurant() {
-    let order1 = back_of_house::Appetizer::Soup;
-    let order2 = back_of_house::Appetizer::Salad;
+    pub fn eat_at_restaurant() {
+        let order1 = back_of_house::Appetizer::Soup;
+        let order2 = back_of_house::Appetizer::Salad;
+    }
 }
-
-
-
-
-
-
-
-
-
-
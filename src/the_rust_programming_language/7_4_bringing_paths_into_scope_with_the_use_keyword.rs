@@ -5,40 +5,52 @@
 
 
 // Listing 7-11: Bringing a module into scope with use
-mod front_of_house {
-    pub mod hosting {
-        pub fn add_to_waitlist() {}
+
+pub mod listing_07_11 {
+    mod front_of_house {
+        pub mod hosting {
+            pub fn add_to_waitlist() {}
+        }
     }
-}
 
-use crate::front_of_house::hosting;
+    use crate::trpl::ch07_modules::use_keyword::listing_07_11::front_of_house::hosting;
 
-pub fn eat_at_restaurant() {
-    hosting::add_to_waitlist();
+    pub fn eat_at_restaurant() {
+        hosting::add_to_waitlist();
+    }
 }
 
 
 
-// Listing 7-12: A use statement only applies in the scope it’s in
-mod front_of_house {
-    pub mod hosting {
-        pub fn add_to_waitlist() {}
-    }
-}
 
-use crate::front_of_house::hosting;
+// Listing 7-12: A use statement only applies in the scope it’s in
 
-mod customer {
-    pub fn eat_at_restaurant() {
-        hosting::add_to_waitlist();
-    }
-}
+/// ```compile_fail
+/// mod front_of_house {
+///     pub mod hosting {
+///         pub fn add_to_waitlist() {}
+///     }
+/// }
+///
+/// use crate::front_of_house::hosting;
+///
+/// mod customer {
+///     pub fn eat_at_restaurant() {
+///         hosting::add_to_waitlist();
+///     }
+/// }
+/// # fn main() {}
+/// ```
+pub mod listing_07_12 {}
 
 
 
 
 // The compiler error shows that the shortcut no longer applies within the customer module:
 
+
+
+
 /*
 $ cargo build
    Compiling restaurant v0.1.0 (file:///projects/restaurant)
@@ -65,50 +77,57 @@ error: could not compile `restaurant` due to previous error; 1 warning emitted
 
 
 
-
-
 // Creating Idiomatic use Paths
 
 // Listing 7-13: Bringing the add_to_waitlist function into scope with use, which is unidiomatic
-mod front_of_house {
-    pub mod hosting {
-        pub fn add_to_waitlist() {}
+
+pub mod listing_07_13 {
+    mod front_of_house {
+        pub mod hosting {
+            pub fn add_to_waitlist() {}
+        }
     }
-}
 
-use crate::front_of_house::hosting::add_to_waitlist;
+    use crate::trpl::ch07_modules::use_keyword::listing_07_13::front_of_house::hosting::add_to_waitlist;
 
-pub fn eat_at_restaurant() {
-    add_to_waitlist();
+    pub fn eat_at_restaurant() {
+        add_to_waitlist();
+    }
 }
 
 
 
+
 // Listing 7-14: Bringing HashMap into scope in an idiomatic way
-use std::collections::HashMap;
 
-fn main() {
-    let mut map = HashMap::new();
-    map.insert(1, 2);
+pub mod listing_07_14 {
+    use std::collections::HashMap;
+
+    pub fn main() {
+        let mut map = HashMap::new();
+        map.insert(1, 2);
+    }
 }
 
 
 
 
 // Listing 7-15: Bringing two types with the same name into the same scope requires using their parent modules.
-use std::fmt;
-use std::io;
 
-fn function1() -> fmt::Result {
-    // --snip--
-    Ok(())
-}
+pub mod listing_07_15 {
+    use std::fmt;
+    use std::io;
 
-fn function2() -> io::Result<()> {
-    // --snip--
-    Ok(())
-}
+    fn function1() -> fmt::Result {
+        // --snip--
+        Ok(())
+    }
 
+    fn function2() -> io::Result<()> {
+        // --snip--
+        Ok(())
+    }
+}
 
 
 
@@ -116,20 +135,21 @@ fn function2() -> io::Result<()> {
 // Providing New Names with the as Keyword
 
 // Listing 7-16: Renaming a type when it’s brought into scope with the as keyword
-use std::fmt::Result;
-use std::io::Result as IoResult;
-
-fn function1() -> Result {
-    // --snip--
-    Ok(())
-}
 
-fn function2() -> IoResult<()> {
-    // --snip--
-    Ok(())
-}
+pub mod listing_07_16 {
+    use std::fmt::Result;
+    use std::io::Result as IoResult;
 
+    fn function1() -> Result {
+        // --snip--
+        Ok(())
+    }
 
+    fn function2() -> IoResult<()> {
+        // --snip--
+        Ok(())
+    }
+}
 
 
 
@@ -137,78 +157,93 @@ fn function2() -> IoResult<()> {
 // Re-exporting Names with pub use
 
 // Listing 7-17: Making a name available for any code to use from a new scope with pub use
-mod front_of_house {
-    pub mod hosting {
-        pub fn add_to_waitlist() {}
+
+pub mod listing_07_17 {
+    mod front_of_house {
+        pub mod hosting {
+            pub fn add_to_waitlist() {}
+        }
     }
-}
 
-pub use crate::front_of_house::hosting;
+    pub use crate::trpl::ch07_modules::use_keyword::listing_07_17::front_of_house::hosting;
 
-pub fn eat_at_restaurant() {
-    hosting::add_to_waitlist();
+    pub fn eat_at_restaurant() {
+        hosting::add_to_waitlist();
+    }
 }
 
 
 
 
-
-
 // Using External Packages
 
 // Filename: Cargo.toml
-rand = "0.8.3"
+
+/// ```toml
+/// rand = "0.8.3"
+/// ```
+pub mod example_08 {}
+
+
 
 
 // Recall that in the “Generating a Random Number” section in Chapter 2, we brought 
 // the Rng trait into scope and called the rand::thread_rng function:
-use std::io;
-use rand::Rng;
 
-fn main() {
-    println!("Guess the number!");
+pub mod example_09 {
+    use std::io;
+    use rand::Rng;
 
-    let secret_number = rand::thread_rng().gen_range(1..=100);
+    pub fn main() {
+        println!("Guess the number!");
 
-    println!("The secret number is: {secret_number}");
+        let secret_number = rand::thread_rng().gen_range(1..=100);
 
-    println!("Please input your guess.");
+        println!("The secret number is: {secret_number}");
 
-    let mut guess = String::new();
+        println!("Please input your guess.");
 
-    io::stdin()
-        .read_line(&mut guess)
-        .expect("Failed to read line");
+        let mut guess = String::new();
 
-    println!("You guessed: {guess}");
+        io::stdin()
+            .read_line(&mut guess)
+            .expect("Failed to read line");
+
+        println!("You guessed: {guess}");
+    }
 }
 
 
 
 
 // This is an absolute path starting with std, the name of the standard library crate.
-#![allow(unused)]
-fn main() {
-use std::collections::HashMap;
+
+pub mod example_10 {
+    #![allow(unused)]
+    pub fn main() {
+    use std::collections::HashMap;
+    }
 }
 
 
 
 
+// Using Nested Paths to Clean Up Large use Lists
 
 
-// Using Nested Paths to Clean Up Large use Lists
 
 
 // For example, these two use statements we had in the Guessing Game in 
 // Listing 2-4 bring items from std into scope:
 
 // --snip--
-use std::cmp::Ordering;
-use std::io;
-// --snip--
 
+pub mod listing_02_04 {
+    use std::cmp::Ordering;
+    use std::io;
 
+    // --snip--
+}
 
 
 
@@ -216,37 +251,40 @@ use std::io;
 // Listing 7-18: Specifying a nested path to bring multiple items with the same prefix into scope
 
 // --snip--
-use std::{cmp::Ordering, io};
-// --snip--
 
+pub mod listing_07_18 {
+    use std::{cmp::Ordering, io};
 
+    // --snip--
+}
 
-// Listing 7-19: Two use statements where one is a subpath of the other
-// Filename: src/lib.rs
-use std::io;
-use std::io::Write;
 
 
 
-// Listing 7-20: Combining the paths in Listing 7-19 into one use statement
+// Listing 7-19: Two use statements where one is a subpath of the other
 // Filename: src/lib.rs
-// This line brings std::io and std::io::Write into scope.
-use std::io::{self, Write};
-
-
-
-
-
-// The Glob Operator
-use std::collections::*;
 
+pub mod listing_07_19 {
+    use std::io;
+    use std::io::Write;
+}
 
 
 
 
+// Listing 7-20: Combining the paths in Listing 7-19 into one use statement
+// Filename: src/lib.rs
+// This line brings std::io and std::io::Write into scope.
 
+pub mod listing_07_20 {
+    use std::io::{self, Write};
+}
 
 
 
 
+// The Glob Operator
 
+pub mod example_15 {
+    use std::collections::*;
+}
@@ -6,36 +6,39 @@
 
 // Listing 7-21: Declaring the front_of_house module whose body will be in src/front_of_house.rs
 // Filename: src/lib.rs
-mod front_of_house;
-
-pub use crate::front_of_house::hosting;
-
-pub fn eat_at_restaurant() {
-    hosting::add_to_waitlist();
-}
 
+/// ```ignore
+/// mod front_of_house;
+///
+/// pub use crate::front_of_house::hosting;
+///
+/// pub fn eat_at_restaurant() {
+///     hosting::add_to_waitlist();
+/// }
+/// ```
+pub mod listing_07_21 {}
 
 
 
 
 // Listing 7-22: Definitions inside the front_of_house module in src/front_of_house.rs
 // Filename: src/front_of_house.rs
-pub mod hosting {
-    pub fn add_to_waitlist() {}
-}
-
-
-// Filename: src/front_of_house.rs
-pub mod hosting;
-
-// Filename: src/front_of_house/hosting.rs
-pub fn add_to_waitlist() {}
-
-
-
 
+pub mod listing_07_22 {
+    pub mod hosting {
+        pub fn add_to_waitlist() {}
+    }
+}
 
 
 
 
+// Filename: src/front_of_house.rs
 
+/// ```ignore
+/// pub mod hosting;
+///
+/// // Filename: src/front_of_house/hosting.rs
+/// pub fn add_to_waitlist() {}
+/// ```
+pub mod example_03 {}
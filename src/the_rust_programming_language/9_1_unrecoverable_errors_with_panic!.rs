@@ -3,74 +3,81 @@
 
 
 
-// Unwinding the Stack or Aborting in Response to a Panic
-[profile.release]
-panic = 'abort'
-
 
+// Unwinding the Stack or Aborting in Response to a Panic
 
-// Let’s try calling panic! in a simple program:
-fn main() {
-    panic!("crash and burn");
-}
+/// ```toml
+/// [profile.release]
+/// panic = 'abort'
+/// ```
+pub mod example_01 {}
 
-/*
-$ cargo run
-   Compiling panic v0.1.0 (file:///projects/panic)
-    Finished dev [unoptimized + debuginfo] target(s) in 0.25s
-     Running `target/debug/panic`
-thread 'main' panicked at 'crash and burn', src/main.rs:2:5
-note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
 
-*/
 
 
+// Let’s try calling panic! in a simple program:
 
-// Using a panic! Backtrace
+pub mod example_02 {
+    pub fn main() {
+        panic!("crash and burn");
+    }
 
-// Listing 9-1: Attempting to access an element beyond the end of a vector, which will cause a call to panic!
-fn main() {
-    let v = vec![1, 2, 3];
+    /*
+    $ cargo run
+       Compiling panic v0.1.0 (file:///projects/panic)
+        Finished dev [unoptimized + debuginfo] target(s) in 0.25s
+         Running `target/debug/panic`
+    thread 'main' panicked at 'crash and burn', src/main.rs:2:5
+    note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
 
-    v[99];
+    */
 }
 
-/*
-$ cargo run
-   Compiling panic v0.1.0 (file:///projects/panic)
-    Finished dev [unoptimized + debuginfo] target(s) in 0.27s
-     Running `target/debug/panic`
-thread 'main' panicked at 'index out of bounds: the len is 3 but the index is 99', src/main.rs:4:5
-note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
-
-*/
-
-
-/*
-$ RUST_BACKTRACE=1 cargo run
-thread 'main' panicked at 'index out of bounds: the len is 3 but the index is 99', src/main.rs:4:5
-stack backtrace:
-   0: rust_begin_unwind
-             at /rustc/7eac88abb2e57e752f3302f02be5f3ce3d7adfb4/library/std/src/panicking.rs:483
-   1: core::panicking::panic_fmt
-             at /rustc/7eac88abb2e57e752f3302f02be5f3ce3d7adfb4/library/core/src/panicking.rs:85
-   2: core::panicking::panic_bounds_check
-             at /rustc/7eac88abb2e57e752f3302f02be5f3ce3d7adfb4/library/core/src/panicking.rs:62
-   3: <usize as core::slice::index::SliceIndex<[T]>>::index
-             at /rustc/7eac88abb2e57e752f3302f02be5f3ce3d7adfb4/library/core/src/slice/index.rs:255
-   4: core::slice::index::<impl core::ops::index::Index<I> for [T]>::index
-             at /rustc/7eac88abb2e57e752f3302f02be5f3ce3d7adfb4/library/core/src/slice/index.rs:15
-   5: <alloc::vec::Vec<T> as core::ops::index::Index<I>>::index
-             at /rustc/7eac88abb2e57e752f3302f02be5f3ce3d7adfb4/library/alloc/src/vec.rs:1982
-   6: panic::main
-             at ./src/main.rs:4
-   7: core::ops::function::FnOnce::call_once
-             at /rustc/7eac88abb2e57e752f3302f02be5f3ce3d7adfb4/library/core/src/ops/function.rs:227
-note: Some details are omitted, run with `RUST_BACKTRACE=full` for a verbose backtrace.
-
-*/
 
 
 
+// Using a panic! Backtrace
 
+// Listing 9-1: Attempting to access an element beyond the end of a vector, which will cause a call to panic!
 
+pub mod listing_09_01 {
+    pub fn main() {
+        let v = vec![1, 2, 3];
+
+        v[99];
+    }
+
+    /*
+    $ cargo run
+       Compiling panic v0.1.0 (file:///projects/panic)
+        Finished dev [unoptimized + debuginfo] target(s) in 0.27s
+         Running `target/debug/panic`
+    thread 'main' panicked at 'index out of bounds: the len is 3 but the index is 99', src/main.rs:4:5
+    note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+    */
+
+    /*
+    $ RUST_BACKTRACE=1 cargo run
+    thread 'main' panicked at 'index out of bounds: the len is 3 but the index is 99', src/main.rs:4:5
+    stack backtrace:
+       0: rust_begin_unwind
+                 at /rustc/7eac88abb2e57e752f3302f02be5f3ce3d7adfb4/library/std/src/panicking.rs:483
+       1: core::panicking::panic_fmt
+                 at /rustc/7eac88abb2e57e752f3302f02be5f3ce3d7adfb4/library/core/src/panicking.rs:85
+       2: core::panicking::panic_bounds_check
+                 at /rustc/7eac88abb2e57e752f3302f02be5f3ce3d7adfb4/library/core/src/panicking.rs:62
+       3: <usize as core::slice::index::SliceIndex<[T]>>::index
+                 at /rustc/7eac88abb2e57e752f3302f02be5f3ce3d7adfb4/library/core/src/slice/index.rs:255
+       4: core::slice::index::<impl core::ops::index::Index<I> for [T]>::index
+                 at /rustc/7eac88abb2e57e752f3302f02be5f3ce3d7adfb4/library/core/src/slice/index.rs:15
+       5: <alloc::vec::Vec<T> as core::ops::index::Index<I>>::index
+                 at /rustc/7eac88abb2e57e752f3302f02be5f3ce3d7adfb4/library/alloc/src/vec.rs:1982
+       6: panic::main
+                 at ./src/main.rs:4
+       7: core::ops::function::FnOnce::call_once
+                 at /rustc/7eac88abb2e57e752f3302f02be5f3ce3d7adfb4/library/core/src/ops/function.rs:227
+    note: Some details are omitted, run with `RUST_BACKTRACE=full` for a verbose backtrace.
+
+    */
+}
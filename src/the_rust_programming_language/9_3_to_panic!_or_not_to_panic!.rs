@@ -2,59 +2,67 @@
 // https://doc.rust-lang.org/stable/book/ch09-03-to-panic-or-not-to-panic.html
 
 
+
+
 // Cases in Which You Have More Information Than the Compiler
-fn main() {
-    use std::net::IpAddr;
 
-    let home: IpAddr = "127.0.0.1"
-        .parse()
-        .expect("Hardcoded IP address should be valid");
+pub mod example_01 {
+    pub fn main() {
+        use std::net::IpAddr;
+
+        let home: IpAddr = "127.0.0.1"
+            .parse()
+            .expect("Hardcoded IP address should be valid");
+    }
 }
 
 
 
 
 // Creating Custom Types for Validation
-use rand::Rng;
-use std::cmp::Ordering;
-use std::io;
 
-fn main() {
-    println!("Guess the number!");
+pub mod example_02 {
+    use rand::Rng;
+    use std::cmp::Ordering;
+    use std::io;
 
-    let secret_number = rand::thread_rng().gen_range(1..=100);
+    pub fn main() {
+        println!("Guess the number!");
 
-    loop {
-    
-        println!("Please input your guess.");
+        let secret_number = rand::thread_rng().gen_range(1..=100);
 
-        let mut guess = String::new();
+        loop {
 
-        io::stdin()
-            .read_line(&mut guess)
-            .expect("Failed to read line");
+            println!("Please input your guess.");
 
-         // --snip--
+            let mut guess = String::new();
 
-        let guess: i32 = match guess.trim().parse() {
-            Ok(num) => num,
-            Err(_) => continue,
-        };
+            io::stdin()
+                .read_line(&mut guess)
+                .expect("Failed to read line");
 
-        if guess < 1 || guess > 100 {
-            println!("The secret number will be between 1 and 100.");
-            continue;
-        }
+             // --snip--
+
+            let guess: i32 = match guess.trim().parse() {
+                Ok(num) => num,
+                Err(_) => continue,
+            };
+
+            if guess < 1 || guess > 100 {
+                println!("The secret number will be between 1 and 100.");
+                continue;
+            }
 
-        match guess.cmp(&secret_number) {
-        
-        // --snip--
-        
-            Ordering::Less => println!("Too small!"),
-            Ordering::Greater => println!("Too big!"),
-            Ordering::Equal => {
-                println!("You win!");
-                break;
+            match guess.cmp(&secret_number) {
+
+            // --snip--
+
+                Ordering::Less => println!("Too small!"),
+                Ordering::Greater => println!("Too big!"),
+                Ordering::Equal => {
+                    println!("You win!");
+                    break;
+                }
             }
         }
     }
@@ -63,56 +71,55 @@ fn main() {
 
 
 
+// Listing 9-13: A Guess type that will only continue with values between 1 and 100
 
+pub mod listing_09_13 {
+    #![allow(unused)]
+    pub fn main() {
+    pub struct Guess {
+        value: i32,
+    }
 
-// Listing 9-13: A Guess type that will only continue with values between 1 and 100
-#![allow(unused)]
-fn main() {
-pub struct Guess {
-    value: i32,
-}
+    impl Guess {
+        pub fn new(value: i32) -> Guess {
+            if value < 1 || value > 100 {
+                panic!("Guess value must be between 1 and 100, got {}.", value);
+            }
 
-impl Guess {
-    pub fn new(value: i32) -> Guess {
-        if value < 1 || value > 100 {
-            panic!("Guess value must be between 1 and 100, got {}.", value);
+            Guess { value }
         }
 
-        Guess { value }
+        pub fn value(&self) -> i32 {
+            self.value
+        }
     }
-
-    pub fn value(&self) -> i32 {
-        self.value
     }
 }
-}
 
 
 
 
 // Listing 9-13: A Guess type that will only continue with values between 1 and 100
-#![allow(unused)]
-fn main() {
-pub struct Guess {
-    value: i32,
-}
 
-impl Guess {
-    pub fn new(value: i32) -> Guess {
-        if value < 1 || value > 100 {
-            panic!("Guess value must be between 1 and 100, got {}.", value);
+pub mod example_04 {
+    #![allow(unused)]
+    pub fn main() {
+    pub struct Guess {
+        value: i32,
+    }
+
+    impl Guess {
+        pub fn new(value: i32) -> Guess {
+            if value < 1 || value > 100 {
+                panic!("Guess value must be between 1 and 100, got {}.", value);
+            }
+
+            Guess { value }
         }
 
-        Guess { value }
+        pub fn value(&self) -> i32 {
+            self.value
+        }
     }
-
-    pub fn value(&self) -> i32 {
-        self.value
     }
 }
-}
-
-
-
-
-
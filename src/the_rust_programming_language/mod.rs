@@ -0,0 +1,250 @@
+//! Listings from "The Rust Programming Language", one module per chapter
+//! and one submodule per section.
+//!
+//! https://doc.rust-lang.org/stable/book/
+
+#[path = "."]
+pub mod ch03_common_concepts {
+    #[path = "3_1_variables_and_mutability.rs"]
+    pub mod variables_and_mutability;
+
+    #[path = "3_2_data_tipes.rs"]
+    pub mod data_types;
+
+    #[path = "3_3_functions.rs"]
+    pub mod functions;
+
+    #[path = "3_4_comments.rs"]
+    pub mod comments;
+
+    #[path = "3_5_controm_flow.rs"]
+    pub mod control_flow;
+}
+
+#[path = "."]
+pub mod ch04_ownership {
+    #[path = "4_1_what is ownership.rs"]
+    pub mod what_is_ownership;
+
+    #[path = "4_2_references_and_borrowing.rs"]
+    pub mod references_and_borrowing;
+
+    #[path = "4_3_slice_type.rs"]
+    pub mod slices;
+}
+
+#[path = "."]
+pub mod ch05_structs {
+    #[path = "5_1_defining_and_ instantiating_ structs.rs"]
+    pub mod defining_structs;
+
+    #[path = "5_2_an_example_program_using_structs.rs"]
+    pub mod example_structs;
+
+    #[path = "5_3_method_syntax.rs"]
+    pub mod method_syntax;
+}
+
+#[path = "."]
+pub mod ch06_enums {
+    #[path = "6_1_defining_an_enum.rs"]
+    pub mod defining_an_enum;
+
+    #[path = "6_2_the_match_control_flow_construct.rs"]
+    pub mod match_control_flow;
+
+    #[path = "6_3_concise_control_flow_with_if_let.rs"]
+    pub mod if_let;
+}
+
+#[path = "."]
+pub mod ch07_modules {
+    #[path = "7_2_defining_modules_to_control_scope_and_privacy.rs"]
+    pub mod defining_modules;
+
+    #[path = "7_3_paths_for_referring_to_an_item_in_the_module_tree.rs"]
+    pub mod paths;
+
+    #[path = "7_4_bringing_paths_into_scope_with_the_use_keyword.rs"]
+    pub mod use_keyword;
+
+    #[path = "7_5_separating_modules_into_different_files.rs"]
+    pub mod separating_modules_into_files;
+}
+
+#[path = "."]
+pub mod ch08_collections {
+    #[path = "8_1_storing_lists_of_values_with_vectors.rs"]
+    pub mod vectors;
+
+    #[path = "8_2_storing_utf-8_encoded_text_with_strings.rs"]
+    pub mod strings;
+
+    #[path = "8_3_storing_keys_with_associated_values_in_hash_maps.rs"]
+    pub mod hash_maps;
+}
+
+#[path = "."]
+pub mod ch09_error_handling {
+    #[path = "9_1_unrecoverable_errors_with_panic!.rs"]
+    pub mod unrecoverable_errors_with_panic;
+
+    #[path = "9_2_recoverable_errors_with_result.rs"]
+    pub mod recoverable_errors_with_result;
+
+    #[path = "9_3_to_panic!_or_not_to_panic!.rs"]
+    pub mod to_panic_or_not_to_panic;
+}
+
+#[path = "."]
+pub mod ch10_generics {
+    #[path = "10_0_generic_types,_traits,_and_lifetimes.rs"]
+    pub mod intro;
+
+    #[path = "10_1_generic_data_types.rs"]
+    pub mod generic_data_types;
+
+    #[path = "10_2_traits_defining_shared_behavior.rs"]
+    pub mod traits;
+
+    #[path = "10_3_validating_references_with_lifetimes.rs"]
+    pub mod lifetimes;
+}
+
+#[path = "."]
+pub mod ch11_testing {
+    #[path = "11_1_how_to_write_tests.rs"]
+    pub mod writing_tests;
+
+    #[path = "11_2_сontrolling_how_tests_are_run.rs"]
+    pub mod running_tests;
+
+    #[path = "11_3_test_organization.rs"]
+    pub mod test_organization;
+}
+
+#[path = "."]
+pub mod ch12_minigrep {
+    #[path = "12_1_accepting_command_line_arguments.rs"]
+    pub mod accepting_command_line_arguments;
+
+    #[path = "12_2_reading_a_file.rs"]
+    pub mod reading_a_file;
+
+    #[path = "12_3_refactoring_to_improve_modularity_and_error_handling.rs"]
+    pub mod improving_error_handling_and_modularity;
+
+    #[path = "12_4_developing_the_library’s_functionality_with_test-driven_development.rs"]
+    pub mod testing_the_librarys_functionality;
+
+    #[path = "12_5_working_with_environment_variables.rs"]
+    pub mod working_with_environment_variables;
+
+    #[path = "12_6_writing_error_messages_to_standard_error_instead_of_standard_output.rs"]
+    pub mod writing_to_stderr;
+}
+
+#[path = "."]
+pub mod ch13_functional_features {
+    #[path = "13_1_closures_anonymous_functions_that_capture_their_environment.rs"]
+    pub mod closures;
+
+    #[path = "13_2_processing_a_series_of_items_with_iterators.rs"]
+    pub mod iterators;
+
+    #[path = "13_3_improving_our_IO_project.rs"]
+    pub mod improving_our_io_project;
+
+    #[path = "13_4_comparing_performance_loops_vs._iterators.rs"]
+    pub mod performance;
+}
+
+#[path = "."]
+pub mod ch14_cargo {
+    #[path = "14_1_customizing_builds_with_release_profiles.rs"]
+    pub mod release_profiles;
+
+    #[path = "14_2_publishing_a_crate_to_crates.io.rs"]
+    pub mod publishing_to_crates_io;
+
+    #[path = "14_3_cargo_workspaces.rs"]
+    pub mod cargo_workspaces;
+
+    #[path = "14_4_installing_binaries_with_cargo_install.rs"]
+    pub mod installing_binaries;
+}
+
+#[path = "."]
+pub mod ch15_smart_pointers {
+    #[path = "15_1_using_box_to_point_to_data_on_the_heap.rs"]
+    pub mod using_box;
+
+    #[path = "15_2_treating_smart_pointers_like_regular_references_with_the_deref_trait.rs"]
+    pub mod deref;
+
+    #[path = "15_3_running_code_on_cleanup_with_the_drop_trait.rs"]
+    pub mod drop;
+
+    #[path = "15_4_rc,_the_reference_counted_smart_pointer.rs"]
+    pub mod rc;
+
+    #[path = "15_5_refcell_and_the_interior_mutability_pattern.rs"]
+    pub mod interior_mutability;
+
+    #[path = "15_6_reference_cycles_can_leak_memory.rs"]
+    pub mod reference_cycles;
+}
+
+#[path = "."]
+pub mod ch16_concurrency {
+    #[path = "16_1_using_threads_to_run_code_simultaneously.rs"]
+    pub mod threads;
+
+    #[path = "16_2_using_message_passing_to_transfer_data_between_threads.rs"]
+    pub mod message_passing;
+
+    #[path = "16_3_shared-state_concurrency.rs"]
+    pub mod shared_state;
+}
+
+#[path = "."]
+pub mod ch17_oop {
+    #[path = "17_1_characteristics_of_object-oriented_languages.rs"]
+    pub mod what_is_oo;
+
+    #[path = "17_2_using_trait_objects_that_allow_for_values_of_different_types.rs"]
+    pub mod trait_objects;
+
+    #[path = "17_3_implementing_an_object-oriented_design_pattern.rs"]
+    pub mod oo_design_patterns;
+}
+
+#[path = "."]
+pub mod ch18_patterns {
+    #[path = "18_1_all_the_places_patterns_can_be_used.rs"]
+    pub mod all_the_places_for_patterns;
+
+    #[path = "18_2_refutability_whether_a_pattern_might_fail_to_match.rs"]
+    pub mod refutability;
+
+    #[path = "18_3_pattern_syntax.rs"]
+    pub mod pattern_syntax;
+}
+
+#[path = "."]
+pub mod ch19_advanced_features {
+    #[path = "19_1_unsafe_rust.rs"]
+    pub mod unsafe_rust;
+
+    #[path = "19_2_advanced_traits.rs"]
+    pub mod advanced_traits;
+
+    #[path = "19_3_advanced_types.rs"]
+    pub mod advanced_types;
+
+    #[path = "19_4_advanced_functions_and_closures.rs"]
+    pub mod advanced_functions_and_closures;
+
+    #[path = "19_5_macros.rs"]
+    pub mod macros;
+}